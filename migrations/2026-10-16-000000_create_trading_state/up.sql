@@ -0,0 +1,6 @@
+-- Trading state: single row holding the operator-controlled trading state
+CREATE TABLE trading_state (
+    id INTEGER PRIMARY KEY NOT NULL CHECK (id = 1),
+    state TEXT NOT NULL,  -- JSON: {"state": "halted", "reason": "..."}
+    updated_at TEXT NOT NULL  -- ISO 8601
+);
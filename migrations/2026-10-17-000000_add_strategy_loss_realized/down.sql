@@ -0,0 +1 @@
+ALTER TABLE strategy_daily_stats DROP COLUMN loss_realized;
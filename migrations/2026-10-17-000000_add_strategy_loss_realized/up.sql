@@ -0,0 +1,2 @@
+-- Track realized losses per strategy so net profit can be reported per strategy
+ALTER TABLE strategy_daily_stats ADD COLUMN loss_realized REAL NOT NULL DEFAULT 0;
@@ -0,0 +1,9 @@
+-- Latency snapshot: single row holding the latest detection latency percentiles
+CREATE TABLE latency_snapshot (
+    id INTEGER PRIMARY KEY NOT NULL CHECK (id = 1),
+    p50_us BIGINT NOT NULL,
+    p95_us BIGINT NOT NULL,
+    p99_us BIGINT NOT NULL,
+    sample_count INTEGER NOT NULL,
+    updated_at TEXT NOT NULL  -- ISO 8601
+);
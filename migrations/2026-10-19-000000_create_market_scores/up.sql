@@ -0,0 +1,12 @@
+-- Market scores: top-ranked markets from the latest startup with their factor breakdown
+CREATE TABLE market_scores (
+    rank INTEGER PRIMARY KEY NOT NULL,
+    market_id TEXT NOT NULL,
+    question TEXT NOT NULL,
+    composite REAL NOT NULL,
+    liquidity REAL NOT NULL,
+    spread REAL NOT NULL,
+    activity REAL NOT NULL,
+    time_to_close REAL NOT NULL,
+    scored_at TEXT NOT NULL  -- ISO 8601
+);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_opportunities_variant;
+ALTER TABLE opportunities DROP COLUMN variant;
@@ -0,0 +1,4 @@
+-- Tag opportunities with the strategy variant that detected them so shadow
+-- configurations can be compared against the primary one
+ALTER TABLE opportunities ADD COLUMN variant TEXT NOT NULL DEFAULT 'primary';
+CREATE INDEX idx_opportunities_variant ON opportunities(variant);
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_trades_opportunity_uid;
+DROP INDEX IF EXISTS idx_opportunities_uid;
+ALTER TABLE trades DROP COLUMN opportunity_uid;
+ALTER TABLE opportunities DROP COLUMN uid;
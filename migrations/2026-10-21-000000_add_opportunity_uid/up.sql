@@ -0,0 +1,6 @@
+-- Store the identifier generated at detection so opportunities and the
+-- trades they open can be matched against structured logs and notifications
+ALTER TABLE opportunities ADD COLUMN uid TEXT;
+ALTER TABLE trades ADD COLUMN opportunity_uid TEXT;
+CREATE INDEX idx_opportunities_uid ON opportunities(uid);
+CREATE INDEX idx_trades_opportunity_uid ON trades(opportunity_uid);
@@ -0,0 +1,18 @@
+-- Positions: tracked positions persisted so exposure survives restarts
+CREATE TABLE positions (
+    id INTEGER PRIMARY KEY NOT NULL,
+    market_id TEXT NOT NULL,
+    legs TEXT NOT NULL,  -- JSON array of {token_id, size, entry_price}
+    entry_cost TEXT NOT NULL,  -- Decimal
+    guaranteed_payout TEXT NOT NULL,  -- Decimal
+    status TEXT NOT NULL,  -- 'open', 'partial_fill', or 'closed'
+    filled TEXT,  -- JSON array of token IDs, partial fills only
+    missing TEXT,  -- JSON array of token IDs, partial fills only
+    realized_pnl TEXT,  -- Decimal, closed positions only
+    trade_id INTEGER,
+    held_to_settlement INTEGER NOT NULL DEFAULT 0,
+    opened_at TEXT NOT NULL,  -- ISO 8601
+    updated_at TEXT NOT NULL  -- ISO 8601
+);
+
+CREATE INDEX idx_positions_status ON positions(status);
@@ -0,0 +1,116 @@
+//! Handler for the `bench` command.
+//!
+//! Measures how fast the strategy registry evaluates synthetic in-memory
+//! markets, to help size `max_markets` for a host. Never touches the network
+//! or the database.
+
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::adapter::inbound::cli::command::BenchArgs;
+use crate::adapter::inbound::cli::{operator, output};
+use crate::error::Result;
+
+/// Run detection over synthetic markets and print throughput and latency.
+pub fn execute(args: &BenchArgs) -> Result<()> {
+    let service = operator::operator();
+    let config_toml = args
+        .config
+        .as_deref()
+        .map(operator::read_config_toml)
+        .transpose()?;
+    let strategies: Option<Vec<String>> = args.strategies.as_ref().map(|raw| {
+        raw.split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(ToOwned::to_owned)
+            .collect()
+    });
+
+    let pb = (!output::is_json()).then(|| {
+        output::spinner(&format!(
+            "Running {} passes over {} synthetic markets...",
+            args.iterations, args.markets
+        ))
+    });
+    let report = match service.bench_detection(
+        config_toml.as_deref(),
+        strategies.as_deref(),
+        args.markets,
+        args.iterations,
+        args.seed,
+    ) {
+        Ok(report) => {
+            if let Some(pb) = &pb {
+                output::spinner_success(pb, "Benchmark complete");
+            }
+            report
+        }
+        Err(e) => {
+            if let Some(pb) = &pb {
+                output::spinner_fail(pb, "Benchmark failed");
+            }
+            return Err(e);
+        }
+    };
+
+    if output::is_json() {
+        output::json_output(json!({
+            "command": "bench",
+            "markets": report.markets,
+            "tokens": report.tokens,
+            "strategies": report.strategies,
+            "iterations": report.iterations,
+            "seed": args.seed,
+            "detections": report.detections,
+            "opportunities": report.opportunities,
+            "elapsed_ms": millis(report.elapsed),
+            "detections_per_sec": report.detections_per_sec,
+            "opportunities_per_sec": report.opportunities_per_sec,
+            "latency": {
+                "p50_ms": millis(report.p50),
+                "p95_ms": millis(report.p95),
+                "p99_ms": millis(report.p99),
+            },
+        }));
+        return Ok(());
+    }
+
+    output::section("Detection Benchmark");
+    output::field("Markets", report.markets);
+    output::field("Tokens", report.tokens);
+    output::field("Strategies", report.strategies.join(", "));
+    output::field("Iterations", report.iterations);
+    output::field("Detections", report.detections);
+    output::field("Elapsed", format!("{:.1}ms", millis(report.elapsed)));
+
+    output::section("Throughput");
+    output::field("Detections", format!("{:.0}/s", report.detections_per_sec));
+    output::field(
+        "Opportunities",
+        format!(
+            "{:.0}/s ({} found)",
+            report.opportunities_per_sec, report.opportunities
+        ),
+    );
+
+    output::field(
+        "Latency",
+        format!(
+            "p50 {:.3}ms  p95 {:.3}ms  p99 {:.3}ms",
+            millis(report.p50),
+            millis(report.p95),
+            millis(report.p99)
+        ),
+    );
+
+    if report.strategies.is_empty() {
+        output::warning("No known strategies enabled; detection did no work");
+    }
+    Ok(())
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
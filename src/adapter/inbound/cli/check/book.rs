@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use crate::adapter::inbound::cli::{operator, output};
+use crate::error::{Error, Result};
+use serde_json::json;
+
+/// Validate the exchange order book parser against a golden fixture.
+pub fn execute_book_parse<P: AsRef<Path>>(config_path: P, fixture_path: P) -> Result<()> {
+    let config_toml = operator::read_config_toml(config_path.as_ref())?;
+    let fixture_json = std::fs::read_to_string(fixture_path.as_ref())?;
+    let report = operator::operator().check_book_parse(&config_toml, &fixture_json)?;
+
+    if output::is_json() {
+        output::json_output(json!({
+            "command": "check.book_parse",
+            "status": if report.is_match() { "match" } else { "mismatch" },
+            "exchange": report.exchange,
+            "books_parsed": report.books_parsed,
+            "books_expected": report.books_expected,
+            "mismatches": report.mismatches,
+        }));
+    } else {
+        output::section("Book Parse");
+        output::field("Exchange", &report.exchange);
+        output::field("Fixture", fixture_path.as_ref().display());
+        output::field(
+            "Books",
+            format!(
+                "{} parsed / {} expected",
+                report.books_parsed, report.books_expected
+            ),
+        );
+        for mismatch in &report.mismatches {
+            output::warning(mismatch);
+        }
+    }
+
+    if !report.is_match() {
+        output::error("Book parse check failed");
+        return Err(Error::Parse(format!(
+            "order book fixture mismatch ({} differences)",
+            report.mismatches.len()
+        )));
+    }
+    if !output::is_quiet() {
+        output::success("Parsed books match fixture");
+    }
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use std::path::Path;
+use std::time::Duration;
+
+use crate::adapter::inbound::cli::{operator, output};
+use crate::error::Result;
+use serde_json::json;
+
+/// Scan live markets once and print the opportunities strategies detect.
+///
+/// Subscribes to up to `max_markets` markets, collects order books for
+/// `duration_secs`, then runs detection once. Never places orders.
+pub async fn execute_markets<P: AsRef<Path>>(
+    config_path: P,
+    max_markets: usize,
+    duration_secs: u64,
+) -> Result<()> {
+    let service = operator::operator();
+    let config_toml = operator::read_config_toml(config_path.as_ref())?;
+
+    let pb = (!output::is_json()).then(|| {
+        output::spinner(&format!(
+            "Collecting order books for {duration_secs}s from up to {max_markets} markets..."
+        ))
+    });
+    let report = match service
+        .scan_markets(
+            &config_toml,
+            max_markets,
+            Duration::from_secs(duration_secs),
+        )
+        .await
+    {
+        Ok(report) => {
+            if let Some(pb) = &pb {
+                output::spinner_success(pb, "Order books collected");
+            }
+            report
+        }
+        Err(e) => {
+            if let Some(pb) = &pb {
+                output::spinner_fail(pb, "Market scan failed");
+            }
+            return Err(e);
+        }
+    };
+
+    if output::is_json() {
+        let opportunities: Vec<_> = report
+            .opportunities
+            .iter()
+            .map(|opp| {
+                json!({
+                    "strategy": opp.strategy,
+                    "market_id": opp.market_id,
+                    "question": opp.question,
+                    "edge": opp.edge.to_string(),
+                    "volume": opp.volume.to_string(),
+                    "expected_profit": opp.expected_profit.to_string(),
+                    "explanation": opp.explanation,
+                })
+            })
+            .collect();
+        output::json_output(json!({
+            "command": "check.markets",
+            "exchange": report.exchange,
+            "markets": report.markets,
+            "tokens": report.tokens,
+            "books": report.books,
+            "duration_secs": report.duration.as_secs(),
+            "opportunities": opportunities,
+        }));
+        return Ok(());
+    }
+
+    output::section("Market Scan");
+    output::field("Exchange", &report.exchange);
+    output::field("Markets", report.markets);
+    output::field(
+        "Books",
+        format!("{} of {} tokens", report.books, report.tokens),
+    );
+    output::field("Collected", format!("{}s", report.duration.as_secs()));
+
+    if report.opportunities.is_empty() {
+        output::note("No opportunities detected");
+        return Ok(());
+    }
+
+    let widths = [20, 40, 8, 10, 10];
+
+    output::section("Opportunities");
+    output::table_header(&[
+        ("Strategy", 20),
+        ("Market", 40),
+        ("Edge", 8),
+        ("Volume", 10),
+        ("Profit", 10),
+    ]);
+    output::table_separator(&widths);
+
+    for opp in &report.opportunities {
+        output::table_row(
+            &[
+                opp.strategy.clone(),
+                output::truncate(&opp.question, 40),
+                format!("{:.2}%", opp.edge * rust_decimal::Decimal::from(100)),
+                format!("{:.2}", opp.volume),
+                format!("${:.2}", opp.expected_profit),
+            ],
+            &widths,
+        );
+        output::note(&format!("  {}", opp.explanation));
+    }
+
+    output::success(&format!(
+        "{} opportunities detected",
+        report.opportunities.len()
+    ));
+    Ok(())
+}
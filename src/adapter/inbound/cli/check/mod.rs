@@ -1,7 +1,9 @@
 //! Configuration and connection validation command handlers.
 
+pub mod book;
 pub mod config;
 pub mod connection;
 pub mod health;
 pub mod live;
+pub mod markets;
 pub mod telegram;
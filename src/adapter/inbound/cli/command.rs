@@ -4,6 +4,7 @@
 //! The CLI supports multiple subcommands for running the arbitrage detector,
 //! viewing statistics, managing configuration, and performing diagnostic checks.
 
+use chrono::NaiveDate;
 use clap::{Parser, Subcommand};
 use rust_decimal::Decimal;
 use std::path::PathBuf;
@@ -62,6 +63,9 @@ pub enum Commands {
     /// Show trading status and statistics
     Status(StatusArgs),
 
+    /// List open positions with mark-to-market P&L
+    Positions(PositionsArgs),
+
     /// View trading statistics
     #[command(subcommand)]
     Statistics(StatsCommand),
@@ -78,6 +82,9 @@ pub enum Commands {
     #[command(subcommand)]
     Check(CheckCommand),
 
+    /// Run every diagnostic check and report overall health
+    Doctor(ConfigPathArg),
+
     /// Manage wallet approvals
     #[command(subcommand)]
     Wallet(WalletCommand),
@@ -88,6 +95,12 @@ pub enum Commands {
     /// Explore available strategies
     #[command(subcommand)]
     Strategies(StrategyCommand),
+
+    /// Measure strategy detection throughput on synthetic markets
+    Bench(BenchArgs),
+
+    /// Backtest recorded market data with simulated execution
+    Simulate(SimulateArgs),
 }
 
 /// Subcommands for `edgelord statistics`.
@@ -102,6 +115,15 @@ pub enum StatsCommand {
     Week(StatisticsArgs),
     /// Display historical statistics over a configurable period.
     History(StatisticsHistoryArgs),
+    /// Display statistics between two dates.
+    Range(StatisticsRangeArgs),
+    /// Display profit and loss broken down by strategy.
+    Strategy(StatisticsHistoryArgs),
+    /// List recorded opportunities matching filters.
+    Opportunities(StatisticsOpportunitiesArgs),
+    /// Compare recorded executions against alternative thresholds.
+    #[command(name = "whatif")]
+    WhatIf(StatisticsWhatIfArgs),
     /// Export statistics to CSV format.
     Export(StatisticsExportArgs),
     /// Prune old records while keeping daily aggregates.
@@ -120,6 +142,10 @@ pub enum ConfigCommand {
     Show(ConfigPathArg),
     /// Validate a configuration file for correctness.
     Validate(ConfigPathArg),
+    /// List settings that differ from the defaults, grouped by section.
+    Diff(ConfigPathArg),
+    /// Upgrade a configuration file written for an older release.
+    Migrate(ConfigMigrateArgs),
 }
 
 /// Subcommands for `edgelord check`.
@@ -138,6 +164,10 @@ pub enum CheckCommand {
     Connection(ConfigPathArg),
     /// Test Telegram notification delivery.
     Telegram(ConfigPathArg),
+    /// Validate order book parsing against a golden fixture.
+    BookParse(CheckBookParseArgs),
+    /// Scan live markets once and print detected opportunities (no trading).
+    Markets(CheckMarketsArgs),
 }
 
 /// Subcommands for `edgelord wallet`.
@@ -152,8 +182,12 @@ pub enum WalletCommand {
     Status(ConfigPathArg),
     /// Display the wallet address derived from the private key.
     Address(ConfigPathArg),
+    /// Display USDC and native gas token balances.
+    Balance(ConfigPathArg),
     /// Transfer the full USDC balance to another address.
     Sweep(WalletSweepArgs),
+    /// Store the wallet private key in the OS keyring.
+    ImportKeyring(ConfigPathArg),
 }
 
 /// Subcommands for `edgelord strategies`.
@@ -181,6 +215,89 @@ pub struct ConfigPathArg {
     pub config: PathBuf,
 }
 
+/// Arguments for the `check book-parse` subcommand.
+///
+/// Points the exchange parser at a stored fixture containing a raw message
+/// and the order books it is expected to produce.
+#[derive(Parser, Debug)]
+pub struct CheckBookParseArgs {
+    /// Path to the JSON fixture file.
+    pub fixture: PathBuf,
+
+    /// Path to the configuration file.
+    #[arg(short, long, default_value_os_t = paths::default_config())]
+    pub config: PathBuf,
+}
+
+/// Arguments for the `check markets` subcommand.
+///
+/// Controls how many markets are subscribed and how long order books are
+/// collected before strategies run.
+#[derive(Parser, Debug)]
+pub struct CheckMarketsArgs {
+    /// Path to the configuration file.
+    #[arg(short, long, default_value_os_t = paths::default_config())]
+    pub config: PathBuf,
+
+    /// Maximum number of markets to subscribe to.
+    #[arg(long, default_value_t = 20)]
+    pub markets: usize,
+
+    /// Seconds to collect order books before evaluating.
+    #[arg(long, default_value_t = 10)]
+    pub duration: u64,
+}
+
+/// Arguments for the `bench` subcommand.
+///
+/// Controls the size of the synthetic market set and which strategies run
+/// over it.
+#[derive(Parser, Debug)]
+pub struct BenchArgs {
+    /// Path to a configuration file for strategy settings (defaults if omitted).
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+
+    /// Number of synthetic markets to generate.
+    #[arg(long, default_value_t = 1000)]
+    pub markets: usize,
+
+    /// Comma-separated list of strategies to run (e.g., "single_condition,market_rebalancing").
+    #[arg(long)]
+    pub strategies: Option<String>,
+
+    /// Passes to make over every market.
+    #[arg(long, default_value_t = 10)]
+    pub iterations: usize,
+
+    /// Seed for generating markets and order books.
+    #[arg(long, default_value_t = 42)]
+    pub seed: u64,
+}
+
+/// Arguments for the `simulate` subcommand.
+///
+/// Points the backtest at a recording and controls the Monte Carlo
+/// reshuffling of its trades.
+#[derive(Parser, Debug)]
+pub struct SimulateArgs {
+    /// Recorded market data to replay (NDJSON with market definitions).
+    #[arg(long)]
+    pub data: PathBuf,
+
+    /// Path to the configuration file for strategy and risk settings.
+    #[arg(short, long, default_value_os_t = paths::default_config())]
+    pub config: PathBuf,
+
+    /// Shuffled trade orderings drawn for the drawdown distribution.
+    #[arg(long, default_value_t = 1000)]
+    pub runs: usize,
+
+    /// Seed for shuffling trade orderings.
+    #[arg(long, default_value_t = 42)]
+    pub seed: u64,
+}
+
 /// Arguments for the `status` subcommand.
 ///
 /// Controls data sources for the status display, including the database
@@ -196,6 +313,29 @@ pub struct StatusArgs {
     pub config: Option<PathBuf>,
 }
 
+/// Arguments for the `positions` subcommand.
+///
+/// Controls where open positions are read from and how current marks are
+/// collected from the exchange.
+#[derive(Parser, Debug)]
+pub struct PositionsArgs {
+    /// Path to the SQLite database file.
+    #[arg(long, default_value_os_t = paths::default_database())]
+    pub db: PathBuf,
+
+    /// Path to the configuration file used to fetch current order books.
+    #[arg(short, long, default_value_os_t = paths::default_config())]
+    pub config: PathBuf,
+
+    /// Seconds to collect order books for marks.
+    #[arg(long, default_value_t = 5)]
+    pub duration: u64,
+
+    /// Skip fetching current order books; show entry cost and settlement estimate only.
+    #[arg(long)]
+    pub offline: bool,
+}
+
 /// Arguments for the `statistics` subcommand.
 ///
 /// Specifies the database source for reading statistics data.
@@ -206,7 +346,7 @@ pub struct StatisticsArgs {
     pub db: PathBuf,
 }
 
-/// Arguments for the `statistics history` subcommand.
+/// Arguments for the `statistics history` and `statistics strategy` subcommands.
 ///
 /// Controls the historical view range and data source for statistics.
 #[derive(Parser, Debug)]
@@ -219,6 +359,90 @@ pub struct StatisticsHistoryArgs {
     pub db: PathBuf,
 }
 
+/// Arguments for the `statistics range` subcommand.
+///
+/// Selects an arbitrary date window and whether to report it as CSV.
+#[derive(Parser, Debug)]
+pub struct StatisticsRangeArgs {
+    /// First date to include (YYYY-MM-DD).
+    #[arg(long, alias = "since")]
+    pub from: NaiveDate,
+    /// Last date to include (YYYY-MM-DD, defaults to today).
+    #[arg(long)]
+    pub to: Option<NaiveDate>,
+    /// Emit daily statistics as CSV instead of a summary.
+    #[arg(long)]
+    pub csv: bool,
+    /// Write the CSV to this file (implies `--csv`).
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+    /// Path to the SQLite database file.
+    #[arg(long, default_value_os_t = paths::default_database())]
+    pub db: PathBuf,
+}
+
+/// Arguments for the `statistics opportunities` subcommand.
+///
+/// Filters recorded opportunities by strategy, outcome, edge, and date.
+#[derive(Parser, Debug)]
+pub struct StatisticsOpportunitiesArgs {
+    /// Only show opportunities from this strategy.
+    #[arg(long)]
+    pub strategy: Option<String>,
+    /// Only show opportunities from this strategy variant (e.g. "primary").
+    #[arg(long)]
+    pub variant: Option<String>,
+    /// Only show executed opportunities.
+    #[arg(long, conflicts_with = "rejected")]
+    pub executed: bool,
+    /// Only show opportunities that were not executed.
+    #[arg(long)]
+    pub rejected: bool,
+    /// Minimum edge per share (e.g., 0.05 for 5%).
+    #[arg(long)]
+    pub min_edge: Option<Decimal>,
+    /// Only show opportunities from the last N days.
+    #[arg(long, conflicts_with = "from")]
+    pub days: Option<u32>,
+    /// Earliest detection date (YYYY-MM-DD, inclusive).
+    #[arg(long)]
+    pub from: Option<NaiveDate>,
+    /// Latest detection date (YYYY-MM-DD, inclusive).
+    #[arg(long)]
+    pub to: Option<NaiveDate>,
+    /// Maximum number of opportunities to show.
+    #[arg(long, default_value = "50")]
+    pub limit: u32,
+    /// Path to the SQLite database file.
+    #[arg(long, default_value_os_t = paths::default_database())]
+    pub db: PathBuf,
+}
+
+/// Arguments for the `statistics whatif` subcommand.
+///
+/// Re-evaluates recorded opportunities under alternative thresholds.
+#[derive(Parser, Debug)]
+pub struct StatisticsWhatIfArgs {
+    /// Hypothetical minimum edge per share (e.g., 0.05 for 5%).
+    #[arg(long, required_unless_present = "min_profit")]
+    pub min_edge: Option<Decimal>,
+    /// Hypothetical minimum expected profit in USD.
+    #[arg(long)]
+    pub min_profit: Option<Decimal>,
+    /// Only evaluate opportunities from this strategy.
+    #[arg(long)]
+    pub strategy: Option<String>,
+    /// Strategy variant whose opportunities are evaluated.
+    #[arg(long, default_value = "primary")]
+    pub variant: String,
+    /// Number of days of recorded opportunities to evaluate.
+    #[arg(long, default_value = "30")]
+    pub days: u32,
+    /// Path to the SQLite database file.
+    #[arg(long, default_value_os_t = paths::default_database())]
+    pub db: PathBuf,
+}
+
 /// Arguments for the `statistics export` subcommand.
 ///
 /// Controls CSV export parameters including date range and output destination.
@@ -261,6 +485,19 @@ pub struct ConfigInitArgs {
     pub force: bool,
 }
 
+/// Arguments for the `config migrate` subcommand.
+///
+/// The original file is kept beside the upgraded one with a `.bak` suffix.
+#[derive(Parser, Debug)]
+pub struct ConfigMigrateArgs {
+    /// Path to the configuration file.
+    #[arg(short, long, default_value_os_t = paths::default_config())]
+    pub config: PathBuf,
+    /// Report the changes without writing the file.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 /// Arguments for the interactive `init` command.
 ///
 /// Controls the interactive configuration wizard that guides users through
@@ -287,6 +524,21 @@ pub struct RunArgs {
     #[arg(short, long, default_value_os_t = paths::default_config())]
     pub config: PathBuf,
 
+    /// Load `base.toml` from this directory instead of a single config file,
+    /// layering the `--env` overlay on top.
+    #[arg(long, conflicts_with = "config")]
+    pub config_dir: Option<PathBuf>,
+
+    /// Environment overlay to layer over `base.toml` (e.g. "mainnet" loads
+    /// `mainnet.toml` from `--config-dir`).
+    #[arg(long, requires = "config_dir")]
+    pub env: Option<String>,
+
+    /// Apply a resource preset (low, medium, high) sizing markets,
+    /// subscriptions, and connections. Explicit flags still take precedence.
+    #[arg(long)]
+    pub profile: Option<String>,
+
     /// Override chain ID (80002 for Amoy testnet, 137 for Polygon mainnet).
     #[arg(long)]
     pub chain_id: Option<u64>,
@@ -371,6 +623,19 @@ pub struct RunArgs {
     #[arg(long)]
     pub database: Option<PathBuf>,
 
+    // === Backtesting ===
+    /// Replay recorded order books from an NDJSON or CSV file (implies --dry-run).
+    #[arg(long)]
+    pub replay: Option<PathBuf>,
+
+    /// Replay speed multiplier (1.0 = real time, 0 = as fast as possible).
+    #[arg(long, requires = "replay")]
+    pub replay_speed: Option<f64>,
+
+    /// Preload order books from a snapshot file and save them back on exit.
+    #[arg(long)]
+    pub warm_from: Option<PathBuf>,
+
     // === Environment Shortcuts ===
     /// Use Polygon mainnet (shortcut for chain ID 137).
     #[arg(long, conflicts_with = "testnet")]
@@ -489,6 +754,38 @@ mod tests {
         assert_eq!(cli.verbose, 0);
     }
 
+    #[test]
+    fn test_parse_run_config_dir_with_env() {
+        let cli = Cli::try_parse_from([
+            "edgelord",
+            "run",
+            "--config-dir",
+            "conf",
+            "--env",
+            "mainnet",
+        ])
+        .unwrap();
+        let Commands::Run(args) = cli.command else {
+            panic!("expected run command");
+        };
+        assert_eq!(args.config_dir, Some(PathBuf::from("conf")));
+        assert_eq!(args.env.as_deref(), Some("mainnet"));
+    }
+
+    #[test]
+    fn test_parse_run_env_requires_config_dir() {
+        assert!(Cli::try_parse_from(["edgelord", "run", "--env", "mainnet"]).is_err());
+        assert!(Cli::try_parse_from([
+            "edgelord",
+            "run",
+            "--config",
+            "a.toml",
+            "--config-dir",
+            "conf"
+        ])
+        .is_err());
+    }
+
     #[test]
     fn test_parse_json_flag() {
         let cli = Cli::try_parse_from(["edgelord", "--json", "run"]).unwrap();
@@ -582,6 +879,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_run_args_warm_from() {
+        let cli = Cli::try_parse_from(["edgelord", "run", "--warm-from", "books.bin"]).unwrap();
+        if let Commands::Run(args) = cli.command {
+            assert_eq!(args.warm_from, Some(PathBuf::from("books.bin")));
+        } else {
+            panic!("Expected Run command");
+        }
+    }
+
     #[test]
     fn test_run_args_chain_id() {
         let cli = Cli::try_parse_from(["edgelord", "run", "--chain-id", "137"]).unwrap();
@@ -614,6 +921,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_positions_args_defaults_and_offline() {
+        let cli = Cli::try_parse_from(["edgelord", "positions", "--offline"]).unwrap();
+        if let Commands::Positions(args) = cli.command {
+            assert!(args.offline);
+            assert_eq!(args.duration, 5);
+        } else {
+            panic!("Expected Positions command");
+        }
+    }
+
     #[test]
     fn test_run_args_mainnet_testnet_conflict() {
         // mainnet and testnet are mutually exclusive
@@ -680,6 +998,98 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_statistics_range_parses_dates() {
+        let cli = Cli::try_parse_from([
+            "edgelord",
+            "statistics",
+            "range",
+            "--from",
+            "2024-01-01",
+            "--to",
+            "2024-01-31",
+        ])
+        .unwrap();
+        if let Commands::Statistics(StatsCommand::Range(args)) = cli.command {
+            assert_eq!(args.from, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+            assert_eq!(args.to, NaiveDate::from_ymd_opt(2024, 1, 31));
+            assert!(!args.csv);
+        } else {
+            panic!("Expected Range command");
+        }
+    }
+
+    #[test]
+    fn test_statistics_range_accepts_since_alias() {
+        let cli = Cli::try_parse_from(["edgelord", "statistics", "range", "--since", "2024-01-01"])
+            .unwrap();
+        if let Commands::Statistics(StatsCommand::Range(args)) = cli.command {
+            assert_eq!(args.from, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+            assert_eq!(args.to, None);
+        } else {
+            panic!("Expected Range command");
+        }
+    }
+
+    #[test]
+    fn test_statistics_range_requires_from() {
+        assert!(Cli::try_parse_from(["edgelord", "statistics", "range"]).is_err());
+    }
+
+    #[test]
+    fn test_statistics_strategy_with_days() {
+        let cli = Cli::try_parse_from(["edgelord", "statistics", "strategy", "14"]).unwrap();
+        if let Commands::Statistics(StatsCommand::Strategy(args)) = cli.command {
+            assert_eq!(args.days, 14);
+        } else {
+            panic!("Expected Strategy command");
+        }
+    }
+
+    #[test]
+    fn test_statistics_whatif_parses_thresholds() {
+        let cli = Cli::try_parse_from([
+            "edgelord",
+            "statistics",
+            "whatif",
+            "--min-edge",
+            "0.03",
+            "--min-profit",
+            "1.5",
+        ])
+        .unwrap();
+        if let Commands::Statistics(StatsCommand::WhatIf(args)) = cli.command {
+            assert_eq!(args.min_edge, Some(Decimal::new(3, 2)));
+            assert_eq!(args.min_profit, Some(Decimal::new(15, 1)));
+            assert_eq!(args.days, 30);
+            assert_eq!(args.variant, "primary");
+        } else {
+            panic!("Expected WhatIf command");
+        }
+    }
+
+    #[test]
+    fn test_statistics_opportunities_parses_variant() {
+        let cli = Cli::try_parse_from([
+            "edgelord",
+            "statistics",
+            "opportunities",
+            "--variant",
+            "candidate",
+        ])
+        .unwrap();
+        if let Commands::Statistics(StatsCommand::Opportunities(args)) = cli.command {
+            assert_eq!(args.variant.as_deref(), Some("candidate"));
+        } else {
+            panic!("Expected Opportunities command");
+        }
+    }
+
+    #[test]
+    fn test_statistics_whatif_requires_a_threshold() {
+        assert!(Cli::try_parse_from(["edgelord", "statistics", "whatif"]).is_err());
+    }
+
     #[test]
     fn test_statistics_export_command() {
         let cli = Cli::try_parse_from(["edgelord", "statistics", "export"]).unwrap();
@@ -758,6 +1168,27 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_config_diff_command() {
+        let cli = Cli::try_parse_from(["edgelord", "config", "diff"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Config(ConfigCommand::Diff(_))
+        ));
+    }
+
+    #[test]
+    fn test_config_migrate_command() {
+        let cli = Cli::try_parse_from(["edgelord", "config", "migrate", "--dry-run"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Config(ConfigCommand::Migrate(ConfigMigrateArgs {
+                dry_run: true,
+                ..
+            }))
+        ));
+    }
+
     // Tests for Check subcommands
 
     #[test]
@@ -805,6 +1236,57 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_doctor_command() {
+        let cli = Cli::try_parse_from(["edgelord", "doctor", "--config", "prod.toml"]).unwrap();
+        if let Commands::Doctor(args) = cli.command {
+            assert_eq!(args.config, PathBuf::from("prod.toml"));
+        } else {
+            panic!("Expected Doctor command");
+        }
+    }
+
+    #[test]
+    fn test_check_book_parse_command() {
+        let cli = Cli::try_parse_from(["edgelord", "check", "book-parse", "fixture.json"]).unwrap();
+        if let Commands::Check(CheckCommand::BookParse(args)) = cli.command {
+            assert_eq!(args.fixture, PathBuf::from("fixture.json"));
+        } else {
+            panic!("Expected Check BookParse command");
+        }
+    }
+
+    #[test]
+    fn test_check_markets_command_defaults() {
+        let cli = Cli::try_parse_from(["edgelord", "check", "markets"]).unwrap();
+        if let Commands::Check(CheckCommand::Markets(args)) = cli.command {
+            assert_eq!(args.markets, 20);
+            assert_eq!(args.duration, 10);
+        } else {
+            panic!("Expected Check Markets command");
+        }
+    }
+
+    #[test]
+    fn test_check_markets_command_with_flags() {
+        let cli = Cli::try_parse_from([
+            "edgelord",
+            "check",
+            "markets",
+            "--markets",
+            "5",
+            "--duration",
+            "30",
+        ])
+        .unwrap();
+        if let Commands::Check(CheckCommand::Markets(args)) = cli.command {
+            assert_eq!(args.markets, 5);
+            assert_eq!(args.duration, 30);
+        } else {
+            panic!("Expected Check Markets command");
+        }
+    }
+
     // Tests for Wallet subcommands
 
     #[test]
@@ -847,6 +1329,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_wallet_import_keyring_command() {
+        let cli = Cli::try_parse_from(["edgelord", "wallet", "import-keyring"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Wallet(WalletCommand::ImportKeyring(_))
+        ));
+    }
+
     #[test]
     fn test_wallet_status_command() {
         let cli = Cli::try_parse_from(["edgelord", "wallet", "status"]).unwrap();
@@ -865,6 +1356,15 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_wallet_balance_command() {
+        let cli = Cli::try_parse_from(["edgelord", "wallet", "balance"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Wallet(WalletCommand::Balance(_))
+        ));
+    }
+
     #[test]
     fn test_wallet_sweep_command() {
         let cli = Cli::try_parse_from([
@@ -906,6 +1406,92 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // Tests for Bench subcommand
+
+    #[test]
+    fn test_bench_defaults() {
+        let cli = Cli::try_parse_from(["edgelord", "bench"]).unwrap();
+        if let Commands::Bench(args) = cli.command {
+            assert!(args.config.is_none());
+            assert_eq!(args.markets, 1000);
+            assert!(args.strategies.is_none());
+            assert_eq!(args.iterations, 10);
+            assert_eq!(args.seed, 42);
+        } else {
+            panic!("Expected Bench command");
+        }
+    }
+
+    #[test]
+    fn test_bench_with_options() {
+        let cli = Cli::try_parse_from([
+            "edgelord",
+            "bench",
+            "--markets",
+            "5000",
+            "--strategies",
+            "single_condition,market_rebalancing",
+            "--iterations",
+            "3",
+        ])
+        .unwrap();
+        if let Commands::Bench(args) = cli.command {
+            assert_eq!(args.markets, 5000);
+            assert_eq!(
+                args.strategies.as_deref(),
+                Some("single_condition,market_rebalancing")
+            );
+            assert_eq!(args.iterations, 3);
+        } else {
+            panic!("Expected Bench command");
+        }
+    }
+
+    // Tests for Simulate subcommand
+
+    #[test]
+    fn test_simulate_defaults() {
+        let cli = Cli::try_parse_from(["edgelord", "simulate", "--data", "day.jsonl"]).unwrap();
+        if let Commands::Simulate(args) = cli.command {
+            assert_eq!(args.data, PathBuf::from("day.jsonl"));
+            assert_eq!(args.config, paths::default_config());
+            assert_eq!(args.runs, 1000);
+            assert_eq!(args.seed, 42);
+        } else {
+            panic!("Expected Simulate command");
+        }
+    }
+
+    #[test]
+    fn test_simulate_with_options() {
+        let cli = Cli::try_parse_from([
+            "edgelord",
+            "simulate",
+            "--data",
+            "day.jsonl",
+            "--config",
+            "cfg.toml",
+            "--runs",
+            "200",
+            "--seed",
+            "7",
+        ])
+        .unwrap();
+        if let Commands::Simulate(args) = cli.command {
+            assert_eq!(args.config, PathBuf::from("cfg.toml"));
+            assert_eq!(args.runs, 200);
+            assert_eq!(args.seed, 7);
+        } else {
+            panic!("Expected Simulate command");
+        }
+    }
+
+    #[test]
+    fn test_simulate_requires_data() {
+        let result = Cli::try_parse_from(["edgelord", "simulate"]);
+        assert!(result.is_err());
+    }
+
     // Tests for Strategy subcommands
 
     #[test]
@@ -1,12 +1,15 @@
 //! Handler for the `config` command group.
 
+use std::ffi::OsString;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use rust_decimal::prelude::ToPrimitive;
+use serde_json::json;
 
 use crate::adapter::inbound::cli::{operator, output};
 use crate::error::{ConfigError, Result};
+use crate::port::inbound::operator::config::ConfigMigrationChange;
 
 /// Default config template with documentation.
 const CONFIG_TEMPLATE: &str = include_str!("../../../../config.toml.example");
@@ -167,6 +170,142 @@ pub fn execute_validate(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Execute `config diff`.
+pub fn execute_diff(path: &Path) -> Result<()> {
+    let config_toml = operator::read_config_toml(path)?;
+    let diff = operator::operator().diff_config(&config_toml)?;
+
+    if output::is_json() {
+        let sections: Vec<_> = diff
+            .sections
+            .iter()
+            .map(|section| {
+                let entries: Vec<_> = section
+                    .entries
+                    .iter()
+                    .map(|entry| {
+                        json!({
+                            "key": entry.key,
+                            "default": entry.default,
+                            "value": entry.value,
+                        })
+                    })
+                    .collect();
+                json!({ "section": section.name, "changes": entries })
+            })
+            .collect();
+        output::json_output(json!({
+            "command": "config.diff",
+            "path": path.display().to_string(),
+            "sections": sections,
+        }));
+        return Ok(());
+    }
+
+    if diff.is_empty() {
+        output::section("Config Diff");
+        output::success("No settings differ from the defaults");
+        return Ok(());
+    }
+
+    for section in &diff.sections {
+        output::section(&section.name);
+        for entry in &section.entries {
+            output::field(
+                &entry.key,
+                format!("{} (default: {})", entry.value, entry.default),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Execute `config migrate`.
+///
+/// Writes the upgraded file in place after copying the original to
+/// `<path>.bak`. Nothing is written when the file is already current or
+/// `dry_run` is set.
+pub fn execute_migrate(path: &Path, dry_run: bool) -> Result<()> {
+    let config_toml = operator::read_config_toml(path)?;
+    let migration = operator::operator().migrate_config(&config_toml)?;
+    let backup = backup_path(path);
+    let written = migration.changed && !dry_run;
+
+    if written {
+        fs::copy(path, &backup)?;
+        let header = format!(
+            "# Migrated by `edgelord config migrate`; the original is in {}\n\n",
+            backup.display()
+        );
+        fs::write(path, header + &migration.config_toml)?;
+    }
+
+    if output::is_json() {
+        let changes: Vec<_> = migration
+            .changes
+            .iter()
+            .map(|change| match change {
+                ConfigMigrationChange::Renamed { from, to } => {
+                    json!({ "kind": "renamed", "path": from, "to": to })
+                }
+                ConfigMigrationChange::Removed { path, reason } => {
+                    json!({ "kind": "removed", "path": path, "reason": reason })
+                }
+                ConfigMigrationChange::Unknown { path } => {
+                    json!({ "kind": "unknown", "path": path })
+                }
+                ConfigMigrationChange::Defaulted { path } => {
+                    json!({ "kind": "defaulted", "path": path })
+                }
+            })
+            .collect();
+        output::json_output(json!({
+            "command": "config.migrate",
+            "path": path.display().to_string(),
+            "backup": written.then(|| backup.display().to_string()),
+            "changes": changes,
+        }));
+        return Ok(());
+    }
+
+    output::section("Config Migration");
+    output::field("Path", path.display());
+    for change in &migration.changes {
+        match change {
+            ConfigMigrationChange::Renamed { from, to } => {
+                output::note(&format!("renamed {from} -> {to}"));
+            }
+            ConfigMigrationChange::Removed { path, reason } => {
+                output::warning(&format!("removed {path}: {reason}"));
+            }
+            ConfigMigrationChange::Unknown { path } => {
+                output::warning(&format!("unknown setting {path} (kept, ignored)"));
+            }
+            ConfigMigrationChange::Defaulted { path } => {
+                output::note(&format!("added {path} with its default"));
+            }
+        }
+    }
+
+    if !migration.changed {
+        output::success("Config file is up to date");
+    } else if written {
+        output::success("Config file upgraded");
+        output::field("Backup", backup.display());
+    } else {
+        output::note("Dry run: no files written");
+    }
+    Ok(())
+}
+
+/// Path the original file is copied to before migrating: `<path>.bak`.
+fn backup_path(path: &Path) -> PathBuf {
+    let mut backup = OsString::from(path.as_os_str());
+    backup.push(".bak");
+    PathBuf::from(backup)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -298,6 +437,14 @@ mod tests {
         assert!(config_path.exists());
     }
 
+    #[test]
+    fn test_backup_path_appends_suffix() {
+        assert_eq!(
+            backup_path(Path::new("/etc/edgelord/config.toml")),
+            PathBuf::from("/etc/edgelord/config.toml.bak")
+        );
+    }
+
     // Tests for path edge cases
 
     #[test]
@@ -0,0 +1,419 @@
+//! Handler for the `doctor` command.
+//!
+//! Runs every diagnostic check in sequence and folds the results into a
+//! single [`HealthCheckReport`]. Critical entries decide the exit code;
+//! non-critical failures are reported as warnings.
+
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use crate::adapter::inbound::cli::{operator, output};
+use crate::error::{Error, Result};
+use crate::port::inbound::operator::diagnostic::{
+    ConfigCheckReport, ConnectionCheckTarget, HealthCheckEntry, HealthCheckReport,
+    HealthCheckStatus, LiveReadinessReport,
+};
+
+/// Run all diagnostic checks and print a consolidated report.
+///
+/// Returns an error when any critical check fails.
+pub async fn execute<P: AsRef<Path>>(config_path: P) -> Result<()> {
+    let service = operator::operator();
+    let mut report = HealthCheckReport::default();
+    let mut sections = serde_json::Map::new();
+
+    let config_toml = match operator::read_config_toml(config_path.as_ref()) {
+        Ok(config_toml) => config_toml,
+        Err(e) => {
+            report.checks.push(unhealthy("Configuration", true, &e));
+            return finish(&report, sections);
+        }
+    };
+
+    let telegram_enabled = match service.check_config(&config_toml) {
+        Ok(config) => {
+            report.checks.extend(config_entries(&config));
+            sections.insert("config".into(), config_json(&config));
+            config.telegram_enabled
+        }
+        Err(e) => {
+            // Nothing else can run without a valid configuration
+            report.checks.push(unhealthy("Configuration", true, &e));
+            return finish(&report, sections);
+        }
+    };
+
+    match service.health_report(&config_toml) {
+        Ok(health) => {
+            sections.insert("health".into(), health_json(&health));
+            report.checks.extend(health.checks);
+        }
+        Err(e) => report.checks.push(unhealthy("Health checks", true, &e)),
+    }
+
+    match service.check_live_readiness(&config_toml) {
+        Ok(live) => {
+            report.checks.push(live_entry(&live));
+            sections.insert("live".into(), live_json(&live));
+        }
+        Err(e) => report.checks.push(unhealthy("Live readiness", false, &e)),
+    }
+
+    match service.connection_target(&config_toml) {
+        Ok(target) => {
+            let rest = service.verify_rest_connectivity(&target.api_url).await;
+            report.checks.push(result_entry("REST API", true, &rest));
+            let websocket = service.verify_websocket_connectivity(&target.ws_url).await;
+            report
+                .checks
+                .push(result_entry("WebSocket", true, &websocket));
+            sections.insert(
+                "connection".into(),
+                connection_json(&target, &rest, &websocket),
+            );
+        }
+        Err(e) => report.checks.push(unhealthy("Connection", true, &e)),
+    }
+
+    if telegram_enabled {
+        let telegram = match service.send_telegram_test(&config_toml).await {
+            Ok(receipt) => {
+                report.checks.push(healthy("Telegram delivery", false));
+                json!({
+                    "status": "sent",
+                    "masked_token": receipt.masked_token,
+                    "chat_id": receipt.chat_id,
+                })
+            }
+            Err(e) => {
+                report
+                    .checks
+                    .push(unhealthy("Telegram delivery", false, &e));
+                json!({ "status": "failed", "details": e.to_string() })
+            }
+        };
+        sections.insert("telegram".into(), telegram);
+    }
+
+    finish(&report, sections)
+}
+
+/// Print the consolidated report and map critical failures to an error.
+fn finish(report: &HealthCheckReport, sections: serde_json::Map<String, Value>) -> Result<()> {
+    let warnings = warning_count(report);
+
+    if output::is_json() {
+        let mut payload = json!({
+            "command": "doctor",
+            "status": if report.is_healthy() { "healthy" } else { "unhealthy" },
+            "warnings": warnings,
+            "checks": report.checks.iter().map(entry_json).collect::<Vec<_>>(),
+        });
+        if let Value::Object(map) = &mut payload {
+            map.extend(sections);
+        }
+        output::json_output(payload);
+    } else {
+        output::section("Doctor");
+        for check in &report.checks {
+            match (&check.status, check.critical) {
+                (HealthCheckStatus::Healthy, _) => output::success(&check.name),
+                (HealthCheckStatus::Unhealthy(reason), true) => {
+                    output::error(&format!("{}: {reason}", check.name));
+                }
+                (HealthCheckStatus::Unhealthy(reason), false) => {
+                    output::warning(&format!("{}: {reason}", check.name));
+                }
+            }
+        }
+    }
+
+    if !report.is_healthy() {
+        output::error("Doctor found critical failures");
+        return Err(Error::Connection("doctor checks failed".to_string()));
+    }
+    if output::is_quiet() {
+        return Ok(());
+    }
+    if warnings > 0 {
+        output::success(&format!("All critical checks passed ({warnings} warnings)"));
+    } else {
+        output::success("All checks passed");
+    }
+    Ok(())
+}
+
+/// Count non-critical checks that failed.
+fn warning_count(report: &HealthCheckReport) -> usize {
+    report
+        .checks
+        .iter()
+        .filter(|check| !check.critical && !is_healthy(check))
+        .count()
+}
+
+fn is_healthy(check: &HealthCheckEntry) -> bool {
+    matches!(check.status, HealthCheckStatus::Healthy)
+}
+
+fn healthy(name: &str, critical: bool) -> HealthCheckEntry {
+    HealthCheckEntry {
+        name: name.to_string(),
+        critical,
+        status: HealthCheckStatus::Healthy,
+    }
+}
+
+fn unhealthy(name: &str, critical: bool, reason: impl ToString) -> HealthCheckEntry {
+    HealthCheckEntry {
+        name: name.to_string(),
+        critical,
+        status: HealthCheckStatus::Unhealthy(reason.to_string()),
+    }
+}
+
+fn result_entry(name: &str, critical: bool, result: &Result<()>) -> HealthCheckEntry {
+    match result {
+        Ok(()) => healthy(name, critical),
+        Err(e) => unhealthy(name, critical, e),
+    }
+}
+
+/// Entries derived from the configuration check.
+fn config_entries(report: &ConfigCheckReport) -> Vec<HealthCheckEntry> {
+    let mut entries = vec![healthy("Configuration", true)];
+
+    entries.push(if report.wallet_configured {
+        healthy("Wallet", false)
+    } else {
+        unhealthy("Wallet", false, "not configured (set WALLET_PRIVATE_KEY)")
+    });
+
+    if report.telegram_enabled {
+        let mut missing = Vec::new();
+        if !report.telegram_token_present {
+            missing.push("TELEGRAM_BOT_TOKEN");
+        }
+        if !report.telegram_chat_present {
+            missing.push("TELEGRAM_CHAT_ID");
+        }
+        entries.push(if missing.is_empty() {
+            healthy("Telegram config", false)
+        } else {
+            unhealthy(
+                "Telegram config",
+                false,
+                format!("missing {}", missing.join(", ")),
+            )
+        });
+    }
+
+    entries
+}
+
+/// Live readiness is advisory: dry-run deployments are still healthy.
+fn live_entry(report: &LiveReadinessReport) -> HealthCheckEntry {
+    if report.is_ready() {
+        return healthy("Live readiness", false);
+    }
+
+    let mut reasons = Vec::new();
+    if !report.environment_is_mainnet {
+        reasons.push("not mainnet");
+    }
+    if !report.chain_is_polygon_mainnet {
+        reasons.push("chain is not Polygon mainnet");
+    }
+    if !report.wallet_configured {
+        reasons.push("wallet not configured");
+    }
+    if report.dry_run {
+        reasons.push("dry run enabled");
+    }
+    unhealthy("Live readiness", false, reasons.join(", "))
+}
+
+fn entry_json(check: &HealthCheckEntry) -> Value {
+    let (status, details) = match &check.status {
+        HealthCheckStatus::Healthy => ("healthy", None),
+        HealthCheckStatus::Unhealthy(reason) => ("unhealthy", Some(reason.as_str())),
+    };
+
+    json!({
+        "name": check.name,
+        "critical": check.critical,
+        "status": status,
+        "details": details,
+    })
+}
+
+fn health_json(report: &HealthCheckReport) -> Value {
+    json!({
+        "status": if report.is_healthy() { "healthy" } else { "unhealthy" },
+        "checks": report.checks.iter().map(entry_json).collect::<Vec<_>>(),
+    })
+}
+
+fn config_json(report: &ConfigCheckReport) -> Value {
+    json!({
+        "exchange": report.exchange,
+        "environment": report.environment,
+        "chain_id": report.chain_id,
+        "strategies": report.enabled_strategies,
+        "dry_run": report.dry_run,
+        "wallet_configured": report.wallet_configured,
+        "telegram_enabled": report.telegram_enabled,
+    })
+}
+
+fn live_json(report: &LiveReadinessReport) -> Value {
+    json!({
+        "ready": report.is_ready(),
+        "environment_is_mainnet": report.environment_is_mainnet,
+        "chain_is_polygon_mainnet": report.chain_is_polygon_mainnet,
+        "wallet_configured": report.wallet_configured,
+        "dry_run": report.dry_run,
+    })
+}
+
+fn connection_json(
+    target: &ConnectionCheckTarget,
+    rest: &Result<()>,
+    websocket: &Result<()>,
+) -> Value {
+    let status = |result: &Result<()>| match result {
+        Ok(()) => json!({ "status": "connected" }),
+        Err(e) => json!({ "status": "failed", "details": e.to_string() }),
+    };
+
+    json!({
+        "exchange": target.exchange,
+        "environment": target.environment,
+        "api_url": target.api_url,
+        "ws_url": target.ws_url,
+        "rest": status(rest),
+        "websocket": status(websocket),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_report() -> ConfigCheckReport {
+        ConfigCheckReport {
+            exchange: "polymarket".to_string(),
+            environment: "mainnet".to_string(),
+            chain_id: 137,
+            enabled_strategies: vec!["binary".to_string()],
+            dry_run: true,
+            wallet_configured: true,
+            telegram_enabled: false,
+            telegram_token_present: false,
+            telegram_chat_present: false,
+        }
+    }
+
+    fn live_report() -> LiveReadinessReport {
+        LiveReadinessReport {
+            exchange: "polymarket".to_string(),
+            environment: "mainnet".to_string(),
+            chain_id: 137,
+            dry_run: false,
+            environment_is_mainnet: true,
+            chain_is_polygon_mainnet: true,
+            wallet_configured: true,
+        }
+    }
+
+    #[test]
+    fn test_config_entries_missing_wallet_is_warning() {
+        let report = ConfigCheckReport {
+            wallet_configured: false,
+            ..config_report()
+        };
+        let entries = config_entries(&report);
+
+        let wallet = entries.iter().find(|e| e.name == "Wallet").unwrap();
+        assert!(!wallet.critical);
+        assert!(!is_healthy(wallet));
+        assert!(HealthCheckReport { checks: entries }.is_healthy());
+    }
+
+    #[test]
+    fn test_config_entries_telegram_missing_env() {
+        let report = ConfigCheckReport {
+            telegram_enabled: true,
+            telegram_token_present: false,
+            telegram_chat_present: true,
+            ..config_report()
+        };
+        let entries = config_entries(&report);
+
+        let telegram = entries
+            .iter()
+            .find(|e| e.name == "Telegram config")
+            .unwrap();
+        match &telegram.status {
+            HealthCheckStatus::Unhealthy(reason) => {
+                assert_eq!(reason, "missing TELEGRAM_BOT_TOKEN")
+            }
+            HealthCheckStatus::Healthy => panic!("expected unhealthy telegram config"),
+        }
+    }
+
+    #[test]
+    fn test_config_entries_telegram_disabled_is_skipped() {
+        let entries = config_entries(&config_report());
+        assert!(entries.iter().all(|e| e.name != "Telegram config"));
+    }
+
+    #[test]
+    fn test_live_entry_ready() {
+        assert!(is_healthy(&live_entry(&live_report())));
+    }
+
+    #[test]
+    fn test_live_entry_dry_run_is_non_critical_warning() {
+        let report = LiveReadinessReport {
+            dry_run: true,
+            ..live_report()
+        };
+        let entry = live_entry(&report);
+
+        assert!(!entry.critical);
+        match entry.status {
+            HealthCheckStatus::Unhealthy(reason) => assert_eq!(reason, "dry run enabled"),
+            HealthCheckStatus::Healthy => panic!("expected dry run warning"),
+        }
+    }
+
+    #[test]
+    fn test_warning_count_ignores_critical_failures() {
+        let report = HealthCheckReport {
+            checks: vec![
+                healthy("Configuration", true),
+                unhealthy("REST API", true, "timeout"),
+                unhealthy("Wallet", false, "missing"),
+                unhealthy("Live readiness", false, "dry run enabled"),
+            ],
+        };
+
+        assert_eq!(warning_count(&report), 2);
+        assert!(!report.is_healthy());
+    }
+
+    #[test]
+    fn test_result_entry_maps_errors() {
+        let entry = result_entry(
+            "WebSocket",
+            true,
+            &Err(Error::Connection("refused".to_string())),
+        );
+
+        assert!(entry.critical);
+        assert!(!is_healthy(&entry));
+        assert_eq!(entry_json(&entry)["status"], "unhealthy");
+    }
+}
@@ -1,15 +1,19 @@
 //! CLI module graph.
 
+pub mod bench;
 pub mod check;
 pub mod command;
 pub mod config;
 pub mod diagnostic;
+pub mod doctor;
 pub mod init;
 pub mod operator;
 pub mod output;
 pub mod paths;
+pub mod positions;
 pub mod provision;
 pub mod run;
+pub mod simulate;
 pub mod stats;
 pub mod status;
 pub mod strategy;
@@ -27,6 +27,18 @@ pub fn read_config_toml(path: &Path) -> Result<String> {
     Ok(std::fs::read_to_string(path)?)
 }
 
+/// Load `base.toml` from `dir` and layer the `<env>.toml` overlay over it.
+///
+/// Without an environment the base file is returned as is.
+pub fn read_layered_config_toml(dir: &Path, env: Option<&str>) -> Result<String> {
+    let base = read_config_toml(&dir.join("base.toml"))?;
+    let Some(env) = env else {
+        return Ok(base);
+    };
+    let overlay = read_config_toml(&dir.join(format!("{env}.toml")))?;
+    operator().layer_config(&base, &overlay)
+}
+
 /// Build a sqlite database URL from a filesystem path.
 #[must_use]
 pub fn sqlite_database_url(path: &Path) -> String {
@@ -546,6 +546,16 @@ pub fn table_row(cells: &[String], widths: &[usize]) {
     println!("{}", line);
 }
 
+/// Truncate a table cell to `max` characters, ending it with an ellipsis
+/// when cut.
+pub fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        return s.to_string();
+    }
+    let cut: String = s.chars().take(max.saturating_sub(1)).collect();
+    format!("{cut}…")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -842,4 +852,12 @@ mod tests {
         assert_eq!(CONFIG.quiet, false);
         assert_eq!(CONFIG.verbose, 5);
     }
+
+    #[test]
+    fn test_truncate_keeps_cell_within_width() {
+        assert_eq!(truncate("short", 8), "short");
+        assert_eq!(truncate("exactly8", 8), "exactly8");
+        assert_eq!(truncate("much too long", 8), "much to…");
+        assert_eq!(truncate("ééééé", 3), "éé…");
+    }
 }
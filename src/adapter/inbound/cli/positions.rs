@@ -0,0 +1,201 @@
+//! Handler for the `positions` command.
+
+use std::path::Path;
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use serde_json::json;
+
+use crate::adapter::inbound::cli::command::PositionsArgs;
+use crate::adapter::inbound::cli::{operator, output};
+use crate::error::Result;
+use crate::port::inbound::operator::position::{OpenPositionView, PositionsReport};
+
+/// List open positions with entry cost, current value, and unrealized P&L.
+///
+/// Unless `--offline` is set, connects to the exchange for `--duration`
+/// seconds to collect current order books and marks each leg at its best bid.
+pub async fn execute(args: &PositionsArgs) -> Result<()> {
+    if !args.db.exists() {
+        if output::is_json() {
+            output::json_output(json!({
+                "command": "positions",
+                "database": args.db.display().to_string(),
+                "status": "missing_database",
+                "positions": [],
+            }));
+        } else {
+            output::warning(&format!("Database not found ({:?})", args.db));
+            output::hint("run `edgelord run` to start trading and create the database");
+        }
+        return Ok(());
+    }
+
+    let service = operator::operator();
+    let database_url = operator::sqlite_database_url(&args.db);
+    let config_toml = if args.offline {
+        None
+    } else {
+        Some(operator::read_config_toml(&args.config)?)
+    };
+    let duration = Duration::from_secs(args.duration);
+
+    let pb = (config_toml.is_some() && !output::is_json())
+        .then(|| output::spinner(&format!("Collecting marks for {}s...", args.duration)));
+    let report = match service
+        .load_positions(&database_url, config_toml.as_deref(), duration)
+        .await
+    {
+        Ok(report) => {
+            if let Some(pb) = &pb {
+                output::spinner_success(pb, "Marks collected");
+            }
+            report
+        }
+        Err(e) => {
+            if let Some(pb) = &pb {
+                output::spinner_fail(pb, "Failed to collect marks");
+            }
+            return Err(e);
+        }
+    };
+
+    if output::is_json() {
+        output::json_output(report_json(&args.db, &report));
+        return Ok(());
+    }
+
+    display(&report);
+    Ok(())
+}
+
+/// Build the JSON envelope emitted in JSON mode.
+fn report_json(db_path: &Path, report: &PositionsReport) -> serde_json::Value {
+    let positions: Vec<_> = report
+        .positions
+        .iter()
+        .map(|position| {
+            let legs: Vec<_> = position
+                .legs
+                .iter()
+                .map(|leg| {
+                    json!({
+                        "token_id": leg.token_id,
+                        "side": leg.side,
+                        "size": leg.size.to_string(),
+                        "entry_price": leg.entry_price.to_string(),
+                        "mark": leg.mark.map(|mark| mark.to_string()),
+                    })
+                })
+                .collect();
+            json!({
+                "trade_id": position.trade_id,
+                "strategy": position.strategy,
+                "market_ids": position.market_ids,
+                "opened_at": position.opened_at,
+                "legs": legs,
+                "entry_cost": position.entry_cost.to_string(),
+                "current_value": position.current_value.map(|value| value.to_string()),
+                "unrealized_pnl": position.unrealized_pnl.map(|pnl| pnl.to_string()),
+                "settlement_pnl": position.settlement_pnl.to_string(),
+            })
+        })
+        .collect();
+
+    json!({
+        "command": "positions",
+        "database": db_path.display().to_string(),
+        "status": "ok",
+        "marked": report.marked,
+        "positions": positions,
+        "totals": {
+            "entry_cost": report.total_entry_cost().to_string(),
+            "unrealized_pnl": report.total_unrealized_pnl().to_string(),
+            "settlement_pnl": report.total_settlement_pnl().to_string(),
+        },
+    })
+}
+
+/// Render the positions table and totals.
+fn display(report: &PositionsReport) {
+    output::section("Open Positions");
+
+    if report.positions.is_empty() {
+        output::note("No open positions");
+        return;
+    }
+
+    let widths = [6, 28, 10, 10, 12, 12];
+    output::table_header(&[
+        ("ID", 6),
+        ("Market", 28),
+        ("Entry", 10),
+        ("Value", 10),
+        ("Unrealized", 12),
+        ("Settlement", 12),
+    ]);
+    output::table_separator(&widths);
+
+    for position in &report.positions {
+        output::table_row(
+            &[
+                position.trade_id.to_string(),
+                output::truncate(&market_label(position), 28),
+                format!("${:.2}", position.entry_cost),
+                position
+                    .current_value
+                    .map_or_else(|| "-".to_string(), |value| format!("${value:.2}")),
+                position
+                    .unrealized_pnl
+                    .map_or_else(|| "-".to_string(), format_pnl),
+                format_pnl(position.settlement_pnl),
+            ],
+            &widths,
+        );
+        for leg in &position.legs {
+            let mark = leg
+                .mark
+                .map_or_else(|| "no book".to_string(), |mark| format!("{mark:.3}"));
+            output::note(&format!(
+                "  {} {} {} @ {:.3} → {}",
+                leg.side,
+                leg.size.normalize(),
+                output::truncate(&leg.token_id, 16),
+                leg.entry_price,
+                mark
+            ));
+        }
+    }
+
+    output::section("Totals");
+    output::field("Positions", report.positions.len());
+    output::field("Entry cost", format!("${:.2}", report.total_entry_cost()));
+    if report.marked {
+        output::field("Unrealized", format_pnl(report.total_unrealized_pnl()));
+    }
+    output::field("At settlement", format_pnl(report.total_settlement_pnl()));
+
+    if !report.marked {
+        output::hint("drop `--offline` to mark positions against current order books");
+    } else if report.positions.iter().any(|p| p.current_value.is_none()) {
+        output::hint("some legs had no bids; increase `--duration` to collect more books");
+    }
+}
+
+/// Describe the markets a position spans.
+fn market_label(position: &OpenPositionView) -> String {
+    match position.market_ids.as_slice() {
+        [] => "unknown".to_string(),
+        [single] => single.clone(),
+        [first, rest @ ..] => format!("{first} +{}", rest.len()),
+    }
+}
+
+/// Format a signed dollar amount.
+fn format_pnl(value: Decimal) -> String {
+    if value.is_sign_negative() {
+        format!("-${:.2}", value.abs())
+    } else {
+        format!("+${value:.2}")
+    }
+}
@@ -7,7 +7,10 @@ use crate::port::inbound::operator::runtime::{RunRequest, RunStartupSnapshot};
 
 /// Execute the run command.
 pub async fn execute(args: &RunArgs) -> Result<()> {
-    let config_toml = operator::read_config_toml(&args.config)?;
+    let config_toml = match &args.config_dir {
+        Some(dir) => operator::read_layered_config_toml(dir, args.env.as_deref())?,
+        None => operator::read_config_toml(&args.config)?,
+    };
     let machine_output = output::is_json();
     let request = build_run_request(args, config_toml, machine_output);
     let service = operator::operator();
@@ -31,6 +34,7 @@ fn build_run_request(args: &RunArgs, config_toml: String, force_json_logs: bool)
 
     RunRequest {
         config_toml,
+        profile: args.profile.clone(),
         chain_id: args.chain_id,
         log_level: args.log_level.clone(),
         json_logs: args.json_logs || force_json_logs,
@@ -54,6 +58,15 @@ fn build_run_request(args: &RunArgs, config_toml: String, force_json_logs: bool)
             .database
             .as_ref()
             .map(|path| path.to_string_lossy().to_string()),
+        replay_path: args
+            .replay
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string()),
+        replay_speed: args.replay_speed,
+        warm_from: args
+            .warm_from
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string()),
         mainnet: args.mainnet,
         testnet: args.testnet,
     }
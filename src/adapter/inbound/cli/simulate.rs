@@ -0,0 +1,104 @@
+//! Handler for the `simulate` command.
+//!
+//! Backtests a recorded session through detection and simulated execution,
+//! using an in-memory database. Never touches the exchange or the configured
+//! database.
+
+use rust_decimal::Decimal;
+use serde_json::json;
+
+use crate::adapter::inbound::cli::command::SimulateArgs;
+use crate::adapter::inbound::cli::{operator, output};
+use crate::error::Result;
+
+/// Replay a recording and print the backtest report.
+pub async fn execute(args: &SimulateArgs) -> Result<()> {
+    let service = operator::operator();
+    let config_toml = operator::read_config_toml(&args.config)?;
+    let data_path = args.data.to_string_lossy();
+
+    let pb = (!output::is_json())
+        .then(|| output::spinner(&format!("Replaying {}...", args.data.display())));
+    let report = match service
+        .simulate(&config_toml, &data_path, args.runs, args.seed)
+        .await
+    {
+        Ok(report) => {
+            if let Some(pb) = &pb {
+                output::spinner_success(pb, "Replay complete");
+            }
+            report
+        }
+        Err(e) => {
+            if let Some(pb) = &pb {
+                output::spinner_fail(pb, "Replay failed");
+            }
+            return Err(e);
+        }
+    };
+
+    if output::is_json() {
+        output::json_output(json!({
+            "command": "simulate",
+            "data": data_path,
+            "markets": report.markets,
+            "events": report.events,
+            "opportunities": report.opportunities,
+            "trades": report.trades,
+            "wins": report.wins,
+            "pnl": report.pnl.to_string(),
+            "max_drawdown": report.max_drawdown.to_string(),
+            "win_rate": report.win_rate,
+            "monte_carlo": {
+                "runs": report.runs,
+                "seed": args.seed,
+                "drawdown_p50": report.drawdown_p50.to_string(),
+                "drawdown_p95": report.drawdown_p95.to_string(),
+            },
+        }));
+        return Ok(());
+    }
+
+    output::section("Backtest");
+    output::field("Markets", report.markets);
+    output::field("Events", report.events);
+    output::field("Opportunities", report.opportunities);
+    output::field("Trades", report.trades);
+
+    output::section("Results");
+    output::field("P&L", format_pnl(report.pnl));
+    output::field("Max drawdown", format!("${:.2}", report.max_drawdown));
+    output::field(
+        "Win rate",
+        report.win_rate.map_or_else(
+            || "-".to_string(),
+            |rate| format!("{rate:.1}% ({}/{})", report.wins, report.trades),
+        ),
+    );
+
+    if report.runs > 0 && report.trades > 0 {
+        output::section("Monte Carlo");
+        output::field("Runs", report.runs);
+        output::field(
+            "Drawdown",
+            format!(
+                "p50 ${:.2}  p95 ${:.2}",
+                report.drawdown_p50, report.drawdown_p95
+            ),
+        );
+    }
+
+    if report.trades == 0 {
+        output::hint("no trades were simulated; check strategy thresholds and risk limits");
+    }
+    Ok(())
+}
+
+/// Format a signed dollar amount.
+fn format_pnl(value: Decimal) -> String {
+    if value.is_sign_negative() {
+        format!("-${:.2}", value.abs())
+    } else {
+        format!("+${value:.2}")
+    }
+}
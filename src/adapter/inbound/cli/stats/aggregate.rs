@@ -2,6 +2,9 @@
 
 use std::collections::HashMap;
 
+use rust_decimal::Decimal;
+
+use crate::domain::stats::{OpportunitySummary, StatsSummary};
 use crate::port::inbound::operator::stats::StrategyStatsRecord;
 
 /// Aggregate strategy breakdown rows by strategy name.
@@ -26,11 +29,28 @@ pub fn aggregate_by_strategy(rows: &[StrategyStatsRecord]) -> HashMap<String, St
         entry.profit_realized += row.profit_realized;
         entry.win_count += row.win_count;
         entry.loss_count += row.loss_count;
+        entry.loss_realized += row.loss_realized;
     }
 
     by_strategy
 }
 
+/// Order per-strategy summaries by net profit, best first.
+///
+/// Ties are broken by strategy name so output is stable.
+pub fn rank_by_net_profit(summaries: &HashMap<String, StatsSummary>) -> Vec<(&str, &StatsSummary)> {
+    let mut ranked: Vec<(&str, &StatsSummary)> = summaries
+        .iter()
+        .map(|(name, summary)| (name.as_str(), summary))
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.1.net_profit()
+            .cmp(&a.1.net_profit())
+            .then_with(|| a.0.cmp(b.0))
+    });
+    ranked
+}
+
 /// Compute a percentage, returning None if the denominator is zero.
 pub fn compute_percentage(numerator: i32, denominator: i32) -> Option<f64> {
     if denominator > 0 {
@@ -46,9 +66,66 @@ pub fn compute_win_rate(win_count: i32, loss_count: i32) -> Option<f64> {
     compute_percentage(win_count, total)
 }
 
+/// Recorded executions compared with what alternative thresholds would allow.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WhatIfComparison {
+    /// Opportunities evaluated.
+    pub considered: usize,
+    /// Opportunities actually executed.
+    pub current_executed: usize,
+    /// Expected profit of the executed opportunities.
+    pub current_profit: Decimal,
+    /// Opportunities that clear the alternative thresholds.
+    pub hypothetical_executed: usize,
+    /// Expected profit of the opportunities that clear them.
+    pub hypothetical_profit: Decimal,
+    /// Rejected opportunities the alternative thresholds would admit.
+    pub gained: usize,
+    /// Executed opportunities the alternative thresholds would drop.
+    pub dropped: usize,
+}
+
+/// Re-evaluate recorded opportunities against alternative thresholds.
+///
+/// An opportunity counts as hypothetically executed when it clears every
+/// threshold given. Risk limits and other rejection reasons are not replayed,
+/// so the hypothetical side is an upper bound.
+pub fn compare_thresholds(
+    opportunities: &[OpportunitySummary],
+    min_edge: Option<Decimal>,
+    min_profit: Option<Decimal>,
+) -> WhatIfComparison {
+    let mut comparison = WhatIfComparison {
+        considered: opportunities.len(),
+        ..Default::default()
+    };
+
+    for opp in opportunities {
+        let passes = min_edge.map_or(true, |min| opp.edge >= min)
+            && min_profit.map_or(true, |min| opp.expected_profit >= min);
+
+        if opp.executed {
+            comparison.current_executed += 1;
+            comparison.current_profit += opp.expected_profit;
+        }
+        if passes {
+            comparison.hypothetical_executed += 1;
+            comparison.hypothetical_profit += opp.expected_profit;
+        }
+        match (opp.executed, passes) {
+            (false, true) => comparison.gained += 1,
+            (true, false) => comparison.dropped += 1,
+            _ => {}
+        }
+    }
+
+    comparison
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::stats::PRIMARY_VARIANT;
 
     // Tests for compute_percentage
 
@@ -295,4 +372,87 @@ mod tests {
         let entry = result.get("loser").unwrap();
         assert_eq!(entry.profit_realized, -80.0);
     }
+
+    // Tests for rank_by_net_profit
+
+    #[test]
+    fn test_rank_by_net_profit_orders_best_first() {
+        use rust_decimal_macros::dec;
+
+        let mut summaries = HashMap::new();
+        summaries.insert(
+            "loser".to_string(),
+            StatsSummary {
+                loss_realized: dec!(20),
+                ..Default::default()
+            },
+        );
+        summaries.insert(
+            "winner".to_string(),
+            StatsSummary {
+                profit_realized: dec!(50),
+                loss_realized: dec!(10),
+                ..Default::default()
+            },
+        );
+        summaries.insert("idle_b".to_string(), StatsSummary::default());
+        summaries.insert("idle_a".to_string(), StatsSummary::default());
+
+        let ranked: Vec<&str> = rank_by_net_profit(&summaries)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(ranked, vec!["winner", "idle_a", "idle_b", "loser"]);
+    }
+
+    fn opportunity(edge: Decimal, expected_profit: Decimal, executed: bool) -> OpportunitySummary {
+        OpportunitySummary {
+            id: 0,
+            uid: None,
+            strategy: "single_condition".to_string(),
+            edge,
+            expected_profit,
+            executed,
+            rejected_reason: None,
+            detected_at: "2026-01-01T00:00:00Z".to_string(),
+            variant: PRIMARY_VARIANT.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_compare_thresholds_counts_current_and_hypothetical() {
+        use rust_decimal_macros::dec;
+
+        let opportunities = vec![
+            opportunity(dec!(0.06), dec!(3.00), true),
+            opportunity(dec!(0.02), dec!(1.00), true),
+            opportunity(dec!(0.04), dec!(2.00), false),
+            opportunity(dec!(0.01), dec!(0.50), false),
+        ];
+
+        let comparison = compare_thresholds(&opportunities, Some(dec!(0.03)), None);
+
+        assert_eq!(comparison.considered, 4);
+        assert_eq!(comparison.current_executed, 2);
+        assert_eq!(comparison.current_profit, dec!(4.00));
+        assert_eq!(comparison.hypothetical_executed, 2);
+        assert_eq!(comparison.hypothetical_profit, dec!(5.00));
+        assert_eq!(comparison.gained, 1);
+        assert_eq!(comparison.dropped, 1);
+    }
+
+    #[test]
+    fn test_compare_thresholds_applies_both_thresholds() {
+        use rust_decimal_macros::dec;
+
+        let opportunities = vec![
+            opportunity(dec!(0.06), dec!(0.50), false),
+            opportunity(dec!(0.06), dec!(2.00), false),
+        ];
+
+        let comparison = compare_thresholds(&opportunities, Some(dec!(0.05)), Some(dec!(1.00)));
+
+        assert_eq!(comparison.hypothetical_executed, 1);
+        assert_eq!(comparison.hypothetical_profit, dec!(2.00));
+    }
 }
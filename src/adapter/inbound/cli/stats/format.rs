@@ -1,13 +1,18 @@
 //! Formatting and printing functions for statistics output.
 
+use std::collections::HashMap;
+
 use rust_decimal::Decimal;
 
 use crate::adapter::inbound::cli::output;
-use crate::domain::stats::StatsSummary;
+use crate::domain::stats::{OpportunitySummary, StatsSummary, PRIMARY_VARIANT};
 use crate::error::Result;
 use crate::port::inbound::operator::stats::{DailyStatsRecord, StrategyStatsRecord};
 
-use super::aggregate::{aggregate_by_strategy, compute_percentage, compute_win_rate};
+use super::aggregate::{
+    aggregate_by_strategy, compute_percentage, compute_win_rate, rank_by_net_profit,
+    WhatIfComparison,
+};
 
 /// Print a statistics summary to stdout.
 pub fn print_summary(summary: &StatsSummary, label: &str) -> Result<()> {
@@ -101,6 +106,49 @@ pub fn print_breakdown(rows: &[StrategyStatsRecord]) -> Result<()> {
     Ok(())
 }
 
+/// Print per-strategy profit and loss table to stdout.
+pub fn print_strategy_summary(summaries: &HashMap<String, StatsSummary>) -> Result<()> {
+    if summaries.is_empty() {
+        output::note("No data for this period.");
+        return Ok(());
+    }
+
+    let widths = [20, 8, 8, 8, 8, 8, 10];
+
+    output::section("Strategy P&L");
+    output::table_header(&[
+        ("Strategy", 20),
+        ("Opps", 8),
+        ("Exec", 8),
+        ("Opened", 8),
+        ("Closed", 8),
+        ("Win %", 8),
+        ("Net P/L", 10),
+    ]);
+    output::table_separator(&widths);
+
+    for (name, summary) in rank_by_net_profit(summaries) {
+        let win_rate = summary
+            .win_rate()
+            .map(|r| format!("{r:.1}%"))
+            .unwrap_or_else(|| "N/A".to_string());
+        output::table_row(
+            &[
+                name.to_string(),
+                summary.opportunities_detected.to_string(),
+                summary.opportunities_executed.to_string(),
+                summary.trades_opened.to_string(),
+                summary.trades_closed.to_string(),
+                win_rate,
+                format!("${:.2}", summary.net_profit()),
+            ],
+            &widths,
+        );
+    }
+
+    Ok(())
+}
+
 /// Print daily breakdown table to stdout.
 pub fn print_daily(rows: &[DailyStatsRecord]) -> Result<()> {
     if rows.is_empty() {
@@ -140,6 +188,96 @@ pub fn print_daily(rows: &[DailyStatsRecord]) -> Result<()> {
     Ok(())
 }
 
+/// Print recorded opportunities table to stdout.
+pub fn print_opportunities(opportunities: &[OpportunitySummary]) -> Result<()> {
+    if opportunities.is_empty() {
+        output::note("No matching opportunities.");
+        return Ok(());
+    }
+
+    let widths = [17, 20, 10, 8, 10, 24];
+
+    output::section("Opportunities");
+    output::table_header(&[
+        ("Detected", 17),
+        ("Strategy", 20),
+        ("Variant", 10),
+        ("Edge", 8),
+        ("Profit", 10),
+        ("Outcome", 24),
+    ]);
+    output::table_separator(&widths);
+
+    for opp in opportunities {
+        let detected: String = opp
+            .detected_at
+            .replacen('T', " ", 1)
+            .chars()
+            .take(16)
+            .collect();
+        let outcome = if opp.executed {
+            "executed".to_string()
+        } else if opp.variant != PRIMARY_VARIANT {
+            "shadow".to_string()
+        } else {
+            opp.rejected_reason
+                .clone()
+                .unwrap_or_else(|| "rejected".to_string())
+        };
+        output::table_row(
+            &[
+                detected,
+                opp.strategy.clone(),
+                opp.variant.clone(),
+                format!("{:.2}%", opp.edge * Decimal::ONE_HUNDRED),
+                format!("${:.2}", opp.expected_profit),
+                outcome,
+            ],
+            &widths,
+        );
+    }
+
+    Ok(())
+}
+
+/// Print current and hypothetical executions side by side.
+pub fn print_whatif(comparison: &WhatIfComparison) -> Result<()> {
+    if comparison.considered == 0 {
+        output::note("No recorded opportunities for this period.");
+        return Ok(());
+    }
+
+    let widths = [20, 12, 12];
+
+    output::field("Opportunities", comparison.considered);
+    output::section("Comparison");
+    output::table_header(&[("", 20), ("Current", 12), ("What-if", 12)]);
+    output::table_separator(&widths);
+    output::table_row(
+        &[
+            "Executed".to_string(),
+            comparison.current_executed.to_string(),
+            comparison.hypothetical_executed.to_string(),
+        ],
+        &widths,
+    );
+    output::table_row(
+        &[
+            "Expected profit".to_string(),
+            format!("${:.2}", comparison.current_profit),
+            format!("${:.2}", comparison.hypothetical_profit),
+        ],
+        &widths,
+    );
+
+    output::section("Changes");
+    output::field("Newly executed", comparison.gained);
+    output::field("No longer executed", comparison.dropped);
+    output::hint("risk limits are not replayed, so what-if counts are an upper bound");
+
+    Ok(())
+}
+
 /// Print open positions count if non-zero.
 pub fn print_open_positions(open_count: i64) {
     if open_count > 0 {
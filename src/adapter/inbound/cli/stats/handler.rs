@@ -3,18 +3,29 @@
 //! This module orchestrates data loading, aggregation, and formatting
 //! for statistics CLI commands.
 
+use std::collections::HashMap;
 use std::path::Path;
 
 use chrono::NaiveDate;
 use serde_json::json;
 
+use crate::adapter::inbound::cli::command::{
+    StatisticsOpportunitiesArgs, StatisticsRangeArgs, StatisticsWhatIfArgs,
+};
 use crate::adapter::inbound::cli::{operator, output};
-use crate::domain::stats::StatsSummary;
+use crate::domain::stats::{OpportunityFilter, OpportunitySummary, StatsSummary};
 use crate::error::Result;
 use crate::port::inbound::operator::stats::{DailyStatsRecord, StrategyStatsRecord};
 
-use super::format::{print_breakdown, print_daily, print_open_positions, print_summary};
-use super::json::{daily_rows_to_json, strategy_rows_to_json, summary_to_json};
+use super::aggregate::compare_thresholds;
+use super::format::{
+    print_breakdown, print_daily, print_open_positions, print_opportunities,
+    print_strategy_summary, print_summary, print_whatif,
+};
+use super::json::{
+    daily_rows_to_json, opportunities_to_json, strategy_rows_to_json, strategy_summaries_to_json,
+    summary_to_json, whatif_to_json,
+};
 use super::range::DateRange;
 
 // Data loading helpers - delegate to operator
@@ -31,6 +42,14 @@ fn load_strategy_breakdown(
     operator::operator().load_strategy_breakdown(database_url, from, to)
 }
 
+fn load_strategy_summary(
+    database_url: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<HashMap<String, StatsSummary>> {
+    operator::operator().load_strategy_summary(database_url, from, to)
+}
+
 fn load_open_positions(database_url: &str) -> Result<i64> {
     operator::operator().load_open_positions(database_url)
 }
@@ -51,6 +70,13 @@ fn prune_old_records(database_url: &str, retention_days: u32) -> Result<()> {
     operator::operator().prune_old_records(database_url, retention_days)
 }
 
+fn query_opportunities(
+    database_url: &str,
+    filter: OpportunityFilter,
+) -> Result<Vec<OpportunitySummary>> {
+    operator::operator().query_opportunities(database_url, filter)
+}
+
 /// Execute `statistics` (default: today).
 pub fn execute_today(db_path: &Path) -> Result<()> {
     if output::is_quiet() && !output::is_json() {
@@ -138,6 +164,193 @@ pub fn execute_history(db_path: &Path, days: u32) -> Result<()> {
     Ok(())
 }
 
+/// Execute `statistics range --from DATE [--to DATE] [--csv] [--output FILE]`.
+pub fn execute_range(args: &StatisticsRangeArgs) -> Result<()> {
+    let range = DateRange::between(args.from, args.to)?;
+    let database_url = operator::sqlite_database_url(&args.db);
+
+    if args.csv || args.output.is_some() {
+        let csv = export_daily_csv(&database_url, range.start, range.end)?;
+        return write_range_csv(&range, &csv, args.output.as_deref());
+    }
+
+    if output::is_quiet() && !output::is_json() {
+        return Ok(());
+    }
+
+    let summary = load_summary(&database_url, range.start, range.end)?;
+    let rows = load_daily_rows(&database_url, range.start, range.end)?;
+
+    if output::is_json() {
+        output::json_output(json!({
+            "command": "statistics.range",
+            "label": range.label,
+            "from": range.start.to_string(),
+            "to": range.end.to_string(),
+            "summary": summary_to_json(&summary),
+            "daily_breakdown": daily_rows_to_json(&rows),
+        }));
+        return Ok(());
+    }
+
+    print_summary(&summary, &range.label)?;
+    print_daily(&rows)?;
+
+    Ok(())
+}
+
+/// Write or print the CSV produced by `statistics range --csv`.
+fn write_range_csv(range: &DateRange, csv: &str, output_path: Option<&Path>) -> Result<()> {
+    if let Some(path) = output_path {
+        std::fs::write(path, csv)?;
+    }
+
+    if output::is_json() {
+        let mut payload = json!({
+            "command": "statistics.range",
+            "from": range.start.to_string(),
+            "to": range.end.to_string(),
+        });
+        if let Some(path) = output_path {
+            payload["status"] = json!("written");
+            payload["path"] = json!(path.display().to_string());
+            payload["bytes"] = json!(csv.len());
+        } else {
+            payload["status"] = json!("stdout");
+            payload["csv"] = json!(csv);
+        }
+        output::json_output(payload);
+        return Ok(());
+    }
+
+    if let Some(path) = output_path {
+        output::success("Statistics export complete");
+        output::field("Range", &range.label);
+        output::field("Path", path.display());
+    } else {
+        print!("{csv}");
+    }
+
+    Ok(())
+}
+
+/// Execute `statistics strategy [days]`.
+pub fn execute_strategy(db_path: &Path, days: u32) -> Result<()> {
+    if output::is_quiet() && !output::is_json() {
+        return Ok(());
+    }
+
+    let database_url = operator::sqlite_database_url(db_path);
+    let range = DateRange::history(days);
+    let summaries = load_strategy_summary(&database_url, range.start, range.end)?;
+
+    if output::is_json() {
+        output::json_output(json!({
+            "command": "statistics.strategy",
+            "label": range.label,
+            "days": days,
+            "from": range.start.to_string(),
+            "to": range.end.to_string(),
+            "strategies": strategy_summaries_to_json(&summaries),
+        }));
+        return Ok(());
+    }
+
+    output::section(&range.label);
+    print_strategy_summary(&summaries)?;
+
+    Ok(())
+}
+
+/// Execute `statistics opportunities [filters]`.
+pub fn execute_opportunities(args: &StatisticsOpportunitiesArgs) -> Result<()> {
+    if output::is_quiet() && !output::is_json() {
+        return Ok(());
+    }
+
+    let database_url = operator::sqlite_database_url(&args.db);
+    let executed = match (args.executed, args.rejected) {
+        (true, _) => Some(true),
+        (_, true) => Some(false),
+        _ => None,
+    };
+    let filter = OpportunityFilter {
+        strategy: args.strategy.clone(),
+        variant: args.variant.clone(),
+        executed,
+        min_edge: args.min_edge,
+        from: args
+            .from
+            .or_else(|| args.days.map(|days| DateRange::history(days).start)),
+        to: args.to,
+        limit: Some(args.limit),
+    };
+    let opportunities = query_opportunities(&database_url, filter.clone())?;
+
+    if output::is_json() {
+        output::json_output(json!({
+            "command": "statistics.opportunities",
+            "filter": {
+                "strategy": filter.strategy,
+                "variant": filter.variant,
+                "executed": filter.executed,
+                "min_edge": filter.min_edge,
+                "from": filter.from.map(|date| date.to_string()),
+                "to": filter.to.map(|date| date.to_string()),
+                "limit": filter.limit,
+            },
+            "opportunities": opportunities_to_json(&opportunities),
+        }));
+        return Ok(());
+    }
+
+    print_opportunities(&opportunities)?;
+
+    Ok(())
+}
+
+/// Execute `statistics whatif --min-edge X --min-profit Y`.
+pub fn execute_whatif(args: &StatisticsWhatIfArgs) -> Result<()> {
+    if output::is_quiet() && !output::is_json() {
+        return Ok(());
+    }
+
+    let database_url = operator::sqlite_database_url(&args.db);
+    let range = DateRange::history(args.days);
+    let filter = OpportunityFilter {
+        strategy: args.strategy.clone(),
+        variant: Some(args.variant.clone()),
+        from: Some(range.start),
+        to: Some(range.end),
+        ..Default::default()
+    };
+    let opportunities = query_opportunities(&database_url, filter)?;
+    let comparison = compare_thresholds(&opportunities, args.min_edge, args.min_profit);
+
+    if output::is_json() {
+        output::json_output(json!({
+            "command": "statistics.whatif",
+            "label": range.label,
+            "days": args.days,
+            "from": range.start.to_string(),
+            "to": range.end.to_string(),
+            "strategy": args.strategy,
+            "variant": args.variant,
+            "thresholds": {
+                "min_edge": args.min_edge,
+                "min_profit": args.min_profit,
+            },
+            "comparison": whatif_to_json(&comparison),
+        }));
+        return Ok(());
+    }
+
+    output::section(&range.label);
+    print_whatif(&comparison)?;
+
+    Ok(())
+}
+
 /// Execute `statistics export [--days N] [--output FILE]`.
 pub fn execute_export(db_path: &Path, days: u32, output_path: Option<&Path>) -> Result<()> {
     let database_url = operator::sqlite_database_url(db_path);
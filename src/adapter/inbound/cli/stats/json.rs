@@ -1,11 +1,13 @@
 //! JSON serialization for statistics output.
 
+use std::collections::HashMap;
+
 use serde_json::{json, Value};
 
-use crate::domain::stats::StatsSummary;
+use crate::domain::stats::{OpportunitySummary, StatsSummary};
 use crate::port::inbound::operator::stats::{DailyStatsRecord, StrategyStatsRecord};
 
-use super::aggregate::compute_win_rate;
+use super::aggregate::{compute_win_rate, rank_by_net_profit, WhatIfComparison};
 
 /// Convert a StatsSummary to JSON.
 pub fn summary_to_json(summary: &StatsSummary) -> Value {
@@ -37,6 +39,8 @@ pub fn strategy_rows_to_json(rows: &[StrategyStatsRecord]) -> Value {
                 "trades_opened": row.trades_opened,
                 "trades_closed": row.trades_closed,
                 "profit_realized": row.profit_realized,
+                "loss_realized": row.loss_realized,
+                "net_profit": row.profit_realized - row.loss_realized,
                 "win_count": row.win_count,
                 "loss_count": row.loss_count,
                 "win_rate_pct": win_rate,
@@ -46,6 +50,27 @@ pub fn strategy_rows_to_json(rows: &[StrategyStatsRecord]) -> Value {
     json!(payload)
 }
 
+/// Convert per-strategy summaries to JSON, ordered by net profit.
+pub fn strategy_summaries_to_json(summaries: &HashMap<String, StatsSummary>) -> Value {
+    let payload: Vec<_> = rank_by_net_profit(summaries)
+        .into_iter()
+        .map(|(name, summary)| {
+            json!({
+                "strategy": name,
+                "opportunities_detected": summary.opportunities_detected,
+                "opportunities_executed": summary.opportunities_executed,
+                "trades_opened": summary.trades_opened,
+                "trades_closed": summary.trades_closed,
+                "win_rate_pct": summary.win_rate(),
+                "profit_realized": summary.profit_realized,
+                "loss_realized": summary.loss_realized,
+                "net_profit": summary.net_profit(),
+            })
+        })
+        .collect();
+    json!(payload)
+}
+
 /// Convert daily breakdown rows to JSON.
 pub fn daily_rows_to_json(rows: &[DailyStatsRecord]) -> Value {
     let payload: Vec<_> = rows
@@ -72,6 +97,44 @@ pub fn daily_rows_to_json(rows: &[DailyStatsRecord]) -> Value {
     json!(payload)
 }
 
+/// Convert opportunity summaries to JSON.
+pub fn opportunities_to_json(opportunities: &[OpportunitySummary]) -> Value {
+    let payload: Vec<_> = opportunities
+        .iter()
+        .map(|opp| {
+            json!({
+                "id": opp.id,
+                "uid": opp.uid,
+                "strategy": opp.strategy,
+                "variant": opp.variant,
+                "edge": opp.edge,
+                "expected_profit": opp.expected_profit,
+                "executed": opp.executed,
+                "rejected_reason": opp.rejected_reason,
+                "detected_at": opp.detected_at,
+            })
+        })
+        .collect();
+    json!(payload)
+}
+
+/// Convert a what-if comparison to JSON.
+pub fn whatif_to_json(comparison: &WhatIfComparison) -> Value {
+    json!({
+        "considered": comparison.considered,
+        "current": {
+            "executed": comparison.current_executed,
+            "expected_profit": comparison.current_profit,
+        },
+        "hypothetical": {
+            "executed": comparison.hypothetical_executed,
+            "expected_profit": comparison.hypothetical_profit,
+        },
+        "gained": comparison.gained,
+        "dropped": comparison.dropped,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,6 +228,7 @@ mod tests {
             profit_realized: 150.0,
             win_count: 5,
             loss_count: 1,
+            loss_realized: 20.0,
         }];
 
         let json = strategy_rows_to_json(&rows);
@@ -178,6 +242,8 @@ mod tests {
         assert_eq!(row["trades_opened"], 8);
         assert_eq!(row["trades_closed"], 6);
         assert_eq!(row["profit_realized"], 150.0);
+        assert_eq!(row["loss_realized"], 20.0);
+        assert_eq!(row["net_profit"], 130.0);
         assert_eq!(row["win_count"], 5);
         assert_eq!(row["loss_count"], 1);
 
@@ -220,6 +286,39 @@ mod tests {
         assert!(arr[0]["win_rate_pct"].is_null());
     }
 
+    // Tests for strategy_summaries_to_json
+
+    #[test]
+    fn test_strategy_summaries_to_json_ordered_by_net_profit() {
+        let mut summaries = HashMap::new();
+        summaries.insert(
+            "binary".to_string(),
+            StatsSummary {
+                profit_realized: dec!(10),
+                ..Default::default()
+            },
+        );
+        summaries.insert(
+            "multi".to_string(),
+            StatsSummary {
+                trades_closed: 4,
+                profit_realized: dec!(100),
+                loss_realized: dec!(25),
+                win_count: 3,
+                loss_count: 1,
+                ..Default::default()
+            },
+        );
+
+        let json = strategy_summaries_to_json(&summaries);
+        let arr = json.as_array().unwrap();
+        assert_eq!(arr.len(), 2);
+        assert_eq!(arr[0]["strategy"], "multi");
+        assert_eq!(arr[0]["net_profit"], "75");
+        assert_eq!(arr[0]["win_rate_pct"], 75.0);
+        assert_eq!(arr[1]["strategy"], "binary");
+    }
+
     // Tests for daily_rows_to_json
 
     #[test]
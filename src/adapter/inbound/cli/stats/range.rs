@@ -2,6 +2,8 @@
 
 use chrono::{Duration, NaiveDate, Utc};
 
+use crate::error::{ConfigError, Result};
+
 /// A date range for statistics queries.
 #[derive(Debug, Clone)]
 pub struct DateRange {
@@ -45,6 +47,25 @@ impl DateRange {
             label: format!("Last {days} Days"),
         }
     }
+
+    /// Create a range between two dates, ending today when `to` is omitted.
+    ///
+    /// Fails when `from` falls after the end date.
+    pub fn between(from: NaiveDate, to: Option<NaiveDate>) -> Result<Self> {
+        let end = to.unwrap_or_else(|| Utc::now().date_naive());
+        if from > end {
+            return Err(ConfigError::InvalidValue {
+                field: "from",
+                reason: format!("{from} is after {end}"),
+            }
+            .into());
+        }
+        Ok(Self {
+            start: from,
+            end,
+            label: format!("{from} to {end}"),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -111,6 +132,37 @@ mod tests {
         assert_eq!(range.end, today);
     }
 
+    #[test]
+    fn test_between_range_uses_given_dates() {
+        let from = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let range = DateRange::between(from, Some(to)).unwrap();
+        assert_eq!(range.start, from);
+        assert_eq!(range.end, to);
+        assert_eq!(range.label, "2024-01-01 to 2024-01-31");
+    }
+
+    #[test]
+    fn test_between_range_defaults_end_to_today() {
+        let from = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let range = DateRange::between(from, None).unwrap();
+        assert_eq!(range.end, Utc::now().date_naive());
+    }
+
+    #[test]
+    fn test_between_range_allows_single_day() {
+        let day = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let range = DateRange::between(day, Some(day)).unwrap();
+        assert_eq!(range.start, range.end);
+    }
+
+    #[test]
+    fn test_between_range_rejects_from_after_to() {
+        let from = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        assert!(DateRange::between(from, Some(to)).is_err());
+    }
+
     #[test]
     fn test_date_range_clone() {
         let original = DateRange::today();
@@ -1,10 +1,12 @@
 //! Handler for the `status` command.
 
 use std::path::Path;
+use std::time::Duration;
 
 use crate::adapter::inbound::cli::{operator, output};
+use crate::domain::trading::TradingState;
 use crate::port::inbound::operator::status::{RecentActivity, StatusSnapshot};
-use serde_json::json;
+use serde::Serialize;
 
 /// Execute the status command.
 pub fn execute(db_path: &Path, config_path: Option<&Path>) {
@@ -20,33 +22,38 @@ pub fn execute(db_path: &Path, config_path: Option<&Path>) {
         .unwrap_or_else(|| "unknown".to_string());
 
     if output::is_json() {
-        let payload = if db_path.exists() {
+        let database = db_path.display().to_string();
+        let report = if db_path.exists() {
             let database_url = operator::sqlite_database_url(db_path);
             match service.load_status(&database_url) {
-                Ok(snapshot) => json!({
-                    "command": "status",
-                    "network": network,
-                    "database": db_path.display().to_string(),
-                    "status": "ok",
-                    "snapshot": snapshot_to_json(&snapshot),
-                }),
-                Err(error) => json!({
-                    "command": "status",
-                    "network": network,
-                    "database": db_path.display().to_string(),
-                    "status": "error",
-                    "error": error.to_string(),
-                }),
+                Ok(snapshot) => StatusReport {
+                    command: "status",
+                    network,
+                    database,
+                    status: "ok",
+                    error: None,
+                    snapshot: Some(StatusReportSnapshot::from(&snapshot)),
+                },
+                Err(error) => StatusReport {
+                    command: "status",
+                    network,
+                    database,
+                    status: "error",
+                    error: Some(error.to_string()),
+                    snapshot: None,
+                },
             }
         } else {
-            json!({
-                "command": "status",
-                "network": network,
-                "database": db_path.display().to_string(),
-                "status": "missing_database",
-            })
+            StatusReport {
+                command: "status",
+                network,
+                database,
+                status: "missing_database",
+                error: None,
+                snapshot: None,
+            }
         };
-        output::json_output(payload);
+        output::json_output(serde_json::to_value(report).unwrap_or_default());
         return;
     }
 
@@ -66,46 +73,167 @@ pub fn execute(db_path: &Path, config_path: Option<&Path>) {
     }
 }
 
-fn snapshot_to_json(snapshot: &StatusSnapshot) -> serde_json::Value {
-    let today = snapshot.today.as_ref().map(|row| {
-        json!({
-            "opportunities_detected": row.opportunities_detected,
-            "opportunities_executed": row.opportunities_executed,
-            "opportunities_rejected": row.opportunities_rejected,
-            "profit_realized": row.profit_realized,
-            "loss_realized": row.loss_realized,
-        })
-    });
-
-    let recent_activity: Vec<_> = snapshot
-        .recent_activity
-        .iter()
-        .map(|item| match item {
-            RecentActivity::Executed {
-                timestamp,
-                profit,
-                market_description,
-            } => json!({
-                "type": "executed",
-                "timestamp": timestamp,
-                "profit": profit,
-                "market_description": market_description,
-            }),
-            RecentActivity::Rejected { timestamp, reason } => json!({
-                "type": "rejected",
-                "timestamp": timestamp,
-                "reason": reason,
-            }),
-        })
-        .collect();
-
-    json!({
-        "today": today,
-        "open_positions": snapshot.open_positions,
-        "distinct_markets": snapshot.distinct_markets,
-        "current_exposure": snapshot.current_exposure,
-        "recent_activity": recent_activity,
-    })
+/// JSON envelope emitted by `status` in JSON mode.
+#[derive(Debug, Serialize)]
+struct StatusReport {
+    command: &'static str,
+    network: String,
+    database: String,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snapshot: Option<StatusReportSnapshot>,
+}
+
+/// Machine-readable view of a [`StatusSnapshot`].
+#[derive(Debug, Serialize)]
+struct StatusReportSnapshot {
+    circuit_breaker: CircuitBreakerReport,
+    current_exposure: f32,
+    open_positions: i64,
+    distinct_markets: i64,
+    last_event_at: Option<String>,
+    today: Option<TodayReport>,
+    recent_activity: Vec<ActivityReport>,
+    trading_state: Option<TradingState>,
+    latency: Option<LatencyReport>,
+    top_markets: Vec<MarketScoreReport>,
+}
+
+/// Composite score and factor breakdown for a ranked market.
+#[derive(Debug, Serialize)]
+struct MarketScoreReport {
+    rank: usize,
+    market_id: String,
+    question: String,
+    score: f64,
+    volume: f64,
+    liquidity: f64,
+    spread: f64,
+    time_to_close: f64,
+}
+
+/// Detection latency percentiles in milliseconds.
+#[derive(Debug, Serialize)]
+struct LatencyReport {
+    p50_ms: f64,
+    p95_ms: f64,
+    p99_ms: f64,
+    samples: usize,
+}
+
+/// Circuit breaker state derived from the persisted trading state.
+#[derive(Debug, Serialize)]
+struct CircuitBreakerReport {
+    state: &'static str,
+    tripped: bool,
+    reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct TodayReport {
+    opportunities_detected: i32,
+    opportunities_executed: i32,
+    opportunities_rejected: i32,
+    profit_realized: f32,
+    loss_realized: f32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ActivityReport {
+    Executed {
+        timestamp: String,
+        profit: f32,
+        market_description: String,
+    },
+    Rejected {
+        timestamp: String,
+        reason: String,
+    },
+}
+
+impl From<&StatusSnapshot> for StatusReportSnapshot {
+    fn from(snapshot: &StatusSnapshot) -> Self {
+        let state = snapshot.trading_state.clone().unwrap_or_default();
+        let circuit_breaker = CircuitBreakerReport {
+            state: state.label(),
+            tripped: !matches!(state, TradingState::Active),
+            reason: state.reason().map(str::to_string),
+        };
+
+        let today = snapshot.today.as_ref().map(|row| TodayReport {
+            opportunities_detected: row.opportunities_detected,
+            opportunities_executed: row.opportunities_executed,
+            opportunities_rejected: row.opportunities_rejected,
+            profit_realized: row.profit_realized,
+            loss_realized: row.loss_realized,
+        });
+
+        let recent_activity = snapshot
+            .recent_activity
+            .iter()
+            .map(|item| match item {
+                RecentActivity::Executed {
+                    timestamp,
+                    profit,
+                    market_description,
+                } => ActivityReport::Executed {
+                    timestamp: timestamp.clone(),
+                    profit: *profit,
+                    market_description: market_description.clone(),
+                },
+                RecentActivity::Rejected { timestamp, reason } => ActivityReport::Rejected {
+                    timestamp: timestamp.clone(),
+                    reason: reason.clone(),
+                },
+            })
+            .collect();
+
+        let latency = snapshot.latency.map(|latency| LatencyReport {
+            p50_ms: millis(latency.p50),
+            p95_ms: millis(latency.p95),
+            p99_ms: millis(latency.p99),
+            samples: latency.samples,
+        });
+
+        let top_markets = snapshot
+            .top_markets
+            .iter()
+            .enumerate()
+            .map(|(index, ranked)| {
+                let factors = ranked.score.factors();
+                MarketScoreReport {
+                    rank: index + 1,
+                    market_id: ranked.score.market_id().to_string(),
+                    question: ranked.question.clone(),
+                    score: ranked.score.composite(),
+                    volume: factors.activity,
+                    liquidity: factors.liquidity,
+                    spread: factors.spread,
+                    time_to_close: factors.time_to_close,
+                }
+            })
+            .collect();
+
+        Self {
+            circuit_breaker,
+            current_exposure: snapshot.current_exposure,
+            open_positions: snapshot.open_positions,
+            distinct_markets: snapshot.distinct_markets,
+            last_event_at: snapshot.last_event_at.clone(),
+            today,
+            recent_activity,
+            trading_state: snapshot.trading_state.clone(),
+            latency,
+            top_markets,
+        }
+    }
+}
+
+fn millis(latency: Duration) -> f64 {
+    latency.as_micros() as f64 / 1000.0
 }
 
 fn display_db_stats(snapshot: StatusSnapshot) {
@@ -115,6 +243,14 @@ fn display_db_stats(snapshot: StatusSnapshot) {
     let current_exposure = snapshot.current_exposure;
     let recent_activity = snapshot.recent_activity;
 
+    match snapshot.trading_state {
+        Some(state @ TradingState::Halted { .. }) => {
+            output::field("Trading", output::negative(state.to_string()));
+            output::hint("send /resume via Telegram to resume trading");
+        }
+        _ => output::field("Trading", "active"),
+    }
+
     if current_exposure > 0.0 {
         output::field("Exposure", format!("${:.2}", current_exposure));
     }
@@ -131,6 +267,19 @@ fn display_db_stats(snapshot: StatusSnapshot) {
         output::field("Positions", output::muted("none"));
     }
 
+    if let Some(latency) = snapshot.latency.filter(|latency| latency.samples > 0) {
+        output::field(
+            "Latency",
+            format!(
+                "p50 {:.1}ms  p95 {:.1}ms  p99 {:.1}ms  ({} events)",
+                millis(latency.p50),
+                millis(latency.p95),
+                millis(latency.p99),
+                latency.samples
+            ),
+        );
+    }
+
     output::section("Today");
     if let Some(row) = today_row {
         output::field("Opportunities", row.opportunities_detected);
@@ -154,6 +303,32 @@ fn display_db_stats(snapshot: StatusSnapshot) {
         output::note("No data for today");
     }
 
+    if !snapshot.top_markets.is_empty() {
+        output::section("Top markets");
+        output::table_header(&[
+            ("Market", 40),
+            ("Score", 7),
+            ("Volume", 7),
+            ("Liq", 7),
+            ("Spread", 7),
+            ("Close", 7),
+        ]);
+        for ranked in &snapshot.top_markets {
+            let factors = ranked.score.factors();
+            output::table_row(
+                &[
+                    output::truncate(&ranked.question, 40),
+                    format!("{:.2}", ranked.score.composite()),
+                    format!("{:.2}", factors.activity),
+                    format!("{:.2}", factors.liquidity),
+                    format!("{:.2}", factors.spread),
+                    format!("{:.2}", factors.time_to_close),
+                ],
+                &[40, 7, 7, 7, 7, 7],
+            );
+        }
+    }
+
     if !recent_activity.is_empty() {
         output::section("Recent activity");
         for activity in recent_activity {
@@ -180,3 +355,73 @@ fn display_db_stats(snapshot: StatusSnapshot) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::id::MarketId;
+    use crate::domain::score::{MarketScore, RankedMarket, ScoreFactors};
+    use crate::domain::stats::LatencyPercentiles;
+
+    fn snapshot(trading_state: Option<TradingState>) -> StatusSnapshot {
+        StatusSnapshot {
+            today: None,
+            open_positions: 2,
+            distinct_markets: 1,
+            current_exposure: 150.0,
+            recent_activity: vec![RecentActivity::Rejected {
+                timestamp: "12:00:00".to_string(),
+                reason: "risk_limit".to_string(),
+            }],
+            trading_state,
+            last_event_at: Some("2024-01-01T12:00:00+00:00".to_string()),
+            latency: Some(LatencyPercentiles {
+                p50: Duration::from_micros(1500),
+                p95: Duration::from_millis(4),
+                p99: Duration::from_millis(9),
+                samples: 250,
+            }),
+            top_markets: vec![RankedMarket {
+                question: "Will it rain?".to_string(),
+                score: MarketScore::new(
+                    MarketId::from("rain"),
+                    ScoreFactors::new(0.8, 0.9, 0.0, 0.0, 0.7).with_time_to_close(0.5),
+                    0.75,
+                ),
+            }],
+        }
+    }
+
+    #[test]
+    fn snapshot_report_serializes_core_fields() {
+        let value = serde_json::to_value(StatusReportSnapshot::from(&snapshot(None))).unwrap();
+
+        assert_eq!(value["circuit_breaker"]["state"], "active");
+        assert_eq!(value["circuit_breaker"]["tripped"], false);
+        assert_eq!(value["current_exposure"], 150.0);
+        assert_eq!(value["open_positions"], 2);
+        assert_eq!(value["last_event_at"], "2024-01-01T12:00:00+00:00");
+        assert_eq!(value["recent_activity"][0]["type"], "rejected");
+        assert_eq!(value["latency"]["p50_ms"], 1.5);
+        assert_eq!(value["latency"]["p99_ms"], 9.0);
+        assert_eq!(value["latency"]["samples"], 250);
+        assert_eq!(value["top_markets"][0]["rank"], 1);
+        assert_eq!(value["top_markets"][0]["market_id"], "rain");
+        assert_eq!(value["top_markets"][0]["score"], 0.75);
+        assert_eq!(value["top_markets"][0]["volume"], 0.7);
+        assert_eq!(value["top_markets"][0]["time_to_close"], 0.5);
+    }
+
+    #[test]
+    fn snapshot_report_marks_halted_breaker_as_tripped() {
+        let halted = TradingState::Halted {
+            reason: "manual".to_string(),
+        };
+        let value =
+            serde_json::to_value(StatusReportSnapshot::from(&snapshot(Some(halted)))).unwrap();
+
+        assert_eq!(value["circuit_breaker"]["state"], "halted");
+        assert_eq!(value["circuit_breaker"]["tripped"], true);
+        assert_eq!(value["circuit_breaker"]["reason"], "manual");
+    }
+}
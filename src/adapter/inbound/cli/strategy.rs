@@ -1,10 +1,12 @@
 //! Strategy listing and explanation.
 
-use serde_json::json;
+use rust_decimal::Decimal;
+use serde_json::{json, Value};
 use tabled::{Table, Tabled};
 
-use crate::adapter::inbound::cli::output;
+use crate::adapter::inbound::cli::{operator, output};
 use crate::error::Result;
+use crate::port::inbound::operator::config::ConfigStrategyDefaults;
 
 const STRATEGY_SINGLE_CONDITION: &str = "single_condition";
 const STRATEGY_MARKET_REBALANCING: &str = "market_rebalancing";
@@ -87,34 +89,21 @@ pub fn list() -> Result<()> {
 }
 
 /// Explain a specific strategy.
+///
+/// Prints when the strategy applies, a worked example priced against its
+/// default thresholds, and the defaults themselves.
 pub fn explain(name: &str) -> Result<()> {
     if output::is_quiet() && !output::is_json() {
         return Ok(());
     }
 
     let normalized_name = normalize(name);
+    let guide = guide(&normalized_name, &operator::operator().strategy_defaults());
 
     if output::is_json() {
-        let payload = match normalized_name.as_str() {
-            STRATEGY_SINGLE_CONDITION => json!({
-                "command": "strategies.explain",
-                "strategy": STRATEGY_SINGLE_CONDITION,
-                "summary": "Detects arbitrage in binary (YES/NO) markets when YES + NO < $1.00 payout",
-                "config_path": "strategies.single_condition",
-            }),
-            STRATEGY_MARKET_REBALANCING => json!({
-                "command": "strategies.explain",
-                "strategy": STRATEGY_MARKET_REBALANCING,
-                "summary": "Detects arbitrage in multi-outcome markets when total outcome price < $1.00 payout",
-                "config_path": "strategies.market_rebalancing",
-            }),
-            STRATEGY_COMBINATORIAL => json!({
-                "command": "strategies.explain",
-                "strategy": STRATEGY_COMBINATORIAL,
-                "summary": "Detects cross-market opportunities using inferred constraints and optimization",
-                "config_path": "strategies.combinatorial",
-            }),
-            _ => json!({
+        let payload = match guide {
+            Some(guide) => guide.to_json(),
+            None => json!({
                 "command": "strategies.explain",
                 "status": "unknown_strategy",
                 "requested": name,
@@ -132,75 +121,320 @@ pub fn explain(name: &str) -> Result<()> {
 
     output::header(env!("CARGO_PKG_VERSION"));
 
-    match normalized_name.as_str() {
-        STRATEGY_SINGLE_CONDITION => explain_single_condition(),
-        STRATEGY_MARKET_REBALANCING => explain_market_rebalancing(),
-        STRATEGY_COMBINATORIAL => explain_combinatorial(),
-        _ => {
-            output::error(&format!("Unknown strategy: {}", name));
-            output::hint(&format!(
-                "available strategies: {STRATEGY_SINGLE_CONDITION}, {STRATEGY_MARKET_REBALANCING}, {STRATEGY_COMBINATORIAL}"
-            ));
-            return Ok(());
+    let Some(guide) = guide else {
+        output::error(&format!("Unknown strategy: {}", name));
+        output::hint(&format!(
+            "available strategies: {STRATEGY_SINGLE_CONDITION}, {STRATEGY_MARKET_REBALANCING}, {STRATEGY_COMBINATORIAL}"
+        ));
+        return Ok(());
+    };
+
+    output::section(guide.name);
+    output::lines(&guide.render());
+
+    Ok(())
+}
+
+/// Everything `strategies explain` shows for one strategy.
+struct StrategyGuide {
+    name: &'static str,
+    summary: &'static str,
+    /// When the strategy's `applies_to` accepts a market.
+    applies_to: String,
+    example: WorkedExample,
+    /// Extra explanation printed after the example.
+    notes: &'static str,
+    enabled_by_default: bool,
+    /// Default settings as `(key, value)` pairs.
+    defaults: Vec<(&'static str, String)>,
+}
+
+impl StrategyGuide {
+    fn config_path(&self) -> String {
+        format!("strategies.{}", self.name)
+    }
+
+    fn render(&self) -> String {
+        let mut text = format!(
+            "{}\n\nApplies to:\n  {}\n\nWorked example:\n{}",
+            self.summary,
+            self.applies_to,
+            self.example.render()
+        );
+        if !self.notes.is_empty() {
+            text.push_str(&format!("\n\n{}", self.notes));
+        }
+        text.push_str(&format!(
+            "\n\nDefaults (enabled by default: {}):\n  [{}]",
+            if self.enabled_by_default { "yes" } else { "no" },
+            self.config_path()
+        ));
+        for (key, value) in &self.defaults {
+            text.push_str(&format!("\n  {key} = {value}"));
         }
+        text
     }
 
-    Ok(())
+    fn to_json(&self) -> Value {
+        let defaults: serde_json::Map<String, Value> = self
+            .defaults
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), Value::String(value.clone())))
+            .collect();
+        json!({
+            "command": "strategies.explain",
+            "strategy": self.name,
+            "summary": self.summary,
+            "config_path": self.config_path(),
+            "applies_to": self.applies_to,
+            "enabled_by_default": self.enabled_by_default,
+            "defaults": defaults,
+            "example": self.example.to_json(),
+        })
+    }
+}
+
+/// A priced trade showing how a strategy computes its edge and profit.
+struct WorkedExample {
+    /// Market situation the prices come from.
+    setup: &'static str,
+    /// Outcomes bought and their ask prices.
+    legs: Vec<(&'static str, Decimal)>,
+    /// Guaranteed payout per share of the combined position.
+    payout: Decimal,
+    /// Shares available at the quoted prices.
+    shares: Decimal,
+    /// Minimum edge setting and its default value.
+    min_edge: (&'static str, Decimal),
+    /// Minimum profit setting and its default value, if the strategy has one.
+    min_profit: Option<(&'static str, Decimal)>,
+}
+
+impl WorkedExample {
+    fn cost(&self) -> Decimal {
+        self.legs.iter().map(|(_, price)| *price).sum()
+    }
+
+    fn edge(&self) -> Decimal {
+        self.payout - self.cost()
+    }
+
+    fn profit(&self) -> Decimal {
+        self.edge() * self.shares
+    }
+
+    fn render(&self) -> String {
+        let legs: Vec<String> = self
+            .legs
+            .iter()
+            .map(|(label, price)| format!("{label} @ {}", usd(*price)))
+            .collect();
+        let mut lines = vec![
+            format!("  {}", self.setup),
+            format!(
+                "  {} = {} cost per share",
+                legs.join(" + "),
+                usd(self.cost())
+            ),
+            format!(
+                "  Payout = {}, so edge = {} - {} = {} per share ({}%)",
+                usd(self.payout),
+                usd(self.payout),
+                usd(self.cost()),
+                usd(self.edge()),
+                (self.edge() / self.payout * Decimal::ONE_HUNDRED).round_dp(1)
+            ),
+            format!(
+                "  Expected profit = {} x {} shares = {}",
+                usd(self.edge()),
+                self.shares.normalize(),
+                usd(self.profit())
+            ),
+        ];
+
+        let (key, min_edge) = self.min_edge;
+        lines.push(threshold_line("edge", self.edge(), key, min_edge));
+        if let Some((key, min_profit)) = self.min_profit {
+            lines.push(threshold_line("profit", self.profit(), key, min_profit));
+        }
+        lines.join("\n")
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "setup": self.setup,
+            "legs": self
+                .legs
+                .iter()
+                .map(|(label, price)| json!({ "outcome": label, "price": price.to_string() }))
+                .collect::<Vec<_>>(),
+            "cost": self.cost().to_string(),
+            "payout": self.payout.to_string(),
+            "edge": self.edge().to_string(),
+            "shares": self.shares.normalize().to_string(),
+            "expected_profit": self.profit().to_string(),
+        })
+    }
 }
 
-fn explain_single_condition() {
-    output::section(STRATEGY_SINGLE_CONDITION);
-    output::lines(
-        "Detects arbitrage in binary (YES/NO) markets where:
-YES price + NO price < $1.00 payout
-
-Example:
-  YES @ $0.45 + NO @ $0.52 = $0.97
-  Payout = $1.00
-  Edge = $0.03 (3%)
-
-Configuration:
-  [strategies.single_condition]
-  min_edge = 0.05    # 5% minimum edge
-  min_profit = 0.50  # $0.50 minimum profit",
-    );
+/// Describe whether `value` clears the `key` threshold.
+fn threshold_line(label: &str, value: Decimal, key: &str, threshold: Decimal) -> String {
+    if value >= threshold {
+        format!(
+            "  {label} {} >= {key} {}: passes",
+            usd(value),
+            usd(threshold)
+        )
+    } else {
+        format!(
+            "  {label} {} < {key} {}: skipped",
+            usd(value),
+            usd(threshold)
+        )
+    }
 }
 
-fn explain_market_rebalancing() {
-    output::section(STRATEGY_MARKET_REBALANCING);
-    output::lines(
-        "Detects arbitrage in multi-outcome markets where:
-sum(all outcome prices) < $1.00 payout
-
-Example (3-outcome market):
-  Option A @ $0.30 + Option B @ $0.35 + Option C @ $0.32 = $0.97
-  Payout = $1.00
-  Edge = $0.03 (3%)
-
-Configuration:
-  [strategies.market_rebalancing]
-  min_edge = 0.03",
-    );
+fn usd(value: Decimal) -> String {
+    format!("${:.2}", value)
 }
 
-fn explain_combinatorial() {
-    output::section(STRATEGY_COMBINATORIAL);
-    output::lines(
-        "Detects arbitrage across related markets using:
-- LLM inference to identify market relationships
-- LP/ILP optimization to find profitable combinations
-
-Example:
-  Market A: \"Will X happen in 2024?\"
-  Market B: \"Will X happen in Q4 2024?\"
-  Constraint: B implies A
-
-Requires:
-  [inference]
-  provider = \"anthropic\"  # or \"openai\"
-
-Configuration:
-  [strategies.combinatorial]
-  min_edge = 0.02",
-    );
+/// Build the explanation for `name`, priced against `defaults`.
+fn guide(name: &str, defaults: &ConfigStrategyDefaults) -> Option<StrategyGuide> {
+    let enabled_by_default = |strategy: &str| defaults.enabled.iter().any(|s| s == strategy);
+
+    let guide = match name {
+        STRATEGY_SINGLE_CONDITION => StrategyGuide {
+            name: STRATEGY_SINGLE_CONDITION,
+            summary: "Detects arbitrage in binary (YES/NO) markets when YES + NO < $1.00 payout",
+            applies_to: "Binary markets (exactly two outcomes).".to_string(),
+            example: WorkedExample {
+                setup: "Binary market with both sides offering 100 shares:",
+                legs: vec![("YES", Decimal::new(45, 2)), ("NO", Decimal::new(50, 2))],
+                payout: Decimal::ONE,
+                shares: Decimal::ONE_HUNDRED,
+                min_edge: ("min_edge", defaults.single_condition_min_edge),
+                min_profit: Some(("min_profit", defaults.single_condition_min_profit)),
+            },
+            notes: "Markets with a non-standard payout use it in place of $1.00:\n\
+                    YES + NO < payout - min_edge",
+            enabled_by_default: enabled_by_default(STRATEGY_SINGLE_CONDITION),
+            defaults: vec![
+                ("min_edge", defaults.single_condition_min_edge.to_string()),
+                ("min_profit", defaults.single_condition_min_profit.to_string()),
+            ],
+        },
+        STRATEGY_MARKET_REBALANCING => StrategyGuide {
+            name: STRATEGY_MARKET_REBALANCING,
+            summary: "Detects arbitrage in multi-outcome markets when total outcome price < $1.00 payout",
+            applies_to: format!(
+                "Markets with 3 to {} outcomes. Binary markets are left to {STRATEGY_SINGLE_CONDITION}.",
+                defaults.market_rebalancing_max_outcomes
+            ),
+            example: WorkedExample {
+                setup: "3-outcome market with every outcome offering 50 shares:",
+                legs: vec![
+                    ("Option A", Decimal::new(30, 2)),
+                    ("Option B", Decimal::new(30, 2)),
+                    ("Option C", Decimal::new(30, 2)),
+                ],
+                payout: Decimal::ONE,
+                shares: Decimal::new(50, 0),
+                min_edge: ("min_edge", defaults.market_rebalancing_min_edge),
+                min_profit: Some(("min_profit", defaults.market_rebalancing_min_profit)),
+            },
+            notes: "",
+            enabled_by_default: enabled_by_default(STRATEGY_MARKET_REBALANCING),
+            defaults: vec![
+                ("min_edge", defaults.market_rebalancing_min_edge.to_string()),
+                ("min_profit", defaults.market_rebalancing_min_profit.to_string()),
+                (
+                    "max_outcomes",
+                    defaults.market_rebalancing_max_outcomes.to_string(),
+                ),
+            ],
+        },
+        STRATEGY_COMBINATORIAL => StrategyGuide {
+            name: STRATEGY_COMBINATORIAL,
+            summary: "Detects cross-market opportunities using inferred constraints and optimization",
+            applies_to: "Markets with known relations to other markets, from LLM inference or \
+                         the cluster cache, while the strategy is enabled."
+                .to_string(),
+            example: WorkedExample {
+                setup: "Market B \"X in Q4 2024?\" implies market A \"X in 2024?\", \
+                        so A YES or B NO always pays out:",
+                legs: vec![("A YES", Decimal::new(35, 2)), ("B NO", Decimal::new(60, 2))],
+                payout: Decimal::ONE,
+                shares: Decimal::ONE_HUNDRED,
+                min_edge: ("gap_threshold", defaults.combinatorial_gap_threshold),
+                min_profit: None,
+            },
+            notes: "Relations come from LLM inference (LP/ILP optimization finds the trade), \
+                    so this needs:\n  [inference]\n  enabled = true\n\
+                    The live detector measures the gap with Frank-Wolfe projection over every \
+                    related market; the two-market case above is the simplest instance.",
+            enabled_by_default: defaults.combinatorial_enabled,
+            defaults: vec![
+                ("enabled", defaults.combinatorial_enabled.to_string()),
+                (
+                    "gap_threshold",
+                    defaults.combinatorial_gap_threshold.to_string(),
+                ),
+            ],
+        },
+        _ => return None,
+    };
+    Some(guide)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn defaults() -> ConfigStrategyDefaults {
+        ConfigStrategyDefaults {
+            enabled: vec![STRATEGY_SINGLE_CONDITION.to_string()],
+            single_condition_min_edge: dec!(0.03),
+            single_condition_min_profit: dec!(0.10),
+            market_rebalancing_min_edge: dec!(0.03),
+            market_rebalancing_min_profit: dec!(0.25),
+            market_rebalancing_max_outcomes: 10,
+            combinatorial_gap_threshold: dec!(0.02),
+            combinatorial_enabled: false,
+        }
+    }
+
+    #[test]
+    fn rebalancing_example_works_through_the_profit_math() {
+        let guide = guide(STRATEGY_MARKET_REBALANCING, &defaults()).unwrap();
+
+        assert_eq!(guide.example.cost(), dec!(0.90));
+        assert_eq!(guide.example.edge(), dec!(0.10));
+        assert_eq!(guide.example.profit(), dec!(5.00));
+        let text = guide.render();
+        assert!(text.contains("Option A @ $0.30 + Option B @ $0.30 + Option C @ $0.30 = $0.90"));
+        assert!(text.contains("edge $0.10 >= min_edge $0.03: passes"));
+        assert!(text.contains("Markets with 3 to 10 outcomes"));
+    }
+
+    #[test]
+    fn example_reflects_the_default_thresholds() {
+        let defaults = ConfigStrategyDefaults {
+            single_condition_min_edge: dec!(0.08),
+            ..defaults()
+        };
+
+        let text = guide(STRATEGY_SINGLE_CONDITION, &defaults)
+            .unwrap()
+            .render();
+
+        assert!(text.contains("edge $0.05 < min_edge $0.08: skipped"));
+        assert!(text.contains("min_edge = 0.08"));
+        assert!(text.contains("enabled by default: yes"));
+    }
+
+    #[test]
+    fn unknown_strategy_has_no_guide() {
+        assert!(guide("binary", &defaults()).is_none());
+    }
 }
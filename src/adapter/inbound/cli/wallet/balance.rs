@@ -0,0 +1,59 @@
+use std::path::Path;
+
+use serde_json::json;
+
+use crate::adapter::inbound::cli::{operator, output};
+use crate::error::Result;
+
+/// Display USDC and native gas balances for the configured wallet.
+///
+/// Warns when the gas balance is too low to cover on-chain transactions.
+pub async fn execute_balance(config_path: &Path) -> Result<()> {
+    let config_toml = operator::read_config_toml(config_path)?;
+    let service = operator::operator();
+
+    if output::is_json() {
+        let balances = service.wallet_balances(&config_toml).await?;
+        output::json_output(json!({
+            "command": "wallet.balance",
+            "exchange": balances.exchange,
+            "wallet": balances.wallet_address,
+            "usdc": balances.usdc,
+            "gas_token": balances.gas_token,
+            "gas": balances.gas,
+            "min_gas": balances.min_gas,
+            "low_gas": balances.low_gas(),
+        }));
+        return Ok(());
+    }
+
+    output::section("Wallet Balance");
+    let pb = output::spinner("Fetching balances...");
+    let balances = match service.wallet_balances(&config_toml).await {
+        Ok(balances) => {
+            output::spinner_success(&pb, "Fetched balances");
+            balances
+        }
+        Err(e) => {
+            output::spinner_fail(&pb, "Failed to fetch balances");
+            return Err(e);
+        }
+    };
+    output::field("Exchange", &balances.exchange);
+    output::field("Wallet", &balances.wallet_address);
+    output::field("USDC", format!("${}", balances.usdc));
+    output::field("Gas", format!("{} {}", balances.gas, balances.gas_token));
+
+    if balances.low_gas() {
+        output::warning(&format!(
+            "Gas balance is below {} {}",
+            balances.min_gas, balances.gas_token
+        ));
+        output::hint(&format!(
+            "send {} to the wallet to cover approval and transfer fees",
+            balances.gas_token
+        ));
+    }
+
+    Ok(())
+}
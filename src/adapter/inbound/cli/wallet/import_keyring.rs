@@ -0,0 +1,30 @@
+use std::path::Path;
+
+use dialoguer::{theme::ColorfulTheme, Password};
+
+use crate::adapter::inbound::cli::{operator, output};
+use crate::error::Result;
+
+/// Store the wallet private key in the OS keyring.
+///
+/// The key is taken from `WALLET_PRIVATE_KEY` when set, otherwise it is
+/// prompted for without echo.
+pub fn execute_import_keyring(config_path: &Path) -> Result<()> {
+    let config_toml = operator::read_config_toml(config_path)?;
+    let private_key = match std::env::var("WALLET_PRIVATE_KEY") {
+        Ok(key) if !key.trim().is_empty() => key,
+        _ => Password::with_theme(&ColorfulTheme::default())
+            .with_prompt("Private key")
+            .interact()?,
+    };
+
+    let import = operator::operator().wallet_import_keyring(&config_toml, &private_key)?;
+
+    output::section("Keyring Import");
+    output::field("Service", import.service);
+    output::field("Account", import.account);
+    output::field("Address", import.wallet_address);
+    output::success("Private key stored in the OS keyring");
+    output::note("Set key_source = \"keyring\" under [wallet] to use it");
+    Ok(())
+}
@@ -4,5 +4,7 @@
 
 pub mod address;
 pub mod approve;
+pub mod balance;
+pub mod import_keyring;
 pub mod status;
 pub mod sweep;
@@ -11,8 +11,9 @@ use tracing::{debug, warn};
 use crate::domain::{id::MarketId, relation::Relation, relation::RelationKind};
 use crate::error::Result;
 use crate::port::outbound::inference::MarketSummary;
-use crate::port::outbound::inference::RelationInferrer;
+use crate::port::outbound::inference::{InferenceOutput, RelationInferrer};
 use crate::port::outbound::llm::Llm;
+use crate::port::outbound::llm::TokenUsage;
 
 /// LLM-powered relation inferrer.
 pub struct LlmInferrer {
@@ -134,15 +135,31 @@ impl RelationInferrer for LlmInferrer {
     }
 
     async fn infer(&self, markets: &[MarketSummary]) -> Result<Vec<Relation>> {
+        Ok(self.infer_with_usage(markets).await?.relations)
+    }
+
+    /// Report the provider's token usage, or an estimate from the prompt
+    /// and response length when the provider reports none.
+    async fn infer_with_usage(&self, markets: &[MarketSummary]) -> Result<InferenceOutput> {
         if markets.len() < 2 {
-            return Ok(vec![]);
+            return Ok(InferenceOutput::default());
         }
 
         let prompt = self.build_prompt(markets);
-        let response = self.llm.complete(&prompt).await?;
-        debug!(provider = self.llm.name(), "LLM inference complete");
-
-        self.parse_response(&response, markets)
+        let completion = self.llm.complete_with_usage(&prompt).await?;
+        let usage = completion
+            .usage
+            .unwrap_or_else(|| TokenUsage::estimate(&prompt, &completion.text));
+        debug!(
+            provider = self.llm.name(),
+            tokens = usage.total(),
+            "LLM inference complete"
+        );
+
+        Ok(InferenceOutput {
+            relations: self.parse_response(&completion.text, markets)?,
+            usage: Some(usage),
+        })
     }
 
     fn batch_limit(&self) -> usize {
@@ -159,9 +176,9 @@ struct LlmResponse {
 struct RawRelation {
     #[serde(rename = "type")]
     kind: String,
-    #[serde(default)]
+    #[serde(default, alias = "antecedent")]
     if_yes: Option<String>,
-    #[serde(default)]
+    #[serde(default, alias = "consequent")]
     then_yes: Option<String>,
     #[serde(default)]
     markets: Option<Vec<String>>,
@@ -171,30 +188,28 @@ struct RawRelation {
 
 impl RawRelation {
     /// Map short IDs (M1, M2) to real market IDs and build RelationKind.
+    ///
+    /// Relation types and short IDs are matched regardless of case and
+    /// surrounding whitespace, so "Mutually Exclusive" and " m1" still map.
     fn into_kind_mapped(self, id_map: &HashMap<String, &MarketId>) -> Option<Option<RelationKind>> {
-        match self.kind.as_str() {
-            "implies" => {
-                let if_yes = id_map.get(self.if_yes.as_ref()?)?;
-                let then_yes = id_map.get(self.then_yes.as_ref()?)?;
+        let lookup = |short_id: &str| id_map.get(&short_id.trim().to_uppercase()).copied();
+        match normalize_kind(&self.kind).as_str() {
+            "implies" | "implication" => {
+                let if_yes = lookup(self.if_yes.as_deref()?)?;
+                let then_yes = lookup(self.then_yes.as_deref()?)?;
                 Some(Some(RelationKind::Implies {
                     if_yes: (*if_yes).clone(),
                     then_yes: (*then_yes).clone(),
                 }))
             }
             "mutually_exclusive" => {
-                let markets: Option<Vec<MarketId>> = self
-                    .markets?
-                    .iter()
-                    .map(|m| id_map.get(m).map(|id| (*id).clone()))
-                    .collect();
+                let markets: Option<Vec<MarketId>> =
+                    self.markets?.iter().map(|m| lookup(m).cloned()).collect();
                 Some(markets.map(|ms| RelationKind::MutuallyExclusive { markets: ms }))
             }
             "exactly_one" => {
-                let markets: Option<Vec<MarketId>> = self
-                    .markets?
-                    .iter()
-                    .map(|m| id_map.get(m).map(|id| (*id).clone()))
-                    .collect();
+                let markets: Option<Vec<MarketId>> =
+                    self.markets?.iter().map(|m| lookup(m).cloned()).collect();
                 Some(markets.map(|ms| RelationKind::ExactlyOne { markets: ms }))
             }
             _ => Some(None),
@@ -202,6 +217,16 @@ impl RawRelation {
     }
 }
 
+/// Fold a relation type name to lowercase snake case.
+fn normalize_kind(kind: &str) -> String {
+    kind.trim()
+        .to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
 fn extract_json(text: &str) -> Result<&str> {
     // Find JSON in markdown code block or raw
     if let Some(start) = text.find("```json") {
@@ -356,4 +381,118 @@ mod tests {
             _ => panic!("Expected Implies"),
         }
     }
+
+    #[tokio::test]
+    async fn parses_implication_alias() {
+        let response = r#"{"relations": [
+            {
+                "type": "implication",
+                "antecedent": "M1",
+                "consequent": "M2",
+                "confidence": 0.9,
+                "reasoning": "If PA then swing state"
+            }
+        ]}"#;
+
+        let llm = Arc::new(MockLlm::new(response));
+        let inferrer = LlmInferrer::new(llm, Duration::hours(1));
+
+        let markets = vec![
+            MarketSummary {
+                id: MarketId::new("trump-pa"),
+                question: "Trump wins PA?".into(),
+                outcomes: vec!["Yes".into(), "No".into()],
+            },
+            MarketSummary {
+                id: MarketId::new("trump-swing"),
+                question: "Trump wins swing state?".into(),
+                outcomes: vec!["Yes".into(), "No".into()],
+            },
+        ];
+
+        let relations = inferrer.infer(&markets).await.unwrap();
+        assert_eq!(relations.len(), 1);
+        assert_eq!(
+            relations[0].kind,
+            RelationKind::Implies {
+                if_yes: MarketId::new("trump-pa"),
+                then_yes: MarketId::new("trump-swing"),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn tolerates_case_and_spacing_in_types_and_short_ids() {
+        let response = r#"{"relations": [
+            {
+                "type": "Mutually Exclusive",
+                "markets": ["m1", " M2 "],
+                "confidence": 0.95,
+                "reasoning": "Same election"
+            }
+        ]}"#;
+
+        let llm = Arc::new(MockLlm::new(response));
+        let inferrer = LlmInferrer::new(llm, Duration::hours(1));
+
+        let markets = vec![
+            MarketSummary {
+                id: MarketId::new("a"),
+                question: "Will A win?".into(),
+                outcomes: vec!["yes".into(), "no".into()],
+            },
+            MarketSummary {
+                id: MarketId::new("b"),
+                question: "Will B win?".into(),
+                outcomes: vec!["yes".into(), "no".into()],
+            },
+        ];
+
+        let relations = inferrer.infer(&markets).await.unwrap();
+        assert_eq!(
+            relations[0].kind,
+            RelationKind::MutuallyExclusive {
+                markets: vec![MarketId::new("a"), MarketId::new("b")],
+            }
+        );
+    }
+
+    #[test]
+    fn normalize_kind_folds_separators_and_case() {
+        assert_eq!(normalize_kind("mutually_exclusive"), "mutually_exclusive");
+        assert_eq!(normalize_kind(" Exactly-One "), "exactly_one");
+        assert_eq!(normalize_kind("IMPLIES"), "implies");
+    }
+
+    #[tokio::test]
+    async fn infer_with_usage_estimates_when_provider_reports_none() {
+        let llm = Arc::new(MockLlm::new(r#"{"relations": []}"#));
+        let inferrer = LlmInferrer::new(llm, Duration::hours(1));
+
+        let markets = vec![
+            MarketSummary::new(MarketId::new("a"), "Will A win?", vec![]),
+            MarketSummary::new(MarketId::new("b"), "Will B win?", vec![]),
+        ];
+
+        let output = inferrer.infer_with_usage(&markets).await.unwrap();
+        let usage = output.usage.expect("usage should be estimated");
+        assert!(usage.input_tokens > 0);
+        assert_eq!(usage.output_tokens, 5);
+    }
+
+    #[tokio::test]
+    async fn infer_with_usage_skips_single_market() {
+        let llm = Arc::new(MockLlm::new(r#"{"relations": []}"#));
+        let inferrer = LlmInferrer::new(llm, Duration::hours(1));
+
+        let markets = vec![MarketSummary::new(
+            MarketId::new("a"),
+            "Will A win?",
+            vec![],
+        )];
+
+        let output = inferrer.infer_with_usage(&markets).await.unwrap();
+        assert!(output.relations.is_empty());
+        assert!(output.usage.is_none());
+    }
 }
@@ -0,0 +1,146 @@
+//! Local Kalshi order book state.
+//!
+//! Kalshi only publishes bids, for both the yes and the no side of a market.
+//! Buying yes at `p` cents is equivalent to selling no at `100 - p`, so the
+//! yes asks are the no bids reflected around the payout, and vice versa.
+//! [`KalshiBook`] keeps both bid ladders and derives the two domain books.
+
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+
+use super::dto::message::{KalshiDeltaMessage, KalshiSide, KalshiSnapshotMessage};
+use super::market::{cents_to_price, outcome_token, KALSHI_PAYOUT_CENTS};
+use crate::domain::book::{Book, PriceLevel};
+
+/// Resting bid quantities by price in cents, for both sides of one market.
+#[derive(Debug, Clone, Default)]
+pub struct KalshiBook {
+    yes: BTreeMap<i64, i64>,
+    no: BTreeMap<i64, i64>,
+}
+
+impl KalshiBook {
+    /// Build book state from a full snapshot.
+    #[must_use]
+    pub fn from_snapshot(snapshot: &KalshiSnapshotMessage) -> Self {
+        let ladder = |levels: &[(i64, i64)]| {
+            levels
+                .iter()
+                .copied()
+                .filter(|&(_, quantity)| quantity > 0)
+                .collect()
+        };
+        Self {
+            yes: ladder(&snapshot.yes),
+            no: ladder(&snapshot.no),
+        }
+    }
+
+    /// Apply an incremental change to one price level.
+    ///
+    /// Levels whose quantity drops to zero or below are removed.
+    pub fn apply_delta(&mut self, delta: &KalshiDeltaMessage) {
+        let ladder = match delta.side {
+            KalshiSide::Yes => &mut self.yes,
+            KalshiSide::No => &mut self.no,
+        };
+        let quantity = ladder.entry(delta.price).or_insert(0);
+        *quantity += delta.delta;
+        if *quantity <= 0 {
+            ladder.remove(&delta.price);
+        }
+    }
+
+    /// Derive the yes and no domain books, in that order, with dollar prices.
+    #[must_use]
+    pub fn to_books(&self, ticker: &str) -> [Book; 2] {
+        [
+            Book::with_levels(
+                outcome_token(ticker, KalshiSide::Yes),
+                bids(&self.yes),
+                asks(&self.no),
+            ),
+            Book::with_levels(
+                outcome_token(ticker, KalshiSide::No),
+                bids(&self.no),
+                asks(&self.yes),
+            ),
+        ]
+    }
+}
+
+/// Bids for a side, best (highest) first.
+fn bids(ladder: &BTreeMap<i64, i64>) -> Vec<PriceLevel> {
+    ladder
+        .iter()
+        .rev()
+        .map(|(&price, &quantity)| PriceLevel::new(cents_to_price(price), Decimal::from(quantity)))
+        .collect()
+}
+
+/// Asks for a side implied by the opposite side's bids, best (lowest) first.
+fn asks(opposite: &BTreeMap<i64, i64>) -> Vec<PriceLevel> {
+    opposite
+        .iter()
+        .rev()
+        .map(|(&price, &quantity)| {
+            PriceLevel::new(
+                cents_to_price(KALSHI_PAYOUT_CENTS - price),
+                Decimal::from(quantity),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn snapshot() -> KalshiSnapshotMessage {
+        KalshiSnapshotMessage {
+            market_ticker: "M".into(),
+            yes: vec![(8, 300), (22, 333)],
+            no: vec![(54, 20), (56, 146)],
+        }
+    }
+
+    #[test]
+    fn snapshot_derives_asks_from_opposite_bids() {
+        let [yes, no] = KalshiBook::from_snapshot(&snapshot()).to_books("M");
+
+        assert_eq!(yes.best_bid().unwrap().price(), dec!(0.22));
+        assert_eq!(yes.best_ask().unwrap().price(), dec!(0.44));
+        assert_eq!(yes.best_ask().unwrap().size(), dec!(146));
+        assert_eq!(yes.asks()[1].price(), dec!(0.46));
+
+        assert_eq!(no.best_bid().unwrap().price(), dec!(0.56));
+        assert_eq!(no.best_ask().unwrap().price(), dec!(0.78));
+        assert_eq!(no.best_ask().unwrap().size(), dec!(333));
+    }
+
+    #[test]
+    fn delta_updates_and_removes_levels() {
+        let mut book = KalshiBook::from_snapshot(&snapshot());
+
+        book.apply_delta(&KalshiDeltaMessage {
+            market_ticker: "M".into(),
+            price: 56,
+            delta: -146,
+            side: KalshiSide::No,
+        });
+        book.apply_delta(&KalshiDeltaMessage {
+            market_ticker: "M".into(),
+            price: 30,
+            delta: 50,
+            side: KalshiSide::Yes,
+        });
+
+        let [yes, no] = book.to_books("M");
+        assert_eq!(yes.best_ask().unwrap().price(), dec!(0.46));
+        assert_eq!(yes.best_bid().unwrap().price(), dec!(0.30));
+        assert_eq!(no.best_ask().unwrap().price(), dec!(0.70));
+        assert_eq!(no.best_ask().unwrap().size(), dec!(50));
+    }
+}
@@ -0,0 +1,208 @@
+//! Kalshi REST API client.
+//!
+//! Market discovery uses `GET /markets`, paging with the returned cursor until
+//! the requested number of open markets has been collected.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client as HttpClient;
+use tracing::{debug, info, warn};
+
+use super::dto::message::KalshiSide;
+use super::dto::response::{KalshiMarket, KalshiMarketsResponse};
+use super::market::{outcome_token, KALSHI_PAYOUT_CENTS};
+use super::settings::KalshiConfig;
+use crate::error::Result;
+use crate::port::{
+    outbound::exchange::MarketFetcher, outbound::exchange::MarketInfo,
+    outbound::exchange::OutcomeInfo,
+};
+
+/// Largest page size the markets endpoint accepts.
+const MAX_PAGE_SIZE: usize = 1000;
+
+/// HTTP client for the Kalshi REST API.
+pub struct KalshiClient {
+    http: HttpClient,
+    base_url: String,
+}
+
+impl KalshiClient {
+    /// Create a new Kalshi client with the given base URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL for the Kalshi trade API
+    ///   (e.g., `https://api.elections.kalshi.com/trade-api/v2`)
+    #[must_use]
+    pub fn new(base_url: String) -> Self {
+        Self {
+            http: HttpClient::new(),
+            base_url,
+        }
+    }
+
+    #[must_use]
+    pub fn from_config(config: &KalshiConfig) -> Self {
+        let http = HttpClient::builder()
+            .timeout(Duration::from_millis(config.timeout_ms))
+            .build()
+            .unwrap_or_else(|err| {
+                warn!(error = %err, "Failed to build HTTP client, using defaults");
+                HttpClient::new()
+            });
+
+        Self {
+            http,
+            base_url: config.api_url.clone(),
+        }
+    }
+
+    /// Fetch up to `limit` open markets, following pagination cursors.
+    pub async fn get_open_markets(&self, limit: usize) -> Result<Vec<KalshiMarket>> {
+        let url = format!("{}/markets", self.base_url);
+        let mut markets = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        info!(url = %url, limit, "Fetching open markets (Kalshi)");
+
+        while markets.len() < limit {
+            let page_size = (limit - markets.len()).min(MAX_PAGE_SIZE);
+            let mut request = self
+                .http
+                .get(&url)
+                .query(&[("status", "open"), ("limit", &page_size.to_string())]);
+            if let Some(cursor) = &cursor {
+                request = request.query(&[("cursor", cursor)]);
+            }
+
+            let page: KalshiMarketsResponse =
+                request.send().await?.error_for_status()?.json().await?;
+            let fetched = page.markets.len();
+            markets.extend(page.markets);
+
+            match page.cursor.filter(|next| !next.is_empty()) {
+                Some(next) if fetched > 0 => cursor = Some(next),
+                _ => break,
+            }
+        }
+
+        markets.truncate(limit);
+        debug!(count = markets.len(), "Fetched markets from Kalshi");
+
+        Ok(markets)
+    }
+}
+
+#[async_trait]
+impl MarketFetcher for KalshiClient {
+    async fn get_markets(&self, limit: usize) -> Result<Vec<MarketInfo>> {
+        let markets = self.get_open_markets(limit).await?;
+        Ok(markets.into_iter().map(MarketInfo::from).collect())
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        "Kalshi"
+    }
+}
+
+// ---------------------------------------------------------------------------
+// MarketInfo conversions
+// ---------------------------------------------------------------------------
+
+impl From<KalshiMarket> for MarketInfo {
+    fn from(m: KalshiMarket) -> Self {
+        let outcome = |side: KalshiSide, name: &str, ask: Option<i64>| OutcomeInfo {
+            token_id: outcome_token(&m.ticker, side).as_str().to_string(),
+            name: name.to_string(),
+            price: ask
+                .filter(|cents| (1..KALSHI_PAYOUT_CENTS).contains(cents))
+                .map(cents_to_dollars),
+            volume_24h: None,
+            liquidity: None,
+        };
+        let outcomes = vec![
+            outcome(KalshiSide::Yes, "Yes", m.yes_ask),
+            outcome(KalshiSide::No, "No", m.no_ask),
+        ];
+
+        Self {
+            active: m.is_active(),
+            question: m.title,
+            outcomes,
+            // Contracts pay $1, so contract volume approximates USD notional
+            volume_24h: m.volume_24h.map(|contracts| contracts as f64),
+            liquidity: m.liquidity.map(cents_to_dollars),
+            close_time: m
+                .close_time
+                .as_deref()
+                .and_then(|close| DateTime::parse_from_rfc3339(close).ok())
+                .map(|close| close.with_timezone(&Utc)),
+            id: m.ticker,
+        }
+    }
+}
+
+/// Convert cents to a floating-point dollar amount for market metadata.
+fn cents_to_dollars(cents: i64) -> f64 {
+    cents as f64 / KALSHI_PAYOUT_CENTS as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kalshi_market() -> KalshiMarket {
+        KalshiMarket {
+            ticker: "FED-23DEC-T3.00".into(),
+            title: "Will the Fed raise rates?".into(),
+            status: "active".into(),
+            yes_ask: Some(44),
+            no_ask: Some(58),
+            volume_24h: Some(1200),
+            liquidity: Some(250_000),
+            close_time: Some("2023-12-13T19:00:00Z".into()),
+        }
+    }
+
+    #[test]
+    fn market_info_normalizes_cents_to_dollars() {
+        let info = MarketInfo::from(kalshi_market());
+
+        assert_eq!(info.id, "FED-23DEC-T3.00");
+        assert!(info.active);
+        assert!(info.is_binary());
+        assert_eq!(info.outcomes[0].token_id, "FED-23DEC-T3.00:yes");
+        assert_eq!(info.outcomes[0].name, "Yes");
+        assert_eq!(info.outcomes[0].price, Some(0.44));
+        assert_eq!(info.outcomes[1].token_id, "FED-23DEC-T3.00:no");
+        assert_eq!(info.outcomes[1].price, Some(0.58));
+        assert_eq!(info.volume_24h, Some(1200.0));
+        assert_eq!(info.liquidity, Some(2500.0));
+        assert_eq!(
+            info.close_time,
+            Some("2023-12-13T19:00:00Z".parse::<DateTime<Utc>>().unwrap())
+        );
+    }
+
+    #[test]
+    fn market_info_drops_missing_quotes() {
+        // Kalshi reports an empty side as 0 (no bid) or 100 (no ask)
+        let info = MarketInfo::from(KalshiMarket {
+            yes_ask: Some(100),
+            no_ask: None,
+            ..kalshi_market()
+        });
+
+        assert!(info.outcomes[0].price.is_none());
+        assert!(info.outcomes[1].price.is_none());
+    }
+
+    #[test]
+    fn client_exchange_name() {
+        let client = KalshiClient::new("https://example.com".into());
+        assert_eq!(client.exchange_name(), "Kalshi");
+    }
+}
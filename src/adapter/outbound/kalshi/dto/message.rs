@@ -0,0 +1,149 @@
+//! Kalshi WebSocket message types.
+//!
+//! Kalshi publishes resting bids for both sides of a market on the
+//! `orderbook_delta` channel: one full snapshot on subscription, followed by
+//! per-level deltas. Prices are integer cents and sizes are contract counts.
+//!
+//! Example message format:
+//! ```json
+//! {"type":"orderbook_snapshot","sid":1,"seq":1,"msg":{"market_ticker":"FED-23DEC-T3.00","yes":[[8,300]],"no":[[54,20]]}}
+//! {"type":"orderbook_delta","sid":1,"seq":2,"msg":{"market_ticker":"FED-23DEC-T3.00","price":54,"delta":-20,"side":"no"}}
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Order book channel name.
+const ORDERBOOK_CHANNEL: &str = "orderbook_delta";
+
+/// Subscription command sent to the Kalshi WebSocket.
+#[derive(Debug, Serialize)]
+pub struct KalshiSubscribeMessage {
+    pub id: u64,
+    pub cmd: String,
+    pub params: KalshiSubscribeParams,
+}
+
+/// Parameters of a subscription command.
+#[derive(Debug, Serialize)]
+pub struct KalshiSubscribeParams {
+    pub channels: Vec<String>,
+    pub market_tickers: Vec<String>,
+}
+
+impl KalshiSubscribeMessage {
+    /// Build an order book subscription for the given market tickers.
+    #[must_use]
+    pub fn orderbook(id: u64, market_tickers: Vec<String>) -> Self {
+        Self {
+            id,
+            cmd: "subscribe".into(),
+            params: KalshiSubscribeParams {
+                channels: vec![ORDERBOOK_CHANNEL.into()],
+                market_tickers,
+            },
+        }
+    }
+}
+
+/// Side of a Kalshi binary contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KalshiSide {
+    Yes,
+    No,
+}
+
+impl KalshiSide {
+    /// Return the lowercase wire name of this side.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Yes => "yes",
+            Self::No => "no",
+        }
+    }
+}
+
+/// Messages received from the Kalshi WebSocket.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum KalshiWsMessage {
+    /// Full bid ladders for both sides of a market.
+    OrderbookSnapshot { msg: KalshiSnapshotMessage },
+
+    /// Change in resting quantity at one price level.
+    OrderbookDelta { msg: KalshiDeltaMessage },
+
+    /// Subscription acknowledgements, errors, and other control messages.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Order book snapshot payload: `[price_cents, quantity]` pairs per side.
+#[derive(Debug, Deserialize)]
+pub struct KalshiSnapshotMessage {
+    pub market_ticker: String,
+    #[serde(default)]
+    pub yes: Vec<(i64, i64)>,
+    #[serde(default)]
+    pub no: Vec<(i64, i64)>,
+}
+
+/// Order book delta payload.
+#[derive(Debug, Deserialize)]
+pub struct KalshiDeltaMessage {
+    pub market_ticker: String,
+    /// Price level in cents.
+    pub price: i64,
+    /// Signed change in resting quantity.
+    pub delta: i64,
+    pub side: KalshiSide,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscribe_message_serializes_correctly() {
+        let msg = KalshiSubscribeMessage::orderbook(7, vec!["FED-23DEC-T3.00".into()]);
+        let json: serde_json::Value = serde_json::to_value(&msg).unwrap();
+
+        assert_eq!(json["id"], 7);
+        assert_eq!(json["cmd"], "subscribe");
+        assert_eq!(json["params"]["channels"][0], "orderbook_delta");
+        assert_eq!(json["params"]["market_tickers"][0], "FED-23DEC-T3.00");
+    }
+
+    #[test]
+    fn parses_snapshot_and_delta() {
+        let snapshot = r#"{"type":"orderbook_snapshot","sid":1,"seq":1,"msg":{"market_ticker":"M","yes":[[8,300],[22,333]],"no":[[54,20]]}}"#;
+        match serde_json::from_str::<KalshiWsMessage>(snapshot).unwrap() {
+            KalshiWsMessage::OrderbookSnapshot { msg } => {
+                assert_eq!(msg.market_ticker, "M");
+                assert_eq!(msg.yes, vec![(8, 300), (22, 333)]);
+                assert_eq!(msg.no, vec![(54, 20)]);
+            }
+            other => panic!("Expected snapshot, got {other:?}"),
+        }
+
+        let delta = r#"{"type":"orderbook_delta","sid":1,"seq":2,"msg":{"market_ticker":"M","price":54,"delta":-20,"side":"no"}}"#;
+        match serde_json::from_str::<KalshiWsMessage>(delta).unwrap() {
+            KalshiWsMessage::OrderbookDelta { msg } => {
+                assert_eq!(msg.price, 54);
+                assert_eq!(msg.delta, -20);
+                assert_eq!(msg.side, KalshiSide::No);
+            }
+            other => panic!("Expected delta, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn control_messages_parse_as_unknown() {
+        let json = r#"{"type":"subscribed","id":1,"msg":{"channel":"orderbook_delta","sid":1}}"#;
+        assert!(matches!(
+            serde_json::from_str::<KalshiWsMessage>(json).unwrap(),
+            KalshiWsMessage::Unknown
+        ));
+    }
+}
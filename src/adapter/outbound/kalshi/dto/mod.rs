@@ -0,0 +1,8 @@
+//! Kalshi data transfer objects.
+//!
+//! Contains types for API and WebSocket communication:
+//! - WebSocket messages (subscriptions, order book snapshots and deltas)
+//! - REST API responses (market listings)
+
+pub mod message;
+pub mod response;
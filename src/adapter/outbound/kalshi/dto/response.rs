@@ -0,0 +1,89 @@
+//! Kalshi REST API response types.
+//!
+//! Prices are integer cents and volumes are contract counts, exactly as the
+//! API returns them. Conversion to dollars happens when building
+//! [`MarketInfo`](crate::port::outbound::exchange::MarketInfo).
+
+use serde::Deserialize;
+
+/// Page of markets returned by `GET /markets`.
+#[derive(Debug, Deserialize)]
+pub struct KalshiMarketsResponse {
+    #[serde(default)]
+    pub markets: Vec<KalshiMarket>,
+    /// Cursor for the next page; empty or absent on the last page.
+    #[serde(default)]
+    pub cursor: Option<String>,
+}
+
+/// A single Kalshi market (one binary contract).
+#[derive(Debug, Deserialize)]
+pub struct KalshiMarket {
+    pub ticker: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub status: String,
+    /// Best yes ask in cents.
+    #[serde(default)]
+    pub yes_ask: Option<i64>,
+    /// Best no ask in cents.
+    #[serde(default)]
+    pub no_ask: Option<i64>,
+    /// Contracts traded over the last 24 hours.
+    #[serde(default)]
+    pub volume_24h: Option<i64>,
+    /// Resting liquidity in cents.
+    #[serde(default)]
+    pub liquidity: Option<i64>,
+    /// When trading closes, in RFC 3339 format.
+    #[serde(default)]
+    pub close_time: Option<String>,
+}
+
+impl KalshiMarket {
+    /// Return `true` if the market is open for trading.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        matches!(self.status.as_str(), "active" | "open")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_markets_page() {
+        let json = r#"{
+            "cursor": "next-page",
+            "markets": [{
+                "ticker": "FED-23DEC-T3.00",
+                "event_ticker": "FED-23DEC",
+                "title": "Will the Fed raise rates?",
+                "status": "active",
+                "yes_ask": 44,
+                "no_ask": 58,
+                "volume_24h": 1200,
+                "liquidity": 250000
+            }]
+        }"#;
+
+        let response: KalshiMarketsResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.cursor.as_deref(), Some("next-page"));
+        assert_eq!(response.markets.len(), 1);
+        let market = &response.markets[0];
+        assert_eq!(market.ticker, "FED-23DEC-T3.00");
+        assert_eq!(market.yes_ask, Some(44));
+        assert!(market.is_active());
+    }
+
+    #[test]
+    fn settled_market_is_not_active() {
+        let market: KalshiMarket =
+            serde_json::from_str(r#"{"ticker": "X", "status": "settled"}"#).unwrap();
+        assert!(!market.is_active());
+        assert!(market.yes_ask.is_none());
+    }
+}
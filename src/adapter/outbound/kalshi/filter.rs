@@ -0,0 +1,135 @@
+//! Market filter for Kalshi exchange.
+//!
+//! Implements the [`MarketFilter`] trait for Kalshi markets. Every Kalshi
+//! market is a binary contract, so eligibility depends only on status,
+//! activity thresholds, and proximity to resolution.
+
+use std::time::Duration;
+
+use chrono::Utc;
+
+use super::settings::KalshiFilterConfig;
+use crate::port::{
+    outbound::exchange::MarketInfo, outbound::filter::MarketFilter,
+    outbound::filter::MarketFilterConfig,
+};
+
+/// Market eligibility filter for Kalshi.
+#[derive(Debug, Clone)]
+pub struct KalshiFilter {
+    /// Domain-level filter configuration.
+    config: MarketFilterConfig,
+}
+
+impl KalshiFilter {
+    /// Create a new Kalshi filter from exchange configuration.
+    #[must_use]
+    pub fn new(config: &KalshiFilterConfig) -> Self {
+        Self {
+            config: MarketFilterConfig {
+                max_markets: config.max_markets,
+                // Each market subscribes a yes and a no token
+                max_subscriptions: config.max_markets * 2,
+                min_volume_24h: config.min_volume_24h,
+                min_liquidity: config.min_liquidity,
+                max_spread_pct: MarketFilterConfig::default().max_spread_pct,
+                include_binary: true,
+                include_multi_outcome: false,
+                max_outcomes: 2,
+                max_outcome_price: config.max_outcome_price,
+                min_time_to_close: config.min_time_to_close_secs.map(Duration::from_secs),
+                whitelist: config.whitelist.clone(),
+                blacklist: config.blacklist.clone(),
+                exclude_keywords: config.exclude_keywords.clone(),
+            },
+        }
+    }
+}
+
+impl MarketFilter for KalshiFilter {
+    fn is_eligible(&self, market: &MarketInfo) -> bool {
+        if !market.active || !market.is_binary() {
+            return false;
+        }
+
+        // Markets without volume or liquidity data pass
+        if market
+            .volume_24h
+            .is_some_and(|volume| volume < self.config.min_volume_24h)
+        {
+            return false;
+        }
+        if market
+            .liquidity
+            .is_some_and(|liquidity| liquidity < self.config.min_liquidity)
+        {
+            return false;
+        }
+
+        !self.config.is_near_resolution(market, Utc::now())
+    }
+
+    fn config(&self) -> &MarketFilterConfig {
+        &self.config
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        "Kalshi"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::outbound::exchange::OutcomeInfo;
+
+    fn market(active: bool, volume_24h: Option<f64>) -> MarketInfo {
+        let outcome = |name: &str| OutcomeInfo {
+            token_id: format!("M:{}", name.to_lowercase()),
+            name: name.into(),
+            price: None,
+            volume_24h: None,
+            liquidity: None,
+        };
+        MarketInfo {
+            id: "M".into(),
+            question: "Will it happen?".into(),
+            outcomes: vec![outcome("Yes"), outcome("No")],
+            active,
+            volume_24h,
+            liquidity: None,
+            close_time: None,
+        }
+    }
+
+    #[test]
+    fn rejects_inactive_markets() {
+        let filter = KalshiFilter::new(&KalshiFilterConfig::default());
+        assert!(filter.is_eligible(&market(true, None)));
+        assert!(!filter.is_eligible(&market(false, None)));
+    }
+
+    #[test]
+    fn applies_volume_threshold() {
+        let filter = KalshiFilter::new(&KalshiFilterConfig {
+            min_volume_24h: 100.0,
+            ..Default::default()
+        });
+        assert!(filter.is_eligible(&market(true, Some(150.0))));
+        assert!(!filter.is_eligible(&market(true, Some(50.0))));
+    }
+
+    #[test]
+    fn applies_time_to_close_floor() {
+        let filter = KalshiFilter::new(&KalshiFilterConfig {
+            min_time_to_close_secs: Some(3600),
+            ..Default::default()
+        });
+        let closing_at = |minutes| MarketInfo {
+            close_time: Some(Utc::now() + chrono::Duration::minutes(minutes)),
+            ..market(true, None)
+        };
+        assert!(filter.is_eligible(&closing_at(120)));
+        assert!(!filter.is_eligible(&closing_at(10)));
+    }
+}
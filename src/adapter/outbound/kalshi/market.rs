@@ -0,0 +1,136 @@
+//! Kalshi-specific market parser.
+//!
+//! Each Kalshi market is a single binary contract identified by its ticker.
+//! The yes and no sides become two outcome tokens, `<ticker>:yes` and
+//! `<ticker>:no`, so they can be tracked like any other binary market.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use super::book::KalshiBook;
+use super::dto::message::{KalshiSide, KalshiWsMessage};
+use crate::domain::{book::Book, id::TokenId, money::Price};
+use crate::error::Error;
+use crate::port::outbound::exchange::MarketParser;
+
+/// Default payout amount for Kalshi ($1.00 per contract).
+pub const KALSHI_PAYOUT: Decimal = dec!(1);
+
+/// Payout per contract in cents, the unit Kalshi quotes prices in.
+pub const KALSHI_PAYOUT_CENTS: i64 = 100;
+
+/// Convert a price in cents to dollars.
+#[must_use]
+pub fn cents_to_price(cents: i64) -> Price {
+    Decimal::new(cents, 2)
+}
+
+/// Build the outcome token identifier for one side of a market.
+#[must_use]
+pub fn outcome_token(ticker: &str, side: KalshiSide) -> TokenId {
+    TokenId::from(format!("{ticker}:{}", side.as_str()))
+}
+
+/// Return the market ticker an outcome token belongs to.
+///
+/// Returns `None` if the token was not built by [`outcome_token`].
+#[must_use]
+pub fn market_ticker(token_id: &TokenId) -> Option<&str> {
+    let (ticker, side) = token_id.as_str().rsplit_once(':')?;
+    matches!(side, "yes" | "no").then_some(ticker)
+}
+
+/// Kalshi market parser.
+///
+/// Kalshi uses:
+/// - $1.00 payout per winning contract
+/// - "Yes"/"No" outcome naming convention
+///
+/// # Examples
+///
+/// ```
+/// use edgelord::adapter::outbound::kalshi::market::KalshiMarketParser;
+/// use edgelord::port::outbound::exchange::MarketParser;
+/// use rust_decimal_macros::dec;
+///
+/// let parser = KalshiMarketParser;
+/// assert_eq!(parser.name(), "kalshi");
+/// assert_eq!(parser.default_payout(), dec!(1.00));
+/// assert_eq!(parser.binary_outcome_names(), ("Yes", "No"));
+/// ```
+pub struct KalshiMarketParser;
+
+impl MarketParser for KalshiMarketParser {
+    fn name(&self) -> &'static str {
+        "kalshi"
+    }
+
+    fn default_payout(&self) -> Decimal {
+        KALSHI_PAYOUT
+    }
+
+    fn binary_outcome_names(&self) -> (&'static str, &'static str) {
+        ("Yes", "No")
+    }
+
+    fn parse_order_book(&self, raw: &str) -> Result<Vec<Book>, Error> {
+        match serde_json::from_str::<KalshiWsMessage>(raw)? {
+            KalshiWsMessage::OrderbookSnapshot { msg } => Ok(KalshiBook::from_snapshot(&msg)
+                .to_books(&msg.market_ticker)
+                .to_vec()),
+            _ => Err(Error::Parse(
+                "message is not a kalshi order book snapshot".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cents_normalize_to_dollars() {
+        assert_eq!(cents_to_price(44), dec!(0.44));
+        assert_eq!(cents_to_price(1), dec!(0.01));
+        assert_eq!(cents_to_price(KALSHI_PAYOUT_CENTS), KALSHI_PAYOUT);
+    }
+
+    #[test]
+    fn test_outcome_token_round_trips_ticker() {
+        let yes = outcome_token("FED-23DEC-T3.00", KalshiSide::Yes);
+        assert_eq!(yes.as_str(), "FED-23DEC-T3.00:yes");
+        assert_eq!(market_ticker(&yes), Some("FED-23DEC-T3.00"));
+        assert_eq!(market_ticker(&TokenId::from("polymarket-token")), None);
+    }
+
+    #[test]
+    fn test_parser_values() {
+        let parser = KalshiMarketParser;
+        assert_eq!(parser.name(), "kalshi");
+        assert_eq!(parser.default_payout(), Decimal::ONE);
+        assert_eq!(parser.binary_outcome_names(), ("Yes", "No"));
+        assert!(parser.is_positive_outcome("yes"));
+        assert!(parser.is_negative_outcome("NO"));
+    }
+
+    #[test]
+    fn test_parse_order_book_from_snapshot() {
+        let raw = r#"{"type":"orderbook_snapshot","sid":1,"seq":1,"msg":{"market_ticker":"M","yes":[[40,10]],"no":[[55,20]]}}"#;
+
+        let books = KalshiMarketParser.parse_order_book(raw).unwrap();
+
+        assert_eq!(books.len(), 2);
+        assert_eq!(books[0].token_id().as_str(), "M:yes");
+        assert_eq!(books[0].best_bid().unwrap().price(), dec!(0.40));
+        assert_eq!(books[0].best_ask().unwrap().price(), dec!(0.45));
+        assert_eq!(books[1].token_id().as_str(), "M:no");
+        assert_eq!(books[1].best_ask().unwrap().price(), dec!(0.60));
+    }
+
+    #[test]
+    fn test_parse_order_book_rejects_delta() {
+        let raw = r#"{"type":"orderbook_delta","msg":{"market_ticker":"M","price":40,"delta":5,"side":"yes"}}"#;
+        assert!(KalshiMarketParser.parse_order_book(raw).is_err());
+    }
+}
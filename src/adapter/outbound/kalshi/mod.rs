@@ -0,0 +1,26 @@
+//! Kalshi exchange integration.
+//!
+//! Provides adapters for reading market data from the Kalshi prediction market
+//! exchange: market discovery over REST and order book streaming over
+//! WebSocket. Order execution is not supported yet, so Kalshi runs in
+//! detection-only mode.
+//!
+//! Kalshi quotes prices in cents. Every adapter here normalizes them to dollar
+//! decimals before they reach the domain.
+//!
+//! # Modules
+//!
+//! - [`client`] - REST API client for market discovery
+//! - [`stream`] - WebSocket handler for real-time order books
+//! - [`book`] - Local order book state built from snapshots and deltas
+//! - [`market`] - Market parser and outcome token helpers
+//! - [`filter`] - Market eligibility filtering
+//! - [`settings`] - Configuration types for the Kalshi adapter
+
+pub mod book;
+pub mod client;
+pub mod dto;
+pub mod filter;
+pub mod market;
+pub mod settings;
+pub mod stream;
@@ -0,0 +1,149 @@
+//! Kalshi exchange configuration.
+//!
+//! Defines configuration types for the Kalshi adapter: API endpoints for the
+//! demo and production environments, HTTP timeouts, and market filtering.
+
+use serde::{Deserialize, Serialize};
+
+use crate::adapter::outbound::polymarket::settings::Environment;
+
+/// Demo REST API URL.
+pub const KALSHI_DEMO_API_URL: &str = "https://demo-api.kalshi.co/trade-api/v2";
+
+/// Demo WebSocket URL.
+pub const KALSHI_DEMO_WS_URL: &str = "wss://demo-api.kalshi.co/trade-api/ws/v2";
+
+/// Production REST API URL.
+pub const KALSHI_PRODUCTION_API_URL: &str = "https://api.elections.kalshi.com/trade-api/v2";
+
+/// Production WebSocket URL.
+pub const KALSHI_PRODUCTION_WS_URL: &str = "wss://api.elections.kalshi.com/trade-api/ws/v2";
+
+/// Market filter configuration for subscription eligibility.
+///
+/// Kalshi markets are always binary, so only activity thresholds apply.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KalshiFilterConfig {
+    /// Maximum number of markets to track simultaneously.
+    #[serde(default = "default_filter_max_markets")]
+    pub max_markets: usize,
+    /// Minimum contracts traded over the last 24 hours.
+    #[serde(default)]
+    pub min_volume_24h: f64,
+    /// Minimum resting liquidity in USD.
+    #[serde(default)]
+    pub min_liquidity: f64,
+    /// Skip markets where any outcome is priced above this (e.g., 0.97).
+    #[serde(default)]
+    pub max_outcome_price: Option<f64>,
+    /// Skip markets that close within this many seconds.
+    #[serde(default)]
+    pub min_time_to_close_secs: Option<u64>,
+    /// Market IDs that bypass the volume floor.
+    #[serde(default)]
+    pub whitelist: Vec<String>,
+    /// Market IDs that are always skipped.
+    #[serde(default)]
+    pub blacklist: Vec<String>,
+    /// Skip markets whose question contains any of these (case-insensitive).
+    #[serde(default)]
+    pub exclude_keywords: Vec<String>,
+}
+
+const fn default_filter_max_markets() -> usize {
+    200
+}
+
+impl Default for KalshiFilterConfig {
+    fn default() -> Self {
+        Self {
+            max_markets: default_filter_max_markets(),
+            min_volume_24h: 0.0,
+            min_liquidity: 0.0,
+            max_outcome_price: None,
+            min_time_to_close_secs: None,
+            whitelist: Vec::new(),
+            blacklist: Vec::new(),
+            exclude_keywords: Vec::new(),
+        }
+    }
+}
+
+/// Complete Kalshi exchange configuration.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KalshiConfig {
+    /// Deployment environment (testnet selects Kalshi's demo environment).
+    #[serde(default)]
+    pub environment: Environment,
+    /// WebSocket URL for real-time order books.
+    #[serde(default = "default_kalshi_ws_url")]
+    pub ws_url: String,
+    /// REST API URL for market discovery.
+    #[serde(default = "default_kalshi_api_url")]
+    pub api_url: String,
+    /// HTTP request timeout in milliseconds.
+    #[serde(default = "default_http_timeout_ms")]
+    pub timeout_ms: u64,
+    /// Market eligibility filter settings.
+    #[serde(default)]
+    pub market_filter: KalshiFilterConfig,
+}
+
+fn default_kalshi_ws_url() -> String {
+    KALSHI_DEMO_WS_URL.into()
+}
+
+fn default_kalshi_api_url() -> String {
+    KALSHI_DEMO_API_URL.into()
+}
+
+const fn default_http_timeout_ms() -> u64 {
+    5000
+}
+
+impl KalshiConfig {
+    /// Switch to the given environment and its default endpoints.
+    pub fn set_environment(&mut self, environment: Environment) {
+        let (api_url, ws_url) = match environment {
+            Environment::Testnet => (KALSHI_DEMO_API_URL, KALSHI_DEMO_WS_URL),
+            Environment::Mainnet => (KALSHI_PRODUCTION_API_URL, KALSHI_PRODUCTION_WS_URL),
+        };
+        self.environment = environment;
+        self.api_url = api_url.into();
+        self.ws_url = ws_url.into();
+    }
+}
+
+impl Default for KalshiConfig {
+    fn default() -> Self {
+        Self {
+            environment: Environment::default(),
+            ws_url: default_kalshi_ws_url(),
+            api_url: default_kalshi_api_url(),
+            timeout_ms: default_http_timeout_ms(),
+            market_filter: KalshiFilterConfig::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_target_demo_environment() {
+        let config: KalshiConfig = toml::from_str("").unwrap();
+        assert_eq!(config.environment, Environment::Testnet);
+        assert_eq!(config.api_url, KALSHI_DEMO_API_URL);
+        assert_eq!(config.ws_url, KALSHI_DEMO_WS_URL);
+        assert_eq!(config.market_filter.max_markets, 200);
+    }
+
+    #[test]
+    fn set_environment_switches_endpoints() {
+        let mut config = KalshiConfig::default();
+        config.set_environment(Environment::Mainnet);
+        assert_eq!(config.api_url, KALSHI_PRODUCTION_API_URL);
+        assert_eq!(config.ws_url, KALSHI_PRODUCTION_WS_URL);
+    }
+}
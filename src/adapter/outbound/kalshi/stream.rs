@@ -0,0 +1,239 @@
+//! Kalshi WebSocket market data stream.
+//!
+//! Subscribes to the `orderbook_delta` channel for each market ticker and
+//! maintains a local [`KalshiBook`] per market. Every snapshot or delta emits
+//! fresh [`MarketEvent::BookSnapshot`] events for both the yes and no tokens,
+//! so downstream caches always hold complete books with dollar prices.
+//!
+//! This stream does not reconnect on its own. After a reconnect, Kalshi sends
+//! a fresh snapshot, so local book state is cleared on every `connect()`.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+use tracing::{debug, error, info, trace, warn};
+
+use super::book::KalshiBook;
+use super::dto::message::{KalshiSubscribeMessage, KalshiWsMessage};
+use super::market::market_ticker;
+use crate::domain::{book::Book, id::TokenId};
+use crate::error::Result;
+use crate::port::{outbound::exchange::MarketDataStream, outbound::exchange::MarketEvent};
+
+/// Kalshi market data stream implementing the `MarketDataStream` trait.
+pub struct KalshiDataStream {
+    url: String,
+    ws: Option<WebSocketStream<MaybeTlsStream<TcpStream>>>,
+    /// Local book state keyed by market ticker.
+    books: HashMap<String, KalshiBook>,
+    /// Book events produced by a message but not yet returned.
+    pending: Vec<MarketEvent>,
+    /// Identifier for the next command sent to the server.
+    next_command_id: u64,
+}
+
+impl KalshiDataStream {
+    /// Create a new data stream for the given WebSocket URL.
+    #[must_use]
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            ws: None,
+            books: HashMap::new(),
+            pending: Vec::new(),
+            next_command_id: 1,
+        }
+    }
+
+    /// Apply a parsed message to local book state.
+    ///
+    /// Returns the updated yes and no books, or `None` if the message carried
+    /// no book data or referenced a market without a snapshot.
+    fn apply_message(&mut self, message: KalshiWsMessage) -> Option<[Book; 2]> {
+        match message {
+            KalshiWsMessage::OrderbookSnapshot { msg } => {
+                let book = KalshiBook::from_snapshot(&msg);
+                let books = book.to_books(&msg.market_ticker);
+                self.books.insert(msg.market_ticker, book);
+                Some(books)
+            }
+            KalshiWsMessage::OrderbookDelta { msg } => {
+                let Some(book) = self.books.get_mut(&msg.market_ticker) else {
+                    debug!(ticker = %msg.market_ticker, "Delta before snapshot, skipping");
+                    return None;
+                };
+                book.apply_delta(&msg);
+                Some(book.to_books(&msg.market_ticker))
+            }
+            KalshiWsMessage::Unknown => None,
+        }
+    }
+}
+
+/// Collect the distinct market tickers behind a set of outcome tokens.
+fn market_tickers(token_ids: &[TokenId]) -> Vec<String> {
+    let mut tickers: Vec<String> = Vec::new();
+    for token_id in token_ids {
+        match market_ticker(token_id) {
+            Some(ticker) if !tickers.iter().any(|t| t == ticker) => {
+                tickers.push(ticker.to_string());
+            }
+            Some(_) => {}
+            None => warn!(token = %token_id, "Not a Kalshi outcome token, skipping"),
+        }
+    }
+    tickers
+}
+
+fn snapshot_event(book: Book) -> MarketEvent {
+    MarketEvent::BookSnapshot {
+        token_id: book.token_id().clone(),
+        book,
+    }
+}
+
+#[async_trait]
+impl MarketDataStream for KalshiDataStream {
+    async fn connect(&mut self) -> Result<()> {
+        info!(url = %self.url, "Connecting to WebSocket");
+        let (ws_stream, response) = connect_async(&self.url).await?;
+        info!(status = %response.status(), "WebSocket connected");
+        self.ws = Some(ws_stream);
+        self.books.clear();
+        self.pending.clear();
+        Ok(())
+    }
+
+    async fn subscribe(&mut self, token_ids: &[TokenId]) -> Result<()> {
+        let ws = self
+            .ws
+            .as_mut()
+            .ok_or_else(|| crate::error::Error::Connection("Not connected".into()))?;
+
+        let tickers = market_tickers(token_ids);
+        let total = tickers.len();
+        if total <= 5 {
+            info!(markets = ?tickers, "Subscribing to markets");
+        } else {
+            let preview: Vec<_> = tickers.iter().take(5).collect();
+            info!(markets = ?preview, more = total - 5, "Subscribing to markets");
+        }
+
+        let msg = KalshiSubscribeMessage::orderbook(self.next_command_id, tickers);
+        self.next_command_id += 1;
+        ws.send(Message::Text(serde_json::to_string(&msg)?)).await?;
+        Ok(())
+    }
+
+    async fn next_event(&mut self) -> Option<MarketEvent> {
+        if let Some(event) = self.pending.pop() {
+            return Some(event);
+        }
+
+        loop {
+            let ws = self.ws.as_mut()?;
+            match ws.next().await? {
+                Ok(Message::Text(text)) => {
+                    trace!(bytes = text.len(), "Received WebSocket text frame");
+                    let message = match serde_json::from_str::<KalshiWsMessage>(&text) {
+                        Ok(message) => message,
+                        Err(e) => {
+                            warn!(error = %e, bytes = text.len(), "Failed to parse message");
+                            continue;
+                        }
+                    };
+                    if let Some([yes, no]) = self.apply_message(message) {
+                        self.pending.push(snapshot_event(no));
+                        return Some(snapshot_event(yes));
+                    }
+                }
+                Ok(Message::Ping(data)) => {
+                    trace!("Received WebSocket ping");
+                    if ws.send(Message::Pong(data)).await.is_err() {
+                        return Some(MarketEvent::Disconnected {
+                            reason: "Failed to send pong".into(),
+                        });
+                    }
+                }
+                Ok(Message::Close(frame)) => {
+                    info!(frame = ?frame, "WebSocket closed by server");
+                    return Some(MarketEvent::Disconnected {
+                        reason: frame.map(|f| f.reason.to_string()).unwrap_or_default(),
+                    });
+                }
+                Ok(_) => continue,
+                Err(e) => {
+                    error!(error = %e, "WebSocket error");
+                    return Some(MarketEvent::Disconnected {
+                        reason: e.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        "Kalshi"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn parse(json: &str) -> KalshiWsMessage {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn market_tickers_deduplicates_yes_and_no_tokens() {
+        let tokens = vec![
+            TokenId::from("A:yes"),
+            TokenId::from("A:no"),
+            TokenId::from("B:yes"),
+            TokenId::from("not-kalshi"),
+        ];
+        assert_eq!(market_tickers(&tokens), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn snapshot_then_delta_updates_books() {
+        let mut stream = KalshiDataStream::new("wss://test.com".into());
+
+        let [yes, no] = stream
+            .apply_message(parse(
+                r#"{"type":"orderbook_snapshot","msg":{"market_ticker":"M","yes":[[40,10]],"no":[[55,20]]}}"#,
+            ))
+            .unwrap();
+        assert_eq!(yes.token_id().as_str(), "M:yes");
+        assert_eq!(yes.best_ask().unwrap().price(), dec!(0.45));
+        assert_eq!(no.best_ask().unwrap().price(), dec!(0.60));
+
+        let [yes, _] = stream
+            .apply_message(parse(
+                r#"{"type":"orderbook_delta","msg":{"market_ticker":"M","price":57,"delta":5,"side":"no"}}"#,
+            ))
+            .unwrap();
+        assert_eq!(yes.best_ask().unwrap().price(), dec!(0.43));
+        assert_eq!(yes.best_ask().unwrap().size(), dec!(5));
+    }
+
+    #[test]
+    fn delta_without_snapshot_is_skipped() {
+        let mut stream = KalshiDataStream::new("wss://test.com".into());
+        let update = stream.apply_message(parse(
+            r#"{"type":"orderbook_delta","msg":{"market_ticker":"M","price":57,"delta":5,"side":"no"}}"#,
+        ));
+        assert!(update.is_none());
+    }
+
+    #[test]
+    fn data_stream_exchange_name_returns_kalshi() {
+        let stream = KalshiDataStream::new("wss://test.com".into());
+        assert_eq!(stream.exchange_name(), "Kalshi");
+    }
+}
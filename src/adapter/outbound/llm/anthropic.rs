@@ -8,7 +8,7 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{Error, Result};
-use crate::port::outbound::llm::Llm;
+use crate::port::outbound::llm::{Completion, Llm, TokenUsage};
 
 /// Anthropic Messages API endpoint.
 const API_URL: &str = "https://api.anthropic.com/v1/messages";
@@ -84,6 +84,14 @@ struct Message {
 #[derive(Deserialize)]
 struct Response {
     content: Vec<ContentBlock>,
+    #[serde(default)]
+    usage: Option<Usage>,
+}
+
+#[derive(Deserialize)]
+struct Usage {
+    input_tokens: u64,
+    output_tokens: u64,
 }
 
 #[derive(Deserialize)]
@@ -98,6 +106,10 @@ impl Llm for Anthropic {
     }
 
     async fn complete(&self, prompt: &str) -> Result<String> {
+        Ok(self.complete_with_usage(prompt).await?.text)
+    }
+
+    async fn complete_with_usage(&self, prompt: &str) -> Result<Completion> {
         let request = Request {
             model: self.model.clone(),
             max_tokens: self.max_tokens,
@@ -122,12 +134,17 @@ impl Llm for Anthropic {
             .json::<Response>()
             .await?;
 
-        Ok(response
-            .content
-            .into_iter()
-            .map(|c| c.text)
-            .collect::<Vec<_>>()
-            .join(""))
+        Ok(Completion {
+            text: response
+                .content
+                .into_iter()
+                .map(|c| c.text)
+                .collect::<Vec<_>>()
+                .join(""),
+            usage: response
+                .usage
+                .map(|u| TokenUsage::new(u.input_tokens, u.output_tokens)),
+        })
     }
 }
 
@@ -200,6 +217,8 @@ mod tests {
         let response: Response = serde_json::from_str(json).unwrap();
         assert_eq!(response.content.len(), 1);
         assert_eq!(response.content[0].text, "Hello, I'm Claude!");
+        let usage = response.usage.unwrap();
+        assert_eq!((usage.input_tokens, usage.output_tokens), (10, 20));
     }
 
     #[test]
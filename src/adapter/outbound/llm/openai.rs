@@ -8,7 +8,7 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{Error, Result};
-use crate::port::outbound::llm::Llm;
+use crate::port::outbound::llm::{Completion, Llm, TokenUsage};
 
 /// OpenAI Chat Completions API endpoint.
 const API_URL: &str = "https://api.openai.com/v1/chat/completions";
@@ -81,6 +81,14 @@ struct Message {
 #[derive(Deserialize)]
 struct Response {
     choices: Vec<Choice>,
+    #[serde(default)]
+    usage: Option<Usage>,
+}
+
+#[derive(Deserialize)]
+struct Usage {
+    prompt_tokens: u64,
+    completion_tokens: u64,
 }
 
 #[derive(Deserialize)]
@@ -100,6 +108,10 @@ impl Llm for OpenAi {
     }
 
     async fn complete(&self, prompt: &str) -> Result<String> {
+        Ok(self.complete_with_usage(prompt).await?.text)
+    }
+
+    async fn complete_with_usage(&self, prompt: &str) -> Result<Completion> {
         let request = Request {
             model: self.model.clone(),
             max_tokens: self.max_tokens,
@@ -123,12 +135,17 @@ impl Llm for OpenAi {
             .json::<Response>()
             .await?;
 
-        Ok(response
-            .choices
-            .into_iter()
-            .next()
-            .map(|c| c.message.content)
-            .unwrap_or_default())
+        Ok(Completion {
+            text: response
+                .choices
+                .into_iter()
+                .next()
+                .map(|c| c.message.content)
+                .unwrap_or_default(),
+            usage: response
+                .usage
+                .map(|u| TokenUsage::new(u.prompt_tokens, u.completion_tokens)),
+        })
     }
 }
 
@@ -211,6 +228,8 @@ mod tests {
             response.choices[0].message.content,
             "Hello! How can I help you?"
         );
+        let usage = response.usage.unwrap();
+        assert_eq!((usage.prompt_tokens, usage.completion_tokens), (10, 15));
     }
 
     #[test]
@@ -300,7 +319,10 @@ mod tests {
     #[test]
     fn test_empty_choices_returns_empty_string() {
         // Simulate what happens when choices is empty
-        let response = Response { choices: vec![] };
+        let response = Response {
+            choices: vec![],
+            usage: None,
+        };
         let result: String = response
             .choices
             .into_iter()
@@ -0,0 +1,245 @@
+//! Manifold REST API client.
+//!
+//! Market discovery uses `GET /markets`, paging backwards with the `before`
+//! cursor until enough open binary and multiple-choice markets have been
+//! collected. The listing omits answers, so multiple-choice markets are
+//! fetched individually through `GET /market/{id}`.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client as HttpClient;
+use tracing::{debug, info, warn};
+
+use super::dto::ManifoldMarket;
+use super::market::{market_outcomes, probability_to_price};
+use super::settings::ManifoldConfig;
+use crate::error::Result;
+use crate::port::{
+    outbound::exchange::MarketFetcher, outbound::exchange::MarketInfo,
+    outbound::exchange::OutcomeInfo,
+};
+
+/// Largest page size the markets endpoint accepts.
+const MAX_PAGE_SIZE: usize = 1000;
+
+/// HTTP client for the Manifold REST API.
+#[derive(Clone)]
+pub struct ManifoldClient {
+    http: HttpClient,
+    base_url: String,
+}
+
+impl ManifoldClient {
+    /// Create a new Manifold client with the given base URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL for the Manifold API
+    ///   (e.g., `https://api.manifold.markets/v0`)
+    #[must_use]
+    pub fn new(base_url: String) -> Self {
+        Self {
+            http: HttpClient::new(),
+            base_url,
+        }
+    }
+
+    #[must_use]
+    pub fn from_config(config: &ManifoldConfig) -> Self {
+        let http = HttpClient::builder()
+            .timeout(Duration::from_millis(config.timeout_ms))
+            .build()
+            .unwrap_or_else(|err| {
+                warn!(error = %err, "Failed to build HTTP client, using defaults");
+                HttpClient::new()
+            });
+
+        Self {
+            http,
+            base_url: config.api_url.clone(),
+        }
+    }
+
+    /// Fetch a single market, including answers for multiple-choice markets.
+    pub async fn get_market(&self, id: &str) -> Result<ManifoldMarket> {
+        let url = format!("{}/market/{id}", self.base_url);
+        let market = self
+            .http
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(market)
+    }
+
+    /// Fetch up to `limit` open binary and multiple-choice markets.
+    pub async fn get_open_markets(&self, limit: usize) -> Result<Vec<ManifoldMarket>> {
+        let url = format!("{}/markets", self.base_url);
+        let now_ms = Utc::now().timestamp_millis();
+        let mut markets = Vec::new();
+        let mut before: Option<String> = None;
+
+        info!(url = %url, limit, "Fetching open markets (Manifold)");
+
+        while markets.len() < limit {
+            let mut request = self
+                .http
+                .get(&url)
+                .query(&[("limit", MAX_PAGE_SIZE.to_string())]);
+            if let Some(before) = &before {
+                request = request.query(&[("before", before)]);
+            }
+
+            let page: Vec<ManifoldMarket> =
+                request.send().await?.error_for_status()?.json().await?;
+            let Some(last) = page.last() else {
+                break;
+            };
+            before = Some(last.id.clone());
+
+            for market in page {
+                if markets.len() >= limit {
+                    break;
+                }
+                if !market.is_open(now_ms) {
+                    continue;
+                }
+                if market.is_binary() {
+                    markets.push(market);
+                } else if market.is_multiple_choice() {
+                    match self.get_market(&market.id).await {
+                        Ok(full) => markets.push(full),
+                        Err(e) => warn!(market = %market.id, error = %e, "Failed to fetch answers"),
+                    }
+                }
+            }
+        }
+
+        debug!(count = markets.len(), "Fetched markets from Manifold");
+
+        Ok(markets)
+    }
+}
+
+#[async_trait]
+impl MarketFetcher for ManifoldClient {
+    async fn get_markets(&self, limit: usize) -> Result<Vec<MarketInfo>> {
+        let markets = self.get_open_markets(limit).await?;
+        Ok(markets.into_iter().map(MarketInfo::from).collect())
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        "Manifold"
+    }
+}
+
+// ---------------------------------------------------------------------------
+// MarketInfo conversions
+// ---------------------------------------------------------------------------
+
+impl From<ManifoldMarket> for MarketInfo {
+    fn from(m: ManifoldMarket) -> Self {
+        let outcomes = market_outcomes(&m)
+            .into_iter()
+            .map(|outcome| OutcomeInfo {
+                token_id: outcome.token_id.as_str().to_string(),
+                name: outcome.name,
+                price: outcome
+                    .probability
+                    .filter(|p| probability_to_price(*p).is_some()),
+                volume_24h: None,
+                liquidity: None,
+            })
+            .collect();
+
+        Self {
+            active: m.is_open(Utc::now().timestamp_millis()),
+            question: m.question,
+            outcomes,
+            // Mana stands in for USD; thresholds apply to play-money amounts
+            volume_24h: m.volume_24_hours,
+            liquidity: m.total_liquidity,
+            close_time: m.close_time.and_then(DateTime::from_timestamp_millis),
+            id: m.id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn market_info_maps_binary_probability() {
+        let market: ManifoldMarket = serde_json::from_value(serde_json::json!({
+            "id": "bin",
+            "question": "Will it rain?",
+            "outcomeType": "BINARY",
+            "probability": 0.62,
+            "closeTime": 4102444800000_i64,
+            "volume24Hours": 150.0,
+            "totalLiquidity": 1000.0
+        }))
+        .unwrap();
+
+        let info = MarketInfo::from(market);
+
+        assert_eq!(info.id, "bin");
+        assert!(info.active);
+        assert!(info.is_binary());
+        assert_eq!(info.outcomes[0].token_id, "bin:yes");
+        assert_eq!(info.outcomes[0].price, Some(0.62));
+        assert_eq!(info.outcomes[1].token_id, "bin:no");
+        assert_eq!(info.outcomes[1].name, "No");
+        assert_eq!(info.volume_24h, Some(150.0));
+        assert_eq!(info.liquidity, Some(1000.0));
+        assert_eq!(
+            info.close_time,
+            DateTime::from_timestamp_millis(4_102_444_800_000)
+        );
+    }
+
+    #[test]
+    fn market_info_maps_multiple_choice_answers() {
+        let market: ManifoldMarket = serde_json::from_value(serde_json::json!({
+            "id": "mc",
+            "question": "Who wins?",
+            "outcomeType": "MULTIPLE_CHOICE",
+            "answers": [
+                {"id": "a1", "text": "Alice", "probability": 0.5},
+                {"id": "a2", "text": "Bob", "probability": 0.3},
+                {"id": "a3", "text": "Carol"}
+            ]
+        }))
+        .unwrap();
+
+        let info = MarketInfo::from(market);
+
+        assert_eq!(info.outcomes.len(), 3);
+        assert_eq!(info.outcomes[1].name, "Bob");
+        assert_eq!(info.outcomes[1].token_id, "mc:a2");
+        assert!(info.outcomes[2].price.is_none());
+    }
+
+    #[test]
+    fn resolved_market_is_inactive() {
+        let market: ManifoldMarket = serde_json::from_value(serde_json::json!({
+            "id": "done",
+            "outcomeType": "BINARY",
+            "isResolved": true
+        }))
+        .unwrap();
+
+        assert!(!MarketInfo::from(market).active);
+    }
+
+    #[test]
+    fn client_exchange_name() {
+        let client = ManifoldClient::new("https://example.com".into());
+        assert_eq!(client.exchange_name(), "Manifold");
+    }
+}
@@ -0,0 +1,140 @@
+//! Manifold REST API response types.
+//!
+//! Field names follow the API's camelCase JSON. Probabilities are floats in
+//! `[0, 1]` and volumes are in mana, exactly as the API returns them.
+
+use serde::Deserialize;
+
+/// Outcome type of a binary market.
+pub const OUTCOME_TYPE_BINARY: &str = "BINARY";
+
+/// Outcome type of a multiple-choice market.
+pub const OUTCOME_TYPE_MULTIPLE_CHOICE: &str = "MULTIPLE_CHOICE";
+
+/// A Manifold market as returned by `GET /markets` or `GET /market/{id}`.
+///
+/// The listing endpoint omits `answers`; fetch the market individually to
+/// get them for multiple-choice markets.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifoldMarket {
+    pub id: String,
+    #[serde(default)]
+    pub question: String,
+    #[serde(default)]
+    pub outcome_type: String,
+    #[serde(default)]
+    pub is_resolved: bool,
+    /// Close time in milliseconds since the epoch.
+    #[serde(default)]
+    pub close_time: Option<i64>,
+    /// Probability of YES for binary markets.
+    #[serde(default)]
+    pub probability: Option<f64>,
+    /// Mana traded over the last 24 hours.
+    #[serde(default, rename = "volume24Hours")]
+    pub volume_24_hours: Option<f64>,
+    /// Total AMM liquidity in mana.
+    #[serde(default)]
+    pub total_liquidity: Option<f64>,
+    /// Answers of a multiple-choice market.
+    #[serde(default)]
+    pub answers: Option<Vec<ManifoldAnswer>>,
+}
+
+/// A single answer of a multiple-choice market.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ManifoldAnswer {
+    pub id: String,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub probability: Option<f64>,
+}
+
+impl ManifoldMarket {
+    /// Return `true` for binary (YES/NO) markets.
+    #[must_use]
+    pub fn is_binary(&self) -> bool {
+        self.outcome_type == OUTCOME_TYPE_BINARY
+    }
+
+    /// Return `true` for multiple-choice markets.
+    #[must_use]
+    pub fn is_multiple_choice(&self) -> bool {
+        self.outcome_type == OUTCOME_TYPE_MULTIPLE_CHOICE
+    }
+
+    /// Return `true` if the market is unresolved and not past its close time.
+    #[must_use]
+    pub fn is_open(&self, now_ms: i64) -> bool {
+        if self.is_resolved {
+            return false;
+        }
+        match self.close_time {
+            Some(close_time) => close_time > now_ms,
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_binary_lite_market() {
+        let json = r#"{
+            "id": "abc123",
+            "question": "Will it rain tomorrow?",
+            "outcomeType": "BINARY",
+            "mechanism": "cpmm-1",
+            "probability": 0.62,
+            "isResolved": false,
+            "closeTime": 4102444800000,
+            "volume24Hours": 150.5,
+            "totalLiquidity": 1000
+        }"#;
+
+        let market: ManifoldMarket = serde_json::from_str(json).unwrap();
+
+        assert!(market.is_binary());
+        assert_eq!(market.probability, Some(0.62));
+        assert_eq!(market.volume_24_hours, Some(150.5));
+        assert_eq!(market.total_liquidity, Some(1000.0));
+        assert!(market.answers.is_none());
+        assert!(market.is_open(0));
+    }
+
+    #[test]
+    fn parses_multiple_choice_answers() {
+        let json = r#"{
+            "id": "mc1",
+            "question": "Who wins?",
+            "outcomeType": "MULTIPLE_CHOICE",
+            "answers": [
+                {"id": "a1", "text": "Alice", "probability": 0.5},
+                {"id": "a2", "text": "Bob", "probability": 0.45}
+            ]
+        }"#;
+
+        let market: ManifoldMarket = serde_json::from_str(json).unwrap();
+
+        assert!(market.is_multiple_choice());
+        let answers = market.answers.unwrap();
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[1].text, "Bob");
+        assert_eq!(answers[1].probability, Some(0.45));
+    }
+
+    #[test]
+    fn resolved_or_closed_market_is_not_open() {
+        let resolved: ManifoldMarket =
+            serde_json::from_str(r#"{"id": "x", "isResolved": true}"#).unwrap();
+        let closed: ManifoldMarket =
+            serde_json::from_str(r#"{"id": "y", "closeTime": 1000}"#).unwrap();
+
+        assert!(!resolved.is_open(0));
+        assert!(!closed.is_open(2000));
+    }
+}
@@ -0,0 +1,144 @@
+//! Market filter for Manifold Markets.
+//!
+//! Implements the [`MarketFilter`] trait for Manifold markets. Binary markets
+//! are always considered; multiple-choice markets are included when enabled
+//! and within the answer limit.
+
+use std::time::Duration;
+
+use chrono::Utc;
+
+use super::settings::ManifoldFilterConfig;
+use crate::port::{
+    outbound::exchange::MarketInfo, outbound::filter::MarketFilter,
+    outbound::filter::MarketFilterConfig,
+};
+
+/// Market eligibility filter for Manifold.
+#[derive(Debug, Clone)]
+pub struct ManifoldFilter {
+    /// Domain-level filter configuration.
+    config: MarketFilterConfig,
+}
+
+impl ManifoldFilter {
+    /// Create a new Manifold filter from exchange configuration.
+    #[must_use]
+    pub fn new(config: &ManifoldFilterConfig) -> Self {
+        Self {
+            config: MarketFilterConfig {
+                max_markets: config.max_markets,
+                max_subscriptions: config.max_markets * config.max_outcomes.max(2),
+                min_volume_24h: config.min_volume_24h,
+                min_liquidity: config.min_liquidity,
+                max_spread_pct: MarketFilterConfig::default().max_spread_pct,
+                include_binary: true,
+                include_multi_outcome: config.include_multi_outcome,
+                max_outcomes: config.max_outcomes,
+                max_outcome_price: config.max_outcome_price,
+                min_time_to_close: config.min_time_to_close_secs.map(Duration::from_secs),
+                whitelist: config.whitelist.clone(),
+                blacklist: config.blacklist.clone(),
+                exclude_keywords: config.exclude_keywords.clone(),
+            },
+        }
+    }
+}
+
+impl MarketFilter for ManifoldFilter {
+    fn is_eligible(&self, market: &MarketInfo) -> bool {
+        if !market.active {
+            return false;
+        }
+
+        let outcomes = market.outcomes.len();
+        let shape_ok = if market.is_binary() {
+            self.config.include_binary
+        } else {
+            self.config.include_multi_outcome
+                && outcomes > 2
+                && outcomes <= self.config.max_outcomes
+        };
+        if !shape_ok {
+            return false;
+        }
+
+        // Markets without volume or liquidity data pass
+        if market
+            .volume_24h
+            .is_some_and(|volume| volume < self.config.min_volume_24h)
+        {
+            return false;
+        }
+        if market
+            .liquidity
+            .is_some_and(|liquidity| liquidity < self.config.min_liquidity)
+        {
+            return false;
+        }
+
+        !self.config.is_near_resolution(market, Utc::now())
+    }
+
+    fn config(&self) -> &MarketFilterConfig {
+        &self.config
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        "Manifold"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::outbound::exchange::OutcomeInfo;
+
+    fn market(outcomes: usize, liquidity: Option<f64>) -> MarketInfo {
+        MarketInfo {
+            id: "M".into(),
+            question: "Who wins?".into(),
+            outcomes: (0..outcomes)
+                .map(|i| OutcomeInfo {
+                    token_id: format!("M:a{i}"),
+                    name: format!("Answer {i}"),
+                    price: None,
+                    volume_24h: None,
+                    liquidity: None,
+                })
+                .collect(),
+            active: true,
+            volume_24h: None,
+            liquidity,
+            close_time: None,
+        }
+    }
+
+    #[test]
+    fn includes_binary_and_multi_outcome_by_default() {
+        let filter = ManifoldFilter::new(&ManifoldFilterConfig::default());
+        assert!(filter.is_eligible(&market(2, None)));
+        assert!(filter.is_eligible(&market(4, None)));
+        assert!(!filter.is_eligible(&market(11, None)));
+    }
+
+    #[test]
+    fn excludes_multi_outcome_when_disabled() {
+        let filter = ManifoldFilter::new(&ManifoldFilterConfig {
+            include_multi_outcome: false,
+            ..Default::default()
+        });
+        assert!(filter.is_eligible(&market(2, None)));
+        assert!(!filter.is_eligible(&market(4, None)));
+    }
+
+    #[test]
+    fn applies_liquidity_threshold() {
+        let filter = ManifoldFilter::new(&ManifoldFilterConfig {
+            min_liquidity: 500.0,
+            ..Default::default()
+        });
+        assert!(filter.is_eligible(&market(2, Some(1000.0))));
+        assert!(!filter.is_eligible(&market(2, Some(100.0))));
+    }
+}
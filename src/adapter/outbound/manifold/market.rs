@@ -0,0 +1,359 @@
+//! Manifold-specific market parser.
+//!
+//! Manifold markets are automated market makers without order books. Each
+//! outcome becomes a token, `<market>:yes` and `<market>:no` for binary
+//! markets or `<market>:<answer>` for multiple-choice markets, and its current
+//! probability is quoted as a single-level synthetic book. The detection
+//! pipeline can then treat Manifold like any order book exchange.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use super::dto::ManifoldMarket;
+use crate::domain::{
+    book::{Book, PriceLevel},
+    id::{MarketId, TokenId},
+    market::{Market, Outcome},
+    money::Price,
+};
+use crate::error::Error;
+use crate::port::outbound::exchange::{MarketInfo, MarketParser};
+
+/// Payout per winning share (M$1).
+pub const MANIFOLD_PAYOUT: Decimal = dec!(1);
+
+/// Share size quoted at each synthetic book level when none is configured.
+pub const DEFAULT_BOOK_DEPTH: Decimal = dec!(100);
+
+/// Decimal places kept when converting probabilities to prices.
+const PRICE_DECIMALS: u32 = 4;
+
+/// A tradable outcome of a Manifold market.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifoldOutcome {
+    /// Token identifier built by [`outcome_token`].
+    pub token_id: TokenId,
+    /// Outcome name ("Yes", "No", or the answer text).
+    pub name: String,
+    /// Current probability, if the API reported one.
+    pub probability: Option<f64>,
+}
+
+/// Build the outcome token identifier for one outcome of a market.
+#[must_use]
+pub fn outcome_token(market_id: &str, outcome: &str) -> TokenId {
+    TokenId::from(format!("{market_id}:{outcome}"))
+}
+
+/// Return the market an outcome token belongs to.
+///
+/// Returns `None` if the token was not built by [`outcome_token`].
+#[must_use]
+pub fn market_id_of(token_id: &TokenId) -> Option<&str> {
+    let (market_id, outcome) = token_id.as_str().split_once(':')?;
+    (!market_id.is_empty() && !outcome.is_empty()).then_some(market_id)
+}
+
+/// Convert a probability to a price, rejecting values outside `(0, 1)`.
+#[must_use]
+pub fn probability_to_price(probability: f64) -> Option<Price> {
+    if !(probability > 0.0 && probability < 1.0) {
+        return None;
+    }
+    Decimal::from_f64_retain(probability).map(|price| price.round_dp(PRICE_DECIMALS))
+}
+
+/// List the tradable outcomes of a market.
+///
+/// Binary markets yield a yes and a no outcome priced at `p` and `1 - p`.
+/// Multiple-choice markets yield one outcome per answer. Other market types
+/// yield nothing.
+#[must_use]
+pub fn market_outcomes(market: &ManifoldMarket) -> Vec<ManifoldOutcome> {
+    if market.is_binary() {
+        let yes = market.probability;
+        return vec![
+            ManifoldOutcome {
+                token_id: outcome_token(&market.id, "yes"),
+                name: "Yes".into(),
+                probability: yes,
+            },
+            ManifoldOutcome {
+                token_id: outcome_token(&market.id, "no"),
+                name: "No".into(),
+                probability: yes.map(|p| 1.0 - p),
+            },
+        ];
+    }
+
+    if market.is_multiple_choice() {
+        return market
+            .answers
+            .iter()
+            .flatten()
+            .map(|answer| ManifoldOutcome {
+                token_id: outcome_token(&market.id, &answer.id),
+                name: answer.text.clone(),
+                probability: answer.probability,
+            })
+            .collect();
+    }
+
+    Vec::new()
+}
+
+/// Build synthetic books quoting each outcome at its probability.
+///
+/// Each book has a single bid and ask level of `depth` shares. Outcomes
+/// without a usable probability are skipped.
+#[must_use]
+pub fn market_books(market: &ManifoldMarket, depth: Decimal) -> Vec<Book> {
+    market_outcomes(market)
+        .into_iter()
+        .filter_map(|outcome| {
+            let price = outcome.probability.and_then(probability_to_price)?;
+            Some(Book::with_levels(
+                outcome.token_id,
+                vec![PriceLevel::new(price, depth)],
+                vec![PriceLevel::new(price, depth)],
+            ))
+        })
+        .collect()
+}
+
+/// Manifold market parser.
+///
+/// Manifold uses:
+/// - M$1 payout per winning share
+/// - "Yes"/"No" outcome naming for binary markets
+/// - Free-form answer names for multiple-choice markets
+///
+/// # Examples
+///
+/// ```
+/// use edgelord::adapter::outbound::manifold::market::ManifoldMarketParser;
+/// use edgelord::port::outbound::exchange::MarketParser;
+/// use rust_decimal_macros::dec;
+///
+/// let parser = ManifoldMarketParser;
+/// assert_eq!(parser.name(), "manifold");
+/// assert_eq!(parser.default_payout(), dec!(1.00));
+/// assert_eq!(parser.binary_outcome_names(), ("Yes", "No"));
+/// ```
+pub struct ManifoldMarketParser;
+
+impl MarketParser for ManifoldMarketParser {
+    fn name(&self) -> &'static str {
+        "manifold"
+    }
+
+    fn default_payout(&self) -> Decimal {
+        MANIFOLD_PAYOUT
+    }
+
+    fn binary_outcome_names(&self) -> (&'static str, &'static str) {
+        ("Yes", "No")
+    }
+
+    /// Convert markets, keeping multiple-choice markets as multi-outcome markets.
+    fn parse_markets(&self, market_infos: &[MarketInfo]) -> Vec<Market> {
+        let (binary, multi): (Vec<_>, Vec<_>) = market_infos
+            .iter()
+            .cloned()
+            .partition(MarketInfo::is_binary);
+
+        let mut markets = self.parse_binary_markets(&binary);
+        markets.extend(
+            multi
+                .into_iter()
+                .filter(|info| info.outcomes.len() > 2)
+                .map(multi_outcome_market),
+        );
+        markets
+    }
+
+    fn parse_order_book(&self, raw: &str) -> Result<Vec<Book>, Error> {
+        let market: ManifoldMarket = serde_json::from_str(raw)?;
+        let books = market_books(&market, DEFAULT_BOOK_DEPTH);
+        if books.is_empty() {
+            return Err(Error::Parse(format!(
+                "manifold market {} has no priced outcomes",
+                market.id
+            )));
+        }
+        Ok(books)
+    }
+}
+
+/// Convert a multiple-choice market, keeping every answer as an outcome.
+fn multi_outcome_market(info: MarketInfo) -> Market {
+    let outcomes = info
+        .outcomes
+        .into_iter()
+        .map(|outcome| Outcome::new(TokenId::from(outcome.token_id), outcome.name))
+        .collect();
+    Market::new(
+        MarketId::from(info.id),
+        info.question,
+        outcomes,
+        MANIFOLD_PAYOUT,
+    )
+}
+
+impl ManifoldMarketParser {
+    /// Convert binary markets using the default Yes/No mapping.
+    fn parse_binary_markets(&self, market_infos: &[MarketInfo]) -> Vec<Market> {
+        let (positive_name, negative_name) = self.binary_outcome_names();
+        market_infos
+            .iter()
+            .filter_map(|info| {
+                let positive = info
+                    .outcomes
+                    .iter()
+                    .find(|outcome| self.is_positive_outcome(&outcome.name))?;
+                let negative = info
+                    .outcomes
+                    .iter()
+                    .find(|outcome| self.is_negative_outcome(&outcome.name))?;
+                let outcomes = vec![
+                    Outcome::new(TokenId::from(positive.token_id.clone()), positive_name),
+                    Outcome::new(TokenId::from(negative.token_id.clone()), negative_name),
+                ];
+                Some(Market::new(
+                    MarketId::from(info.id.clone()),
+                    info.question.clone(),
+                    outcomes,
+                    MANIFOLD_PAYOUT,
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::outbound::exchange::OutcomeInfo;
+
+    fn binary_market(probability: f64) -> ManifoldMarket {
+        serde_json::from_value(serde_json::json!({
+            "id": "bin",
+            "question": "Will it rain?",
+            "outcomeType": "BINARY",
+            "probability": probability,
+        }))
+        .unwrap()
+    }
+
+    fn multi_market() -> ManifoldMarket {
+        serde_json::from_value(serde_json::json!({
+            "id": "mc",
+            "question": "Who wins?",
+            "outcomeType": "MULTIPLE_CHOICE",
+            "answers": [
+                {"id": "a1", "text": "Alice", "probability": 0.5},
+                {"id": "a2", "text": "Bob", "probability": 0.3},
+                {"id": "a3", "text": "Carol", "probability": 0.1}
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn outcome_token_round_trips_market_id() {
+        let token = outcome_token("abc123", "yes");
+        assert_eq!(token.as_str(), "abc123:yes");
+        assert_eq!(market_id_of(&token), Some("abc123"));
+        assert_eq!(market_id_of(&TokenId::from("polymarket-token")), None);
+    }
+
+    #[test]
+    fn probability_to_price_rejects_bounds() {
+        assert_eq!(probability_to_price(0.62), Some(dec!(0.62)));
+        assert_eq!(probability_to_price(0.123456), Some(dec!(0.1235)));
+        assert!(probability_to_price(0.0).is_none());
+        assert!(probability_to_price(1.0).is_none());
+        assert!(probability_to_price(f64::NAN).is_none());
+    }
+
+    #[test]
+    fn binary_market_books_quote_yes_and_no() {
+        let books = market_books(&binary_market(0.62), dec!(50));
+
+        assert_eq!(books.len(), 2);
+        assert_eq!(books[0].token_id().as_str(), "bin:yes");
+        assert_eq!(books[0].best_ask().unwrap().price(), dec!(0.62));
+        assert_eq!(books[0].best_ask().unwrap().size(), dec!(50));
+        assert_eq!(books[1].token_id().as_str(), "bin:no");
+        assert_eq!(books[1].best_ask().unwrap().price(), dec!(0.38));
+    }
+
+    #[test]
+    fn multiple_choice_books_quote_each_answer() {
+        let books = market_books(&multi_market(), DEFAULT_BOOK_DEPTH);
+
+        let asks: Vec<_> = books
+            .iter()
+            .map(|book| book.best_ask().unwrap().price())
+            .collect();
+        assert_eq!(asks, vec![dec!(0.5), dec!(0.3), dec!(0.1)]);
+        assert_eq!(books[2].token_id().as_str(), "mc:a3");
+    }
+
+    #[test]
+    fn parse_markets_keeps_binary_and_multi_outcome() {
+        let outcome = |token: &str, name: &str| OutcomeInfo {
+            token_id: token.into(),
+            name: name.into(),
+            price: None,
+            volume_24h: None,
+            liquidity: None,
+        };
+        let info = |id: &str, outcomes: Vec<OutcomeInfo>| MarketInfo {
+            id: id.into(),
+            question: "?".into(),
+            outcomes,
+            active: true,
+            volume_24h: None,
+            liquidity: None,
+            close_time: None,
+        };
+        let infos = vec![
+            info(
+                "bin",
+                vec![outcome("bin:yes", "Yes"), outcome("bin:no", "No")],
+            ),
+            info(
+                "mc",
+                vec![
+                    outcome("mc:a1", "Alice"),
+                    outcome("mc:a2", "Bob"),
+                    outcome("mc:a3", "Carol"),
+                ],
+            ),
+        ];
+
+        let markets = ManifoldMarketParser.parse_markets(&infos);
+
+        assert_eq!(markets.len(), 2);
+        assert!(markets[0].is_binary());
+        assert_eq!(markets[1].outcome_count(), 3);
+        assert_eq!(markets[1].payout(), MANIFOLD_PAYOUT);
+    }
+
+    #[test]
+    fn parse_order_book_from_market_json() {
+        let raw = r#"{"id":"bin","outcomeType":"BINARY","probability":0.25}"#;
+
+        let books = ManifoldMarketParser.parse_order_book(raw).unwrap();
+
+        assert_eq!(books.len(), 2);
+        assert_eq!(books[1].best_ask().unwrap().price(), dec!(0.75));
+    }
+
+    #[test]
+    fn parse_order_book_rejects_unpriced_market() {
+        let raw = r#"{"id":"x","outcomeType":"POLL"}"#;
+        assert!(ManifoldMarketParser.parse_order_book(raw).is_err());
+    }
+}
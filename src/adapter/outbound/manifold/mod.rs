@@ -0,0 +1,22 @@
+//! Manifold Markets integration.
+//!
+//! Provides adapters for reading play-money market data from Manifold's
+//! public REST API, for exercising the detection pipeline end to end without
+//! risking capital. Manifold markets are automated market makers, so outcome
+//! probabilities are polled and quoted as synthetic order books. There is no
+//! order execution; Manifold always runs in detection-only mode.
+//!
+//! # Modules
+//!
+//! - [`client`] - REST API client for market discovery
+//! - [`stream`] - Polling stream emitting synthetic order books
+//! - [`market`] - Market parser, outcome tokens, and book synthesis
+//! - [`filter`] - Market eligibility filtering
+//! - [`settings`] - Configuration types for the Manifold adapter
+
+pub mod client;
+pub mod dto;
+pub mod filter;
+pub mod market;
+pub mod settings;
+pub mod stream;
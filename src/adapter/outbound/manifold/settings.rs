@@ -0,0 +1,158 @@
+//! Manifold Markets configuration.
+//!
+//! Defines configuration types for the Manifold adapter: API endpoints, the
+//! polling cadence used in place of a WebSocket order book feed, the depth of
+//! the synthetic books built from market probabilities, and market filtering.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::adapter::outbound::polymarket::settings::Environment;
+
+/// Public REST API URL.
+pub const MANIFOLD_API_URL: &str = "https://api.manifold.markets/v0";
+
+/// Public WebSocket URL, used only for connectivity checks.
+pub const MANIFOLD_WS_URL: &str = "wss://api.manifold.markets/ws";
+
+/// Market filter configuration for subscription eligibility.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ManifoldFilterConfig {
+    /// Maximum number of markets to track simultaneously.
+    #[serde(default = "default_filter_max_markets")]
+    pub max_markets: usize,
+    /// Minimum mana traded over the last 24 hours.
+    #[serde(default)]
+    pub min_volume_24h: f64,
+    /// Minimum AMM liquidity in mana.
+    #[serde(default)]
+    pub min_liquidity: f64,
+    /// Include multiple-choice markets alongside binary ones.
+    #[serde(default = "default_include_multi_outcome")]
+    pub include_multi_outcome: bool,
+    /// Maximum number of answers for multiple-choice markets.
+    #[serde(default = "default_max_outcomes")]
+    pub max_outcomes: usize,
+    /// Skip markets where any outcome is priced above this (e.g., 0.97).
+    #[serde(default)]
+    pub max_outcome_price: Option<f64>,
+    /// Skip markets that close within this many seconds.
+    #[serde(default)]
+    pub min_time_to_close_secs: Option<u64>,
+    /// Market IDs that bypass the volume floor.
+    #[serde(default)]
+    pub whitelist: Vec<String>,
+    /// Market IDs that are always skipped.
+    #[serde(default)]
+    pub blacklist: Vec<String>,
+    /// Skip markets whose question contains any of these (case-insensitive).
+    #[serde(default)]
+    pub exclude_keywords: Vec<String>,
+}
+
+const fn default_filter_max_markets() -> usize {
+    50
+}
+
+const fn default_include_multi_outcome() -> bool {
+    true
+}
+
+const fn default_max_outcomes() -> usize {
+    10
+}
+
+impl Default for ManifoldFilterConfig {
+    fn default() -> Self {
+        Self {
+            max_markets: default_filter_max_markets(),
+            min_volume_24h: 0.0,
+            min_liquidity: 0.0,
+            include_multi_outcome: default_include_multi_outcome(),
+            max_outcomes: default_max_outcomes(),
+            max_outcome_price: None,
+            min_time_to_close_secs: None,
+            whitelist: Vec::new(),
+            blacklist: Vec::new(),
+            exclude_keywords: Vec::new(),
+        }
+    }
+}
+
+/// Complete Manifold Markets configuration.
+///
+/// Manifold trades play money, so there is no separate test environment; the
+/// environment only labels the session.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ManifoldConfig {
+    /// Deployment environment label.
+    #[serde(default)]
+    pub environment: Environment,
+    /// WebSocket URL, used only for connectivity checks.
+    #[serde(default = "default_manifold_ws_url")]
+    pub ws_url: String,
+    /// REST API URL for market discovery and polling.
+    #[serde(default = "default_manifold_api_url")]
+    pub api_url: String,
+    /// HTTP request timeout in milliseconds.
+    #[serde(default = "default_http_timeout_ms")]
+    pub timeout_ms: u64,
+    /// Interval between market probability polls in milliseconds.
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+    /// Share size quoted at each synthetic book level.
+    #[serde(default = "default_book_depth")]
+    pub book_depth: Decimal,
+    /// Market eligibility filter settings.
+    #[serde(default)]
+    pub market_filter: ManifoldFilterConfig,
+}
+
+fn default_manifold_ws_url() -> String {
+    MANIFOLD_WS_URL.into()
+}
+
+fn default_manifold_api_url() -> String {
+    MANIFOLD_API_URL.into()
+}
+
+const fn default_http_timeout_ms() -> u64 {
+    5000
+}
+
+const fn default_poll_interval_ms() -> u64 {
+    10_000
+}
+
+fn default_book_depth() -> Decimal {
+    Decimal::from(100)
+}
+
+impl Default for ManifoldConfig {
+    fn default() -> Self {
+        Self {
+            environment: Environment::default(),
+            ws_url: default_manifold_ws_url(),
+            api_url: default_manifold_api_url(),
+            timeout_ms: default_http_timeout_ms(),
+            poll_interval_ms: default_poll_interval_ms(),
+            book_depth: default_book_depth(),
+            market_filter: ManifoldFilterConfig::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_target_public_api() {
+        let config: ManifoldConfig = toml::from_str("").unwrap();
+        assert_eq!(config.api_url, MANIFOLD_API_URL);
+        assert_eq!(config.ws_url, MANIFOLD_WS_URL);
+        assert_eq!(config.poll_interval_ms, 10_000);
+        assert_eq!(config.book_depth, Decimal::from(100));
+        assert!(config.market_filter.include_multi_outcome);
+    }
+}
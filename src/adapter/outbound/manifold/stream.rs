@@ -0,0 +1,219 @@
+//! Manifold polling market data stream.
+//!
+//! Manifold exposes no order book feed, so this stream polls each subscribed
+//! market over REST and emits [`MarketEvent::BookSnapshot`] events for the
+//! synthetic books built from its outcome probabilities. Polls run on a fixed
+//! interval; the first poll after `connect()` happens immediately.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use tokio::time::{sleep_until, Instant};
+use tracing::{debug, info, warn};
+
+use super::client::ManifoldClient;
+use super::market::{market_books, market_id_of};
+use super::settings::ManifoldConfig;
+use crate::domain::id::TokenId;
+use crate::error::Result;
+use crate::port::{outbound::exchange::MarketDataStream, outbound::exchange::MarketEvent};
+
+/// Manifold market data stream implementing the `MarketDataStream` trait.
+pub struct ManifoldDataStream {
+    client: ManifoldClient,
+    /// Time between polls of the subscribed markets.
+    poll_interval: Duration,
+    /// Share size quoted at each synthetic book level.
+    book_depth: Decimal,
+    /// Subscribed market IDs.
+    markets: Vec<String>,
+    /// Book events produced by a poll but not yet returned.
+    pending: VecDeque<MarketEvent>,
+    /// When the next poll may run; `None` while disconnected.
+    next_poll: Option<Instant>,
+}
+
+impl ManifoldDataStream {
+    /// Create a new data stream polling through the given client.
+    #[must_use]
+    pub fn new(client: ManifoldClient, poll_interval: Duration, book_depth: Decimal) -> Self {
+        Self {
+            client,
+            poll_interval,
+            book_depth,
+            markets: Vec::new(),
+            pending: VecDeque::new(),
+            next_poll: None,
+        }
+    }
+
+    /// Create a data stream from exchange configuration.
+    #[must_use]
+    pub fn from_config(config: &ManifoldConfig) -> Self {
+        Self::new(
+            ManifoldClient::from_config(config),
+            Duration::from_millis(config.poll_interval_ms),
+            config.book_depth,
+        )
+    }
+
+    /// Poll every subscribed market, queueing book snapshots.
+    ///
+    /// Returns an error message if every request failed.
+    async fn poll(&mut self) -> std::result::Result<(), String> {
+        let mut last_error = None;
+        let mut succeeded = false;
+
+        for market_id in &self.markets {
+            match self.client.get_market(market_id).await {
+                Ok(market) => {
+                    succeeded = true;
+                    for book in market_books(&market, self.book_depth) {
+                        self.pending.push_back(MarketEvent::BookSnapshot {
+                            token_id: book.token_id().clone(),
+                            book,
+                        });
+                    }
+                }
+                Err(e) => {
+                    warn!(market = %market_id, error = %e, "Failed to poll market");
+                    last_error = Some(e.to_string());
+                }
+            }
+        }
+
+        match last_error {
+            Some(error) if !succeeded => Err(error),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Collect the distinct market IDs behind a set of outcome tokens.
+fn market_ids(token_ids: &[TokenId]) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for token_id in token_ids {
+        match market_id_of(token_id) {
+            Some(id) if !ids.iter().any(|existing| existing == id) => ids.push(id.to_string()),
+            Some(_) => {}
+            None => warn!(token = %token_id, "Not a Manifold outcome token, skipping"),
+        }
+    }
+    ids
+}
+
+#[async_trait]
+impl MarketDataStream for ManifoldDataStream {
+    async fn connect(&mut self) -> Result<()> {
+        info!(interval = ?self.poll_interval, "Starting Manifold polling");
+        self.pending.clear();
+        self.next_poll = Some(Instant::now());
+        Ok(())
+    }
+
+    async fn subscribe(&mut self, token_ids: &[TokenId]) -> Result<()> {
+        for id in market_ids(token_ids) {
+            if !self.markets.contains(&id) {
+                self.markets.push(id);
+            }
+        }
+        info!(markets = self.markets.len(), "Subscribed to markets");
+        Ok(())
+    }
+
+    async fn resubscribe(&mut self, token_ids: &[TokenId]) -> Result<()> {
+        // Polling has no connection to rebuild; just swap the market list.
+        self.markets.clear();
+        self.subscribe(token_ids).await
+    }
+
+    async fn next_event(&mut self) -> Option<MarketEvent> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(event);
+            }
+
+            let next_poll = self.next_poll?;
+            sleep_until(next_poll).await;
+            self.next_poll = Some(Instant::now() + self.poll_interval);
+
+            if self.markets.is_empty() {
+                continue;
+            }
+            if let Err(reason) = self.poll().await {
+                self.next_poll = None;
+                return Some(MarketEvent::Disconnected { reason });
+            }
+            debug!(events = self.pending.len(), "Polled Manifold markets");
+        }
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        "Manifold"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream() -> ManifoldDataStream {
+        ManifoldDataStream::new(
+            ManifoldClient::new("https://example.com".into()),
+            Duration::from_secs(10),
+            Decimal::from(100),
+        )
+    }
+
+    #[test]
+    fn market_ids_deduplicates_outcome_tokens() {
+        let tokens = vec![
+            TokenId::from("A:yes"),
+            TokenId::from("A:no"),
+            TokenId::from("B:a1"),
+            TokenId::from("not-manifold"),
+        ];
+        assert_eq!(market_ids(&tokens), vec!["A", "B"]);
+    }
+
+    #[tokio::test]
+    async fn subscribe_accumulates_markets() {
+        let mut stream = stream();
+        stream.connect().await.unwrap();
+
+        stream
+            .subscribe(&[TokenId::from("A:yes"), TokenId::from("A:no")])
+            .await
+            .unwrap();
+        stream.subscribe(&[TokenId::from("B:a1")]).await.unwrap();
+
+        assert_eq!(stream.markets, vec!["A", "B"]);
+    }
+
+    #[tokio::test]
+    async fn resubscribe_replaces_markets() {
+        let mut stream = stream();
+        stream.connect().await.unwrap();
+        stream.subscribe(&[TokenId::from("A:yes")]).await.unwrap();
+
+        stream
+            .resubscribe(&[TokenId::from("B:a1"), TokenId::from("C:yes")])
+            .await
+            .unwrap();
+
+        assert_eq!(stream.markets, vec!["B", "C"]);
+    }
+
+    #[tokio::test]
+    async fn next_event_without_connect_ends_stream() {
+        let mut stream = stream();
+        assert!(stream.next_event().await.is_none());
+    }
+
+    #[test]
+    fn data_stream_exchange_name_returns_manifold() {
+        assert_eq!(stream().exchange_name(), "Manifold");
+    }
+}
@@ -1,9 +1,14 @@
 //! Outbound adapters (driven side).
 
 pub mod inference;
+#[cfg(feature = "kalshi")]
+pub mod kalshi;
 pub mod llm;
+#[cfg(feature = "manifold")]
+pub mod manifold;
 pub mod memory;
 pub mod notifier;
 pub mod polymarket;
+pub mod replay;
 pub mod solver;
 pub mod sqlite;
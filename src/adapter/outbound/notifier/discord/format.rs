@@ -0,0 +1,306 @@
+//! Embed formatting for Discord notifications.
+
+use serde::Serialize;
+
+use crate::adapter::outbound::notifier::text::truncate;
+use crate::port::outbound::exchange::DisconnectReason;
+use crate::port::outbound::notifier::Event;
+
+use super::notifier::DiscordConfig;
+
+/// Embed color for successful outcomes.
+pub const COLOR_SUCCESS: u32 = 0x2ECC71;
+/// Embed color for failures and halts.
+pub const COLOR_FAILURE: u32 = 0xE74C3C;
+/// Embed color for warnings such as risk rejections.
+pub const COLOR_WARNING: u32 = 0xF1C40F;
+/// Embed color for informational events.
+pub const COLOR_INFO: u32 = 0x3498DB;
+
+/// Request body for a Discord webhook.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookPayload {
+    /// Embeds to post.
+    pub embeds: Vec<Embed>,
+}
+
+/// A Discord message embed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Embed {
+    /// Embed title.
+    pub title: String,
+    /// Embed body text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Sidebar color as a 24-bit RGB value.
+    pub color: u32,
+    /// Name/value fields shown below the description.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<EmbedField>,
+}
+
+/// A single name/value field in an embed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EmbedField {
+    /// Field label.
+    pub name: String,
+    /// Field content.
+    pub value: String,
+    /// Whether the field may share a row with its neighbours.
+    pub inline: bool,
+}
+
+impl Embed {
+    fn new(title: &str, color: u32) -> Self {
+        Self {
+            title: title.to_string(),
+            description: None,
+            color,
+            fields: Vec::new(),
+        }
+    }
+
+    fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    fn field(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.fields.push(EmbedField {
+            name: name.to_string(),
+            value: value.into(),
+            inline: true,
+        });
+        self
+    }
+}
+
+/// Format an event into a Discord embed, or None if the event should be skipped.
+pub fn format_event_embed(event: &Event, config: &DiscordConfig) -> Option<Embed> {
+    match event {
+        Event::OpportunityDetected(e) if config.notify_opportunities => Some(
+            Embed::new("🎯 Opportunity Detected", COLOR_INFO)
+                .description(truncate(&e.question, 60))
+                .field(
+                    "Edge",
+                    format!("{:.2}%", e.edge * rust_decimal::Decimal::from(100)),
+                )
+                .field("Volume", format!("${:.2}", e.volume))
+                .field("Expected", format!("+${:.2}", e.expected_profit))
+                .field("Math", e.explanation.clone()),
+        ),
+        Event::NearArbitrage(e) if config.notify_opportunities => Some(
+            Embed::new("👀 Near Arbitrage", COLOR_INFO)
+                .description(truncate(&e.question, 60))
+                .field(
+                    "Edge",
+                    format!("{:.2}%", e.edge * rust_decimal::Decimal::from(100)),
+                )
+                .field("Status", "Watch only"),
+        ),
+        Event::ExecutionCompleted(e) if config.notify_executions => {
+            let (title, color) = if e.success {
+                ("✅ Trade Executed", COLOR_SUCCESS)
+            } else {
+                ("❌ Execution Failed", COLOR_FAILURE)
+            };
+            Some(
+                Embed::new(title, color)
+                    .description(e.details.clone())
+                    .field("Market", truncate(&e.market_id, 16)),
+            )
+        }
+        Event::RiskRejected(e) if config.notify_risk_rejections => Some(
+            Embed::new("⚠️ Risk Check Failed", COLOR_WARNING)
+                .description(e.reason.clone())
+                .field("Market", truncate(&e.market_id, 16)),
+        ),
+        Event::CircuitBreakerActivated { reason } => Some(
+            Embed::new("🛑 Circuit Breaker Activated", COLOR_FAILURE)
+                .description(format!("{reason}\nTrading halted")),
+        ),
+        Event::CircuitBreakerReset => Some(
+            Embed::new("✅ Circuit Breaker Reset", COLOR_SUCCESS).description("Trading resumed"),
+        ),
+        Event::StreamDisconnected { cause, reason } => Some(match cause {
+            DisconnectReason::AuthFailure => {
+                Embed::new("🔒 Market Data Auth Failed", COLOR_FAILURE).description(reason.clone())
+            }
+            DisconnectReason::Stalled => Embed::new("⏸️ Market Data Stalled", COLOR_WARNING)
+                .description(format!("{reason}\nReconnecting")),
+            _ => {
+                Embed::new("📵 Market Data Rate Limited", COLOR_WARNING).description(reason.clone())
+            }
+        }),
+        Event::InferenceDegraded { reason } => Some(
+            Embed::new("🧠 Inference Degraded", COLOR_WARNING)
+                .description(format!("{reason}\nOther strategies keep running")),
+        ),
+        Event::DailySummary(e) => Some(
+            Embed::new(&format!("📊 Daily Summary — {}", e.date), COLOR_INFO)
+                .field("Opportunities", e.opportunities_detected.to_string())
+                .field("Trades", e.trades_executed.to_string())
+                .field("Successful", e.trades_successful.to_string())
+                .field("Profit", format!("+${:.2}", e.total_profit))
+                .field("Exposure", format!("${:.2}", e.current_exposure)),
+        ),
+        Event::DrainCompleted(e) => {
+            let color = if e.failed > 0 || e.timed_out {
+                COLOR_WARNING
+            } else {
+                COLOR_SUCCESS
+            };
+            Some(
+                Embed::new("🏁 Shutdown Drain", color)
+                    .field("Positions", e.positions.to_string())
+                    .field("Closed", e.closed.to_string())
+                    .field("Failed", e.failed.to_string())
+                    .field("PnL", format!("${:.2}", e.realized_pnl))
+                    .field(
+                        "Remaining exposure",
+                        format!("${:.2}", e.remaining_exposure),
+                    )
+                    .field("Timed out", if e.timed_out { "yes" } else { "no" }),
+            )
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use rust_decimal_macros::dec;
+
+    use crate::port::outbound::notifier::{
+        ExecutionEvent, OpportunityEvent, RiskEvent, SummaryEvent,
+    };
+
+    fn test_config(notify_opportunities: bool) -> DiscordConfig {
+        DiscordConfig {
+            webhook_url: "https://discord.test/webhook".to_string(),
+            notify_opportunities,
+            notify_executions: true,
+            notify_risk_rejections: true,
+        }
+    }
+
+    fn execution(success: bool) -> Event {
+        Event::ExecutionCompleted(ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
+            market_id: "0x1234567890abcdef1234".to_string(),
+            success,
+            details: "Order filled".to_string(),
+            failure_kind: None,
+        })
+    }
+
+    #[test]
+    fn execution_color_reflects_outcome() {
+        let config = test_config(false);
+
+        let success = format_event_embed(&execution(true), &config).unwrap();
+        let failure = format_event_embed(&execution(false), &config).unwrap();
+
+        assert_eq!(success.color, COLOR_SUCCESS);
+        assert_eq!(failure.color, COLOR_FAILURE);
+        assert_eq!(failure.title, "❌ Execution Failed");
+        assert_eq!(success.fields[0].value, "0x1234567890abcd...");
+    }
+
+    #[test]
+    fn opportunity_respects_config_and_truncates_question() {
+        let event = Event::OpportunityDetected(OpportunityEvent {
+            opportunity_id: "opp-1".to_string(),
+            market_id: "m1".to_string(),
+            question: "Q".repeat(80),
+            edge: dec!(0.05),
+            volume: dec!(100),
+            expected_profit: dec!(5),
+            explanation: "Bought yes@0.45 + no@0.50 = 0.95".to_string(),
+        });
+
+        assert!(format_event_embed(&event, &test_config(false)).is_none());
+
+        let embed = format_event_embed(&event, &test_config(true)).unwrap();
+        assert_eq!(
+            embed.description.as_deref(),
+            Some(format!("{}...", "Q".repeat(60)).as_str())
+        );
+        assert_eq!(embed.fields[0].value, "5.00%");
+        assert_eq!(embed.fields[3].value, "Bought yes@0.45 + no@0.50 = 0.95");
+    }
+
+    #[test]
+    fn risk_rejection_and_circuit_breaker_embeds() {
+        let config = test_config(false);
+        let risk = Event::RiskRejected(RiskEvent {
+            opportunity_id: "opp-1".to_string(),
+            market_id: "m1".to_string(),
+            reason: "exposure limit".to_string(),
+        });
+        let activated = Event::CircuitBreakerActivated {
+            reason: "losses".to_string(),
+        };
+
+        assert_eq!(
+            format_event_embed(&risk, &config).unwrap().color,
+            COLOR_WARNING
+        );
+        assert_eq!(
+            format_event_embed(&activated, &config).unwrap().color,
+            COLOR_FAILURE
+        );
+        assert_eq!(
+            format_event_embed(&Event::CircuitBreakerReset, &config)
+                .unwrap()
+                .color,
+            COLOR_SUCCESS
+        );
+    }
+
+    #[test]
+    fn stream_disconnected_embed_color_follows_cause() {
+        let config = test_config(false);
+        let rate_limited = Event::StreamDisconnected {
+            cause: DisconnectReason::RateLimited,
+            reason: "429 Too Many Requests".to_string(),
+        };
+        let auth = Event::StreamDisconnected {
+            cause: DisconnectReason::AuthFailure,
+            reason: "401 Unauthorized".to_string(),
+        };
+
+        assert_eq!(
+            format_event_embed(&rate_limited, &config).unwrap().color,
+            COLOR_WARNING
+        );
+        assert_eq!(
+            format_event_embed(&auth, &config).unwrap().color,
+            COLOR_FAILURE
+        );
+    }
+
+    #[test]
+    fn daily_summary_serializes_as_webhook_payload() {
+        let event = Event::DailySummary(SummaryEvent {
+            date: NaiveDate::from_ymd_opt(2026, 1, 15).unwrap(),
+            opportunities_detected: 12,
+            trades_executed: 3,
+            trades_successful: 2,
+            total_profit: dec!(4.5),
+            current_exposure: dec!(100),
+        });
+        let embed = format_event_embed(&event, &test_config(false)).unwrap();
+
+        let json = serde_json::to_value(WebhookPayload {
+            embeds: vec![embed],
+        })
+        .unwrap();
+
+        assert_eq!(json["embeds"][0]["title"], "📊 Daily Summary — 2026-01-15");
+        assert_eq!(json["embeds"][0]["fields"][3]["value"], "+$4.50");
+        assert!(json["embeds"][0].get("description").is_none());
+    }
+}
@@ -0,0 +1,8 @@
+//! Discord webhook notifications.
+//!
+//! Posts trade notifications to a Discord channel as color-coded embeds
+//! through an incoming webhook.
+
+mod format;
+
+pub mod notifier;
@@ -0,0 +1,115 @@
+//! Discord webhook notifier.
+//!
+//! Provides the [`DiscordNotifier`] for posting trade notifications to a
+//! Discord channel. Spawns a background worker for outbound messages.
+//!
+//! Requires the `discord` feature to be enabled.
+
+use tokio::sync::mpsc;
+use tracing::{error, info, warn};
+
+use crate::port::{outbound::notifier::Event, outbound::notifier::Notifier};
+
+use super::format::{format_event_embed, WebhookPayload};
+
+/// Configuration for the Discord notifier.
+///
+/// Controls which events trigger notifications.
+#[derive(Debug, Clone)]
+pub struct DiscordConfig {
+    /// Incoming webhook URL for the target channel.
+    pub webhook_url: String,
+    /// Send notifications for detected opportunities (can be noisy).
+    pub notify_opportunities: bool,
+    /// Send notifications for executed trades.
+    pub notify_executions: bool,
+    /// Send notifications for risk-rejected opportunities.
+    pub notify_risk_rejections: bool,
+}
+
+impl DiscordConfig {
+    /// Create configuration from environment variables.
+    ///
+    /// Reads `DISCORD_WEBHOOK_URL` and optionally
+    /// `DISCORD_NOTIFY_OPPORTUNITIES`. Returns `None` if the webhook URL is
+    /// missing or empty.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        let webhook_url = std::env::var("DISCORD_WEBHOOK_URL")
+            .ok()
+            .filter(|url| !url.is_empty())?;
+
+        Some(Self {
+            webhook_url,
+            notify_opportunities: std::env::var("DISCORD_NOTIFY_OPPORTUNITIES")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            notify_executions: true,
+            notify_risk_rejections: true,
+        })
+    }
+}
+
+/// Discord notifier that posts embeds to a webhook.
+///
+/// Implements the [`Notifier`] trait and spawns a background worker for
+/// message delivery.
+pub struct DiscordNotifier {
+    /// Channel sender for queuing outbound notifications.
+    sender: mpsc::UnboundedSender<Event>,
+}
+
+impl DiscordNotifier {
+    /// Create a new Discord notifier and spawn the background worker.
+    #[must_use]
+    pub fn new(config: DiscordConfig) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        // Spawn background task to handle outbound notifications.
+        tokio::spawn(discord_worker(config, receiver));
+
+        Self { sender }
+    }
+
+    /// Create a notifier configured from environment variables.
+    ///
+    /// Returns `None` if `DISCORD_WEBHOOK_URL` is not set.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        DiscordConfig::from_env().map(Self::new)
+    }
+}
+
+impl Notifier for DiscordNotifier {
+    fn notify(&self, event: Event) {
+        if self.sender.send(event).is_err() {
+            warn!("Discord notifier channel closed");
+        }
+    }
+}
+
+/// Background worker that posts Discord webhook messages.
+async fn discord_worker(config: DiscordConfig, mut receiver: mpsc::UnboundedReceiver<Event>) {
+    let client = reqwest::Client::new();
+
+    info!("Discord notifier started");
+
+    while let Some(event) = receiver.recv().await {
+        let Some(embed) = format_event_embed(&event, &config) else {
+            continue;
+        };
+        let payload = WebhookPayload {
+            embeds: vec![embed],
+        };
+
+        match client.post(&config.webhook_url).json(&payload).send().await {
+            Ok(response) if !response.status().is_success() => {
+                error!(status = %response.status(), "Discord webhook rejected message");
+            }
+            Ok(_) => {}
+            Err(e) => error!(error = %e, "Failed to send Discord message"),
+        }
+    }
+
+    warn!("Discord notifier worker shutting down");
+}
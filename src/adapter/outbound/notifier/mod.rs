@@ -1,11 +1,19 @@
 //! Notification adapters.
 //!
 //! Implements the [`Notifier`](crate::port::outbound::notifier::Notifier) trait
-//! for various notification backends. Currently supports Telegram notifications
-//! when the `telegram` feature is enabled.
+//! for various notification backends. Supports Telegram notifications when
+//! the `telegram` feature is enabled, Discord webhooks when the `discord`
+//! feature is enabled, and Slack webhooks when the `slack` feature is
+//! enabled. The generic JSON webhook notifier is always available.
 
+#[cfg(feature = "discord")]
+pub mod discord;
+#[cfg(feature = "slack")]
+pub mod slack;
 #[cfg(feature = "telegram")]
 pub mod telegram;
+pub mod text;
+pub mod webhook;
 
 #[cfg(test)]
 mod tests;
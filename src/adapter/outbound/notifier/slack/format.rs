@@ -0,0 +1,413 @@
+//! Block Kit formatting for Slack notifications.
+
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+use crate::adapter::outbound::notifier::text::truncate;
+use crate::port::outbound::exchange::DisconnectReason;
+use crate::port::outbound::notifier::{Event, OpportunityEvent};
+
+use super::notifier::SlackConfig;
+
+/// Request body for a Slack incoming webhook.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Message {
+    /// Plain-text fallback shown in push notifications.
+    pub text: String,
+    /// Block Kit layout rendered in the channel.
+    pub blocks: Vec<Block>,
+}
+
+/// A Block Kit layout block.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Block {
+    /// Large bold title.
+    Header {
+        /// Title text; Slack requires plain text here.
+        text: Text,
+    },
+    /// Body text and/or a two-column grid of fields.
+    Section {
+        /// Body text.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<Text>,
+        /// Fields laid out two per row.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        fields: Vec<Text>,
+    },
+    /// Small muted text beneath the message.
+    Context {
+        /// Context elements.
+        elements: Vec<Text>,
+    },
+    /// Horizontal rule.
+    Divider,
+}
+
+/// A Block Kit text object.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum Text {
+    /// Unformatted text.
+    #[serde(rename = "plain_text")]
+    Plain {
+        /// Text content.
+        text: String,
+    },
+    /// Text rendered as Slack `mrkdwn`.
+    #[serde(rename = "mrkdwn")]
+    Markdown {
+        /// Text content.
+        text: String,
+    },
+}
+
+impl Text {
+    fn plain(text: impl Into<String>) -> Self {
+        Self::Plain { text: text.into() }
+    }
+
+    fn markdown(text: impl Into<String>) -> Self {
+        Self::Markdown { text: text.into() }
+    }
+
+    /// Bold label over a value, as used in section fields.
+    fn field(label: &str, value: &str) -> Self {
+        Self::markdown(format!("*{label}*\n{}", escape(value)))
+    }
+}
+
+/// Builder for a message: a header followed by body blocks.
+struct MessageBuilder {
+    title: String,
+    blocks: Vec<Block>,
+}
+
+impl MessageBuilder {
+    fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            blocks: vec![Block::Header {
+                text: Text::plain(title),
+            }],
+        }
+    }
+
+    /// Add a body paragraph; the text is escaped.
+    fn body(mut self, text: &str) -> Self {
+        self.blocks.push(Block::Section {
+            text: Some(Text::markdown(escape(text))),
+            fields: Vec::new(),
+        });
+        self
+    }
+
+    /// Add a grid of labelled values.
+    fn fields(mut self, fields: &[(&str, String)]) -> Self {
+        self.blocks.push(Block::Section {
+            text: None,
+            fields: fields
+                .iter()
+                .map(|(label, value)| Text::field(label, value))
+                .collect(),
+        });
+        self
+    }
+
+    /// Add a horizontal rule.
+    fn divider(mut self) -> Self {
+        self.blocks.push(Block::Divider);
+        self
+    }
+
+    /// Add a muted footnote; the text is escaped.
+    fn context(mut self, text: &str) -> Self {
+        self.blocks.push(Block::Context {
+            elements: vec![Text::markdown(escape(text))],
+        });
+        self
+    }
+
+    fn build(self) -> Message {
+        Message {
+            text: self.title,
+            blocks: self.blocks,
+        }
+    }
+}
+
+/// Escape the characters Slack treats as control sequences in `mrkdwn`.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn percent(edge: Decimal) -> String {
+    format!("{:.2}%", edge * Decimal::from(100))
+}
+
+/// Opportunity message: question, a field grid, and the arbitrage math.
+fn opportunity_message(title: &str, e: &OpportunityEvent) -> MessageBuilder {
+    MessageBuilder::new(title)
+        .body(&truncate(&e.question, 60))
+        .fields(&[
+            ("Edge", percent(e.edge)),
+            ("Volume", format!("${:.2}", e.volume)),
+            ("Expected", format!("+${:.2}", e.expected_profit)),
+            ("Market", truncate(&e.market_id, 16)),
+        ])
+}
+
+/// Format an event into a Slack message, or None if the event should be skipped.
+pub fn format_event_message(event: &Event, config: &SlackConfig) -> Option<Message> {
+    let message = match event {
+        Event::OpportunityDetected(e) if config.notify_opportunities => {
+            opportunity_message("🎯 Opportunity Detected", e).context(&e.explanation)
+        }
+        Event::NearArbitrage(e) if config.notify_opportunities => {
+            opportunity_message("👀 Near Arbitrage", e).context("Watch only")
+        }
+        Event::ExecutionCompleted(e) if config.notify_executions => {
+            let (title, status) = if e.success {
+                ("✅ Trade Executed", "Filled")
+            } else {
+                ("❌ Execution Failed", "Failed")
+            };
+            MessageBuilder::new(title)
+                .fields(&[
+                    ("Status", status.to_string()),
+                    ("Market", truncate(&e.market_id, 16)),
+                ])
+                .context(&e.details)
+        }
+        Event::RiskRejected(e) if config.notify_risk_rejections => {
+            MessageBuilder::new("⚠️ Risk Check Failed")
+                .body(&e.reason)
+                .context(&format!("Market {}", truncate(&e.market_id, 16)))
+        }
+        Event::CircuitBreakerActivated { reason } => {
+            MessageBuilder::new("🛑 Circuit Breaker Activated")
+                .body(&format!("{reason}\nTrading halted"))
+                .divider()
+                .context("Trading resumes when the circuit breaker resets")
+        }
+        Event::CircuitBreakerReset => {
+            MessageBuilder::new("✅ Circuit Breaker Reset").body("Trading resumed")
+        }
+        Event::TradingHalted { reason } => {
+            MessageBuilder::new("⏸️ Trading Halted").body(&format!("{reason}\nTrading halted"))
+        }
+        Event::StreamDisconnected { cause, reason } => match cause {
+            DisconnectReason::AuthFailure => {
+                MessageBuilder::new("🔒 Market Data Auth Failed").body(reason)
+            }
+            DisconnectReason::Stalled => MessageBuilder::new("⏸️ Market Data Stalled")
+                .body(reason)
+                .context("Reconnecting"),
+            _ => MessageBuilder::new("📵 Market Data Rate Limited").body(reason),
+        },
+        Event::InferenceDegraded { reason } => MessageBuilder::new("🧠 Inference Degraded")
+            .body(reason)
+            .context("Other strategies keep running"),
+        Event::DailySummary(e) => MessageBuilder::new(&format!("📊 Daily Summary — {}", e.date))
+            .fields(&[
+                ("Opportunities", e.opportunities_detected.to_string()),
+                ("Trades", e.trades_executed.to_string()),
+                ("Successful", e.trades_successful.to_string()),
+                ("Profit", format!("+${:.2}", e.total_profit)),
+                ("Exposure", format!("${:.2}", e.current_exposure)),
+            ]),
+        Event::DrainCompleted(e) => MessageBuilder::new("🏁 Shutdown Drain").fields(&[
+            ("Positions", e.positions.to_string()),
+            ("Closed", e.closed.to_string()),
+            ("Failed", e.failed.to_string()),
+            ("PnL", format!("${:.2}", e.realized_pnl)),
+            (
+                "Remaining exposure",
+                format!("${:.2}", e.remaining_exposure),
+            ),
+            (
+                "Timed out",
+                if e.timed_out { "yes" } else { "no" }.to_string(),
+            ),
+        ]),
+        _ => return None,
+    };
+    Some(message.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use rust_decimal_macros::dec;
+
+    use crate::port::outbound::notifier::{ExecutionEvent, RiskEvent, SummaryEvent};
+
+    fn test_config(notify_opportunities: bool) -> SlackConfig {
+        SlackConfig {
+            webhook_url: "https://hooks.slack.test/services/T/B/X".to_string(),
+            notify_opportunities,
+            notify_executions: true,
+            notify_risk_rejections: true,
+        }
+    }
+
+    fn execution(success: bool) -> Event {
+        Event::ExecutionCompleted(ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
+            market_id: "0x1234567890abcdef1234".to_string(),
+            success,
+            details: "Order filled".to_string(),
+            failure_kind: None,
+        })
+    }
+
+    fn header(message: &Message) -> &str {
+        match &message.blocks[0] {
+            Block::Header {
+                text: Text::Plain { text },
+            } => text,
+            other => panic!("expected plain header, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn opportunity_respects_config_and_shows_math() {
+        let event = Event::OpportunityDetected(OpportunityEvent {
+            opportunity_id: "opp-1".to_string(),
+            market_id: "m1".to_string(),
+            question: "Q".repeat(80),
+            edge: dec!(0.05),
+            volume: dec!(100),
+            expected_profit: dec!(5),
+            explanation: "Bought yes@0.45 + no@0.50 = 0.95".to_string(),
+        });
+
+        assert!(format_event_message(&event, &test_config(false)).is_none());
+
+        let message = format_event_message(&event, &test_config(true)).unwrap();
+        let json = serde_json::to_value(&message).unwrap();
+
+        assert_eq!(message.text, "🎯 Opportunity Detected");
+        assert_eq!(json["blocks"][0]["type"], "header");
+        assert_eq!(json["blocks"][0]["text"]["type"], "plain_text");
+        assert_eq!(
+            json["blocks"][1]["text"]["text"],
+            format!("{}...", "Q".repeat(60))
+        );
+        assert_eq!(json["blocks"][2]["fields"][0]["type"], "mrkdwn");
+        assert_eq!(json["blocks"][2]["fields"][0]["text"], "*Edge*\n5.00%");
+        assert_eq!(json["blocks"][3]["type"], "context");
+        assert_eq!(
+            json["blocks"][3]["elements"][0]["text"],
+            "Bought yes@0.45 + no@0.50 = 0.95"
+        );
+    }
+
+    #[test]
+    fn execution_title_and_status_reflect_outcome() {
+        let config = test_config(false);
+
+        let success = format_event_message(&execution(true), &config).unwrap();
+        let failure = format_event_message(&execution(false), &config).unwrap();
+
+        assert_eq!(header(&success), "✅ Trade Executed");
+        assert_eq!(header(&failure), "❌ Execution Failed");
+        assert_eq!(
+            failure.blocks[1],
+            Block::Section {
+                text: None,
+                fields: vec![
+                    Text::markdown("*Status*\nFailed"),
+                    Text::markdown("*Market*\n0x1234567890abcd..."),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn circuit_breaker_message_is_set_apart_with_divider() {
+        let activated = Event::CircuitBreakerActivated {
+            reason: "losses".to_string(),
+        };
+
+        let message = format_event_message(&activated, &test_config(false)).unwrap();
+
+        assert_eq!(header(&message), "🛑 Circuit Breaker Activated");
+        assert_eq!(
+            message.blocks[1],
+            Block::Section {
+                text: Some(Text::markdown("losses\nTrading halted")),
+                fields: Vec::new(),
+            }
+        );
+        assert_eq!(message.blocks[2], Block::Divider);
+        assert_eq!(
+            serde_json::to_value(&message.blocks[2]).unwrap(),
+            serde_json::json!({ "type": "divider" })
+        );
+    }
+
+    #[test]
+    fn risk_rejection_escapes_control_characters() {
+        let event = Event::RiskRejected(RiskEvent {
+            opportunity_id: "opp-1".to_string(),
+            market_id: "m1".to_string(),
+            reason: "exposure <limit> & more".to_string(),
+        });
+
+        let message = format_event_message(&event, &test_config(false)).unwrap();
+
+        assert_eq!(
+            message.blocks[1],
+            Block::Section {
+                text: Some(Text::markdown("exposure &lt;limit&gt; &amp; more")),
+                fields: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn stream_disconnected_title_follows_cause() {
+        let config = test_config(false);
+        let rate_limited = Event::StreamDisconnected {
+            cause: DisconnectReason::RateLimited,
+            reason: "429 Too Many Requests".to_string(),
+        };
+        let auth = Event::StreamDisconnected {
+            cause: DisconnectReason::AuthFailure,
+            reason: "401 Unauthorized".to_string(),
+        };
+
+        assert_eq!(
+            header(&format_event_message(&rate_limited, &config).unwrap()),
+            "📵 Market Data Rate Limited"
+        );
+        assert_eq!(
+            header(&format_event_message(&auth, &config).unwrap()),
+            "🔒 Market Data Auth Failed"
+        );
+    }
+
+    #[test]
+    fn daily_summary_serializes_as_webhook_payload() {
+        let event = Event::DailySummary(SummaryEvent {
+            date: NaiveDate::from_ymd_opt(2026, 1, 15).unwrap(),
+            opportunities_detected: 12,
+            trades_executed: 3,
+            trades_successful: 2,
+            total_profit: dec!(4.5),
+            current_exposure: dec!(100),
+        });
+        let message = format_event_message(&event, &test_config(false)).unwrap();
+
+        let json = serde_json::to_value(message).unwrap();
+
+        assert_eq!(json["text"], "📊 Daily Summary — 2026-01-15");
+        assert_eq!(json["blocks"][1]["fields"][3]["text"], "*Profit*\n+$4.50");
+        assert!(json["blocks"][1].get("text").is_none());
+    }
+}
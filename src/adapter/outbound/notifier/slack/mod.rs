@@ -0,0 +1,8 @@
+//! Slack webhook notifications.
+//!
+//! Posts trade notifications to a Slack channel as Block Kit messages
+//! through an incoming webhook.
+
+mod format;
+
+pub mod notifier;
@@ -0,0 +1,140 @@
+//! Slack webhook notifier.
+//!
+//! Provides the [`SlackNotifier`] for posting trade notifications to a Slack
+//! channel. Spawns a background worker for outbound messages.
+//!
+//! Requires the `slack` feature to be enabled.
+
+use tokio::sync::mpsc;
+use tracing::{error, info, warn};
+
+use crate::port::{outbound::notifier::Event, outbound::notifier::Notifier};
+
+use super::format::format_event_message;
+
+/// Configuration for the Slack notifier.
+///
+/// Controls which events trigger notifications.
+#[derive(Debug, Clone)]
+pub struct SlackConfig {
+    /// Incoming webhook URL for the target channel.
+    pub webhook_url: String,
+    /// Send notifications for detected opportunities (can be noisy).
+    pub notify_opportunities: bool,
+    /// Send notifications for executed trades.
+    pub notify_executions: bool,
+    /// Send notifications for risk-rejected opportunities.
+    pub notify_risk_rejections: bool,
+}
+
+impl SlackConfig {
+    /// Create configuration from environment variables.
+    ///
+    /// Reads `SLACK_WEBHOOK_URL` and optionally `SLACK_NOTIFY_OPPORTUNITIES`.
+    /// Returns `None` if the webhook URL is missing or empty.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        let webhook_url = std::env::var("SLACK_WEBHOOK_URL")
+            .ok()
+            .filter(|url| !url.is_empty())?;
+
+        Some(Self {
+            webhook_url,
+            notify_opportunities: std::env::var("SLACK_NOTIFY_OPPORTUNITIES")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            notify_executions: true,
+            notify_risk_rejections: true,
+        })
+    }
+}
+
+/// Slack notifier that posts Block Kit messages to a webhook.
+///
+/// Implements the [`Notifier`] trait and spawns a background worker for
+/// message delivery.
+pub struct SlackNotifier {
+    /// Channel sender for queuing outbound notifications.
+    sender: mpsc::UnboundedSender<Event>,
+}
+
+impl SlackNotifier {
+    /// Create a new Slack notifier and spawn the background worker.
+    #[must_use]
+    pub fn new(config: SlackConfig) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        // Spawn background task to handle outbound notifications.
+        tokio::spawn(slack_worker(config, receiver));
+
+        Self { sender }
+    }
+
+    /// Create a notifier configured from environment variables.
+    ///
+    /// Returns `None` if `SLACK_WEBHOOK_URL` is not set.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        SlackConfig::from_env().map(Self::new)
+    }
+}
+
+impl Notifier for SlackNotifier {
+    fn notify(&self, event: Event) {
+        if self.sender.send(event).is_err() {
+            warn!("Slack notifier channel closed");
+        }
+    }
+}
+
+/// Background worker that posts Slack webhook messages.
+async fn slack_worker(config: SlackConfig, mut receiver: mpsc::UnboundedReceiver<Event>) {
+    let client = reqwest::Client::new();
+
+    info!("Slack notifier started");
+
+    while let Some(event) = receiver.recv().await {
+        let Some(message) = format_event_message(&event, &config) else {
+            continue;
+        };
+
+        match client.post(&config.webhook_url).json(&message).send().await {
+            Ok(response) if !response.status().is_success() => {
+                error!(status = %response.status(), "Slack webhook rejected message");
+            }
+            Ok(_) => {}
+            Err(e) => error!(error = %e, "Failed to send Slack message"),
+        }
+    }
+
+    warn!("Slack notifier worker shutting down");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Both cases share one test so they never race on the process environment.
+    #[test]
+    fn from_env_requires_webhook_url() {
+        std::env::remove_var("SLACK_WEBHOOK_URL");
+        assert!(SlackConfig::from_env().is_none());
+
+        std::env::set_var(
+            "SLACK_WEBHOOK_URL",
+            "https://hooks.slack.test/services/T/B/X",
+        );
+        std::env::set_var("SLACK_NOTIFY_OPPORTUNITIES", "1");
+        let config = SlackConfig::from_env().unwrap();
+        std::env::remove_var("SLACK_WEBHOOK_URL");
+        std::env::remove_var("SLACK_NOTIFY_OPPORTUNITIES");
+
+        assert_eq!(
+            config.webhook_url,
+            "https://hooks.slack.test/services/T/B/X"
+        );
+        assert!(config.notify_opportunities);
+        assert!(config.notify_executions);
+        assert!(config.notify_risk_rejections);
+    }
+}
@@ -13,15 +13,15 @@ use super::control::TelegramControl;
 /// - Messages that are not commands (don't start with `/`)
 ///
 /// Returns `Some(response)` for:
-/// - Valid commands from the authorized chat
+/// - Valid commands from any authorized chat
 /// - Invalid commands (with error message and help)
 pub fn command_response_for_message(
     text: &str,
     incoming_chat: ChatId,
-    allowed_chat: ChatId,
+    allowed_chats: &[ChatId],
     control: &TelegramControl,
 ) -> Option<String> {
-    if !is_authorized_chat(incoming_chat, allowed_chat) {
+    if !is_authorized_chat(incoming_chat, allowed_chats) {
         return None;
     }
 
@@ -33,8 +33,8 @@ pub fn command_response_for_message(
 }
 
 /// Check if a chat is authorized to send commands.
-fn is_authorized_chat(incoming_chat: ChatId, allowed_chat: ChatId) -> bool {
-    if incoming_chat == allowed_chat {
+fn is_authorized_chat(incoming_chat: ChatId, allowed_chats: &[ChatId]) -> bool {
+    if allowed_chats.contains(&incoming_chat) {
         return true;
     }
 
@@ -48,9 +48,10 @@ fn is_authorized_chat(incoming_chat: ChatId, allowed_chat: ChatId) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::trading::TradingState;
     use crate::port::inbound::runtime::{
-        RuntimePosition, RuntimeRiskLimitKind, RuntimeRiskLimitUpdateError, RuntimeRiskLimits,
-        RuntimeState,
+        RuntimeEvent, RuntimePosition, RuntimeRiskLimitKind, RuntimeRiskLimitUpdateError,
+        RuntimeRiskLimits, RuntimeState,
     };
     use parking_lot::RwLock;
     use rust_decimal_macros::dec;
@@ -59,7 +60,7 @@ mod tests {
     #[derive(Debug)]
     struct MockRuntimeState {
         limits: RwLock<RuntimeRiskLimits>,
-        breaker_reason: RwLock<Option<String>>,
+        trading: RwLock<TradingState>,
     }
 
     impl Default for MockRuntimeState {
@@ -71,7 +72,7 @@ mod tests {
                     min_profit_threshold: dec!(0.2),
                     max_slippage: dec!(0.05),
                 }),
-                breaker_reason: RwLock::new(None),
+                trading: RwLock::new(TradingState::Active),
             }
         }
     }
@@ -98,20 +99,16 @@ mod tests {
             Ok(limits.clone())
         }
 
-        fn is_circuit_breaker_active(&self) -> bool {
-            self.breaker_reason.read().is_some()
+        fn trading_state(&self) -> TradingState {
+            self.trading.read().clone()
         }
 
-        fn circuit_breaker_reason(&self) -> Option<String> {
-            self.breaker_reason.read().clone()
+        fn halt(&self, reason: &str) {
+            *self.trading.write() = TradingState::halted(reason);
         }
 
-        fn activate_circuit_breaker(&self, reason: &str) {
-            *self.breaker_reason.write() = Some(reason.to_string());
-        }
-
-        fn reset_circuit_breaker(&self) {
-            *self.breaker_reason.write() = None;
+        fn resume(&self) {
+            *self.trading.write() = TradingState::Active;
         }
 
         fn open_position_count(&self) -> usize {
@@ -133,6 +130,10 @@ mod tests {
         fn active_positions(&self) -> Vec<RuntimePosition> {
             Vec::new()
         }
+
+        fn recent_events(&self, _limit: usize) -> Vec<RuntimeEvent> {
+            Vec::new()
+        }
     }
 
     fn as_runtime(state: Arc<MockRuntimeState>) -> Arc<dyn RuntimeState> {
@@ -149,7 +150,7 @@ mod tests {
         let control = TelegramControl::new(as_runtime(state));
         let chat = ChatId(42);
 
-        let response = command_response_for_message("/status", chat, chat, &control).unwrap();
+        let response = command_response_for_message("/status", chat, &[chat], &control).unwrap();
         assert!(response.contains("Status"));
     }
 
@@ -158,10 +159,23 @@ mod tests {
         let state = Arc::new(MockRuntimeState::default());
         let control = TelegramControl::new(as_runtime(state));
 
-        let response = command_response_for_message("/status", ChatId(7), ChatId(42), &control);
+        let response = command_response_for_message("/status", ChatId(7), &[ChatId(42)], &control);
         assert!(response.is_none());
     }
 
+    #[test]
+    fn test_command_response_accepts_any_listed_chat() {
+        let state = Arc::new(MockRuntimeState::default());
+        let control = TelegramControl::new(as_runtime(state));
+        let allowed = [ChatId(42), ChatId(-100123)];
+
+        assert!(command_response_for_message("/status", ChatId(42), &allowed, &control).is_some());
+        assert!(
+            command_response_for_message("/status", ChatId(-100123), &allowed, &control).is_some()
+        );
+        assert!(command_response_for_message("/status", ChatId(7), &allowed, &control).is_none());
+    }
+
     #[test]
     fn test_authorization_with_various_chat_ids() {
         let state = Arc::new(MockRuntimeState::default());
@@ -169,14 +183,16 @@ mod tests {
         let allowed = ChatId(12345);
 
         // Same chat ID should be authorized
-        assert!(command_response_for_message("/status", allowed, allowed, &control).is_some());
+        assert!(command_response_for_message("/status", allowed, &[allowed], &control).is_some());
 
         // Different chat IDs should be rejected
-        assert!(command_response_for_message("/status", ChatId(1), allowed, &control).is_none());
-        assert!(command_response_for_message("/status", ChatId(0), allowed, &control).is_none());
-        assert!(command_response_for_message("/status", ChatId(-100), allowed, &control).is_none());
+        assert!(command_response_for_message("/status", ChatId(1), &[allowed], &control).is_none());
+        assert!(command_response_for_message("/status", ChatId(0), &[allowed], &control).is_none());
+        assert!(
+            command_response_for_message("/status", ChatId(-100), &[allowed], &control).is_none()
+        );
         assert!(
-            command_response_for_message("/status", ChatId(99999), allowed, &control).is_none()
+            command_response_for_message("/status", ChatId(99999), &[allowed], &control).is_none()
         );
     }
 
@@ -188,11 +204,11 @@ mod tests {
         let allowed = ChatId(-123456789);
 
         // Same negative chat ID should be authorized
-        assert!(command_response_for_message("/status", allowed, allowed, &control).is_some());
+        assert!(command_response_for_message("/status", allowed, &[allowed], &control).is_some());
 
         // Different negative chat ID should be rejected
         assert!(
-            command_response_for_message("/status", ChatId(-987654321), allowed, &control)
+            command_response_for_message("/status", ChatId(-987654321), &[allowed], &control)
                 .is_none()
         );
     }
@@ -207,7 +223,7 @@ mod tests {
         let control = TelegramControl::new(as_runtime(state));
         let chat = ChatId(42);
 
-        let response = command_response_for_message("/bad", chat, chat, &control).unwrap();
+        let response = command_response_for_message("/bad", chat, &[chat], &control).unwrap();
         assert!(response.contains("Invalid command"));
         assert!(response.contains("Commands"));
     }
@@ -218,7 +234,7 @@ mod tests {
         let control = TelegramControl::new(as_runtime(state));
         let chat = ChatId(42);
 
-        let response = command_response_for_message("hello", chat, chat, &control);
+        let response = command_response_for_message("hello", chat, &[chat], &control);
         assert!(response.is_none());
     }
 
@@ -228,7 +244,7 @@ mod tests {
         let control = TelegramControl::new(as_runtime(state));
         let chat = ChatId(42);
 
-        let response = command_response_for_message("", chat, chat, &control);
+        let response = command_response_for_message("", chat, &[chat], &control);
         assert!(response.is_none());
     }
 
@@ -238,7 +254,7 @@ mod tests {
         let control = TelegramControl::new(as_runtime(state));
         let chat = ChatId(42);
 
-        let response = command_response_for_message("   ", chat, chat, &control);
+        let response = command_response_for_message("   ", chat, &[chat], &control);
         assert!(response.is_none());
     }
 
@@ -253,25 +269,25 @@ mod tests {
         let chat = ChatId(42);
 
         // Basic info commands
-        assert!(command_response_for_message("/start", chat, chat, &control).is_some());
-        assert!(command_response_for_message("/help", chat, chat, &control).is_some());
-        assert!(command_response_for_message("/status", chat, chat, &control).is_some());
-        assert!(command_response_for_message("/health", chat, chat, &control).is_some());
-        assert!(command_response_for_message("/positions", chat, chat, &control).is_some());
-        assert!(command_response_for_message("/version", chat, chat, &control).is_some());
+        assert!(command_response_for_message("/start", chat, &[chat], &control).is_some());
+        assert!(command_response_for_message("/help", chat, &[chat], &control).is_some());
+        assert!(command_response_for_message("/status", chat, &[chat], &control).is_some());
+        assert!(command_response_for_message("/health", chat, &[chat], &control).is_some());
+        assert!(command_response_for_message("/positions", chat, &[chat], &control).is_some());
+        assert!(command_response_for_message("/version", chat, &[chat], &control).is_some());
 
         // Stats commands (may show "not available" without recorder)
-        assert!(command_response_for_message("/stats", chat, chat, &control).is_some());
-        assert!(command_response_for_message("/pool", chat, chat, &control).is_some());
-        assert!(command_response_for_message("/markets", chat, chat, &control).is_some());
+        assert!(command_response_for_message("/stats", chat, &[chat], &control).is_some());
+        assert!(command_response_for_message("/pool", chat, &[chat], &control).is_some());
+        assert!(command_response_for_message("/markets", chat, &[chat], &control).is_some());
 
         // Control commands
-        assert!(command_response_for_message("/pause", chat, chat, &control).is_some());
-        assert!(command_response_for_message("/resume", chat, chat, &control).is_some());
+        assert!(command_response_for_message("/pause", chat, &[chat], &control).is_some());
+        assert!(command_response_for_message("/resume", chat, &[chat], &control).is_some());
 
         // Set risk command
         assert!(
-            command_response_for_message("/set_risk min_profit 0.5", chat, chat, &control)
+            command_response_for_message("/set_risk min_profit 0.5", chat, &[chat], &control)
                 .is_some()
         );
     }
@@ -286,7 +302,7 @@ mod tests {
         let control = TelegramControl::new(as_runtime(state));
         let chat = ChatId(42);
 
-        let response = command_response_for_message("/unknown", chat, chat, &control).unwrap();
+        let response = command_response_for_message("/unknown", chat, &[chat], &control).unwrap();
         assert!(response.contains("Invalid command"));
         assert!(response.contains("unknown command"));
         assert!(response.contains("/status")); // Help should be included
@@ -298,7 +314,7 @@ mod tests {
         let control = TelegramControl::new(as_runtime(state));
         let chat = ChatId(42);
 
-        let response = command_response_for_message("/set_risk", chat, chat, &control).unwrap();
+        let response = command_response_for_message("/set_risk", chat, &[chat], &control).unwrap();
         assert!(response.contains("Invalid command"));
         assert!(response.contains("missing argument"));
     }
@@ -310,7 +326,8 @@ mod tests {
         let chat = ChatId(42);
 
         let response =
-            command_response_for_message("/set_risk bad_field 1.0", chat, chat, &control).unwrap();
+            command_response_for_message("/set_risk bad_field 1.0", chat, &[chat], &control)
+                .unwrap();
         assert!(response.contains("Invalid command"));
         assert!(response.contains("invalid risk field"));
     }
@@ -322,7 +339,8 @@ mod tests {
         let chat = ChatId(42);
 
         let response =
-            command_response_for_message("/set_risk min_profit abc", chat, chat, &control).unwrap();
+            command_response_for_message("/set_risk min_profit abc", chat, &[chat], &control)
+                .unwrap();
         assert!(response.contains("Invalid command"));
         assert!(response.contains("invalid decimal"));
     }
@@ -338,7 +356,7 @@ mod tests {
         let chat = ChatId(42);
 
         let response =
-            command_response_for_message("/status@my_bot", chat, chat, &control).unwrap();
+            command_response_for_message("/status@my_bot", chat, &[chat], &control).unwrap();
         assert!(response.contains("Status"));
     }
 
@@ -348,7 +366,7 @@ mod tests {
         let control = TelegramControl::new(as_runtime(state));
 
         let response =
-            command_response_for_message("/status@my_bot", ChatId(7), ChatId(42), &control);
+            command_response_for_message("/status@my_bot", ChatId(7), &[ChatId(42)], &control);
         assert!(response.is_none());
     }
 
@@ -363,7 +381,7 @@ mod tests {
         let chat = ChatId(42);
 
         // Just a slash
-        let response = command_response_for_message("/", chat, chat, &control);
+        let response = command_response_for_message("/", chat, &[chat], &control);
         assert!(response.is_some()); // Should be "unknown command"
         assert!(response.unwrap().contains("Invalid command"));
     }
@@ -375,7 +393,8 @@ mod tests {
         let chat = ChatId(42);
 
         // Leading/trailing whitespace
-        let response = command_response_for_message("  /status  ", chat, chat, &control).unwrap();
+        let response =
+            command_response_for_message("  /status  ", chat, &[chat], &control).unwrap();
         assert!(response.contains("Status"));
     }
 
@@ -386,7 +405,7 @@ mod tests {
         let chat = ChatId(42);
 
         // Uppercase should be treated as unknown command
-        let response = command_response_for_message("/STATUS", chat, chat, &control).unwrap();
+        let response = command_response_for_message("/STATUS", chat, &[chat], &control).unwrap();
         assert!(response.contains("Invalid command"));
         assert!(response.contains("unknown command"));
     }
@@ -403,8 +422,8 @@ mod tests {
 
         // Execute multiple commands - they should all work
         for _ in 0..10 {
-            assert!(command_response_for_message("/status", chat, chat, &control).is_some());
-            assert!(command_response_for_message("/health", chat, chat, &control).is_some());
+            assert!(command_response_for_message("/status", chat, &[chat], &control).is_some());
+            assert!(command_response_for_message("/health", chat, &[chat], &control).is_some());
         }
     }
 }
@@ -0,0 +1,218 @@
+//! Telegram chat targets and per-chat event filtering.
+//!
+//! Chat lists are parsed from a comma-separated string where each entry is a
+//! chat ID optionally followed by `:` and a `+`-separated list of event kinds:
+//!
+//! ```text
+//! -100111,-100222:opportunities,-100333:executions+risk
+//! ```
+//!
+//! Entries without a filter receive every event.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::port::outbound::notifier::Event;
+
+/// Category of notification event used for per-chat filtering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatEventKind {
+    /// Detected opportunities.
+    Opportunities,
+    /// Completed executions.
+    Executions,
+    /// Risk-rejected opportunities.
+    Risk,
+    /// Circuit breaker and trading halt alerts.
+    Alerts,
+    /// Daily summaries.
+    Summaries,
+    /// Discovered market relations.
+    Relations,
+}
+
+impl ChatEventKind {
+    /// Return the kind an event belongs to.
+    #[must_use]
+    pub const fn of(event: &Event) -> Self {
+        match event {
+            Event::OpportunityDetected(_) | Event::NearArbitrage(_) => Self::Opportunities,
+            Event::ExecutionCompleted(_) => Self::Executions,
+            Event::RiskRejected(_) => Self::Risk,
+            Event::CircuitBreakerActivated { .. }
+            | Event::CircuitBreakerReset
+            | Event::TradingHalted { .. }
+            | Event::StreamDisconnected { .. }
+            | Event::InferenceDegraded { .. } => Self::Alerts,
+            Event::DailySummary(_) | Event::DrainCompleted(_) => Self::Summaries,
+            Event::RelationsDiscovered(_) => Self::Relations,
+        }
+    }
+}
+
+impl FromStr for ChatEventKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "opportunities" => Ok(Self::Opportunities),
+            "executions" => Ok(Self::Executions),
+            "risk" => Ok(Self::Risk),
+            "alerts" => Ok(Self::Alerts),
+            "summaries" => Ok(Self::Summaries),
+            "relations" => Ok(Self::Relations),
+            other => Err(format!("unknown event kind '{other}'")),
+        }
+    }
+}
+
+/// A chat that receives notifications and may issue commands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelegramChat {
+    /// Telegram chat ID (negative for groups and channels).
+    pub id: i64,
+    /// Event kinds delivered to this chat, or `None` for all events.
+    pub events: Option<Vec<ChatEventKind>>,
+}
+
+impl TelegramChat {
+    /// Create a chat that receives every event.
+    #[must_use]
+    pub const fn new(id: i64) -> Self {
+        Self { id, events: None }
+    }
+
+    /// Restrict the chat to the given event kinds.
+    #[must_use]
+    pub fn with_events(mut self, events: Vec<ChatEventKind>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Check whether this chat should receive the given event.
+    #[must_use]
+    pub fn accepts(&self, event: &Event) -> bool {
+        match &self.events {
+            Some(events) => events.contains(&ChatEventKind::of(event)),
+            None => true,
+        }
+    }
+}
+
+impl fmt::Display for TelegramChat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+impl FromStr for TelegramChat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, events) = match s.split_once(':') {
+            Some((id, events)) => (id, Some(events)),
+            None => (s, None),
+        };
+
+        let id: i64 = id
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid chat ID '{}'", id.trim()))?;
+        let chat = Self::new(id);
+
+        match events {
+            Some(events) => {
+                let events = events
+                    .split('+')
+                    .map(str::parse)
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(chat.with_events(events))
+            }
+            None => Ok(chat),
+        }
+    }
+}
+
+/// Parse a comma-separated chat list, ignoring empty entries.
+///
+/// # Errors
+///
+/// Returns an error if any entry has an invalid chat ID or event kind.
+pub fn parse_chat_list(s: &str) -> Result<Vec<TelegramChat>, String> {
+    s.split(',')
+        .filter(|entry| !entry.trim().is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::outbound::notifier::{ExecutionEvent, RiskEvent};
+
+    fn execution_event() -> Event {
+        Event::ExecutionCompleted(ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
+            market_id: "market".to_string(),
+            success: true,
+            details: "filled".to_string(),
+            failure_kind: None,
+        })
+    }
+
+    #[test]
+    fn parse_chat_list_plain_ids() {
+        let chats = parse_chat_list("12345, -100987").unwrap();
+
+        assert_eq!(
+            chats,
+            vec![TelegramChat::new(12345), TelegramChat::new(-100987)]
+        );
+    }
+
+    #[test]
+    fn parse_chat_list_with_filters() {
+        let chats = parse_chat_list("1:opportunities,-2:executions+risk").unwrap();
+
+        assert_eq!(chats[0].events, Some(vec![ChatEventKind::Opportunities]));
+        assert_eq!(
+            chats[1].events,
+            Some(vec![ChatEventKind::Executions, ChatEventKind::Risk])
+        );
+    }
+
+    #[test]
+    fn parse_chat_list_skips_empty_entries() {
+        let chats = parse_chat_list("1,,2,").unwrap();
+
+        assert_eq!(chats.len(), 2);
+    }
+
+    #[test]
+    fn parse_chat_list_rejects_invalid_entries() {
+        assert!(parse_chat_list("abc").is_err());
+        assert!(parse_chat_list("1:unknown").is_err());
+        assert!(parse_chat_list("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn unfiltered_chat_accepts_every_event() {
+        let chat = TelegramChat::new(1);
+
+        assert!(chat.accepts(&execution_event()));
+        assert!(chat.accepts(&Event::CircuitBreakerReset));
+    }
+
+    #[test]
+    fn filtered_chat_accepts_only_listed_kinds() {
+        let chat = TelegramChat::new(1).with_events(vec![ChatEventKind::Executions]);
+
+        assert!(chat.accepts(&execution_event()));
+        assert!(!chat.accepts(&Event::RiskRejected(RiskEvent {
+            opportunity_id: "opp-1".to_string(),
+            market_id: "market".to_string(),
+            reason: "limit".to_string(),
+        })));
+        assert!(!chat.accepts(&Event::CircuitBreakerReset));
+    }
+}
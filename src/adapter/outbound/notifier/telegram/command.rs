@@ -24,8 +24,23 @@ pub enum TelegramCommand {
         kind: RuntimeRiskLimitKind,
         value: Decimal,
     },
+    Strategies,
+    SetStrategy {
+        name: String,
+        enabled: bool,
+    },
+    Recent {
+        limit: usize,
+    },
 }
 
+/// Events listed by `/recent` when no count is given.
+pub const DEFAULT_RECENT_LIMIT: usize = 10;
+
+/// Most events listed by a single `/recent`, keeping replies within
+/// Telegram's message size limit.
+pub const MAX_RECENT_LIMIT: usize = 50;
+
 /// Parse error for Telegram command messages.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CommandParseError {
@@ -34,6 +49,8 @@ pub enum CommandParseError {
     MissingArgument(&'static str),
     InvalidRiskField(String),
     InvalidDecimal(String),
+    InvalidToggle(String),
+    InvalidCount(String),
 }
 
 impl std::fmt::Display for CommandParseError {
@@ -47,6 +64,11 @@ impl std::fmt::Display for CommandParseError {
                 "invalid risk field `{field}` (use: min_profit, max_slippage, max_position, max_exposure)"
             ),
             Self::InvalidDecimal(value) => write!(f, "invalid decimal value `{value}`"),
+            Self::InvalidToggle(value) => write!(f, "invalid state `{value}` (use: on, off)"),
+            Self::InvalidCount(value) => write!(
+                f,
+                "invalid count `{value}` (use: 1 to {MAX_RECENT_LIMIT})"
+            ),
         }
     }
 }
@@ -67,19 +89,32 @@ pub fn parse_command(text: &str) -> Result<TelegramCommand, CommandParseError> {
         .split_once('@')
         .map_or(raw_command, |(head, _)| head);
 
-    match command {
-        "/start" => Ok(TelegramCommand::Start),
-        "/help" => Ok(TelegramCommand::Help),
-        "/status" => Ok(TelegramCommand::Status),
-        "/health" => Ok(TelegramCommand::Health),
-        "/positions" => Ok(TelegramCommand::Positions),
-        "/stats" => Ok(TelegramCommand::Stats),
-        "/pool" => Ok(TelegramCommand::Pool),
-        "/markets" => Ok(TelegramCommand::Markets),
-        "/version" => Ok(TelegramCommand::Version),
-        "/pause" => Ok(TelegramCommand::Pause),
-        "/resume" => Ok(TelegramCommand::Resume),
-        "/set_risk" => {
+    parse_invocation(&command[1..], parts).map_err(|err| match err {
+        CommandParseError::UnknownCommand(_) => CommandParseError::UnknownCommand(command.into()),
+        other => other,
+    })
+}
+
+/// Parse a command from its name, without the leading slash, and arguments.
+///
+/// Shared by every control front-end so each accepts the same commands.
+pub fn parse_invocation<'a>(
+    name: &str,
+    mut parts: impl Iterator<Item = &'a str>,
+) -> Result<TelegramCommand, CommandParseError> {
+    match name {
+        "start" => Ok(TelegramCommand::Start),
+        "help" => Ok(TelegramCommand::Help),
+        "status" => Ok(TelegramCommand::Status),
+        "health" => Ok(TelegramCommand::Health),
+        "positions" => Ok(TelegramCommand::Positions),
+        "stats" => Ok(TelegramCommand::Stats),
+        "pool" => Ok(TelegramCommand::Pool),
+        "markets" => Ok(TelegramCommand::Markets),
+        "version" => Ok(TelegramCommand::Version),
+        "pause" => Ok(TelegramCommand::Pause),
+        "resume" => Ok(TelegramCommand::Resume),
+        "set_risk" => {
             let raw_field = parts
                 .next()
                 .ok_or(CommandParseError::MissingArgument("field"))?;
@@ -93,6 +128,38 @@ pub fn parse_command(text: &str) -> Result<TelegramCommand, CommandParseError> {
 
             Ok(TelegramCommand::SetRisk { kind, value })
         }
+        "strategies" => Ok(TelegramCommand::Strategies),
+        "strategy" => {
+            let name = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("name"))?;
+            let raw_state = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("state"))?;
+
+            let enabled = match raw_state {
+                "on" => true,
+                "off" => false,
+                other => return Err(CommandParseError::InvalidToggle(other.to_string())),
+            };
+
+            Ok(TelegramCommand::SetStrategy {
+                name: name.to_string(),
+                enabled,
+            })
+        }
+        "recent" => {
+            let limit = match parts.next() {
+                None => DEFAULT_RECENT_LIMIT,
+                Some(raw) => raw
+                    .parse()
+                    .ok()
+                    .filter(|limit| (1..=MAX_RECENT_LIMIT).contains(limit))
+                    .ok_or_else(|| CommandParseError::InvalidCount(raw.to_string()))?,
+            };
+
+            Ok(TelegramCommand::Recent { limit })
+        }
         other => Err(CommandParseError::UnknownCommand(other.to_string())),
     }
 }
@@ -122,7 +189,10 @@ pub const fn command_help() -> &'static str {
     /version - 🔖 Build version\n\
     /pause - ⏸️ Halt trading\n\
     /resume - ▶️ Resume trading\n\
-    /set_risk <field> <value> - ⚙️ Update risk limit\n\n\
+    /set_risk <field> <value> - ⚙️ Update risk limit\n\
+    /strategies - 🧠 Detection strategies and their state\n\
+    /strategy <name> on|off - 🔀 Enable or disable a strategy\n\
+    /recent [n] - 🕘 Recent events (default 10)\n\n\
     Risk fields: min_profit, max_slippage, max_position, max_exposure"
 }
 
@@ -142,6 +212,9 @@ pub fn bot_commands() -> Vec<(&'static str, &'static str)> {
         ("pause", "Halt trading"),
         ("resume", "Resume trading"),
         ("set_risk", "Update risk limit"),
+        ("strategies", "Detection strategies and their state"),
+        ("strategy", "Enable or disable a strategy"),
+        ("recent", "Recent opportunities, executions, and rejections"),
         ("help", "Show all commands"),
     ]
 }
@@ -467,6 +540,18 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parse_invocation_takes_name_without_slash() {
+        assert_eq!(
+            parse_invocation("recent", ["5"].into_iter()).unwrap(),
+            TelegramCommand::Recent { limit: 5 }
+        );
+        assert!(matches!(
+            parse_invocation("launch", std::iter::empty()),
+            Err(CommandParseError::UnknownCommand(ref cmd)) if cmd == "launch"
+        ));
+    }
+
     #[test]
     fn parse_unknown_command() {
         let err = parse_command("/unknown").unwrap_err();
@@ -552,6 +637,84 @@ mod tests {
         );
     }
 
+    // -------------------------------------------------------------------------
+    // Strategy toggle parsing
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn parse_strategies() {
+        assert_eq!(
+            parse_command("/strategies").unwrap(),
+            TelegramCommand::Strategies
+        );
+    }
+
+    #[test]
+    fn parse_strategy_on_and_off() {
+        assert_eq!(
+            parse_command("/strategy combinatorial off").unwrap(),
+            TelegramCommand::SetStrategy {
+                name: "combinatorial".to_string(),
+                enabled: false,
+            }
+        );
+        assert_eq!(
+            parse_command("/strategy@edgelord_bot single_condition on").unwrap(),
+            TelegramCommand::SetStrategy {
+                name: "single_condition".to_string(),
+                enabled: true,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_strategy_missing_arguments() {
+        assert!(matches!(
+            parse_command("/strategy"),
+            Err(CommandParseError::MissingArgument("name"))
+        ));
+        assert!(matches!(
+            parse_command("/strategy combinatorial"),
+            Err(CommandParseError::MissingArgument("state"))
+        ));
+    }
+
+    #[test]
+    fn parse_strategy_invalid_state() {
+        assert_eq!(
+            parse_command("/strategy combinatorial maybe"),
+            Err(CommandParseError::InvalidToggle("maybe".to_string()))
+        );
+    }
+
+    // -------------------------------------------------------------------------
+    // Recent events parsing
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn parse_recent_defaults_limit() {
+        assert_eq!(
+            parse_command("/recent").unwrap(),
+            TelegramCommand::Recent {
+                limit: DEFAULT_RECENT_LIMIT
+            }
+        );
+        assert_eq!(
+            parse_command("/recent@edgelord_bot 25").unwrap(),
+            TelegramCommand::Recent { limit: 25 }
+        );
+    }
+
+    #[test]
+    fn parse_recent_rejects_invalid_counts() {
+        for raw in ["0", "51", "-1", "many"] {
+            assert_eq!(
+                parse_command(&format!("/recent {raw}")),
+                Err(CommandParseError::InvalidCount(raw.to_string()))
+            );
+        }
+    }
+
     // -------------------------------------------------------------------------
     // Bot commands registration
     // -------------------------------------------------------------------------
@@ -580,6 +743,9 @@ mod tests {
             "pause",
             "resume",
             "set_risk",
+            "strategies",
+            "strategy",
+            "recent",
             "help",
         ];
 
@@ -620,6 +786,9 @@ mod tests {
         assert!(help.contains("/pause"));
         assert!(help.contains("/resume"));
         assert!(help.contains("/set_risk"));
+        assert!(help.contains("/strategies"));
+        assert!(help.contains("/strategy <name> on|off"));
+        assert!(help.contains("/recent [n]"));
     }
 
     #[test]
@@ -661,5 +830,15 @@ mod tests {
             CommandParseError::InvalidDecimal("xyz".to_string()).to_string(),
             "invalid decimal value `xyz`"
         );
+
+        assert_eq!(
+            CommandParseError::InvalidToggle("maybe".to_string()).to_string(),
+            "invalid state `maybe` (use: on, off)"
+        );
+
+        assert_eq!(
+            CommandParseError::InvalidCount("0".to_string()).to_string(),
+            "invalid count `0` (use: 1 to 50)"
+        );
     }
 }
@@ -6,9 +6,11 @@ use std::sync::Arc;
 use chrono::Utc;
 use parking_lot::RwLock;
 
+use crate::domain::stats::LatencyPercentiles;
 use crate::port::{
     inbound::runtime::RuntimeClusterView, inbound::runtime::RuntimeState,
-    outbound::exchange::PoolStats, outbound::stats::StatsRecorder,
+    inbound::runtime::RuntimeStrategyControl, outbound::exchange::PoolStats,
+    outbound::stats::StatsRecorder,
 };
 
 mod dispatch;
@@ -29,6 +31,10 @@ pub struct RuntimeStats {
     token_count: AtomicUsize,
     /// Cluster view for relation lookups.
     cluster_view: RwLock<Option<Arc<dyn RuntimeClusterView>>>,
+    /// Detection latency percentiles over the recent window.
+    latency: RwLock<Option<LatencyPercentiles>>,
+    /// Runtime on/off switches for detection strategies.
+    strategy_control: RwLock<Option<Arc<dyn RuntimeStrategyControl>>>,
 }
 
 /// Runtime command executor for Telegram control commands.
@@ -52,6 +52,11 @@ impl TelegramControl {
             TelegramCommand::Pause => self.pause_text(),
             TelegramCommand::Resume => self.resume_text(),
             TelegramCommand::SetRisk { kind, value } => self.set_risk_text(kind, value),
+            TelegramCommand::Strategies => self.strategies_text(),
+            TelegramCommand::SetStrategy { name, enabled } => {
+                self.set_strategy_text(&name, enabled)
+            }
+            TelegramCommand::Recent { limit } => self.recent_text(limit),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::domain::trading::TradingState;
 use crate::port::inbound::runtime::RuntimeRiskLimitKind;
 
 use super::TelegramControl;
@@ -27,25 +28,40 @@ impl TelegramControl {
         }
     }
 
+    pub(super) fn set_strategy_text(&self, name: &str, enabled: bool) -> String {
+        let Some(control) = self
+            .runtime_stats
+            .as_ref()
+            .and_then(|runtime| runtime.strategy_control())
+        else {
+            return "🧠 Strategy control not available".to_string();
+        };
+
+        match control.set_strategy_enabled(name, enabled) {
+            Ok(status) if status.enabled => format!("🟢 Strategy {} enabled", status.name),
+            Ok(status) => format!("⚪ Strategy {} disabled", status.name),
+            Err(err) => {
+                let known: Vec<&str> = control.strategies().iter().map(|s| s.name).collect();
+                format!("❌ Error: {} (known: {})", err, known.join(", "))
+            }
+        }
+    }
+
     pub(super) fn pause_text(&self) -> String {
-        if self.state.is_circuit_breaker_active() {
-            let reason = self
-                .state
-                .circuit_breaker_reason()
-                .unwrap_or_else(|| "unknown".to_string());
-            return format!("⏸️ Already paused: {}", reason);
+        if let TradingState::Halted { reason } = self.state.trading_state() {
+            return format!("⏹️ Already halted: {}", reason);
         }
 
-        self.state.activate_circuit_breaker("paused via Telegram");
-        "⏸️ Trading paused".to_string()
+        self.state.halt("halted via Telegram");
+        "⏹️ Trading halted until /resume (survives restarts)".to_string()
     }
 
     pub(super) fn resume_text(&self) -> String {
-        if !self.state.is_circuit_breaker_active() {
+        if self.state.trading_state().is_active() {
             return "▶️ Trading already active".to_string();
         }
 
-        self.state.reset_circuit_breaker();
+        self.state.resume();
         "▶️ Trading resumed".to_string()
     }
 }
@@ -1,4 +1,6 @@
+use crate::adapter::outbound::notifier::text::truncate;
 use crate::domain::relation::RelationKind;
+use crate::domain::trading::TradingState;
 use crate::port::inbound::runtime::RuntimePositionStatus;
 
 use super::{format_uptime, TelegramControl};
@@ -10,26 +12,19 @@ impl TelegramControl {
         let exposure = self.state.total_exposure();
         let pending_exposure = self.state.pending_exposure();
         let pending_executions = self.state.pending_execution_count();
-        let is_paused = self.state.is_circuit_breaker_active();
+        let trading = self.state.trading_state();
 
-        let (mode_emoji, mode) = if is_paused {
-            ("⏸️", "PAUSED")
-        } else {
-            ("▶️", "ACTIVE")
-        };
-        let breaker = if is_paused {
-            self.state
-                .circuit_breaker_reason()
-                .unwrap_or_else(|| "unknown".to_string())
-        } else {
-            "inactive".to_string()
+        let (mode_emoji, mode) = match trading {
+            TradingState::Active => ("▶️", "ACTIVE"),
+            TradingState::Paused { .. } => ("⏸️", "PAUSED"),
+            TradingState::Halted { .. } => ("⏹️", "HALTED"),
         };
 
-        format!(
+        let mut response = format!(
             "📊 Status\n\n\
             {} Mode: {}\n\
             ⏱️ Uptime: {}\n\
-            🛑 Circuit Breaker: {}\n\n\
+            🛑 Trading: {}\n\n\
             💼 Portfolio\n\
             • Open Positions: {}\n\
             • Exposure: ${}\n\
@@ -43,7 +38,7 @@ impl TelegramControl {
             mode_emoji,
             mode,
             format_uptime(self.started_at),
-            breaker,
+            trading,
             open_positions,
             exposure,
             pending_exposure,
@@ -52,7 +47,29 @@ impl TelegramControl {
             limits.max_slippage * rust_decimal::Decimal::from(100),
             limits.max_position_per_market,
             limits.max_total_exposure
-        )
+        );
+
+        if let Some(latency) = self
+            .runtime_stats
+            .as_ref()
+            .and_then(|runtime| runtime.latency())
+            .filter(|latency| latency.samples > 0)
+        {
+            let millis = |latency: std::time::Duration| latency.as_micros() as f64 / 1000.0;
+            response.push_str(&format!(
+                "\n\n⚡ Detection Latency\n\
+                • p50: {:.1}ms\n\
+                • p95: {:.1}ms\n\
+                • p99: {:.1}ms\n\
+                • Events: {}",
+                millis(latency.p50),
+                millis(latency.p95),
+                millis(latency.p99),
+                latency.samples
+            ));
+        }
+
+        response
     }
 
     pub(super) fn health_text(&self) -> String {
@@ -61,11 +78,12 @@ impl TelegramControl {
         let pending_exposure = self.state.pending_exposure();
         let total_exposure = exposure + pending_exposure;
         let exposure_ok = total_exposure <= limits.max_total_exposure;
-        let breaker_ok = !self.state.is_circuit_breaker_active();
+        let trading = self.state.trading_state();
+        let trading_ok = trading.is_active();
         let slippage_ok = limits.max_slippage >= rust_decimal::Decimal::ZERO
             && limits.max_slippage <= rust_decimal::Decimal::ONE;
 
-        let healthy = exposure_ok && breaker_ok && slippage_ok;
+        let healthy = exposure_ok && trading_ok && slippage_ok;
         let (status_emoji, status) = if healthy {
             ("✅", "HEALTHY")
         } else {
@@ -74,23 +92,15 @@ impl TelegramControl {
 
         let check = |ok: bool| if ok { "✅" } else { "❌" };
 
-        let breaker_detail = if breaker_ok {
-            "inactive".to_string()
-        } else {
-            self.state
-                .circuit_breaker_reason()
-                .unwrap_or_else(|| "active".to_string())
-        };
-
         format!(
             "🏥 Health Check: {} {}\n\n\
-            🛑 Circuit Breaker: {} ({})\n\
+            🛑 Trading: {} ({})\n\
             💰 Exposure: {} (${}/{})\n\
             📉 Slippage Config: {} ({})",
             status_emoji,
             status,
-            check(breaker_ok),
-            breaker_detail,
+            check(trading_ok),
+            trading,
             check(exposure_ok),
             total_exposure,
             limits.max_total_exposure,
@@ -299,6 +309,53 @@ impl TelegramControl {
         response
     }
 
+    pub(super) fn strategies_text(&self) -> String {
+        let Some(control) = self
+            .runtime_stats
+            .as_ref()
+            .and_then(|runtime| runtime.strategy_control())
+        else {
+            return "🧠 Strategy control not available".to_string();
+        };
+
+        let strategies = control.strategies();
+        if strategies.is_empty() {
+            return "🧠 No strategies registered".to_string();
+        }
+
+        let mut response = "🧠 Strategies\n\n".to_string();
+        for strategy in strategies {
+            let state = if strategy.enabled {
+                "🟢 on"
+            } else {
+                "⚪ off"
+            };
+            response.push_str(&format!("• {}: {}\n", strategy.name, state));
+        }
+        response.push_str("\nToggle with /strategy <name> on|off");
+
+        response
+    }
+
+    pub(super) fn recent_text(&self, limit: usize) -> String {
+        let events = self.state.recent_events(limit);
+        if events.is_empty() {
+            return "🕘 No recent events".to_string();
+        }
+
+        let mut response = format!("🕘 Recent Events ({})\n\n", events.len());
+        for event in events {
+            response.push_str(&format!(
+                "{} {}\n   {}\n",
+                event.at.format("%H:%M:%S"),
+                event.kind,
+                truncate(&event.summary, 120)
+            ));
+        }
+
+        response
+    }
+
     pub(super) fn version_text(&self) -> String {
         let version = env!("CARGO_PKG_VERSION");
 
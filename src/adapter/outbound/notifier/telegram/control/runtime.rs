@@ -3,7 +3,11 @@ use std::sync::Arc;
 
 use chrono::Utc;
 
-use crate::port::{inbound::runtime::RuntimeClusterView, outbound::exchange::PoolStats};
+use crate::domain::stats::LatencyPercentiles;
+use crate::port::{
+    inbound::runtime::RuntimeClusterView, inbound::runtime::RuntimeStrategyControl,
+    outbound::exchange::PoolStats,
+};
 
 use super::RuntimeStats;
 
@@ -43,6 +47,17 @@ impl RuntimeStats {
         self.token_count.load(Ordering::Relaxed)
     }
 
+    /// Update detection latency percentiles.
+    pub fn update_latency(&self, latency: LatencyPercentiles) {
+        *self.latency.write() = Some(latency);
+    }
+
+    /// Get detection latency percentiles.
+    #[must_use]
+    pub fn latency(&self) -> Option<LatencyPercentiles> {
+        *self.latency.read()
+    }
+
     /// Set cluster view for relation lookups.
     pub fn set_cluster_cache(&self, view: Arc<dyn RuntimeClusterView>) {
         *self.cluster_view.write() = Some(view);
@@ -53,6 +68,17 @@ impl RuntimeStats {
     pub fn cluster_view(&self) -> Option<Arc<dyn RuntimeClusterView>> {
         self.cluster_view.read().clone()
     }
+
+    /// Set strategy control for runtime enable/disable.
+    pub fn set_strategy_control(&self, control: Arc<dyn RuntimeStrategyControl>) {
+        *self.strategy_control.write() = Some(control);
+    }
+
+    /// Get strategy control.
+    #[must_use]
+    pub fn strategy_control(&self) -> Option<Arc<dyn RuntimeStrategyControl>> {
+        self.strategy_control.read().clone()
+    }
 }
 
 pub(super) fn format_uptime(started_at: chrono::DateTime<Utc>) -> String {
@@ -7,9 +7,11 @@ use rust_decimal_macros::dec;
 
 use crate::adapter::outbound::sqlite::database;
 use crate::adapter::outbound::sqlite::recorder;
+use crate::domain::trading::TradingState;
 use crate::port::inbound::runtime::{
-    RuntimePosition, RuntimePositionStatus, RuntimeRiskLimitKind, RuntimeRiskLimitUpdateError,
-    RuntimeRiskLimits, RuntimeState,
+    RuntimeEvent, RuntimePosition, RuntimePositionStatus, RuntimeRiskLimitKind,
+    RuntimeRiskLimitUpdateError, RuntimeRiskLimits, RuntimeState, RuntimeStrategyControl,
+    RuntimeStrategyStatus, UnknownStrategyError,
 };
 
 // =============================================================================
@@ -41,10 +43,11 @@ impl TestPositionStore {
 #[derive(Debug)]
 struct MockRuntimeState {
     limits: RwLock<RuntimeRiskLimits>,
-    breaker_reason: RwLock<Option<String>>,
+    trading: RwLock<TradingState>,
     positions: RwLock<TestPositionStore>,
     pending_exposure: RwLock<rust_decimal::Decimal>,
     pending_executions: RwLock<usize>,
+    events: RwLock<Vec<RuntimeEvent>>,
 }
 
 impl Default for MockRuntimeState {
@@ -56,10 +59,11 @@ impl Default for MockRuntimeState {
                 min_profit_threshold: dec!(0.2),
                 max_slippage: dec!(0.05),
             }),
-            breaker_reason: RwLock::new(None),
+            trading: RwLock::new(TradingState::Active),
             positions: RwLock::new(TestPositionStore::default()),
             pending_exposure: RwLock::new(dec!(0)),
             pending_executions: RwLock::new(0),
+            events: RwLock::new(Vec::new()),
         }
     }
 }
@@ -80,6 +84,18 @@ impl MockRuntimeState {
         *self.pending_exposure.write() = exposure;
         *self.pending_executions.write() = count;
     }
+
+    /// Record an event as the newest one.
+    fn push_event(&self, kind: &'static str, summary: &str) {
+        self.events.write().insert(
+            0,
+            RuntimeEvent {
+                at: chrono::Utc::now(),
+                kind,
+                summary: summary.to_string(),
+            },
+        );
+    }
 }
 
 impl RuntimeState for MockRuntimeState {
@@ -123,20 +139,16 @@ impl RuntimeState for MockRuntimeState {
         Ok(limits.clone())
     }
 
-    fn is_circuit_breaker_active(&self) -> bool {
-        self.breaker_reason.read().is_some()
-    }
-
-    fn circuit_breaker_reason(&self) -> Option<String> {
-        self.breaker_reason.read().clone()
+    fn trading_state(&self) -> TradingState {
+        self.trading.read().clone()
     }
 
-    fn activate_circuit_breaker(&self, reason: &str) {
-        *self.breaker_reason.write() = Some(reason.to_string());
+    fn halt(&self, reason: &str) {
+        *self.trading.write() = TradingState::halted(reason);
     }
 
-    fn reset_circuit_breaker(&self) {
-        *self.breaker_reason.write() = None;
+    fn resume(&self) {
+        *self.trading.write() = TradingState::Active;
     }
 
     fn open_position_count(&self) -> usize {
@@ -164,6 +176,10 @@ impl RuntimeState for MockRuntimeState {
         *self.pending_executions.read()
     }
 
+    fn recent_events(&self, limit: usize) -> Vec<RuntimeEvent> {
+        self.events.read().iter().take(limit).cloned().collect()
+    }
+
     fn active_positions(&self) -> Vec<RuntimePosition> {
         self.positions
             .read()
@@ -200,13 +216,13 @@ fn execute_pause_and_resume() {
     let state = Arc::new(MockRuntimeState::default());
     let control = TelegramControl::new(as_runtime(Arc::clone(&state)));
 
-    let paused = control.execute(TelegramCommand::Pause);
-    assert!(paused.contains("paused"));
-    assert!(state.is_circuit_breaker_active());
+    let halted = control.execute(TelegramCommand::Pause);
+    assert!(halted.contains("halted"));
+    assert!(state.trading_state().is_halted());
 
     let resumed = control.execute(TelegramCommand::Resume);
     assert!(resumed.contains("resumed"));
-    assert!(!state.is_circuit_breaker_active());
+    assert!(state.trading_state().is_active());
 }
 
 #[test]
@@ -434,18 +450,33 @@ fn execute_health_command() {
 
     let text = control.execute(TelegramCommand::Health);
     assert!(text.contains("Health Check:"));
-    assert!(text.contains("Circuit Breaker:"));
+    assert!(text.contains("Trading:"));
     assert!(text.contains("Exposure:"));
 }
 
 #[test]
-fn pause_when_already_paused() {
+fn pause_when_already_halted() {
     let state = Arc::new(MockRuntimeState::default());
-    state.activate_circuit_breaker("manual pause");
+    state.halt("manual halt");
     let control = TelegramControl::new(as_runtime(Arc::clone(&state)));
 
     let text = control.execute(TelegramCommand::Pause);
-    assert!(text.contains("Already paused"));
+    assert!(text.contains("Already halted"));
+    assert!(text.contains("manual halt"));
+}
+
+#[test]
+fn pause_upgrades_automatic_pause_to_halt() {
+    let state = Arc::new(MockRuntimeState::default());
+    *state.trading.write() = TradingState::paused("kill-switch");
+    let control = TelegramControl::new(as_runtime(Arc::clone(&state)));
+
+    let text = control.execute(TelegramCommand::Pause);
+    assert!(text.contains("halted"));
+    assert_eq!(
+        state.trading_state(),
+        TradingState::halted("halted via Telegram")
+    );
 }
 
 #[test]
@@ -511,20 +542,20 @@ fn runtime_stats_pool_stats_none_initially() {
 }
 
 #[test]
-fn status_shows_paused_when_circuit_breaker_active() {
+fn status_shows_halted_with_reason() {
     let state = Arc::new(MockRuntimeState::default());
-    state.activate_circuit_breaker("test reason");
+    state.halt("test reason");
     let control = TelegramControl::new(as_runtime(Arc::clone(&state)));
 
     let text = control.execute(TelegramCommand::Status);
-    assert!(text.contains("PAUSED"));
+    assert!(text.contains("HALTED"));
     assert!(text.contains("test reason"));
 }
 
 #[test]
-fn health_shows_degraded_when_circuit_breaker_active() {
+fn health_shows_degraded_when_trading_halted() {
     let state = Arc::new(MockRuntimeState::default());
-    state.activate_circuit_breaker("test failure");
+    state.halt("test failure");
     let control = TelegramControl::new(as_runtime(Arc::clone(&state)));
 
     let text = control.execute(TelegramCommand::Health);
@@ -725,7 +756,7 @@ fn status_shows_active_mode() {
 #[test]
 fn status_shows_paused_mode() {
     let state = Arc::new(MockRuntimeState::default());
-    state.activate_circuit_breaker("manual pause");
+    *state.trading.write() = TradingState::paused("manual pause");
     let control = TelegramControl::new(as_runtime(state));
 
     let text = control.execute(TelegramCommand::Status);
@@ -763,6 +794,31 @@ fn status_shows_pending_info() {
     assert!(text.contains("In-Flight: 3"));
 }
 
+#[test]
+fn status_shows_detection_latency() {
+    let state = Arc::new(MockRuntimeState::default());
+    let pool = database::connection::create_pool("sqlite://:memory:").expect("create pool");
+    database::connection::run_migrations(&pool).expect("run migrations");
+    let recorder = recorder::create_recorder(pool);
+    let runtime = Arc::new(RuntimeStats::new());
+    let control = TelegramControl::with_config(as_runtime(state), recorder, runtime.clone(), 10);
+
+    assert!(!control.execute(TelegramCommand::Status).contains("Latency"));
+
+    runtime.update_latency(crate::domain::stats::LatencyPercentiles {
+        p50: std::time::Duration::from_micros(1500),
+        p95: std::time::Duration::from_millis(4),
+        p99: std::time::Duration::from_millis(9),
+        samples: 250,
+    });
+
+    let text = control.execute(TelegramCommand::Status);
+    assert!(text.contains("Detection Latency"));
+    assert!(text.contains("p50: 1.5ms"));
+    assert!(text.contains("p99: 9.0ms"));
+    assert!(text.contains("Events: 250"));
+}
+
 // =============================================================================
 // Health Command Tests
 // =============================================================================
@@ -950,6 +1006,7 @@ fn runtime_stats_default_values() {
     assert_eq!(stats.market_count(), 0);
     assert_eq!(stats.token_count(), 0);
     assert!(stats.cluster_view().is_none());
+    assert!(stats.latency().is_none());
 }
 
 #[test]
@@ -1077,7 +1134,7 @@ fn telegram_control_clone_shares_state() {
 
     // Should be visible on clone
     let status = cloned.execute(TelegramCommand::Status);
-    assert!(status.contains("PAUSED"));
+    assert!(status.contains("HALTED"));
 }
 
 // =============================================================================
@@ -1129,6 +1186,9 @@ fn help_contains_all_commands() {
     assert!(text.contains("/pause"));
     assert!(text.contains("/resume"));
     assert!(text.contains("/set_risk"));
+    assert!(text.contains("/strategies"));
+    assert!(text.contains("/strategy"));
+    assert!(text.contains("/recent"));
 }
 
 // =============================================================================
@@ -1142,17 +1202,17 @@ fn multiple_pauses_are_safe() {
 
     // First pause
     let text1 = control.execute(TelegramCommand::Pause);
-    assert!(text1.contains("paused"));
-    assert!(state.is_circuit_breaker_active());
+    assert!(text1.contains("halted"));
+    assert!(state.trading_state().is_halted());
 
-    // Second pause - should indicate already paused
+    // Second pause - should indicate already halted
     let text2 = control.execute(TelegramCommand::Pause);
-    assert!(text2.contains("Already paused"));
-    assert!(state.is_circuit_breaker_active());
+    assert!(text2.contains("Already halted"));
+    assert!(state.trading_state().is_halted());
 
     // Third pause
     let text3 = control.execute(TelegramCommand::Pause);
-    assert!(text3.contains("Already paused"));
+    assert!(text3.contains("Already halted"));
 }
 
 #[test]
@@ -1163,10 +1223,156 @@ fn multiple_resumes_are_safe() {
     // First resume (already active)
     let text1 = control.execute(TelegramCommand::Resume);
     assert!(text1.contains("already active"));
-    assert!(!state.is_circuit_breaker_active());
+    assert!(state.trading_state().is_active());
 
     // Second resume
     let text2 = control.execute(TelegramCommand::Resume);
     assert!(text2.contains("already active"));
-    assert!(!state.is_circuit_breaker_active());
+    assert!(state.trading_state().is_active());
+}
+
+// =============================================================================
+// Strategy Toggles
+// =============================================================================
+
+struct MockStrategyControl {
+    strategies: RwLock<Vec<RuntimeStrategyStatus>>,
+}
+
+impl MockStrategyControl {
+    fn new(names: &[&'static str]) -> Self {
+        Self {
+            strategies: RwLock::new(
+                names
+                    .iter()
+                    .copied()
+                    .map(|name| RuntimeStrategyStatus {
+                        name,
+                        enabled: true,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl RuntimeStrategyControl for MockStrategyControl {
+    fn strategies(&self) -> Vec<RuntimeStrategyStatus> {
+        self.strategies.read().clone()
+    }
+
+    fn set_strategy_enabled(
+        &self,
+        name: &str,
+        enabled: bool,
+    ) -> Result<RuntimeStrategyStatus, UnknownStrategyError> {
+        let mut strategies = self.strategies.write();
+        let strategy = strategies
+            .iter_mut()
+            .find(|s| s.name == name)
+            .ok_or_else(|| UnknownStrategyError::new(name))?;
+        strategy.enabled = enabled;
+        Ok(strategy.clone())
+    }
+}
+
+fn control_with_strategies(names: &[&'static str]) -> TelegramControl {
+    let pool = database::connection::create_pool("sqlite://:memory:").expect("create pool");
+    database::connection::run_migrations(&pool).expect("run migrations");
+    let recorder = recorder::create_recorder(pool);
+    let runtime = Arc::new(RuntimeStats::new());
+    runtime.set_strategy_control(Arc::new(MockStrategyControl::new(names)));
+
+    TelegramControl::with_config(
+        as_runtime(Arc::new(MockRuntimeState::default())),
+        recorder,
+        runtime,
+        10,
+    )
+}
+
+#[test]
+fn strategies_without_runtime_stats() {
+    let state = Arc::new(MockRuntimeState::default());
+    let control = TelegramControl::new(as_runtime(state));
+
+    assert!(control
+        .execute(TelegramCommand::Strategies)
+        .contains("not available"));
+    assert!(control
+        .execute(TelegramCommand::SetStrategy {
+            name: "combinatorial".to_string(),
+            enabled: false,
+        })
+        .contains("not available"));
+}
+
+#[test]
+fn strategy_toggle_is_reflected_in_listing() {
+    let control = control_with_strategies(&["single_condition", "combinatorial"]);
+
+    let text = control.execute(TelegramCommand::Strategies);
+    assert!(text.contains("single_condition: 🟢 on"));
+    assert!(text.contains("combinatorial: 🟢 on"));
+
+    let text = control.execute(TelegramCommand::SetStrategy {
+        name: "combinatorial".to_string(),
+        enabled: false,
+    });
+    assert!(text.contains("combinatorial disabled"));
+
+    let text = control.execute(TelegramCommand::Strategies);
+    assert!(text.contains("single_condition: 🟢 on"));
+    assert!(text.contains("combinatorial: ⚪ off"));
+
+    let text = control.execute(TelegramCommand::SetStrategy {
+        name: "combinatorial".to_string(),
+        enabled: true,
+    });
+    assert!(text.contains("combinatorial enabled"));
+}
+
+#[test]
+fn strategy_toggle_unknown_name_lists_known() {
+    let control = control_with_strategies(&["single_condition", "combinatorial"]);
+
+    let text = control.execute(TelegramCommand::SetStrategy {
+        name: "nope".to_string(),
+        enabled: false,
+    });
+
+    assert!(text.contains("Error"));
+    assert!(text.contains("unknown strategy `nope`"));
+    assert!(text.contains("single_condition, combinatorial"));
+}
+
+// =============================================================================
+// Recent Events
+// =============================================================================
+
+#[test]
+fn recent_without_events() {
+    let state = Arc::new(MockRuntimeState::default());
+    let control = TelegramControl::new(as_runtime(state));
+
+    let text = control.execute(TelegramCommand::Recent { limit: 10 });
+
+    assert_eq!(text, "🕘 No recent events");
+}
+
+#[test]
+fn recent_lists_newest_events_up_to_limit() {
+    let state = Arc::new(MockRuntimeState::default());
+    state.push_event("opportunity_detected", "m1 edge 0.05 profit $0.50");
+    state.push_event("risk_rejected", "m1: exposure limit exceeded");
+    state.push_event("execution_completed", "m2 succeeded: Orders: o1");
+    let control = TelegramControl::new(as_runtime(Arc::clone(&state)));
+
+    let text = control.execute(TelegramCommand::Recent { limit: 2 });
+
+    assert!(text.contains("Recent Events (2)"));
+    assert!(text.contains("execution_completed"));
+    assert!(text.contains("m1: exposure limit exceeded"));
+    assert!(!text.contains("opportunity_detected"));
+    assert!(text.find("execution_completed") < text.find("risk_rejected"));
 }
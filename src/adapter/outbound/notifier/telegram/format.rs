@@ -1,5 +1,7 @@
 //! Message formatting for Telegram notifications.
 
+use crate::adapter::outbound::notifier::text::truncate;
+use crate::port::outbound::exchange::DisconnectReason;
 use crate::port::outbound::notifier::Event;
 
 use super::notifier::TelegramConfig;
@@ -16,11 +18,26 @@ pub fn format_event_message(event: &Event, config: &TelegramConfig) -> Option<St
                 📋 {}\n\
                 📈 Edge: `{:.2}%`\n\
                 💵 Volume: `${:.2}`\n\
-                💰 Expected: `\\+${:.2}`",
+                💰 Expected: `\\+${:.2}`\n\
+                🧮 {}",
                 escape_markdown(&question),
                 e.edge * rust_decimal::Decimal::from(100),
                 e.volume,
-                e.expected_profit
+                e.expected_profit,
+                escape_markdown(&e.explanation)
+            ))
+        }
+        Event::NearArbitrage(e) if config.notify_opportunities => {
+            let question = truncate(&e.question, 60);
+
+            Some(format!(
+                "👀 *Near Arbitrage*\n\
+                \n\
+                📋 {}\n\
+                📉 Edge: `{:.2}%`\n\
+                ℹ️ Watch only, not executed",
+                escape_markdown(&question),
+                e.edge * rust_decimal::Decimal::from(100),
             ))
         }
         Event::ExecutionCompleted(e) if config.notify_executions => {
@@ -68,6 +85,39 @@ pub fn format_event_message(event: &Event, config: &TelegramConfig) -> Option<St
             ▶️ Trading resumed"
                 .to_string(),
         ),
+        Event::TradingHalted { reason } => Some(format!(
+            "⏹️ *Trading Halted*\n\
+            \n\
+            ⚠️ Reason: {}\n\
+            ▶️ Send /resume to resume trading",
+            escape_markdown(reason)
+        )),
+        Event::StreamDisconnected { cause, reason } => {
+            let (title, action) = match cause {
+                DisconnectReason::AuthFailure => {
+                    ("🔒 *Market Data Auth Failed*", "Check exchange credentials")
+                }
+                DisconnectReason::Stalled => ("⏸️ *Market Data Stalled*", "Reconnecting"),
+                _ => (
+                    "📵 *Market Data Rate Limited*",
+                    "Backing off before reconnecting",
+                ),
+            };
+            Some(format!(
+                "{title}\n\
+                \n\
+                ⚠️ Reason: {}\n\
+                ⏳ {action}",
+                escape_markdown(reason)
+            ))
+        }
+        Event::InferenceDegraded { reason } => Some(format!(
+            "🧠 *Inference Degraded*\n\
+            \n\
+            ⚠️ Reason: {}\n\
+            🔁 Retrying on the inference schedule; other strategies keep running",
+            escape_markdown(reason)
+        )),
         Event::DailySummary(e) => Some(format!(
             "📊 *Daily Summary — {}*\n\
             \n\
@@ -83,6 +133,25 @@ pub fn format_event_message(event: &Event, config: &TelegramConfig) -> Option<St
             e.total_profit,
             e.current_exposure
         )),
+        Event::DrainCompleted(e) => Some(format!(
+            "🏁 *Shutdown Drain*\n\
+            \n\
+            💼 Positions: `{}`\n\
+            ✅ Closed: `{}`\n\
+            ❌ Failed: `{}`\n\
+            💰 PnL: `{}`\n\
+            📉 Remaining exposure: `${:.2}`{}",
+            e.positions,
+            e.closed,
+            e.failed,
+            escape_markdown(&format!("{:.2}", e.realized_pnl)),
+            e.remaining_exposure,
+            if e.timed_out {
+                "\n⏱️ Timed out"
+            } else {
+                ""
+            }
+        )),
         Event::RelationsDiscovered(e) => {
             if e.relations.is_empty() {
                 return None;
@@ -139,17 +208,6 @@ pub fn format_event_message(event: &Event, config: &TelegramConfig) -> Option<St
     }
 }
 
-/// Truncate a string with ellipsis (Unicode-safe).
-pub fn truncate(s: &str, max_chars: usize) -> String {
-    let char_count = s.chars().count();
-    if char_count > max_chars {
-        let truncated: String = s.chars().take(max_chars).collect();
-        format!("{}...", truncated)
-    } else {
-        s.to_string()
-    }
-}
-
 /// Escape special characters for Telegram `MarkdownV2`.
 pub fn escape_markdown(text: &str) -> String {
     let special_chars = [
@@ -173,8 +231,10 @@ mod tests {
     use chrono::NaiveDate;
     use rust_decimal_macros::dec;
 
+    use crate::adapter::outbound::notifier::telegram::chat::TelegramChat;
     use crate::port::outbound::notifier::{
-        ExecutionEvent, OpportunityEvent, RelationDetail, RelationsEvent, RiskEvent, SummaryEvent,
+        DrainEvent, ExecutionEvent, OpportunityEvent, RelationDetail, RelationsEvent, RiskEvent,
+        SummaryEvent,
     };
 
     // -------------------------------------------------------------------------
@@ -239,50 +299,6 @@ mod tests {
         assert_eq!(escape_markdown("café"), "café");
     }
 
-    // -------------------------------------------------------------------------
-    // truncate tests
-    // -------------------------------------------------------------------------
-
-    #[test]
-    fn test_truncate() {
-        assert_eq!(truncate("hello", 10), "hello");
-        assert_eq!(truncate("hello world", 5), "hello...");
-        assert_eq!(truncate("ab", 2), "ab");
-    }
-
-    #[test]
-    fn test_truncate_unicode() {
-        // Handles multi-byte UTF-8 characters without panic
-        assert_eq!(truncate("日本語テスト", 3), "日本語...");
-        assert_eq!(truncate("café", 4), "café");
-        assert_eq!(truncate("🎯🚀💰", 2), "🎯🚀...");
-    }
-
-    #[test]
-    fn test_truncate_exact_length() {
-        assert_eq!(truncate("hello", 5), "hello");
-    }
-
-    #[test]
-    fn test_truncate_one_over() {
-        assert_eq!(truncate("hello!", 5), "hello...");
-    }
-
-    #[test]
-    fn test_truncate_empty() {
-        assert_eq!(truncate("", 10), "");
-    }
-
-    #[test]
-    fn test_truncate_zero_max() {
-        assert_eq!(truncate("hello", 0), "...");
-    }
-
-    #[test]
-    fn test_truncate_one_char() {
-        assert_eq!(truncate("hello", 1), "h...");
-    }
-
     // -------------------------------------------------------------------------
     // Helper function to create test config
     // -------------------------------------------------------------------------
@@ -294,7 +310,7 @@ mod tests {
     ) -> TelegramConfig {
         TelegramConfig {
             bot_token: "test-token".to_string(),
-            chat_id: 12345,
+            chats: vec![TelegramChat::new(12345)],
             notify_opportunities,
             notify_executions,
             notify_risk_rejections,
@@ -310,11 +326,13 @@ mod tests {
     fn format_opportunity_detected_when_enabled() {
         let config = test_config(true, true, true);
         let event = Event::OpportunityDetected(OpportunityEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-123".to_string(),
             question: "Will it rain tomorrow?".to_string(),
             edge: dec!(0.05),
             volume: dec!(100),
             expected_profit: dec!(5.25),
+            explanation: "Bought yes@0.45 + no@0.50 = 0.95".to_string(),
         });
 
         let result = format_event_message(&event, &config);
@@ -322,6 +340,7 @@ mod tests {
 
         let msg = result.unwrap();
         assert!(msg.contains("Opportunity Detected"));
+        assert!(msg.contains("Bought yes@0\\.45 \\+ no@0\\.50 \\= 0\\.95"));
         assert!(msg.contains("rain tomorrow"));
         assert!(msg.contains("Edge:"));
         assert!(msg.contains("5.00%")); // 0.05 * 100
@@ -335,11 +354,13 @@ mod tests {
     fn format_opportunity_detected_when_disabled() {
         let config = test_config(false, true, true);
         let event = Event::OpportunityDetected(OpportunityEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-123".to_string(),
             question: "Will it rain tomorrow?".to_string(),
             edge: dec!(0.05),
             volume: dec!(100),
             expected_profit: dec!(5.25),
+            explanation: String::new(),
         });
 
         let result = format_event_message(&event, &config);
@@ -352,11 +373,13 @@ mod tests {
         let long_question =
             "This is a very long question that exceeds the maximum character limit for display";
         let event = Event::OpportunityDetected(OpportunityEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-123".to_string(),
             question: long_question.to_string(),
             edge: dec!(0.05),
             volume: dec!(100),
             expected_profit: dec!(5.25),
+            explanation: String::new(),
         });
 
         let result = format_event_message(&event, &config);
@@ -378,9 +401,11 @@ mod tests {
     fn format_execution_success_when_enabled() {
         let config = test_config(true, true, true);
         let event = Event::ExecutionCompleted(ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-abc123def".to_string(),
             success: true,
             details: "Orders: order1, order2".to_string(),
+            failure_kind: None,
         });
 
         let result = format_event_message(&event, &config);
@@ -396,9 +421,11 @@ mod tests {
     fn format_execution_failure_when_enabled() {
         let config = test_config(true, true, true);
         let event = Event::ExecutionCompleted(ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-123".to_string(),
             success: false,
             details: "Failed: insufficient balance".to_string(),
+            failure_kind: None,
         });
 
         let result = format_event_message(&event, &config);
@@ -413,9 +440,11 @@ mod tests {
     fn format_execution_when_disabled() {
         let config = test_config(true, false, true);
         let event = Event::ExecutionCompleted(ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-123".to_string(),
             success: true,
             details: "Orders: order1".to_string(),
+            failure_kind: None,
         });
 
         let result = format_event_message(&event, &config);
@@ -426,9 +455,11 @@ mod tests {
     fn format_execution_truncates_long_market_id() {
         let config = test_config(true, true, true);
         let event = Event::ExecutionCompleted(ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "very-long-market-id-that-exceeds-limit".to_string(),
             success: true,
             details: "OK".to_string(),
+            failure_kind: None,
         });
 
         let result = format_event_message(&event, &config);
@@ -450,6 +481,7 @@ mod tests {
     fn format_risk_rejected_when_enabled() {
         let config = test_config(true, true, true);
         let event = Event::RiskRejected(RiskEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-123".to_string(),
             reason: "Exceeds max position limit".to_string(),
         });
@@ -467,6 +499,7 @@ mod tests {
     fn format_risk_rejected_when_disabled() {
         let config = test_config(true, true, false);
         let event = Event::RiskRejected(RiskEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-123".to_string(),
             reason: "Exceeds max position limit".to_string(),
         });
@@ -523,6 +556,60 @@ mod tests {
         assert!(msg.contains("Trading resumed"));
     }
 
+    #[test]
+    fn format_trading_halted() {
+        let config = test_config(true, true, true);
+        let event = Event::TradingHalted {
+            reason: "halted via Telegram".to_string(),
+        };
+
+        let msg = format_event_message(&event, &config).unwrap();
+        assert!(msg.contains("Trading Halted"));
+        assert!(msg.contains("halted via Telegram"));
+        assert!(msg.contains("/resume"));
+    }
+
+    #[test]
+    fn format_stream_disconnected_by_cause() {
+        let config = test_config(false, false, false);
+        let rate_limited = Event::StreamDisconnected {
+            cause: DisconnectReason::RateLimited,
+            reason: "HTTP error: 429 Too Many Requests".to_string(),
+        };
+        let auth = Event::StreamDisconnected {
+            cause: DisconnectReason::AuthFailure,
+            reason: "401 Unauthorized".to_string(),
+        };
+
+        let msg = format_event_message(&rate_limited, &config).unwrap();
+        assert!(msg.contains("Market Data Rate Limited"));
+        assert!(msg.contains("429 Too Many Requests"));
+
+        let msg = format_event_message(&auth, &config).unwrap();
+        assert!(msg.contains("Market Data Auth Failed"));
+        assert!(msg.contains("401 Unauthorized"));
+
+        let stalled = Event::StreamDisconnected {
+            cause: DisconnectReason::Stalled,
+            reason: "no market events for 300s".to_string(),
+        };
+        let msg = format_event_message(&stalled, &config).unwrap();
+        assert!(msg.contains("Market Data Stalled"));
+        assert!(msg.contains("300s"));
+    }
+
+    #[test]
+    fn format_inference_degraded() {
+        let config = test_config(false, false, false);
+        let event = Event::InferenceDegraded {
+            reason: "connection refused".to_string(),
+        };
+
+        let msg = format_event_message(&event, &config).unwrap();
+        assert!(msg.contains("Inference Degraded"));
+        assert!(msg.contains("connection refused"));
+    }
+
     // -------------------------------------------------------------------------
     // DailySummary event formatting
     // -------------------------------------------------------------------------
@@ -552,6 +639,28 @@ mod tests {
         assert!(msg.contains("$500")); // exposure
     }
 
+    #[test]
+    fn format_drain_completed() {
+        let config = test_config(true, true, true);
+        let event = Event::DrainCompleted(DrainEvent {
+            positions: 3,
+            closed: 2,
+            failed: 1,
+            timed_out: false,
+            realized_pnl: dec!(-1.25),
+            remaining_exposure: dec!(40),
+        });
+
+        let msg = format_event_message(&event, &config).unwrap();
+
+        assert!(msg.contains("Shutdown Drain"));
+        assert!(msg.contains("Closed: `2`"));
+        assert!(msg.contains("Failed: `1`"));
+        assert!(msg.contains("\\-1\\.25")); // PnL escaped
+        assert!(msg.contains("$40.00"));
+        assert!(!msg.contains("Timed out"));
+    }
+
     // -------------------------------------------------------------------------
     // RelationsDiscovered event formatting
     // -------------------------------------------------------------------------
@@ -722,22 +831,27 @@ mod tests {
 
         // These should all return None when disabled
         let opportunity = Event::OpportunityDetected(OpportunityEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "m1".to_string(),
             question: "Q".to_string(),
             edge: dec!(0.01),
             volume: dec!(10),
             expected_profit: dec!(0.1),
+            explanation: String::new(),
         });
         assert!(format_event_message(&opportunity, &config).is_none());
 
         let execution = Event::ExecutionCompleted(ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "m1".to_string(),
             success: true,
             details: "OK".to_string(),
+            failure_kind: None,
         });
         assert!(format_event_message(&execution, &config).is_none());
 
         let risk = Event::RiskRejected(RiskEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "m1".to_string(),
             reason: "Rejected".to_string(),
         });
@@ -772,11 +886,13 @@ mod tests {
     fn format_opportunity_escapes_special_chars_in_question() {
         let config = test_config(true, true, true);
         let event = Event::OpportunityDetected(OpportunityEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-123".to_string(),
             question: "Will the price go *up* (or down)?".to_string(),
             edge: dec!(0.05),
             volume: dec!(100),
             expected_profit: dec!(5),
+            explanation: String::new(),
         });
 
         let result = format_event_message(&event, &config);
@@ -792,9 +908,11 @@ mod tests {
     fn format_execution_escapes_special_chars_in_details() {
         let config = test_config(true, true, true);
         let event = Event::ExecutionCompleted(ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-123".to_string(),
             success: true,
             details: "Orders: [order_1, order_2]".to_string(),
+            failure_kind: None,
         });
 
         let result = format_event_message(&event, &config);
@@ -4,8 +4,9 @@
 //! interactive bot commands for controlling the arbitrage detector remotely.
 
 mod auth;
-mod command;
 mod format;
 
+pub mod chat;
+pub mod command;
 pub mod control;
 pub mod notifier;
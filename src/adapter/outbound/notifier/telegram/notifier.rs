@@ -17,6 +17,7 @@ use crate::port::{inbound::runtime::RuntimeState, outbound::stats::StatsRecorder
 use crate::port::{outbound::notifier::Event, outbound::notifier::Notifier};
 
 use super::auth::command_response_for_message;
+use super::chat::{parse_chat_list, TelegramChat};
 use super::command::bot_commands;
 use super::control::{RuntimeStats, TelegramControl};
 use super::format::format_event_message;
@@ -29,8 +30,8 @@ use super::format::format_event_message;
 pub struct TelegramConfig {
     /// Bot API token obtained from BotFather.
     pub bot_token: String,
-    /// Target chat ID for notifications.
-    pub chat_id: i64,
+    /// Chats that receive notifications and may issue commands.
+    pub chats: Vec<TelegramChat>,
     /// Send notifications for detected opportunities (can be noisy).
     pub notify_opportunities: bool,
     /// Send notifications for executed trades.
@@ -44,19 +45,28 @@ pub struct TelegramConfig {
 impl TelegramConfig {
     /// Create configuration from environment variables.
     ///
-    /// Reads `TELEGRAM_BOT_TOKEN`, `TELEGRAM_CHAT_ID`, and optionally
-    /// `TELEGRAM_NOTIFY_OPPORTUNITIES`. Returns `None` if required
+    /// Reads `TELEGRAM_BOT_TOKEN`, the chat list, and optionally
+    /// `TELEGRAM_NOTIFY_OPPORTUNITIES`. Chats come from the comma-separated
+    /// `TELEGRAM_CHAT_IDS` (with optional per-chat event filters), falling
+    /// back to a single `TELEGRAM_CHAT_ID`. Returns `None` if required
     /// variables are missing or invalid.
     #[must_use]
     pub fn from_env() -> Option<Self> {
         let bot_token = std::env::var("TELEGRAM_BOT_TOKEN").ok()?;
-        let chat_id = std::env::var("TELEGRAM_CHAT_ID")
-            .ok()
-            .and_then(|s| s.parse().ok())?;
+        let chats = match std::env::var("TELEGRAM_CHAT_IDS") {
+            Ok(list) => parse_chat_list(&list).ok()?,
+            Err(_) => vec![std::env::var("TELEGRAM_CHAT_ID")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .map(TelegramChat::new)?],
+        };
+        if chats.is_empty() {
+            return None;
+        }
 
         Some(Self {
             bot_token,
-            chat_id,
+            chats,
             notify_opportunities: std::env::var("TELEGRAM_NOTIFY_OPPORTUNITIES")
                 .map(|v| v == "true" || v == "1")
                 .unwrap_or(false),
@@ -67,7 +77,7 @@ impl TelegramConfig {
     }
 }
 
-/// Telegram notifier that sends messages to a chat.
+/// Telegram notifier that sends messages to the configured chats.
 ///
 /// Implements the [`Notifier`] trait and spawns background workers for
 /// message delivery and command handling.
@@ -154,20 +164,21 @@ impl Notifier for TelegramNotifier {
 /// Background worker that sends Telegram messages.
 async fn telegram_worker(config: TelegramConfig, mut receiver: mpsc::UnboundedReceiver<Event>) {
     let bot = Bot::new(&config.bot_token);
-    let chat_id = ChatId(config.chat_id);
 
-    info!(chat_id = config.chat_id, "Telegram notifier started");
+    info!(chats = config.chats.len(), "Telegram notifier started");
 
     while let Some(event) = receiver.recv().await {
-        let message = format_event_message(&event, &config);
+        let Some(text) = format_event_message(&event, &config) else {
+            continue;
+        };
 
-        if let Some(text) = message {
+        for chat in config.chats.iter().filter(|chat| chat.accepts(&event)) {
             if let Err(e) = bot
-                .send_message(chat_id, &text)
+                .send_message(ChatId(chat.id), &text)
                 .parse_mode(ParseMode::MarkdownV2)
                 .await
             {
-                error!(error = %e, "Failed to send Telegram message");
+                error!(error = %e, chat_id = chat.id, "Failed to send Telegram message");
             }
         }
     }
@@ -178,7 +189,7 @@ async fn telegram_worker(config: TelegramConfig, mut receiver: mpsc::UnboundedRe
 /// Background worker that handles inbound Telegram commands.
 async fn telegram_command_worker(config: TelegramConfig, control: TelegramControl) {
     let bot = Bot::new(&config.bot_token);
-    let allowed_chat = ChatId(config.chat_id);
+    let allowed_chats: Arc<[ChatId]> = config.chats.iter().map(|chat| ChatId(chat.id)).collect();
 
     // Register commands with Telegram so they appear in the "/" menu
     if let Err(e) = register_bot_commands(&bot).await {
@@ -186,19 +197,20 @@ async fn telegram_command_worker(config: TelegramConfig, control: TelegramContro
     }
 
     info!(
-        chat_id = config.chat_id,
+        chats = allowed_chats.len(),
         "Telegram command listener started"
     );
 
     teloxide::repl(bot, move |bot: Bot, msg: Message| {
         let control = control.clone();
+        let allowed_chats = Arc::clone(&allowed_chats);
         async move {
             let Some(text) = msg.text() else {
                 return respond(());
             };
 
             if let Some(response) =
-                command_response_for_message(text, msg.chat.id, allowed_chat, &control)
+                command_response_for_message(text, msg.chat.id, &allowed_chats, &control)
             {
                 if let Err(e) = bot.send_message(msg.chat.id, response).await {
                     error!(error = %e, "Failed to send Telegram command response");
@@ -226,6 +238,8 @@ async fn register_bot_commands(bot: &Bot) -> Result<(), teloxide::RequestError>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::adapter::outbound::notifier::telegram::chat::ChatEventKind;
+    use crate::domain::trade::{FailureKind, TradeResult};
     use crate::port::outbound::notifier::{ExecutionEvent, OpportunityEvent, RiskEvent};
     use rust_decimal_macros::dec;
     use std::sync::atomic::{AtomicUsize, Ordering};
@@ -281,7 +295,7 @@ mod tests {
 
         let config = TelegramConfig::from_env().unwrap();
         assert_eq!(config.bot_token, "test-token");
-        assert_eq!(config.chat_id, 12345);
+        assert_eq!(config.chats, vec![TelegramChat::new(12345)]);
         assert!(config.notify_opportunities);
         assert!(config.notify_executions);
         assert!(config.notify_risk_rejections);
@@ -344,7 +358,7 @@ mod tests {
         std::env::set_var("TELEGRAM_CHAT_ID", "-123456789");
 
         let config = TelegramConfig::from_env().unwrap();
-        assert_eq!(config.chat_id, -123456789);
+        assert_eq!(config.chats, vec![TelegramChat::new(-123456789)]);
 
         std::env::remove_var("TELEGRAM_BOT_TOKEN");
         std::env::remove_var("TELEGRAM_CHAT_ID");
@@ -378,6 +392,47 @@ mod tests {
         std::env::remove_var("TELEGRAM_CHAT_ID");
     }
 
+    #[test]
+    fn test_from_env_chat_ids_list() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("TELEGRAM_BOT_TOKEN", "test-token");
+        std::env::set_var("TELEGRAM_CHAT_ID", "1");
+        std::env::set_var(
+            "TELEGRAM_CHAT_IDS",
+            "-100111:opportunities,-100222:executions+risk",
+        );
+
+        let config = TelegramConfig::from_env().unwrap();
+        // TELEGRAM_CHAT_IDS takes precedence over TELEGRAM_CHAT_ID
+        assert_eq!(
+            config.chats,
+            vec![
+                TelegramChat::new(-100111).with_events(vec![ChatEventKind::Opportunities]),
+                TelegramChat::new(-100222)
+                    .with_events(vec![ChatEventKind::Executions, ChatEventKind::Risk]),
+            ]
+        );
+
+        std::env::remove_var("TELEGRAM_BOT_TOKEN");
+        std::env::remove_var("TELEGRAM_CHAT_ID");
+        std::env::remove_var("TELEGRAM_CHAT_IDS");
+    }
+
+    #[test]
+    fn test_from_env_invalid_chat_ids_list() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("TELEGRAM_BOT_TOKEN", "test-token");
+        std::env::set_var("TELEGRAM_CHAT_IDS", "12345,not-a-number");
+
+        assert!(TelegramConfig::from_env().is_none());
+
+        std::env::set_var("TELEGRAM_CHAT_IDS", " , ");
+        assert!(TelegramConfig::from_env().is_none());
+
+        std::env::remove_var("TELEGRAM_BOT_TOKEN");
+        std::env::remove_var("TELEGRAM_CHAT_IDS");
+    }
+
     // -------------------------------------------------------------------------
     // TelegramConfig struct tests
     // -------------------------------------------------------------------------
@@ -386,7 +441,7 @@ mod tests {
     fn test_config_clone() {
         let config = TelegramConfig {
             bot_token: "token".to_string(),
-            chat_id: 12345,
+            chats: vec![TelegramChat::new(12345)],
             notify_opportunities: true,
             notify_executions: true,
             notify_risk_rejections: false,
@@ -395,7 +450,7 @@ mod tests {
 
         let cloned = config.clone();
         assert_eq!(cloned.bot_token, "token");
-        assert_eq!(cloned.chat_id, 12345);
+        assert_eq!(cloned.chats, config.chats);
         assert!(cloned.notify_opportunities);
         assert!(cloned.notify_executions);
         assert!(!cloned.notify_risk_rejections);
@@ -406,7 +461,7 @@ mod tests {
     fn test_config_debug() {
         let config = TelegramConfig {
             bot_token: "secret-token".to_string(),
-            chat_id: 12345,
+            chats: vec![TelegramChat::new(12345)],
             notify_opportunities: true,
             notify_executions: true,
             notify_risk_rejections: true,
@@ -467,11 +522,13 @@ mod tests {
     #[test]
     fn test_opportunity_event_creation() {
         let event = OpportunityEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "test-market".to_string(),
             question: "Will it happen?".to_string(),
             edge: dec!(0.05),
             volume: dec!(100),
             expected_profit: dec!(5),
+            explanation: String::new(),
         };
 
         assert_eq!(event.market_id, "test-market");
@@ -484,9 +541,11 @@ mod tests {
     #[test]
     fn test_execution_event_success() {
         let event = ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-1".to_string(),
             success: true,
             details: "Order filled".to_string(),
+            failure_kind: None,
         };
 
         assert!(event.success);
@@ -496,18 +555,41 @@ mod tests {
     #[test]
     fn test_execution_event_failure() {
         let event = ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-1".to_string(),
             success: false,
             details: "Insufficient balance".to_string(),
+            failure_kind: None,
         };
 
         assert!(!event.success);
         assert_eq!(event.details, "Insufficient balance");
     }
 
+    #[test]
+    fn test_execution_event_from_failed_result_names_failure_kind() {
+        let rejected = TradeResult::Failed {
+            reason: "not enough balance / allowance".to_string(),
+            kind: FailureKind::InsufficientBalance,
+        };
+        let moved = TradeResult::Failed {
+            reason: "order crosses book".to_string(),
+            kind: FailureKind::PriceMoved,
+        };
+
+        let event = ExecutionEvent::from_result("opp-1", "market-1", &rejected);
+        assert_eq!(event.failure_kind, Some(FailureKind::InsufficientBalance));
+        assert!(event.details.starts_with("Rejected: insufficient balance"));
+
+        let event = ExecutionEvent::from_result("opp-1", "market-1", &moved);
+        assert_eq!(event.failure_kind, Some(FailureKind::PriceMoved));
+        assert!(event.details.starts_with("Rejected: price moved"));
+    }
+
     #[test]
     fn test_risk_event_creation() {
         let event = RiskEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "market-2".to_string(),
             reason: "Exceeds position limit".to_string(),
         };
@@ -595,7 +677,7 @@ mod integration_tests {
 
         Some(TelegramConfig {
             bot_token: token,
-            chat_id,
+            chats: vec![TelegramChat::new(chat_id)],
             notify_opportunities: true,
             notify_executions: true,
             notify_risk_rejections: true,
@@ -625,11 +707,13 @@ mod integration_tests {
         let notifier = TelegramNotifier::new(config);
 
         let event = Event::OpportunityDetected(OpportunityEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "integration-test-market".to_string(),
             question: "[Integration Test] Will this test pass?".to_string(),
             edge: dec!(0.05),
             volume: dec!(100),
             expected_profit: dec!(5.00),
+            explanation: String::new(),
         });
 
         notifier.notify(event);
@@ -645,9 +729,11 @@ mod integration_tests {
         let notifier = TelegramNotifier::new(config);
 
         let event = Event::ExecutionCompleted(ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "integration-test-market".to_string(),
             success: true,
             details: "[Integration Test] Order 12345 filled successfully".to_string(),
+            failure_kind: None,
         });
 
         notifier.notify(event);
@@ -662,6 +748,7 @@ mod integration_tests {
         let notifier = TelegramNotifier::new(config);
 
         let event = Event::RiskRejected(RiskEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "integration-test-market".to_string(),
             reason: "[Integration Test] Position would exceed max exposure limit".to_string(),
         });
@@ -754,11 +841,13 @@ mod integration_tests {
         // Send multiple notifications rapidly to test queuing
         for i in 0..5 {
             notifier.notify(Event::OpportunityDetected(OpportunityEvent {
+                opportunity_id: "opp-1".to_string(),
                 market_id: format!("rapid-test-{}", i),
                 question: format!("[Integration Test] Rapid notification #{}", i),
                 edge: dec!(0.01),
                 volume: dec!(10),
                 expected_profit: dec!(0.1),
+                explanation: String::new(),
             }));
         }
 
@@ -774,10 +863,12 @@ mod integration_tests {
 
         // Test with characters that need escaping in MarkdownV2
         let event = Event::ExecutionCompleted(ExecutionEvent {
+            opportunity_id: "opp-1".to_string(),
             market_id: "test_market-123.456".to_string(),
             success: true,
             details: "[Integration Test] Special chars: *bold* _italic_ `code` [link](url)"
                 .to_string(),
+            failure_kind: None,
         });
 
         notifier.notify(event);
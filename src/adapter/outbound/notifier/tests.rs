@@ -1,6 +1,6 @@
 use crate::port::{
     outbound::notifier::Event, outbound::notifier::Notifier, outbound::notifier::NotifierRegistry,
-    outbound::notifier::NullNotifier,
+    outbound::notifier::NullNotifier, outbound::notifier::RiskEvent,
 };
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
@@ -48,3 +48,34 @@ fn test_registry_len_and_is_empty() {
     assert!(!registry.is_empty());
     assert_eq!(registry.len(), 1);
 }
+
+#[test]
+fn test_event_kind_matches_serialized_type() {
+    let events = [
+        Event::CircuitBreakerReset,
+        Event::TradingHalted {
+            reason: "manual".to_string(),
+        },
+        Event::RiskRejected(RiskEvent {
+            opportunity_id: "opp-1".to_string(),
+            market_id: "m1".to_string(),
+            reason: "exposure limit".to_string(),
+        }),
+    ];
+
+    for event in events {
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], event.kind());
+    }
+}
+
+#[test]
+fn test_event_summary_is_one_line() {
+    let event = Event::RiskRejected(RiskEvent {
+        opportunity_id: "opp-1".to_string(),
+        market_id: "m1".to_string(),
+        reason: "exposure limit".to_string(),
+    });
+
+    assert_eq!(event.summary(), "m1: exposure limit");
+}
@@ -0,0 +1,57 @@
+//! Plain-text helpers shared by notification formatters.
+
+/// Truncate a string with ellipsis (Unicode-safe).
+pub fn truncate(s: &str, max_chars: usize) -> String {
+    let char_count = s.chars().count();
+    if char_count > max_chars {
+        let truncated: String = s.chars().take(max_chars).collect();
+        format!("{}...", truncated)
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("hello", 10), "hello");
+        assert_eq!(truncate("hello world", 5), "hello...");
+        assert_eq!(truncate("ab", 2), "ab");
+    }
+
+    #[test]
+    fn test_truncate_unicode() {
+        // Handles multi-byte UTF-8 characters without panic
+        assert_eq!(truncate("日本語テスト", 3), "日本語...");
+        assert_eq!(truncate("café", 4), "café");
+        assert_eq!(truncate("🎯🚀💰", 2), "🎯🚀...");
+    }
+
+    #[test]
+    fn test_truncate_exact_length() {
+        assert_eq!(truncate("hello", 5), "hello");
+    }
+
+    #[test]
+    fn test_truncate_one_over() {
+        assert_eq!(truncate("hello!", 5), "hello...");
+    }
+
+    #[test]
+    fn test_truncate_empty() {
+        assert_eq!(truncate("", 10), "");
+    }
+
+    #[test]
+    fn test_truncate_zero_max() {
+        assert_eq!(truncate("hello", 0), "...");
+    }
+
+    #[test]
+    fn test_truncate_one_char() {
+        assert_eq!(truncate("hello", 1), "h...");
+    }
+}
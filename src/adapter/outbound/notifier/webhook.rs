@@ -0,0 +1,201 @@
+//! Generic HTTP webhook notifier.
+//!
+//! Provides the [`WebhookNotifier`], which POSTs every [`Event`] as JSON to
+//! a configured URL. Server errors are retried with exponential backoff.
+//!
+//! When a secret is configured, each request carries an
+//! `X-Edgelord-Signature: sha256=<hex>` header holding the HMAC-SHA256 of the
+//! raw request body, so receivers can verify authenticity.
+
+use std::time::Duration;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use tokio::sync::mpsc;
+use tracing::{debug, error, info, warn};
+
+use crate::port::{outbound::notifier::Event, outbound::notifier::Notifier};
+
+/// Header carrying the request body signature.
+pub const SIGNATURE_HEADER: &str = "X-Edgelord-Signature";
+
+/// Delay before the first retry; doubled for each further attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Configuration for the webhook notifier.
+#[derive(Debug, Clone)]
+pub struct WebhookConfig {
+    /// Endpoint that receives events.
+    pub url: String,
+    /// Timeout for each delivery attempt.
+    pub timeout: Duration,
+    /// Maximum retries after a server error or failed request.
+    pub max_retries: u32,
+    /// Shared secret used to sign request bodies.
+    pub secret: Option<String>,
+}
+
+impl WebhookConfig {
+    /// Create configuration for `url` with default timeout and retries.
+    ///
+    /// Reads the signing secret from `WEBHOOK_SECRET` if it is set.
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            timeout: Duration::from_secs(5),
+            max_retries: 3,
+            secret: std::env::var("WEBHOOK_SECRET")
+                .ok()
+                .filter(|secret| !secret.is_empty()),
+        }
+    }
+}
+
+/// Notifier that POSTs events as JSON to a webhook.
+///
+/// Implements the [`Notifier`] trait and spawns a background worker for
+/// delivery.
+pub struct WebhookNotifier {
+    /// Channel sender for queuing outbound notifications.
+    sender: mpsc::UnboundedSender<Event>,
+}
+
+impl WebhookNotifier {
+    /// Create a new webhook notifier and spawn the background worker.
+    #[must_use]
+    pub fn new(config: WebhookConfig) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        // Spawn background task to handle outbound notifications.
+        tokio::spawn(webhook_worker(config, receiver));
+
+        Self { sender }
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    fn notify(&self, event: Event) {
+        if self.sender.send(event).is_err() {
+            warn!("Webhook notifier channel closed");
+        }
+    }
+}
+
+/// Compute the hex-encoded HMAC-SHA256 signature of `body`.
+#[must_use]
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Return the delay before retry number `attempt` (starting at 0).
+fn backoff_delay(attempt: u32) -> Duration {
+    INITIAL_BACKOFF * 2u32.saturating_pow(attempt)
+}
+
+/// Background worker that delivers events to the webhook.
+async fn webhook_worker(config: WebhookConfig, mut receiver: mpsc::UnboundedReceiver<Event>) {
+    let client = match reqwest::Client::builder().timeout(config.timeout).build() {
+        Ok(client) => client,
+        Err(e) => {
+            error!(error = %e, "Failed to build webhook HTTP client");
+            return;
+        }
+    };
+
+    info!(url = %config.url, signed = config.secret.is_some(), "Webhook notifier started");
+
+    while let Some(event) = receiver.recv().await {
+        let body = match serde_json::to_vec(&event) {
+            Ok(body) => body,
+            Err(e) => {
+                error!(error = %e, "Failed to serialize webhook event");
+                continue;
+            }
+        };
+        deliver(&client, &config, body).await;
+    }
+
+    warn!("Webhook notifier worker shutting down");
+}
+
+/// POST one event body, retrying server errors and failed requests.
+async fn deliver(client: &reqwest::Client, config: &WebhookConfig, body: Vec<u8>) {
+    let signature = config
+        .secret
+        .as_deref()
+        .map(|secret| format!("sha256={}", sign(secret, &body)));
+
+    for attempt in 0..=config.max_retries {
+        if attempt > 0 {
+            tokio::time::sleep(backoff_delay(attempt - 1)).await;
+        }
+
+        let mut request = client
+            .post(&config.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.clone());
+        if let Some(ref signature) = signature {
+            request = request.header(SIGNATURE_HEADER, signature);
+        }
+
+        match request.send().await {
+            Ok(response) if response.status().is_success() => return,
+            Ok(response) if response.status().is_server_error() => {
+                debug!(status = %response.status(), attempt, "Webhook server error");
+            }
+            Ok(response) => {
+                error!(status = %response.status(), "Webhook rejected event");
+                return;
+            }
+            Err(e) => debug!(error = %e, attempt, "Webhook request failed"),
+        }
+    }
+
+    error!(
+        attempts = config.max_retries + 1,
+        "Webhook delivery failed after retries"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::outbound::notifier::RiskEvent;
+
+    #[test]
+    fn sign_matches_known_vector() {
+        // RFC 4231 test case 2.
+        assert_eq!(
+            sign("Jefe", b"what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn backoff_doubles_each_attempt() {
+        assert_eq!(backoff_delay(0), Duration::from_millis(500));
+        assert_eq!(backoff_delay(1), Duration::from_millis(1000));
+        assert_eq!(backoff_delay(3), Duration::from_millis(4000));
+    }
+
+    #[test]
+    fn events_serialize_with_type_tag() {
+        let risk = Event::RiskRejected(RiskEvent {
+            opportunity_id: "opp-1".to_string(),
+            market_id: "m1".to_string(),
+            reason: "exposure limit".to_string(),
+        });
+        let json = serde_json::to_value(&risk).unwrap();
+        assert_eq!(json["type"], "risk_rejected");
+        assert_eq!(json["opportunity_id"], "opp-1");
+        assert_eq!(json["market_id"], "m1");
+        assert_eq!(json["reason"], "exposure limit");
+
+        let reset = serde_json::to_value(Event::CircuitBreakerReset).unwrap();
+        assert_eq!(reset, serde_json::json!({"type": "circuit_breaker_reset"}));
+    }
+}
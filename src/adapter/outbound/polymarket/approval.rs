@@ -6,7 +6,7 @@
 use std::str::FromStr;
 
 use alloy_primitives::{Address, U256};
-use alloy_provider::ProviderBuilder;
+use alloy_provider::{Provider, ProviderBuilder};
 use alloy_signer_local::PrivateKeySigner;
 use alloy_sol_types::sol;
 use async_trait::async_trait;
@@ -18,7 +18,7 @@ use super::settings::{Environment, PolymarketRuntimeConfig};
 use crate::error::{ConfigError, ExecutionError, Result};
 use crate::port::{
     outbound::approval::ApprovalResult, outbound::approval::ApprovalStatus,
-    outbound::approval::TokenApproval,
+    outbound::approval::TokenApproval, outbound::exchange::GasBalanceFetcher,
 };
 
 /// Native USDC contract address on Polygon mainnet.
@@ -42,6 +42,9 @@ const AMOY_RPC: &str = "https://rpc-amoy.polygon.technology";
 /// Number of decimals for USDC token.
 const USDC_DECIMALS: u32 = 6;
 
+/// Number of decimals for the native gas token (POL).
+const NATIVE_DECIMALS: u32 = 18;
+
 // ERC-20 interface (minimal for approval)
 sol! {
     #[sol(rpc)]
@@ -160,6 +163,17 @@ impl PolymarketApproval {
         Decimal::from(int_val) / Decimal::from(10u64.pow(USDC_DECIMALS))
     }
 
+    /// Convert native token base units (wei) to whole tokens.
+    ///
+    /// Scales down to gwei first so balances fit in a `u128`.
+    fn from_native_units(units: U256) -> Decimal {
+        let gwei_scale = 10u64.pow(NATIVE_DECIMALS / 2);
+        let gwei: u128 = (units / U256::from(gwei_scale))
+            .try_into()
+            .unwrap_or(u128::MAX);
+        Decimal::from(gwei) / Decimal::from(gwei_scale)
+    }
+
     /// Return the wallet address derived from the private key.
     #[must_use]
     pub fn wallet_address(&self) -> Address {
@@ -191,6 +205,31 @@ impl PolymarketApproval {
         Ok(Self::from_usdc_units(balance))
     }
 
+    /// Query the native gas token (POL) balance for the wallet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the RPC call fails.
+    pub async fn native_balance(&self) -> Result<Decimal> {
+        let rpc_url: url::Url =
+            self.rpc_url()
+                .parse()
+                .map_err(|e: url::ParseError| ConfigError::InvalidValue {
+                    field: "rpc_url",
+                    reason: e.to_string(),
+                })?;
+        let provider = ProviderBuilder::new().connect_http(rpc_url);
+
+        let balance = provider
+            .get_balance(self.signer.address())
+            .await
+            .map_err(|e| {
+                ExecutionError::SubmissionFailed(format!("Failed to get gas balance: {e}"))
+            })?;
+
+        Ok(Self::from_native_units(balance))
+    }
+
     /// Transfer the full USDC balance to another address.
     ///
     /// # Errors
@@ -325,6 +364,17 @@ impl TokenApproval for PolymarketApproval {
     }
 }
 
+#[async_trait]
+impl GasBalanceFetcher for PolymarketApproval {
+    async fn get_gas_balance(&self) -> Result<Decimal> {
+        self.native_balance().await
+    }
+
+    fn gas_token(&self) -> &'static str {
+        "POL"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -405,6 +455,20 @@ mod tests {
         assert_eq!(result, dec!(0.5));
     }
 
+    #[test]
+    fn from_native_units_converts_wei_to_tokens() {
+        // 1.5 POL = 1.5e18 wei
+        let result =
+            PolymarketApproval::from_native_units(U256::from(1_500_000_000_000_000_000u128));
+        assert_eq!(result, dec!(1.5));
+    }
+
+    #[test]
+    fn from_native_units_truncates_below_gwei() {
+        let result = PolymarketApproval::from_native_units(U256::from(999_999_999u64));
+        assert_eq!(result, dec!(0));
+    }
+
     #[test]
     fn from_usdc_units_handles_large_amounts() {
         // 10,000,000,000 base units = 10,000 USDC
@@ -1,28 +1,68 @@
 //! Polymarket REST API client.
 //!
-//! Supports two API surfaces:
+//! Supports three API surfaces:
 //! - **CLOB API** (`clob.polymarket.com`) — order execution, order book queries
 //! - **Gamma API** (`gamma-api.polymarket.com`) — market discovery with
 //!   volume, liquidity, and outcome metadata
+//! - **Data API** (`data-api.polymarket.com`) — shares held by a wallet
 //!
 //! Market discovery uses the Gamma API for richer data (volume/liquidity).
 //! All other operations (WS streaming, order execution) use the CLOB API.
 
+use std::collections::HashMap;
+use std::sync::OnceLock;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
 use reqwest::Client as HttpClient;
+use rust_decimal::Decimal;
 use tokio::time::sleep;
 use tracing::{debug, info, warn};
 
-use super::dto::response::{GammaMarket, PolymarketMarket, PolymarketMarketsResponse};
-use super::settings::PolymarketConfig;
+use super::dto::response::{
+    DataApiPosition, GammaMarket, PolymarketMarket, PolymarketMarketsResponse,
+};
+use super::settings::{PolymarketConfig, PolymarketHttpConfig};
+use crate::domain::id::{MarketId, TokenId};
 use crate::error::Result;
 use crate::port::{
-    outbound::exchange::MarketFetcher, outbound::exchange::MarketInfo,
-    outbound::exchange::OutcomeInfo,
+    outbound::exchange::Holding, outbound::exchange::HoldingsFetcher,
+    outbound::exchange::MarketEvent, outbound::exchange::MarketFetcher,
+    outbound::exchange::MarketInfo, outbound::exchange::OutcomeInfo,
+    outbound::exchange::SettlementFetcher,
 };
 
+/// HTTP clients shared by every [`PolymarketClient`] built from the same
+/// settings, so the market, settlement, and holdings fetchers reuse one
+/// connection pool instead of each opening their own.
+static SHARED_HTTP: OnceLock<Mutex<HashMap<PolymarketHttpConfig, HttpClient>>> = OnceLock::new();
+
+/// Return the shared HTTP client for `config`, building it on first use.
+fn shared_http_client(config: &PolymarketHttpConfig) -> HttpClient {
+    let clients = SHARED_HTTP.get_or_init(|| Mutex::new(HashMap::new()));
+    clients
+        .lock()
+        .entry(config.clone())
+        .or_insert_with(|| build_http_client(config))
+        .clone()
+}
+
+/// Build an HTTP client with the configured timeouts and pool limits.
+fn build_http_client(config: &PolymarketHttpConfig) -> HttpClient {
+    HttpClient::builder()
+        .timeout(Duration::from_millis(config.timeout_ms))
+        .connect_timeout(Duration::from_millis(config.connect_timeout_ms))
+        .pool_idle_timeout(Duration::from_secs(config.pool_idle_timeout_secs))
+        .pool_max_idle_per_host(config.pool_max_idle_per_host)
+        .build()
+        .unwrap_or_else(|err| {
+            warn!(error = %err, "Failed to build HTTP client, using defaults");
+            HttpClient::new()
+        })
+}
+
 /// HTTP client for the Polymarket REST APIs.
 ///
 /// Handles fetching market data from both the CLOB and Gamma APIs.
@@ -33,8 +73,11 @@ pub struct PolymarketClient {
     base_url: String,
     /// Gamma API base URL (market discovery, volume/liquidity).
     gamma_url: String,
+    /// Data API base URL (wallet holdings).
+    data_url: String,
     retry_max_attempts: u32,
     retry_backoff_ms: u64,
+    retry_max_backoff_ms: u64,
 }
 
 impl PolymarketClient {
@@ -49,29 +92,28 @@ impl PolymarketClient {
         Self {
             http: HttpClient::new(),
             gamma_url: "https://gamma-api.polymarket.com".into(),
+            data_url: "https://data-api.polymarket.com".into(),
             base_url,
             retry_max_attempts: 1,
             retry_backoff_ms: 0,
+            retry_max_backoff_ms: 0,
         }
     }
 
+    /// Create a client from configuration.
+    ///
+    /// Clients with the same HTTP settings share one underlying connection
+    /// pool.
     #[must_use]
     pub fn from_config(config: &PolymarketConfig) -> Self {
-        let http = HttpClient::builder()
-            .timeout(Duration::from_millis(config.http.timeout_ms))
-            .connect_timeout(Duration::from_millis(config.http.connect_timeout_ms))
-            .build()
-            .unwrap_or_else(|err| {
-                warn!(error = %err, "Failed to build HTTP client, using defaults");
-                HttpClient::new()
-            });
-
         Self {
-            http,
+            http: shared_http_client(&config.http),
             base_url: config.api_url.clone(),
             gamma_url: config.gamma_api_url.clone(),
+            data_url: config.data_api_url.clone(),
             retry_max_attempts: config.http.retry_max_attempts,
             retry_backoff_ms: config.http.retry_backoff_ms,
+            retry_max_backoff_ms: config.http.retry_max_backoff_ms,
         }
     }
 
@@ -98,7 +140,13 @@ impl PolymarketClient {
 
             let response = match response.error_for_status() {
                 Ok(response) => response,
-                Err(err) => return Err(err.into()),
+                Err(err) => {
+                    if attempt >= max_attempts || !Self::should_retry(&err) {
+                        return Err(err.into());
+                    }
+                    self.backoff(attempt, max_attempts, &err).await;
+                    continue;
+                }
             };
 
             let parsed = response.json::<T>().await;
@@ -114,19 +162,38 @@ impl PolymarketClient {
         }
     }
 
+    /// Whether an error is transient: a timeout, a failed connection, or a
+    /// server-side (5xx) response.
     fn should_retry(err: &reqwest::Error) -> bool {
-        err.is_timeout() || err.is_connect()
+        err.is_timeout()
+            || err.is_connect()
+            || err.status().is_some_and(|status| status.is_server_error())
+    }
+
+    /// Delay before retrying after the given failed attempt.
+    ///
+    /// Starts at the configured backoff and doubles per attempt, capped at
+    /// the configured maximum.
+    fn retry_delay(&self, attempt: u32) -> Duration {
+        let factor = 1u64 << attempt.saturating_sub(1).min(16);
+        let delay_ms = self
+            .retry_backoff_ms
+            .saturating_mul(factor)
+            .min(self.retry_max_backoff_ms.max(self.retry_backoff_ms));
+        Duration::from_millis(delay_ms)
     }
 
     async fn backoff(&self, attempt: u32, max_attempts: u32, err: &reqwest::Error) {
+        let delay = self.retry_delay(attempt);
         warn!(
             attempt,
             max_attempts,
+            delay_ms = delay.as_millis() as u64,
             error = %err,
             "HTTP request failed, retrying"
         );
-        if self.retry_backoff_ms > 0 {
-            sleep(Duration::from_millis(self.retry_backoff_ms)).await;
+        if !delay.is_zero() {
+            sleep(delay).await;
         }
     }
 
@@ -167,6 +234,62 @@ impl PolymarketClient {
 
         Ok(markets)
     }
+
+    /// Fetch a single market by condition ID from the CLOB API.
+    ///
+    /// Unlike the listing endpoints, this also returns closed markets along
+    /// with the winning token once the market has resolved.
+    pub async fn get_market(&self, condition_id: &str) -> Result<PolymarketMarket> {
+        let url = format!("{}/markets/{}", self.base_url, condition_id);
+        debug!(url = %url, "Fetching market (CLOB)");
+        self.get_with_retry(&url).await
+    }
+
+    /// Fetch the outcome shares held by a wallet from the Data API.
+    pub async fn get_wallet_positions(&self, user: &str) -> Result<Vec<DataApiPosition>> {
+        let url = format!(
+            "{}/positions?user={}&sizeThreshold=0&limit=500",
+            self.data_url, user
+        );
+        debug!(url = %url, "Fetching wallet positions (Data)");
+        self.get_with_retry(&url).await
+    }
+}
+
+/// Holdings of a single wallet, read from the Polymarket Data API.
+pub struct PolymarketHoldings {
+    client: PolymarketClient,
+    /// Wallet address whose holdings are fetched.
+    user: String,
+}
+
+impl PolymarketHoldings {
+    /// Create a holdings fetcher for the given wallet address.
+    #[must_use]
+    pub fn new(client: PolymarketClient, user: String) -> Self {
+        Self { client, user }
+    }
+}
+
+#[async_trait]
+impl HoldingsFetcher for PolymarketHoldings {
+    async fn get_holdings(&self) -> Result<Vec<Holding>> {
+        let positions = self.client.get_wallet_positions(&self.user).await?;
+        Ok(positions.into_iter().filter_map(holding).collect())
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        "Polymarket"
+    }
+}
+
+/// Convert a Data API position into a holding, skipping empty positions.
+fn holding(position: DataApiPosition) -> Option<Holding> {
+    let size = Decimal::try_from(position.size).ok()?;
+    (size > Decimal::ZERO).then(|| Holding {
+        token_id: TokenId::from(position.asset),
+        size,
+    })
 }
 
 #[async_trait]
@@ -181,6 +304,53 @@ impl MarketFetcher for PolymarketClient {
     }
 }
 
+#[async_trait]
+impl SettlementFetcher for PolymarketClient {
+    async fn get_settlements(&self, market_ids: &[MarketId]) -> Result<Vec<MarketEvent>> {
+        let mut events = Vec::new();
+        let mut last_error = None;
+        let mut fetched = 0usize;
+
+        for market_id in market_ids {
+            match self.get_market(market_id.as_str()).await {
+                Ok(market) => {
+                    fetched += 1;
+                    events.extend(settlement_event(&market));
+                }
+                Err(error) => {
+                    warn!(market_id = %market_id, error = %error, "Failed to fetch market status");
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        match last_error {
+            Some(error) if fetched == 0 => Err(error),
+            _ => Ok(events),
+        }
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        "Polymarket"
+    }
+}
+
+/// Build a settlement event for a market that has resolved.
+///
+/// A market counts as resolved once it is closed and one of its tokens is
+/// flagged as the winner, which then pays out $1 per share.
+fn settlement_event(market: &PolymarketMarket) -> Option<MarketEvent> {
+    if !market.closed {
+        return None;
+    }
+    let winner = market.tokens.iter().find(|token| token.winner)?;
+    Some(MarketEvent::MarketSettled {
+        market_id: MarketId::from(market.condition_id.clone()),
+        winning_outcome: winner.outcome.clone(),
+        payout_per_share: Decimal::ONE,
+    })
+}
+
 // ---------------------------------------------------------------------------
 // MarketInfo conversions
 // ---------------------------------------------------------------------------
@@ -197,11 +367,14 @@ impl From<PolymarketMarket> for MarketInfo {
                     token_id: t.token_id,
                     name: t.outcome,
                     price: t.price,
+                    volume_24h: None,
+                    liquidity: None,
                 })
                 .collect(),
             active: m.active && !m.closed,
             volume_24h: m.volume_24h,
             liquidity: m.liquidity,
+            close_time: m.end_date_iso.as_deref().and_then(parse_end_date),
         }
     }
 }
@@ -212,6 +385,8 @@ impl From<GammaMarket> for MarketInfo {
         let names = m.outcome_names();
         let prices = m.outcome_prices();
 
+        // Both outcomes of a condition trade against one mirrored book, so
+        // each reports the condition's volume and liquidity.
         let outcomes = token_ids
             .into_iter()
             .enumerate()
@@ -219,6 +394,8 @@ impl From<GammaMarket> for MarketInfo {
                 token_id,
                 name: names.get(i).cloned().unwrap_or_default(),
                 price: prices.get(i).copied(),
+                volume_24h: m.volume_24hr,
+                liquidity: m.liquidity_num,
             })
             .collect();
 
@@ -229,10 +406,18 @@ impl From<GammaMarket> for MarketInfo {
             active: m.active && !m.closed,
             volume_24h: m.volume_24hr,
             liquidity: m.liquidity_num,
+            close_time: m.end_date.as_deref().and_then(parse_end_date),
         }
     }
 }
 
+/// Parse an RFC 3339 end date, ignoring values that fail to parse.
+fn parse_end_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -262,11 +447,12 @@ mod tests {
         let config = PolymarketConfig {
             api_url: "https://custom-clob.com".into(),
             gamma_api_url: "https://custom-gamma.com".into(),
-            http: super::super::settings::PolymarketHttpConfig {
+            http: PolymarketHttpConfig {
                 timeout_ms: 10000,
                 connect_timeout_ms: 5000,
                 retry_max_attempts: 5,
                 retry_backoff_ms: 1000,
+                ..Default::default()
             },
             ..Default::default()
         };
@@ -275,6 +461,25 @@ mod tests {
         assert_eq!(client.exchange_name(), "Polymarket");
     }
 
+    #[test]
+    fn retry_delay_doubles_up_to_the_cap() {
+        let config = PolymarketConfig {
+            http: PolymarketHttpConfig {
+                retry_backoff_ms: 500,
+                retry_max_backoff_ms: 3000,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let client = PolymarketClient::from_config(&config);
+
+        assert_eq!(client.retry_delay(1), Duration::from_millis(500));
+        assert_eq!(client.retry_delay(2), Duration::from_millis(1000));
+        assert_eq!(client.retry_delay(3), Duration::from_millis(2000));
+        assert_eq!(client.retry_delay(4), Duration::from_millis(3000));
+        assert_eq!(client.retry_delay(40), Duration::from_millis(3000));
+    }
+
     #[test]
     fn client_from_default_config() {
         let config = PolymarketConfig::default();
@@ -323,17 +528,20 @@ mod tests {
                     token_id: "yes-token".into(),
                     outcome: "Yes".into(),
                     price: Some(0.65),
+                    winner: false,
                 },
                 PolymarketToken {
                     token_id: "no-token".into(),
                     outcome: "No".into(),
                     price: Some(0.35),
+                    winner: false,
                 },
             ],
             active: true,
             closed: false,
             volume_24h: Some(10000.0),
             liquidity: Some(5000.0),
+            end_date_iso: None,
         };
 
         let info = MarketInfo::from(market);
@@ -365,6 +573,7 @@ mod tests {
             closed: false,
             volume_24h: None,
             liquidity: None,
+            end_date_iso: None,
         };
 
         let info = MarketInfo::from(market);
@@ -381,6 +590,7 @@ mod tests {
             closed: true, // closed flag takes precedence
             volume_24h: None,
             liquidity: None,
+            end_date_iso: None,
         };
 
         let info = MarketInfo::from(market);
@@ -397,6 +607,7 @@ mod tests {
             closed: false,
             volume_24h: None,
             liquidity: None,
+            end_date_iso: None,
         };
 
         let info = MarketInfo::from(market);
@@ -413,6 +624,7 @@ mod tests {
             closed: false,
             volume_24h: None,
             liquidity: None,
+            end_date_iso: None,
         };
 
         let info = MarketInfo::from(market);
@@ -429,6 +641,7 @@ mod tests {
             closed: false,
             volume_24h: None,
             liquidity: None,
+            end_date_iso: None,
         };
 
         let info = MarketInfo::from(market);
@@ -445,28 +658,107 @@ mod tests {
                     token_id: "token-1".into(),
                     outcome: "A".into(),
                     price: None,
+                    winner: false,
                 },
                 PolymarketToken {
                     token_id: "token-2".into(),
                     outcome: "B".into(),
                     price: None,
+                    winner: false,
                 },
                 PolymarketToken {
                     token_id: "token-3".into(),
                     outcome: "C".into(),
                     price: None,
+                    winner: false,
                 },
             ],
             active: true,
             closed: false,
             volume_24h: None,
             liquidity: None,
+            end_date_iso: None,
         };
 
         let ids = market.token_ids();
         assert_eq!(ids, vec!["token-1", "token-2", "token-3"]);
     }
 
+    // -------------------------------------------------------------------------
+    // Settlement Tests
+    // -------------------------------------------------------------------------
+
+    fn resolved_market(closed: bool, winner: Option<&str>) -> PolymarketMarket {
+        let token = |outcome: &str| PolymarketToken {
+            token_id: format!("{}-token", outcome.to_lowercase()),
+            outcome: outcome.into(),
+            price: None,
+            winner: winner == Some(outcome),
+        };
+        PolymarketMarket {
+            condition_id: "cond-123".into(),
+            question: None,
+            tokens: vec![token("Yes"), token("No")],
+            active: false,
+            closed,
+            volume_24h: None,
+            liquidity: None,
+            end_date_iso: None,
+        }
+    }
+
+    #[test]
+    fn settlement_event_reports_winning_outcome() {
+        let event = settlement_event(&resolved_market(true, Some("No")));
+
+        match event {
+            Some(MarketEvent::MarketSettled {
+                market_id,
+                winning_outcome,
+                payout_per_share,
+            }) => {
+                assert_eq!(market_id.as_str(), "cond-123");
+                assert_eq!(winning_outcome, "No");
+                assert_eq!(payout_per_share, Decimal::ONE);
+            }
+            other => panic!("expected settlement, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn settlement_event_ignores_open_market() {
+        assert!(settlement_event(&resolved_market(false, Some("Yes"))).is_none());
+    }
+
+    #[test]
+    fn settlement_event_ignores_closed_market_without_winner() {
+        assert!(settlement_event(&resolved_market(true, None)).is_none());
+    }
+
+    #[test]
+    fn polymarket_token_winner_defaults_to_false() {
+        let token: PolymarketToken =
+            serde_json::from_str(r#"{"token_id":"t","outcome":"Yes","price":0.5}"#).unwrap();
+        assert!(!token.winner);
+    }
+
+    #[test]
+    fn holding_converts_data_api_position_and_skips_empty() {
+        let held = holding(DataApiPosition {
+            asset: "12345".into(),
+            size: 10.5,
+        })
+        .unwrap();
+        assert_eq!(held.token_id.as_str(), "12345");
+        assert_eq!(held.size, rust_decimal_macros::dec!(10.5));
+
+        assert!(holding(DataApiPosition {
+            asset: "12345".into(),
+            size: 0.0,
+        })
+        .is_none());
+    }
+
     // -------------------------------------------------------------------------
     // GammaMarket to MarketInfo Conversion Tests
     // -------------------------------------------------------------------------
@@ -484,6 +776,7 @@ mod tests {
             volume_24hr: Some(50000.0),
             volume_num: Some(1000000.0),
             liquidity_num: Some(25000.0),
+            end_date: Some("2026-11-05T12:00:00Z".into()),
         };
 
         let info = MarketInfo::from(market);
@@ -494,11 +787,18 @@ mod tests {
         assert_eq!(info.outcomes.len(), 2);
         assert_eq!(info.volume_24h, Some(50000.0));
         assert_eq!(info.liquidity, Some(25000.0));
+        assert_eq!(
+            info.close_time,
+            Some("2026-11-05T12:00:00Z".parse::<DateTime<Utc>>().unwrap())
+        );
 
         // Check outcomes
         assert_eq!(info.outcomes[0].token_id, "token-yes");
         assert_eq!(info.outcomes[0].name, "Yes");
         assert!((info.outcomes[0].price.unwrap() - 0.70).abs() < 0.01);
+        assert_eq!(info.outcomes[1].liquidity, Some(25000.0));
+        assert_eq!(info.outcomes[1].volume_24h, Some(50000.0));
+        assert_eq!(info.outcome_liquidity_balance(), Some(1.0));
     }
 
     #[test]
@@ -514,6 +814,7 @@ mod tests {
             volume_24hr: None,
             volume_num: None,
             liquidity_num: None,
+            end_date: None,
         };
 
         let info = MarketInfo::from(market);
@@ -539,6 +840,7 @@ mod tests {
             volume_24hr: None,
             volume_num: None,
             liquidity_num: None,
+            end_date: None,
         };
 
         let info = MarketInfo::from(market);
@@ -564,6 +866,7 @@ mod tests {
             volume_24hr: None,
             volume_num: None,
             liquidity_num: None,
+            end_date: None,
         };
 
         let info = MarketInfo::from(market);
@@ -616,16 +919,21 @@ mod tests {
                     token_id: "t1".into(),
                     name: "A".into(),
                     price: None,
+                    volume_24h: None,
+                    liquidity: None,
                 },
                 OutcomeInfo {
                     token_id: "t2".into(),
                     name: "B".into(),
                     price: None,
+                    volume_24h: None,
+                    liquidity: None,
                 },
             ],
             active: true,
             volume_24h: None,
             liquidity: None,
+            close_time: None,
         };
 
         let ids = info.token_ids();
@@ -642,16 +950,21 @@ mod tests {
                     token_id: "yes".into(),
                     name: "Yes".into(),
                     price: None,
+                    volume_24h: None,
+                    liquidity: None,
                 },
                 OutcomeInfo {
                     token_id: "no".into(),
                     name: "No".into(),
                     price: None,
+                    volume_24h: None,
+                    liquidity: None,
                 },
             ],
             active: true,
             volume_24h: None,
             liquidity: None,
+            close_time: None,
         };
 
         assert!(info.is_binary());
@@ -667,10 +980,13 @@ mod tests {
                 token_id: "only".into(),
                 name: "Only".into(),
                 price: None,
+                volume_24h: None,
+                liquidity: None,
             }],
             active: true,
             volume_24h: None,
             liquidity: None,
+            close_time: None,
         };
         assert!(!info1.is_binary());
 
@@ -683,21 +999,28 @@ mod tests {
                     token_id: "a".into(),
                     name: "A".into(),
                     price: None,
+                    volume_24h: None,
+                    liquidity: None,
                 },
                 OutcomeInfo {
                     token_id: "b".into(),
                     name: "B".into(),
                     price: None,
+                    volume_24h: None,
+                    liquidity: None,
                 },
                 OutcomeInfo {
                     token_id: "c".into(),
                     name: "C".into(),
                     price: None,
+                    volume_24h: None,
+                    liquidity: None,
                 },
             ],
             active: true,
             volume_24h: None,
             liquidity: None,
+            close_time: None,
         };
         assert!(!info3.is_binary());
 
@@ -709,6 +1032,7 @@ mod tests {
             active: true,
             volume_24h: None,
             liquidity: None,
+            close_time: None,
         };
         assert!(!info0.is_binary());
     }
@@ -0,0 +1,424 @@
+//! Fill deadlines for arbitrage leg orders.
+//!
+//! A limit order that rests on the book instead of matching leaves the other
+//! legs unhedged for as long as it lingers. [`place_leg`] submits a leg, waits
+//! for the venue to confirm it filled, and cancels it once the leg timeout
+//! passes so the leg is reported as failed and the partial-fill policy runs.
+//! A cancel that fails usually means the order matched in the meantime, so
+//! the leg's status is re-queried and a filled (or unknown) leg is kept.
+//! [`place_legs`] does this for every leg of an opportunity at once.
+
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures_util::FutureExt;
+use tracing::{error, warn};
+
+use crate::domain::id::OrderId;
+use crate::error::Result;
+use crate::port::outbound::exchange::{OrderExecutor, OrderRequest};
+
+/// State of a leg order right after submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Placement {
+    /// The order matched in full on submission.
+    Filled(OrderId),
+    /// The order was accepted but is still resting on the book.
+    Resting(OrderId),
+}
+
+/// Order venue that can report whether a submitted order has filled.
+#[async_trait]
+pub trait LegOrders: OrderExecutor {
+    /// Submit a leg order and report whether it filled immediately.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order is rejected or cannot be submitted.
+    async fn place(&self, order: &OrderRequest) -> Result<Placement>;
+
+    /// Wait until a resting order is confirmed fully filled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order is cancelled by the exchange or its
+    /// status cannot be fetched.
+    async fn wait_for_fill(&self, order_id: &OrderId) -> Result<()>;
+
+    /// Check once whether an order is fully filled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order status cannot be fetched.
+    async fn is_filled(&self, order_id: &OrderId) -> Result<bool>;
+}
+
+/// Place a leg order and wait up to `timeout` for it to fill.
+///
+/// Returns the order ID once the leg is confirmed filled. A leg still resting
+/// when the deadline passes is cancelled and returned as an error. If the
+/// cancel fails, the leg is kept as filled unless its status confirms it
+/// never filled, so recovery accounts for a fill it could not rule out. With
+/// no timeout, acceptance by the exchange counts as a fill.
+pub async fn place_leg(
+    orders: &dyn LegOrders,
+    order: &OrderRequest,
+    timeout: Option<Duration>,
+) -> std::result::Result<OrderId, String> {
+    let order_id = match orders.place(order).await.map_err(|e| e.to_string())? {
+        Placement::Filled(order_id) => return Ok(order_id),
+        Placement::Resting(order_id) => order_id,
+    };
+
+    let Some(timeout) = timeout else {
+        return Ok(order_id);
+    };
+
+    match tokio::time::timeout(timeout, orders.wait_for_fill(&order_id)).await {
+        Ok(Ok(())) => Ok(order_id),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => {
+            let waited = timeout.as_millis();
+            warn!(
+                order_id = %order_id,
+                token_id = %order.token_id,
+                timeout_ms = waited,
+                "Leg not filled before deadline, cancelling"
+            );
+            let cancel_error = match orders.cancel(&order_id).await {
+                Ok(()) => {
+                    return Err(format!(
+                        "order {order_id} not filled within {waited}ms, cancelled"
+                    ))
+                }
+                Err(e) => e,
+            };
+            match orders.is_filled(&order_id).await {
+                Ok(false) => Err(format!(
+                    "order {order_id} not filled within {waited}ms, cancel failed: {cancel_error}"
+                )),
+                Ok(true) => {
+                    warn!(order_id = %order_id, "Leg filled while cancelling, keeping it");
+                    Ok(order_id)
+                }
+                Err(status_error) => {
+                    warn!(
+                        order_id = %order_id,
+                        cancel_error = %cancel_error,
+                        status_error = %status_error,
+                        "Leg status unknown after failed cancel, treating it as filled"
+                    );
+                    Ok(order_id)
+                }
+            }
+        }
+    }
+}
+
+/// Place every leg concurrently and wait for all of them to settle.
+///
+/// Submitting the legs together keeps the gap between the first and last
+/// leg reaching the book as small as possible. A leg that panics is reported
+/// as failed without disturbing the others. Results are sorted by token ID
+/// so reports are deterministic regardless of completion order.
+pub async fn place_legs(
+    orders: &dyn LegOrders,
+    legs: Vec<OrderRequest>,
+    timeout: Option<Duration>,
+) -> Vec<(OrderRequest, std::result::Result<OrderId, String>)> {
+    let futures = legs.into_iter().map(|order| async move {
+        let result = AssertUnwindSafe(place_leg(orders, &order, timeout))
+            .catch_unwind()
+            .await
+            .unwrap_or_else(|panic| {
+                let reason = panic_message(panic.as_ref());
+                error!(token_id = %order.token_id, reason = %reason, "Leg task panicked");
+                Err(format!("leg panicked: {reason}"))
+            });
+        (order, result)
+    });
+
+    let mut results = futures_util::future::join_all(futures).await;
+    results.sort_by(|(a, _), (b, _)| a.token_id.cmp(&b.token_id));
+    results
+}
+
+/// Extract a readable message from a panic payload.
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use parking_lot::Mutex;
+    use rust_decimal_macros::dec;
+
+    use crate::error::ExecutionError;
+    use crate::port::outbound::exchange::{ExecutionResult, OrderSide};
+
+    /// Venue whose orders rest and fill after a fixed delay.
+    struct DelayedOrders {
+        fill_delay: Duration,
+        matched_on_submit: bool,
+        fail_cancel: bool,
+        /// Status reported after a cancel, `None` when the lookup fails.
+        filled_status: Option<bool>,
+        cancelled: Mutex<Vec<OrderId>>,
+    }
+
+    impl DelayedOrders {
+        fn new(fill_delay: Duration) -> Self {
+            Self {
+                fill_delay,
+                matched_on_submit: false,
+                fail_cancel: false,
+                filled_status: Some(false),
+                cancelled: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn cancelled(&self) -> Vec<OrderId> {
+            self.cancelled.lock().clone()
+        }
+    }
+
+    #[async_trait]
+    impl OrderExecutor for DelayedOrders {
+        async fn execute(&self, order: &OrderRequest) -> Result<ExecutionResult> {
+            Ok(ExecutionResult::Success {
+                order_id: OrderId::new(format!("order-{}", order.token_id)),
+                filled_amount: order.size,
+                average_price: order.price,
+            })
+        }
+
+        async fn cancel(&self, order_id: &OrderId) -> Result<()> {
+            self.cancelled.lock().push(order_id.clone());
+            if self.fail_cancel {
+                return Err(ExecutionError::OrderRejected("already matched".to_string()).into());
+            }
+            Ok(())
+        }
+
+        fn exchange_name(&self) -> &'static str {
+            "delayed"
+        }
+    }
+
+    #[async_trait]
+    impl LegOrders for DelayedOrders {
+        async fn place(&self, order: &OrderRequest) -> Result<Placement> {
+            let order_id = OrderId::new(format!("order-{}", order.token_id));
+            if self.matched_on_submit {
+                return Ok(Placement::Filled(order_id));
+            }
+            Ok(Placement::Resting(order_id))
+        }
+
+        async fn wait_for_fill(&self, _order_id: &OrderId) -> Result<()> {
+            tokio::time::sleep(self.fill_delay).await;
+            Ok(())
+        }
+
+        async fn is_filled(&self, _order_id: &OrderId) -> Result<bool> {
+            self.filled_status.ok_or_else(|| {
+                ExecutionError::SubmissionFailed("status lookup failed".into()).into()
+            })
+        }
+    }
+
+    fn buy(token_id: &str) -> OrderRequest {
+        OrderRequest {
+            token_id: token_id.to_string(),
+            side: OrderSide::Buy,
+            size: dec!(10),
+            price: dec!(0.45),
+        }
+    }
+
+    #[tokio::test]
+    async fn leg_filled_before_deadline_is_kept() {
+        let orders = DelayedOrders::new(Duration::from_millis(5));
+
+        let result = place_leg(&orders, &buy("yes"), Some(Duration::from_millis(50))).await;
+
+        assert_eq!(result, Ok(OrderId::new("order-yes")));
+        assert!(orders.cancelled().is_empty());
+    }
+
+    #[tokio::test]
+    async fn leg_past_deadline_is_cancelled() {
+        let orders = DelayedOrders::new(Duration::from_secs(60));
+
+        let err = place_leg(&orders, &buy("no"), Some(Duration::from_millis(50)))
+            .await
+            .unwrap_err();
+
+        assert_eq!(orders.cancelled(), vec![OrderId::new("order-no")]);
+        assert!(err.contains("not filled within 50ms"));
+        assert!(err.contains("cancelled"));
+    }
+
+    #[tokio::test]
+    async fn failed_cancel_of_unfilled_leg_is_reported() {
+        let mut orders = DelayedOrders::new(Duration::from_secs(60));
+        orders.fail_cancel = true;
+
+        let err = place_leg(&orders, &buy("no"), Some(Duration::from_millis(50)))
+            .await
+            .unwrap_err();
+
+        assert_eq!(orders.cancelled().len(), 1);
+        assert!(err.contains("cancel failed"));
+    }
+
+    #[tokio::test]
+    async fn failed_cancel_of_filled_leg_keeps_it() {
+        let mut orders = DelayedOrders::new(Duration::from_secs(60));
+        orders.fail_cancel = true;
+        orders.filled_status = Some(true);
+
+        let result = place_leg(&orders, &buy("no"), Some(Duration::from_millis(50))).await;
+
+        assert_eq!(result, Ok(OrderId::new("order-no")));
+        assert_eq!(orders.cancelled().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_cancel_with_unknown_status_counts_as_filled() {
+        let mut orders = DelayedOrders::new(Duration::from_secs(60));
+        orders.fail_cancel = true;
+        orders.filled_status = None;
+
+        let result = place_leg(&orders, &buy("no"), Some(Duration::from_millis(50))).await;
+
+        assert_eq!(result, Ok(OrderId::new("order-no")));
+    }
+
+    #[tokio::test]
+    async fn matched_leg_skips_waiting() {
+        let mut orders = DelayedOrders::new(Duration::from_secs(60));
+        orders.matched_on_submit = true;
+
+        let result = place_leg(&orders, &buy("yes"), Some(Duration::from_millis(50))).await;
+
+        assert_eq!(result, Ok(OrderId::new("order-yes")));
+        assert!(orders.cancelled().is_empty());
+    }
+
+    #[tokio::test]
+    async fn no_timeout_accepts_resting_leg() {
+        let orders = DelayedOrders::new(Duration::from_secs(60));
+
+        let result = place_leg(&orders, &buy("yes"), None).await;
+
+        assert_eq!(result, Ok(OrderId::new("order-yes")));
+        assert!(orders.cancelled().is_empty());
+    }
+
+    /// Venue whose orders only go through once every leg has been submitted.
+    struct BarrierOrders {
+        barrier: tokio::sync::Barrier,
+        panic_on: Option<&'static str>,
+    }
+
+    impl BarrierOrders {
+        fn new(legs: usize) -> Self {
+            Self {
+                barrier: tokio::sync::Barrier::new(legs),
+                panic_on: None,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OrderExecutor for BarrierOrders {
+        async fn execute(&self, _order: &OrderRequest) -> Result<ExecutionResult> {
+            unreachable!("legs are placed through LegOrders")
+        }
+
+        async fn cancel(&self, _order_id: &OrderId) -> Result<()> {
+            Ok(())
+        }
+
+        fn exchange_name(&self) -> &'static str {
+            "barrier"
+        }
+    }
+
+    #[async_trait]
+    impl LegOrders for BarrierOrders {
+        async fn place(&self, order: &OrderRequest) -> Result<Placement> {
+            if self.panic_on == Some(order.token_id.as_str()) {
+                panic!("venue exploded");
+            }
+            self.barrier.wait().await;
+            Ok(Placement::Filled(OrderId::new(format!(
+                "order-{}",
+                order.token_id
+            ))))
+        }
+
+        async fn wait_for_fill(&self, _order_id: &OrderId) -> Result<()> {
+            Ok(())
+        }
+
+        async fn is_filled(&self, _order_id: &OrderId) -> Result<bool> {
+            Ok(true)
+        }
+    }
+
+    #[tokio::test]
+    async fn legs_are_submitted_concurrently() {
+        // Each placement blocks until all three are in flight, so sequential
+        // submission would never finish.
+        let orders = BarrierOrders::new(3);
+        let legs = vec![buy("a"), buy("b"), buy("c")];
+
+        let results = tokio::time::timeout(Duration::from_secs(1), place_legs(&orders, legs, None))
+            .await
+            .expect("legs were not submitted concurrently");
+
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|(_, result)| result.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn results_are_sorted_by_token_id() {
+        let orders = BarrierOrders::new(3);
+        let legs = vec![buy("c"), buy("a"), buy("b")];
+
+        let results = place_legs(&orders, legs, None).await;
+
+        let tokens: Vec<_> = results
+            .iter()
+            .map(|(order, _)| order.token_id.as_str())
+            .collect();
+        assert_eq!(tokens, vec!["a", "b", "c"]);
+        assert_eq!(results[0].1, Ok(OrderId::new("order-a")));
+    }
+
+    #[tokio::test]
+    async fn panicking_leg_fails_without_aborting_others() {
+        let mut orders = BarrierOrders::new(2);
+        orders.panic_on = Some("b");
+        let legs = vec![buy("a"), buy("b"), buy("c")];
+
+        let results = place_legs(&orders, legs, None).await;
+
+        assert_eq!(results[0].1, Ok(OrderId::new("order-a")));
+        let err = results[1].1.as_ref().unwrap_err();
+        assert!(err.contains("venue exploded"));
+        assert_eq!(results[2].1, Ok(OrderId::new("order-c")));
+    }
+}
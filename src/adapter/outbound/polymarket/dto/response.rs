@@ -1,10 +1,12 @@
 //! Polymarket API response types.
 //!
-//! Two API surfaces:
+//! Three API surfaces:
 //! - **CLOB API** (`clob.polymarket.com`) — order execution, order book, WS streaming.
 //!   Uses [`PolymarketMarket`] and [`PolymarketMarketsResponse`].
 //! - **Gamma API** (`gamma-api.polymarket.com`) — market discovery, metadata,
 //!   volume/liquidity stats. Uses [`GammaMarket`].
+//! - **Data API** (`data-api.polymarket.com`) — wallet holdings. Uses
+//!   [`DataApiPosition`].
 
 use serde::Deserialize;
 use tracing::debug;
@@ -30,6 +32,9 @@ pub struct PolymarketMarket {
     /// Current liquidity depth in USD.
     #[serde(default)]
     pub liquidity: Option<f64>,
+    /// Scheduled end date in RFC 3339 format.
+    #[serde(default)]
+    pub end_date_iso: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -37,6 +42,9 @@ pub struct PolymarketToken {
     pub token_id: String,
     pub outcome: String,
     pub price: Option<f64>,
+    /// Whether this outcome won; only set once the market has resolved.
+    #[serde(default)]
+    pub winner: bool,
 }
 
 impl PolymarketMarket {
@@ -89,6 +97,9 @@ pub struct GammaMarket {
     /// Current liquidity depth in USD.
     #[serde(default, alias = "liquidityNum")]
     pub liquidity_num: Option<f64>,
+    /// Scheduled end date in RFC 3339 format.
+    #[serde(default)]
+    pub end_date: Option<String>,
 }
 
 impl GammaMarket {
@@ -151,10 +162,39 @@ impl GammaMarket {
     }
 }
 
+/// Outcome shares held by a wallet, from the Data API `/positions` endpoint.
+///
+/// Response format: flat JSON array (no wrapper object).
+#[derive(Debug, Deserialize)]
+pub struct DataApiPosition {
+    /// Outcome token ID.
+    pub asset: String,
+    /// Shares held.
+    pub size: f64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn data_api_position_ignores_extra_fields() {
+        let json = r#"[{
+            "proxyWallet": "0xabc",
+            "asset": "12345",
+            "conditionId": "0xdef",
+            "size": 10.5,
+            "avgPrice": 0.45,
+            "outcome": "Yes"
+        }]"#;
+
+        let positions: Vec<DataApiPosition> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].asset, "12345");
+        assert!((positions[0].size - 10.5).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn gamma_market_deserializes_from_api_response() {
         let json = r#"{
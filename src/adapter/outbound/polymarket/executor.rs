@@ -6,21 +6,26 @@
 
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use alloy_signer_local::PrivateKeySigner;
 use async_trait::async_trait;
 use polymarket_client_sdk::auth::state::Authenticated;
 use polymarket_client_sdk::auth::{Normal, Signer};
 use polymarket_client_sdk::clob::types::response::PostOrderResponse;
-use polymarket_client_sdk::clob::types::Side;
+use polymarket_client_sdk::clob::types::{OrderStatusType, Side};
 use polymarket_client_sdk::clob::{Client, Config as ClobConfig};
 use polymarket_client_sdk::types::U256;
-use rust_decimal::Decimal;
-use tracing::{info, warn};
+use rust_decimal::{Decimal, RoundingStrategy};
+use tracing::{debug, info, warn};
 
+use super::deadline::{place_legs, LegOrders, Placement};
+use super::failure::classify;
+use super::recovery::{FailedLeg, FilledLeg, PartialFillRecovery};
 use super::settings::PolymarketRuntimeConfig;
 use crate::domain::{
-    id::OrderId, opportunity::Opportunity, trade::Failure, trade::Fill, trade::TradeResult,
+    id::OrderId, id::TokenId, opportunity::Opportunity, trade::Failure, trade::FailureKind,
+    trade::Fill, trade::TradeResult,
 };
 use crate::error::{ConfigError, ExecutionError, Result};
 use crate::port::{
@@ -32,6 +37,12 @@ use crate::port::{
 /// Type alias for the authenticated CLOB client.
 type AuthenticatedClient = Client<Authenticated<Normal>>;
 
+/// Decimal places of the finest CLOB tick size.
+pub(super) const PRICE_DECIMALS: u32 = 3;
+
+/// Interval between order status checks while a leg waits to fill.
+const FILL_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
 /// Trade executor for the Polymarket CLOB.
 ///
 /// Handles order signing, submission, and cancellation using the Polymarket
@@ -42,6 +53,16 @@ pub struct PolymarketExecutor {
     client: Arc<AuthenticatedClient>,
     /// Local signer for order signatures.
     signer: Arc<PrivateKeySigner>,
+    /// Fraction by which each leg's limit price is raised above the detected ask.
+    slippage_buffer: Decimal,
+    /// How to handle legs left unhedged by a partial fill.
+    recovery: PartialFillRecovery,
+    /// How long a resting leg may wait to fill before it is cancelled.
+    leg_timeout: Option<Duration>,
+    /// Increment limit prices are rounded to.
+    price_tick: Decimal,
+    /// Increment order sizes are rounded down to.
+    size_increment: Decimal,
 }
 
 impl PolymarketExecutor {
@@ -88,13 +109,60 @@ impl PolymarketExecutor {
         Ok(Self {
             client: Arc::new(client),
             signer: Arc::new(signer),
+            slippage_buffer: Decimal::ZERO,
+            recovery: PartialFillRecovery::default(),
+            leg_timeout: None,
+            price_tick: Decimal::new(1, PRICE_DECIMALS),
+            size_increment: Decimal::new(1, 2),
         })
     }
 
+    /// Set the slippage buffer applied to arbitrage leg limit prices.
+    ///
+    /// Each leg's limit price is raised by this fraction of its detected ask
+    /// (e.g., 0.02 = 2%), so orders still fill after small adverse moves.
+    #[must_use]
+    pub fn with_slippage_buffer(mut self, slippage_buffer: Decimal) -> Self {
+        self.slippage_buffer = slippage_buffer;
+        self
+    }
+
+    /// Set how partial fills are retried, unwound, or held.
+    #[must_use]
+    pub fn with_partial_fill_recovery(mut self, recovery: PartialFillRecovery) -> Self {
+        self.recovery = recovery;
+        self
+    }
+
+    /// Set how long each arbitrage leg may rest unfilled before it is cancelled.
+    ///
+    /// A cancelled leg counts as failed, so the partial-fill policy handles
+    /// the legs that did fill. `None` treats an accepted order as filled.
+    #[must_use]
+    pub fn with_leg_timeout(mut self, leg_timeout: Option<Duration>) -> Self {
+        self.leg_timeout = leg_timeout;
+        self
+    }
+
+    /// Set the price and size increments orders are rounded to.
+    ///
+    /// Leg limit prices are rounded up to `price_tick` and the trade volume
+    /// down to `size_increment`. Opportunities whose edge does not survive
+    /// the rounding are rejected.
+    #[must_use]
+    pub fn with_increments(mut self, price_tick: Decimal, size_increment: Decimal) -> Self {
+        self.price_tick = price_tick;
+        self.size_increment = size_increment;
+        self
+    }
+
     /// Execute an arbitrage opportunity by placing orders on all legs in parallel.
     ///
-    /// Submits buy orders for all legs concurrently and aggregates results
-    /// into success, partial fill, or failure outcomes.
+    /// Submits buy orders for all legs concurrently, waits for every leg to
+    /// settle, and aggregates results ordered by token ID
+    /// into success, partial fill, or failure outcomes. Legs still resting
+    /// after the leg timeout are cancelled and count as failed. Partial fills
+    /// are then resolved by the configured [`PartialFillRecovery`].
     async fn execute_arbitrage_impl(&self, opportunity: &Opportunity) -> Result<TradeResult> {
         info!(
             market = %opportunity.market_id(),
@@ -108,66 +176,121 @@ impl PolymarketExecutor {
         if legs.len() < 2 {
             return Ok(TradeResult::Failed {
                 reason: "Opportunity must have at least 2 legs".to_string(),
+                kind: FailureKind::Other,
             });
         }
 
-        let volume = opportunity.volume();
+        if opportunity.is_sell() {
+            return Ok(TradeResult::Failed {
+                reason: "Sell-side (negative risk) arbitrage is not supported on Polymarket"
+                    .to_string(),
+                kind: FailureKind::Other,
+            });
+        }
 
-        // Execute all legs in parallel
-        let futures: Vec<_> = legs
+        let orders = order_size(opportunity.volume(), self.size_increment).and_then(|volume| {
+            buffered_limit_prices(opportunity, self.slippage_buffer, self.price_tick)
+                .map(|prices| (volume, prices))
+        });
+        let (volume, limit_prices) = match orders {
+            Ok(orders) => orders,
+            Err(reason) => {
+                warn!(market = %opportunity.market_id(), reason = %reason, "Arbitrage rejected");
+                return Ok(TradeResult::Failed {
+                    reason,
+                    kind: FailureKind::Other,
+                });
+            }
+        };
+
+        // Submit all legs concurrently so they reach the book together
+        let orders: Vec<_> = legs
             .iter()
-            .map(|leg| {
-                let token_id = leg.token_id().clone();
-                let token_str = token_id.to_string();
-                let price = leg.ask_price();
-                async move {
-                    let result = self
-                        .submit_order(&token_str, Side::Buy, volume, price)
-                        .await;
-                    (token_id, result)
+            .zip(limit_prices)
+            .map(|(leg, price)| {
+                debug!(
+                    token = %leg.token_id(),
+                    ask = %leg.ask_price(),
+                    price = %price,
+                    source = leg.source(),
+                    "Submitting leg against selected price source"
+                );
+                OrderRequest {
+                    token_id: leg.token_id().to_string(),
+                    side: OrderSide::Buy,
+                    size: volume,
+                    price,
                 }
             })
             .collect();
 
-        let results = futures_util::future::join_all(futures).await;
+        let results = place_legs(self, orders, self.leg_timeout).await;
 
-        // Separate successful and failed legs
-        let mut fills = Vec::new();
-        let mut failures = Vec::new();
+        // Separate successful and failed legs, already ordered by token ID
+        let mut filled = Vec::new();
+        let mut failed = Vec::new();
 
-        for (token_id, result) in results {
+        for (order, result) in results {
+            let token_id = TokenId::from(order.token_id);
+            let price = order.price;
             match result {
-                Ok(resp) => {
-                    fills.push(Fill {
-                        token_id,
-                        order_id: resp.order_id,
+                Ok(order_id) => {
+                    filled.push(FilledLeg {
+                        fill: Fill {
+                            token_id,
+                            order_id: order_id.to_string(),
+                        },
+                        price,
                     });
                 }
-                Err(err) => {
-                    failures.push(Failure {
-                        token_id,
-                        error: err.to_string(),
+                Err(error) => {
+                    failed.push(FailedLeg {
+                        failure: Failure {
+                            token_id,
+                            kind: classify(&error),
+                            error,
+                        },
+                        price,
                     });
                 }
             }
         }
 
-        if failures.is_empty() {
-            info!(fills = fills.len(), "All legs executed successfully");
-            Ok(TradeResult::Success { fills })
-        } else if fills.is_empty() {
-            let errors: Vec<_> = failures.iter().map(|f| f.error.as_str()).collect();
-            warn!(errors = ?errors, "All legs failed");
+        if failed.is_empty() {
+            info!(fills = filled.len(), "All legs executed successfully");
+            Ok(TradeResult::Success {
+                fills: filled.into_iter().map(|leg| leg.fill).collect(),
+            })
+        } else if filled.is_empty() {
+            let errors: Vec<_> = failed
+                .iter()
+                .map(|leg| leg.failure.error.as_str())
+                .collect();
+            // Legs are ordered by token ID, so the reported class is stable.
+            let kind = failed[0].failure.kind;
+            warn!(errors = ?errors, kind = %kind, "All legs failed");
             Ok(TradeResult::Failed {
                 reason: errors.join("; "),
+                kind,
             })
         } else {
             warn!(
-                fills = fills.len(),
-                failures = failures.len(),
+                fills = filled.len(),
+                failures = failed.len(),
+                policy = ?self.recovery.policy,
                 "Partial fill detected"
             );
-            Ok(TradeResult::Partial { fills, failures })
+            Ok(self
+                .recovery
+                .resolve(
+                    self,
+                    self.leg_timeout,
+                    volume,
+                    opportunity.payout() - opportunity.fees(),
+                    filled,
+                    failed,
+                )
+                .await)
         }
     }
 
@@ -256,6 +379,80 @@ impl PolymarketExecutor {
     }
 }
 
+/// Compute slippage-buffered limit prices for every leg of an opportunity.
+///
+/// Each leg's ask is raised by `slippage_buffer` (a fraction of the ask) and
+/// rounded down to `price_tick`, never below the ask rounded up to the tick,
+/// so every limit is a valid price that still crosses the ask. Returns an
+/// error naming the first leg whose rounded limit would leave the
+/// opportunity with no edge after fees.
+fn buffered_limit_prices(
+    opportunity: &Opportunity,
+    slippage_buffer: Decimal,
+    price_tick: Decimal,
+) -> std::result::Result<Vec<Decimal>, String> {
+    let legs = opportunity.legs();
+    let budget = opportunity.payout() - opportunity.fees();
+    let mut remaining_cost = opportunity.total_cost();
+    let mut buffered_cost = Decimal::ZERO;
+    let mut prices = Vec::with_capacity(legs.len());
+
+    for leg in legs {
+        let ask = leg.ask_price();
+        let price = round_to_increment(
+            ask * (Decimal::ONE + slippage_buffer),
+            price_tick,
+            RoundingStrategy::ToZero,
+        )
+        .max(round_to_increment(
+            ask,
+            price_tick,
+            RoundingStrategy::AwayFromZero,
+        ));
+
+        remaining_cost -= ask;
+        buffered_cost += price;
+
+        if buffered_cost + remaining_cost >= budget {
+            return Err(format!(
+                "limit {price} on leg {} (slippage buffer {slippage_buffer}, tick {price_tick}) \
+                 leaves no edge: buffered cost {} >= payout {} minus fees {}",
+                leg.token_id(),
+                buffered_cost + remaining_cost,
+                opportunity.payout(),
+                opportunity.fees(),
+            ));
+        }
+
+        prices.push(price);
+    }
+
+    Ok(prices)
+}
+
+/// Round `volume` down to `size_increment`.
+///
+/// Returns an error when the volume is smaller than one increment.
+fn order_size(volume: Decimal, size_increment: Decimal) -> std::result::Result<Decimal, String> {
+    let size = round_to_increment(volume, size_increment, RoundingStrategy::ToZero);
+    if size <= Decimal::ZERO {
+        return Err(format!(
+            "volume {volume} is below the size increment {size_increment}"
+        ));
+    }
+    Ok(size)
+}
+
+/// Round `value` to a multiple of `increment` using `strategy`.
+///
+/// A non-positive increment leaves the value unchanged.
+fn round_to_increment(value: Decimal, increment: Decimal, strategy: RoundingStrategy) -> Decimal {
+    if increment <= Decimal::ZERO {
+        return value;
+    }
+    (value / increment).round_dp_with_strategy(0, strategy) * increment
+}
+
 #[async_trait]
 impl OrderExecutor for PolymarketExecutor {
     async fn execute(&self, order: &OrderRequest) -> Result<ExecutionResult> {
@@ -288,12 +485,87 @@ impl OrderExecutor for PolymarketExecutor {
     }
 }
 
+#[async_trait]
+impl LegOrders for PolymarketExecutor {
+    async fn place(&self, order: &OrderRequest) -> Result<Placement> {
+        let side = match order.side {
+            OrderSide::Buy => Side::Buy,
+            OrderSide::Sell => Side::Sell,
+        };
+
+        let response = self
+            .submit_order(&order.token_id, side, order.size, order.price)
+            .await?;
+        let order_id = OrderId::new(response.order_id);
+
+        match response.status {
+            OrderStatusType::Matched => Ok(Placement::Filled(order_id)),
+            OrderStatusType::Canceled => Err(ExecutionError::OrderRejected(format!(
+                "Order {} cancelled on submission",
+                order_id.as_str()
+            ))
+            .into()),
+            _ => Ok(Placement::Resting(order_id)),
+        }
+    }
+
+    async fn wait_for_fill(&self, order_id: &OrderId) -> Result<()> {
+        loop {
+            let order = self.client.order(order_id.as_str()).await.map_err(|e| {
+                ExecutionError::SubmissionFailed(format!("Order status failed: {e}"))
+            })?;
+
+            match order.status {
+                OrderStatusType::Matched => return Ok(()),
+                OrderStatusType::Canceled => {
+                    return Err(ExecutionError::OrderRejected(format!(
+                        "Order {} cancelled before filling",
+                        order_id.as_str()
+                    ))
+                    .into());
+                }
+                _ if order.size_matched >= order.original_size => return Ok(()),
+                _ => tokio::time::sleep(FILL_POLL_INTERVAL).await,
+            }
+        }
+    }
+
+    async fn is_filled(&self, order_id: &OrderId) -> Result<bool> {
+        let order =
+            self.client.order(order_id.as_str()).await.map_err(|e| {
+                ExecutionError::SubmissionFailed(format!("Order status failed: {e}"))
+            })?;
+        Ok(matches!(order.status, OrderStatusType::Matched)
+            || order.size_matched >= order.original_size)
+    }
+}
+
 #[async_trait]
 impl ArbitrageExecutor for PolymarketExecutor {
     async fn execute_arbitrage(&self, opportunity: &Opportunity) -> Result<TradeResult> {
         self.execute_arbitrage_impl(opportunity).await
     }
 
+    async fn sell(
+        &self,
+        token_id: &TokenId,
+        size: Decimal,
+        limit: Decimal,
+    ) -> Result<ExecutionResult> {
+        let price = round_to_increment(limit, self.price_tick, RoundingStrategy::ToZero)
+            .max(self.price_tick);
+        OrderExecutor::execute(
+            self,
+            &OrderRequest {
+                token_id: token_id.to_string(),
+                side: OrderSide::Sell,
+                size,
+                price,
+            },
+        )
+        .await
+    }
+
     async fn cancel(&self, order_id: &OrderId) -> Result<()> {
         self.cancel_order_impl(order_id).await
     }
@@ -420,6 +692,7 @@ mod tests {
         let failures = vec![Failure {
             token_id: TokenId::new("no"),
             error: "timeout".into(),
+            kind: FailureKind::Timeout,
         }];
 
         let result = TradeResult::Partial { fills, failures };
@@ -435,6 +708,7 @@ mod tests {
     fn trade_result_failed_reason() {
         let result = TradeResult::Failed {
             reason: "All orders rejected".into(),
+            kind: FailureKind::RejectedByExchange,
         };
 
         assert!(!result.is_success());
@@ -509,6 +783,98 @@ mod tests {
         assert_eq!(opp.expected_profit(), dec!(5.00));
     }
 
+    // -------------------------------------------------------------------------
+    // Slippage Buffer Tests
+    // -------------------------------------------------------------------------
+
+    fn two_leg_opportunity(yes: Decimal, no: Decimal) -> Opportunity {
+        Opportunity::new(
+            MarketId::new("test-market"),
+            "Two legs?",
+            vec![
+                OpportunityLeg::new(TokenId::new("yes"), yes),
+                OpportunityLeg::new(TokenId::new("no"), no),
+            ],
+            dec!(100),
+            dec!(1.00),
+        )
+    }
+
+    #[test]
+    fn buffered_limit_prices_widen_each_leg() {
+        let opp = two_leg_opportunity(dec!(0.40), dec!(0.50));
+
+        let prices = buffered_limit_prices(&opp, dec!(0.02), dec!(0.001)).unwrap();
+
+        assert_eq!(prices, vec![dec!(0.408), dec!(0.510)]);
+    }
+
+    #[test]
+    fn buffered_limit_prices_zero_buffer_keeps_asks() {
+        let opp = two_leg_opportunity(dec!(0.45), dec!(0.50));
+
+        let prices = buffered_limit_prices(&opp, Decimal::ZERO, dec!(0.001)).unwrap();
+
+        assert_eq!(prices, vec![dec!(0.45), dec!(0.50)]);
+    }
+
+    #[test]
+    fn buffered_limit_prices_round_down_to_tick() {
+        let opp = two_leg_opportunity(dec!(0.333), dec!(0.333));
+
+        let prices = buffered_limit_prices(&opp, dec!(0.01), dec!(0.001)).unwrap();
+
+        // 0.333 * 1.01 = 0.33633 -> 0.336
+        assert_eq!(prices, vec![dec!(0.336), dec!(0.336)]);
+    }
+
+    #[test]
+    fn buffered_limit_prices_reject_when_edge_eliminated() {
+        // Edge of 0.02 cannot absorb a 5% buffer on 0.98 of cost
+        let opp = two_leg_opportunity(dec!(0.48), dec!(0.50));
+
+        let err = buffered_limit_prices(&opp, dec!(0.05), dec!(0.001)).unwrap_err();
+
+        assert!(err.contains("leaves no edge"), "unexpected reason: {err}");
+        assert!(err.contains("leg yes"), "unexpected reason: {err}");
+    }
+
+    #[test]
+    fn buffered_limit_prices_round_ask_up_to_tick() {
+        let opp = two_leg_opportunity(dec!(0.305), dec!(0.60));
+
+        let prices = buffered_limit_prices(&opp, Decimal::ZERO, dec!(0.01)).unwrap();
+
+        // 0.305 is off the 0.01 grid; the limit must still cross the ask
+        assert_eq!(prices, vec![dec!(0.31), dec!(0.60)]);
+        let rounded_cost: Decimal = prices.iter().sum();
+        assert!(rounded_cost < opp.payout());
+    }
+
+    #[test]
+    fn buffered_limit_prices_reject_when_rounding_eliminates_edge() {
+        // 0.01 of edge before rounding, none after
+        let opp = two_leg_opportunity(dec!(0.495), dec!(0.495));
+        assert_eq!(opp.edge(), dec!(0.01));
+
+        let err = buffered_limit_prices(&opp, Decimal::ZERO, dec!(0.01)).unwrap_err();
+
+        assert!(err.contains("leaves no edge"), "unexpected reason: {err}");
+        assert!(err.contains("leg no"), "unexpected reason: {err}");
+    }
+
+    #[test]
+    fn order_size_rounds_down_to_increment() {
+        assert_eq!(order_size(dec!(12.345), dec!(0.01)).unwrap(), dec!(12.34));
+        assert_eq!(order_size(dec!(7.9), Decimal::ONE).unwrap(), dec!(7));
+
+        let err = order_size(dec!(0.004), dec!(0.01)).unwrap_err();
+        assert!(
+            err.contains("below the size increment"),
+            "unexpected reason: {err}"
+        );
+    }
+
     // -------------------------------------------------------------------------
     // Fill and Failure Tests
     // -------------------------------------------------------------------------
@@ -529,6 +895,7 @@ mod tests {
         let failure = Failure {
             token_id: TokenId::new("token-fail"),
             error: "Connection timeout".into(),
+            kind: FailureKind::Timeout,
         };
 
         assert_eq!(failure.token_id.as_str(), "token-fail");
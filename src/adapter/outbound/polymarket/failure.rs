@@ -0,0 +1,126 @@
+//! Classification of Polymarket order failures.
+//!
+//! The CLOB reports rejections as free-form messages, and leg failures reach
+//! the executor as strings. [`classify`] maps a message onto a
+//! [`FailureKind`] using [`PATTERNS`], so recovery and notifications can
+//! branch on the class of failure instead of the wording.
+
+use crate::domain::trade::FailureKind;
+
+/// Lowercase message fragments and the failure class they indicate.
+///
+/// The first matching fragment wins, so more specific fragments come first.
+/// Messages matching nothing are [`FailureKind::Other`].
+pub const PATTERNS: &[(&str, FailureKind)] = &[
+    ("not enough balance", FailureKind::InsufficientBalance),
+    ("insufficient balance", FailureKind::InsufficientBalance),
+    ("insufficient funds", FailureKind::InsufficientBalance),
+    ("allowance", FailureKind::InsufficientBalance),
+    ("status 429", FailureKind::RateLimited),
+    ("too many requests", FailureKind::RateLimited),
+    ("rate limit", FailureKind::RateLimited),
+    ("not filled within", FailureKind::Timeout),
+    ("timed out", FailureKind::Timeout),
+    ("timeout", FailureKind::Timeout),
+    ("couldn't be fully filled", FailureKind::PriceMoved),
+    ("no orders found to match", FailureKind::PriceMoved),
+    ("crosses book", FailureKind::PriceMoved),
+    ("only partially filled", FailureKind::PriceMoved),
+    (
+        "trading is currently disabled",
+        FailureKind::RejectedByExchange,
+    ),
+    ("not yet ready", FailureKind::RejectedByExchange),
+    ("tick size", FailureKind::RejectedByExchange),
+    ("lower than the minimum", FailureKind::RejectedByExchange),
+    ("order rejected", FailureKind::RejectedByExchange),
+];
+
+/// Classify a Polymarket order error message.
+#[must_use]
+pub fn classify(message: &str) -> FailureKind {
+    let message = message.to_lowercase();
+    PATTERNS
+        .iter()
+        .find(|(fragment, _)| message.contains(fragment))
+        .map_or(FailureKind::Other, |(_, kind)| *kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_exchange_messages() {
+        let cases = [
+            (
+                "failed to submit order: not enough balance / allowance",
+                FailureKind::InsufficientBalance,
+            ),
+            (
+                "failed to submit order: HTTP 429 Too Many Requests",
+                FailureKind::RateLimited,
+            ),
+            (
+                "failed to submit order: status 429",
+                FailureKind::RateLimited,
+            ),
+            (
+                "order 0xabc not filled within 500ms, cancelled",
+                FailureKind::Timeout,
+            ),
+            (
+                "failed to submit order: request timed out",
+                FailureKind::Timeout,
+            ),
+            (
+                "failed to submit order: order couldn't be fully filled. FOK orders are fully filled or killed.",
+                FailureKind::PriceMoved,
+            ),
+            (
+                "failed to submit order: invalid post-only order: order crosses book",
+                FailureKind::PriceMoved,
+            ),
+            (
+                "failed to submit order: Trading is currently disabled",
+                FailureKind::RejectedByExchange,
+            ),
+            (
+                "order rejected: Order 0xabc cancelled on submission",
+                FailureKind::RejectedByExchange,
+            ),
+            (
+                "failed to sign order: invalid signature",
+                FailureKind::Other,
+            ),
+        ];
+
+        for (message, kind) in cases {
+            assert_eq!(classify(message), kind, "{message}");
+        }
+    }
+
+    #[test]
+    fn specific_patterns_take_precedence() {
+        // A rejection that names the cause is classified by the cause.
+        assert_eq!(
+            classify("order rejected: not enough balance / allowance"),
+            FailureKind::InsufficientBalance
+        );
+    }
+
+    #[test]
+    fn order_ids_do_not_match_status_codes() {
+        assert_eq!(
+            classify("order 0x4290ab not filled within 500ms, cancelled"),
+            FailureKind::Timeout
+        );
+    }
+
+    #[test]
+    fn patterns_are_lowercase() {
+        for (fragment, _) in PATTERNS {
+            assert_eq!(*fragment, fragment.to_lowercase());
+        }
+    }
+}
@@ -4,6 +4,10 @@
 //! markets are eligible for subscription based on configurable criteria
 //! such as volume, liquidity, and outcome count.
 
+use std::time::Duration;
+
+use chrono::Utc;
+
 use super::settings::PolymarketFilterConfig;
 use crate::port::{
     outbound::exchange::MarketInfo, outbound::filter::MarketFilter,
@@ -34,6 +38,11 @@ impl PolymarketFilter {
                 include_binary: config.include_binary,
                 include_multi_outcome: config.include_multi_outcome,
                 max_outcomes: config.max_outcomes,
+                max_outcome_price: config.max_outcome_price,
+                min_time_to_close: config.min_time_to_close_secs.map(Duration::from_secs),
+                whitelist: config.whitelist.clone(),
+                blacklist: config.blacklist.clone(),
+                exclude_keywords: config.exclude_keywords.clone(),
             },
         }
     }
@@ -81,6 +90,11 @@ impl MarketFilter for PolymarketFilter {
             }
         }
 
+        // Resolution filter: reject extreme prices and imminent closes.
+        if self.config.is_near_resolution(market, Utc::now()) {
+            return false;
+        }
+
         true
     }
 
@@ -118,6 +132,8 @@ mod tests {
                 token_id: format!("token-{}", i),
                 name: format!("Outcome {}", i),
                 price: None,
+                volume_24h: None,
+                liquidity: None,
             })
             .collect();
 
@@ -128,6 +144,7 @@ mod tests {
             active,
             volume_24h,
             liquidity,
+            close_time: None,
         }
     }
 
@@ -425,6 +442,58 @@ mod tests {
 
     // --- filter (batch) tests ---
 
+    // --- resolution filter tests ---
+
+    #[test]
+    fn is_eligible_market_closing_within_floor_returns_false() {
+        let mut config = default_config();
+        config.min_time_to_close_secs = Some(3600);
+        let filter = PolymarketFilter::new(&config);
+
+        let mut closing_soon = make_market("m1", true, 2);
+        closing_soon.close_time = Some(Utc::now() + chrono::Duration::minutes(10));
+        let mut closing_later = make_market("m2", true, 2);
+        closing_later.close_time = Some(Utc::now() + chrono::Duration::hours(2));
+
+        assert!(!filter.is_eligible(&closing_soon));
+        assert!(filter.is_eligible(&closing_later));
+    }
+
+    #[test]
+    fn is_eligible_market_without_close_time_passes_time_floor() {
+        let mut config = default_config();
+        config.min_time_to_close_secs = Some(3600);
+        let filter = PolymarketFilter::new(&config);
+
+        assert!(filter.is_eligible(&make_market("m1", true, 2)));
+    }
+
+    #[test]
+    fn is_eligible_market_closing_soon_passes_without_floor() {
+        let filter = PolymarketFilter::new(&default_config());
+        let mut market = make_market("m1", true, 2);
+        market.close_time = Some(Utc::now() + chrono::Duration::minutes(10));
+
+        assert!(filter.is_eligible(&market));
+    }
+
+    #[test]
+    fn is_eligible_outcome_above_max_price_returns_false() {
+        let mut config = default_config();
+        config.max_outcome_price = Some(0.97);
+        let filter = PolymarketFilter::new(&config);
+
+        let mut extreme = make_market("m1", true, 2);
+        extreme.outcomes[0].price = Some(0.99);
+        extreme.outcomes[1].price = Some(0.01);
+        let mut balanced = make_market("m2", true, 2);
+        balanced.outcomes[0].price = Some(0.60);
+        balanced.outcomes[1].price = Some(0.40);
+
+        assert!(!filter.is_eligible(&extreme));
+        assert!(filter.is_eligible(&balanced));
+    }
+
     #[test]
     fn filter_returns_only_eligible_markets() {
         let filter = PolymarketFilter::new(&default_config());
@@ -475,6 +544,93 @@ mod tests {
         assert!(filtered.is_empty());
     }
 
+    // --- whitelist/blacklist tests ---
+
+    fn ids(markets: &[MarketInfo]) -> Vec<&str> {
+        markets.iter().map(|m| m.id.as_str()).collect()
+    }
+
+    #[test]
+    fn filter_whitelisted_market_bypasses_volume_floor() {
+        let mut config = default_config();
+        config.min_volume_24h = 1000.0;
+        config.whitelist = vec!["pinned".to_string()];
+        let filter = PolymarketFilter::new(&config);
+        let markets = vec![
+            make_market_with_metrics("pinned", true, 2, Some(10.0), None),
+            make_market_with_metrics("thin", true, 2, Some(10.0), None),
+        ];
+
+        assert_eq!(ids(&filter.filter(&markets)), vec!["pinned"]);
+    }
+
+    #[test]
+    fn filter_whitelisted_market_still_needs_other_criteria() {
+        let mut config = default_config();
+        config.min_volume_24h = 1000.0;
+        config.min_liquidity = 500.0;
+        config.whitelist = vec!["inactive".to_string(), "shallow".to_string()];
+        let filter = PolymarketFilter::new(&config);
+        let markets = vec![
+            make_market_with_metrics("inactive", false, 2, Some(10.0), None),
+            make_market_with_metrics("shallow", true, 2, Some(10.0), Some(100.0)),
+        ];
+
+        assert!(filter.filter(&markets).is_empty());
+    }
+
+    #[test]
+    fn filter_blacklisted_market_dropped_despite_volume() {
+        let mut config = default_config();
+        config.min_volume_24h = 1000.0;
+        config.blacklist = vec!["banned".to_string()];
+        let filter = PolymarketFilter::new(&config);
+        let markets = vec![
+            make_market_with_metrics("banned", true, 2, Some(50000.0), None),
+            make_market_with_metrics("busy", true, 2, Some(50000.0), None),
+        ];
+
+        assert_eq!(ids(&filter.filter(&markets)), vec!["busy"]);
+    }
+
+    #[test]
+    fn filter_blacklist_wins_over_whitelist() {
+        let mut config = default_config();
+        config.whitelist = vec!["m1".to_string()];
+        config.blacklist = vec!["m1".to_string()];
+        let filter = PolymarketFilter::new(&config);
+
+        assert!(filter.filter(&[make_market("m1", true, 2)]).is_empty());
+    }
+
+    #[test]
+    fn filter_keyword_match_dropped_despite_volume() {
+        let mut config = default_config();
+        config.min_volume_24h = 1000.0;
+        config.exclude_keywords = vec!["PLAYER PROPS".to_string()];
+        let filter = PolymarketFilter::new(&config);
+        let mut props = make_market_with_metrics("m1", true, 2, Some(50000.0), None);
+        props.question = "NBA player props: will he score 30?".to_string();
+        let markets = vec![
+            props,
+            make_market_with_metrics("m2", true, 2, Some(50000.0), None),
+        ];
+
+        assert_eq!(ids(&filter.filter(&markets)), vec!["m2"]);
+    }
+
+    #[test]
+    fn filter_keyword_match_drops_whitelisted_market() {
+        let mut config = default_config();
+        config.whitelist = vec!["m1".to_string()];
+        config.exclude_keywords = vec!["props".to_string()];
+        let filter = PolymarketFilter::new(&config);
+        let mut market = make_market_with_metrics("m1", true, 2, Some(10.0), None);
+        market.question = "Player props".to_string();
+
+        assert!(filter.filter(&[market]).is_empty());
+    }
+
     // --- config accessor tests ---
 
     #[test]
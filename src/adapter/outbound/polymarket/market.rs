@@ -5,6 +5,9 @@
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
+use super::dto::message::PolymarketWsMessage;
+use crate::domain::book::Book;
+use crate::error::Error;
 use crate::port::outbound::exchange::MarketParser;
 
 /// Default payout amount for Polymarket ($1.00 per share).
@@ -42,6 +45,17 @@ impl MarketParser for PolymarketMarketParser {
     fn binary_outcome_names(&self) -> (&'static str, &'static str) {
         ("Yes", "No")
     }
+
+    fn parse_order_book(&self, raw: &str) -> Result<Vec<Book>, Error> {
+        match serde_json::from_str::<PolymarketWsMessage>(raw)? {
+            PolymarketWsMessage::Books(books) => {
+                Ok(books.iter().map(|book| book.to_orderbook()).collect())
+            }
+            PolymarketWsMessage::Unknown(_) => Err(Error::Parse(
+                "message is not a polymarket book snapshot".to_string(),
+            )),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -121,6 +135,27 @@ mod tests {
         assert_send_sync::<PolymarketMarketParser>();
     }
 
+    #[test]
+    fn test_parse_order_book_converts_snapshots() {
+        let parser = PolymarketMarketParser;
+        let raw = r#"[{"asset_id": "token-1", "bids": [{"price": "0.45", "size": "100"}], "asks": [{"price": "0.55", "size": "200"}]}]"#;
+
+        let books = parser.parse_order_book(raw).unwrap();
+
+        assert_eq!(books.len(), 1);
+        assert_eq!(books[0].token_id().as_str(), "token-1");
+        assert_eq!(books[0].best_bid().unwrap().price(), dec!(0.45));
+        assert_eq!(books[0].best_ask().unwrap().size(), dec!(200));
+    }
+
+    #[test]
+    fn test_parse_order_book_rejects_unknown_messages() {
+        let parser = PolymarketMarketParser;
+        let raw = r#"{"type": "error", "message": "invalid subscription"}"#;
+
+        assert!(parser.parse_order_book(raw).is_err());
+    }
+
     #[test]
     fn test_config_as_trait_object() {
         let parser: &dyn MarketParser = &PolymarketMarketParser;
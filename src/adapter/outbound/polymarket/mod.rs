@@ -9,6 +9,9 @@
 //! - [`client`] - REST API client for CLOB and Gamma endpoints
 //! - [`stream`] - WebSocket handler for real-time market data
 //! - [`executor`] - Order execution and trade management
+//! - [`deadline`] - Fill deadlines and cancellation for arbitrage legs
+//! - [`failure`] - Classification of order failures
+//! - [`recovery`] - Retry and unwind handling for partial fills
 //! - [`approval`] - ERC-20 token approval for exchange contracts
 //! - [`filter`] - Market eligibility filtering
 //! - [`scorer`] - Market scoring for subscription prioritization
@@ -17,11 +20,14 @@
 
 pub mod approval;
 pub mod client;
+pub mod deadline;
 pub mod dedup;
 pub mod dto;
 pub mod executor;
+pub mod failure;
 pub mod filter;
 pub mod market;
+pub mod recovery;
 pub mod scorer;
 pub mod settings;
 pub mod stream;
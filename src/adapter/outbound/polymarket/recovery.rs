@@ -0,0 +1,652 @@
+//! Recovery from partially filled arbitrage trades.
+//!
+//! When only some legs of an arbitrage fill, the filled legs are an unhedged
+//! directional position. [`PartialFillRecovery`] applies the configured
+//! [`PartialFillPolicy`]: hold the legs as they are, retry the missing legs at
+//! widened prices, or sell the filled legs back. Recovery orders go through
+//! [`place_leg`] with the same leg deadline as the original legs, so an order
+//! left resting on the book is cancelled and reported as unrecovered.
+
+use std::time::Duration;
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use tracing::{info, warn};
+
+use super::deadline::{place_leg, LegOrders};
+use super::executor::PRICE_DECIMALS;
+use super::failure::classify;
+use crate::domain::{
+    id::TokenId, trade::Failure, trade::Fill, trade::PartialFillPolicy, trade::TradeResult,
+};
+use crate::port::{outbound::exchange::OrderRequest, outbound::exchange::OrderSide};
+
+/// Settings for recovering from a partial arbitrage fill.
+#[derive(Debug, Clone)]
+pub struct PartialFillRecovery {
+    /// What to do with the unhedged legs.
+    pub policy: PartialFillPolicy,
+    /// Rounds of resubmission for failed legs under [`PartialFillPolicy::Retry`].
+    pub retry_attempts: u32,
+    /// Fraction of the original limit price added per retry round.
+    pub retry_price_step: Decimal,
+    /// Fraction below the fill price at which filled legs are sold back.
+    pub unwind_discount: Decimal,
+}
+
+impl Default for PartialFillRecovery {
+    fn default() -> Self {
+        Self {
+            policy: PartialFillPolicy::Hold,
+            retry_attempts: 2,
+            retry_price_step: Decimal::new(1, 2),
+            unwind_discount: Decimal::new(2, 2),
+        }
+    }
+}
+
+/// A leg that filled, with the limit price it was bought at.
+#[derive(Debug, Clone)]
+pub struct FilledLeg {
+    /// Fill record for the leg.
+    pub fill: Fill,
+    /// Limit price the leg was bought at.
+    pub price: Decimal,
+}
+
+/// A leg that failed, with the limit price it was submitted at.
+#[derive(Debug, Clone)]
+pub struct FailedLeg {
+    /// Failure record for the leg.
+    pub failure: Failure,
+    /// Limit price the leg was submitted at.
+    pub price: Decimal,
+}
+
+impl PartialFillRecovery {
+    /// Resolve a partial fill according to the configured policy.
+    ///
+    /// `max_cost` is the most the legs may cost together per share while the
+    /// trade keeps an edge: the payout less fees. Each recovery order may
+    /// rest for up to `leg_timeout` before it is cancelled and counted as
+    /// failed.
+    ///
+    /// Returns [`TradeResult::Success`] if retries complete every leg,
+    /// [`TradeResult::Unwound`] if every filled leg was sold back, and
+    /// [`TradeResult::Partial`] with the legs still held otherwise.
+    pub async fn resolve(
+        &self,
+        orders: &dyn LegOrders,
+        leg_timeout: Option<Duration>,
+        volume: Decimal,
+        max_cost: Decimal,
+        filled: Vec<FilledLeg>,
+        failed: Vec<FailedLeg>,
+    ) -> TradeResult {
+        match self.policy {
+            PartialFillPolicy::Hold => TradeResult::Partial {
+                fills: filled.into_iter().map(|leg| leg.fill).collect(),
+                failures: failed.into_iter().map(|leg| leg.failure).collect(),
+            },
+            PartialFillPolicy::Retry => {
+                let (filled, failed) = self
+                    .retry(orders, leg_timeout, volume, max_cost, filled, failed)
+                    .await;
+                if failed.is_empty() {
+                    info!(fills = filled.len(), "Retry completed all legs");
+                    return TradeResult::Success {
+                        fills: filled.into_iter().map(|leg| leg.fill).collect(),
+                    };
+                }
+                warn!(
+                    failures = failed.len(),
+                    "Retries exhausted, unwinding filled legs"
+                );
+                self.unwind(orders, leg_timeout, volume, filled, failed)
+                    .await
+            }
+            PartialFillPolicy::Unwind => {
+                self.unwind(orders, leg_timeout, volume, filled, failed)
+                    .await
+            }
+        }
+    }
+
+    /// Resubmit failed legs, raising the limit price each round.
+    ///
+    /// Each leg's limit price stays one tick below what `max_cost` leaves
+    /// after the filled legs and the other missing legs, so completing the
+    /// trade never costs more than it pays. When that leaves no room above a
+    /// leg's last price, retrying stops and the remaining legs are returned
+    /// as failed. Legs whose failure is not retryable, such as an
+    /// insufficient balance, are not resubmitted.
+    async fn retry(
+        &self,
+        orders: &dyn LegOrders,
+        leg_timeout: Option<Duration>,
+        volume: Decimal,
+        max_cost: Decimal,
+        mut filled: Vec<FilledLeg>,
+        mut failed: Vec<FailedLeg>,
+    ) -> (Vec<FilledLeg>, Vec<FailedLeg>) {
+        let tick = Decimal::new(1, PRICE_DECIMALS);
+
+        for attempt in 1..=self.retry_attempts {
+            if failed.is_empty() {
+                break;
+            }
+
+            let step = self.retry_price_step * Decimal::from(attempt);
+            let mut still_failed = Vec::with_capacity(failed.len());
+            let mut pending = failed.into_iter();
+
+            while let Some(leg) = pending.next() {
+                if !leg.failure.kind.is_retryable() {
+                    still_failed.push(leg);
+                    continue;
+                }
+
+                let committed: Decimal = filled.iter().map(|other| other.price).sum::<Decimal>()
+                    + still_failed
+                        .iter()
+                        .chain(pending.as_slice())
+                        .map(|other| other.price)
+                        .sum::<Decimal>();
+                let ceiling = max_cost - committed - tick;
+                let token_id = leg.failure.token_id.clone();
+                if ceiling <= leg.price {
+                    warn!(token = %token_id, attempt, ceiling = %ceiling, "No edge left to retry leg, giving up");
+                    still_failed.push(leg);
+                    still_failed.extend(pending);
+                    return (filled, still_failed);
+                }
+
+                let price =
+                    round_to_tick((leg.price * (Decimal::ONE + step)).min(ceiling)).max(leg.price);
+
+                match submit(
+                    orders,
+                    leg_timeout,
+                    &token_id,
+                    OrderSide::Buy,
+                    volume,
+                    price,
+                )
+                .await
+                {
+                    Ok(order_id) => {
+                        info!(token = %token_id, attempt, price = %price, "Retried leg filled");
+                        filled.push(FilledLeg {
+                            fill: Fill::new(token_id, order_id),
+                            price,
+                        });
+                    }
+                    Err(error) => {
+                        warn!(token = %token_id, attempt, price = %price, error = %error, "Retried leg failed");
+                        let kind = classify(&error);
+                        still_failed.push(FailedLeg {
+                            failure: Failure::new(token_id, error).with_kind(kind),
+                            price: leg.price,
+                        });
+                    }
+                }
+            }
+
+            failed = still_failed;
+        }
+
+        (filled, failed)
+    }
+
+    /// Sell every filled leg back at a discount to its fill price.
+    async fn unwind(
+        &self,
+        orders: &dyn LegOrders,
+        leg_timeout: Option<Duration>,
+        volume: Decimal,
+        filled: Vec<FilledLeg>,
+        failed: Vec<FailedLeg>,
+    ) -> TradeResult {
+        let mut failures: Vec<Failure> = failed.into_iter().map(|leg| leg.failure).collect();
+        let mut fills = Vec::with_capacity(filled.len());
+        let mut unwinds = Vec::with_capacity(filled.len());
+        let mut held = Vec::new();
+        let mut net_cost = Decimal::ZERO;
+
+        for leg in filled {
+            let token_id = leg.fill.token_id.clone();
+            let price = round_to_tick(leg.price * (Decimal::ONE - self.unwind_discount))
+                .max(Decimal::new(1, PRICE_DECIMALS));
+
+            match submit(
+                orders,
+                leg_timeout,
+                &token_id,
+                OrderSide::Sell,
+                volume,
+                price,
+            )
+            .await
+            {
+                Ok(order_id) => {
+                    info!(token = %token_id, price = %price, "Filled leg sold back");
+                    net_cost += (leg.price - price) * volume;
+                    unwinds.push(Fill::new(token_id, order_id));
+                    fills.push(leg.fill);
+                }
+                Err(error) => {
+                    warn!(token = %token_id, price = %price, error = %error, "Unwind sell failed");
+                    let kind = classify(&error);
+                    failures.push(
+                        Failure::new(token_id, format!("unwind failed: {error}")).with_kind(kind),
+                    );
+                    held.push(leg.fill);
+                }
+            }
+        }
+
+        if held.is_empty() {
+            info!(net_cost = %net_cost, "Partial fill unwound");
+            TradeResult::Unwound {
+                fills,
+                unwinds,
+                net_cost,
+            }
+        } else {
+            warn!(
+                held = held.len(),
+                unwound = unwinds.len(),
+                net_cost = %net_cost,
+                "Unwind incomplete, legs still held"
+            );
+            TradeResult::Partial {
+                fills: held,
+                failures,
+            }
+        }
+    }
+}
+
+/// Submit one order, returning its ID only if it filled before the deadline.
+async fn submit(
+    orders: &dyn LegOrders,
+    leg_timeout: Option<Duration>,
+    token_id: &TokenId,
+    side: OrderSide,
+    size: Decimal,
+    price: Decimal,
+) -> Result<String, String> {
+    let request = OrderRequest {
+        token_id: token_id.to_string(),
+        side,
+        size,
+        price,
+    };
+
+    place_leg(orders, &request, leg_timeout)
+        .await
+        .map(|order_id| order_id.as_str().to_string())
+}
+
+/// Round a price down to the finest CLOB tick.
+fn round_to_tick(price: Decimal) -> Decimal {
+    price.round_dp_with_strategy(PRICE_DECIMALS, RoundingStrategy::ToZero)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::outbound::polymarket::deadline::Placement;
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+    use rust_decimal_macros::dec;
+
+    use crate::domain::id::OrderId;
+    use crate::domain::trade::FailureKind;
+    use crate::error::{Error, ExecutionError};
+    use crate::port::outbound::exchange::{ExecutionResult, OrderExecutor};
+
+    /// Order venue that fails a set number of buys and optionally all sells.
+    struct ScriptedOrders {
+        submitted: Mutex<Vec<OrderRequest>>,
+        cancelled: Mutex<Vec<OrderId>>,
+        failing_buys: Mutex<u32>,
+        fail_sells: bool,
+        /// Accepted orders rest on the book and never fill.
+        resting: bool,
+    }
+
+    impl ScriptedOrders {
+        fn new(failing_buys: u32, fail_sells: bool) -> Self {
+            Self {
+                submitted: Mutex::new(Vec::new()),
+                cancelled: Mutex::new(Vec::new()),
+                failing_buys: Mutex::new(failing_buys),
+                fail_sells,
+                resting: false,
+            }
+        }
+
+        fn resting() -> Self {
+            Self {
+                resting: true,
+                ..Self::new(0, false)
+            }
+        }
+
+        fn submitted(&self) -> Vec<OrderRequest> {
+            self.submitted.lock().clone()
+        }
+
+        fn cancelled(&self) -> Vec<OrderId> {
+            self.cancelled.lock().clone()
+        }
+    }
+
+    #[async_trait]
+    impl OrderExecutor for ScriptedOrders {
+        async fn execute(&self, order: &OrderRequest) -> Result<ExecutionResult, Error> {
+            let mut submitted = self.submitted.lock();
+            submitted.push(order.clone());
+            let order_id = OrderId::new(format!("order-{}", submitted.len()));
+
+            let fail = match order.side {
+                OrderSide::Buy => {
+                    let mut failing = self.failing_buys.lock();
+                    let fail = *failing > 0;
+                    *failing = failing.saturating_sub(1);
+                    fail
+                }
+                OrderSide::Sell => self.fail_sells,
+            };
+
+            if fail {
+                return Ok(ExecutionResult::Failed {
+                    reason: "no liquidity".to_string(),
+                });
+            }
+            Ok(ExecutionResult::Success {
+                order_id,
+                filled_amount: order.size,
+                average_price: order.price,
+            })
+        }
+
+        async fn cancel(&self, order_id: &OrderId) -> Result<(), Error> {
+            self.cancelled.lock().push(order_id.clone());
+            Ok(())
+        }
+
+        fn exchange_name(&self) -> &'static str {
+            "scripted"
+        }
+    }
+
+    #[async_trait]
+    impl LegOrders for ScriptedOrders {
+        async fn place(&self, order: &OrderRequest) -> Result<Placement, Error> {
+            match self.execute(order).await? {
+                ExecutionResult::Success { order_id, .. } if self.resting => {
+                    Ok(Placement::Resting(order_id))
+                }
+                ExecutionResult::Success { order_id, .. } => Ok(Placement::Filled(order_id)),
+                ExecutionResult::PartialFill { order_id, .. } => Ok(Placement::Resting(order_id)),
+                ExecutionResult::Failed { reason } => {
+                    Err(ExecutionError::SubmissionFailed(reason).into())
+                }
+            }
+        }
+
+        async fn wait_for_fill(&self, _order_id: &OrderId) -> Result<(), Error> {
+            std::future::pending().await
+        }
+
+        async fn is_filled(&self, _order_id: &OrderId) -> Result<bool, Error> {
+            Ok(false)
+        }
+    }
+
+    fn recovery(policy: PartialFillPolicy) -> PartialFillRecovery {
+        PartialFillRecovery {
+            policy,
+            ..PartialFillRecovery::default()
+        }
+    }
+
+    fn filled_yes() -> Vec<FilledLeg> {
+        vec![FilledLeg {
+            fill: Fill::new(TokenId::from("yes"), "buy-yes"),
+            price: dec!(0.40),
+        }]
+    }
+
+    fn failed_no() -> Vec<FailedLeg> {
+        failed_no_at(dec!(0.50))
+    }
+
+    fn failed_no_at(price: Decimal) -> Vec<FailedLeg> {
+        vec![FailedLeg {
+            failure: Failure::new(TokenId::from("no"), "no liquidity"),
+            price,
+        }]
+    }
+
+    #[tokio::test]
+    async fn hold_reports_partial_without_submitting() {
+        let orders = ScriptedOrders::new(0, false);
+
+        let result = recovery(PartialFillPolicy::Hold)
+            .resolve(
+                &orders,
+                None,
+                dec!(100),
+                Decimal::ONE,
+                filled_yes(),
+                failed_no(),
+            )
+            .await;
+
+        assert!(result.is_partial());
+        assert_eq!(result.fills().len(), 1);
+        assert_eq!(result.failures().len(), 1);
+        assert!(orders.submitted().is_empty());
+    }
+
+    #[tokio::test]
+    async fn retry_fills_missing_leg_at_widened_price() {
+        let orders = ScriptedOrders::new(1, false);
+
+        let result = recovery(PartialFillPolicy::Retry)
+            .resolve(
+                &orders,
+                None,
+                dec!(100),
+                Decimal::ONE,
+                filled_yes(),
+                failed_no(),
+            )
+            .await;
+
+        assert!(result.is_success());
+        assert_eq!(result.fills().len(), 2);
+
+        let submitted = orders.submitted();
+        assert_eq!(submitted.len(), 2);
+        assert!(submitted.iter().all(|order| order.side == OrderSide::Buy));
+        assert_eq!(submitted[0].price, dec!(0.505));
+        assert_eq!(submitted[1].price, dec!(0.51));
+    }
+
+    #[tokio::test]
+    async fn retry_unwinds_when_attempts_are_exhausted() {
+        let orders = ScriptedOrders::new(u32::MAX, false);
+
+        let result = recovery(PartialFillPolicy::Retry)
+            .resolve(
+                &orders,
+                None,
+                dec!(100),
+                Decimal::ONE,
+                filled_yes(),
+                failed_no(),
+            )
+            .await;
+
+        assert!(result.is_unwound());
+        // Bought at 0.40, sold back at 0.392: 0.008 * 100 shares
+        assert_eq!(result.net_cost(), Some(dec!(0.8)));
+
+        let submitted = orders.submitted();
+        assert_eq!(submitted.len(), 3);
+        assert_eq!(submitted[2].side, OrderSide::Sell);
+        assert_eq!(submitted[2].token_id, "yes");
+    }
+
+    #[tokio::test]
+    async fn retry_keeps_total_cost_below_max_cost() {
+        let orders = ScriptedOrders::new(u32::MAX, false);
+        let filled = vec![FilledLeg {
+            fill: Fill::new(TokenId::from("yes"), "buy-yes"),
+            price: dec!(0.595),
+        }];
+        let failed = vec![FailedLeg {
+            failure: Failure::new(TokenId::from("no"), "no liquidity"),
+            price: dec!(0.40),
+        }];
+
+        let result = recovery(PartialFillPolicy::Retry)
+            .resolve(&orders, None, dec!(100), Decimal::ONE, filled, failed)
+            .await;
+
+        // 0.40 widened by 2% would be 0.408; capped one tick below 1 - 0.595.
+        assert!(result.is_unwound());
+        let submitted = orders.submitted();
+        let buys: Vec<Decimal> = submitted
+            .iter()
+            .filter(|order| order.side == OrderSide::Buy)
+            .map(|order| order.price)
+            .collect();
+        assert_eq!(buys, vec![dec!(0.404), dec!(0.404)]);
+    }
+
+    #[tokio::test]
+    async fn retry_unwinds_at_once_when_filled_legs_leave_no_edge() {
+        let orders = ScriptedOrders::new(0, false);
+        let filled = vec![FilledLeg {
+            fill: Fill::new(TokenId::from("yes"), "buy-yes"),
+            price: dec!(0.57),
+        }];
+
+        // Fees of 0.03 leave 0.97 for both legs; 0.57 + 0.40 uses all of it.
+        let result = recovery(PartialFillPolicy::Retry)
+            .resolve(
+                &orders,
+                None,
+                dec!(100),
+                dec!(0.97),
+                filled,
+                failed_no_at(dec!(0.40)),
+            )
+            .await;
+
+        assert!(result.is_unwound());
+        let submitted = orders.submitted();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].side, OrderSide::Sell);
+    }
+
+    #[tokio::test]
+    async fn retry_skips_legs_failed_for_insufficient_balance() {
+        let orders = ScriptedOrders::new(0, false);
+        let failed = vec![FailedLeg {
+            failure: Failure::new(TokenId::from("no"), "not enough balance / allowance")
+                .with_kind(FailureKind::InsufficientBalance),
+            price: dec!(0.50),
+        }];
+
+        let result = recovery(PartialFillPolicy::Retry)
+            .resolve(&orders, None, dec!(100), Decimal::ONE, filled_yes(), failed)
+            .await;
+
+        // The missing leg is never resubmitted; the filled leg is sold back.
+        assert!(result.is_unwound());
+        let submitted = orders.submitted();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].side, OrderSide::Sell);
+    }
+
+    #[tokio::test]
+    async fn unwind_sells_filled_legs_without_retrying() {
+        let orders = ScriptedOrders::new(0, false);
+
+        let result = recovery(PartialFillPolicy::Unwind)
+            .resolve(
+                &orders,
+                None,
+                dec!(100),
+                Decimal::ONE,
+                filled_yes(),
+                failed_no(),
+            )
+            .await;
+
+        let TradeResult::Unwound {
+            fills,
+            unwinds,
+            net_cost,
+        } = result
+        else {
+            panic!("expected unwound result, got {result:?}");
+        };
+        assert_eq!(fills.len(), 1);
+        assert_eq!(unwinds.len(), 1);
+        assert_eq!(net_cost, dec!(0.8));
+
+        let submitted = orders.submitted();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].side, OrderSide::Sell);
+        assert_eq!(submitted[0].price, dec!(0.392));
+    }
+
+    #[tokio::test]
+    async fn failed_unwind_reports_legs_still_held() {
+        let orders = ScriptedOrders::new(0, true);
+
+        let result = recovery(PartialFillPolicy::Unwind)
+            .resolve(
+                &orders,
+                None,
+                dec!(100),
+                Decimal::ONE,
+                filled_yes(),
+                failed_no(),
+            )
+            .await;
+
+        assert!(result.is_partial());
+        assert_eq!(result.fills()[0].token_id.as_str(), "yes");
+        assert_eq!(result.failures().len(), 2);
+        assert!(result.net_cost().is_none());
+    }
+
+    #[tokio::test]
+    async fn resting_retry_is_cancelled_and_unwound() {
+        let orders = ScriptedOrders::resting();
+
+        let result = recovery(PartialFillPolicy::Retry)
+            .resolve(
+                &orders,
+                Some(Duration::from_millis(20)),
+                dec!(100),
+                Decimal::ONE,
+                filled_yes(),
+                failed_no(),
+            )
+            .await;
+
+        // Both retries and the unwind sell rest, so nothing was recovered.
+        assert!(result.is_partial());
+        assert_eq!(result.fills()[0].token_id.as_str(), "yes");
+        assert_eq!(orders.submitted().len(), 3);
+        assert_eq!(orders.cancelled().len(), 3);
+    }
+}
@@ -149,6 +149,8 @@ mod tests {
                 token_id: format!("token-{}", i),
                 name: format!("Outcome {}", i),
                 price: None,
+                volume_24h: None,
+                liquidity: None,
             })
             .collect();
 
@@ -159,6 +161,7 @@ mod tests {
             active: true,
             volume_24h: None,
             liquidity: None,
+            close_time: None,
         }
     }
 
@@ -170,6 +173,8 @@ mod tests {
                 token_id: format!("token-{}", i),
                 name: format!("Outcome {}", i),
                 price: Some(price),
+                volume_24h: None,
+                liquidity: None,
             })
             .collect();
 
@@ -180,6 +185,7 @@ mod tests {
             active: true,
             volume_24h: None,
             liquidity: None,
+            close_time: None,
         }
     }
 
@@ -373,16 +379,21 @@ mod tests {
                     token_id: "t0".to_string(),
                     name: "Yes".to_string(),
                     price: Some(0.5),
+                    volume_24h: None,
+                    liquidity: None,
                 },
                 OutcomeInfo {
                     token_id: "t1".to_string(),
                     name: "No".to_string(),
                     price: None, // Missing!
+                    volume_24h: None,
+                    liquidity: None,
                 },
             ],
             active: true,
             volume_24h: None,
             liquidity: None,
+            close_time: None,
         };
         let score = scorer.opportunity_score(&market);
         assert!((score - 0.5).abs() < SCORE_TOLERANCE);
@@ -3,12 +3,13 @@
 //! Defines configuration types for the Polymarket adapter including connection
 //! settings, market filtering, scoring weights, and deduplication options.
 
-use serde::Deserialize;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 
 /// Exchange deployment environment.
 ///
 /// Determines which blockchain network and API endpoints to use.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Environment {
     /// Amoy testnet (chain ID 80002).
@@ -31,7 +32,7 @@ impl std::fmt::Display for Environment {
 ///
 /// Controls connection lifecycle, health monitoring, and capacity limits
 /// for the WebSocket connection pool.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PolymarketConnectionConfig {
     /// Maximum number of concurrent WebSocket connections.
     #[serde(default = "default_pool_max_connections")]
@@ -100,9 +101,10 @@ impl Default for PolymarketConnectionConfig {
 
 /// HTTP client configuration for REST API calls.
 ///
-/// Controls timeouts and retry behavior for HTTP requests to the
-/// Polymarket REST APIs.
-#[derive(Debug, Clone, Deserialize)]
+/// Controls timeouts, connection pooling, and retry behavior for HTTP
+/// requests to the Polymarket REST APIs. Clients built from the same
+/// settings share one connection pool.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct PolymarketHttpConfig {
     /// Request timeout in milliseconds.
     #[serde(default = "default_http_timeout_ms")]
@@ -110,12 +112,24 @@ pub struct PolymarketHttpConfig {
     /// Connection establishment timeout in milliseconds.
     #[serde(default = "default_http_connect_timeout_ms")]
     pub connect_timeout_ms: u64,
-    /// Maximum retry attempts for transient failures.
+    /// Maximum attempts per request, including the first.
+    ///
+    /// Timeouts, connection errors, and 5xx responses are retried.
     #[serde(default = "default_http_retry_max_attempts")]
     pub retry_max_attempts: u32,
-    /// Backoff delay between retries in milliseconds.
+    /// Delay before the first retry in milliseconds, doubled for each
+    /// further retry.
     #[serde(default = "default_http_retry_backoff_ms")]
     pub retry_backoff_ms: u64,
+    /// Upper bound on the retry delay in milliseconds.
+    #[serde(default = "default_http_retry_max_backoff_ms")]
+    pub retry_max_backoff_ms: u64,
+    /// Seconds an idle pooled connection is kept open.
+    #[serde(default = "default_http_pool_idle_timeout_secs")]
+    pub pool_idle_timeout_secs: u64,
+    /// Maximum idle pooled connections kept per host.
+    #[serde(default = "default_http_pool_max_idle_per_host")]
+    pub pool_max_idle_per_host: usize,
 }
 
 const fn default_http_timeout_ms() -> u64 {
@@ -134,6 +148,18 @@ const fn default_http_retry_backoff_ms() -> u64 {
     500
 }
 
+const fn default_http_retry_max_backoff_ms() -> u64 {
+    5000
+}
+
+const fn default_http_pool_idle_timeout_secs() -> u64 {
+    90
+}
+
+const fn default_http_pool_max_idle_per_host() -> usize {
+    8
+}
+
 impl Default for PolymarketHttpConfig {
     fn default() -> Self {
         Self {
@@ -141,6 +167,9 @@ impl Default for PolymarketHttpConfig {
             connect_timeout_ms: default_http_connect_timeout_ms(),
             retry_max_attempts: default_http_retry_max_attempts(),
             retry_backoff_ms: default_http_retry_backoff_ms(),
+            retry_max_backoff_ms: default_http_retry_max_backoff_ms(),
+            pool_idle_timeout_secs: default_http_pool_idle_timeout_secs(),
+            pool_max_idle_per_host: default_http_pool_max_idle_per_host(),
         }
     }
 }
@@ -149,7 +178,7 @@ impl Default for PolymarketHttpConfig {
 ///
 /// Defines criteria for determining which markets are eligible for
 /// tracking and subscription.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PolymarketFilterConfig {
     /// Maximum number of markets to track simultaneously.
     #[serde(default = "default_filter_max_markets")]
@@ -175,6 +204,21 @@ pub struct PolymarketFilterConfig {
     /// Maximum allowed outcomes per market.
     #[serde(default = "default_max_outcomes")]
     pub max_outcomes: usize,
+    /// Skip markets where any outcome is priced above this (e.g., 0.97).
+    #[serde(default)]
+    pub max_outcome_price: Option<f64>,
+    /// Skip markets that close within this many seconds.
+    #[serde(default)]
+    pub min_time_to_close_secs: Option<u64>,
+    /// Market IDs that bypass the volume floor.
+    #[serde(default)]
+    pub whitelist: Vec<String>,
+    /// Market IDs that are always skipped.
+    #[serde(default)]
+    pub blacklist: Vec<String>,
+    /// Skip markets whose question contains any of these (case-insensitive).
+    #[serde(default)]
+    pub exclude_keywords: Vec<String>,
 }
 
 const fn default_true() -> bool {
@@ -216,6 +260,11 @@ impl Default for PolymarketFilterConfig {
             include_binary: true,
             include_multi_outcome: true,
             max_outcomes: default_max_outcomes(),
+            max_outcome_price: None,
+            min_time_to_close_secs: None,
+            whitelist: Vec::new(),
+            blacklist: Vec::new(),
+            exclude_keywords: Vec::new(),
         }
     }
 }
@@ -224,7 +273,7 @@ impl Default for PolymarketFilterConfig {
 ///
 /// Weights determine the relative importance of each scoring factor
 /// when computing composite market scores.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ScoringWeightsConfig {
     /// Weight for liquidity depth factor.
     #[serde(default = "default_weight_liquidity")]
@@ -279,7 +328,7 @@ impl Default for ScoringWeightsConfig {
 ///
 /// Configures bonus multipliers applied to markets based on their
 /// outcome count, favoring multi-outcome markets for arbitrage potential.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OutcomeBonusConfig {
     /// Bonus multiplier for binary (2-outcome) markets.
     #[serde(default = "default_bonus_binary")]
@@ -318,7 +367,7 @@ impl Default for OutcomeBonusConfig {
 ///
 /// Combines scoring weights and outcome bonuses for computing composite
 /// market scores used in subscription prioritization.
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct PolymarketScoringConfig {
     /// Factor weights for composite score calculation.
     #[serde(default)]
@@ -332,7 +381,7 @@ pub struct PolymarketScoringConfig {
 ///
 /// Determines how duplicate messages are detected across redundant
 /// WebSocket connections.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum DedupStrategyConfig {
     /// Hash-based deduplication using message content hash.
@@ -348,7 +397,7 @@ pub enum DedupStrategyConfig {
 ///
 /// Controls duplicate message filtering when using multiple WebSocket
 /// connections for redundancy.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PolymarketDedupConfig {
     /// Enable duplicate message filtering.
     #[serde(default = "default_true")]
@@ -395,7 +444,7 @@ impl Default for PolymarketDedupConfig {
 ///
 /// Aggregates all configuration subsections for the Polymarket adapter
 /// including network settings, connection management, and trading parameters.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PolymarketConfig {
     /// Deployment environment (testnet or mainnet).
     #[serde(default)]
@@ -409,6 +458,9 @@ pub struct PolymarketConfig {
     /// Gamma REST API URL for market discovery.
     #[serde(default = "default_polymarket_gamma_url")]
     pub gamma_api_url: String,
+    /// Data REST API URL for wallet holdings.
+    #[serde(default = "default_polymarket_data_url")]
+    pub data_api_url: String,
     /// Blockchain chain ID (80002 for Amoy, 137 for Polygon).
     #[serde(default = "default_polymarket_chain_id")]
     pub chain_id: u64,
@@ -427,6 +479,12 @@ pub struct PolymarketConfig {
     /// Message deduplication settings.
     #[serde(default)]
     pub dedup: PolymarketDedupConfig,
+    /// Price increment order limit prices are rounded to.
+    #[serde(default = "default_polymarket_price_tick")]
+    pub price_tick: Decimal,
+    /// Share increment order sizes are rounded down to.
+    #[serde(default = "default_polymarket_size_increment")]
+    pub size_increment: Decimal,
 }
 
 fn default_polymarket_ws_url() -> String {
@@ -441,11 +499,24 @@ fn default_polymarket_gamma_url() -> String {
     "https://gamma-api.polymarket.com".into()
 }
 
+fn default_polymarket_data_url() -> String {
+    "https://data-api.polymarket.com".into()
+}
+
 /// Default chain ID is Amoy testnet (80002) for safety
 const fn default_polymarket_chain_id() -> u64 {
     80002
 }
 
+/// Standard CLOB tick; only markets priced near 0 or 1 use a finer one.
+fn default_polymarket_price_tick() -> Decimal {
+    Decimal::new(1, 2) // 0.01
+}
+
+fn default_polymarket_size_increment() -> Decimal {
+    Decimal::new(1, 2) // 0.01
+}
+
 impl Default for PolymarketConfig {
     fn default() -> Self {
         Self {
@@ -453,12 +524,15 @@ impl Default for PolymarketConfig {
             ws_url: default_polymarket_ws_url(),
             api_url: default_polymarket_api_url(),
             gamma_api_url: default_polymarket_gamma_url(),
+            data_api_url: default_polymarket_data_url(),
             chain_id: default_polymarket_chain_id(),
             connections: PolymarketConnectionConfig::default(),
             http: PolymarketHttpConfig::default(),
             market_filter: PolymarketFilterConfig::default(),
             scoring: PolymarketScoringConfig::default(),
             dedup: PolymarketDedupConfig::default(),
+            price_tick: default_polymarket_price_tick(),
+            size_increment: default_polymarket_size_increment(),
         }
     }
 }
@@ -0,0 +1,536 @@
+//! Recorded order book replay for backtesting.
+//!
+//! [`ReplayDataStream`] plays back timestamped order book events from a file
+//! through the [`MarketDataStream`] port, so detection can be run against
+//! historical data without a live exchange connection.
+//!
+//! Two formats are supported, chosen by file extension:
+//!
+//! - **NDJSON** (default): one event per line.
+//!
+//!   ```text
+//!   {"timestamp": 1700000000000, "token_id": "yes", "bids": [["0.44", "100"]], "asks": [["0.46", "50"]]}
+//!   {"timestamp": 1700000000250, "token_id": "yes", "type": "delta", "bids": [], "asks": [["0.45", "20"]]}
+//!   ```
+//!
+//!   `type` is `snapshot` (the default) or `delta`. Recordings may also
+//!   define the markets they cover with `market` lines, which the stream
+//!   skips and [`load_recorded_markets`] reads:
+//!
+//!   ```text
+//!   {"type": "market", "market_id": "m1", "question": "Rain?", "outcomes": [{"token_id": "yes", "name": "Yes"}, {"token_id": "no", "name": "No"}]}
+//!   ```
+//!
+//! - **CSV** (`.csv`): one price level per row, with an optional header.
+//!   Consecutive rows sharing a timestamp and token form one snapshot.
+//!
+//!   ```text
+//!   timestamp,token_id,side,price,size
+//!   1700000000000,yes,bid,0.44,100
+//!   1700000000000,yes,ask,0.46,50
+//!   ```
+//!
+//! Timestamps are Unix milliseconds.
+
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use tokio::time::Instant;
+use tracing::info;
+
+use crate::domain::book::{Book, PriceLevel};
+use crate::domain::id::{MarketId, TokenId};
+use crate::domain::market::{Market, Outcome};
+use crate::error::{Error, Result};
+use crate::port::outbound::exchange::{MarketDataStream, MarketEvent};
+
+/// A recorded event and the time it was observed.
+#[derive(Debug, Clone)]
+struct ReplayRecord {
+    timestamp_ms: i64,
+    event: MarketEvent,
+}
+
+/// Kind of book update in an NDJSON record.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RecordKind {
+    #[default]
+    Snapshot,
+    Delta,
+}
+
+/// One line of an NDJSON recording.
+#[derive(Debug, Deserialize)]
+struct JsonRecord {
+    timestamp: i64,
+    token_id: String,
+    #[serde(default, rename = "type")]
+    kind: RecordKind,
+    #[serde(default)]
+    bids: Vec<(Decimal, Decimal)>,
+    #[serde(default)]
+    asks: Vec<(Decimal, Decimal)>,
+}
+
+impl JsonRecord {
+    fn into_record(self) -> ReplayRecord {
+        let token_id = TokenId::from(self.token_id);
+        let book = sorted_book(token_id.clone(), to_levels(self.bids), to_levels(self.asks));
+        let event = match self.kind {
+            RecordKind::Snapshot => MarketEvent::BookSnapshot { token_id, book },
+            RecordKind::Delta => MarketEvent::BookDelta { token_id, book },
+        };
+        ReplayRecord {
+            timestamp_ms: self.timestamp,
+            event,
+        }
+    }
+}
+
+/// A market definition line in an NDJSON recording.
+#[derive(Debug, Deserialize)]
+struct MarketRecord {
+    market_id: String,
+    #[serde(default)]
+    question: String,
+    outcomes: Vec<OutcomeRecord>,
+    #[serde(default = "default_payout")]
+    payout: Decimal,
+}
+
+/// One outcome of a recorded market definition.
+#[derive(Debug, Deserialize)]
+struct OutcomeRecord {
+    token_id: String,
+    name: String,
+}
+
+fn default_payout() -> Decimal {
+    Decimal::ONE
+}
+
+impl MarketRecord {
+    fn into_market(self) -> Result<Market> {
+        let outcomes = self
+            .outcomes
+            .into_iter()
+            .map(|outcome| Outcome::new(TokenId::from(outcome.token_id), outcome.name))
+            .collect();
+        Ok(Market::try_new(
+            MarketId::from(self.market_id),
+            self.question,
+            outcomes,
+            self.payout,
+        )?)
+    }
+}
+
+fn to_levels(levels: Vec<(Decimal, Decimal)>) -> Vec<PriceLevel> {
+    levels
+        .into_iter()
+        .map(|(price, size)| PriceLevel::new(price, size))
+        .collect()
+}
+
+/// Build a book, sorting levels so recordings need not be pre-sorted.
+fn sorted_book(token_id: TokenId, mut bids: Vec<PriceLevel>, mut asks: Vec<PriceLevel>) -> Book {
+    bids.sort_by(|a, b| b.price().cmp(&a.price()));
+    asks.sort_by_key(PriceLevel::price);
+    Book::with_levels(token_id, bids, asks)
+}
+
+/// Market data stream that replays a recorded file of order book events.
+///
+/// Events are emitted in file order. With a positive `speed`, the gap between
+/// consecutive timestamps is slept through, divided by `speed` (2.0 replays
+/// twice as fast as recorded). A `speed` of zero replays without delay.
+pub struct ReplayDataStream {
+    path: PathBuf,
+    speed: f64,
+    records: VecDeque<ReplayRecord>,
+    subscribed: HashSet<TokenId>,
+    last_timestamp_ms: Option<i64>,
+    deadline: Option<Instant>,
+}
+
+impl ReplayDataStream {
+    /// Create a replay stream for the recording at `path`.
+    pub fn new(path: impl Into<PathBuf>, speed: f64) -> Self {
+        Self {
+            path: path.into(),
+            speed,
+            records: VecDeque::new(),
+            subscribed: HashSet::new(),
+            last_timestamp_ms: None,
+            deadline: None,
+        }
+    }
+
+    fn is_csv(&self) -> bool {
+        is_csv_path(&self.path)
+    }
+
+    /// Delay to wait before emitting an event recorded at `timestamp_ms`.
+    fn delay_before(&self, timestamp_ms: i64) -> Option<Duration> {
+        if self.speed <= 0.0 {
+            return None;
+        }
+        let elapsed = timestamp_ms - self.last_timestamp_ms?;
+        if elapsed <= 0 {
+            return None;
+        }
+        Some(Duration::from_secs_f64(
+            elapsed as f64 / 1000.0 / self.speed,
+        ))
+    }
+}
+
+/// Split an NDJSON recording into 1-based line numbers and parsed values,
+/// skipping blank lines.
+fn ndjson_values(content: &str) -> impl Iterator<Item = Result<(usize, serde_json::Value)>> + '_ {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .map(|value| (index + 1, value))
+                .map_err(|e| line_error(index + 1, &e))
+        })
+}
+
+fn line_error(line: usize, error: &serde_json::Error) -> Error {
+    Error::Parse(format!("replay line {line}: {error}"))
+}
+
+/// Whether an NDJSON value is a market definition rather than a book update.
+fn is_market_line(value: &serde_json::Value) -> bool {
+    value.get("type").and_then(serde_json::Value::as_str) == Some("market")
+}
+
+/// Parse the book updates of an NDJSON recording.
+fn parse_ndjson(content: &str) -> Result<Vec<ReplayRecord>> {
+    let mut records = Vec::new();
+    for entry in ndjson_values(content) {
+        let (line, value) = entry?;
+        if is_market_line(&value) {
+            continue;
+        }
+        let record: JsonRecord = serde_json::from_value(value).map_err(|e| line_error(line, &e))?;
+        records.push(record.into_record());
+    }
+    Ok(records)
+}
+
+/// Parse the market definitions of an NDJSON recording.
+fn parse_markets(content: &str) -> Result<Vec<Market>> {
+    let mut markets = Vec::new();
+    for entry in ndjson_values(content) {
+        let (line, value) = entry?;
+        if !is_market_line(&value) {
+            continue;
+        }
+        let record: MarketRecord =
+            serde_json::from_value(value).map_err(|e| line_error(line, &e))?;
+        markets.push(record.into_market()?);
+    }
+    Ok(markets)
+}
+
+/// Read the market definitions embedded in a recording.
+///
+/// Only NDJSON recordings can define markets; CSV recordings yield none.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a market line is invalid.
+pub async fn load_recorded_markets(path: &Path) -> Result<Vec<Market>> {
+    if is_csv_path(path) {
+        return Ok(Vec::new());
+    }
+    let content = tokio::fs::read_to_string(path).await?;
+    parse_markets(&content)
+}
+
+fn is_csv_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"))
+}
+
+/// Parse a CSV recording, grouping consecutive rows into snapshots.
+fn parse_csv(content: &str) -> Result<Vec<ReplayRecord>> {
+    let mut records = Vec::new();
+    let mut current: Option<(i64, TokenId, Vec<PriceLevel>, Vec<PriceLevel>)> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || (index == 0 && line.starts_with("timestamp")) {
+            continue;
+        }
+
+        let parse_error =
+            |reason: &str| Error::Parse(format!("replay line {}: {reason}", index + 1));
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [timestamp, token_id, side, price, size] = fields[..] else {
+            return Err(parse_error("expected 5 columns"));
+        };
+        let timestamp: i64 = timestamp
+            .parse()
+            .map_err(|_| parse_error("invalid timestamp"))?;
+        let token_id = TokenId::from(token_id);
+        let level = PriceLevel::new(
+            price.parse().map_err(|_| parse_error("invalid price"))?,
+            size.parse().map_err(|_| parse_error("invalid size"))?,
+        );
+
+        let same_group = current
+            .as_ref()
+            .is_some_and(|(ts, token, _, _)| *ts == timestamp && *token == token_id);
+        if !same_group {
+            records.extend(current.take().map(csv_snapshot));
+            current = Some((timestamp, token_id, Vec::new(), Vec::new()));
+        }
+
+        let (_, _, bids, asks) = current.as_mut().expect("group initialized above");
+        match side.to_ascii_lowercase().as_str() {
+            "bid" => bids.push(level),
+            "ask" => asks.push(level),
+            _ => return Err(parse_error("side must be 'bid' or 'ask'")),
+        }
+    }
+
+    records.extend(current.map(csv_snapshot));
+    Ok(records)
+}
+
+fn csv_snapshot(
+    (timestamp_ms, token_id, bids, asks): (i64, TokenId, Vec<PriceLevel>, Vec<PriceLevel>),
+) -> ReplayRecord {
+    let book = sorted_book(token_id.clone(), bids, asks);
+    ReplayRecord {
+        timestamp_ms,
+        event: MarketEvent::BookSnapshot { token_id, book },
+    }
+}
+
+#[async_trait]
+impl MarketDataStream for ReplayDataStream {
+    async fn connect(&mut self) -> Result<()> {
+        let content = tokio::fs::read_to_string(&self.path).await?;
+        let records = if self.is_csv() {
+            parse_csv(&content)?
+        } else {
+            parse_ndjson(&content)?
+        };
+        info!(
+            path = %self.path.display(),
+            events = records.len(),
+            speed = self.speed,
+            "Loaded replay file"
+        );
+        self.records = records.into();
+        self.last_timestamp_ms = None;
+        self.deadline = None;
+        Ok(())
+    }
+
+    async fn subscribe(&mut self, token_ids: &[TokenId]) -> Result<()> {
+        self.subscribed.extend(token_ids.iter().cloned());
+        Ok(())
+    }
+
+    async fn next_event(&mut self) -> Option<MarketEvent> {
+        loop {
+            let record = self.records.front()?;
+            let timestamp_ms = record.timestamp_ms;
+            let subscribed = record
+                .event
+                .token_id()
+                .map_or(true, |token_id| self.subscribed.contains(token_id));
+            if !subscribed {
+                self.records.pop_front();
+                continue;
+            }
+
+            // The record stays queued until its deadline passes, so a caller
+            // that drops this future mid-sleep resumes the same wait.
+            if let Some(delay) = self.delay_before(timestamp_ms) {
+                let deadline = *self.deadline.get_or_insert_with(|| Instant::now() + delay);
+                tokio::time::sleep_until(deadline).await;
+            }
+            self.deadline = None;
+            self.last_timestamp_ms = Some(timestamp_ms);
+            return self.records.pop_front().map(|record| record.event);
+        }
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        "Replay"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use std::io::Write;
+
+    fn write_recording(extension: &str, content: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::Builder::new()
+            .suffix(extension)
+            .tempfile()
+            .unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    async fn drain(stream: &mut ReplayDataStream) -> Vec<MarketEvent> {
+        let mut events = Vec::new();
+        while let Some(event) = stream.next_event().await {
+            events.push(event);
+        }
+        events
+    }
+
+    const NDJSON: &str = r#"{"timestamp": 1000, "token_id": "a", "bids": [["0.44", "100"]], "asks": [["0.46", "50"]]}
+
+{"timestamp": 1100, "token_id": "b", "asks": [["0.52", "10"]]}
+{"timestamp": 1200, "token_id": "a", "type": "delta", "asks": [["0.45", "20"]]}
+"#;
+
+    #[tokio::test]
+    async fn replays_ndjson_in_order() {
+        let file = write_recording(".jsonl", NDJSON);
+        let mut stream = ReplayDataStream::new(file.path(), 0.0);
+        stream.connect().await.unwrap();
+        stream
+            .subscribe(&[TokenId::from("a"), TokenId::from("b")])
+            .await
+            .unwrap();
+
+        let events = drain(&mut stream).await;
+
+        assert_eq!(events.len(), 3);
+        match &events[0] {
+            MarketEvent::BookSnapshot { token_id, book } => {
+                assert_eq!(token_id.as_str(), "a");
+                assert_eq!(book.best_bid().unwrap().price(), dec!(0.44));
+                assert_eq!(book.best_ask().unwrap().price(), dec!(0.46));
+            }
+            other => panic!("expected snapshot, got {other:?}"),
+        }
+        assert!(
+            matches!(&events[1], MarketEvent::BookSnapshot { token_id, .. } if token_id.as_str() == "b")
+        );
+        assert!(
+            matches!(&events[2], MarketEvent::BookDelta { token_id, .. } if token_id.as_str() == "a")
+        );
+    }
+
+    #[tokio::test]
+    async fn subscribe_filters_tokens() {
+        let file = write_recording(".jsonl", NDJSON);
+        let mut stream = ReplayDataStream::new(file.path(), 0.0);
+        stream.connect().await.unwrap();
+        stream.subscribe(&[TokenId::from("b")]).await.unwrap();
+
+        let events = drain(&mut stream).await;
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].token_id().unwrap().as_str(), "b");
+    }
+
+    #[tokio::test]
+    async fn groups_csv_rows_into_snapshots() {
+        let csv = "timestamp,token_id,side,price,size\n\
+                   1000,a,bid,0.44,100\n\
+                   1000,a,ask,0.46,50\n\
+                   1000,a,ask,0.47,25\n\
+                   2000,a,ask,0.45,10\n";
+        let file = write_recording(".csv", csv);
+        let mut stream = ReplayDataStream::new(file.path(), 0.0);
+        stream.connect().await.unwrap();
+        stream.subscribe(&[TokenId::from("a")]).await.unwrap();
+
+        let events = drain(&mut stream).await;
+
+        assert_eq!(events.len(), 2);
+        match &events[0] {
+            MarketEvent::BookSnapshot { book, .. } => {
+                assert_eq!(book.bids().len(), 1);
+                assert_eq!(book.asks().len(), 2);
+            }
+            other => panic!("expected snapshot, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn invalid_line_reports_position() {
+        let file = write_recording(
+            ".jsonl",
+            "{\"timestamp\": 1, \"token_id\": \"a\"}\nnot json\n",
+        );
+        let mut stream = ReplayDataStream::new(file.path(), 0.0);
+
+        let err = stream.connect().await.unwrap_err();
+
+        assert!(err.to_string().contains("line 2"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn market_lines_are_loaded_and_not_replayed() {
+        let recording = format!(
+            "{}\n{NDJSON}",
+            r#"{"type": "market", "market_id": "m1", "question": "Rain?", "outcomes": [{"token_id": "a", "name": "Yes"}, {"token_id": "b", "name": "No"}]}"#
+        );
+        let file = write_recording(".jsonl", &recording);
+
+        let markets = load_recorded_markets(file.path()).await.unwrap();
+        let mut stream = ReplayDataStream::new(file.path(), 0.0);
+        stream.connect().await.unwrap();
+        stream
+            .subscribe(&[TokenId::from("a"), TokenId::from("b")])
+            .await
+            .unwrap();
+
+        assert_eq!(markets.len(), 1);
+        assert_eq!(markets[0].market_id().as_str(), "m1");
+        assert_eq!(markets[0].outcome_count(), 2);
+        assert_eq!(markets[0].payout(), Decimal::ONE);
+        assert_eq!(drain(&mut stream).await.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn market_without_outcomes_is_rejected() {
+        let file = write_recording(
+            ".jsonl",
+            r#"{"type": "market", "market_id": "m1", "outcomes": []}"#,
+        );
+
+        assert!(load_recorded_markets(file.path()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn missing_file_fails_to_connect() {
+        let mut stream = ReplayDataStream::new("/nonexistent/replay.jsonl", 0.0);
+        assert!(stream.connect().await.is_err());
+    }
+
+    #[test]
+    fn delay_scales_with_speed() {
+        let mut stream = ReplayDataStream::new("replay.jsonl", 2.0);
+        assert_eq!(stream.delay_before(1000), None);
+
+        stream.last_timestamp_ms = Some(1000);
+        assert_eq!(stream.delay_before(2000), Some(Duration::from_millis(500)));
+        assert_eq!(stream.delay_before(900), None);
+
+        stream.speed = 0.0;
+        assert_eq!(stream.delay_before(2000), None);
+    }
+}
@@ -15,7 +15,8 @@ use crate::domain::constraint::ConstraintSense;
 use crate::error::Result;
 use crate::port::{
     outbound::solver::IlpProblem, outbound::solver::LpProblem, outbound::solver::LpSolution,
-    outbound::solver::SolutionStatus, outbound::solver::Solver,
+    outbound::solver::SolutionStatus, outbound::solver::Solver, outbound::solver::SolverBackend,
+    outbound::solver::Vertex,
 };
 
 /// HiGHS-based LP/ILP solver.
@@ -44,6 +45,30 @@ impl Solver for HiGHSSolver {
     }
 }
 
+impl SolverBackend for HiGHSSolver {
+    fn name(&self) -> &'static str {
+        "highs"
+    }
+
+    fn solve_oracle(
+        &self,
+        gradient: &[Decimal],
+        constraints: &IlpProblem,
+    ) -> Result<Option<Vertex>> {
+        let oracle = LpProblem {
+            objective: gradient.to_vec(),
+            constraints: constraints.lp.constraints.clone(),
+            bounds: constraints.lp.bounds.clone(),
+        };
+        let solution = solve_with_good_lp(&oracle, &constraints.integer_vars)?;
+
+        Ok(solution.is_optimal().then(|| Vertex {
+            values: solution.values,
+            objective: solution.objective,
+        }))
+    }
+}
+
 /// Internal solver implementation using `good_lp`.
 fn solve_with_good_lp(problem: &LpProblem, integer_vars: &[usize]) -> Result<LpSolution> {
     let n = problem.num_vars();
@@ -154,7 +179,8 @@ mod tests {
     #[test]
     fn test_solver_name() {
         let solver = HiGHSSolver::new();
-        assert_eq!(solver.name(), "highs");
+        assert_eq!(Solver::name(&solver), "highs");
+        assert_eq!(SolverBackend::name(&solver), "highs");
     }
 
     #[test]
@@ -214,6 +240,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_oracle_minimizes_gradient_over_region() {
+        // Exactly one of three binary outcomes; the gradient favors the second
+        let solver = HiGHSSolver::new();
+        let lp = LpProblem {
+            objective: vec![Decimal::ZERO; 3],
+            constraints: vec![Constraint::eq(vec![Decimal::ONE; 3], Decimal::ONE)],
+            bounds: vec![VariableBounds::binary(); 3],
+        };
+        let ilp = IlpProblem::all_binary(lp);
+
+        let vertex = solver
+            .solve_oracle(&[dec!(0.2), dec!(-0.5), dec!(0.1)], &ilp)
+            .unwrap()
+            .expect("feasible region has an optimal vertex");
+
+        assert!((vertex.values[1] - Decimal::ONE).abs() < dec!(0.01));
+        assert!((vertex.objective - dec!(-0.5)).abs() < dec!(0.01));
+    }
+
     #[test]
     fn test_equality_constraint() {
         // Minimize: x
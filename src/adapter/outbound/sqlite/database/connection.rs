@@ -28,6 +28,24 @@ pub fn create_pool(database_url: &str) -> Result<DbPool> {
         .map_err(|e| crate::error::Error::Connection(e.to_string()))
 }
 
+/// Create a pool over a private in-memory database.
+///
+/// SQLite gives every `:memory:` connection its own database, so the pool
+/// holds a single connection that is never recycled. Migrations and writes
+/// then see the same data for as long as the pool lives.
+///
+/// # Errors
+/// Returns an error if the pool cannot be created.
+pub fn create_memory_pool() -> Result<DbPool> {
+    let manager = ConnectionManager::<SqliteConnection>::new(":memory:");
+    Pool::builder()
+        .max_size(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .build(manager)
+        .map_err(|e| crate::error::Error::Connection(e.to_string()))
+}
+
 /// Run all pending database migrations.
 ///
 /// # Errors
@@ -107,6 +125,26 @@ mod tests {
         assert!(result.contains(&"trades".to_string()));
         assert!(result.contains(&"daily_stats".to_string()));
         assert!(result.contains(&"strategy_daily_stats".to_string()));
+        assert!(result.contains(&"trading_state".to_string()));
+        assert!(result.contains(&"latency_snapshot".to_string()));
+        assert!(result.contains(&"market_scores".to_string()));
+        assert!(result.contains(&"positions".to_string()));
+    }
+
+    #[test]
+    fn create_memory_pool_keeps_migrated_tables_across_checkouts() {
+        let pool = create_memory_pool().unwrap();
+        run_migrations(&pool).unwrap();
+
+        let mut conn = pool.get().unwrap();
+        let count = diesel::sql_query(
+            "SELECT COUNT(*) as count FROM sqlite_master WHERE type='table' AND name='trades'",
+        )
+        .load::<TableCount>(&mut conn)
+        .unwrap()[0]
+            .count;
+
+        assert_eq!(count, 1);
     }
 
     #[derive(diesel::QueryableByName)]
@@ -3,7 +3,8 @@
 use diesel::prelude::*;
 
 use super::schema::{
-    clusters, daily_stats, opportunities, relations, strategy_daily_stats, trades,
+    clusters, daily_stats, latency_snapshot, market_scores, opportunities, positions, relations,
+    strategy_daily_stats, trades, trading_state,
 };
 
 /// Database row for a relation.
@@ -43,6 +44,8 @@ pub struct NewOpportunityRow {
     pub detected_at: String,
     pub executed: i32,
     pub rejected_reason: Option<String>,
+    pub variant: String,
+    pub uid: Option<String>,
 }
 
 /// Database row for an opportunity (queryable).
@@ -58,6 +61,8 @@ pub struct OpportunityRow {
     pub detected_at: String,
     pub executed: i32,
     pub rejected_reason: Option<String>,
+    pub variant: String,
+    pub uid: Option<String>,
 }
 
 /// Database row for a trade (insertable).
@@ -72,6 +77,7 @@ pub struct NewTradeRow {
     pub expected_profit: f32,
     pub status: String,
     pub opened_at: String,
+    pub opportunity_uid: Option<String>,
 }
 
 /// Database row for a trade (queryable).
@@ -91,6 +97,7 @@ pub struct TradeRow {
     pub opened_at: String,
     pub closed_at: Option<String>,
     pub close_reason: Option<String>,
+    pub opportunity_uid: Option<String>,
 }
 
 /// Database row for daily stats.
@@ -128,6 +135,66 @@ pub struct StrategyDailyStatsRow {
     pub profit_realized: f32,
     pub win_count: i32,
     pub loss_count: i32,
+    pub loss_realized: f32,
+}
+
+/// Database row for the persisted trading state.
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = trading_state)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct TradingStateRow {
+    pub id: i32,
+    pub state: String,
+    pub updated_at: String,
+}
+
+/// Database row for a tracked position.
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = positions)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct PositionRow {
+    pub id: i32,
+    pub market_id: String,
+    pub legs: String,
+    pub entry_cost: String,
+    pub guaranteed_payout: String,
+    pub status: String,
+    pub filled: Option<String>,
+    pub missing: Option<String>,
+    pub realized_pnl: Option<String>,
+    pub trade_id: Option<i32>,
+    pub held_to_settlement: i32,
+    pub opened_at: String,
+    pub updated_at: String,
+}
+
+/// Database row for the latest detection latency percentiles.
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = latency_snapshot)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct LatencySnapshotRow {
+    pub id: i32,
+    pub p50_us: i64,
+    pub p95_us: i64,
+    pub p99_us: i64,
+    pub sample_count: i32,
+    pub updated_at: String,
+}
+
+/// Database row for a top-ranked market and its score breakdown.
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = market_scores)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct MarketScoreRow {
+    pub rank: i32,
+    pub market_id: String,
+    pub question: String,
+    pub composite: f32,
+    pub liquidity: f32,
+    pub spread: f32,
+    pub activity: f32,
+    pub time_to_close: f32,
+    pub scored_at: String,
 }
 
 #[cfg(test)]
@@ -174,6 +241,8 @@ mod tests {
             detected_at: "2026-01-01T00:00:00Z".to_string(),
             executed: 0,
             rejected_reason: None,
+            variant: "primary".to_string(),
+            uid: None,
         };
     }
 
@@ -188,6 +257,7 @@ mod tests {
             expected_profit: 5.0,
             status: "open".to_string(),
             opened_at: "2026-01-01T00:00:00Z".to_string(),
+            opportunity_uid: None,
         };
     }
 
@@ -387,6 +457,8 @@ mod tests {
             detected_at: "2026-01-15T10:30:00Z".to_string(),
             executed: 1,
             rejected_reason: None,
+            variant: "primary".to_string(),
+            uid: None,
         };
 
         diesel::insert_into(opportunities::table)
@@ -420,6 +492,8 @@ mod tests {
             detected_at: "2026-01-01T00:00:00Z".to_string(),
             executed: 1,
             rejected_reason: None,
+            variant: "primary".to_string(),
+            uid: None,
         };
         diesel::insert_into(opportunities::table)
             .values(&opp)
@@ -435,6 +509,7 @@ mod tests {
             expected_profit: 5.0,
             status: "open".to_string(),
             opened_at: "2026-01-15T10:30:00Z".to_string(),
+            opportunity_uid: None,
         };
 
         diesel::insert_into(trades::table)
@@ -510,6 +585,7 @@ mod tests {
             profit_realized: 250.0,
             win_count: 10,
             loss_count: 2,
+            loss_realized: 40.0,
         };
 
         diesel::insert_into(strategy_daily_stats::table)
@@ -546,6 +622,8 @@ mod tests {
             detected_at: "2026-01-01T00:00:00Z".to_string(),
             executed: 0,
             rejected_reason: Some("risk_limit_exceeded".to_string()),
+            variant: "primary".to_string(),
+            uid: None,
         };
 
         diesel::insert_into(opportunities::table)
@@ -580,6 +658,8 @@ mod tests {
             detected_at: "2026-01-01T00:00:00Z".to_string(),
             executed: 1,
             rejected_reason: None,
+            variant: "primary".to_string(),
+            uid: None,
         };
         diesel::insert_into(opportunities::table)
             .values(&opp)
@@ -596,6 +676,7 @@ mod tests {
             expected_profit: 5.0,
             status: "open".to_string(),
             opened_at: "2026-01-01T10:00:00Z".to_string(),
+            opportunity_uid: None,
         };
         diesel::insert_into(trades::table)
             .values(&trade)
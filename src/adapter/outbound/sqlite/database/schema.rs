@@ -29,6 +29,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    latency_snapshot (id) {
+        id -> Integer,
+        p50_us -> BigInt,
+        p95_us -> BigInt,
+        p99_us -> BigInt,
+        sample_count -> Integer,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    market_scores (rank) {
+        rank -> Integer,
+        market_id -> Text,
+        question -> Text,
+        composite -> Float,
+        liquidity -> Float,
+        spread -> Float,
+        activity -> Float,
+        time_to_close -> Float,
+        scored_at -> Text,
+    }
+}
+
 diesel::table! {
     opportunities (id) {
         id -> Nullable<Integer>,
@@ -39,6 +64,26 @@ diesel::table! {
         detected_at -> Text,
         executed -> Integer,
         rejected_reason -> Nullable<Text>,
+        variant -> Text,
+        uid -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    positions (id) {
+        id -> Integer,
+        market_id -> Text,
+        legs -> Text,
+        entry_cost -> Text,
+        guaranteed_payout -> Text,
+        status -> Text,
+        filled -> Nullable<Text>,
+        missing -> Nullable<Text>,
+        realized_pnl -> Nullable<Text>,
+        trade_id -> Nullable<Integer>,
+        held_to_settlement -> Integer,
+        opened_at -> Text,
+        updated_at -> Text,
     }
 }
 
@@ -65,6 +110,15 @@ diesel::table! {
         profit_realized -> Float,
         win_count -> Integer,
         loss_count -> Integer,
+        loss_realized -> Float,
+    }
+}
+
+diesel::table! {
+    trading_state (id) {
+        id -> Integer,
+        state -> Text,
+        updated_at -> Text,
     }
 }
 
@@ -82,6 +136,7 @@ diesel::table! {
         opened_at -> Text,
         closed_at -> Nullable<Text>,
         close_reason -> Nullable<Text>,
+        opportunity_uid -> Nullable<Text>,
     }
 }
 
@@ -90,8 +145,12 @@ diesel::joinable!(trades -> opportunities (opportunity_id));
 diesel::allow_tables_to_appear_in_same_query!(
     clusters,
     daily_stats,
+    latency_snapshot,
+    market_scores,
     opportunities,
+    positions,
     relations,
     strategy_daily_stats,
     trades,
+    trading_state,
 );
@@ -4,28 +4,37 @@
 //! Implements the [`StatsRecorder`](crate::port::outbound::stats::StatsRecorder)
 //! trait for the statistics recording port.
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use chrono::{NaiveDate, Utc};
 use diesel::prelude::*;
 use diesel::r2d2::{ConnectionManager, Pool};
 use diesel::OptionalExtension;
 use diesel::SqliteConnection;
+use parking_lot::Mutex;
 use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 use tracing::{debug, warn};
 
 use crate::adapter::outbound::sqlite::database::connection::configure_sqlite_connection;
 use crate::adapter::outbound::sqlite::database::model::{
-    DailyStatsRow, NewOpportunityRow, NewTradeRow, OpportunityRow, StrategyDailyStatsRow, TradeRow,
+    DailyStatsRow, LatencySnapshotRow, MarketScoreRow, NewOpportunityRow, NewTradeRow,
+    OpportunityRow, StrategyDailyStatsRow, TradeRow,
 };
 use crate::adapter::outbound::sqlite::database::schema::{
-    daily_stats, opportunities, strategy_daily_stats, trades,
+    daily_stats, latency_snapshot, market_scores, opportunities, strategy_daily_stats, trades,
 };
+use crate::domain::score::RankedMarket;
 use crate::domain::stats::{
-    OpportunitySummary, RecordedOpportunity, StatsSummary, TradeCloseEvent, TradeOpenEvent,
+    LatencyPercentiles, OpportunityFilter, OpportunitySummary, RecordedOpportunity, StatsSummary,
+    TradeCloseEvent, TradeOpenEvent, PRIMARY_VARIANT,
 };
 
+/// Primary key of the single latency snapshot row.
+const LATENCY_SNAPSHOT_ROW_ID: i32 = 1;
+
 /// Convert a decimal to f32 for storage.
 fn decimal_to_f32(d: Decimal) -> f32 {
     d.to_f32().unwrap_or(0.0)
@@ -44,6 +53,35 @@ pub fn f32_to_decimal(f: f32) -> Decimal {
 pub struct SqliteRecorder {
     /// Database connection pool.
     pool: Pool<ConnectionManager<SqliteConnection>>,
+    /// How long a recorded opportunity suppresses identical repeats. Zero
+    /// disables deduplication.
+    dedup_window: Duration,
+    /// Row ID and record time of each opportunity recorded within the window.
+    recent: Mutex<HashMap<RecordKey, (i32, Instant)>>,
+}
+
+/// Identity of a recorded opportunity for deduplication.
+///
+/// Besides the content hash, the variant and outcome must match, so a
+/// shadow detection or a later execution of the same arbitrage is still
+/// recorded.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct RecordKey {
+    content_hash: u64,
+    variant: String,
+    executed: bool,
+    rejected_reason: Option<String>,
+}
+
+impl RecordKey {
+    fn new(event: &RecordedOpportunity) -> Option<Self> {
+        Some(Self {
+            content_hash: event.content_hash?,
+            variant: event.variant.clone(),
+            executed: event.executed,
+            rejected_reason: event.rejected_reason.clone(),
+        })
+    }
 }
 
 #[derive(QueryableByName)]
@@ -57,13 +95,40 @@ impl SqliteRecorder {
     /// Create a new statistics recorder with the given connection pool.
     #[must_use]
     pub fn new(pool: Pool<ConnectionManager<SqliteConnection>>) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            dedup_window: Duration::ZERO,
+            recent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Skip opportunities identical to one recorded within `window`.
+    ///
+    /// Opportunities are identical when their content hash, variant, and
+    /// outcome match. Repeats return the first row's ID without writing.
+    #[must_use]
+    pub fn with_opportunity_dedup(mut self, window: Duration) -> Self {
+        self.dedup_window = window;
+        self
     }
 
     /// Record an opportunity detection event.
     ///
     /// Returns the database row ID if successful, or `None` on failure.
     pub fn record_opportunity(&self, event: &RecordedOpportunity) -> Option<i32> {
+        self.record_opportunity_at(event, Instant::now())
+    }
+
+    /// Record an opportunity detection event observed at `now`.
+    fn record_opportunity_at(&self, event: &RecordedOpportunity, now: Instant) -> Option<i32> {
+        let key = RecordKey::new(event).filter(|_| !self.dedup_window.is_zero());
+        if let Some(ref key) = key {
+            if let Some(id) = self.recorded_within_window(key, now) {
+                debug!(id = id, opportunity_id = %event.uid, "Opportunity already recorded, skipping");
+                return Some(id);
+            }
+        }
+
         let market_ids_json = serde_json::to_string(&event.market_ids).unwrap_or_default();
         let now = Utc::now().to_rfc3339();
         let today = Utc::now().date_naive().to_string();
@@ -76,6 +141,8 @@ impl SqliteRecorder {
             detected_at: now,
             executed: i32::from(event.executed),
             rejected_reason: event.rejected_reason.clone(),
+            variant: event.variant.clone(),
+            uid: Some(event.uid.clone()),
         };
 
         let mut conn = self.pool.get().ok()?;
@@ -92,23 +159,34 @@ impl SqliteRecorder {
                 .get_result::<LastInsertRowId>(conn)
                 .map(|row| row.id)?;
 
-            self.update_daily_stats_with_conn(conn, &today, &event.strategy, |daily, strategy| {
-                daily.opportunities_detected += 1;
-                strategy.opportunities_detected += 1;
-                if event.executed {
-                    daily.opportunities_executed += 1;
-                    strategy.opportunities_executed += 1;
-                } else if event.rejected_reason.is_some() {
-                    daily.opportunities_rejected += 1;
-                }
-            })?;
+            // Daily aggregates describe the primary configuration only
+            if event.variant == PRIMARY_VARIANT {
+                self.update_daily_stats_with_conn(
+                    conn,
+                    &today,
+                    &event.strategy,
+                    |daily, strategy| {
+                        daily.opportunities_detected += 1;
+                        strategy.opportunities_detected += 1;
+                        if event.executed {
+                            daily.opportunities_executed += 1;
+                            strategy.opportunities_executed += 1;
+                        } else if event.rejected_reason.is_some() {
+                            daily.opportunities_rejected += 1;
+                        }
+                    },
+                )?;
+            }
 
             Ok::<i32, diesel::result::Error>(id)
         });
 
         match id {
             Ok(id) => {
-                debug!(id = id, strategy = %event.strategy, "Recorded opportunity");
+                debug!(id = id, opportunity_id = %event.uid, strategy = %event.strategy, "Recorded opportunity");
+                if let Some(key) = key {
+                    self.recent.lock().insert(key, (id, now));
+                }
                 Some(id)
             }
             Err(e) => {
@@ -118,6 +196,67 @@ impl SqliteRecorder {
         }
     }
 
+    /// Return the row ID of a matching record made within the window.
+    ///
+    /// Records older than the window are forgotten. Repeats do not extend
+    /// the window, so a persistent opportunity is recorded once per window.
+    fn recorded_within_window(&self, key: &RecordKey, now: Instant) -> Option<i32> {
+        let window = self.dedup_window;
+        let mut recent = self.recent.lock();
+        recent.retain(|_, (_, recorded_at)| now.saturating_duration_since(*recorded_at) < window);
+        recent.get(key).map(|(id, _)| *id)
+    }
+
+    /// Mark a recorded opportunity as rejected with `reason`.
+    ///
+    /// An opportunity recorded as executed moves from the executed to the
+    /// rejected count of the day it was detected.
+    pub fn record_opportunity_rejected(&self, opportunity_id: i32, reason: &str) {
+        let mut conn = match self.pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                warn!(error = %e, "Failed to get database connection");
+                return;
+            }
+        };
+        if let Err(e) = configure_sqlite_connection(&mut conn) {
+            warn!(error = %e, "Failed to configure SQLite connection");
+        }
+
+        let result = conn.transaction(|conn| {
+            let row: OpportunityRow = opportunities::table
+                .filter(opportunities::id.eq(opportunity_id))
+                .first(conn)?;
+
+            diesel::update(opportunities::table.filter(opportunities::id.eq(opportunity_id)))
+                .set((
+                    opportunities::executed.eq(0),
+                    opportunities::rejected_reason.eq(Some(reason)),
+                ))
+                .execute(conn)?;
+
+            if row.executed != 0 && row.variant == PRIMARY_VARIANT {
+                // `detected_at` is RFC 3339 in UTC, so it starts with the date.
+                let date = row.detected_at.get(..10).unwrap_or_default();
+                self.update_daily_stats_with_conn(conn, date, &row.strategy, |daily, strategy| {
+                    daily.opportunities_executed -= 1;
+                    daily.opportunities_rejected += 1;
+                    strategy.opportunities_executed -= 1;
+                })?;
+            }
+
+            Ok::<(), diesel::result::Error>(())
+        });
+
+        match result {
+            Ok(()) => debug!(
+                id = opportunity_id,
+                reason, "Recorded opportunity rejection"
+            ),
+            Err(e) => warn!(error = %e, "Opportunity rejection transaction failed"),
+        }
+    }
+
     /// Record a trade opening event.
     ///
     /// Returns the database row ID if successful, or `None` on failure.
@@ -136,6 +275,7 @@ impl SqliteRecorder {
             expected_profit: decimal_to_f32(event.expected_profit),
             status: "open".to_string(),
             opened_at: now,
+            opportunity_uid: Some(event.opportunity_uid.clone()),
         };
 
         let mut conn = self.pool.get().ok()?;
@@ -163,7 +303,12 @@ impl SqliteRecorder {
 
         match id {
             Ok(id) => {
-                debug!(id = id, strategy = %event.strategy, "Recorded trade open");
+                debug!(
+                    id = id,
+                    opportunity_id = %event.opportunity_uid,
+                    strategy = %event.strategy,
+                    "Recorded trade open"
+                );
                 Some(id)
             }
             Err(e) => {
@@ -223,6 +368,7 @@ impl SqliteRecorder {
             } else {
                 daily.loss_realized += profit.abs();
                 daily.loss_count += 1;
+                strat.loss_realized += profit.abs();
                 strat.loss_count += 1;
             }
         });
@@ -239,6 +385,77 @@ impl SqliteRecorder {
         });
     }
 
+    /// Replace the stored latency percentiles with the latest window.
+    pub fn record_latency_percentiles(&self, percentiles: &LatencyPercentiles) {
+        let mut conn = match self.pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                warn!(error = %e, "Failed to get connection for latency snapshot");
+                return;
+            }
+        };
+
+        let micros =
+            |latency: std::time::Duration| i64::try_from(latency.as_micros()).unwrap_or(i64::MAX);
+        let row = LatencySnapshotRow {
+            id: LATENCY_SNAPSHOT_ROW_ID,
+            p50_us: micros(percentiles.p50),
+            p95_us: micros(percentiles.p95),
+            p99_us: micros(percentiles.p99),
+            sample_count: i32::try_from(percentiles.samples).unwrap_or(i32::MAX),
+            updated_at: Utc::now().to_rfc3339(),
+        };
+
+        if let Err(e) = diesel::replace_into(latency_snapshot::table)
+            .values(&row)
+            .execute(&mut conn)
+        {
+            warn!(error = %e, "Failed to record latency snapshot");
+        }
+    }
+
+    /// Replace the stored market ranking with the latest scores.
+    pub fn record_market_scores(&self, markets: &[RankedMarket]) {
+        let mut conn = match self.pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                warn!(error = %e, "Failed to get connection for market scores");
+                return;
+            }
+        };
+
+        let scored_at = Utc::now().to_rfc3339();
+        let rows: Vec<MarketScoreRow> = markets
+            .iter()
+            .enumerate()
+            .map(|(index, market)| {
+                let factors = market.score.factors();
+                MarketScoreRow {
+                    rank: i32::try_from(index + 1).unwrap_or(i32::MAX),
+                    market_id: market.score.market_id().to_string(),
+                    question: market.question.clone(),
+                    composite: market.score.composite() as f32,
+                    liquidity: factors.liquidity as f32,
+                    spread: factors.spread as f32,
+                    activity: factors.activity as f32,
+                    time_to_close: factors.time_to_close as f32,
+                    scored_at: scored_at.clone(),
+                }
+            })
+            .collect();
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            diesel::delete(market_scores::table).execute(conn)?;
+            diesel::insert_into(market_scores::table)
+                .values(&rows)
+                .execute(conn)?;
+            Ok(())
+        });
+        if let Err(e) = result {
+            warn!(error = %e, "Failed to record market scores");
+        }
+    }
+
     /// Update peak exposure if the current value is higher than recorded.
     pub fn update_peak_exposure(&self, exposure: Decimal) {
         let today = Utc::now().date_naive().to_string();
@@ -274,6 +491,67 @@ impl SqliteRecorder {
         self.get_summary(today, today)
     }
 
+    /// Retrieve aggregated statistics per strategy for a date range.
+    #[must_use]
+    pub fn get_strategy_summary(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> HashMap<String, StatsSummary> {
+        let mut conn = match self.pool.get() {
+            Ok(c) => c,
+            Err(_) => return HashMap::new(),
+        };
+
+        let rows: Vec<StrategyDailyStatsRow> = strategy_daily_stats::table
+            .filter(strategy_daily_stats::date.ge(from.to_string()))
+            .filter(strategy_daily_stats::date.le(to.to_string()))
+            .load(&mut conn)
+            .unwrap_or_default();
+
+        strategy_summaries_from_rows(&rows)
+    }
+
+    /// Retrieve recorded opportunities matching a filter, most recent first.
+    #[must_use]
+    pub fn query_opportunities(&self, filter: OpportunityFilter) -> Vec<OpportunitySummary> {
+        let mut conn = match self.pool.get() {
+            Ok(c) => c,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut query = opportunities::table.into_boxed();
+        if let Some(strategy) = filter.strategy {
+            query = query.filter(opportunities::strategy.eq(strategy));
+        }
+        if let Some(variant) = filter.variant {
+            query = query.filter(opportunities::variant.eq(variant));
+        }
+        if let Some(executed) = filter.executed {
+            query = query.filter(opportunities::executed.eq(i32::from(executed)));
+        }
+        if let Some(min_edge) = filter.min_edge {
+            query = query.filter(opportunities::edge.ge(decimal_to_f32(min_edge)));
+        }
+        // `detected_at` is RFC 3339, so date strings bound it lexically
+        if let Some(from) = filter.from {
+            query = query.filter(opportunities::detected_at.ge(from.to_string()));
+        }
+        if let Some(next_day) = filter.to.and_then(|to| to.succ_opt()) {
+            query = query.filter(opportunities::detected_at.lt(next_day.to_string()));
+        }
+        if let Some(limit) = filter.limit {
+            query = query.limit(i64::from(limit));
+        }
+
+        let rows: Vec<OpportunityRow> = query
+            .order((opportunities::detected_at.desc(), opportunities::id.desc()))
+            .load(&mut conn)
+            .unwrap_or_default();
+
+        rows.into_iter().map(opportunity_summary_from_row).collect()
+    }
+
     /// Prune old records while preserving aggregated daily statistics.
     pub fn prune_old_records(&self, retention_days: u32) {
         let cutoff = Utc::now().date_naive() - chrono::Duration::days(i64::from(retention_days));
@@ -367,6 +645,10 @@ impl crate::port::outbound::stats::StatsRecorder for SqliteRecorder {
         SqliteRecorder::record_opportunity(self, event)
     }
 
+    fn record_opportunity_rejected(&self, opportunity_id: i32, reason: &str) {
+        SqliteRecorder::record_opportunity_rejected(self, opportunity_id, reason)
+    }
+
     fn record_trade_open(&self, event: &TradeOpenEvent) -> Option<i32> {
         SqliteRecorder::record_trade_open(self, event)
     }
@@ -379,6 +661,14 @@ impl crate::port::outbound::stats::StatsRecorder for SqliteRecorder {
         SqliteRecorder::record_latency(self, latency_ms)
     }
 
+    fn record_latency_percentiles(&self, percentiles: &LatencyPercentiles) {
+        SqliteRecorder::record_latency_percentiles(self, percentiles)
+    }
+
+    fn record_market_scores(&self, markets: &[RankedMarket]) {
+        SqliteRecorder::record_market_scores(self, markets)
+    }
+
     fn update_peak_exposure(&self, exposure: Decimal) {
         SqliteRecorder::update_peak_exposure(self, exposure)
     }
@@ -390,6 +680,18 @@ impl crate::port::outbound::stats::StatsRecorder for SqliteRecorder {
     fn get_today(&self) -> StatsSummary {
         SqliteRecorder::get_today(self)
     }
+
+    fn get_strategy_summary(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> HashMap<String, StatsSummary> {
+        SqliteRecorder::get_strategy_summary(self, from, to)
+    }
+
+    fn query_opportunities(&self, filter: OpportunityFilter) -> Vec<OpportunitySummary> {
+        SqliteRecorder::query_opportunities(self, filter)
+    }
 }
 
 /// Create a statistics recorder from a database connection pool.
@@ -469,17 +771,22 @@ pub fn recent_opportunities(
         .load(&mut conn)
         .unwrap_or_default();
 
-    rows.into_iter()
-        .map(|r| OpportunitySummary {
-            id: r.id.unwrap_or(0),
-            strategy: r.strategy,
-            edge: f32_to_decimal(r.edge),
-            expected_profit: f32_to_decimal(r.expected_profit),
-            executed: r.executed != 0,
-            rejected_reason: r.rejected_reason,
-            detected_at: r.detected_at,
-        })
-        .collect()
+    rows.into_iter().map(opportunity_summary_from_row).collect()
+}
+
+/// Convert a stored opportunity row into its display summary.
+fn opportunity_summary_from_row(row: OpportunityRow) -> OpportunitySummary {
+    OpportunitySummary {
+        id: row.id.unwrap_or(0),
+        uid: row.uid,
+        strategy: row.strategy,
+        edge: f32_to_decimal(row.edge),
+        expected_profit: f32_to_decimal(row.expected_profit),
+        executed: row.executed != 0,
+        rejected_reason: row.rejected_reason,
+        detected_at: row.detected_at,
+        variant: row.variant,
+    }
 }
 
 /// Build a statistics summary from daily stats rows.
@@ -501,9 +808,32 @@ pub fn summary_from_rows(rows: &[DailyStatsRow]) -> StatsSummary {
     summary
 }
 
+/// Aggregate strategy daily rows into a summary per strategy.
+///
+/// Strategy rows do not track rejections or volume, so those fields stay zero.
+#[must_use]
+pub fn strategy_summaries_from_rows(
+    rows: &[StrategyDailyStatsRow],
+) -> HashMap<String, StatsSummary> {
+    let mut summaries: HashMap<String, StatsSummary> = HashMap::new();
+    for row in rows {
+        let summary = summaries.entry(row.strategy.clone()).or_default();
+        summary.opportunities_detected += i64::from(row.opportunities_detected);
+        summary.opportunities_executed += i64::from(row.opportunities_executed);
+        summary.trades_opened += i64::from(row.trades_opened);
+        summary.trades_closed += i64::from(row.trades_closed);
+        summary.profit_realized += f32_to_decimal(row.profit_realized);
+        summary.loss_realized += f32_to_decimal(row.loss_realized);
+        summary.win_count += i64::from(row.win_count);
+        summary.loss_count += i64::from(row.loss_count);
+    }
+    summaries
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::id::OpportunityId;
     use crate::domain::stats::TradeLeg;
     use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
     use rust_decimal_macros::dec;
@@ -533,6 +863,7 @@ mod tests {
 
     fn make_opportunity(strategy: &str, edge: Decimal, executed: bool) -> RecordedOpportunity {
         RecordedOpportunity {
+            uid: OpportunityId::new().to_string(),
             strategy: strategy.to_string(),
             market_ids: vec!["market-1".to_string(), "market-2".to_string()],
             edge,
@@ -543,12 +874,15 @@ mod tests {
             } else {
                 Some("risk_limit".to_string())
             },
+            variant: PRIMARY_VARIANT.to_string(),
+            content_hash: None,
         }
     }
 
     fn make_trade_open(opportunity_id: i32, strategy: &str, size: Decimal) -> TradeOpenEvent {
         TradeOpenEvent {
             opportunity_id,
+            opportunity_uid: format!("opp-{opportunity_id}"),
             strategy: strategy.to_string(),
             market_ids: vec!["market-1".to_string()],
             legs: vec![TradeLeg {
@@ -600,6 +934,62 @@ mod tests {
         assert_eq!(id2, id1 + 1);
     }
 
+    fn hashed(hash: u64, executed: bool) -> RecordedOpportunity {
+        RecordedOpportunity {
+            content_hash: Some(hash),
+            ..make_opportunity("single_condition", dec!(0.05), executed)
+        }
+    }
+
+    #[test]
+    fn opportunity_dedup_skips_repeats_within_window() {
+        let recorder =
+            SqliteRecorder::new(setup_test_db()).with_opportunity_dedup(Duration::from_secs(5));
+        let now = Instant::now();
+
+        let first = recorder.record_opportunity_at(&hashed(7, false), now);
+        let repeat =
+            recorder.record_opportunity_at(&hashed(7, false), now + Duration::from_secs(1));
+
+        assert!(first.is_some());
+        assert_eq!(repeat, first);
+        assert_eq!(recorder.get_today().opportunities_detected, 1);
+
+        let later = recorder.record_opportunity_at(&hashed(7, false), now + Duration::from_secs(5));
+        assert_ne!(later, first);
+        assert_eq!(recorder.get_today().opportunities_detected, 2);
+    }
+
+    #[test]
+    fn opportunity_dedup_records_other_outcomes_and_unhashed_events() {
+        let recorder =
+            SqliteRecorder::new(setup_test_db()).with_opportunity_dedup(Duration::from_secs(5));
+        let now = Instant::now();
+
+        let rejected = recorder.record_opportunity_at(&hashed(7, false), now);
+        let executed = recorder.record_opportunity_at(&hashed(7, true), now);
+        let other = recorder.record_opportunity_at(&hashed(8, false), now);
+        let unhashed = make_opportunity("single_condition", dec!(0.05), false);
+        recorder.record_opportunity_at(&unhashed, now);
+        recorder.record_opportunity_at(&unhashed, now);
+
+        assert_ne!(rejected, executed);
+        assert_ne!(rejected, other);
+        let summary = recorder.get_today();
+        assert_eq!(summary.opportunities_detected, 5);
+        assert_eq!(summary.opportunities_executed, 1);
+    }
+
+    #[test]
+    fn opportunity_dedup_is_disabled_by_default() {
+        let recorder = SqliteRecorder::new(setup_test_db());
+
+        recorder.record_opportunity(&hashed(7, false));
+        recorder.record_opportunity(&hashed(7, false));
+
+        assert_eq!(recorder.get_today().opportunities_detected, 2);
+    }
+
     #[test]
     fn record_trade_open_returns_id() {
         let pool = setup_test_db();
@@ -617,6 +1007,34 @@ mod tests {
         assert!(trade_id.unwrap() > 0);
     }
 
+    #[test]
+    fn detection_uid_is_stored_on_opportunity_and_trade() {
+        let pool = setup_test_db();
+        let recorder = SqliteRecorder::new(pool.clone());
+
+        let opportunity = make_opportunity("single_condition", dec!(0.05), true);
+        let opp_id = recorder.record_opportunity(&opportunity).unwrap();
+        let trade = TradeOpenEvent {
+            opportunity_uid: opportunity.uid.clone(),
+            ..make_trade_open(opp_id, "single_condition", dec!(100))
+        };
+        let trade_id = recorder.record_trade_open(&trade).unwrap();
+
+        let mut conn = pool.get().unwrap();
+        let opp_uid: Option<String> = opportunities::table
+            .filter(opportunities::id.eq(opp_id))
+            .select(opportunities::uid)
+            .first(&mut conn)
+            .unwrap();
+        let trade_row: TradeRow = trades::table
+            .filter(trades::id.eq(trade_id))
+            .first(&mut conn)
+            .unwrap();
+
+        assert_eq!(opp_uid.as_deref(), Some(opportunity.uid.as_str()));
+        assert_eq!(trade_row.opportunity_uid, opp_uid);
+    }
+
     #[test]
     fn record_trade_close_updates_trade() {
         let pool = setup_test_db();
@@ -680,6 +1098,21 @@ mod tests {
         assert_eq!(summary.opportunities_rejected, 1);
     }
 
+    #[test]
+    fn rejecting_executed_opportunity_moves_it_to_rejected() {
+        let pool = setup_test_db();
+        let recorder = SqliteRecorder::new(pool);
+
+        let opp = make_opportunity("single_condition", dec!(0.05), true);
+        let id = recorder.record_opportunity(&opp).unwrap();
+        recorder.record_opportunity_rejected(id, "opportunity_stale");
+
+        let summary = recorder.get_today();
+        assert_eq!(summary.opportunities_detected, 1);
+        assert_eq!(summary.opportunities_executed, 0);
+        assert_eq!(summary.opportunities_rejected, 1);
+    }
+
     #[test]
     fn record_trade_open_updates_volume_and_count() {
         let pool = setup_test_db();
@@ -832,6 +1265,69 @@ mod tests {
         assert_eq!(summary.profit_realized, dec!(125));
     }
 
+    #[test]
+    fn get_strategy_summary_groups_by_strategy() {
+        let pool = setup_test_db();
+
+        {
+            let mut conn = pool.get().unwrap();
+            let rows = [
+                StrategyDailyStatsRow {
+                    date: "2026-01-01".to_string(),
+                    strategy: "single_condition".to_string(),
+                    opportunities_detected: 10,
+                    trades_closed: 3,
+                    profit_realized: 40.0,
+                    win_count: 2,
+                    loss_count: 1,
+                    loss_realized: 15.0,
+                    ..Default::default()
+                },
+                StrategyDailyStatsRow {
+                    date: "2026-01-02".to_string(),
+                    strategy: "single_condition".to_string(),
+                    opportunities_detected: 5,
+                    trades_closed: 1,
+                    profit_realized: 10.0,
+                    win_count: 1,
+                    ..Default::default()
+                },
+                StrategyDailyStatsRow {
+                    date: "2026-01-02".to_string(),
+                    strategy: "combinatorial".to_string(),
+                    opportunities_detected: 2,
+                    ..Default::default()
+                },
+                StrategyDailyStatsRow {
+                    date: "2026-01-05".to_string(),
+                    strategy: "combinatorial".to_string(),
+                    opportunities_detected: 100,
+                    ..Default::default()
+                },
+            ];
+            for row in &rows {
+                diesel::insert_into(strategy_daily_stats::table)
+                    .values(row)
+                    .execute(&mut conn)
+                    .unwrap();
+            }
+        }
+
+        let recorder = SqliteRecorder::new(pool);
+        let from = NaiveDate::parse_from_str("2026-01-01", "%Y-%m-%d").unwrap();
+        let to = NaiveDate::parse_from_str("2026-01-02", "%Y-%m-%d").unwrap();
+
+        let summaries = recorder.get_strategy_summary(from, to);
+
+        assert_eq!(summaries.len(), 2);
+        let single = &summaries["single_condition"];
+        assert_eq!(single.opportunities_detected, 15);
+        assert_eq!(single.trades_closed, 4);
+        assert_eq!(single.win_count, 3);
+        assert_eq!(single.net_profit(), dec!(35));
+        assert_eq!(summaries["combinatorial"].opportunities_detected, 2);
+    }
+
     #[test]
     fn get_summary_returns_empty_for_no_data() {
         let pool = setup_test_db();
@@ -939,6 +1435,8 @@ mod tests {
                 detected_at: "2020-01-01T00:00:00Z".to_string(), // Old date
                 executed: 1,
                 rejected_reason: None,
+                variant: PRIMARY_VARIANT.to_string(),
+                uid: None,
             };
             diesel::insert_into(opportunities::table)
                 .values(&old_opp)
@@ -1038,12 +1536,15 @@ mod tests {
         // Record several opportunities
         for i in 0..5 {
             let opp = RecordedOpportunity {
+                uid: format!("opp-{i}"),
                 strategy: format!("strat_{}", i),
                 market_ids: vec!["m1".to_string()],
                 edge: dec!(0.01) * Decimal::from(i),
                 expected_profit: dec!(1.0),
                 executed: true,
                 rejected_reason: None,
+                variant: PRIMARY_VARIANT.to_string(),
+                content_hash: None,
             };
             recorder.record_opportunity(&opp);
         }
@@ -1066,6 +1567,145 @@ mod tests {
         assert!(recent.is_empty());
     }
 
+    // -------------------------------------------------------------------------
+    // Filtered opportunity query
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn query_opportunities_without_filter_returns_all_most_recent_first() {
+        let pool = setup_test_db();
+        let recorder = SqliteRecorder::new(pool);
+
+        recorder.record_opportunity(&make_opportunity("single_condition", dec!(0.02), true));
+        recorder.record_opportunity(&make_opportunity("rebalancing", dec!(0.08), false));
+
+        let found = recorder.query_opportunities(OpportunityFilter::default());
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].strategy, "rebalancing");
+        assert_eq!(found[1].strategy, "single_condition");
+    }
+
+    #[test]
+    fn query_opportunities_combines_filters() {
+        let pool = setup_test_db();
+        let recorder = SqliteRecorder::new(pool);
+
+        recorder.record_opportunity(&make_opportunity("rebalancing", dec!(0.08), false));
+        recorder.record_opportunity(&make_opportunity("rebalancing", dec!(0.03), false));
+        recorder.record_opportunity(&make_opportunity("rebalancing", dec!(0.09), true));
+        recorder.record_opportunity(&make_opportunity("single_condition", dec!(0.10), false));
+
+        let found = recorder.query_opportunities(OpportunityFilter {
+            strategy: Some("rebalancing".to_string()),
+            executed: Some(false),
+            min_edge: Some(dec!(0.05)),
+            ..Default::default()
+        });
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].strategy, "rebalancing");
+        assert!(!found[0].executed);
+        assert_eq!(found[0].rejected_reason.as_deref(), Some("risk_limit"));
+    }
+
+    #[test]
+    fn query_opportunities_filters_by_variant() {
+        let pool = setup_test_db();
+        let recorder = SqliteRecorder::new(pool);
+
+        recorder.record_opportunity(&make_opportunity("single_condition", dec!(0.05), true));
+        recorder.record_opportunity(&RecordedOpportunity {
+            variant: "candidate".to_string(),
+            ..make_opportunity("single_condition", dec!(0.02), false)
+        });
+
+        let found = recorder.query_opportunities(OpportunityFilter {
+            variant: Some("candidate".to_string()),
+            ..Default::default()
+        });
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].variant, "candidate");
+        assert!(found[0].edge < dec!(0.03));
+    }
+
+    #[test]
+    fn shadow_opportunities_do_not_update_daily_stats() {
+        let pool = setup_test_db();
+        let recorder = SqliteRecorder::new(pool);
+
+        recorder.record_opportunity(&make_opportunity("single_condition", dec!(0.05), true));
+        let shadow_id = recorder.record_opportunity(&RecordedOpportunity {
+            variant: "candidate".to_string(),
+            ..make_opportunity("single_condition", dec!(0.02), false)
+        });
+
+        let summary = recorder.get_today();
+
+        assert!(shadow_id.is_some());
+        assert_eq!(summary.opportunities_detected, 1);
+        assert_eq!(summary.opportunities_rejected, 0);
+    }
+
+    #[test]
+    fn query_opportunities_filters_by_date_range() {
+        let pool = setup_test_db();
+        let recorder = SqliteRecorder::new(pool.clone());
+        recorder.record_opportunity(&make_opportunity("single_condition", dec!(0.05), true));
+
+        {
+            let mut conn = pool.get().unwrap();
+            diesel::insert_into(opportunities::table)
+                .values(&NewOpportunityRow {
+                    strategy: "single_condition".to_string(),
+                    market_ids: "[]".to_string(),
+                    edge: 0.05,
+                    expected_profit: 5.0,
+                    detected_at: "2026-01-10T12:00:00+00:00".to_string(),
+                    executed: 1,
+                    rejected_reason: None,
+                    variant: PRIMARY_VARIANT.to_string(),
+                    uid: None,
+                })
+                .execute(&mut conn)
+                .unwrap();
+        }
+
+        let day = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+        let found = recorder.query_opportunities(OpportunityFilter {
+            from: Some(day),
+            to: Some(day),
+            ..Default::default()
+        });
+
+        assert_eq!(found.len(), 1);
+        assert!(found[0].detected_at.starts_with("2026-01-10"));
+
+        let found = recorder.query_opportunities(OpportunityFilter {
+            from: NaiveDate::from_ymd_opt(2026, 1, 11),
+            ..Default::default()
+        });
+        assert_eq!(found.len(), 1);
+        assert!(!found[0].detected_at.starts_with("2026-01-10"));
+    }
+
+    #[test]
+    fn query_opportunities_respects_limit() {
+        let pool = setup_test_db();
+        let recorder = SqliteRecorder::new(pool);
+        for _ in 0..5 {
+            recorder.record_opportunity(&make_opportunity("single_condition", dec!(0.05), true));
+        }
+
+        let found = recorder.query_opportunities(OpportunityFilter {
+            limit: Some(2),
+            ..Default::default()
+        });
+
+        assert_eq!(found.len(), 2);
+    }
+
     // -------------------------------------------------------------------------
     // Edge cases
     // -------------------------------------------------------------------------
@@ -1076,12 +1716,15 @@ mod tests {
         let recorder = SqliteRecorder::new(pool);
 
         let opp = RecordedOpportunity {
+            uid: "opp-test".to_string(),
             strategy: "test".to_string(),
             market_ids: vec![],
             edge: dec!(0.05),
             expected_profit: dec!(5.0),
             executed: true,
             rejected_reason: None,
+            variant: PRIMARY_VARIANT.to_string(),
+            content_hash: None,
         };
 
         let id = recorder.record_opportunity(&opp);
@@ -4,23 +4,28 @@
 //! traits for CLI commands and status displays.
 
 use chrono::{Duration, NaiveDate, Utc};
+use diesel::dsl::max;
 use diesel::prelude::*;
 use diesel::r2d2::{ConnectionManager, Pool};
 
 use crate::adapter::outbound::sqlite::database::model::{
-    DailyStatsRow, OpportunityRow, StrategyDailyStatsRow, TradeRow,
+    DailyStatsRow, LatencySnapshotRow, MarketScoreRow, OpportunityRow, StrategyDailyStatsRow,
+    TradeRow, TradingStateRow,
 };
 use crate::adapter::outbound::sqlite::database::schema::{
-    daily_stats, opportunities, strategy_daily_stats, trades,
+    daily_stats, latency_snapshot, market_scores, opportunities, strategy_daily_stats, trades,
+    trading_state,
 };
 use crate::adapter::outbound::sqlite::recorder::{
     export_daily_csv as export_csv_impl, f32_to_decimal, SqliteRecorder,
 };
-use crate::domain::stats::StatsSummary;
+use crate::domain::id::MarketId;
+use crate::domain::score::{MarketScore, RankedMarket, ScoreFactors};
+use crate::domain::stats::{LatencyPercentiles, StatsSummary};
 use crate::error::{ConfigError, Error, Result};
 use crate::port::outbound::report::{
-    DailyStatsRecord, RecentActivity, StatisticsReportReader, StatusReportReader, StatusSnapshot,
-    StrategyDailyStatsRecord,
+    DailyStatsRecord, OpenTradeRecord, RecentActivity, StatisticsReportReader, StatusReportReader,
+    StatusSnapshot, StrategyDailyStatsRecord,
 };
 
 /// SQLite report reader for status and statistics queries.
@@ -136,6 +141,66 @@ impl StatusReportReader for SqliteReportReader {
         });
         recent_activity.truncate(5);
 
+        let trading_state = trading_state::table
+            .first::<TradingStateRow>(&mut conn)
+            .ok()
+            .and_then(|row| serde_json::from_str(&row.state).ok());
+
+        let last_detected: Option<String> = opportunities::table
+            .select(max(opportunities::detected_at))
+            .first(&mut conn)
+            .unwrap_or_default();
+        let last_opened: Option<String> = trades::table
+            .select(max(trades::opened_at))
+            .first(&mut conn)
+            .unwrap_or_default();
+        let last_closed: Option<String> = trades::table
+            .select(max(trades::closed_at))
+            .first(&mut conn)
+            .unwrap_or_default();
+        let last_event_at = [last_detected, last_opened, last_closed]
+            .into_iter()
+            .flatten()
+            .max();
+
+        let latency = latency_snapshot::table
+            .first::<LatencySnapshotRow>(&mut conn)
+            .ok()
+            .map(|row| {
+                let micros = |us: i64| std::time::Duration::from_micros(us.max(0) as u64);
+                LatencyPercentiles {
+                    p50: micros(row.p50_us),
+                    p95: micros(row.p95_us),
+                    p99: micros(row.p99_us),
+                    samples: row.sample_count.max(0) as usize,
+                }
+            });
+
+        let top_markets = market_scores::table
+            .order(market_scores::rank.asc())
+            .load::<MarketScoreRow>(&mut conn)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|row| {
+                let factors = ScoreFactors::new(
+                    f64::from(row.liquidity),
+                    f64::from(row.spread),
+                    0.0,
+                    0.0,
+                    f64::from(row.activity),
+                )
+                .with_time_to_close(f64::from(row.time_to_close));
+                RankedMarket {
+                    question: row.question,
+                    score: MarketScore::new(
+                        MarketId::from(row.market_id),
+                        factors,
+                        f64::from(row.composite),
+                    ),
+                }
+            })
+            .collect();
+
         Ok(StatusSnapshot {
             today: today_row.map(DailyStatsRecord::from),
             week_rows: week_rows.into_iter().map(DailyStatsRecord::from).collect(),
@@ -143,8 +208,38 @@ impl StatusReportReader for SqliteReportReader {
             distinct_markets,
             current_exposure,
             recent_activity,
+            trading_state,
+            last_event_at,
+            latency,
+            top_markets,
         })
     }
+
+    fn load_open_trades(&self) -> Result<Vec<OpenTradeRecord>> {
+        let pool = self.connect()?;
+        let mut conn = pool
+            .get()
+            .map_err(|error| Error::Config(ConfigError::Other(error.to_string())))?;
+
+        let rows: Vec<TradeRow> = trades::table
+            .filter(trades::status.eq("open"))
+            .order(trades::opened_at.asc())
+            .load(&mut conn)
+            .map_err(|error| Error::Database(error.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| OpenTradeRecord {
+                id: row.id.unwrap_or_default(),
+                strategy: row.strategy,
+                market_ids: serde_json::from_str(&row.market_ids).unwrap_or_default(),
+                legs: serde_json::from_str(&row.legs).unwrap_or_default(),
+                size: row.size,
+                expected_profit: row.expected_profit,
+                opened_at: row.opened_at,
+            })
+            .collect())
+    }
 }
 
 impl StatisticsReportReader for SqliteReportReader {
@@ -276,6 +371,7 @@ impl From<StrategyDailyStatsRow> for StrategyDailyStatsRecord {
             profit_realized: row.profit_realized,
             win_count: row.win_count,
             loss_count: row.loss_count,
+            loss_realized: row.loss_realized,
         }
     }
 }
@@ -358,6 +454,77 @@ mod tests {
         assert_eq!(snapshot.distinct_markets, 0);
         assert!((snapshot.current_exposure - 0.0).abs() < 0.01);
         assert!(snapshot.recent_activity.is_empty());
+        assert!(snapshot.trading_state.is_none());
+        assert!(snapshot.last_event_at.is_none());
+        assert!(snapshot.latency.is_none());
+        assert!(snapshot.top_markets.is_empty());
+    }
+
+    #[test]
+    fn load_status_includes_persisted_halt() {
+        use crate::adapter::outbound::sqlite::store::SqliteTradingStateStore;
+        use crate::domain::trading::TradingState;
+        use crate::port::outbound::store::TradingStateStore;
+
+        let (db_url, pool) = setup_test_db();
+        SqliteTradingStateStore::new(pool)
+            .save(&TradingState::halted("maintenance"))
+            .unwrap();
+
+        let snapshot = SqliteReportReader::new(&db_url).load_status().unwrap();
+
+        assert_eq!(
+            snapshot.trading_state,
+            Some(TradingState::halted("maintenance"))
+        );
+    }
+
+    #[test]
+    fn load_status_includes_latest_latency_percentiles() {
+        let (db_url, pool) = setup_test_db();
+        let recorder = SqliteRecorder::new(pool);
+        let percentiles = |p50_us, samples| LatencyPercentiles {
+            p50: std::time::Duration::from_micros(p50_us),
+            p95: std::time::Duration::from_micros(4_000),
+            p99: std::time::Duration::from_micros(9_000),
+            samples,
+        };
+        recorder.record_latency_percentiles(&percentiles(2_000, 100));
+        recorder.record_latency_percentiles(&percentiles(1_500, 250));
+
+        let snapshot = SqliteReportReader::new(&db_url).load_status().unwrap();
+
+        assert_eq!(snapshot.latency, Some(percentiles(1_500, 250)));
+    }
+
+    #[test]
+    fn load_status_includes_latest_market_ranking() {
+        let (db_url, pool) = setup_test_db();
+        let recorder = SqliteRecorder::new(pool);
+        let ranked = |id: &str, composite: f64| RankedMarket {
+            question: format!("Question {id}?"),
+            score: MarketScore::new(
+                MarketId::from(id),
+                ScoreFactors::new(0.5, 0.75, 0.0, 0.0, 1.0).with_time_to_close(0.25),
+                composite,
+            ),
+        };
+        recorder.record_market_scores(&[ranked("stale", 0.9)]);
+        recorder.record_market_scores(&[ranked("first", 0.8), ranked("second", 0.6)]);
+
+        let snapshot = SqliteReportReader::new(&db_url).load_status().unwrap();
+
+        let ids: Vec<&str> = snapshot
+            .top_markets
+            .iter()
+            .map(|market| market.score.market_id().as_str())
+            .collect();
+        assert_eq!(ids, ["first", "second"]);
+        let top = &snapshot.top_markets[0];
+        assert_eq!(top.question, "Question first?");
+        assert!((top.score.composite() - 0.8).abs() < 1e-6);
+        assert!((top.score.factors().spread - 0.75).abs() < 1e-6);
+        assert!((top.score.factors().time_to_close - 0.25).abs() < 1e-6);
     }
 
     #[test]
@@ -434,6 +601,8 @@ mod tests {
                 detected_at: Utc::now().to_rfc3339(),
                 executed: 1,
                 rejected_reason: None,
+                variant: "primary".to_string(),
+                uid: None,
             };
             diesel::insert_into(opportunities::table)
                 .values(&opp)
@@ -451,6 +620,7 @@ mod tests {
                     expected_profit: 5.0,
                     status: "open".to_string(),
                     opened_at: Utc::now().to_rfc3339(),
+                    opportunity_uid: None,
                 };
                 diesel::insert_into(trades::table)
                     .values(&trade)
@@ -480,6 +650,8 @@ mod tests {
                 detected_at: Utc::now().to_rfc3339(),
                 executed: 1,
                 rejected_reason: None,
+                variant: "primary".to_string(),
+                uid: None,
             };
             diesel::insert_into(opportunities::table)
                 .values(&opp)
@@ -497,6 +669,7 @@ mod tests {
                     expected_profit: 5.0,
                     status: "open".to_string(),
                     opened_at: Utc::now().to_rfc3339(),
+                    opportunity_uid: None,
                 };
                 diesel::insert_into(trades::table)
                     .values(&trade)
@@ -526,6 +699,8 @@ mod tests {
                 detected_at: Utc::now().to_rfc3339(),
                 executed: 1,
                 rejected_reason: None,
+                variant: "primary".to_string(),
+                uid: None,
             };
             diesel::insert_into(opportunities::table)
                 .values(&opp)
@@ -542,6 +717,7 @@ mod tests {
                 expected_profit: 5.0,
                 status: "closed".to_string(),
                 opened_at: Utc::now().to_rfc3339(),
+                opportunity_uid: None,
             };
             diesel::insert_into(trades::table)
                 .values(&trade)
@@ -566,6 +742,8 @@ mod tests {
                 detected_at: Utc::now().to_rfc3339(),
                 executed: 0,
                 rejected_reason: Some("risk_limit".to_string()),
+                variant: "primary".to_string(),
+                uid: None,
             };
             diesel::insert_into(opportunities::table)
                 .values(&rejected_opp)
@@ -579,6 +757,58 @@ mod tests {
         assert!(!snapshot.recent_activity.is_empty());
     }
 
+    #[test]
+    fn load_status_reports_latest_event_time() {
+        let (db_url, pool) = setup_test_db();
+        {
+            let mut conn = pool.get().unwrap();
+
+            let opp = NewOpportunityRow {
+                strategy: "test".to_string(),
+                market_ids: "[\"m1\"]".to_string(),
+                edge: 0.05,
+                expected_profit: 5.0,
+                detected_at: "2024-01-01T10:00:00+00:00".to_string(),
+                executed: 1,
+                rejected_reason: None,
+                variant: "primary".to_string(),
+                uid: None,
+            };
+            diesel::insert_into(opportunities::table)
+                .values(&opp)
+                .execute(&mut conn)
+                .unwrap();
+
+            let trade = NewTradeRow {
+                opportunity_id: 1,
+                strategy: "test".to_string(),
+                market_ids: "[\"m1\"]".to_string(),
+                legs: "[]".to_string(),
+                size: 100.0,
+                expected_profit: 5.0,
+                status: "closed".to_string(),
+                opened_at: "2024-01-01T10:00:01+00:00".to_string(),
+                opportunity_uid: None,
+            };
+            diesel::insert_into(trades::table)
+                .values(&trade)
+                .execute(&mut conn)
+                .unwrap();
+
+            diesel::update(trades::table.filter(trades::id.eq(1)))
+                .set(trades::closed_at.eq(Some("2024-01-01T12:30:00+00:00".to_string())))
+                .execute(&mut conn)
+                .unwrap();
+        }
+
+        let snapshot = SqliteReportReader::new(&db_url).load_status().unwrap();
+
+        assert_eq!(
+            snapshot.last_event_at.as_deref(),
+            Some("2024-01-01T12:30:00+00:00")
+        );
+    }
+
     // -------------------------------------------------------------------------
     // StatisticsReportReader tests
     // -------------------------------------------------------------------------
@@ -667,6 +897,8 @@ mod tests {
                 detected_at: Utc::now().to_rfc3339(),
                 executed: 1,
                 rejected_reason: None,
+                variant: "primary".to_string(),
+                uid: None,
             };
             diesel::insert_into(opportunities::table)
                 .values(&opp)
@@ -684,6 +916,7 @@ mod tests {
                     expected_profit: 5.0,
                     status: status.to_string(),
                     opened_at: Utc::now().to_rfc3339(),
+                    opportunity_uid: None,
                 };
                 diesel::insert_into(trades::table)
                     .values(&trade)
@@ -698,6 +931,61 @@ mod tests {
         assert_eq!(count, 2);
     }
 
+    #[test]
+    fn load_open_trades_parses_legs_and_skips_closed() {
+        let (db_url, pool) = setup_test_db();
+        {
+            let mut conn = pool.get().unwrap();
+
+            let opp = NewOpportunityRow {
+                strategy: "test".to_string(),
+                market_ids: "[\"m1\"]".to_string(),
+                edge: 0.05,
+                expected_profit: 5.0,
+                detected_at: Utc::now().to_rfc3339(),
+                executed: 1,
+                rejected_reason: None,
+                variant: "primary".to_string(),
+                uid: None,
+            };
+            diesel::insert_into(opportunities::table)
+                .values(&opp)
+                .execute(&mut conn)
+                .unwrap();
+
+            for (status, opened_at) in [
+                ("open", "2024-01-01T10:00:00+00:00"),
+                ("closed", "2024-01-01T09:00:00+00:00"),
+            ] {
+                let trade = NewTradeRow {
+                    opportunity_id: 1,
+                    strategy: "single_condition".to_string(),
+                    market_ids: "[\"m1\"]".to_string(),
+                    legs: r#"[{"token_id":"yes","side":"buy","price":"0.45","size":"10"},{"token_id":"no","side":"buy","price":"0.50","size":"10"}]"#.to_string(),
+                    size: 10.0,
+                    expected_profit: 0.5,
+                    status: status.to_string(),
+                    opened_at: opened_at.to_string(),
+                    opportunity_uid: None,
+                };
+                diesel::insert_into(trades::table)
+                    .values(&trade)
+                    .execute(&mut conn)
+                    .unwrap();
+            }
+        }
+
+        let reader = SqliteReportReader::new(&db_url);
+        let trades = reader.load_open_trades().unwrap();
+
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].strategy, "single_condition");
+        assert_eq!(trades[0].market_ids, vec!["m1".to_string()]);
+        assert_eq!(trades[0].legs.len(), 2);
+        assert_eq!(trades[0].legs[0].token_id, "yes");
+        assert_eq!(trades[0].legs[0].price, rust_decimal_macros::dec!(0.45));
+    }
+
     #[test]
     fn load_daily_rows_returns_ordered_records() {
         let (db_url, pool) = setup_test_db();
@@ -772,6 +1060,8 @@ mod tests {
                 detected_at: "2020-01-01T00:00:00Z".to_string(),
                 executed: 1,
                 rejected_reason: None,
+                variant: "primary".to_string(),
+                uid: None,
             };
             diesel::insert_into(opportunities::table)
                 .values(&old_opp)
@@ -787,6 +1077,8 @@ mod tests {
                 detected_at: Utc::now().to_rfc3339(),
                 executed: 1,
                 rejected_reason: None,
+                variant: "primary".to_string(),
+                uid: None,
             };
             diesel::insert_into(opportunities::table)
                 .values(&recent_opp)
@@ -906,6 +1198,7 @@ mod tests {
             profit_realized: 250.0,
             win_count: 12,
             loss_count: 6,
+            loss_realized: 30.0,
         };
 
         let record = StrategyDailyStatsRecord::from(row);
@@ -948,6 +1241,8 @@ mod tests {
                 detected_at: Utc::now().to_rfc3339(),
                 executed: 1,
                 rejected_reason: None,
+                variant: "primary".to_string(),
+                uid: None,
             };
             diesel::insert_into(opportunities::table)
                 .values(&opp)
@@ -971,6 +1266,7 @@ mod tests {
                     expected_profit: 5.0,
                     status: "open".to_string(),
                     opened_at: Utc::now().to_rfc3339(),
+                    opportunity_uid: None,
                 };
                 diesel::insert_into(trades::table)
                     .values(&trade)
@@ -1,16 +1,29 @@
-//! SQLite relation store implementation.
+//! SQLite store implementations.
 //!
-//! Provides persistent storage for market relations using SQLite and Diesel ORM.
+//! Provides persistent storage for market relations, clusters, the trading
+//! state, and tracked positions using SQLite and Diesel ORM.
+
+use std::str::FromStr;
 
 use chrono::{DateTime, Utc};
 use diesel::prelude::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 
 use crate::adapter::outbound::sqlite::database::connection::DbPool;
-use crate::adapter::outbound::sqlite::database::model::RelationRow;
-use crate::adapter::outbound::sqlite::database::schema::relations;
-use crate::domain::{id::MarketId, id::RelationId, relation::Relation, relation::RelationKind};
+use crate::adapter::outbound::sqlite::database::model::{
+    ClusterRow, PositionRow, RelationRow, TradingStateRow,
+};
+use crate::adapter::outbound::sqlite::database::schema::{
+    clusters, positions, relations, trading_state,
+};
+use crate::domain::{
+    cluster::Cluster, id::ClusterId, id::MarketId, id::PositionId, id::RelationId, id::TokenId,
+    position::Position, position::PositionLeg, position::PositionStatus, relation::Relation,
+    relation::RelationKind, trading::TradingState,
+};
 use crate::error::{Error, Result};
-use crate::port::outbound::store::RelationStore;
+use crate::port::outbound::store::{ClusterStore, PositionStore, RelationStore, TradingStateStore};
 
 /// SQLite-backed relation store.
 ///
@@ -185,6 +198,363 @@ impl SqliteRelationStore {
     }
 }
 
+/// SQLite-backed cluster store.
+///
+/// Implements the [`ClusterStore`] trait. A cluster's relations are saved to
+/// the relations table alongside it, and its solver constraints are rebuilt
+/// from those relations on load rather than stored.
+pub struct SqliteClusterStore {
+    /// Database connection pool.
+    pool: DbPool,
+}
+
+impl SqliteClusterStore {
+    /// Create a new SQLite cluster store with the given connection pool.
+    #[must_use]
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    fn to_row(cluster: &Cluster) -> Result<ClusterRow> {
+        let relation_ids: Vec<&RelationId> = cluster.relations.iter().map(|r| &r.id).collect();
+        Ok(ClusterRow {
+            id: cluster.id.to_string(),
+            market_ids: serde_json::to_string(&cluster.markets)
+                .map_err(|e| Error::Parse(e.to_string()))?,
+            relation_ids: serde_json::to_string(&relation_ids)
+                .map_err(|e| Error::Parse(e.to_string()))?,
+            // Constraints are derived from relations when the cluster is loaded.
+            constraints_json: "[]".to_string(),
+            updated_at: cluster.updated_at.to_rfc3339(),
+        })
+    }
+
+    fn from_row(row: ClusterRow, conn: &mut SqliteConnection) -> Result<Cluster> {
+        let relation_ids: Vec<String> =
+            serde_json::from_str(&row.relation_ids).map_err(|e| Error::Parse(e.to_string()))?;
+        let updated_at: DateTime<Utc> = DateTime::parse_from_rfc3339(&row.updated_at)
+            .map_err(|e| Error::Parse(e.to_string()))?
+            .with_timezone(&Utc);
+
+        let mut rows: Vec<RelationRow> = relations::table
+            .filter(relations::id.eq_any(&relation_ids))
+            .load(conn)
+            .map_err(|e| Error::Database(e.to_string()))?;
+        rows.sort_by_key(|r| relation_ids.iter().position(|id| *id == r.id));
+        let relations = rows
+            .into_iter()
+            .map(SqliteRelationStore::from_row)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Cluster::restore(
+            ClusterId::from(row.id),
+            relations,
+            updated_at,
+        ))
+    }
+}
+
+impl ClusterStore for SqliteClusterStore {
+    async fn save(&self, cluster: &Cluster) -> Result<()> {
+        let row = Self::to_row(cluster)?;
+        let relation_rows = cluster
+            .relations
+            .iter()
+            .map(SqliteRelationStore::to_row)
+            .collect::<Result<Vec<_>>>()?;
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| Error::Connection(e.to_string()))?;
+
+        conn.transaction(|conn| {
+            for relation_row in &relation_rows {
+                diesel::replace_into(relations::table)
+                    .values(relation_row)
+                    .execute(conn)?;
+            }
+            diesel::replace_into(clusters::table)
+                .values(&row)
+                .execute(conn)
+        })
+        .map_err(|e: diesel::result::Error| Error::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn get(&self, id: &ClusterId) -> Result<Option<Cluster>> {
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| Error::Connection(e.to_string()))?;
+
+        let row: Option<ClusterRow> = clusters::table
+            .find(id.to_string())
+            .first(&mut conn)
+            .optional()
+            .map_err(|e| Error::Database(e.to_string()))?;
+
+        row.map(|row| Self::from_row(row, &mut conn)).transpose()
+    }
+
+    async fn delete(&self, id: &ClusterId) -> Result<bool> {
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| Error::Connection(e.to_string()))?;
+
+        let deleted = diesel::delete(clusters::table.find(id.to_string()))
+            .execute(&mut conn)
+            .map_err(|e| Error::Database(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+
+    async fn list(&self) -> Result<Vec<Cluster>> {
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| Error::Connection(e.to_string()))?;
+
+        let rows: Vec<ClusterRow> = clusters::table
+            .load(&mut conn)
+            .map_err(|e| Error::Database(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|row| Self::from_row(row, &mut conn))
+            .collect()
+    }
+}
+
+/// Primary key of the single trading state row.
+const TRADING_STATE_ROW_ID: i32 = 1;
+
+/// SQLite-backed trading state store.
+///
+/// Implements the [`TradingStateStore`] trait, keeping the current trading
+/// state in a single row.
+pub struct SqliteTradingStateStore {
+    /// Database connection pool.
+    pool: DbPool,
+}
+
+impl SqliteTradingStateStore {
+    /// Create a new SQLite trading state store with the given connection pool.
+    #[must_use]
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl TradingStateStore for SqliteTradingStateStore {
+    fn load(&self) -> Result<Option<TradingState>> {
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| Error::Connection(e.to_string()))?;
+
+        let row: Option<TradingStateRow> = trading_state::table
+            .find(TRADING_STATE_ROW_ID)
+            .first(&mut conn)
+            .optional()
+            .map_err(|e| Error::Database(e.to_string()))?;
+
+        row.map(|row| serde_json::from_str(&row.state).map_err(|e| Error::Parse(e.to_string())))
+            .transpose()
+    }
+
+    fn save(&self, state: &TradingState) -> Result<()> {
+        let row = TradingStateRow {
+            id: TRADING_STATE_ROW_ID,
+            state: serde_json::to_string(state).map_err(|e| Error::Parse(e.to_string()))?,
+            updated_at: Utc::now().to_rfc3339(),
+        };
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| Error::Connection(e.to_string()))?;
+
+        diesel::replace_into(trading_state::table)
+            .values(&row)
+            .execute(&mut conn)
+            .map_err(|e| Error::Database(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Status stored for open positions.
+const STATUS_OPEN: &str = "open";
+/// Status stored for partially filled positions.
+const STATUS_PARTIAL_FILL: &str = "partial_fill";
+/// Status stored for closed positions.
+const STATUS_CLOSED: &str = "closed";
+
+/// JSON form of a position leg in the `positions.legs` column.
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredLeg {
+    token_id: String,
+    size: Decimal,
+    entry_price: Decimal,
+}
+
+/// SQLite-backed position store.
+///
+/// Implements the [`PositionStore`] trait, keeping one row per position that
+/// is rewritten whenever the position changes status.
+pub struct SqlitePositionStore {
+    /// Database connection pool.
+    pool: DbPool,
+}
+
+impl SqlitePositionStore {
+    /// Create a new SQLite position store with the given connection pool.
+    #[must_use]
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    fn to_row(position: &Position) -> Result<PositionRow> {
+        let legs: Vec<StoredLeg> = position
+            .legs()
+            .iter()
+            .map(|leg| StoredLeg {
+                token_id: leg.token_id().to_string(),
+                size: leg.size(),
+                entry_price: leg.entry_price(),
+            })
+            .collect();
+        let (status, filled, missing, realized_pnl) = match position.status() {
+            PositionStatus::Open => (STATUS_OPEN, None, None, None),
+            PositionStatus::PartialFill { filled, missing } => (
+                STATUS_PARTIAL_FILL,
+                Some(Self::token_ids_json(filled)?),
+                Some(Self::token_ids_json(missing)?),
+                None,
+            ),
+            PositionStatus::Closed { pnl } => (STATUS_CLOSED, None, None, Some(pnl.to_string())),
+        };
+
+        Ok(PositionRow {
+            id: i32::try_from(position.id().value())
+                .map_err(|e| Error::Database(format!("position id out of range: {e}")))?,
+            market_id: position.market_id().to_string(),
+            legs: serde_json::to_string(&legs).map_err(|e| Error::Parse(e.to_string()))?,
+            entry_cost: position.entry_cost().to_string(),
+            guaranteed_payout: position.guaranteed_payout().to_string(),
+            status: status.to_string(),
+            filled,
+            missing,
+            realized_pnl,
+            trade_id: position.trade_id(),
+            held_to_settlement: i32::from(position.is_held_to_settlement()),
+            opened_at: position.opened_at().to_rfc3339(),
+            updated_at: Utc::now().to_rfc3339(),
+        })
+    }
+
+    fn from_row(row: PositionRow) -> Result<Position> {
+        let legs: Vec<StoredLeg> =
+            serde_json::from_str(&row.legs).map_err(|e| Error::Parse(e.to_string()))?;
+        let legs = legs
+            .into_iter()
+            .map(|leg| PositionLeg::new(TokenId::from(leg.token_id), leg.size, leg.entry_price))
+            .collect();
+        let status = match row.status.as_str() {
+            STATUS_OPEN => PositionStatus::Open,
+            STATUS_PARTIAL_FILL => PositionStatus::PartialFill {
+                filled: Self::parse_token_ids(row.filled.as_deref())?,
+                missing: Self::parse_token_ids(row.missing.as_deref())?,
+            },
+            STATUS_CLOSED => PositionStatus::Closed {
+                pnl: Self::parse_decimal(row.realized_pnl.as_deref().unwrap_or("0"))?,
+            },
+            other => return Err(Error::Parse(format!("unknown position status: {other}"))),
+        };
+        let opened_at = DateTime::parse_from_rfc3339(&row.opened_at)
+            .map_err(|e| Error::Parse(e.to_string()))?
+            .with_timezone(&Utc);
+
+        let mut position = Position::new(
+            PositionId::new(u64::try_from(row.id).unwrap_or_default()),
+            MarketId::from(row.market_id),
+            legs,
+            Self::parse_decimal(&row.entry_cost)?,
+            Self::parse_decimal(&row.guaranteed_payout)?,
+            opened_at,
+            status,
+        );
+        if let Some(trade_id) = row.trade_id {
+            position = position.with_trade_id(trade_id);
+        }
+        if row.held_to_settlement != 0 {
+            position = position.with_held_to_settlement();
+        }
+        Ok(position)
+    }
+
+    fn token_ids_json(token_ids: &[TokenId]) -> Result<String> {
+        let ids: Vec<&str> = token_ids.iter().map(TokenId::as_str).collect();
+        serde_json::to_string(&ids).map_err(|e| Error::Parse(e.to_string()))
+    }
+
+    fn parse_token_ids(json: Option<&str>) -> Result<Vec<TokenId>> {
+        let ids: Vec<String> =
+            serde_json::from_str(json.unwrap_or("[]")).map_err(|e| Error::Parse(e.to_string()))?;
+        Ok(ids.into_iter().map(TokenId::from).collect())
+    }
+
+    fn parse_decimal(value: &str) -> Result<Decimal> {
+        Decimal::from_str(value).map_err(|e| Error::Parse(e.to_string()))
+    }
+}
+
+impl PositionStore for SqlitePositionStore {
+    fn save(&self, position: &Position) -> Result<()> {
+        let row = Self::to_row(position)?;
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| Error::Connection(e.to_string()))?;
+
+        diesel::replace_into(positions::table)
+            .values(&row)
+            .execute(&mut conn)
+            .map_err(|e| Error::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    fn load_open(&self) -> Result<Vec<Position>> {
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| Error::Connection(e.to_string()))?;
+
+        let rows: Vec<PositionRow> = positions::table
+            .filter(positions::status.ne(STATUS_CLOSED))
+            .order(positions::id.asc())
+            .load(&mut conn)
+            .map_err(|e| Error::Database(e.to_string()))?;
+
+        rows.into_iter().map(Self::from_row).collect()
+    }
+
+    fn last_id(&self) -> Result<u64> {
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| Error::Connection(e.to_string()))?;
+
+        let max: Option<i32> = positions::table
+            .select(diesel::dsl::max(positions::id))
+            .first(&mut conn)
+            .map_err(|e| Error::Database(e.to_string()))?;
+
+        Ok(max.map_or(0, |id| u64::try_from(id).unwrap_or_default()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -689,4 +1059,171 @@ mod tests {
         let pruned = store.prune_expired().await.unwrap();
         assert_eq!(pruned, 1);
     }
+
+    // -------------------------------------------------------------------------
+    // Clusters
+    // -------------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn cluster_roundtrip_restores_relations() {
+        let store = SqliteClusterStore::new(setup_test_db());
+        let cluster = Cluster::from_relations(vec![
+            Relation::new(
+                RelationKind::Implies {
+                    if_yes: market("a"),
+                    then_yes: market("b"),
+                },
+                0.9,
+                "implies".to_string(),
+            ),
+            Relation::new(
+                RelationKind::MutuallyExclusive {
+                    markets: vec![market("b"), market("c")],
+                },
+                0.8,
+                "exclusive".to_string(),
+            ),
+        ]);
+
+        store.save(&cluster).await.unwrap();
+        let loaded = store.get(&cluster.id).await.unwrap().unwrap();
+
+        assert_eq!(loaded.id, cluster.id);
+        assert_eq!(loaded.markets, cluster.markets);
+        assert_eq!(loaded.relations, cluster.relations);
+        assert_eq!(loaded.constraint_count(), cluster.constraint_count());
+        assert!((loaded.updated_at - cluster.updated_at).num_seconds().abs() < 1);
+    }
+
+    #[tokio::test]
+    async fn cluster_list_and_delete() {
+        let store = SqliteClusterStore::new(setup_test_db());
+        let cluster = Cluster::from_relations(vec![Relation::new(
+            RelationKind::MutuallyExclusive {
+                markets: vec![market("m1"), market("m2")],
+            },
+            0.9,
+            "test".to_string(),
+        )]);
+
+        store.save(&cluster).await.unwrap();
+        assert_eq!(store.list().await.unwrap().len(), 1);
+
+        assert!(store.delete(&cluster.id).await.unwrap());
+        assert!(store.list().await.unwrap().is_empty());
+        assert!(store.get(&cluster.id).await.unwrap().is_none());
+    }
+
+    // -------------------------------------------------------------------------
+    // Trading state
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn trading_state_is_none_until_saved() {
+        let store = SqliteTradingStateStore::new(setup_test_db());
+        assert!(store.load().unwrap().is_none());
+    }
+
+    #[test]
+    fn trading_state_save_replaces_previous() {
+        let store = SqliteTradingStateStore::new(setup_test_db());
+
+        store.save(&TradingState::halted("maintenance")).unwrap();
+        assert_eq!(
+            store.load().unwrap(),
+            Some(TradingState::halted("maintenance"))
+        );
+
+        store.save(&TradingState::Active).unwrap();
+        assert_eq!(store.load().unwrap(), Some(TradingState::Active));
+    }
+
+    // -------------------------------------------------------------------------
+    // Positions
+    // -------------------------------------------------------------------------
+
+    fn open_position(id: u64) -> Position {
+        Position::new(
+            PositionId::new(id),
+            market("m1"),
+            vec![
+                PositionLeg::new(TokenId::from("yes"), dec!(10), dec!(0.45)),
+                PositionLeg::new(TokenId::from("no"), dec!(10), dec!(0.50)),
+            ],
+            dec!(9.50),
+            dec!(10),
+            DateTime::parse_from_rfc3339("2024-01-01T10:00:00+00:00")
+                .unwrap()
+                .with_timezone(&Utc),
+            PositionStatus::Open,
+        )
+        .with_trade_id(7)
+        .with_held_to_settlement()
+    }
+
+    #[test]
+    fn position_roundtrip_preserves_every_field() {
+        let store = SqlitePositionStore::new(setup_test_db());
+        let position = open_position(1);
+
+        store.save(&position).unwrap();
+        let loaded = store.load_open().unwrap();
+
+        assert_eq!(loaded.len(), 1);
+        let loaded = &loaded[0];
+        assert_eq!(loaded.id(), position.id());
+        assert_eq!(loaded.market_id(), position.market_id());
+        assert_eq!(loaded.entry_cost(), dec!(9.50));
+        assert_eq!(loaded.guaranteed_payout(), dec!(10));
+        assert_eq!(loaded.opened_at(), position.opened_at());
+        assert_eq!(loaded.status(), &PositionStatus::Open);
+        assert_eq!(loaded.trade_id(), Some(7));
+        assert!(loaded.is_held_to_settlement());
+        assert_eq!(loaded.legs().len(), 2);
+        assert_eq!(loaded.legs()[1].token_id().as_str(), "no");
+        assert_eq!(loaded.legs()[1].size(), dec!(10));
+        assert_eq!(loaded.legs()[1].entry_price(), dec!(0.50));
+    }
+
+    #[test]
+    fn partial_fill_position_roundtrip() {
+        let store = SqlitePositionStore::new(setup_test_db());
+        let status = PositionStatus::PartialFill {
+            filled: vec![TokenId::from("yes")],
+            missing: vec![TokenId::from("no")],
+        };
+        let position = Position::new(
+            PositionId::new(3),
+            market("m2"),
+            vec![PositionLeg::new(TokenId::from("yes"), dec!(5), dec!(0.40))],
+            dec!(2),
+            dec!(5),
+            Utc::now(),
+            status.clone(),
+        );
+
+        store.save(&position).unwrap();
+        let loaded = store.load_open().unwrap();
+
+        assert_eq!(loaded[0].status(), &status);
+        assert_eq!(loaded[0].trade_id(), None);
+        assert!(!loaded[0].is_held_to_settlement());
+    }
+
+    #[test]
+    fn closed_positions_are_not_loaded_but_keep_their_id() {
+        let store = SqlitePositionStore::new(setup_test_db());
+        assert_eq!(store.last_id().unwrap(), 0);
+
+        let mut position = open_position(4);
+        store.save(&open_position(2)).unwrap();
+        store.save(&position).unwrap();
+        position.close(dec!(0.50));
+        store.save(&position).unwrap();
+
+        let loaded = store.load_open().unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].id(), PositionId::new(2));
+        assert_eq!(store.last_id().unwrap(), 4);
+    }
 }
@@ -2,12 +2,31 @@
 //!
 //! Provides concurrent read/write access to order book snapshots, with optional
 //! broadcast notifications for update subscribers (e.g., cluster detection).
-
+//! Each entry records when it was last updated so stale books can be detected,
+//! and books can be trimmed to a maximum depth per side to bound memory.
+//! The whole cache can be snapshotted to disk and reloaded for warm restarts.
+//! Every token also carries a version that increases whenever any of its
+//! cached levels changes, so callers can tell when detection would see a
+//! different book.
+//! Books that arrive crossed or locked are stored as received but logged,
+//! and [`BookCache::is_crossed`] lets detection refuse to trade on them.
+
+use chrono::Utc;
 use parking_lot::RwLock;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
 use tokio::sync::broadcast;
+use tracing::warn;
+
+use crate::domain::book::{Book, PriceLevel};
+use crate::domain::id::TokenId;
+use crate::error::{Error, Result};
 
-use crate::domain::{book::Book, id::TokenId};
+/// Snapshot format version, bumped when the on-disk layout changes.
+const SNAPSHOT_VERSION: u32 = 1;
 
 /// Notification sent when an order book is updated.
 ///
@@ -19,6 +38,75 @@ pub struct BookUpdate {
     pub token_id: TokenId,
 }
 
+/// A cached order book and the time it was last written.
+#[derive(Debug, Clone)]
+struct CachedBook {
+    book: Book,
+    updated_at: Instant,
+    /// Incremented whenever any level's price or size changes.
+    version: u64,
+}
+
+/// Whether two books hold the same levels on both sides.
+fn same_levels(a: &Book, b: &Book) -> bool {
+    let same_side = |x: &[PriceLevel], y: &[PriceLevel]| {
+        x.len() == y.len()
+            && x.iter()
+                .zip(y)
+                .all(|(p, q)| p.price() == q.price() && p.size() == q.size())
+    };
+    same_side(a.bids(), b.bids()) && same_side(a.asks(), b.asks())
+}
+
+/// Log a book whose best bid is at or above its best ask.
+fn warn_if_crossed(book: &Book) {
+    if !book.is_crossed() {
+        return;
+    }
+    warn!(
+        token_id = %book.token_id(),
+        best_bid = ?book.best_bid().map(PriceLevel::price),
+        best_ask = ?book.best_ask().map(PriceLevel::price),
+        "Crossed order book received"
+    );
+}
+
+/// On-disk snapshot of every cached book.
+#[derive(Debug, Serialize, Deserialize)]
+struct BookSnapshot {
+    version: u32,
+    books: Vec<SnapshotEntry>,
+}
+
+/// One book in a snapshot.
+///
+/// Decimals are stored in their fixed 16-byte form, which keeps the file
+/// compact and avoids string round-trips.
+#[derive(Debug, Serialize, Deserialize)]
+struct SnapshotEntry {
+    token_id: TokenId,
+    bids: Vec<([u8; 16], [u8; 16])>,
+    asks: Vec<([u8; 16], [u8; 16])>,
+    /// Unix milliseconds when the book was last updated.
+    updated_at_ms: i64,
+}
+
+fn encode_levels(levels: &[PriceLevel]) -> Vec<([u8; 16], [u8; 16])> {
+    levels
+        .iter()
+        .map(|level| (level.price().serialize(), level.size().serialize()))
+        .collect()
+}
+
+fn decode_levels(levels: Vec<([u8; 16], [u8; 16])>) -> Vec<PriceLevel> {
+    levels
+        .into_iter()
+        .map(|(price, size)| {
+            PriceLevel::new(Decimal::deserialize(price), Decimal::deserialize(size))
+        })
+        .collect()
+}
+
 /// Thread-safe cache of order books with optional broadcast notifications.
 ///
 /// Stores the latest order book snapshot for each token. All read and write
@@ -30,10 +118,12 @@ pub struct BookUpdate {
 /// update events that downstream services can subscribe to for reactive processing.
 pub struct BookCache {
     /// Order books indexed by token ID.
-    books: RwLock<HashMap<TokenId, Book>>,
+    books: RwLock<HashMap<TokenId, CachedBook>>,
     /// Broadcast sender for update notifications.
     /// Wrapped in `Option` to allow construction without notifications.
     tx: Option<broadcast::Sender<BookUpdate>>,
+    /// Maximum levels kept per side, or `None` to keep every level.
+    max_depth: Option<usize>,
 }
 
 impl BookCache {
@@ -43,6 +133,7 @@ impl BookCache {
         Self {
             books: RwLock::new(HashMap::new()),
             tx: None,
+            max_depth: None,
         }
     }
 
@@ -61,10 +152,21 @@ impl BookCache {
         let cache = Self {
             books: RwLock::new(HashMap::new()),
             tx: Some(tx),
+            max_depth: None,
         };
         (cache, rx)
     }
 
+    /// Keep at most `max_depth` of the best levels per side of every book.
+    ///
+    /// Levels beyond the limit are dropped on [`update`](Self::update) and
+    /// [`apply_delta`](Self::apply_delta). `None` keeps every level.
+    #[must_use]
+    pub const fn with_max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
     /// Subscribe to order book update notifications.
     ///
     /// Returns `None` if the cache was created without notifications.
@@ -78,8 +180,32 @@ impl BookCache {
     /// Replaces any existing book for the token. If notifications are enabled,
     /// broadcasts a [`BookUpdate`] message to all subscribers.
     pub fn update(&self, book: Book) {
+        self.update_at(book, Instant::now());
+    }
+
+    /// Update an order book as if it had been received at `updated_at`.
+    pub(crate) fn update_at(&self, mut book: Book, updated_at: Instant) {
+        if let Some(max_depth) = self.max_depth {
+            book.truncate(max_depth);
+        }
+        warn_if_crossed(&book);
         let token_id = book.token_id().clone();
-        self.books.write().insert(token_id.clone(), book);
+        {
+            let mut books = self.books.write();
+            let version = match books.get(&token_id) {
+                Some(previous) if same_levels(&previous.book, &book) => previous.version,
+                Some(previous) => previous.version + 1,
+                None => 1,
+            };
+            books.insert(
+                token_id.clone(),
+                CachedBook {
+                    book,
+                    updated_at,
+                    version,
+                },
+            );
+        }
 
         // Notify subscribers (ignore send errors when no receivers exist)
         if let Some(ref tx) = self.tx {
@@ -87,12 +213,92 @@ impl BookCache {
         }
     }
 
+    /// Merge an incremental update into a cached order book and notify subscribers.
+    ///
+    /// Updated sizes overwrite existing levels, zero sizes remove levels, and
+    /// new levels are inserted in sorted order (see [`Book::apply_delta`]).
+    /// A delta for a token with no cached book starts a new book from it.
+    pub fn apply_delta(&self, token_id: &TokenId, delta: &Book) {
+        {
+            let mut books = self.books.write();
+            let entry = books.entry(token_id.clone()).or_insert_with(|| CachedBook {
+                book: Book::new(token_id.clone()),
+                updated_at: Instant::now(),
+                version: 0,
+            });
+            let previous = entry.book.clone();
+            entry.book.apply_delta(delta);
+            if let Some(max_depth) = self.max_depth {
+                entry.book.truncate(max_depth);
+            }
+            entry.updated_at = Instant::now();
+            if entry.version == 0 || !same_levels(&entry.book, &previous) {
+                entry.version += 1;
+            }
+            warn_if_crossed(&entry.book);
+        }
+
+        if let Some(ref tx) = self.tx {
+            let _ = tx.send(BookUpdate {
+                token_id: token_id.clone(),
+            });
+        }
+    }
+
+    /// Return true if the cached book for the token is crossed or locked.
+    ///
+    /// Returns `false` if no book exists for the given token.
+    #[must_use]
+    pub fn is_crossed(&self, token_id: &TokenId) -> bool {
+        self.books
+            .read()
+            .get(token_id)
+            .is_some_and(|entry| entry.book.is_crossed())
+    }
+
     /// Retrieve a snapshot of an order book.
     ///
     /// Returns `None` if no book exists for the given token.
     #[must_use]
     pub fn get(&self, token_id: &TokenId) -> Option<Book> {
-        self.books.read().get(token_id).cloned()
+        self.books
+            .read()
+            .get(token_id)
+            .map(|entry| entry.book.clone())
+    }
+
+    /// Return how long ago the book for a token was last updated.
+    ///
+    /// Returns `None` if no book exists for the given token.
+    #[must_use]
+    pub fn age(&self, token_id: &TokenId) -> Option<Duration> {
+        self.books
+            .read()
+            .get(token_id)
+            .map(|entry| entry.updated_at.elapsed())
+    }
+
+    /// Return the book version of a token.
+    ///
+    /// Versions start at 1 when a book is first cached and only increase when
+    /// a level's price or size changes at any depth, so an unchanged version
+    /// means detection would see the same book. Returns `None` if no book
+    /// exists.
+    #[must_use]
+    pub fn version(&self, token_id: &TokenId) -> Option<u64> {
+        self.books.read().get(token_id).map(|entry| entry.version)
+    }
+
+    /// Return the book versions of multiple tokens atomically.
+    ///
+    /// See [`version`](Self::version). Tokens without a book map to `None`.
+    #[must_use]
+    pub fn versions(&self, token_ids: &[TokenId]) -> Vec<Option<u64>> {
+        let books = self.books.read();
+        token_ids
+            .iter()
+            .map(|id| books.get(id).map(|entry| entry.version))
+            .collect()
     }
 
     /// Retrieve snapshots of two order books atomically.
@@ -102,7 +308,10 @@ impl BookCache {
     #[must_use]
     pub fn get_pair(&self, token_a: &TokenId, token_b: &TokenId) -> (Option<Book>, Option<Book>) {
         let books = self.books.read();
-        (books.get(token_a).cloned(), books.get(token_b).cloned())
+        (
+            books.get(token_a).map(|entry| entry.book.clone()),
+            books.get(token_b).map(|entry| entry.book.clone()),
+        )
     }
 
     /// Retrieve snapshots of multiple order books atomically.
@@ -112,7 +321,10 @@ impl BookCache {
     #[must_use]
     pub fn get_many(&self, token_ids: &[TokenId]) -> Vec<Option<Book>> {
         let books = self.books.read();
-        token_ids.iter().map(|id| books.get(id).cloned()).collect()
+        token_ids
+            .iter()
+            .map(|id| books.get(id).map(|entry| entry.book.clone()))
+            .collect()
     }
 
     /// Return the number of order books in the cache.
@@ -126,6 +338,85 @@ impl BookCache {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Write every cached book to `path` as a compact binary snapshot.
+    ///
+    /// The file is written beside `path` and renamed into place, so a crash
+    /// mid-write never leaves a truncated snapshot. Returns the number of
+    /// books written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot cannot be encoded or written.
+    pub fn save_snapshot(&self, path: &Path) -> Result<usize> {
+        let now_ms = Utc::now().timestamp_millis();
+        let books: Vec<SnapshotEntry> = self
+            .books
+            .read()
+            .values()
+            .map(|entry| SnapshotEntry {
+                token_id: entry.book.token_id().clone(),
+                bids: encode_levels(entry.book.bids()),
+                asks: encode_levels(entry.book.asks()),
+                updated_at_ms: now_ms
+                    - i64::try_from(entry.updated_at.elapsed().as_millis()).unwrap_or(i64::MAX),
+            })
+            .collect();
+        let count = books.len();
+
+        let bytes = bincode::serialize(&BookSnapshot {
+            version: SNAPSHOT_VERSION,
+            books,
+        })
+        .map_err(|e| Error::Parse(format!("failed to encode book snapshot: {e}")))?;
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, bytes)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(count)
+    }
+
+    /// Load books from a snapshot written by [`save_snapshot`](Self::save_snapshot).
+    ///
+    /// Books last updated more than `max_age` ago are dropped. Loaded books
+    /// keep their original age, so staleness checks treat them exactly as
+    /// if they had never left the cache, and subscribers are notified as for
+    /// a regular update. Returns the number of books loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is not a snapshot, or was
+    /// written by an incompatible version.
+    pub fn load_snapshot(&self, path: &Path, max_age: Duration) -> Result<usize> {
+        let bytes = std::fs::read(path)?;
+        let snapshot: BookSnapshot = bincode::deserialize(&bytes)
+            .map_err(|e| Error::Parse(format!("invalid book snapshot: {e}")))?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(Error::Parse(format!(
+                "unsupported book snapshot version {} (expected {SNAPSHOT_VERSION})",
+                snapshot.version
+            )));
+        }
+
+        let now_ms = Utc::now().timestamp_millis();
+        let mut loaded = 0;
+        for entry in snapshot.books {
+            let age = Duration::from_millis(
+                u64::try_from(now_ms - entry.updated_at_ms).unwrap_or_default(),
+            );
+            if age > max_age {
+                continue;
+            }
+            let book = Book::with_levels(
+                entry.token_id,
+                decode_levels(entry.bids),
+                decode_levels(entry.asks),
+            );
+            let updated_at = Instant::now().checked_sub(age).unwrap_or_else(Instant::now);
+            self.update_at(book, updated_at);
+            loaded += 1;
+        }
+        Ok(loaded)
+    }
 }
 
 impl Default for BookCache {
@@ -189,6 +480,28 @@ mod tests {
         assert_eq!(b.unwrap().best_ask().unwrap().price(), dec!(0.55));
     }
 
+    #[test]
+    fn test_is_crossed() {
+        let cache = BookCache::new();
+        let crossed = TokenId::from("crossed");
+        let normal = TokenId::from("normal");
+
+        cache.update(Book::with_levels(
+            crossed.clone(),
+            vec![PriceLevel::new(dec!(0.55), dec!(100))],
+            vec![PriceLevel::new(dec!(0.50), dec!(100))],
+        ));
+        cache.update(Book::with_levels(
+            normal.clone(),
+            vec![PriceLevel::new(dec!(0.45), dec!(100))],
+            vec![PriceLevel::new(dec!(0.50), dec!(100))],
+        ));
+
+        assert!(cache.is_crossed(&crossed));
+        assert!(!cache.is_crossed(&normal));
+        assert!(!cache.is_crossed(&TokenId::from("missing")));
+    }
+
     #[test]
     fn test_get_many() {
         let cache = BookCache::new();
@@ -211,6 +524,211 @@ mod tests {
         assert!(results.iter().all(|r| r.is_some()));
     }
 
+    #[test]
+    fn test_snapshot_then_deltas_consolidate() {
+        let cache = BookCache::new();
+        let token_id = TokenId::from("test-token");
+
+        cache.update(Book::with_levels(
+            token_id.clone(),
+            vec![
+                PriceLevel::new(dec!(0.45), dec!(100)),
+                PriceLevel::new(dec!(0.44), dec!(200)),
+            ],
+            vec![
+                PriceLevel::new(dec!(0.46), dec!(150)),
+                PriceLevel::new(dec!(0.48), dec!(300)),
+            ],
+        ));
+        cache.apply_delta(
+            &token_id,
+            &Book::with_levels(
+                token_id.clone(),
+                vec![PriceLevel::new(dec!(0.45), dec!(0))],
+                vec![PriceLevel::new(dec!(0.47), dec!(75))],
+            ),
+        );
+        cache.apply_delta(
+            &token_id,
+            &Book::with_levels(
+                token_id.clone(),
+                vec![PriceLevel::new(dec!(0.44), dec!(120))],
+                vec![PriceLevel::new(dec!(0.46), dec!(0))],
+            ),
+        );
+
+        let book = cache.get(&token_id).unwrap();
+        let levels = |levels: &[PriceLevel]| -> Vec<_> {
+            levels.iter().map(|l| (l.price(), l.size())).collect()
+        };
+        assert_eq!(levels(book.bids()), vec![(dec!(0.44), dec!(120))]);
+        assert_eq!(
+            levels(book.asks()),
+            vec![(dec!(0.47), dec!(75)), (dec!(0.48), dec!(300))]
+        );
+    }
+
+    #[test]
+    fn test_delta_without_snapshot_starts_book() {
+        let cache = BookCache::new();
+        let token_id = TokenId::from("test-token");
+
+        cache.apply_delta(
+            &token_id,
+            &Book::with_levels(
+                token_id.clone(),
+                vec![],
+                vec![
+                    PriceLevel::new(dec!(0.50), dec!(10)),
+                    PriceLevel::new(dec!(0.55), dec!(0)),
+                ],
+            ),
+        );
+
+        let book = cache.get(&token_id).unwrap();
+        assert_eq!(book.asks().len(), 1);
+        assert_eq!(book.best_ask().unwrap().price(), dec!(0.50));
+    }
+
+    #[test]
+    fn test_version_tracks_level_changes() {
+        let cache = BookCache::new();
+        let token_id = TokenId::from("test-token");
+        assert_eq!(cache.version(&token_id), None);
+
+        let book = |ask_size| {
+            Book::with_levels(
+                token_id.clone(),
+                vec![
+                    PriceLevel::new(dec!(0.45), dec!(100)),
+                    PriceLevel::new(dec!(0.44), dec!(100)),
+                ],
+                vec![PriceLevel::new(dec!(0.50), ask_size)],
+            )
+        };
+        cache.update(book(dec!(100)));
+        assert_eq!(cache.version(&token_id), Some(1));
+
+        // Same levels: the version holds.
+        cache.update(book(dec!(100)));
+        assert_eq!(cache.version(&token_id), Some(1));
+
+        // A deeper level changes without touching the top.
+        cache.apply_delta(
+            &token_id,
+            &Book::with_levels(
+                token_id.clone(),
+                vec![PriceLevel::new(dec!(0.44), dec!(250))],
+                vec![],
+            ),
+        );
+        assert_eq!(cache.version(&token_id), Some(2));
+
+        cache.update(book(dec!(80)));
+        assert_eq!(cache.version(&token_id), Some(3));
+
+        cache.apply_delta(
+            &token_id,
+            &Book::with_levels(
+                token_id.clone(),
+                vec![PriceLevel::new(dec!(0.46), dec!(10))],
+                vec![],
+            ),
+        );
+        assert_eq!(cache.version(&token_id), Some(4));
+        assert_eq!(
+            cache.versions(&[token_id.clone(), TokenId::from("missing")]),
+            vec![Some(4), None]
+        );
+    }
+
+    #[test]
+    fn test_age_tracks_last_update() {
+        let cache = BookCache::new();
+        let token_id = TokenId::from("test-token");
+        assert!(cache.age(&token_id).is_none());
+
+        let book = Book::with_levels(token_id.clone(), vec![], vec![]);
+        let Some(aged) = Instant::now().checked_sub(Duration::from_secs(30)) else {
+            return;
+        };
+        cache.update_at(book.clone(), aged);
+        assert!(cache.age(&token_id).unwrap() >= Duration::from_secs(30));
+
+        cache.update(book);
+        assert!(cache.age(&token_id).unwrap() < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("books.bin");
+        let cache = BookCache::new();
+        let token_id = TokenId::from("test-token");
+        cache.update(Book::with_levels(
+            token_id.clone(),
+            vec![
+                PriceLevel::new(dec!(0.45), dec!(100)),
+                PriceLevel::new(dec!(0.44), dec!(200)),
+            ],
+            vec![PriceLevel::new(dec!(0.46), dec!(150.5))],
+        ));
+
+        assert_eq!(cache.save_snapshot(&path).unwrap(), 1);
+
+        let restored = BookCache::new();
+        let loaded = restored
+            .load_snapshot(&path, Duration::from_secs(60))
+            .unwrap();
+        assert_eq!(loaded, 1);
+        let book = restored.get(&token_id).unwrap();
+        let levels = |levels: &[PriceLevel]| -> Vec<_> {
+            levels.iter().map(|l| (l.price(), l.size())).collect()
+        };
+        assert_eq!(
+            levels(book.bids()),
+            vec![(dec!(0.45), dec!(100)), (dec!(0.44), dec!(200))]
+        );
+        assert_eq!(levels(book.asks()), vec![(dec!(0.46), dec!(150.5))]);
+        assert!(restored.age(&token_id).unwrap() < Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_snapshot_load_drops_stale_books() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("books.bin");
+        let cache = BookCache::new();
+        let Some(aged) = Instant::now().checked_sub(Duration::from_secs(600)) else {
+            return;
+        };
+        cache.update_at(
+            Book::with_levels(TokenId::from("stale"), vec![], vec![]),
+            aged,
+        );
+        cache.update(Book::with_levels(TokenId::from("fresh"), vec![], vec![]));
+        cache.save_snapshot(&path).unwrap();
+
+        let restored = BookCache::new();
+        let loaded = restored
+            .load_snapshot(&path, Duration::from_secs(300))
+            .unwrap();
+
+        assert_eq!(loaded, 1);
+        assert!(restored.get(&TokenId::from("fresh")).is_some());
+        assert!(restored.get(&TokenId::from("stale")).is_none());
+    }
+
+    #[test]
+    fn test_snapshot_load_rejects_garbage() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("books.bin");
+        std::fs::write(&path, b"not a snapshot").unwrap();
+
+        let result = BookCache::new().load_snapshot(&path, Duration::from_secs(60));
+
+        assert!(matches!(result, Err(Error::Parse(_))));
+    }
+
     #[tokio::test]
     async fn test_with_notifications() {
         let (cache, mut rx) = BookCache::with_notifications(16);
@@ -237,4 +755,68 @@ mod tests {
         let cache_no_notify = BookCache::new();
         assert!(cache_no_notify.subscribe().is_none());
     }
+
+    /// Book with `levels` bids from 0.49 down and asks from 0.51 up, 0.001 apart.
+    fn deep_book(token_id: &TokenId, levels: u32) -> Book {
+        let step = dec!(0.001);
+        Book::with_levels(
+            token_id.clone(),
+            (0..levels)
+                .map(|i| PriceLevel::new(dec!(0.49) - step * Decimal::from(i), dec!(10)))
+                .collect(),
+            (0..levels)
+                .map(|i| PriceLevel::new(dec!(0.51) + step * Decimal::from(i), dec!(10)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn update_trims_book_to_max_depth() {
+        let cache = BookCache::new().with_max_depth(Some(5));
+        let token_id = TokenId::from("deep");
+
+        cache.update(deep_book(&token_id, 100));
+
+        let book = cache.get(&token_id).unwrap();
+        assert_eq!(book.bids().len(), 5);
+        assert_eq!(book.asks().len(), 5);
+        assert_eq!(book.best_bid().unwrap().price(), dec!(0.49));
+        assert_eq!(book.best_ask().unwrap().price(), dec!(0.51));
+        assert_eq!(book.bids()[4].price(), dec!(0.486));
+        assert_eq!(book.asks()[4].price(), dec!(0.515));
+    }
+
+    #[test]
+    fn apply_delta_trims_book_to_max_depth() {
+        let cache = BookCache::new().with_max_depth(Some(3));
+        let token_id = TokenId::from("deep");
+        cache.update(deep_book(&token_id, 3));
+
+        // A better ask pushes the worst ask out; a removed bid is not backfilled.
+        cache.apply_delta(
+            &token_id,
+            &Book::with_levels(
+                token_id.clone(),
+                vec![PriceLevel::new(dec!(0.49), dec!(0))],
+                vec![PriceLevel::new(dec!(0.505), dec!(20))],
+            ),
+        );
+
+        let book = cache.get(&token_id).unwrap();
+        assert_eq!(book.bids().len(), 2);
+        assert_eq!(book.asks().len(), 3);
+        assert_eq!(book.best_bid().unwrap().price(), dec!(0.489));
+        assert_eq!(book.best_ask().unwrap().price(), dec!(0.505));
+        assert_eq!(book.asks()[2].price(), dec!(0.511));
+    }
+
+    #[test]
+    fn cache_without_max_depth_keeps_every_level() {
+        let cache = BookCache::new();
+        let token_id = TokenId::from("deep");
+
+        cache.update(deep_book(&token_id, 100));
+
+        assert_eq!(cache.get(&token_id).unwrap().bids().len(), 100);
+    }
 }
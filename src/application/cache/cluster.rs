@@ -11,6 +11,15 @@ use parking_lot::RwLock;
 use crate::domain::{cluster::Cluster, id::ClusterId, id::MarketId, relation::Relation};
 use crate::port::inbound::runtime::RuntimeClusterView;
 
+/// Entries removed by [`ClusterCache::prune_relations`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneCounts {
+    /// Expired relations dropped from surviving or removed clusters.
+    pub relations: usize,
+    /// Clusters removed because they expired or had no relations left.
+    pub clusters: usize,
+}
+
 /// Cache for relation clusters with TTL-based expiration.
 ///
 /// Provides O(1) lookup of clusters by market ID and automatic expiration
@@ -71,6 +80,15 @@ impl ClusterCache {
         Some(cluster.clone())
     }
 
+    /// Lowest relation confidence of the cluster containing a market.
+    ///
+    /// Returns `None` if no non-expired cluster contains the market or the
+    /// cluster has no source relations.
+    #[must_use]
+    pub fn min_confidence_for_market(&self, market_id: &MarketId) -> Option<f64> {
+        self.get_for_market(market_id)?.min_confidence()
+    }
+
     /// Check if a market belongs to any non-expired cluster.
     #[must_use]
     pub fn has_relations(&self, market_id: &MarketId) -> bool {
@@ -108,6 +126,22 @@ impl ClusterCache {
         self.put(cluster);
     }
 
+    /// Load previously persisted clusters into the cache.
+    ///
+    /// Clusters older than the cache TTL are skipped. Returns the number of
+    /// clusters loaded.
+    pub fn restore(&self, clusters: Vec<Cluster>) -> usize {
+        let now = Utc::now();
+        let mut restored = 0;
+        for cluster in clusters {
+            if cluster.updated_at + self.ttl >= now {
+                self.put(cluster);
+                restored += 1;
+            }
+        }
+        restored
+    }
+
     /// Invalidate and remove any cluster containing a market.
     ///
     /// Use when a market's state changes in a way that invalidates
@@ -169,6 +203,46 @@ impl ClusterCache {
 
         expired.len()
     }
+
+    /// Drop expired relations and remove clusters left without any.
+    ///
+    /// Clusters past the cache TTL are removed first. Clusters that still
+    /// hold live relations are rebuilt from them, keeping their ID and
+    /// update time, so their solver constraints no longer include the
+    /// expired ones.
+    pub fn prune_relations(&self) -> PruneCounts {
+        let mut counts = PruneCounts {
+            relations: 0,
+            clusters: self.prune_expired(),
+        };
+
+        let stale: Vec<Cluster> = self
+            .clusters
+            .read()
+            .values()
+            .filter(|c| c.relations.is_empty() || c.relations.iter().any(Relation::is_expired))
+            .cloned()
+            .collect();
+
+        for cluster in stale {
+            let before = cluster.relations.len();
+            let live: Vec<Relation> = cluster
+                .relations
+                .into_iter()
+                .filter(|r| !r.is_expired())
+                .collect();
+            counts.relations += before - live.len();
+
+            self.remove(&cluster.id);
+            if live.is_empty() {
+                counts.clusters += 1;
+            } else {
+                self.put(Cluster::restore(cluster.id, live, cluster.updated_at));
+            }
+        }
+
+        counts
+    }
 }
 
 impl RuntimeClusterView for ClusterCache {
@@ -213,6 +287,27 @@ mod tests {
         assert!(!cache.has_relations(&MarketId::new("m3")));
     }
 
+    #[test]
+    fn min_confidence_for_market_reports_cluster_minimum() {
+        let cache = ClusterCache::new(Duration::hours(1));
+        cache.put_relations(vec![
+            sample_relation(),
+            Relation::new(
+                RelationKind::MutuallyExclusive {
+                    markets: vec![MarketId::new("m2"), MarketId::new("m3")],
+                },
+                0.6,
+                "test".to_string(),
+            ),
+        ]);
+
+        assert_eq!(
+            cache.min_confidence_for_market(&MarketId::new("m1")),
+            Some(0.6)
+        );
+        assert_eq!(cache.min_confidence_for_market(&MarketId::new("m4")), None);
+    }
+
     #[test]
     fn invalidate_removes_cluster() {
         let cache = ClusterCache::new(Duration::hours(1));
@@ -231,4 +326,63 @@ mod tests {
 
         assert!(!cache.has_relations(&MarketId::new("m1")));
     }
+
+    #[test]
+    fn restore_skips_stale_clusters() {
+        let cache = ClusterCache::new(Duration::hours(1));
+        let fresh = Cluster::from_relations(vec![sample_relation()]);
+        let mut stale = Cluster::from_relations(vec![Relation::new(
+            RelationKind::MutuallyExclusive {
+                markets: vec![MarketId::new("m3"), MarketId::new("m4")],
+            },
+            0.9,
+            "stale".to_string(),
+        )]);
+        stale.updated_at = Utc::now() - Duration::hours(2);
+
+        assert_eq!(cache.restore(vec![fresh, stale]), 1);
+        assert!(cache.has_relations(&MarketId::new("m1")));
+        assert!(!cache.has_relations(&MarketId::new("m3")));
+        assert_eq!(cache.all_clusters().len(), 1);
+    }
+
+    #[test]
+    fn prune_relations_removes_relation_after_ttl() {
+        let cache = ClusterCache::new(Duration::hours(1));
+        cache.put_relations(vec![sample_relation().with_ttl(Duration::milliseconds(10))]);
+        assert_eq!(cache.prune_relations(), PruneCounts::default());
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+
+        let counts = cache.prune_relations();
+        assert_eq!(counts.relations, 1);
+        assert_eq!(counts.clusters, 1);
+        assert!(!cache.has_relations(&MarketId::new("m1")));
+        assert!(cache.all_clusters().is_empty());
+    }
+
+    #[test]
+    fn prune_relations_keeps_cluster_with_live_relations() {
+        let cache = ClusterCache::new(Duration::hours(1));
+        let live = Relation::new(
+            RelationKind::MutuallyExclusive {
+                markets: vec![MarketId::new("m1"), MarketId::new("m3")],
+            },
+            0.9,
+            "live".to_string(),
+        );
+        let mut expired = sample_relation();
+        expired.expires_at = Utc::now() - Duration::seconds(1);
+        cache.put_relations(vec![live, expired]);
+        let id = cache.get_for_market(&MarketId::new("m1")).unwrap().id;
+
+        let counts = cache.prune_relations();
+
+        assert_eq!(counts.relations, 1);
+        assert_eq!(counts.clusters, 0);
+        let cluster = cache.get(&id).unwrap();
+        assert_eq!(cluster.relations.len(), 1);
+        assert!(cache.has_relations(&MarketId::new("m3")));
+        assert!(!cache.has_relations(&MarketId::new("m2")));
+    }
 }
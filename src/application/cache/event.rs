@@ -0,0 +1,150 @@
+//! Ring buffer of recently emitted notification events.
+//!
+//! Registered as a [`Notifier`] so it sees every event the notifier registry
+//! broadcasts, and keeps the most recent ones in memory for operator queries.
+//! Unlike the stats database it captures transient detail such as rejection
+//! reasons at no latency cost.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use chrono::Utc;
+use parking_lot::Mutex;
+
+use crate::port::outbound::notifier::{Event, Notifier, RecentEvent};
+
+/// Number of events retained when no capacity is configured.
+pub const DEFAULT_CAPACITY: usize = 100;
+
+/// Bounded buffer of the most recent events, oldest evicted first.
+///
+/// Clones share the same buffer, so one handle can be registered with the
+/// notifier registry while another serves queries.
+#[derive(Debug, Clone)]
+pub struct EventRingBuffer {
+    capacity: usize,
+    events: Arc<Mutex<VecDeque<RecentEvent>>>,
+}
+
+impl EventRingBuffer {
+    /// Create a buffer retaining up to `capacity` events.
+    ///
+    /// A capacity of 0 retains nothing.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            events: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+        }
+    }
+
+    /// Maximum number of events retained.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Store an event, evicting the oldest one when full.
+    pub fn push(&self, event: Event) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut events = self.events.lock();
+        if events.len() == self.capacity {
+            events.pop_front();
+        }
+        events.push_back(RecentEvent {
+            at: Utc::now(),
+            event,
+        });
+    }
+
+    /// Return up to `limit` retained events, newest first.
+    #[must_use]
+    pub fn recent(&self, limit: usize) -> Vec<RecentEvent> {
+        self.events
+            .lock()
+            .iter()
+            .rev()
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Number of events currently retained.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.events.lock().len()
+    }
+
+    /// Whether no events are retained.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.events.lock().is_empty()
+    }
+}
+
+impl Default for EventRingBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl Notifier for EventRingBuffer {
+    fn notify(&self, event: Event) {
+        self.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn halted(reason: &str) -> Event {
+        Event::TradingHalted {
+            reason: reason.to_string(),
+        }
+    }
+
+    fn reasons(events: &[RecentEvent]) -> Vec<String> {
+        events.iter().map(|recent| recent.event.summary()).collect()
+    }
+
+    #[test]
+    fn recent_returns_newest_first_up_to_limit() {
+        let buffer = EventRingBuffer::new(10);
+        for reason in ["a", "b", "c"] {
+            buffer.push(halted(reason));
+        }
+
+        assert_eq!(reasons(&buffer.recent(2)), vec!["c", "b"]);
+        assert_eq!(buffer.len(), 3);
+    }
+
+    #[test]
+    fn evicts_oldest_event_when_full() {
+        let buffer = EventRingBuffer::new(2);
+        for reason in ["a", "b", "c"] {
+            buffer.push(halted(reason));
+        }
+
+        assert_eq!(reasons(&buffer.recent(10)), vec!["c", "b"]);
+    }
+
+    #[test]
+    fn zero_capacity_retains_nothing() {
+        let buffer = EventRingBuffer::new(0);
+        buffer.notify(halted("a"));
+
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn clones_share_the_buffer() {
+        let buffer = EventRingBuffer::new(5);
+        let handle = buffer.clone();
+        handle.notify(Event::CircuitBreakerReset);
+
+        assert_eq!(buffer.recent(1)[0].event.kind(), "circuit_breaker_reset");
+    }
+}
@@ -4,8 +4,10 @@
 //!
 //! - [`book::BookCache`]: Order book snapshots with optional update notifications
 //! - [`cluster::ClusterCache`]: Relation clusters with TTL-based expiration
+//! - [`event::EventRingBuffer`]: Recently emitted notification events
 //! - [`position::PositionTracker`]: Open and closed position tracking
 
 pub mod book;
 pub mod cluster;
+pub mod event;
 pub mod position;
@@ -5,7 +5,11 @@
 
 use rust_decimal::Decimal;
 
-use crate::domain::{id::PositionId, money::Price, position::Position};
+use crate::domain::{
+    id::{MarketId, PositionId},
+    money::Price,
+    position::Position,
+};
 
 /// Tracks all positions across the application lifecycle.
 ///
@@ -39,6 +43,14 @@ impl PositionTracker {
         id
     }
 
+    /// Continue ID generation after `last_id`.
+    ///
+    /// Used when restoring persisted positions so new positions never reuse
+    /// an ID that is already stored.
+    pub fn advance_ids_past(&mut self, last_id: u64) {
+        self.next_id = self.next_id.max(last_id.saturating_add(1));
+    }
+
     /// Record a new position.
     pub fn add(&mut self, position: Position) {
         self.positions.push(position);
@@ -57,6 +69,16 @@ impl PositionTracker {
             .fold(Decimal::ZERO, |acc, cost| acc + cost)
     }
 
+    /// Calculate exposure in a single market as the sum of entry costs for its
+    /// open positions.
+    #[must_use]
+    pub fn market_exposure(&self, market_id: &MarketId) -> Price {
+        self.open_positions()
+            .filter(|p| p.market_id() == market_id)
+            .map(Position::entry_cost)
+            .fold(Decimal::ZERO, |acc, cost| acc + cost)
+    }
+
     /// Return the count of open positions.
     #[must_use]
     pub fn open_count(&self) -> usize {
@@ -128,6 +150,17 @@ mod tests {
         assert_eq!(id3.value(), 3);
     }
 
+    #[test]
+    fn position_tracker_advance_ids_past_never_moves_backwards() {
+        let mut tracker = PositionTracker::new();
+
+        tracker.advance_ids_past(41);
+        assert_eq!(tracker.next_id().value(), 42);
+
+        tracker.advance_ids_past(10);
+        assert_eq!(tracker.next_id().value(), 43);
+    }
+
     #[test]
     fn position_tracker_add() {
         let mut tracker = PositionTracker::new();
@@ -178,6 +211,33 @@ mod tests {
         assert_eq!(tracker.total_exposure(), dec!(125));
     }
 
+    #[test]
+    fn position_tracker_market_exposure_filters_by_market() {
+        let mut tracker = PositionTracker::new();
+
+        for (market, cost, status) in [
+            ("m1", dec!(20), PositionStatus::Open),
+            ("m1", dec!(30), PositionStatus::Open),
+            ("m1", dec!(40), PositionStatus::Closed { pnl: dec!(1) }),
+            ("m2", dec!(75), PositionStatus::Open),
+        ] {
+            let id = tracker.next_id();
+            tracker.add(Position::new(
+                id,
+                MarketId::new(market),
+                vec![],
+                cost,
+                cost,
+                chrono::Utc::now(),
+                status,
+            ));
+        }
+
+        assert_eq!(tracker.market_exposure(&MarketId::new("m1")), dec!(50));
+        assert_eq!(tracker.market_exposure(&MarketId::new("m2")), dec!(75));
+        assert_eq!(tracker.market_exposure(&MarketId::new("m3")), dec!(0));
+    }
+
     #[test]
     fn position_tracker_closed_positions_not_in_exposure() {
         let mut tracker = PositionTracker::new();
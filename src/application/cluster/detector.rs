@@ -8,17 +8,22 @@ use std::sync::Arc;
 use rust_decimal::Decimal;
 use tracing::{info, trace};
 
+use crate::application::sampling::LogSampler;
 use crate::domain::{
     book::Book, cluster::Cluster, constraint::VariableBounds, id::TokenId, market::MarketRegistry,
-    opportunity::Opportunity, opportunity::OpportunityLeg,
+    opportunity::ClusterContext, opportunity::Opportunity, opportunity::OpportunityLeg,
 };
 use crate::error::{Error, Result};
 use crate::port::{
-    outbound::solver::IlpProblem, outbound::solver::LpProblem, outbound::solver::ProjectionSolver,
+    inbound::strategy::ClusterProjection, outbound::solver::IlpProblem,
+    outbound::solver::LpProblem, outbound::solver::ProjectionSolver,
 };
 
 use super::service::{ClusterDetectionConfig, ClusterOpportunity};
 
+/// Sampler for per-cluster projection trace logs.
+static PROJECTION_LOGS: LogSampler = LogSampler::new();
+
 /// Errors specific to cluster detection operations.
 #[derive(Debug, Clone)]
 pub enum DetectionError {
@@ -70,6 +75,19 @@ impl std::error::Error for DetectionError {}
 /// Function type for looking up order books by token ID.
 pub type BookLookup<'a> = &'a dyn Fn(&TokenId) -> Option<Book>;
 
+/// Outcome of a warm-startable cluster detection run.
+#[derive(Debug, Clone)]
+pub struct ClusterDetection {
+    /// Opportunity found above the gap threshold, if any.
+    pub opportunity: Option<ClusterOpportunity>,
+    /// Converged projection for warm-starting the next run.
+    ///
+    /// `None` if the solver did not converge.
+    pub projection: Option<ClusterProjection>,
+    /// Number of solver iterations used.
+    pub iterations: usize,
+}
+
 /// Cluster detector using Frank-Wolfe projection.
 ///
 /// Encapsulates the detection algorithm for finding arbitrage within a
@@ -120,6 +138,27 @@ impl ClusterDetector {
         book_lookup: BookLookup<'_>,
         registry: &MarketRegistry,
     ) -> Result<Option<ClusterOpportunity>> {
+        self.detect_from(cluster, book_lookup, registry, None, Decimal::ZERO)
+            .map(|detection| detection.opportunity)
+    }
+
+    /// Detect arbitrage in a cluster, warm-starting from a previous projection.
+    ///
+    /// The solver is seeded with `previous.mu` when every current price is
+    /// within `max_drift` of the prices the previous projection was computed
+    /// from. Otherwise the run starts cold from the current prices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if price data is missing or the solver fails.
+    pub fn detect_from(
+        &self,
+        cluster: &Cluster,
+        book_lookup: BookLookup<'_>,
+        registry: &MarketRegistry,
+        previous: Option<&ClusterProjection>,
+        max_drift: Decimal,
+    ) -> Result<ClusterDetection> {
         let cluster_id = cluster.id.to_string();
 
         // Gather prices
@@ -143,21 +182,47 @@ impl ClusterDetector {
         };
         let ilp = IlpProblem::all_binary(lp);
 
+        // Reuse the previous projection only if prices have barely moved
+        let initial = previous
+            .filter(|p| p.is_close_to(&prices, max_drift))
+            .map(|p| p.mu.as_slice());
+
         // Run Frank-Wolfe projection
         let result = self
             .projection_solver
-            .project(&prices, &ilp)
+            .project_from(&prices, initial, &ilp)
             .map_err(|e| Error::Parse(DetectionError::SolverFailed(e.to_string()).to_string()))?;
 
-        // Check threshold
-        if result.gap < self.config.min_gap {
+        let log_projection = PROJECTION_LOGS.sample();
+        if log_projection {
             trace!(
                 cluster = %cluster_id,
-                gap = %result.gap,
-                threshold = %self.config.min_gap,
-                "Gap below threshold"
+                iterations = result.iterations,
+                warm_start = initial.is_some(),
+                "Projection complete"
             );
-            return Ok(None);
+        }
+
+        let projection = result.converged.then(|| ClusterProjection {
+            prices: prices.clone(),
+            mu: result.values.clone(),
+        });
+
+        // Check threshold
+        if result.gap < self.config.min_gap {
+            if log_projection {
+                trace!(
+                    cluster = %cluster_id,
+                    gap = %result.gap,
+                    threshold = %self.config.min_gap,
+                    "Gap below threshold"
+                );
+            }
+            return Ok(ClusterDetection {
+                opportunity: None,
+                projection,
+                iterations: result.iterations,
+            });
         }
 
         info!(
@@ -171,12 +236,16 @@ impl ClusterDetector {
         let opportunity =
             self.build_opportunity(cluster, &token_ids, &result.values, result.gap, registry)?;
 
-        Ok(Some(ClusterOpportunity {
-            cluster_id,
-            markets: cluster.markets.clone(),
-            gap: result.gap,
-            opportunity,
-        }))
+        Ok(ClusterDetection {
+            opportunity: Some(ClusterOpportunity {
+                cluster_id,
+                markets: cluster.markets.clone(),
+                gap: result.gap,
+                opportunity,
+            }),
+            projection,
+            iterations: result.iterations,
+        })
     }
 
     /// Gather current ask prices for all markets in a cluster.
@@ -251,13 +320,15 @@ impl ClusterDetector {
             .map(|m| m.question().to_string())
             .unwrap_or_else(|| format!("Cluster {}", cluster.id));
 
-        Ok(Opportunity::new(
-            market_id,
-            question,
-            legs,
-            gap,
-            Decimal::ONE,
-        ))
+        Ok(
+            Opportunity::new(market_id, question, legs, gap, Decimal::ONE).with_cluster(
+                ClusterContext {
+                    cluster_id: cluster.id.clone(),
+                    markets: cluster.markets.clone(),
+                    gap,
+                },
+            ),
+        )
     }
 }
 
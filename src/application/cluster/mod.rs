@@ -4,7 +4,9 @@
 //! of related markets using Frank-Wolfe projection.
 //!
 //! - [`detector`]: Core detection logic for a single cluster
+//! - [`queue`]: Backpressure on the channel carrying opportunities out
 //! - [`service`]: Background service monitoring order book updates
 
 pub mod detector;
+pub mod queue;
 pub mod service;
@@ -0,0 +1,223 @@
+//! Backpressure for the cluster opportunity channel.
+//!
+//! Cluster detection can find opportunities faster than the consumer handles
+//! them. Instead of blocking detection on a full channel, [`OpportunityQueue`]
+//! stops forwarding once the channel is nearly full and holds the rest back.
+//! Held opportunities are keyed by cluster, so a newer detection replaces the
+//! stale one for the same cluster, and they are released largest gap first as
+//! the channel drains. Opportunities already in the channel cannot be
+//! coalesced, which is why forwarding stops short of filling it.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use tokio::sync::mpsc::{self, error::TrySendError};
+
+use super::service::ClusterOpportunity;
+
+/// Capacity of the channel carrying opportunities to the consumer.
+pub(crate) const CHANNEL_CAPACITY: usize = 64;
+
+/// Free slots below which the channel counts as nearly full.
+pub(crate) const CHANNEL_HEADROOM: usize = CHANNEL_CAPACITY / 4;
+
+/// Counts of cluster opportunities that never reached the consumer.
+#[derive(Debug, Default)]
+pub struct ClusterQueueStats {
+    /// Opportunities replaced by a newer detection for the same cluster.
+    coalesced: AtomicU64,
+    /// Opportunities discarded because too many clusters were held back.
+    dropped: AtomicU64,
+}
+
+impl ClusterQueueStats {
+    /// Return how many opportunities were replaced by a newer one.
+    #[must_use]
+    pub fn coalesced(&self) -> u64 {
+        self.coalesced.load(Ordering::Relaxed)
+    }
+
+    /// Return how many opportunities were discarded outright.
+    #[must_use]
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// Opportunities held back while the channel is nearly full.
+pub(crate) struct OpportunityQueue {
+    /// Latest held opportunity per cluster ID.
+    held: HashMap<String, ClusterOpportunity>,
+    /// Free channel slots to leave unused.
+    headroom: usize,
+    /// Most clusters held at once; the smallest gap is dropped beyond this.
+    max_held: usize,
+    stats: Arc<ClusterQueueStats>,
+}
+
+impl OpportunityQueue {
+    pub(crate) fn new(headroom: usize, max_held: usize, stats: Arc<ClusterQueueStats>) -> Self {
+        Self {
+            held: HashMap::new(),
+            headroom,
+            max_held,
+            stats,
+        }
+    }
+
+    /// Hold `opportunity` until the channel has room.
+    pub(crate) fn push(&mut self, opportunity: ClusterOpportunity) {
+        if self
+            .held
+            .insert(opportunity.cluster_id.clone(), opportunity)
+            .is_some()
+        {
+            self.stats.coalesced.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        if self.held.len() > self.max_held {
+            let smallest = self
+                .held
+                .values()
+                .min_by_key(|opp| opp.gap)
+                .map(|opp| opp.cluster_id.clone());
+            if let Some(cluster_id) = smallest {
+                self.held.remove(&cluster_id);
+                self.stats.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Forward held opportunities, largest gap first, until the channel is
+    /// nearly full.
+    ///
+    /// Returns `false` once the receiver has been dropped.
+    pub(crate) fn flush(&mut self, tx: &mpsc::Sender<ClusterOpportunity>) -> bool {
+        while tx.capacity() > self.headroom {
+            let Some(opportunity) = self.pop() else {
+                break;
+            };
+            match tx.try_send(opportunity) {
+                Ok(()) => {}
+                Err(TrySendError::Full(opportunity)) => {
+                    self.held
+                        .insert(opportunity.cluster_id.clone(), opportunity);
+                    break;
+                }
+                Err(TrySendError::Closed(_)) => return false,
+            }
+        }
+        !tx.is_closed()
+    }
+
+    /// Number of clusters currently held back.
+    pub(crate) fn len(&self) -> usize {
+        self.held.len()
+    }
+
+    /// True when nothing is held back.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.held.is_empty()
+    }
+
+    fn pop(&mut self) -> Option<ClusterOpportunity> {
+        let cluster_id = self
+            .held
+            .values()
+            .max_by_key(|opp| opp.gap)
+            .map(|opp| opp.cluster_id.clone())?;
+        self.held.remove(&cluster_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::id::{MarketId, TokenId};
+    use crate::domain::opportunity::{Opportunity, OpportunityLeg};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn opportunity(cluster_id: &str, gap: Decimal) -> ClusterOpportunity {
+        ClusterOpportunity {
+            cluster_id: cluster_id.to_string(),
+            markets: vec![MarketId::from("m1")],
+            gap,
+            opportunity: Opportunity::new(
+                MarketId::from("m1"),
+                "Test?",
+                vec![OpportunityLeg::new(TokenId::from("yes"), dec!(0.40))],
+                dec!(10),
+                dec!(1),
+            ),
+        }
+    }
+
+    fn queue(headroom: usize, max_held: usize) -> (OpportunityQueue, Arc<ClusterQueueStats>) {
+        let stats = Arc::new(ClusterQueueStats::default());
+        (
+            OpportunityQueue::new(headroom, max_held, Arc::clone(&stats)),
+            stats,
+        )
+    }
+
+    #[test]
+    fn forwards_largest_gap_first_until_nearly_full() {
+        let (mut queue, _) = queue(1, 8);
+        let (tx, mut rx) = mpsc::channel(3);
+        queue.push(opportunity("a", dec!(0.03)));
+        queue.push(opportunity("b", dec!(0.09)));
+        queue.push(opportunity("c", dec!(0.05)));
+
+        assert!(queue.flush(&tx));
+
+        assert_eq!(rx.try_recv().unwrap().cluster_id, "b");
+        assert_eq!(rx.try_recv().unwrap().cluster_id, "c");
+        assert!(rx.try_recv().is_err());
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[test]
+    fn coalesces_repeat_detections_for_a_held_cluster() {
+        let (mut queue, stats) = queue(0, 8);
+        let (tx, mut rx) = mpsc::channel(1);
+        queue.push(opportunity("filler", dec!(0.10)));
+        assert!(queue.flush(&tx));
+
+        // The channel is full, so repeats for "a" replace each other.
+        queue.push(opportunity("a", dec!(0.03)));
+        queue.push(opportunity("a", dec!(0.04)));
+        queue.push(opportunity("a", dec!(0.02)));
+        assert!(queue.flush(&tx));
+        assert_eq!(queue.len(), 1);
+        assert_eq!(stats.coalesced(), 2);
+
+        rx.try_recv().unwrap();
+        assert!(queue.flush(&tx));
+        assert_eq!(rx.try_recv().unwrap().gap, dec!(0.02));
+    }
+
+    #[test]
+    fn drops_smallest_gap_beyond_limit() {
+        let (mut queue, stats) = queue(0, 2);
+        queue.push(opportunity("a", dec!(0.05)));
+        queue.push(opportunity("b", dec!(0.02)));
+        queue.push(opportunity("c", dec!(0.08)));
+
+        assert_eq!(queue.len(), 2);
+        assert_eq!(stats.dropped(), 1);
+        assert_eq!(queue.pop().unwrap().cluster_id, "c");
+        assert_eq!(queue.pop().unwrap().cluster_id, "a");
+    }
+
+    #[test]
+    fn flush_reports_closed_receiver() {
+        let (mut queue, _) = queue(0, 8);
+        let (tx, rx) = mpsc::channel(4);
+        drop(rx);
+        queue.push(opportunity("a", dec!(0.05)));
+
+        assert!(!queue.flush(&tx));
+    }
+}
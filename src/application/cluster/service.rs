@@ -15,9 +15,16 @@
 //!                                                   |
 //!                                                   v
 //!                                           ClusterOpportunity
+//!                                                   |
+//!                                                   v
+//!                                  OpportunityQueue --(mpsc)--> consumer
 //! ```
+//!
+//! Opportunities pass through an [`OpportunityQueue`] that holds them back,
+//! coalesced per cluster, while the channel to the consumer is nearly full.
 
 use super::detector::ClusterDetector;
+use super::queue::{ClusterQueueStats, OpportunityQueue, CHANNEL_CAPACITY, CHANNEL_HEADROOM};
 
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
@@ -30,9 +37,19 @@ use tracing::{debug, info, trace, warn};
 
 use crate::application::cache::book::{BookCache, BookUpdate};
 use crate::application::cache::cluster::ClusterCache;
+use crate::application::sampling::LogSampler;
 use crate::domain::{id::MarketId, id::TokenId, market::MarketRegistry, opportunity::Opportunity};
 use crate::port::outbound::solver::ProjectionSolver;
 
+/// Sampler for per-update dirty-cluster logs.
+static DIRTY_LOGS: LogSampler = LogSampler::new();
+
+/// Sampler for per-cycle detection logs.
+static CYCLE_LOGS: LogSampler = LogSampler::new();
+
+/// Sampler for logs about opportunities held back by backpressure.
+static BACKPRESSURE_LOGS: LogSampler = LogSampler::new();
+
 /// Configuration for the cluster detection service.
 #[derive(Debug, Clone)]
 pub struct ClusterDetectionConfig {
@@ -71,15 +88,58 @@ pub struct ClusterOpportunity {
 pub struct ClusterDetectionHandle {
     /// Channel for sending shutdown signal.
     shutdown_tx: mpsc::Sender<()>,
+    /// Counts of opportunities coalesced or dropped under backpressure.
+    queue_stats: Arc<ClusterQueueStats>,
+    /// Markets shared with the running service.
+    markets: Arc<RwLock<MarketIndex>>,
 }
 
 impl ClusterDetectionHandle {
+    /// Replace the markets the running service resolves updates against.
+    ///
+    /// Called after a market re-scan so newly added markets are detected.
+    pub fn set_registry(&self, registry: Arc<MarketRegistry>) {
+        *self.markets.write() = MarketIndex::new(registry);
+    }
+
+    /// Return the counts of opportunities that never reached the consumer.
+    #[must_use]
+    pub fn queue_stats(&self) -> Arc<ClusterQueueStats> {
+        Arc::clone(&self.queue_stats)
+    }
+
     /// Signal the service to shut down gracefully.
     pub async fn shutdown(&self) {
         let _ = self.shutdown_tx.send(()).await;
     }
 }
 
+/// Market registry together with its token -> market reverse index.
+struct MarketIndex {
+    /// Market registry for resolving metadata.
+    registry: Arc<MarketRegistry>,
+    /// Mapping from token ID to market ID for efficient reverse lookup.
+    token_to_market: HashMap<TokenId, MarketId>,
+}
+
+impl MarketIndex {
+    fn new(registry: Arc<MarketRegistry>) -> Self {
+        let token_to_market = registry
+            .markets()
+            .iter()
+            .flat_map(|m| {
+                m.outcomes()
+                    .iter()
+                    .map(|o| (o.token_id().clone(), m.market_id().clone()))
+            })
+            .collect();
+        Self {
+            registry,
+            token_to_market,
+        }
+    }
+}
+
 /// Background service for cluster-based arbitrage detection.
 ///
 /// Monitors order book updates via broadcast channel, tracks which clusters
@@ -92,12 +152,10 @@ pub struct ClusterDetectionService {
     order_book_cache: Arc<BookCache>,
     /// Cluster cache for relation lookups.
     cluster_cache: Arc<ClusterCache>,
-    /// Market registry for resolving metadata.
-    registry: Arc<MarketRegistry>,
+    /// Markets for resolving metadata, replaced after market re-scans.
+    markets: Arc<RwLock<MarketIndex>>,
     /// Detector instance for running Frank-Wolfe.
     detector: ClusterDetector,
-    /// Mapping from token ID to market ID for efficient reverse lookup.
-    token_to_market: HashMap<TokenId, MarketId>,
     /// Set of cluster IDs with pending updates.
     dirty_clusters: Arc<RwLock<HashSet<String>>>,
 }
@@ -111,26 +169,14 @@ impl ClusterDetectionService {
         registry: Arc<MarketRegistry>,
         projection_solver: Arc<dyn ProjectionSolver>,
     ) -> Self {
-        // Build token -> market mapping for efficient lookups
-        let token_to_market: HashMap<TokenId, MarketId> = registry
-            .markets()
-            .iter()
-            .flat_map(|m| {
-                m.outcomes()
-                    .iter()
-                    .map(|o| (o.token_id().clone(), m.market_id().clone()))
-            })
-            .collect();
-
         let detector = ClusterDetector::new(config.clone(), projection_solver);
 
         Self {
             config,
             order_book_cache,
             cluster_cache,
-            registry,
+            markets: Arc::new(RwLock::new(MarketIndex::new(registry))),
             detector,
-            token_to_market,
             dirty_clusters: Arc::new(RwLock::new(HashSet::new())),
         }
     }
@@ -139,14 +185,21 @@ impl ClusterDetectionService {
     ///
     /// Spawns an async task that monitors order book updates and runs
     /// detection periodically. Returns a handle for lifecycle control
-    /// and a channel for receiving discovered opportunities.
+    /// and a channel for receiving discovered opportunities. Detection never
+    /// waits on the channel: while it is nearly full, opportunities are held
+    /// back and coalesced per cluster.
     pub fn start(
         self,
         mut update_rx: broadcast::Receiver<BookUpdate>,
     ) -> (ClusterDetectionHandle, mpsc::Receiver<ClusterOpportunity>) {
         let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
-        let (opportunity_tx, opportunity_rx) = mpsc::channel::<ClusterOpportunity>(64);
+        let (opportunity_tx, opportunity_rx) =
+            mpsc::channel::<ClusterOpportunity>(CHANNEL_CAPACITY);
+        let queue_stats = Arc::new(ClusterQueueStats::default());
+        let mut queue =
+            OpportunityQueue::new(CHANNEL_HEADROOM, CHANNEL_CAPACITY, Arc::clone(&queue_stats));
 
+        let markets = Arc::clone(&self.markets);
         let service = Arc::new(self);
         let debounce_duration = Duration::from_millis(service.config.debounce_ms);
 
@@ -175,32 +228,55 @@ impl ClusterDetectionService {
 
                     _ = tokio::time::sleep(debounce_duration) => {
                         if last_detection.elapsed() >= debounce_duration {
-                            let opportunities = service.run_detection();
-                            for opp in opportunities {
-                                if opportunity_tx.send(opp).await.is_err() {
-                                    debug!("Opportunity receiver dropped, stopping service");
-                                    return;
-                                }
+                            for opp in service.run_detection() {
+                                queue.push(opp);
                             }
                             last_detection = Instant::now();
                         }
+                        if !queue.flush(&opportunity_tx) {
+                            debug!("Opportunity receiver dropped, stopping service");
+                            return;
+                        }
+                        if !queue.is_empty() && BACKPRESSURE_LOGS.sample() {
+                            debug!(
+                                held = queue.len(),
+                                coalesced = queue_stats.coalesced(),
+                                dropped = queue_stats.dropped(),
+                                "Cluster opportunity channel nearly full, holding opportunities"
+                            );
+                        }
                     }
                 }
             }
         });
 
-        (ClusterDetectionHandle { shutdown_tx }, opportunity_rx)
+        (
+            ClusterDetectionHandle {
+                shutdown_tx,
+                queue_stats,
+                markets,
+            },
+            opportunity_rx,
+        )
     }
 
     /// Process an order book update by marking affected clusters as dirty.
     fn handle_update(&self, update: &BookUpdate) {
-        let Some(market_id) = self.token_to_market.get(&update.token_id) else {
+        let Some(market_id) = self
+            .markets
+            .read()
+            .token_to_market
+            .get(&update.token_id)
+            .cloned()
+        else {
             return;
         };
 
-        if let Some(cluster) = self.cluster_cache.get_for_market(market_id) {
+        if let Some(cluster) = self.cluster_cache.get_for_market(&market_id) {
             self.dirty_clusters.write().insert(cluster.id.to_string());
-            trace!(market = %market_id, cluster = %cluster.id, "Marked cluster dirty");
+            if DIRTY_LOGS.sample() {
+                trace!(market = %market_id, cluster = %cluster.id, "Marked cluster dirty");
+            }
         }
     }
 
@@ -224,7 +300,9 @@ impl ClusterDetectionService {
             return Vec::new();
         }
 
-        debug!(count = dirty.len(), "Running detection on dirty clusters");
+        if CYCLE_LOGS.sample() {
+            debug!(count = dirty.len(), "Running detection on dirty clusters");
+        }
 
         let mut opportunities = Vec::new();
         let mut errors = 0;
@@ -259,7 +337,8 @@ impl ClusterDetectionService {
             })?;
 
         let book_lookup = |token_id: &TokenId| self.order_book_cache.get(token_id);
-        self.detector.detect(&cluster, &book_lookup, &self.registry)
+        let registry = Arc::clone(&self.markets.read().registry);
+        self.detector.detect(&cluster, &book_lookup, &registry)
     }
 
     /// Return the number of clusters with pending updates.
@@ -274,6 +353,40 @@ impl ClusterDetectionService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::cluster::Cluster;
+    use crate::domain::market::{Market, Outcome};
+    use crate::domain::relation::{Relation, RelationKind};
+    use crate::error::Result;
+    use crate::port::outbound::solver::{IlpProblem, ProjectionResult};
+
+    struct MockProjectionSolver;
+
+    impl ProjectionSolver for MockProjectionSolver {
+        fn name(&self) -> &'static str {
+            "mock_projection"
+        }
+
+        fn project(&self, theta: &[Decimal], _problem: &IlpProblem) -> Result<ProjectionResult> {
+            Ok(ProjectionResult {
+                values: theta.to_vec(),
+                gap: Decimal::ZERO,
+                iterations: 1,
+                converged: true,
+            })
+        }
+    }
+
+    fn binary_market(id: &str) -> Market {
+        Market::new(
+            MarketId::from(id),
+            format!("Market {id}?"),
+            vec![
+                Outcome::new(TokenId::from(format!("{id}-yes")), "Yes"),
+                Outcome::new(TokenId::from(format!("{id}-no")), "No"),
+            ],
+            Decimal::ONE,
+        )
+    }
 
     #[test]
     fn test_config_defaults() {
@@ -286,8 +399,55 @@ mod tests {
     #[test]
     fn test_handle_creation() {
         let (tx, _rx) = mpsc::channel(1);
-        let handle = ClusterDetectionHandle { shutdown_tx: tx };
+        let handle = ClusterDetectionHandle {
+            shutdown_tx: tx,
+            queue_stats: Arc::new(ClusterQueueStats::default()),
+            markets: Arc::new(RwLock::new(MarketIndex::new(Arc::new(
+                MarketRegistry::new(),
+            )))),
+        };
         // Handle should be created successfully
         drop(handle);
     }
+
+    #[test]
+    fn rescanned_market_is_detected_after_set_registry() {
+        let cluster_cache = Arc::new(ClusterCache::new(chrono::Duration::hours(1)));
+        cluster_cache.put(Cluster::from_relations(vec![Relation::new(
+            RelationKind::MutuallyExclusive {
+                markets: vec![MarketId::from("m1"), MarketId::from("m2")],
+            },
+            0.9,
+            "test".to_string(),
+        )]));
+        let mut registry = MarketRegistry::new();
+        registry.add(binary_market("m1"));
+        let service = ClusterDetectionService::new(
+            ClusterDetectionConfig::default(),
+            Arc::new(BookCache::new()),
+            cluster_cache,
+            Arc::new(registry),
+            Arc::new(MockProjectionSolver),
+        );
+        let (tx, _rx) = mpsc::channel(1);
+        let handle = ClusterDetectionHandle {
+            shutdown_tx: tx,
+            queue_stats: Arc::new(ClusterQueueStats::default()),
+            markets: Arc::clone(&service.markets),
+        };
+        let update = BookUpdate {
+            token_id: TokenId::from("m2-yes"),
+        };
+
+        service.handle_update(&update);
+        assert_eq!(service.dirty_count(), 0);
+
+        let mut rescanned = MarketRegistry::new();
+        rescanned.add(binary_market("m1"));
+        rescanned.add(binary_market("m2"));
+        handle.set_registry(Arc::new(rescanned));
+
+        service.handle_update(&update);
+        assert_eq!(service.dirty_count(), 1);
+    }
 }
@@ -1,9 +1,11 @@
 //! Inference service configuration.
 
-use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
 
 /// Configuration for the relation inference service.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct InferenceConfig {
     /// Whether the inference service is enabled.
     #[serde(default = "default_enabled")]
@@ -23,6 +25,22 @@ pub struct InferenceConfig {
     /// Maximum number of markets to process per inference batch.
     #[serde(default = "default_batch_size")]
     pub batch_size: usize,
+    /// Maximum inference batches per full run (0 = unlimited).
+    #[serde(default)]
+    pub max_batches_per_run: usize,
+    /// Estimated token budget per full run (0 = unlimited).
+    #[serde(default)]
+    pub max_tokens_per_run: u64,
+    /// Interval between expired-relation pruning passes in seconds (0 = disabled).
+    #[serde(default = "default_prune_interval")]
+    pub prune_interval_secs: u64,
+    /// Whether discovered clusters are saved to the database and reloaded on startup.
+    #[serde(default = "default_persist_clusters")]
+    pub persist_clusters: bool,
+    /// Canonical outcome names mapped to the variants folded onto them
+    /// before inference (e.g., `trump = ["Donald Trump"]`).
+    #[serde(default)]
+    pub aliases: BTreeMap<String, Vec<String>>,
 }
 
 impl Default for InferenceConfig {
@@ -34,6 +52,11 @@ impl Default for InferenceConfig {
             price_change_threshold: default_price_threshold(),
             scan_interval_seconds: default_scan_interval(),
             batch_size: default_batch_size(),
+            max_batches_per_run: 0,
+            max_tokens_per_run: 0,
+            prune_interval_secs: default_prune_interval(),
+            persist_clusters: default_persist_clusters(),
+            aliases: BTreeMap::new(),
         }
     }
 }
@@ -58,6 +81,14 @@ const fn default_scan_interval() -> u64 {
     3600
 }
 
+const fn default_prune_interval() -> u64 {
+    300
+}
+
 const fn default_batch_size() -> usize {
     50
 }
+
+const fn default_persist_clusters() -> bool {
+    true
+}
@@ -4,4 +4,5 @@
 //! enabling combinatorial arbitrage detection across correlated markets.
 
 pub mod config;
+pub mod normalize;
 pub mod service;
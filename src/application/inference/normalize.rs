@@ -0,0 +1,256 @@
+//! Outcome-name normalization for relation inference.
+//!
+//! Markets spell the same outcome differently ("Yes", "YES", " yes") or use
+//! longer and shorter names for the same entity ("Donald Trump", "Trump").
+//! [`OutcomeNormalizer`] folds case and whitespace and maps configured
+//! aliases onto a canonical name, and [`NormalizingInferrer`] applies it to
+//! every market summary before it reaches the underlying inferrer.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::relation::Relation;
+use crate::error::Result;
+use crate::port::outbound::inference::{InferenceOutput, MarketSummary, RelationInferrer};
+
+/// Folds outcome names to a canonical form for matching.
+#[derive(Debug, Clone, Default)]
+pub struct OutcomeNormalizer {
+    /// Folded variant name to folded canonical name.
+    aliases: HashMap<String, String>,
+}
+
+impl OutcomeNormalizer {
+    /// Create a normalizer from canonical names mapped to their variants.
+    ///
+    /// Canonical names and variants are matched after case and whitespace
+    /// folding. A variant listed under several canonical names maps to the
+    /// last one in key order; [`conflicting_alias`] detects that case.
+    #[must_use]
+    pub fn new(aliases: &BTreeMap<String, Vec<String>>) -> Self {
+        let aliases = aliases
+            .iter()
+            .flat_map(|(canonical, variants)| {
+                let canonical = fold(canonical);
+                variants
+                    .iter()
+                    .map(move |variant| (fold(variant), canonical.clone()))
+            })
+            .collect();
+        Self { aliases }
+    }
+
+    /// Normalize an outcome name: trim, lowercase, collapse inner whitespace,
+    /// then replace a known alias with its canonical name.
+    #[must_use]
+    pub fn normalize(&self, name: &str) -> String {
+        let folded = fold(name);
+        match self.aliases.get(&folded) {
+            Some(canonical) => canonical.clone(),
+            None => folded,
+        }
+    }
+
+    /// Whether two outcome names refer to the same outcome.
+    #[must_use]
+    pub fn matches(&self, a: &str, b: &str) -> bool {
+        self.normalize(a) == self.normalize(b)
+    }
+
+    /// Return a copy of `summary` with every outcome name normalized.
+    #[must_use]
+    pub fn normalize_summary(&self, summary: &MarketSummary) -> MarketSummary {
+        MarketSummary {
+            id: summary.id.clone(),
+            question: summary.question.clone(),
+            outcomes: summary
+                .outcomes
+                .iter()
+                .map(|outcome| self.normalize(outcome))
+                .collect(),
+        }
+    }
+}
+
+/// Return the first variant mapped to more than one canonical name.
+#[must_use]
+pub fn conflicting_alias(aliases: &BTreeMap<String, Vec<String>>) -> Option<String> {
+    let mut seen: HashMap<String, String> = HashMap::new();
+    for (canonical, variants) in aliases {
+        let canonical = fold(canonical);
+        for variant in variants {
+            let folded = fold(variant);
+            match seen.get(&folded) {
+                Some(existing) if *existing != canonical => return Some(variant.clone()),
+                _ => {
+                    seen.insert(folded, canonical.clone());
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Lowercase and trim `name`, collapsing runs of whitespace to one space.
+fn fold(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Inferrer decorator that normalizes outcome names before inference.
+pub struct NormalizingInferrer {
+    inner: Arc<dyn RelationInferrer>,
+    normalizer: OutcomeNormalizer,
+}
+
+impl NormalizingInferrer {
+    /// Wrap `inner` so it only sees normalized outcome names.
+    #[must_use]
+    pub fn new(inner: Arc<dyn RelationInferrer>, normalizer: OutcomeNormalizer) -> Self {
+        Self { inner, normalizer }
+    }
+
+    fn normalize_all(&self, markets: &[MarketSummary]) -> Vec<MarketSummary> {
+        markets
+            .iter()
+            .map(|summary| self.normalizer.normalize_summary(summary))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl RelationInferrer for NormalizingInferrer {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    async fn infer(&self, markets: &[MarketSummary]) -> Result<Vec<Relation>> {
+        self.inner.infer(&self.normalize_all(markets)).await
+    }
+
+    async fn infer_with_usage(&self, markets: &[MarketSummary]) -> Result<InferenceOutput> {
+        self.inner
+            .infer_with_usage(&self.normalize_all(markets))
+            .await
+    }
+
+    fn batch_limit(&self) -> usize {
+        self.inner.batch_limit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::id::MarketId;
+    use parking_lot::Mutex;
+
+    fn aliases() -> BTreeMap<String, Vec<String>> {
+        BTreeMap::from([(
+            "Trump".to_string(),
+            vec!["Donald Trump".to_string(), "Donald J. Trump".to_string()],
+        )])
+    }
+
+    #[test]
+    fn folds_case_and_whitespace() {
+        let normalizer = OutcomeNormalizer::default();
+
+        assert_eq!(normalizer.normalize("Yes"), "yes");
+        assert_eq!(normalizer.normalize("  YES "), "yes");
+        assert_eq!(normalizer.normalize("New   York\tCity"), "new york city");
+        assert!(normalizer.matches("yes", "YES"));
+        assert!(!normalizer.matches("yes", "no"));
+    }
+
+    #[test]
+    fn folds_aliases_onto_canonical_name() {
+        let normalizer = OutcomeNormalizer::new(&aliases());
+
+        assert_eq!(normalizer.normalize("Donald Trump"), "trump");
+        assert_eq!(normalizer.normalize("DONALD  J. TRUMP"), "trump");
+        assert_eq!(normalizer.normalize("trump"), "trump");
+        assert!(normalizer.matches("Donald Trump", "TRUMP"));
+        assert_eq!(normalizer.normalize("Kamala Harris"), "kamala harris");
+    }
+
+    #[test]
+    fn normalize_summary_keeps_id_question_and_outcome_count() {
+        let normalizer = OutcomeNormalizer::new(&aliases());
+        let summary = MarketSummary::new(
+            MarketId::from("m1"),
+            "Who wins?",
+            vec!["Donald Trump".into(), "Other".into()],
+        );
+
+        let normalized = normalizer.normalize_summary(&summary);
+
+        assert_eq!(normalized.id, summary.id);
+        assert_eq!(normalized.question, "Who wins?");
+        assert_eq!(normalized.outcomes, vec!["trump", "other"]);
+    }
+
+    #[test]
+    fn conflicting_alias_detects_shared_variant() {
+        assert_eq!(conflicting_alias(&aliases()), None);
+
+        let mut aliases = aliases();
+        aliases.insert("Donald".to_string(), vec!["donald trump".to_string()]);
+
+        assert_eq!(
+            conflicting_alias(&aliases),
+            Some("donald trump".to_string())
+        );
+    }
+
+    /// Inferrer recording the outcomes it was asked about.
+    #[derive(Default)]
+    struct RecordingInferrer {
+        seen: Mutex<Vec<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl RelationInferrer for RecordingInferrer {
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        async fn infer(&self, markets: &[MarketSummary]) -> Result<Vec<Relation>> {
+            self.seen
+                .lock()
+                .extend(markets.iter().map(|m| m.outcomes.clone()));
+            Ok(vec![])
+        }
+
+        fn batch_limit(&self) -> usize {
+            7
+        }
+    }
+
+    #[tokio::test]
+    async fn normalizing_inferrer_feeds_normalized_outcomes() {
+        let inner = Arc::new(RecordingInferrer::default());
+        let inferrer = NormalizingInferrer::new(inner.clone(), OutcomeNormalizer::new(&aliases()));
+        let markets = vec![
+            MarketSummary::new(MarketId::from("m1"), "A?", vec!["YES".into(), "No".into()]),
+            MarketSummary::new(
+                MarketId::from("m2"),
+                "B?",
+                vec!["Donald J. Trump".into(), "Other".into()],
+            ),
+        ];
+
+        inferrer.infer(&markets).await.unwrap();
+
+        assert_eq!(
+            *inner.seen.lock(),
+            vec![vec!["yes", "no"], vec!["trump", "other"]]
+        );
+        assert_eq!(inferrer.name(), "recording");
+        assert_eq!(inferrer.batch_limit(), 7);
+    }
+}
@@ -27,6 +27,42 @@ impl InferenceServiceHandle {
     }
 }
 
+/// Spending limits for a single full inference run.
+///
+/// A zero limit is unlimited. Limits are checked before each batch, so the
+/// batch that crosses the token budget still completes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InferenceBudget {
+    /// Maximum batches sent to the inferrer.
+    pub max_batches: usize,
+    /// Maximum tokens consumed across all batches.
+    pub max_tokens: u64,
+}
+
+impl InferenceBudget {
+    /// Build the budget from inference configuration.
+    #[must_use]
+    pub const fn from_config(config: &InferenceConfig) -> Self {
+        Self {
+            max_batches: config.max_batches_per_run,
+            max_tokens: config.max_tokens_per_run,
+        }
+    }
+
+    /// Return `true` once either limit has been reached.
+    #[must_use]
+    pub const fn is_exhausted(&self, batches: usize, tokens: u64) -> bool {
+        (self.max_batches > 0 && batches >= self.max_batches)
+            || (self.max_tokens > 0 && tokens >= self.max_tokens)
+    }
+}
+
+/// Consecutive failed batches after which a run gives up on the inferrer.
+///
+/// An unreachable LLM fails every batch, often only after a timeout, so the
+/// rest of the run is abandoned rather than stalling startup.
+const MAX_CONSECUTIVE_FAILURES: usize = 3;
+
 /// Result of running inference across markets.
 #[derive(Debug, Clone)]
 pub struct InferenceResult {
@@ -36,21 +72,41 @@ pub struct InferenceResult {
     pub relations_discovered: usize,
     /// Number of inference batches executed.
     pub batches_run: usize,
+    /// Number of batches skipped because the budget was exhausted.
+    pub batches_skipped: usize,
+    /// Number of batches that failed.
+    pub batches_failed: usize,
+    /// Error reported by the most recent failed batch.
+    pub last_error: Option<String>,
+    /// Tokens consumed, as reported or estimated by the inferrer.
+    pub tokens_used: u64,
     /// All discovered relations (for notifications and caching).
     pub relations: Vec<Relation>,
 }
 
+impl InferenceResult {
+    /// Return `true` when batches were attempted and every one failed,
+    /// which indicates the inferrer is unreachable.
+    #[must_use]
+    pub const fn is_unavailable(&self) -> bool {
+        self.batches_failed > 0 && self.batches_run == 0
+    }
+}
+
 /// Run inference on all markets in sequential batches.
 ///
 /// Processes all markets by chunking them into batches of the configured
 /// size and running LLM inference on each batch. Discovered relations are
-/// automatically added to the cluster cache.
+/// automatically added to the cluster cache. Once `budget` is exhausted the
+/// remaining batches are skipped with a warning, and after
+/// `MAX_CONSECUTIVE_FAILURES` failed batches in a row the run is abandoned.
 ///
 /// # Arguments
 ///
 /// * `inferrer` - The relation inferrer implementation to use.
 /// * `markets` - All markets to process.
 /// * `batch_size` - Maximum markets per inference batch.
+/// * `budget` - Batch and token limits for this run.
 /// * `cluster_cache` - Cache to store discovered relations.
 ///
 /// # Returns
@@ -60,10 +116,17 @@ pub async fn run_full_inference(
     inferrer: &dyn RelationInferrer,
     markets: &[MarketSummary],
     batch_size: usize,
+    budget: InferenceBudget,
     cluster_cache: &ClusterCache,
 ) -> InferenceResult {
     let mut total_relations = 0;
     let mut batches_run = 0;
+    let mut batches_attempted = 0;
+    let mut batches_skipped = 0;
+    let mut batches_failed = 0;
+    let mut consecutive_failures = 0;
+    let mut last_error = None;
+    let mut tokens_used = 0;
     let mut all_relations = Vec::new();
 
     if markets.len() < 2 {
@@ -72,6 +135,10 @@ pub async fn run_full_inference(
             markets_processed: markets.len(),
             relations_discovered: 0,
             batches_run: 0,
+            batches_skipped: 0,
+            batches_failed: 0,
+            last_error: None,
+            tokens_used: 0,
             relations: vec![],
         };
     }
@@ -86,14 +153,30 @@ pub async fn run_full_inference(
             continue;
         }
 
+        if consecutive_failures >= MAX_CONSECUTIVE_FAILURES {
+            warn!(
+                failures = consecutive_failures,
+                "Inferrer unreachable, abandoning remaining batches"
+            );
+            break;
+        }
+
+        if budget.is_exhausted(batches_attempted, tokens_used) {
+            batches_skipped += 1;
+            continue;
+        }
+        batches_attempted += 1;
+
         debug!(
             batch = batch_idx,
             markets = chunk.len(),
             "Running inference batch"
         );
 
-        match inferrer.infer(chunk).await {
-            Ok(relations) => {
+        match inferrer.infer_with_usage(chunk).await {
+            Ok(output) => {
+                tokens_used += output.usage.map_or(0, |usage| usage.total());
+                let relations = output.relations;
                 if !relations.is_empty() {
                     info!(
                         batch = batch_idx,
@@ -105,17 +188,33 @@ pub async fn run_full_inference(
                     cluster_cache.put_relations(relations);
                 }
                 batches_run += 1;
+                consecutive_failures = 0;
             }
             Err(e) => {
                 warn!(batch = batch_idx, error = %e, "Inference batch failed");
+                batches_failed += 1;
+                consecutive_failures += 1;
+                last_error = Some(e.to_string());
             }
         }
     }
 
+    if batches_skipped > 0 {
+        warn!(
+            skipped = batches_skipped,
+            batches = batches_attempted,
+            tokens = tokens_used,
+            max_batches = budget.max_batches,
+            max_tokens = budget.max_tokens,
+            "Inference budget exhausted, skipped remaining batches"
+        );
+    }
+
     info!(
         markets = markets.len(),
         relations = total_relations,
         batches = batches_run,
+        tokens = tokens_used,
         "Full inference complete"
     );
 
@@ -123,6 +222,10 @@ pub async fn run_full_inference(
         markets_processed: markets.len(),
         relations_discovered: total_relations,
         batches_run,
+        batches_skipped,
+        batches_failed,
+        last_error,
+        tokens_used,
         relations: all_relations,
     }
 }
@@ -168,6 +271,7 @@ impl InferenceService {
 
         let interval = Duration::from_secs(self.config.scan_interval_seconds);
         let batch_size = self.config.batch_size;
+        let budget = InferenceBudget::from_config(&self.config);
         let inferrer = self.inferrer;
         let cluster_cache = self.cluster_cache;
 
@@ -188,6 +292,7 @@ impl InferenceService {
                             inferrer.as_ref(),
                             &markets,
                             batch_size,
+                            budget,
                             &cluster_cache,
                         ).await;
 
@@ -209,6 +314,7 @@ mod tests {
     use super::*;
     use crate::domain::{id::MarketId, relation::Relation, relation::RelationKind};
     use crate::port::outbound::inference::tests::MockInferrer;
+    use crate::port::outbound::llm::TokenUsage;
     use chrono::Duration as ChronoDuration;
 
     fn sample_markets(count: usize) -> Vec<MarketSummary> {
@@ -237,7 +343,8 @@ mod tests {
         let inferrer = MockInferrer::new(vec![sample_relation()]);
         let cache = ClusterCache::new(ChronoDuration::hours(1));
 
-        let result = run_full_inference(&inferrer, &markets, 100, &cache).await;
+        let result =
+            run_full_inference(&inferrer, &markets, 100, InferenceBudget::default(), &cache).await;
 
         // 250 markets / 100 batch = 3 batches
         assert_eq!(result.batches_run, 3);
@@ -252,7 +359,8 @@ mod tests {
         let inferrer = MockInferrer::new(vec![sample_relation()]);
         let cache = ClusterCache::new(ChronoDuration::hours(1));
 
-        let result = run_full_inference(&inferrer, &markets, 100, &cache).await;
+        let result =
+            run_full_inference(&inferrer, &markets, 100, InferenceBudget::default(), &cache).await;
 
         assert_eq!(result.batches_run, 0);
         assert_eq!(result.relations_discovered, 0);
@@ -264,7 +372,7 @@ mod tests {
         let inferrer = MockInferrer::new(vec![sample_relation()]);
         let cache = ClusterCache::new(ChronoDuration::hours(1));
 
-        run_full_inference(&inferrer, &markets, 100, &cache).await;
+        run_full_inference(&inferrer, &markets, 100, InferenceBudget::default(), &cache).await;
 
         // Verify relations were stored
         assert!(cache.has_relations(&MarketId::new("market-0")));
@@ -277,12 +385,101 @@ mod tests {
         let inferrer = MockInferrer::new(vec![]); // No relations
         let cache = ClusterCache::new(ChronoDuration::hours(1));
 
-        let result = run_full_inference(&inferrer, &markets, 100, &cache).await;
+        let result =
+            run_full_inference(&inferrer, &markets, 100, InferenceBudget::default(), &cache).await;
 
         assert_eq!(result.batches_run, 1);
         assert_eq!(result.relations_discovered, 0);
     }
 
+    #[tokio::test]
+    async fn run_full_inference_stops_at_batch_limit() {
+        let markets = sample_markets(250);
+        let inferrer = MockInferrer::new(vec![sample_relation()]);
+        let cache = ClusterCache::new(ChronoDuration::hours(1));
+        let budget = InferenceBudget {
+            max_batches: 2,
+            max_tokens: 0,
+        };
+
+        let result = run_full_inference(&inferrer, &markets, 100, budget, &cache).await;
+
+        assert_eq!(result.batches_run, 2);
+        assert_eq!(result.batches_skipped, 1);
+        assert_eq!(result.relations_discovered, 2);
+    }
+
+    #[tokio::test]
+    async fn run_full_inference_stops_once_token_budget_is_spent() {
+        let markets = sample_markets(500);
+        let inferrer =
+            MockInferrer::new(vec![sample_relation()]).with_usage(TokenUsage::new(800, 200));
+        let cache = ClusterCache::new(ChronoDuration::hours(1));
+        let budget = InferenceBudget {
+            max_batches: 0,
+            max_tokens: 2500,
+        };
+
+        let result = run_full_inference(&inferrer, &markets, 100, budget, &cache).await;
+
+        // The third batch crosses the budget; the last two are skipped.
+        assert_eq!(result.batches_run, 3);
+        assert_eq!(result.batches_skipped, 2);
+        assert_eq!(result.tokens_used, 3000);
+    }
+
+    #[tokio::test]
+    async fn run_full_inference_tracks_usage_without_budget() {
+        let markets = sample_markets(250);
+        let inferrer = MockInferrer::new(vec![]).with_usage(TokenUsage::new(100, 50));
+        let cache = ClusterCache::new(ChronoDuration::hours(1));
+
+        let result =
+            run_full_inference(&inferrer, &markets, 100, InferenceBudget::default(), &cache).await;
+
+        assert_eq!(result.batches_run, 3);
+        assert_eq!(result.batches_skipped, 0);
+        assert_eq!(result.tokens_used, 450);
+    }
+
+    #[tokio::test]
+    async fn run_full_inference_abandons_unreachable_inferrer() {
+        let markets = sample_markets(1000);
+        let inferrer = MockInferrer::failing("connection refused");
+        let cache = ClusterCache::new(ChronoDuration::hours(1));
+
+        let result =
+            run_full_inference(&inferrer, &markets, 100, InferenceBudget::default(), &cache).await;
+
+        assert_eq!(result.batches_failed, MAX_CONSECUTIVE_FAILURES);
+        assert_eq!(result.batches_run, 0);
+        assert!(result.is_unavailable());
+        assert!(result
+            .last_error
+            .as_deref()
+            .is_some_and(|error| error.contains("connection refused")));
+    }
+
+    #[tokio::test]
+    async fn run_full_inference_with_successes_is_available() {
+        let markets = sample_markets(250);
+        let inferrer = MockInferrer::new(vec![]);
+        let cache = ClusterCache::new(ChronoDuration::hours(1));
+
+        let result =
+            run_full_inference(&inferrer, &markets, 100, InferenceBudget::default(), &cache).await;
+
+        assert!(!result.is_unavailable());
+        assert_eq!(result.batches_failed, 0);
+    }
+
+    #[test]
+    fn budget_from_config_treats_zero_as_unlimited() {
+        let budget = InferenceBudget::from_config(&InferenceConfig::default());
+
+        assert!(!budget.is_exhausted(usize::MAX, u64::MAX));
+    }
+
     #[tokio::test]
     async fn inference_service_can_be_shutdown() {
         let inferrer = Arc::new(MockInferrer::new(vec![]));
@@ -24,6 +24,7 @@
 //! - [`orchestration`]: Event processing and execution workflows
 //! - [`position`]: Position lifecycle management
 //! - [`risk`]: Pre-execution risk validation
+//! - [`sampling`]: Sampling of high-volume log events
 //! - [`solver`]: Mathematical solvers for Bregman projection
 //! - [`state`]: Shared application state and configuration
 //! - [`strategy`]: Arbitrage detection algorithms
@@ -34,6 +35,7 @@ pub mod inference;
 pub mod orchestration;
 pub mod position;
 pub mod risk;
+pub mod sampling;
 pub mod solver;
 pub mod state;
 pub mod strategy;
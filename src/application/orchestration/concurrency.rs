@@ -0,0 +1,124 @@
+//! Concurrency limiting for trade execution.
+//!
+//! Every approved opportunity is executed in its own task, so a burst of
+//! opportunities across markets would otherwise run any number of
+//! executions at once. [`ExecutionSlots`] caps how many may be in flight:
+//! an execution holds an [`ExecutionSlot`] until its task finishes, and
+//! opportunities that find every slot taken are dropped.
+
+use std::sync::Arc;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Bounded pool of slots for concurrent executions.
+///
+/// Cloning is cheap; clones share the same slots.
+#[derive(Debug, Clone)]
+pub struct ExecutionSlots {
+    /// Semaphore holding one permit per slot, `None` when unlimited.
+    semaphore: Option<Arc<Semaphore>>,
+    /// Number of slots, or 0 when unlimited.
+    max: usize,
+}
+
+/// A slot held by one execution, released when dropped.
+///
+/// The default slot holds nothing and is used when executions are not
+/// limited.
+#[derive(Debug, Default)]
+pub struct ExecutionSlot {
+    /// Permit taken from the shared semaphore, if limited.
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl ExecutionSlots {
+    /// Create a pool allowing `max` executions at once. 0 is unlimited.
+    #[must_use]
+    pub fn new(max: usize) -> Self {
+        if max == 0 {
+            return Self::unlimited();
+        }
+        Self {
+            semaphore: Some(Arc::new(Semaphore::new(max))),
+            max,
+        }
+    }
+
+    /// Create a pool that never limits executions.
+    #[must_use]
+    pub const fn unlimited() -> Self {
+        Self {
+            semaphore: None,
+            max: 0,
+        }
+    }
+
+    /// Number of slots, or 0 when unlimited.
+    #[must_use]
+    pub const fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Number of slots currently held by executions.
+    #[must_use]
+    pub fn in_flight(&self) -> usize {
+        self.semaphore
+            .as_ref()
+            .map_or(0, |semaphore| self.max - semaphore.available_permits())
+    }
+
+    /// Take a slot for one execution without waiting.
+    ///
+    /// Returns `None` when every slot is taken, in which case the
+    /// execution should be dropped.
+    #[must_use]
+    pub fn try_acquire(&self) -> Option<ExecutionSlot> {
+        let Some(semaphore) = &self.semaphore else {
+            return Some(ExecutionSlot::default());
+        };
+        let permit = Arc::clone(semaphore).try_acquire_owned().ok()?;
+        Some(ExecutionSlot {
+            _permit: Some(permit),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slots_are_capped_and_released_on_drop() {
+        let slots = ExecutionSlots::new(2);
+
+        let first = slots.try_acquire().expect("first slot");
+        let second = slots.try_acquire().expect("second slot");
+        assert_eq!(slots.in_flight(), 2);
+        assert!(slots.try_acquire().is_none());
+
+        drop(first);
+        assert_eq!(slots.in_flight(), 1);
+        assert!(slots.try_acquire().is_some());
+        drop(second);
+    }
+
+    #[test]
+    fn clones_share_slots() {
+        let slots = ExecutionSlots::new(1);
+        let clone = slots.clone();
+
+        let _held = slots.try_acquire().expect("slot");
+
+        assert!(clone.try_acquire().is_none());
+    }
+
+    #[test]
+    fn zero_is_unlimited() {
+        let slots = ExecutionSlots::new(0);
+
+        let held: Vec<_> = (0..1000).filter_map(|_| slots.try_acquire()).collect();
+
+        assert_eq!(held.len(), 1000);
+        assert_eq!(slots.in_flight(), 0);
+    }
+}
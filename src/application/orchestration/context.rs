@@ -3,6 +3,8 @@
 //! Provides concrete implementations of the [`DetectionContext`] trait that
 //! wrap market metadata and order book cache for strategy detection.
 
+use std::time::Duration;
+
 use rust_decimal::Decimal;
 
 use crate::application::cache::book::BookCache;
@@ -90,6 +92,10 @@ impl DetectionContext for MarketDetectionContext<'_> {
         self.cache.get(token_id)
     }
 
+    fn book_age(&self, token_id: &TokenId) -> Option<Duration> {
+        self.cache.age(token_id)
+    }
+
     fn market(&self) -> &Market {
         self.market
     }
@@ -0,0 +1,189 @@
+//! Opportunity deduplication for orchestration.
+//!
+//! A slow-moving order book yields the same opportunity on every delta.
+//! [`DedupTracker`] remembers recently handled opportunities so that stats and
+//! notifications fire once per window rather than once per book update.
+//!
+//! Opportunities are keyed on [`Opportunity::content_hash_bucketed`], the same
+//! content hash the stats recorder dedups on, with the edge bucketed at the
+//! tracker's own width. The tracker runs first; the recorder's window only
+//! catches duplicates that get past it.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+
+use crate::domain::opportunity::Opportunity;
+
+/// Tracks recently seen opportunities and suppresses repeats within a window.
+///
+/// An opportunity is new when no detection with the same content hash was
+/// recorded within the window. A material edge change moves the opportunity
+/// into another bucket, so it counts as new and is re-alerted.
+pub struct DedupTracker {
+    /// How long a content hash suppresses repeats. Zero disables deduplication.
+    window: Duration,
+    /// Width of the edge buckets (e.g., 0.005 = half a cent).
+    edge_bucket: Decimal,
+    /// First-seen time of each live content hash.
+    seen: Mutex<HashMap<u64, Instant>>,
+}
+
+impl DedupTracker {
+    /// Create a tracker with the given window and edge bucket width.
+    #[must_use]
+    pub fn new(window: Duration, edge_bucket: Decimal) -> Self {
+        Self {
+            window,
+            edge_bucket,
+            seen: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create a tracker that treats every opportunity as new.
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self::new(Duration::ZERO, Decimal::ZERO)
+    }
+
+    /// Record an opportunity and return whether it is new.
+    pub fn is_new(&self, opportunity: &Opportunity) -> bool {
+        self.is_new_at(opportunity, Instant::now())
+    }
+
+    /// Record an opportunity observed at `now` and return whether it is new.
+    ///
+    /// Repeats do not extend the window, so a persistent opportunity is
+    /// reported again once per window.
+    pub fn is_new_at(&self, opportunity: &Opportunity, now: Instant) -> bool {
+        if self.window.is_zero() {
+            return true;
+        }
+
+        let hash = opportunity.content_hash_bucketed(self.edge_bucket);
+        let window = self.window;
+        let mut seen = self.seen.lock();
+        seen.retain(|_, first_seen| now.saturating_duration_since(*first_seen) < window);
+
+        if seen.contains_key(&hash) {
+            return false;
+        }
+        seen.insert(hash, now);
+        true
+    }
+
+    /// Number of content hashes currently suppressing repeats.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.seen.lock().len()
+    }
+
+    /// Whether no content hashes are currently tracked.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.seen.lock().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::domain::id::{MarketId, TokenId};
+    use crate::domain::opportunity::OpportunityLeg;
+
+    fn opportunity(market: &str, yes: Decimal, no: Decimal) -> Opportunity {
+        Opportunity::new(
+            MarketId::new(market),
+            "Dedup?",
+            vec![
+                OpportunityLeg::new(TokenId::new("yes"), yes),
+                OpportunityLeg::new(TokenId::new("no"), no),
+            ],
+            dec!(100),
+            dec!(1.00),
+        )
+    }
+
+    fn tracker() -> DedupTracker {
+        DedupTracker::new(Duration::from_millis(1000), dec!(0.005))
+    }
+
+    #[test]
+    fn repeat_within_window_is_suppressed() {
+        let tracker = tracker();
+        let now = Instant::now();
+        let opp = opportunity("m1", dec!(0.45), dec!(0.50));
+
+        assert!(tracker.is_new_at(&opp, now));
+        assert!(!tracker.is_new_at(&opp, now + Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn repeat_after_window_is_new() {
+        let tracker = tracker();
+        let now = Instant::now();
+        let opp = opportunity("m1", dec!(0.45), dec!(0.50));
+
+        assert!(tracker.is_new_at(&opp, now));
+        assert!(!tracker.is_new_at(&opp, now + Duration::from_millis(900)));
+        // Repeats do not extend the window
+        assert!(tracker.is_new_at(&opp, now + Duration::from_millis(1000)));
+    }
+
+    #[test]
+    fn small_edge_change_within_bucket_is_suppressed() {
+        let tracker = tracker();
+        let now = Instant::now();
+
+        // Edges 0.051 and 0.053 share the [0.050, 0.055) bucket
+        assert!(tracker.is_new_at(&opportunity("m1", dec!(0.449), dec!(0.50)), now));
+        assert!(!tracker.is_new_at(&opportunity("m1", dec!(0.447), dec!(0.50)), now));
+    }
+
+    #[test]
+    fn edge_crossing_bucket_is_new() {
+        let tracker = tracker();
+        let now = Instant::now();
+
+        assert!(tracker.is_new_at(&opportunity("m1", dec!(0.45), dec!(0.50)), now));
+        assert!(tracker.is_new_at(&opportunity("m1", dec!(0.44), dec!(0.50)), now));
+    }
+
+    #[test]
+    fn different_markets_are_distinct() {
+        let tracker = tracker();
+        let now = Instant::now();
+
+        assert!(tracker.is_new_at(&opportunity("m1", dec!(0.45), dec!(0.50)), now));
+        assert!(tracker.is_new_at(&opportunity("m2", dec!(0.45), dec!(0.50)), now));
+        assert_eq!(tracker.len(), 2);
+    }
+
+    #[test]
+    fn disabled_tracker_reports_every_opportunity() {
+        let tracker = DedupTracker::disabled();
+        let opp = opportunity("m1", dec!(0.45), dec!(0.50));
+
+        assert!(tracker.is_new(&opp));
+        assert!(tracker.is_new(&opp));
+        assert!(tracker.is_empty());
+    }
+
+    #[test]
+    fn expired_hashes_are_evicted() {
+        let tracker = tracker();
+        let now = Instant::now();
+
+        tracker.is_new_at(&opportunity("m1", dec!(0.45), dec!(0.50)), now);
+        tracker.is_new_at(
+            &opportunity("m2", dec!(0.45), dec!(0.50)),
+            now + Duration::from_millis(1500),
+        );
+
+        assert_eq!(tracker.len(), 1);
+    }
+}
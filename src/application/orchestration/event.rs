@@ -3,7 +3,7 @@
 //! Processes incoming market events and triggers appropriate actions:
 //! order book updates, strategy detection, and position settlements.
 
-use std::time::Instant;
+use std::time::Duration;
 
 use rust_decimal::Decimal;
 use tracing::{debug, info, warn};
@@ -11,60 +11,41 @@ use tracing::{debug, info, warn};
 use super::context::MarketDetectionContext;
 use super::handler::handle_opportunity;
 use super::handler::MarketEventHandlingContext;
+use super::shadow::record_shadow_opportunities;
 use crate::application::position::manager::{CloseReason, PositionManager};
-use crate::port::outbound::exchange::MarketEvent;
+use crate::application::sampling::LogSampler;
+use crate::domain::id::TokenId;
+use crate::port::outbound::exchange::{DisconnectReason, MarketEvent};
+use crate::port::outbound::notifier::Event;
+
+/// Sampler for the per-event detection summary.
+static DETECTION_LOGS: LogSampler = LogSampler::new();
 
 /// Process an incoming market event from the data stream.
 ///
 /// Dispatches to the appropriate handler based on event type:
-/// - Book snapshots/deltas: Update cache and run strategy detection
+/// - Book snapshots: Replace the cached book and run strategy detection
+/// - Book deltas: Merge into the cached book and run strategy detection
 /// - Market settled: Close all positions for the market
-/// - Connection events: Log status changes
-pub(crate) fn handle_market_event(event: MarketEvent, context: MarketEventHandlingContext<'_>) {
-    let start = Instant::now();
-
+/// - Connection events: Log status changes, alerting on rate limits and
+///   authentication failures
+///
+/// Returns the detection latency for book events: the time from the event's
+/// receipt to the end of strategy detection. Risk checks and execution that
+/// follow are not included. Other events, and book events skipped because
+/// the market's books did not change, return `None`.
+pub(crate) fn handle_market_event(
+    event: MarketEvent,
+    context: MarketEventHandlingContext<'_>,
+) -> Option<Duration> {
     match event {
         MarketEvent::BookSnapshot { token_id, book } => {
             context.cache.update(book);
-
-            if let Some(market) = context.registry.get_by_token(&token_id) {
-                let ctx = MarketDetectionContext::new(market, context.cache);
-                let opportunities = context.strategies.detect_opportunities(&ctx);
-
-                debug!(
-                    market_id = %market.market_id(),
-                    opportunities_found = opportunities.len(),
-                    "Strategy detection complete (snapshot)"
-                );
-
-                for opp in opportunities {
-                    handle_opportunity(opp, context.opportunity_context());
-                }
-            }
-
-            let elapsed = start.elapsed();
-            context.stats.record_latency(elapsed.as_millis() as u32);
+            detect_and_handle(&token_id, "snapshot", &context)
         }
         MarketEvent::BookDelta { token_id, book } => {
-            context.cache.update(book);
-
-            if let Some(market) = context.registry.get_by_token(&token_id) {
-                let ctx = MarketDetectionContext::new(market, context.cache);
-                let opportunities = context.strategies.detect_opportunities(&ctx);
-
-                debug!(
-                    market_id = %market.market_id(),
-                    opportunities_found = opportunities.len(),
-                    "Strategy detection complete (delta)"
-                );
-
-                for opp in opportunities {
-                    handle_opportunity(opp, context.opportunity_context());
-                }
-            }
-
-            let elapsed = start.elapsed();
-            context.stats.record_latency(elapsed.as_millis() as u32);
+            context.cache.apply_delta(&token_id, &book);
+            detect_and_handle(&token_id, "delta", &context)
         }
         MarketEvent::MarketSettled {
             market_id,
@@ -95,12 +76,81 @@ pub(crate) fn handle_market_event(event: MarketEvent, context: MarketEventHandli
                     "Positions settled"
                 );
             }
+            None
         }
         MarketEvent::Connected => {
             info!("Data stream connected");
+            None
         }
         MarketEvent::Disconnected { reason } => {
             warn!(reason = %reason, "Data stream disconnected");
+            let cause = DisconnectReason::classify(&reason);
+            if cause != DisconnectReason::Transient {
+                context
+                    .notifiers
+                    .notify_all(Event::StreamDisconnected { cause, reason });
+            }
+            None
         }
     }
 }
+
+/// Run detection for the market owning `token_id` and handle what it finds.
+///
+/// Detection is skipped, returning `None`, when the token belongs to no
+/// registered market, when none of the market's tokens changed their book
+/// since its last detection, or when any of them has a crossed or locked
+/// book whose prices cannot be traded. Opportunities are handled in the
+/// configured priority order, so the most valuable ones claim the exposure
+/// budget first. A market that yielded opportunities is always detected again
+/// on its next update, so one dropped for a passing reason is retried.
+/// Latency is only reported for events that ran detection, and is measured
+/// before opportunities are handled so that risk checks, stats writes,
+/// execution, and shadow detection do not count towards it.
+fn detect_and_handle(
+    token_id: &TokenId,
+    update: &str,
+    context: &MarketEventHandlingContext<'_>,
+) -> Option<Duration> {
+    let market = context.registry.get_by_token(token_id)?;
+    if market
+        .token_ids()
+        .into_iter()
+        .any(|token| context.cache.is_crossed(token))
+    {
+        debug!(market_id = %market.market_id(), "Skipping detection on crossed book");
+        return None;
+    }
+    if !context.versions.should_detect(market, context.cache) {
+        return None;
+    }
+    let ctx = MarketDetectionContext::new(market, context.cache);
+    let mut opportunities = context.strategies.detect_opportunities(&ctx);
+    if DETECTION_LOGS.sample() {
+        debug!(
+            market_id = %market.market_id(),
+            opportunities_found = opportunities.len(),
+            update,
+            "Strategy detection complete"
+        );
+    }
+    let latency = context.received_at.elapsed();
+
+    if !opportunities.is_empty() {
+        context.versions.forget(market.market_id());
+    }
+    context.priority.sort(&mut opportunities);
+    for opp in opportunities {
+        handle_opportunity(opp, context.opportunity_context());
+    }
+    if let Some(shadow) = context.shadow {
+        record_shadow_opportunities(market, shadow, context);
+    }
+
+    context.stats.record_latency(latency_millis(latency));
+    Some(latency)
+}
+
+fn latency_millis(latency: Duration) -> u32 {
+    u32::try_from(latency.as_millis()).unwrap_or(u32::MAX)
+}
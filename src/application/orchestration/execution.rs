@@ -5,20 +5,23 @@
 
 use std::sync::Arc;
 
-use tokio::time::{timeout, Duration};
-use tracing::{error, info, warn};
+use tokio::time::{sleep, timeout, Duration};
+use tracing::{debug, error, info, info_span, warn, Instrument};
 
+use super::concurrency::ExecutionSlot;
 use super::position::{record_partial_position, record_position};
+use crate::application::position::manager::PositionManager;
+use crate::application::risk::manager::RiskManager;
 use crate::application::state::AppState;
 use crate::domain::id::OrderId;
 use crate::domain::opportunity::Opportunity;
 use crate::domain::stats::{TradeLeg, TradeOpenEvent};
-use crate::domain::trade::TradeResult;
+use crate::domain::trade::{FailureKind, TradeResult};
 use crate::port::outbound::notifier::NotifierRegistry;
 use crate::port::outbound::stats::StatsRecorder;
 use crate::port::{
     outbound::exchange::ArbitrageExecutor, outbound::notifier::Event,
-    outbound::notifier::ExecutionEvent,
+    outbound::notifier::ExecutionEvent, outbound::notifier::RiskEvent,
 };
 
 /// RAII guard that releases the execution lock when dropped.
@@ -45,6 +48,28 @@ impl Drop for ExecutionLockGuard {
     }
 }
 
+/// Everything an execution task needs besides the opportunity itself.
+pub(crate) struct ExecutionContext {
+    /// Executor submitting the orders.
+    pub executor: Arc<dyn ArbitrageExecutor + Send + Sync>,
+    /// Notifier registry for execution events.
+    pub notifiers: Arc<NotifierRegistry>,
+    /// Shared application state.
+    pub state: Arc<AppState>,
+    /// Risk manager fed the execution outcome.
+    pub risk_manager: RiskManager,
+    /// Statistics recorder for trade records.
+    pub stats: Arc<dyn StatsRecorder>,
+    /// Position manager recording executed positions.
+    pub position_manager: Arc<PositionManager>,
+    /// Stats ID of the recorded opportunity, if it was recorded.
+    pub opportunity_id: Option<i32>,
+    /// Concurrency slot held until the task finishes.
+    pub slot: ExecutionSlot,
+    /// Wait assigned by the execution rate limiter.
+    pub delay: Duration,
+}
+
 /// Spawn asynchronous trade execution without blocking the event loop.
 ///
 /// Executes the opportunity in a background task, handling all outcomes:
@@ -52,19 +77,31 @@ impl Drop for ExecutionLockGuard {
 /// - Partial fill: Attempts cancellation, records partial position if needed
 /// - Failure/timeout: Releases reserved exposure
 ///
-/// Sends execution result notifications regardless of outcome.
-pub(crate) fn spawn_execution(
-    executor: Arc<dyn ArbitrageExecutor + Send + Sync>,
-    opportunity: Opportunity,
-    notifiers: Arc<NotifierRegistry>,
-    state: Arc<AppState>,
-    stats: Arc<dyn StatsRecorder>,
-    opportunity_id: Option<i32>,
-) {
+/// Sends execution result notifications regardless of outcome, and feeds the
+/// outcome to the risk manager's failure circuit breaker. Orders are submitted
+/// after the context's `delay`; an opportunity that outlives the risk
+/// manager's maximum age during that wait is dropped instead. The context's
+/// `slot` is held until the task finishes. The task runs inside a span carrying the opportunity's
+/// `opportunity_id`.
+pub(crate) fn spawn_execution(opportunity: Opportunity, context: ExecutionContext) {
+    let ExecutionContext {
+        executor,
+        notifiers,
+        state,
+        risk_manager,
+        stats,
+        position_manager,
+        opportunity_id,
+        slot,
+        delay,
+    } = context;
     let market_id = opportunity.market_id().to_string();
+    let opportunity_uid = opportunity.id().to_string();
+    let span = info_span!("execution", opportunity_id = %opportunity_uid, market_id = %market_id);
 
-    tokio::spawn(async move {
+    let task = async move {
         let _lock_guard = ExecutionLockGuard::new(Arc::clone(&state), market_id.clone());
+        let _slot = slot;
         // Calculate reserved exposure for release
         let reserved_exposure = opportunity.total_cost() * opportunity.volume();
 
@@ -74,6 +111,26 @@ pub(crate) fn spawn_execution(
         #[cfg(not(test))]
         let execution_timeout = Duration::from_secs(state.risk_limits().execution_timeout_secs);
 
+        if !delay.is_zero() {
+            debug!(market_id = %market_id, delay_ms = delay.as_millis(), "Waiting for order rate limit");
+            sleep(delay).await;
+
+            // The opportunity may have aged out while waiting for its turn.
+            if let Err(error) = risk_manager.check_opportunity_age(&opportunity) {
+                warn!(reason = %error, "Opportunity aged out during rate limit wait, not executing");
+                state.release_exposure(reserved_exposure);
+                if let Some(opp_id) = opportunity_id {
+                    stats.record_opportunity_rejected(opp_id, &error.to_string());
+                }
+                notifiers.notify_all(Event::RiskRejected(RiskEvent::new(
+                    &opportunity_uid,
+                    &market_id,
+                    &error,
+                )));
+                return;
+            }
+        }
+
         let result = timeout(execution_timeout, executor.execute_arbitrage(&opportunity)).await;
 
         match result {
@@ -88,7 +145,7 @@ pub(crate) fn spawn_execution(
                                     .iter()
                                     .map(|leg| TradeLeg {
                                         token_id: leg.token_id().to_string(),
-                                        side: "buy".to_string(),
+                                        side: leg.side().label().to_string(),
                                         price: leg.ask_price(),
                                         size: opportunity.volume(),
                                     })
@@ -96,6 +153,7 @@ pub(crate) fn spawn_execution(
 
                                 stats.record_trade_open(&TradeOpenEvent {
                                     opportunity_id: opp_id,
+                                    opportunity_uid: opportunity_uid.clone(),
                                     strategy: opportunity.strategy().to_string(),
                                     market_ids: vec![market_id.clone()],
                                     legs,
@@ -107,7 +165,7 @@ pub(crate) fn spawn_execution(
                             };
 
                             // Record position with trade_id for close tracking
-                            record_position(&state, &opportunity, trade_id);
+                            record_position(&state, &position_manager, &opportunity, trade_id);
 
                             // Release reserved exposure (now converted to actual position exposure)
                             state.release_exposure(reserved_exposure);
@@ -143,6 +201,7 @@ pub(crate) fn spawn_execution(
                                 warn!("Some cancellations failed, recording partial position");
                                 record_partial_position(
                                     &state,
+                                    &position_manager,
                                     &opportunity,
                                     fills,
                                     failures,
@@ -158,6 +217,14 @@ pub(crate) fn spawn_execution(
                                 state.release_exposure(reserved_exposure);
                             }
                         }
+                        TradeResult::Unwound { net_cost, .. } => {
+                            warn!(
+                                net_cost = %net_cost,
+                                "Partial fill unwound, no position recorded"
+                            );
+                            // Release reserved exposure (filled legs were sold back)
+                            state.release_exposure(reserved_exposure);
+                        }
                         TradeResult::Failed { .. } => {
                             // Release reserved exposure on failure
                             state.release_exposure(reserved_exposure);
@@ -166,19 +233,29 @@ pub(crate) fn spawn_execution(
 
                     // Notify execution result
                     notifiers.notify_all(Event::ExecutionCompleted(ExecutionEvent::from_result(
+                        &opportunity_uid,
                         &market_id,
                         &exec_result,
                     )));
+                    record_outcome(
+                        &risk_manager,
+                        &notifiers,
+                        &market_id,
+                        exec_result.is_success(),
+                    );
                 }
                 Err(e) => {
                     error!(error = %e, "Execution failed");
                     // Release reserved exposure on error
                     state.release_exposure(reserved_exposure);
                     notifiers.notify_all(Event::ExecutionCompleted(ExecutionEvent {
-                        market_id,
+                        opportunity_id: opportunity_uid,
+                        market_id: market_id.clone(),
                         success: false,
                         details: e.to_string(),
+                        failure_kind: None,
                     }));
+                    record_outcome(&risk_manager, &notifiers, &market_id, false);
                 }
             },
             Err(_) => {
@@ -186,33 +263,62 @@ pub(crate) fn spawn_execution(
                 // Release reserved exposure on timeout
                 state.release_exposure(reserved_exposure);
                 notifiers.notify_all(Event::ExecutionCompleted(ExecutionEvent {
-                    market_id,
+                    opportunity_id: opportunity_uid,
+                    market_id: market_id.clone(),
                     success: false,
                     details: "execution_timeout".to_string(),
+                    failure_kind: Some(FailureKind::Timeout),
                 }));
+                record_outcome(&risk_manager, &notifiers, &market_id, false);
             }
         }
-    });
+    };
+
+    tokio::spawn(task.instrument(span));
+}
+
+/// Feed an execution outcome to the failure breaker, alerting if it trips.
+///
+/// A failure also starts the market's cooldown.
+fn record_outcome(
+    risk_manager: &RiskManager,
+    notifiers: &NotifierRegistry,
+    market_id: &str,
+    succeeded: bool,
+) {
+    if !succeeded {
+        risk_manager.record_market_failure(market_id);
+    }
+    if let Some(reason) = risk_manager.record_execution_outcome(succeeded) {
+        notifiers.notify_all(Event::CircuitBreakerActivated { reason });
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::spawn_execution;
+    use super::{spawn_execution, ExecutionContext};
     use std::future::pending;
     use std::sync::Arc;
 
     use async_trait::async_trait;
+    use rust_decimal::Decimal;
     use rust_decimal_macros::dec;
     use tokio::time::{sleep, Duration, Instant};
 
+    use crate::application::orchestration::concurrency::ExecutionSlot;
+    use crate::application::position::manager::PositionManager;
+    use crate::application::risk::breaker::FailureBreaker;
+    use crate::application::risk::manager::RiskManager;
     use crate::application::state::AppState;
     use crate::domain::id::{MarketId, OrderId, TokenId};
     use crate::domain::opportunity::{Opportunity, OpportunityLeg};
     use crate::domain::position::PositionStatus;
+    use crate::domain::stats::{RecordedOpportunity, PRIMARY_VARIANT};
     use crate::domain::trade::{Failure, Fill, TradeResult};
     use crate::error::{Error, ExecutionError};
-    use crate::port::outbound::exchange::ArbitrageExecutor;
-    use crate::port::outbound::notifier::NotifierRegistry;
+    use crate::port::outbound::exchange::{ArbitrageExecutor, ExecutionResult};
+    use crate::port::outbound::notifier::{Event, NotifierRegistry};
+    use crate::testkit::notifier::RecordingNotifier;
     use crate::testkit::stats::in_memory_stats_recorder;
 
     /// Mock executor that returns PartialFill and fails cancel on one leg.
@@ -236,6 +342,15 @@ mod tests {
             })
         }
 
+        async fn sell(
+            &self,
+            _token_id: &TokenId,
+            _size: Decimal,
+            _limit: Decimal,
+        ) -> Result<ExecutionResult, Error> {
+            unimplemented!("not used by these tests")
+        }
+
         async fn cancel(&self, order_id: &OrderId) -> Result<(), Error> {
             if self
                 .cancel_fail_order_ids
@@ -267,6 +382,16 @@ mod tests {
             unreachable!("pending should never resolve");
         }
 
+        async fn sell(
+            &self,
+            _token_id: &TokenId,
+            _size: Decimal,
+            _limit: Decimal,
+        ) -> Result<ExecutionResult, Error> {
+            pending::<()>().await;
+            unreachable!("pending should never resolve");
+        }
+
         async fn cancel(&self, _order_id: &OrderId) -> Result<(), Error> {
             Ok(())
         }
@@ -300,12 +425,18 @@ mod tests {
         let stats = in_memory_stats_recorder();
 
         spawn_execution(
-            executor,
             opportunity.clone(),
-            notifiers,
-            state.clone(),
-            stats,
-            None,
+            ExecutionContext {
+                executor,
+                notifiers,
+                state: state.clone(),
+                risk_manager: RiskManager::new(state.clone()),
+                stats: Arc::clone(&stats),
+                position_manager: Arc::new(PositionManager::new(stats)),
+                opportunity_id: None,
+                slot: ExecutionSlot::default(),
+                delay: Duration::ZERO,
+            },
         );
 
         let start = Instant::now();
@@ -384,7 +515,20 @@ mod tests {
 
         assert!(state.try_lock_execution("timeout-market"));
 
-        spawn_execution(executor, opportunity, notifiers, state.clone(), stats, None);
+        spawn_execution(
+            opportunity,
+            ExecutionContext {
+                executor,
+                notifiers,
+                state: state.clone(),
+                risk_manager: RiskManager::new(state.clone()),
+                stats: Arc::clone(&stats),
+                position_manager: Arc::new(PositionManager::new(stats)),
+                opportunity_id: None,
+                slot: ExecutionSlot::default(),
+                delay: Duration::ZERO,
+            },
+        );
 
         let start = Instant::now();
         let timeout = Duration::from_secs(1);
@@ -399,4 +543,167 @@ mod tests {
             sleep(Duration::from_millis(10)).await;
         }
     }
+
+    #[tokio::test]
+    async fn opportunity_aged_out_during_rate_limit_wait_is_not_executed() {
+        let opportunity = Opportunity::with_strategy(
+            MarketId::from("aged-market"),
+            "Aged test?",
+            vec![
+                OpportunityLeg::new(TokenId::from("token-1"), dec!(0.40)),
+                OpportunityLeg::new(TokenId::from("token-2"), dec!(0.50)),
+            ],
+            dec!(100),
+            dec!(1.00),
+            "test-strategy",
+        );
+
+        let state = Arc::new(AppState::default());
+        let (notifier, notifiers) = RecordingNotifier::registry();
+        let stats = in_memory_stats_recorder();
+
+        assert!(state.try_lock_execution("aged-market"));
+        assert!(state.try_reserve_exposure(dec!(90)));
+        let opportunity_id = stats.record_opportunity(&RecordedOpportunity {
+            uid: opportunity.id().to_string(),
+            strategy: opportunity.strategy().to_string(),
+            market_ids: vec!["aged-market".to_string()],
+            edge: opportunity.edge(),
+            expected_profit: opportunity.expected_profit(),
+            executed: true,
+            rejected_reason: None,
+            variant: PRIMARY_VARIANT.to_string(),
+            content_hash: None,
+        });
+        assert!(opportunity_id.is_some());
+
+        // Fresh when spawned, but older than the maximum age once the wait ends.
+        spawn_execution(
+            opportunity,
+            ExecutionContext {
+                executor: Arc::new(MockHangingExecutor),
+                notifiers,
+                state: state.clone(),
+                risk_manager: RiskManager::new(state.clone())
+                    .with_max_opportunity_age(Duration::from_millis(50)),
+                stats: Arc::clone(&stats),
+                position_manager: Arc::new(PositionManager::new(Arc::clone(&stats))),
+                opportunity_id,
+                slot: ExecutionSlot::default(),
+                delay: Duration::from_millis(100),
+            },
+        );
+
+        sleep(Duration::from_millis(150)).await;
+
+        assert!(state.try_lock_execution("aged-market"));
+        assert_eq!(state.pending_exposure(), dec!(0));
+        let events = notifier.events();
+        let [Event::RiskRejected(rejected)] = events.as_slice() else {
+            panic!("expected only a risk rejection, got {events:?}");
+        };
+        assert!(rejected.reason.starts_with("opportunity_stale"));
+        let today = stats.get_today();
+        assert_eq!(today.opportunities_executed, 0);
+        assert_eq!(today.opportunities_rejected, 1);
+    }
+
+    #[tokio::test]
+    async fn execution_failure_starts_market_cooldown() {
+        let executor = Arc::new(MockHangingExecutor);
+        let opportunity = Opportunity::with_strategy(
+            MarketId::from("cooldown-market"),
+            "Cooldown test?",
+            vec![
+                OpportunityLeg::new(TokenId::from("token-1"), dec!(0.40)),
+                OpportunityLeg::new(TokenId::from("token-2"), dec!(0.50)),
+            ],
+            dec!(100),
+            dec!(1.00),
+            "test-strategy",
+        );
+
+        let state = Arc::new(AppState::default());
+        let notifiers = Arc::new(NotifierRegistry::new());
+        let stats = in_memory_stats_recorder();
+
+        spawn_execution(
+            opportunity,
+            ExecutionContext {
+                executor,
+                notifiers,
+                state: state.clone(),
+                risk_manager: RiskManager::new(state.clone())
+                    .with_market_cooldown(Duration::from_secs(60)),
+                stats: Arc::clone(&stats),
+                position_manager: Arc::new(PositionManager::new(stats)),
+                opportunity_id: None,
+                slot: ExecutionSlot::default(),
+                delay: Duration::ZERO,
+            },
+        );
+
+        let start = Instant::now();
+        let timeout = Duration::from_secs(1);
+        while state
+            .market_cooldown_until("cooldown-market", std::time::Instant::now())
+            .is_none()
+        {
+            if start.elapsed() > timeout {
+                panic!("Market cooldown was not started after a failed execution");
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn execution_failure_trips_failure_breaker() {
+        let executor = Arc::new(MockHangingExecutor);
+        let opportunity = Opportunity::with_strategy(
+            MarketId::from("breaker-market"),
+            "Breaker test?",
+            vec![
+                OpportunityLeg::new(TokenId::from("token-1"), dec!(0.40)),
+                OpportunityLeg::new(TokenId::from("token-2"), dec!(0.50)),
+            ],
+            dec!(100),
+            dec!(1.00),
+            "test-strategy",
+        );
+
+        let state = Arc::new(
+            AppState::default().with_failure_breaker(FailureBreaker::new(
+                1,
+                Duration::from_secs(60),
+                Duration::from_secs(300),
+            )),
+        );
+        let notifiers = Arc::new(NotifierRegistry::new());
+        let stats = in_memory_stats_recorder();
+
+        spawn_execution(
+            opportunity,
+            ExecutionContext {
+                executor,
+                notifiers,
+                state: state.clone(),
+                risk_manager: RiskManager::new(state.clone()),
+                stats: Arc::clone(&stats),
+                position_manager: Arc::new(PositionManager::new(stats)),
+                opportunity_id: None,
+                slot: ExecutionSlot::default(),
+                delay: Duration::ZERO,
+            },
+        );
+
+        let start = Instant::now();
+        let timeout = Duration::from_secs(1);
+        while state.is_trading_active() {
+            if start.elapsed() > timeout {
+                panic!("Failure breaker did not trip after execution timeout");
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert!(state.breaker().is_tripped());
+    }
 }
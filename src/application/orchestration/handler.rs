@@ -4,12 +4,21 @@
 //! delegating to internal modules for the actual implementation.
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crate::application::cache::book::BookCache;
+use crate::application::orchestration::concurrency::ExecutionSlots;
+use crate::application::orchestration::dedup::DedupTracker;
+use crate::application::orchestration::priority::OpportunityPriority;
+use crate::application::orchestration::throttle::RateLimiter;
+use crate::application::orchestration::versions::BookVersionTracker;
 use crate::application::position::manager::PositionManager;
 use crate::application::risk::manager::RiskManager;
 use crate::application::state::AppState;
-use crate::domain::{market::MarketRegistry, opportunity::Opportunity};
+use crate::domain::{
+    market::{Market, MarketRegistry},
+    opportunity::Opportunity,
+};
 use crate::port::inbound::strategy::StrategyEngine;
 use crate::port::outbound::exchange::{ArbitrageExecutor, MarketEvent};
 use crate::port::outbound::notifier::NotifierRegistry;
@@ -38,8 +47,39 @@ pub struct MarketEventHandlingContext<'a> {
     pub stats: &'a Arc<dyn StatsRecorder>,
     /// Position manager for settlement handling.
     pub position_manager: &'a Arc<PositionManager>,
+    /// Tracker suppressing repeat detections of the same opportunity.
+    pub dedup: &'a DedupTracker,
+    /// Book versions seen at each market's last detection.
+    pub versions: &'a BookVersionTracker,
+    /// Limiter pacing execution submissions.
+    pub rate_limiter: &'a RateLimiter,
+    /// Slots capping simultaneous executions.
+    pub execution_slots: &'a ExecutionSlots,
+    /// Whether to re-price opportunities from the current top of book
+    /// before executing them.
+    pub verify_before_execute: bool,
+    /// Order in which opportunities from one event are handled.
+    pub priority: OpportunityPriority,
+    /// When the event was received from the data stream.
+    pub received_at: Instant,
     /// Whether to skip actual execution (log only).
     pub dry_run: bool,
+    /// Shadow strategy set compared against the primary one, if configured.
+    pub shadow: Option<ShadowDetection<'a>>,
+}
+
+/// Secondary strategy set evaluated alongside the primary one.
+///
+/// Opportunities it detects are recorded under `variant` for comparison but
+/// never executed.
+#[derive(Clone, Copy)]
+pub struct ShadowDetection<'a> {
+    /// Variant label recorded with each shadow opportunity.
+    pub variant: &'a str,
+    /// Strategy engine built from the shadow configuration.
+    pub strategies: &'a dyn StrategyEngine,
+    /// Tracker suppressing repeat shadow detections.
+    pub dedup: &'a DedupTracker,
 }
 
 /// Context containing dependencies required to process a detected opportunity.
@@ -57,8 +97,18 @@ pub(crate) struct OpportunityHandlingContext<'a> {
     pub state: &'a Arc<AppState>,
     /// Statistics recorder.
     pub stats: &'a Arc<dyn StatsRecorder>,
+    /// Position manager recording executed positions.
+    pub position_manager: &'a Arc<PositionManager>,
     /// Order book cache for slippage checks.
     pub cache: &'a BookCache,
+    /// Tracker suppressing repeat detections.
+    pub dedup: &'a DedupTracker,
+    /// Limiter pacing execution submissions.
+    pub rate_limiter: &'a RateLimiter,
+    /// Slots capping simultaneous executions.
+    pub execution_slots: &'a ExecutionSlots,
+    /// Whether to re-confirm the edge before execution.
+    pub verify_before_execute: bool,
     /// Whether to skip actual execution.
     pub dry_run: bool,
 }
@@ -72,7 +122,12 @@ impl<'a> MarketEventHandlingContext<'a> {
             notifiers: self.notifiers,
             state: self.state,
             stats: self.stats,
+            position_manager: self.position_manager,
             cache: self.cache,
+            dedup: self.dedup,
+            rate_limiter: self.rate_limiter,
+            execution_slots: self.execution_slots,
+            verify_before_execute: self.verify_before_execute,
             dry_run: self.dry_run,
         }
     }
@@ -80,9 +135,27 @@ impl<'a> MarketEventHandlingContext<'a> {
 
 /// Process an incoming market event from the data stream.
 ///
-/// Delegates to the internal event module for actual processing.
-pub(crate) fn handle_market_event(event: MarketEvent, context: MarketEventHandlingContext<'_>) {
-    super::event::handle_market_event(event, context);
+/// Delegates to the internal event module for actual processing. Returns the
+/// detection latency for order book events that ran detection, measured from
+/// `received_at`.
+pub(crate) fn handle_market_event(
+    event: MarketEvent,
+    context: MarketEventHandlingContext<'_>,
+) -> Option<Duration> {
+    super::event::handle_market_event(event, context)
+}
+
+/// Run strategy detection once for a market against the current cache.
+///
+/// Performs detection only: no dedup, risk checks, or execution. Used by
+/// diagnostics that want to inspect what the strategies currently see.
+pub fn detect_market_opportunities(
+    market: &Market,
+    cache: &BookCache,
+    strategies: &dyn StrategyEngine,
+) -> Vec<Opportunity> {
+    let ctx = super::context::MarketDetectionContext::new(market, cache);
+    strategies.detect_opportunities(&ctx)
 }
 
 /// Process a detected opportunity through risk checks and execution.
@@ -95,6 +168,7 @@ pub(crate) fn handle_opportunity(opp: Opportunity, context: OpportunityHandlingC
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
+    use std::time::{Duration, Instant};
 
     use rust_decimal::Decimal;
     use rust_decimal_macros::dec;
@@ -108,8 +182,9 @@ mod tests {
     use crate::domain::book::{Book, PriceLevel};
     use crate::domain::id::{MarketId, TokenId};
     use crate::domain::market::{Market, Outcome};
-    use crate::domain::opportunity::{Opportunity, OpportunityLeg};
-    use crate::port::outbound::notifier::NotifierRegistry;
+    use crate::domain::opportunity::{Opportunity, OpportunityKind, OpportunityLeg};
+    use crate::port::outbound::notifier::{Event, NotifierRegistry};
+    use crate::testkit::notifier::RecordingNotifier;
     use crate::testkit::stats::in_memory_stats_recorder;
 
     fn make_order_book(token_id: &str, bid: Decimal, ask: Decimal) -> Book {
@@ -274,7 +349,12 @@ mod tests {
                 notifiers: &notifiers,
                 state: &state,
                 stats: &stats,
+                position_manager: &Arc::new(PositionManager::new(Arc::clone(&stats))),
                 cache: &cache,
+                dedup: &DedupTracker::disabled(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
                 dry_run: true,
             },
         );
@@ -308,7 +388,12 @@ mod tests {
                 notifiers: &notifiers,
                 state: &state,
                 stats: &stats,
+                position_manager: &Arc::new(PositionManager::new(Arc::clone(&stats))),
                 cache: &cache,
+                dedup: &DedupTracker::disabled(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
                 dry_run: true,
             },
         );
@@ -320,6 +405,378 @@ mod tests {
         );
     }
 
+    #[test]
+    fn handle_opportunity_rejects_stale_book() {
+        let state = Arc::new(AppState::new(RiskLimits {
+            max_book_age_ms: Some(1_000),
+            ..Default::default()
+        }));
+        let notifiers = Arc::new(NotifierRegistry::new());
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let cache = BookCache::new();
+
+        let Some(aged) = Instant::now().checked_sub(Duration::from_secs(5)) else {
+            return;
+        };
+        cache.update(make_order_book("yes-token", dec!(0.39), dec!(0.40)));
+        cache.update_at(make_order_book("no-token", dec!(0.49), dec!(0.50)), aged);
+
+        handle_opportunity(
+            make_test_opportunity(),
+            OpportunityHandlingContext {
+                executor: None,
+                risk_manager: &risk_manager,
+                notifiers: &notifiers,
+                state: &state,
+                stats: &stats,
+                position_manager: &Arc::new(PositionManager::new(Arc::clone(&stats))),
+                cache: &cache,
+                dedup: &DedupTracker::disabled(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                dry_run: true,
+            },
+        );
+
+        let today = stats.get_today();
+        assert_eq!(today.opportunities_rejected, 1);
+        assert_eq!(today.opportunities_executed, 0);
+        assert!(
+            state.try_lock_execution("test-market"),
+            "Lock should be released after stale book rejection"
+        );
+    }
+
+    #[test]
+    fn handle_opportunity_rejects_when_edge_disappears_before_execution() {
+        let (notifier, notifiers) = RecordingNotifier::registry();
+        // Tolerant enough that each leg's move alone passes the slippage check.
+        let state = Arc::new(AppState::new(RiskLimits {
+            max_slippage: dec!(0.20),
+            ..Default::default()
+        }));
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let cache = BookCache::new();
+
+        // Detected at 0.40 + 0.50; both asks move up before verification.
+        cache.update(make_order_book("yes-token", dec!(0.45), dec!(0.46)));
+        cache.update(make_order_book("no-token", dec!(0.54), dec!(0.55)));
+
+        handle_opportunity(
+            make_test_opportunity(),
+            OpportunityHandlingContext {
+                executor: None,
+                risk_manager: &risk_manager,
+                notifiers: &notifiers,
+                state: &state,
+                stats: &stats,
+                position_manager: &Arc::new(PositionManager::new(Arc::clone(&stats))),
+                cache: &cache,
+                dedup: &DedupTracker::disabled(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: true,
+                dry_run: true,
+            },
+        );
+
+        let today = stats.get_today();
+        assert_eq!(today.opportunities_rejected, 1);
+        assert_eq!(today.opportunities_executed, 0);
+        assert_eq!(state.pending_exposure(), dec!(0));
+        assert!(state.try_lock_execution("test-market"));
+        let events = notifier.events();
+        let Some(Event::RiskRejected(rejected)) = events.last() else {
+            panic!("expected a risk rejection, got {events:?}");
+        };
+        assert!(rejected.reason.starts_with("edge_disappeared"));
+    }
+
+    #[test]
+    fn handle_opportunity_rejects_when_a_book_empties_before_execution() {
+        let (notifier, notifiers) = RecordingNotifier::registry();
+        let state = Arc::new(AppState::default());
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let cache = BookCache::new();
+
+        cache.update(make_order_book("yes-token", dec!(0.39), dec!(0.40)));
+        cache.update(Book::with_levels(TokenId::from("no-token"), vec![], vec![]));
+
+        handle_opportunity(
+            make_test_opportunity(),
+            OpportunityHandlingContext {
+                executor: None,
+                risk_manager: &risk_manager,
+                notifiers: &notifiers,
+                state: &state,
+                stats: &stats,
+                position_manager: &Arc::new(PositionManager::new(Arc::clone(&stats))),
+                cache: &cache,
+                dedup: &DedupTracker::disabled(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: true,
+                dry_run: true,
+            },
+        );
+
+        assert_eq!(stats.get_today().opportunities_rejected, 1);
+        assert_eq!(state.pending_exposure(), dec!(0));
+        assert!(state.try_lock_execution("test-market"));
+        let events = notifier.events();
+        let Some(Event::RiskRejected(rejected)) = events.last() else {
+            panic!("expected a risk rejection, got {events:?}");
+        };
+        assert!(rejected.reason.starts_with("edge_disappeared"));
+    }
+
+    #[test]
+    fn handle_opportunity_drops_aged_opportunity_before_execution() {
+        let (notifier, notifiers) = RecordingNotifier::registry();
+        let state = Arc::new(AppState::default());
+        let risk_manager = RiskManager::new(Arc::clone(&state))
+            .with_max_opportunity_age(Duration::from_millis(100));
+        let stats = in_memory_stats_recorder();
+        let cache = BookCache::new();
+
+        cache.update(make_order_book("yes-token", dec!(0.39), dec!(0.40)));
+        cache.update(make_order_book("no-token", dec!(0.49), dec!(0.50)));
+
+        // Detected a second ago, e.g. after waiting in the cluster queue.
+        let aged =
+            make_test_opportunity().with_detected_at(Instant::now() - Duration::from_secs(1));
+
+        handle_opportunity(
+            aged,
+            OpportunityHandlingContext {
+                executor: None,
+                risk_manager: &risk_manager,
+                notifiers: &notifiers,
+                state: &state,
+                stats: &stats,
+                position_manager: &Arc::new(PositionManager::new(Arc::clone(&stats))),
+                cache: &cache,
+                dedup: &DedupTracker::disabled(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                dry_run: true,
+            },
+        );
+
+        let today = stats.get_today();
+        assert_eq!(today.opportunities_rejected, 1);
+        assert_eq!(today.opportunities_executed, 0);
+        assert_eq!(state.pending_exposure(), dec!(0));
+        assert!(state.try_lock_execution("test-market"));
+        let events = notifier.events();
+        let Some(Event::RiskRejected(rejected)) = events.last() else {
+            panic!("expected a risk rejection, got {events:?}");
+        };
+        assert!(rejected.reason.starts_with("opportunity_stale"));
+    }
+
+    #[tokio::test]
+    async fn handle_opportunity_caps_concurrent_executions() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use async_trait::async_trait;
+
+        use crate::domain::id::OrderId;
+        use crate::domain::trade::{Fill, TradeResult};
+        use crate::error::Error;
+        use crate::port::outbound::exchange::ExecutionResult;
+        /// Executor that takes a while to fill and tracks how many
+        /// executions overlap.
+        #[derive(Default)]
+        struct SlowExecutor {
+            in_flight: AtomicUsize,
+            peak: AtomicUsize,
+        }
+
+        #[async_trait]
+        impl ArbitrageExecutor for SlowExecutor {
+            async fn execute_arbitrage(
+                &self,
+                opportunity: &Opportunity,
+            ) -> Result<TradeResult, Error> {
+                let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                self.peak.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(TradeResult::Success {
+                    fills: opportunity
+                        .legs()
+                        .iter()
+                        .map(|leg| Fill::new(leg.token_id().clone(), "order"))
+                        .collect(),
+                })
+            }
+
+            async fn sell(
+                &self,
+                _token_id: &TokenId,
+                _size: Decimal,
+                _limit: Decimal,
+            ) -> Result<ExecutionResult, Error> {
+                unimplemented!("not used by this test")
+            }
+
+            async fn cancel(&self, _order_id: &OrderId) -> Result<(), Error> {
+                Ok(())
+            }
+
+            fn exchange_name(&self) -> &'static str {
+                "slow"
+            }
+        }
+
+        let (notifier, notifiers) = RecordingNotifier::registry();
+        let state = Arc::new(AppState::default());
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let position_manager = Arc::new(PositionManager::new(Arc::clone(&stats)));
+        let cache = BookCache::new();
+        let executor = Arc::new(SlowExecutor::default());
+        let execution_slots = ExecutionSlots::new(2);
+
+        cache.update(make_order_book("yes-token", dec!(0.39), dec!(0.40)));
+        cache.update(make_order_book("no-token", dec!(0.49), dec!(0.50)));
+
+        for market in ["market-a", "market-b", "market-c"] {
+            let opp = Opportunity::new(
+                MarketId::from(market),
+                "Will it rain?",
+                vec![
+                    OpportunityLeg::new(TokenId::from("yes-token"), dec!(0.40)),
+                    OpportunityLeg::new(TokenId::from("no-token"), dec!(0.50)),
+                ],
+                dec!(100),
+                dec!(1.00),
+            );
+            handle_opportunity(
+                opp,
+                OpportunityHandlingContext {
+                    executor: Some(
+                        Arc::clone(&executor) as Arc<dyn ArbitrageExecutor + Send + Sync>
+                    ),
+                    risk_manager: &risk_manager,
+                    notifiers: &notifiers,
+                    state: &state,
+                    stats: &stats,
+                    position_manager: &position_manager,
+                    cache: &cache,
+                    dedup: &DedupTracker::disabled(),
+                    rate_limiter: &RateLimiter::unlimited(),
+                    execution_slots: &execution_slots,
+                    verify_before_execute: false,
+                    dry_run: false,
+                },
+            );
+        }
+
+        assert_eq!(execution_slots.in_flight(), 2);
+        {
+            let events = notifier.events();
+            let Some(Event::RiskRejected(rejected)) = events.last() else {
+                panic!("expected a risk rejection, got {events:?}");
+            };
+            assert!(rejected.reason.starts_with("concurrency_limited"));
+            assert_eq!(rejected.market_id, "market-c");
+        }
+        assert!(state.try_lock_execution("market-c"));
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert_eq!(executor.peak.load(Ordering::SeqCst), 2);
+        assert_eq!(execution_slots.in_flight(), 0);
+        assert_eq!(stats.get_today().opportunities_rejected, 1);
+    }
+
+    #[test]
+    fn handle_opportunity_proceeds_when_edge_is_confirmed() {
+        let state = Arc::new(AppState::default());
+        let notifiers = Arc::new(NotifierRegistry::new());
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let cache = BookCache::new();
+
+        cache.update(make_order_book("yes-token", dec!(0.39), dec!(0.40)));
+        cache.update(make_order_book("no-token", dec!(0.49), dec!(0.50)));
+
+        handle_opportunity(
+            make_test_opportunity(),
+            OpportunityHandlingContext {
+                executor: None,
+                risk_manager: &risk_manager,
+                notifiers: &notifiers,
+                state: &state,
+                stats: &stats,
+                position_manager: &Arc::new(PositionManager::new(Arc::clone(&stats))),
+                cache: &cache,
+                dedup: &DedupTracker::disabled(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: true,
+                dry_run: true,
+            },
+        );
+
+        let today = stats.get_today();
+        assert_eq!(today.opportunities_detected, 1);
+        assert_eq!(today.opportunities_rejected, 0);
+    }
+
+    #[test]
+    fn handle_opportunity_notifies_watch_without_executing() {
+        let (notifier, notifiers) = RecordingNotifier::registry();
+        let state = Arc::new(AppState::default());
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let cache = BookCache::new();
+
+        let opp = Opportunity::new(
+            MarketId::from("test-market"),
+            "Will it rain?",
+            vec![
+                OpportunityLeg::new(TokenId::from("yes-token"), dec!(0.51)),
+                OpportunityLeg::new(TokenId::from("no-token"), dec!(0.50)),
+            ],
+            dec!(100),
+            dec!(1.00),
+        )
+        .with_kind(OpportunityKind::Watch);
+
+        handle_opportunity(
+            opp,
+            OpportunityHandlingContext {
+                executor: None,
+                risk_manager: &risk_manager,
+                notifiers: &notifiers,
+                state: &state,
+                stats: &stats,
+                position_manager: &Arc::new(PositionManager::new(Arc::clone(&stats))),
+                cache: &cache,
+                dedup: &DedupTracker::disabled(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                dry_run: false,
+            },
+        );
+
+        let events = notifier.events();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Event::NearArbitrage(_)));
+        assert_eq!(stats.get_today().opportunities_detected, 0);
+        assert_eq!(state.pending_exposure(), dec!(0));
+        assert!(state.try_lock_execution("test-market"));
+    }
+
     #[test]
     fn handle_opportunity_releases_lock_on_risk_rejection() {
         let state = Arc::new(AppState::new(RiskLimits {
@@ -344,7 +801,12 @@ mod tests {
                 notifiers: &notifiers,
                 state: &state,
                 stats: &stats,
+                position_manager: &Arc::new(PositionManager::new(Arc::clone(&stats))),
                 cache: &cache,
+                dedup: &DedupTracker::disabled(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
                 dry_run: true,
             },
         );
@@ -377,7 +839,12 @@ mod tests {
                 notifiers: &notifiers,
                 state: &state,
                 stats: &stats,
+                position_manager: &Arc::new(PositionManager::new(Arc::clone(&stats))),
                 cache: &cache,
+                dedup: &DedupTracker::disabled(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
                 dry_run: true,
             },
         );
@@ -417,7 +884,12 @@ mod tests {
                 notifiers: &notifiers,
                 state: &state,
                 stats: &stats,
+                position_manager: &Arc::new(PositionManager::new(Arc::clone(&stats))),
                 cache: &cache,
+                dedup: &DedupTracker::disabled(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
                 dry_run: false,
             },
         );
@@ -429,6 +901,142 @@ mod tests {
         );
     }
 
+    #[test]
+    fn handle_opportunity_suppresses_duplicates_within_window() {
+        let state = Arc::new(AppState::default());
+        let notifiers = Arc::new(NotifierRegistry::new());
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let cache = BookCache::new();
+        let dedup = DedupTracker::new(std::time::Duration::from_secs(60), dec!(0.005));
+
+        cache.update(make_order_book("yes-token", dec!(0.39), dec!(0.40)));
+        cache.update(make_order_book("no-token", dec!(0.49), dec!(0.50)));
+
+        for _ in 0..3 {
+            handle_opportunity(
+                make_test_opportunity(),
+                OpportunityHandlingContext {
+                    executor: None,
+                    risk_manager: &risk_manager,
+                    notifiers: &notifiers,
+                    state: &state,
+                    stats: &stats,
+                    position_manager: &Arc::new(PositionManager::new(Arc::clone(&stats))),
+                    cache: &cache,
+                    dedup: &dedup,
+                    rate_limiter: &RateLimiter::unlimited(),
+                    execution_slots: &ExecutionSlots::unlimited(),
+                    verify_before_execute: false,
+                    dry_run: true,
+                },
+            );
+        }
+
+        assert_eq!(stats.get_today().opportunities_detected, 1);
+        assert_eq!(dedup.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn handle_opportunity_executes_duplicate_after_transient_skip() {
+        use crate::application::orchestration::simulator::SimulatedExecutor;
+
+        let state = Arc::new(AppState::default());
+        let notifiers = Arc::new(NotifierRegistry::new());
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let position_manager = Arc::new(PositionManager::new(Arc::clone(&stats)));
+        let cache = Arc::new(BookCache::new());
+        let dedup = DedupTracker::new(std::time::Duration::from_secs(60), dec!(0.005));
+        let executor: Arc<dyn ArbitrageExecutor + Send + Sync> =
+            Arc::new(SimulatedExecutor::new(Arc::clone(&cache)));
+
+        cache.update(make_order_book("yes-token", dec!(0.39), dec!(0.40)));
+        cache.update(make_order_book("no-token", dec!(0.49), dec!(0.50)));
+
+        let handle = || {
+            handle_opportunity(
+                make_test_opportunity(),
+                OpportunityHandlingContext {
+                    executor: Some(Arc::clone(&executor)),
+                    risk_manager: &risk_manager,
+                    notifiers: &notifiers,
+                    state: &state,
+                    stats: &stats,
+                    position_manager: &position_manager,
+                    cache: &cache,
+                    dedup: &dedup,
+                    rate_limiter: &RateLimiter::unlimited(),
+                    execution_slots: &ExecutionSlots::unlimited(),
+                    verify_before_execute: false,
+                    dry_run: false,
+                },
+            );
+        };
+
+        // The first detection finds the market busy and is skipped.
+        assert!(state.try_lock_execution("test-market"));
+        handle();
+        assert_eq!(stats.get_today().opportunities_executed, 0);
+        state.release_execution("test-market");
+
+        // The repeat is not re-alerted but still executes.
+        handle();
+        assert_eq!(stats.get_today().opportunities_executed, 1);
+        assert_eq!(dedup.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn handle_opportunity_skips_sell_side_the_executor_cannot_trade() {
+        use crate::application::orchestration::simulator::SimulatedExecutor;
+
+        let state = Arc::new(AppState::default());
+        let notifiers = Arc::new(NotifierRegistry::new());
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let position_manager = Arc::new(PositionManager::new(Arc::clone(&stats)));
+        let cache = Arc::new(BookCache::new());
+        let execution_slots = ExecutionSlots::new(1);
+        let executor: Arc<dyn ArbitrageExecutor + Send + Sync> =
+            Arc::new(SimulatedExecutor::new(Arc::clone(&cache)));
+
+        let opp = Opportunity::new(
+            MarketId::from("test-market"),
+            "Will it rain?",
+            vec![
+                OpportunityLeg::sell(TokenId::from("yes-token"), dec!(0.55)),
+                OpportunityLeg::sell(TokenId::from("no-token"), dec!(0.50)),
+            ],
+            dec!(100),
+            dec!(1.00),
+        );
+
+        handle_opportunity(
+            opp,
+            OpportunityHandlingContext {
+                executor: Some(executor),
+                risk_manager: &risk_manager,
+                notifiers: &notifiers,
+                state: &state,
+                stats: &stats,
+                position_manager: &position_manager,
+                cache: &cache,
+                dedup: &DedupTracker::disabled(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &execution_slots,
+                verify_before_execute: false,
+                dry_run: false,
+            },
+        );
+
+        let today = stats.get_today();
+        assert_eq!(today.opportunities_detected, 1);
+        assert_eq!(today.opportunities_executed, 0);
+        assert_eq!(execution_slots.in_flight(), 0);
+        assert_eq!(state.pending_exposure(), dec!(0));
+        assert!(state.try_lock_execution("test-market"));
+    }
+
     // ========== handle_market_event tests ==========
 
     #[test]
@@ -467,7 +1075,15 @@ mod tests {
                 state: &state,
                 stats: &stats,
                 position_manager: &position_manager,
+                dedup: &DedupTracker::disabled(),
+                versions: &BookVersionTracker::new(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                priority: OpportunityPriority::default(),
+                received_at: Instant::now(),
                 dry_run: true,
+                shadow: None,
             },
         );
 
@@ -517,7 +1133,15 @@ mod tests {
                 state: &state,
                 stats: &stats,
                 position_manager: &position_manager,
+                dedup: &DedupTracker::disabled(),
+                versions: &BookVersionTracker::new(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                priority: OpportunityPriority::default(),
+                received_at: Instant::now(),
                 dry_run: true,
+                shadow: None,
             },
         );
 
@@ -526,6 +1150,70 @@ mod tests {
         assert!(cached.is_some(), "Order book should be in cache");
     }
 
+    #[test]
+    fn handle_market_event_records_shadow_opportunities_without_executing() {
+        use crate::application::strategy::single_condition::SingleConditionConfig;
+        use crate::domain::stats::OpportunityFilter;
+
+        let cache = Arc::new(BookCache::new());
+        let registry = Arc::new(make_registry(vec![make_binary_market(
+            "market-1",
+            "Test?",
+            "yes-1",
+            "no-1",
+            dec!(1.00),
+        )]));
+        let strategies = StrategyRegistry::new();
+        let shadow_strategies = StrategyRegistry::builder()
+            .single_condition(SingleConditionConfig::default())
+            .build();
+        let state = Arc::new(AppState::default());
+        let notifiers = Arc::new(NotifierRegistry::new());
+        let risk_manager = Arc::new(RiskManager::new(Arc::clone(&state)));
+        let stats = in_memory_stats_recorder();
+        let position_manager = Arc::new(
+            crate::application::position::manager::PositionManager::new(Arc::clone(&stats)),
+        );
+        cache.update(make_order_book("yes-1", dec!(0.39), dec!(0.40)));
+
+        handle_market_event(
+            MarketEvent::BookSnapshot {
+                token_id: TokenId::from("no-1"),
+                book: make_order_book("no-1", dec!(0.49), dec!(0.50)),
+            },
+            MarketEventHandlingContext {
+                cache: &cache,
+                registry: &registry,
+                strategies: &strategies,
+                executor: None,
+                risk_manager: &risk_manager,
+                notifiers: &notifiers,
+                state: &state,
+                stats: &stats,
+                position_manager: &position_manager,
+                dedup: &DedupTracker::disabled(),
+                versions: &BookVersionTracker::new(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                priority: OpportunityPriority::default(),
+                received_at: Instant::now(),
+                dry_run: true,
+                shadow: Some(ShadowDetection {
+                    variant: "candidate",
+                    strategies: &shadow_strategies,
+                    dedup: &DedupTracker::disabled(),
+                }),
+            },
+        );
+
+        let recorded = stats.query_opportunities(OpportunityFilter::default());
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].variant, "candidate");
+        assert!(!recorded[0].executed);
+        assert_eq!(stats.get_today().opportunities_detected, 0);
+    }
+
     #[test]
     fn handle_market_event_settles_positions() {
         let cache = Arc::new(BookCache::new());
@@ -580,7 +1268,15 @@ mod tests {
                 state: &state,
                 stats: &stats,
                 position_manager: &position_manager,
+                dedup: &DedupTracker::disabled(),
+                versions: &BookVersionTracker::new(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                priority: OpportunityPriority::default(),
+                received_at: Instant::now(),
                 dry_run: true,
+                shadow: None,
             },
         );
 
@@ -622,7 +1318,15 @@ mod tests {
                 state: &state,
                 stats: &stats,
                 position_manager: &position_manager,
+                dedup: &DedupTracker::disabled(),
+                versions: &BookVersionTracker::new(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                priority: OpportunityPriority::default(),
+                received_at: Instant::now(),
                 dry_run: true,
+                shadow: None,
             },
         );
     }
@@ -655,7 +1359,15 @@ mod tests {
                 state: &state,
                 stats: &stats,
                 position_manager: &position_manager,
+                dedup: &DedupTracker::disabled(),
+                versions: &BookVersionTracker::new(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                priority: OpportunityPriority::default(),
+                received_at: Instant::now(),
                 dry_run: true,
+                shadow: None,
             },
         );
     }
@@ -676,7 +1388,7 @@ mod tests {
         let book = make_order_book("unknown-token", dec!(0.40), dec!(0.42));
 
         // Should not panic, just update cache and skip strategy detection
-        handle_market_event(
+        let latency = handle_market_event(
             MarketEvent::BookSnapshot {
                 token_id: TokenId::from("unknown-token"),
                 book,
@@ -691,11 +1403,351 @@ mod tests {
                 state: &state,
                 stats: &stats,
                 position_manager: &position_manager,
+                dedup: &DedupTracker::disabled(),
+                versions: &BookVersionTracker::new(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                priority: OpportunityPriority::default(),
+                received_at: Instant::now(),
                 dry_run: true,
+                shadow: None,
             },
         );
 
         // Cache should still be updated
         assert!(cache.get(&TokenId::from("unknown-token")).is_some());
+        // No detection ran, so no latency is reported
+        assert!(latency.is_none());
+    }
+
+    #[test]
+    fn handle_market_event_reports_latency_from_receipt_for_book_events() {
+        let cache = BookCache::new();
+        let registry = make_registry(vec![make_binary_market(
+            "market-1",
+            "Test?",
+            "yes-1",
+            "no-1",
+            dec!(1.00),
+        )]);
+        let strategies = StrategyRegistry::new();
+        let state = Arc::new(AppState::default());
+        let notifiers = Arc::new(NotifierRegistry::new());
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let position_manager = Arc::new(
+            crate::application::position::manager::PositionManager::new(Arc::clone(&stats)),
+        );
+        let dedup = DedupTracker::disabled();
+        let versions = BookVersionTracker::new();
+        let rate_limiter = RateLimiter::unlimited();
+        let context = |received_at| MarketEventHandlingContext {
+            cache: &cache,
+            registry: &registry,
+            strategies: &strategies,
+            executor: None,
+            risk_manager: &risk_manager,
+            notifiers: &notifiers,
+            state: &state,
+            stats: &stats,
+            position_manager: &position_manager,
+            dedup: &dedup,
+            versions: &versions,
+            rate_limiter: &rate_limiter,
+            execution_slots: &ExecutionSlots::unlimited(),
+            verify_before_execute: false,
+            priority: OpportunityPriority::default(),
+            received_at,
+            dry_run: true,
+            shadow: None,
+        };
+
+        let queued = Duration::from_millis(20);
+        let received_at = Instant::now().checked_sub(queued).unwrap();
+        let latency = handle_market_event(
+            MarketEvent::BookSnapshot {
+                token_id: TokenId::from("yes-1"),
+                book: make_order_book("yes-1", dec!(0.40), dec!(0.42)),
+            },
+            context(received_at),
+        );
+        assert!(latency.is_some_and(|latency| latency >= queued));
+
+        assert!(handle_market_event(MarketEvent::Connected, context(Instant::now())).is_none());
+    }
+
+    #[test]
+    fn handle_market_event_skips_detection_when_books_are_unchanged() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use crate::port::inbound::strategy::DetectionContext;
+
+        /// Engine counting how often detection runs.
+        #[derive(Default)]
+        struct CountingEngine(AtomicUsize);
+
+        impl StrategyEngine for CountingEngine {
+            fn strategy_names(&self) -> Vec<&'static str> {
+                vec!["counting"]
+            }
+
+            fn set_market_registry(&mut self, _registry: Arc<MarketRegistry>) {}
+
+            fn detect_opportunities(&self, _ctx: &dyn DetectionContext) -> Vec<Opportunity> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Vec::new()
+            }
+        }
+
+        let cache = BookCache::new();
+        let registry = make_registry(vec![
+            make_binary_market("market-1", "Test?", "yes-1", "no-1", dec!(1.00)),
+            make_binary_market("market-2", "Other?", "yes-2", "no-2", dec!(1.00)),
+        ]);
+        let strategies = CountingEngine::default();
+        let state = Arc::new(AppState::default());
+        let notifiers = Arc::new(NotifierRegistry::new());
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let position_manager = Arc::new(
+            crate::application::position::manager::PositionManager::new(Arc::clone(&stats)),
+        );
+        let dedup = DedupTracker::disabled();
+        let versions = BookVersionTracker::new();
+        let rate_limiter = RateLimiter::unlimited();
+        let snapshot = |token_id: &str, ask: Decimal| {
+            let context = MarketEventHandlingContext {
+                cache: &cache,
+                registry: &registry,
+                strategies: &strategies,
+                executor: None,
+                risk_manager: &risk_manager,
+                notifiers: &notifiers,
+                state: &state,
+                stats: &stats,
+                position_manager: &position_manager,
+                dedup: &dedup,
+                versions: &versions,
+                rate_limiter: &rate_limiter,
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                priority: OpportunityPriority::default(),
+                received_at: Instant::now(),
+                dry_run: true,
+                shadow: None,
+            };
+            handle_market_event(
+                MarketEvent::BookSnapshot {
+                    token_id: TokenId::from(token_id),
+                    book: make_order_book(token_id, ask - dec!(0.02), ask),
+                },
+                context,
+            )
+        };
+
+        assert!(snapshot("yes-1", dec!(0.42)).is_some());
+        assert!(snapshot("no-1", dec!(0.55)).is_some());
+        assert_eq!(strategies.0.load(Ordering::SeqCst), 2);
+
+        // An unrelated market's token moves, then market-1 resends the same book.
+        assert!(snapshot("yes-2", dec!(0.30)).is_some());
+        assert!(snapshot("yes-1", dec!(0.42)).is_none());
+        assert_eq!(strategies.0.load(Ordering::SeqCst), 3);
+
+        assert!(snapshot("yes-1", dec!(0.41)).is_some());
+        assert_eq!(strategies.0.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn handle_market_event_skips_detection_on_crossed_book() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use crate::port::inbound::strategy::DetectionContext;
+
+        /// Engine counting how often detection runs.
+        #[derive(Default)]
+        struct CountingEngine(AtomicUsize);
+
+        impl StrategyEngine for CountingEngine {
+            fn strategy_names(&self) -> Vec<&'static str> {
+                vec!["counting"]
+            }
+
+            fn set_market_registry(&mut self, _registry: Arc<MarketRegistry>) {}
+
+            fn detect_opportunities(&self, _ctx: &dyn DetectionContext) -> Vec<Opportunity> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Vec::new()
+            }
+        }
+
+        let cache = BookCache::new();
+        let registry = make_registry(vec![make_binary_market(
+            "market-1",
+            "Test?",
+            "yes-1",
+            "no-1",
+            dec!(1.00),
+        )]);
+        let strategies = CountingEngine::default();
+        let state = Arc::new(AppState::default());
+        let notifiers = Arc::new(NotifierRegistry::new());
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let position_manager = Arc::new(
+            crate::application::position::manager::PositionManager::new(Arc::clone(&stats)),
+        );
+        let dedup = DedupTracker::disabled();
+        let versions = BookVersionTracker::new();
+        let rate_limiter = RateLimiter::unlimited();
+        let snapshot = |token_id: &str, bid: Decimal, ask: Decimal| {
+            let context = MarketEventHandlingContext {
+                cache: &cache,
+                registry: &registry,
+                strategies: &strategies,
+                executor: None,
+                risk_manager: &risk_manager,
+                notifiers: &notifiers,
+                state: &state,
+                stats: &stats,
+                position_manager: &position_manager,
+                dedup: &dedup,
+                versions: &versions,
+                rate_limiter: &rate_limiter,
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                priority: OpportunityPriority::default(),
+                received_at: Instant::now(),
+                dry_run: true,
+                shadow: None,
+            };
+            handle_market_event(
+                MarketEvent::BookSnapshot {
+                    token_id: TokenId::from(token_id),
+                    book: make_order_book(token_id, bid, ask),
+                },
+                context,
+            )
+        };
+
+        // A crossed YES book blocks detection for the whole market.
+        assert!(snapshot("yes-1", dec!(0.45), dec!(0.40)).is_none());
+        assert!(snapshot("no-1", dec!(0.53), dec!(0.55)).is_none());
+        assert_eq!(strategies.0.load(Ordering::SeqCst), 0);
+
+        // A locked book is refused as well.
+        assert!(snapshot("yes-1", dec!(0.42), dec!(0.42)).is_none());
+        assert_eq!(strategies.0.load(Ordering::SeqCst), 0);
+
+        // Once the book uncrosses, detection resumes.
+        assert!(snapshot("yes-1", dec!(0.40), dec!(0.42)).is_some());
+        assert_eq!(strategies.0.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn handle_market_event_executes_most_profitable_opportunities_first() {
+        use crate::application::orchestration::simulator::SimulatedExecutor;
+        use crate::domain::stats::OpportunityFilter;
+        use crate::port::inbound::strategy::DetectionContext;
+
+        /// Engine returning fixed opportunities, least profitable first.
+        struct FixedEngine;
+
+        impl StrategyEngine for FixedEngine {
+            fn strategy_names(&self) -> Vec<&'static str> {
+                vec!["fixed"]
+            }
+
+            fn set_market_registry(&mut self, _registry: Arc<MarketRegistry>) {}
+
+            fn detect_opportunities(&self, _ctx: &dyn DetectionContext) -> Vec<Opportunity> {
+                [
+                    ("low", dec!(0.45)),
+                    ("mid", dec!(0.40)),
+                    ("high", dec!(0.35)),
+                ]
+                .into_iter()
+                .map(|(market, price)| {
+                    Opportunity::new(
+                        MarketId::from(market),
+                        "Test?",
+                        vec![
+                            OpportunityLeg::new(TokenId::from(format!("{market}-yes")), price),
+                            OpportunityLeg::new(TokenId::from(format!("{market}-no")), price),
+                        ],
+                        dec!(100),
+                        dec!(1.00),
+                    )
+                })
+                .collect()
+            }
+        }
+
+        let cache = Arc::new(BookCache::new());
+        let registry = make_registry(vec![make_binary_market(
+            "market-1",
+            "Test?",
+            "yes-1",
+            "no-1",
+            dec!(1.00),
+        )]);
+        // Profits are $10, $20, and $30 for $90, $80, and $70 of exposure:
+        // the budget covers the two most profitable only.
+        let state = Arc::new(AppState::new(RiskLimits {
+            max_total_exposure: dec!(150),
+            ..Default::default()
+        }));
+        let notifiers = Arc::new(NotifierRegistry::new());
+        let risk_manager = RiskManager::new(Arc::clone(&state));
+        let stats = in_memory_stats_recorder();
+        let position_manager = Arc::new(PositionManager::new(Arc::clone(&stats)));
+        let executor: Arc<dyn ArbitrageExecutor + Send + Sync> =
+            Arc::new(SimulatedExecutor::new(Arc::clone(&cache)));
+
+        handle_market_event(
+            MarketEvent::BookSnapshot {
+                token_id: TokenId::from("yes-1"),
+                book: make_order_book("yes-1", dec!(0.40), dec!(0.42)),
+            },
+            MarketEventHandlingContext {
+                cache: &cache,
+                registry: &registry,
+                strategies: &FixedEngine,
+                executor: Some(executor),
+                risk_manager: &risk_manager,
+                notifiers: &notifiers,
+                state: &state,
+                stats: &stats,
+                position_manager: &position_manager,
+                dedup: &DedupTracker::disabled(),
+                versions: &BookVersionTracker::new(),
+                rate_limiter: &RateLimiter::unlimited(),
+                execution_slots: &ExecutionSlots::unlimited(),
+                verify_before_execute: false,
+                priority: OpportunityPriority::Profit,
+                received_at: Instant::now(),
+                dry_run: false,
+                shadow: None,
+            },
+        );
+
+        let executed = |executed| {
+            let mut profits: Vec<Decimal> = stats
+                .query_opportunities(OpportunityFilter {
+                    executed: Some(executed),
+                    ..Default::default()
+                })
+                .into_iter()
+                .map(|opp| opp.expected_profit)
+                .collect();
+            profits.sort();
+            profits
+        };
+        assert_eq!(executed(true), vec![dec!(20), dec!(30)]);
+        assert_eq!(executed(false), vec![dec!(10)]);
+        assert!(!state.try_lock_execution("high"));
+        assert!(!state.try_lock_execution("mid"));
+        assert!(state.try_lock_execution("low"));
     }
 }
@@ -9,25 +9,52 @@
 //! The orchestration flow processes events through several stages:
 //!
 //! 1. **Event Handling**: Market events (book updates, settlements) trigger processing
-//! 2. **Strategy Detection**: Applicable strategies scan for arbitrage opportunities
-//! 3. **Slippage Check**: Validates prices have not moved adversely since detection
-//! 4. **Risk Validation**: Ensures opportunity passes all risk gates
-//! 5. **Execution**: Spawns async execution task for approved opportunities
+//! 2. **Strategy Detection**: Applicable strategies scan for arbitrage opportunities,
+//!    skipped when none of the market's books has changed
+//! 3. **Prioritization**: Opportunities from one event are ranked for the budget
+//! 4. **Deduplication**: Repeats of recently seen opportunities are not recorded
+//!    or notified again
+//! 5. **Staleness Check**: Rejects opportunities priced from outdated books
+//! 6. **Slippage Check**: Validates prices have not moved adversely since detection
+//! 7. **Risk Validation**: Ensures opportunity passes all risk gates
+//! 8. **Edge Verification**: Optionally re-prices every leg from the current
+//!    top of book and drops opportunities whose edge has disappeared
+//! 9. **Concurrency Limiting**: Drops executions while every slot is in use
+//! 10. **Rate Limiting**: Delays or drops executions beyond the order rate
+//! 11. **Execution**: Spawns async execution task for approved opportunities
 //!
 //! # Modules
 //!
+//! - [`concurrency`]: Cap on simultaneous executions
 //! - [`handler`]: Public facade for event and opportunity handling
 //! - `context`: Detection context wrappers for strategy interface
+//! - [`dedup`]: Suppression of repeated opportunity detections
 //! - `event`: Market event processing logic
 //! - `execution`: Async execution spawning and result handling
 //! - `opportunity`: Opportunity evaluation and routing
 //! - `position`: Position recording helpers
+//! - [`priority`]: Ranking of opportunities detected together
+//! - `shadow`: Recording of shadow strategy detections for comparison
+//! - [`simulator`]: Dry-run executor filling against cached book depth
 //! - `slippage`: Price slippage calculations
+//! - `staleness`: Order book age checks
+//! - [`throttle`]: Token-bucket rate limiting of executions
+//! - `verify`: Re-pricing opportunities before execution
+//! - [`versions`]: Skipping detection for markets whose books have not moved
 
+pub mod concurrency;
 mod context;
+pub mod dedup;
 mod event;
 mod execution;
 pub mod handler;
 mod opportunity;
 mod position;
+pub mod priority;
+mod shadow;
+pub mod simulator;
 mod slippage;
+mod staleness;
+pub mod throttle;
+mod verify;
+pub mod versions;
@@ -4,26 +4,100 @@
 //! and execution routing.
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use tracing::{debug, info};
+use rust_decimal::Decimal;
+use tracing::{debug, info, info_span, warn};
 
-use super::execution::spawn_execution;
+use super::concurrency::ExecutionSlot;
+use super::execution::{spawn_execution, ExecutionContext};
 use super::handler::OpportunityHandlingContext;
 use super::slippage::get_max_slippage;
-use crate::domain::{opportunity::Opportunity, stats::RecordedOpportunity};
+use super::staleness::find_stale_leg;
+use super::verify::verify_edge;
+use crate::application::sampling::LogSampler;
+use crate::application::state::AppState;
+use crate::domain::opportunity::Opportunity;
+use crate::domain::stats::{RecordedOpportunity, PRIMARY_VARIANT};
 use crate::error::RiskError;
 use crate::port::inbound::risk::RiskCheckResult;
-use crate::port::outbound::notifier::{Event, OpportunityEvent, RiskEvent};
+use crate::port::outbound::notifier::{Event, NotifierRegistry, OpportunityEvent, RiskEvent};
+use crate::port::outbound::stats::StatsRecorder;
+
+/// Sampler for repeated-opportunity suppression logs.
+static DUPLICATE_LOGS: LogSampler = LogSampler::new();
+
+/// Dependencies for turning away an opportunity after its market was locked.
+struct Rejecter<'a> {
+    state: &'a AppState,
+    stats: &'a dyn StatsRecorder,
+    notifiers: &'a NotifierRegistry,
+    /// Whether the opportunity is new within the dedup window. Repeats are
+    /// released without being recorded or notified again.
+    report: bool,
+}
+
+impl Rejecter<'_> {
+    /// Release the opportunity's market lock and `reserved_exposure`, record
+    /// it with `reason` as the rejected reason, and notify the rejection.
+    fn reject(
+        &self,
+        opp: &Opportunity,
+        reserved_exposure: Decimal,
+        reason: &str,
+        error: &RiskError,
+    ) {
+        self.state.release_exposure(reserved_exposure);
+        self.state.release_execution(opp.market_id().as_str());
+        if !self.report {
+            return;
+        }
+
+        self.stats.record_opportunity(&RecordedOpportunity {
+            uid: opp.id().to_string(),
+            strategy: opp.strategy().to_string(),
+            market_ids: vec![opp.market_id().to_string()],
+            edge: opp.edge(),
+            expected_profit: opp.expected_profit(),
+            executed: false,
+            rejected_reason: Some(reason.to_string()),
+            variant: PRIMARY_VARIANT.to_string(),
+            content_hash: Some(opp.content_hash()),
+        });
+        self.notifiers
+            .notify_all(Event::RiskRejected(RiskEvent::new(
+                opp.id().as_str(),
+                opp.market_id().as_str(),
+                error,
+            )));
+    }
+}
 
 /// Process a detected opportunity through validation and execution.
 ///
 /// Performs the following steps:
-/// 1. Acquire execution lock (skip if market already being executed)
-/// 2. Check slippage against configured threshold
-/// 3. Validate opportunity against risk manager
-/// 4. Either spawn execution (live mode) or log (dry-run mode)
+/// 1. Check whether the opportunity was already seen within the dedup
+///    window; repeats are still evaluated and executed, but are not recorded
+///    or notified again
+/// 2. Notify and stop for watch-only opportunities
+/// 3. Acquire execution lock (skip if market already being executed)
+/// 4. Reject opportunities priced from stale order books
+/// 5. Size the trade by edge and remaining exposure budget
+/// 6. Check slippage against configured threshold
+/// 7. Resume trading if the failure circuit breaker's cooldown has elapsed
+/// 8. Validate opportunity against risk manager
+/// 9. Re-price every leg from the current top of book when
+///    `verify_before_execute` is set, dropping the opportunity if its edge
+///    has disappeared
+/// 10. Take one of the concurrent execution slots, dropping the opportunity
+///     if every slot is in use
+/// 11. Reserve an execution slot from the rate limiter, dropping the
+///     opportunity if the queue is too long
+/// 12. Either spawn execution (live mode) or log (dry-run mode)
 ///
-/// Releases locks and reserved exposure on all exit paths.
+/// Releases locks and reserved exposure on all exit paths. Everything logged
+/// while handling the opportunity runs inside a span carrying its
+/// `opportunity_id`.
 pub(crate) fn handle_opportunity(opp: Opportunity, context: OpportunityHandlingContext<'_>) {
     let OpportunityHandlingContext {
         executor,
@@ -31,15 +105,61 @@ pub(crate) fn handle_opportunity(opp: Opportunity, context: OpportunityHandlingC
         notifiers,
         state,
         stats,
+        position_manager,
         cache,
+        dedup,
+        rate_limiter,
+        execution_slots,
+        verify_before_execute,
         dry_run,
     } = context;
+    let _span = info_span!("opportunity", opportunity_id = %opp.id()).entered();
+    let is_new = dedup.is_new(&opp);
+    if !is_new && DUPLICATE_LOGS.sample() {
+        debug!(market_id = %opp.market_id(), edge = %opp.edge(), "Duplicate opportunity, suppressing stats and alerts");
+    }
+    let rejecter = Rejecter {
+        state,
+        stats: stats.as_ref(),
+        notifiers,
+        report: is_new,
+    };
+
+    if !opp.is_actionable() {
+        if is_new {
+            debug!(market_id = %opp.market_id(), edge = %opp.edge(), "Near-arbitrage market, notifying only");
+            notifiers.notify_all(Event::NearArbitrage(OpportunityEvent::from(&opp)));
+        }
+        return;
+    }
 
     if !state.try_lock_execution(opp.market_id().as_str()) {
         debug!(market_id = %opp.market_id(), "Execution already in progress, skipping");
         return;
     }
 
+    if let Some(max_age_ms) = state.risk_limits().max_book_age_ms {
+        let max_age = Duration::from_millis(max_age_ms);
+        if let Some((token_id, age)) = find_stale_leg(&opp, cache, max_age) {
+            debug!(
+                market_id = %opp.market_id(),
+                token_id = %token_id,
+                age_ms = age.as_millis(),
+                max_age_ms,
+                "Stale order book, rejecting opportunity"
+            );
+            let error = RiskError::StaleBook {
+                token_id: token_id.to_string(),
+                age_ms: age.as_millis(),
+                max_age_ms: max_age.as_millis(),
+            };
+            rejecter.reject(&opp, Decimal::ZERO, "stale_book", &error);
+            return;
+        }
+    }
+
+    let opp = risk_manager.size(opp);
+
     let max_slippage = state.risk_limits().max_slippage;
     if let Some(slippage) = get_max_slippage(&opp, cache) {
         if slippage > max_slippage {
@@ -49,44 +169,99 @@ pub(crate) fn handle_opportunity(opp: Opportunity, context: OpportunityHandlingC
                 max = %max_slippage,
                 "Slippage check failed, rejecting opportunity"
             );
-            state.release_execution(opp.market_id().as_str());
-
-            stats.record_opportunity(&RecordedOpportunity {
-                strategy: opp.strategy().to_string(),
-                market_ids: vec![opp.market_id().to_string()],
-                edge: opp.edge(),
-                expected_profit: opp.expected_profit(),
-                executed: false,
-                rejected_reason: Some("slippage_too_high".to_string()),
-            });
-
             let error = RiskError::SlippageTooHigh {
                 actual: slippage,
                 max: max_slippage,
             };
-            notifiers.notify_all(Event::RiskRejected(RiskEvent::new(
-                opp.market_id().as_str(),
-                &error,
-            )));
+            rejecter.reject(&opp, Decimal::ZERO, "slippage_too_high", &error);
             return;
         }
     }
 
-    notifiers.notify_all(Event::OpportunityDetected(OpportunityEvent::from(&opp)));
+    if is_new {
+        notifiers.notify_all(Event::OpportunityDetected(OpportunityEvent::from(&opp)));
+    }
+
+    if risk_manager.expire_circuit_breaker() {
+        notifiers.notify_all(Event::CircuitBreakerReset);
+    }
 
     match risk_manager.check(&opp) {
         RiskCheckResult::Approved => {
-            let opp_id = stats.record_opportunity(&RecordedOpportunity {
-                strategy: opp.strategy().to_string(),
-                market_ids: vec![opp.market_id().to_string()],
-                edge: opp.edge(),
-                expected_profit: opp.expected_profit(),
-                executed: !dry_run,
-                rejected_reason: None,
-            });
-
             let reserved_exposure = opp.total_cost() * opp.volume();
 
+            let verified = if verify_before_execute {
+                verify_edge(&opp, cache)
+            } else {
+                Ok(())
+            };
+            if let Err(error) = verified {
+                debug!(
+                    market_id = %opp.market_id(),
+                    detected_edge = %opp.edge(),
+                    reason = %error,
+                    "Edge disappeared before execution, rejecting opportunity"
+                );
+                rejecter.reject(&opp, reserved_exposure, "edge_disappeared", &error);
+                return;
+            }
+
+            let executor = executor.filter(|exec| !opp.is_sell() || exec.supports_sell_arbitrage());
+            let will_execute = !dry_run && executor.is_some();
+            let slot = if will_execute {
+                execution_slots.try_acquire()
+            } else {
+                Some(ExecutionSlot::default())
+            };
+            let Some(slot) = slot else {
+                let error = RiskError::ConcurrencyLimited {
+                    max: execution_slots.max(),
+                };
+                warn!(
+                    market_id = %opp.market_id(),
+                    reason = %error,
+                    "Concurrency limit reached, dropping opportunity"
+                );
+                rejecter.reject(&opp, reserved_exposure, "concurrency_limited", &error);
+                return;
+            };
+
+            let delay = if will_execute {
+                rate_limiter.reserve()
+            } else {
+                Some(Duration::ZERO)
+            };
+            let Some(delay) = delay else {
+                let error = RiskError::RateLimited {
+                    max_wait_ms: rate_limiter.max_wait().as_millis(),
+                };
+                warn!(
+                    market_id = %opp.market_id(),
+                    reason = %error,
+                    "Rate limited, dropping opportunity"
+                );
+                rejecter.reject(&opp, reserved_exposure, "rate_limited", &error);
+                return;
+            };
+
+            // Executions are always recorded so their trades stay linked to
+            // an opportunity, even when the detection repeats an earlier one.
+            let opp_id = (is_new || will_execute)
+                .then(|| {
+                    stats.record_opportunity(&RecordedOpportunity {
+                        uid: opp.id().to_string(),
+                        strategy: opp.strategy().to_string(),
+                        market_ids: vec![opp.market_id().to_string()],
+                        edge: opp.edge(),
+                        expected_profit: opp.expected_profit(),
+                        executed: will_execute,
+                        rejected_reason: None,
+                        variant: PRIMARY_VARIANT.to_string(),
+                        content_hash: Some(opp.content_hash()),
+                    })
+                })
+                .flatten();
+
             if dry_run {
                 info!(
                     market_id = %opp.market_id(),
@@ -98,33 +273,29 @@ pub(crate) fn handle_opportunity(opp: Opportunity, context: OpportunityHandlingC
                 state.release_execution(opp.market_id().as_str());
             } else if let Some(exec) = executor {
                 spawn_execution(
-                    exec,
                     opp,
-                    notifiers.clone(),
-                    state.clone(),
-                    Arc::clone(stats),
-                    opp_id,
+                    ExecutionContext {
+                        executor: exec,
+                        notifiers: notifiers.clone(),
+                        state: state.clone(),
+                        risk_manager: risk_manager.clone(),
+                        stats: Arc::clone(stats),
+                        position_manager: Arc::clone(position_manager),
+                        opportunity_id: opp_id,
+                        slot,
+                        delay,
+                    },
                 );
             } else {
+                if opp.is_sell() {
+                    debug!(market_id = %opp.market_id(), "Executor cannot trade sell-side arbitrage, skipping");
+                }
                 state.release_exposure(reserved_exposure);
                 state.release_execution(opp.market_id().as_str());
             }
         }
         RiskCheckResult::Rejected(error) => {
-            stats.record_opportunity(&RecordedOpportunity {
-                strategy: opp.strategy().to_string(),
-                market_ids: vec![opp.market_id().to_string()],
-                edge: opp.edge(),
-                expected_profit: opp.expected_profit(),
-                executed: false,
-                rejected_reason: Some(format!("{error}")),
-            });
-
-            state.release_execution(opp.market_id().as_str());
-            notifiers.notify_all(Event::RiskRejected(RiskEvent::new(
-                opp.market_id().as_str(),
-                &error,
-            )));
+            rejecter.reject(&opp, Decimal::ZERO, &error.to_string(), &error);
         }
     }
 }
@@ -5,17 +5,26 @@
 
 use rust_decimal::Decimal;
 
+use crate::application::position::manager::PositionManager;
 use crate::application::state::AppState;
 use crate::domain::id::TokenId;
-use crate::domain::opportunity::Opportunity;
+use crate::domain::opportunity::{HoldPolicy, Opportunity};
 use crate::domain::position::{Position, PositionLeg, PositionStatus};
 use crate::domain::trade::{Failure, Fill};
 
 /// Record a fully executed position in shared state.
 ///
 /// Creates a new position from the opportunity with all legs filled,
-/// generating a unique position ID and adding it to the tracker.
-pub(crate) fn record_position(state: &AppState, opportunity: &Opportunity, trade_id: Option<i32>) {
+/// generating a unique position ID and opening it through the position
+/// manager so it is persisted. Fully filled arbitrage positions lock in their
+/// payout and are held to settlement unless the opportunity's hold policy
+/// allows an early exit, in which case exit checks monitor them.
+pub(crate) fn record_position(
+    state: &AppState,
+    position_manager: &PositionManager,
+    opportunity: &Opportunity,
+    trade_id: Option<i32>,
+) {
     let position_legs: Vec<PositionLeg> = opportunity
         .legs()
         .iter()
@@ -39,11 +48,15 @@ pub(crate) fn record_position(state: &AppState, opportunity: &Opportunity, trade
         PositionStatus::Open,
     );
 
+    if opportunity.hold_policy() == HoldPolicy::HoldToSettlement {
+        position = position.with_held_to_settlement();
+    }
+
     if let Some(tid) = trade_id {
         position = position.with_trade_id(tid);
     }
 
-    positions.add(position);
+    position_manager.open_position(&mut positions, position);
 }
 
 /// Record a partial-fill position in shared state.
@@ -53,6 +66,7 @@ pub(crate) fn record_position(state: &AppState, opportunity: &Opportunity, trade
 /// completes and cancellation fails.
 pub(crate) fn record_partial_position(
     state: &AppState,
+    position_manager: &PositionManager,
     opportunity: &Opportunity,
     fills: &[Fill],
     failures: &[Failure],
@@ -97,5 +111,72 @@ pub(crate) fn record_partial_position(
         position = position.with_trade_id(tid);
     }
 
-    positions.add(position);
+    position_manager.open_position(&mut positions, position);
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::application::cache::book::BookCache;
+    use crate::application::position::manager::ExitPolicy;
+    use crate::domain::book::{Book, PriceLevel};
+    use crate::domain::id::MarketId;
+    use crate::domain::opportunity::OpportunityLeg;
+    use crate::testkit::stats::in_memory_stats_recorder;
+
+    fn opportunity(hold_policy: HoldPolicy) -> Opportunity {
+        Opportunity::new(
+            MarketId::from("market-1"),
+            "Will it happen?",
+            vec![
+                OpportunityLeg::new(TokenId::from("yes"), dec!(0.45)),
+                OpportunityLeg::new(TokenId::from("no"), dec!(0.50)),
+            ],
+            dec!(100),
+            dec!(1),
+        )
+        .with_hold_policy(hold_policy)
+    }
+
+    fn converged_books() -> BookCache {
+        let cache = BookCache::new();
+        for token in ["yes", "no"] {
+            cache.update(Book::with_levels(
+                TokenId::from(token),
+                vec![PriceLevel::new(dec!(0.55), dec!(500))],
+                vec![PriceLevel::new(dec!(0.56), dec!(500))],
+            ));
+        }
+        cache
+    }
+
+    #[test]
+    fn hold_policy_routes_positions_to_exit_checks() {
+        let state = AppState::default();
+        let manager = PositionManager::new(in_memory_stats_recorder());
+        let policy = ExitPolicy {
+            stop_loss_pct: None,
+            take_profit_pct: Some(dec!(0.10)),
+            exempt_held_to_settlement: true,
+        };
+
+        record_position(
+            &state,
+            &manager,
+            &opportunity(HoldPolicy::HoldToSettlement),
+            None,
+        );
+        record_position(&state, &manager, &opportunity(HoldPolicy::ExitEarly), None);
+
+        let mut positions = state.positions_mut();
+        let results = manager.check_exit_conditions(&mut positions, &converged_books(), &policy);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(positions.open_count(), 1);
+        assert!(positions
+            .open_positions()
+            .all(Position::is_held_to_settlement));
+    }
 }
@@ -0,0 +1,115 @@
+//! Ordering of opportunities detected together.
+//!
+//! One market event can yield several opportunities across strategies. They
+//! compete for the same exposure budget, so they are ranked by
+//! [`OpportunityPriority`] before risk checks and execution: the first ones
+//! handled claim the budget and later ones are sized down or rejected.
+
+use std::cmp::Reverse;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::opportunity::Opportunity;
+
+/// Key by which simultaneous opportunities are ranked, highest first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OpportunityPriority {
+    /// Expected profit in dollars.
+    #[default]
+    Profit,
+    /// Edge per share.
+    Edge,
+    /// Available volume in shares.
+    Volume,
+}
+
+impl OpportunityPriority {
+    /// Sort opportunities in place, highest priority first.
+    ///
+    /// The sort is stable, so ties keep their detection order.
+    pub fn sort(self, opportunities: &mut [Opportunity]) {
+        match self {
+            Self::Profit => opportunities.sort_by_key(|opp| Reverse(opp.expected_profit())),
+            Self::Edge => opportunities.sort_by_key(|opp| Reverse(opp.edge())),
+            Self::Volume => opportunities.sort_by_key(|opp| Reverse(opp.volume())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::domain::id::{MarketId, TokenId};
+    use crate::domain::opportunity::OpportunityLeg;
+
+    fn opportunity(market: &str, cost: Decimal, volume: Decimal) -> Opportunity {
+        Opportunity::new(
+            MarketId::from(market),
+            "Test?",
+            vec![
+                OpportunityLeg::new(TokenId::from(format!("{market}-yes")), cost / dec!(2)),
+                OpportunityLeg::new(TokenId::from(format!("{market}-no")), cost / dec!(2)),
+            ],
+            volume,
+            dec!(1.00),
+        )
+    }
+
+    fn markets(opportunities: &[Opportunity]) -> Vec<&str> {
+        opportunities
+            .iter()
+            .map(|opp| opp.market_id().as_str())
+            .collect()
+    }
+
+    fn detected() -> Vec<Opportunity> {
+        vec![
+            // Edge 0.10, volume 50, profit $5
+            opportunity("wide", dec!(0.90), dec!(50)),
+            // Edge 0.02, volume 500, profit $10
+            opportunity("deep", dec!(0.98), dec!(500)),
+            // Edge 0.05, volume 400, profit $20
+            opportunity("rich", dec!(0.95), dec!(400)),
+        ]
+    }
+
+    #[test]
+    fn default_is_profit() {
+        assert_eq!(OpportunityPriority::default(), OpportunityPriority::Profit);
+    }
+
+    #[test]
+    fn sorts_by_expected_profit() {
+        let mut opportunities = detected();
+        OpportunityPriority::Profit.sort(&mut opportunities);
+        assert_eq!(markets(&opportunities), ["rich", "deep", "wide"]);
+    }
+
+    #[test]
+    fn sorts_by_edge() {
+        let mut opportunities = detected();
+        OpportunityPriority::Edge.sort(&mut opportunities);
+        assert_eq!(markets(&opportunities), ["wide", "rich", "deep"]);
+    }
+
+    #[test]
+    fn sorts_by_volume() {
+        let mut opportunities = detected();
+        OpportunityPriority::Volume.sort(&mut opportunities);
+        assert_eq!(markets(&opportunities), ["deep", "rich", "wide"]);
+    }
+
+    #[test]
+    fn ties_keep_detection_order() {
+        let mut opportunities = vec![
+            opportunity("first", dec!(0.90), dec!(100)),
+            opportunity("second", dec!(0.90), dec!(100)),
+        ];
+        OpportunityPriority::Profit.sort(&mut opportunities);
+        assert_eq!(markets(&opportunities), ["first", "second"]);
+    }
+}
@@ -0,0 +1,54 @@
+//! Shadow strategy evaluation for orchestration.
+//!
+//! Runs a secondary strategy set over the same market events as the primary
+//! one and records what it finds under its own variant. Shadow opportunities
+//! are sized like primary ones so expected profits compare directly, but they
+//! are never notified, risk-checked, or executed.
+
+use tracing::debug;
+
+use super::context::MarketDetectionContext;
+use super::handler::{MarketEventHandlingContext, ShadowDetection};
+use crate::application::sampling::LogSampler;
+use crate::domain::market::Market;
+use crate::domain::stats::RecordedOpportunity;
+
+/// Sampler for the per-event shadow detection summary.
+static SHADOW_LOGS: LogSampler = LogSampler::new();
+
+/// Detect opportunities for `market` with the shadow strategies and record them.
+pub(crate) fn record_shadow_opportunities(
+    market: &Market,
+    shadow: ShadowDetection<'_>,
+    context: &MarketEventHandlingContext<'_>,
+) {
+    let ctx = MarketDetectionContext::new(market, context.cache);
+    let opportunities = shadow.strategies.detect_opportunities(&ctx);
+    if SHADOW_LOGS.sample() {
+        debug!(
+            market_id = %market.market_id(),
+            variant = shadow.variant,
+            opportunities_found = opportunities.len(),
+            "Shadow detection complete"
+        );
+    }
+
+    for opp in opportunities {
+        if !opp.is_actionable() || !shadow.dedup.is_new(&opp) {
+            continue;
+        }
+
+        let opp = context.risk_manager.size(opp);
+        context.stats.record_opportunity(&RecordedOpportunity {
+            uid: opp.id().to_string(),
+            strategy: opp.strategy().to_string(),
+            market_ids: vec![opp.market_id().to_string()],
+            edge: opp.edge(),
+            expected_profit: opp.expected_profit(),
+            executed: false,
+            rejected_reason: None,
+            variant: shadow.variant.to_string(),
+            content_hash: Some(opp.content_hash()),
+        });
+    }
+}
@@ -0,0 +1,426 @@
+//! Simulated execution for dry-run sessions.
+//!
+//! [`SimulatedExecutor`] implements [`ArbitrageExecutor`] without touching an
+//! exchange. Each leg is filled against the depth currently held in the
+//! [`BookCache`], so dry-run sessions produce the same trade, position, and
+//! statistics records as live trading would.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use tracing::{debug, info, warn};
+
+use crate::application::cache::book::BookCache;
+use crate::domain::book::Book;
+use crate::domain::id::{OrderId, TokenId};
+use crate::domain::opportunity::Opportunity;
+use crate::domain::trade::{Failure, FailureKind, Fill, TradeResult};
+use crate::error::{Error, ExecutionError};
+use crate::port::outbound::exchange::{ArbitrageExecutor, ExecutionResult};
+
+/// Executor that fills arbitrage legs against cached order book depth.
+///
+/// A leg fills when the asks priced at or below its limit (the detected ask
+/// raised by the slippage buffer) cover the full opportunity volume.
+/// Otherwise the leg fails, producing a partial or failed trade just as an
+/// exchange would.
+pub struct SimulatedExecutor {
+    /// Order book cache providing current depth.
+    cache: Arc<BookCache>,
+    /// Fraction by which each leg's limit price is raised above the detected ask.
+    slippage_buffer: Decimal,
+    /// Counter for generating simulated order IDs.
+    next_order: AtomicU64,
+}
+
+impl SimulatedExecutor {
+    /// Create a simulator reading depth from the given cache.
+    #[must_use]
+    pub fn new(cache: Arc<BookCache>) -> Self {
+        Self {
+            cache,
+            slippage_buffer: Decimal::ZERO,
+            next_order: AtomicU64::new(1),
+        }
+    }
+
+    /// Set the slippage buffer applied to leg limit prices.
+    #[must_use]
+    pub fn with_slippage_buffer(mut self, slippage_buffer: Decimal) -> Self {
+        self.slippage_buffer = slippage_buffer;
+        self
+    }
+
+    /// Generate the next simulated order ID.
+    fn order_id(&self) -> String {
+        format!("sim-{}", self.next_order.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Walk the asks of a book up to `limit`, returning the volume-weighted fill
+/// price for `volume`, or an error describing the shortfall.
+fn fill_against_depth(
+    book: &Book,
+    limit: Decimal,
+    volume: Decimal,
+) -> std::result::Result<Decimal, String> {
+    let mut filled = Decimal::ZERO;
+    let mut notional = Decimal::ZERO;
+
+    for level in book.asks() {
+        if level.price() > limit || filled >= volume {
+            break;
+        }
+        let take = level.size().min(volume - filled);
+        filled += take;
+        notional += take * level.price();
+    }
+
+    if filled < volume {
+        return Err(format!(
+            "insufficient depth: {filled} of {volume} available at or below {limit}"
+        ));
+    }
+
+    Ok(notional / volume)
+}
+
+/// Walk the bids of a book down to `limit`, returning the volume-weighted
+/// sale price for `volume`, or an error describing the shortfall.
+fn sell_against_depth(
+    book: &Book,
+    limit: Decimal,
+    volume: Decimal,
+) -> std::result::Result<Decimal, String> {
+    let mut filled = Decimal::ZERO;
+    let mut notional = Decimal::ZERO;
+
+    for level in book.bids() {
+        if level.price() < limit || filled >= volume {
+            break;
+        }
+        let take = level.size().min(volume - filled);
+        filled += take;
+        notional += take * level.price();
+    }
+
+    if filled < volume {
+        return Err(format!(
+            "insufficient depth: {filled} of {volume} bid at or above {limit}"
+        ));
+    }
+
+    Ok(notional / volume)
+}
+
+#[async_trait]
+impl ArbitrageExecutor for SimulatedExecutor {
+    async fn execute_arbitrage(&self, opportunity: &Opportunity) -> Result<TradeResult, Error> {
+        let legs = opportunity.legs();
+        if legs.len() < 2 {
+            return Ok(TradeResult::Failed {
+                reason: "Opportunity must have at least 2 legs".to_string(),
+                kind: FailureKind::Other,
+            });
+        }
+
+        if opportunity.is_sell() {
+            return Ok(TradeResult::Failed {
+                reason: "Sell-side (negative risk) arbitrage cannot be simulated".to_string(),
+                kind: FailureKind::Other,
+            });
+        }
+
+        let volume = opportunity.volume();
+        let mut fills = Vec::new();
+        let mut failures = Vec::new();
+
+        for leg in legs {
+            let token_id = leg.token_id().clone();
+            let limit = leg.ask_price() * (Decimal::ONE + self.slippage_buffer);
+
+            let Some(book) = self.cache.get(&token_id) else {
+                failures.push(Failure::new(token_id, "no order book cached"));
+                continue;
+            };
+
+            match fill_against_depth(&book, limit, volume) {
+                Ok(price) => {
+                    debug!(token = %token_id, price = %price, volume = %volume, "Simulated fill");
+                    fills.push(Fill::new(token_id, self.order_id()));
+                }
+                // The book no longer offers enough at the limit price.
+                Err(error) => {
+                    failures.push(Failure::new(token_id, error).with_kind(FailureKind::PriceMoved))
+                }
+            }
+        }
+
+        if failures.is_empty() {
+            info!(
+                market = %opportunity.market_id(),
+                fills = fills.len(),
+                "Simulated all legs filled"
+            );
+            Ok(TradeResult::Success { fills })
+        } else if fills.is_empty() {
+            let errors: Vec<_> = failures.iter().map(|f| f.error.as_str()).collect();
+            warn!(market = %opportunity.market_id(), errors = ?errors, "Simulated all legs failed");
+            Ok(TradeResult::Failed {
+                reason: errors.join("; "),
+                kind: failures[0].kind,
+            })
+        } else {
+            warn!(
+                market = %opportunity.market_id(),
+                fills = fills.len(),
+                failures = failures.len(),
+                "Simulated partial fill"
+            );
+            Ok(TradeResult::Partial { fills, failures })
+        }
+    }
+
+    async fn sell(
+        &self,
+        token_id: &TokenId,
+        size: Decimal,
+        limit: Decimal,
+    ) -> Result<ExecutionResult, Error> {
+        let outcome = match self.cache.get(token_id) {
+            Some(book) => sell_against_depth(&book, limit, size),
+            None => Err("no order book cached".to_string()),
+        };
+
+        Ok(match outcome {
+            Ok(price) => {
+                debug!(token = %token_id, price = %price, size = %size, "Simulated sale");
+                ExecutionResult::Success {
+                    order_id: OrderId::new(self.order_id()),
+                    filled_amount: size,
+                    average_price: price,
+                }
+            }
+            Err(reason) => ExecutionResult::Failed { reason },
+        })
+    }
+
+    async fn cancel(&self, order_id: &OrderId) -> Result<(), Error> {
+        // Simulated orders fill immediately, so there is nothing left to cancel.
+        Err(
+            ExecutionError::OrderRejected(format!("simulated order {order_id} already filled"))
+                .into(),
+        )
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        "simulated"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::domain::book::PriceLevel;
+    use crate::domain::id::MarketId;
+    use crate::domain::opportunity::OpportunityLeg;
+
+    fn book(token: &str, asks: Vec<(Decimal, Decimal)>) -> Book {
+        Book::with_levels(
+            TokenId::new(token),
+            vec![],
+            asks.into_iter()
+                .map(|(price, size)| PriceLevel::new(price, size))
+                .collect(),
+        )
+    }
+
+    fn bid_book(token: &str, bids: Vec<(Decimal, Decimal)>) -> Book {
+        Book::with_levels(
+            TokenId::new(token),
+            bids.into_iter()
+                .map(|(price, size)| PriceLevel::new(price, size))
+                .collect(),
+            vec![],
+        )
+    }
+
+    fn opportunity(volume: Decimal) -> Opportunity {
+        Opportunity::new(
+            MarketId::new("market"),
+            "Simulated?",
+            vec![
+                OpportunityLeg::new(TokenId::new("yes"), dec!(0.45)),
+                OpportunityLeg::new(TokenId::new("no"), dec!(0.50)),
+            ],
+            volume,
+            dec!(1.00),
+        )
+    }
+
+    fn executor_with(books: Vec<Book>) -> SimulatedExecutor {
+        let cache = BookCache::new();
+        for b in books {
+            cache.update(b);
+        }
+        SimulatedExecutor::new(Arc::new(cache))
+    }
+
+    #[test]
+    fn fill_against_depth_walks_levels() {
+        let b = book("yes", vec![(dec!(0.45), dec!(60)), (dec!(0.46), dec!(100))]);
+
+        let price = fill_against_depth(&b, dec!(0.46), dec!(100)).unwrap();
+
+        // 60 @ 0.45 + 40 @ 0.46 = 45.4
+        assert_eq!(price, dec!(0.454));
+    }
+
+    #[test]
+    fn fill_against_depth_stops_at_limit() {
+        let b = book("yes", vec![(dec!(0.45), dec!(60)), (dec!(0.50), dec!(100))]);
+
+        let err = fill_against_depth(&b, dec!(0.46), dec!(100)).unwrap_err();
+
+        assert!(err.contains("insufficient depth: 60 of 100"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn execute_fills_all_legs_with_depth() {
+        let executor = executor_with(vec![
+            book("yes", vec![(dec!(0.45), dec!(100))]),
+            book("no", vec![(dec!(0.50), dec!(100))]),
+        ]);
+
+        let result = executor
+            .execute_arbitrage(&opportunity(dec!(50)))
+            .await
+            .unwrap();
+
+        assert!(result.is_success());
+        let ids: Vec<_> = result.fills().iter().map(|f| f.order_id.clone()).collect();
+        assert_eq!(ids, vec!["sim-1".to_string(), "sim-2".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn execute_reports_partial_when_one_leg_lacks_depth() {
+        let executor = executor_with(vec![
+            book("yes", vec![(dec!(0.45), dec!(100))]),
+            book("no", vec![(dec!(0.50), dec!(10))]),
+        ]);
+
+        let result = executor
+            .execute_arbitrage(&opportunity(dec!(50)))
+            .await
+            .unwrap();
+
+        assert!(result.is_partial());
+        assert_eq!(result.fills()[0].token_id, TokenId::new("yes"));
+        assert_eq!(result.failures()[0].token_id, TokenId::new("no"));
+    }
+
+    #[tokio::test]
+    async fn execute_fails_closed_on_sell_side_opportunity() {
+        let executor = executor_with(vec![
+            bid_book("a", vec![(dec!(0.40), dec!(100))]),
+            bid_book("b", vec![(dec!(0.40), dec!(100))]),
+            bid_book("c", vec![(dec!(0.40), dec!(100))]),
+        ]);
+        let opp = Opportunity::new(
+            MarketId::new("market"),
+            "Simulated?",
+            vec![
+                OpportunityLeg::sell(TokenId::new("a"), dec!(0.40)),
+                OpportunityLeg::sell(TokenId::new("b"), dec!(0.40)),
+                OpportunityLeg::sell(TokenId::new("c"), dec!(0.40)),
+            ],
+            dec!(10),
+            dec!(1.00),
+        );
+
+        let result = executor.execute_arbitrage(&opp).await.unwrap();
+
+        assert!(result.is_failed());
+        assert!(result.fills().is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_fails_without_cached_books() {
+        let executor = executor_with(vec![]);
+
+        let result = executor
+            .execute_arbitrage(&opportunity(dec!(50)))
+            .await
+            .unwrap();
+
+        assert!(result.is_failed());
+    }
+
+    #[tokio::test]
+    async fn slippage_buffer_reaches_deeper_levels() {
+        let executor = executor_with(vec![
+            book(
+                "yes",
+                vec![(dec!(0.45), dec!(20)), (dec!(0.455), dec!(100))],
+            ),
+            book("no", vec![(dec!(0.50), dec!(100))]),
+        ])
+        .with_slippage_buffer(dec!(0.02));
+
+        let result = executor
+            .execute_arbitrage(&opportunity(dec!(50)))
+            .await
+            .unwrap();
+
+        assert!(result.is_success());
+    }
+
+    #[tokio::test]
+    async fn cancel_rejects_filled_simulated_orders() {
+        let executor = executor_with(vec![]);
+
+        let result = executor.cancel(&OrderId::new("sim-1")).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn sell_walks_bids_down_to_limit() {
+        let executor = executor_with(vec![bid_book(
+            "yes",
+            vec![(dec!(0.44), dec!(60)), (dec!(0.42), dec!(100))],
+        )]);
+
+        let result = executor
+            .sell(&TokenId::new("yes"), dec!(100), dec!(0.40))
+            .await
+            .unwrap();
+
+        // 60 @ 0.44 + 40 @ 0.42 = 43.2
+        match result {
+            ExecutionResult::Success { average_price, .. } => {
+                assert_eq!(average_price, dec!(0.432));
+            }
+            other => panic!("expected simulated sale, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn sell_fails_when_bids_above_limit_are_too_thin() {
+        let executor = executor_with(vec![bid_book(
+            "yes",
+            vec![(dec!(0.44), dec!(60)), (dec!(0.30), dec!(100))],
+        )]);
+
+        let result = executor
+            .sell(&TokenId::new("yes"), dec!(100), dec!(0.40))
+            .await
+            .unwrap();
+
+        assert!(result.is_failed());
+    }
+}
@@ -0,0 +1,26 @@
+//! Order book staleness checks for opportunity handling.
+//!
+//! Rejects opportunities priced from books that have not been updated
+//! recently, since their quotes may no longer be executable.
+
+use std::time::Duration;
+
+use crate::application::cache::book::BookCache;
+use crate::domain::{id::TokenId, opportunity::Opportunity};
+
+/// Find the oldest opportunity leg whose book is older than `max_age`.
+///
+/// Returns the leg's token and book age, or `None` if every leg's book is
+/// fresh. Legs without a cached book are ignored.
+pub(crate) fn find_stale_leg(
+    opportunity: &Opportunity,
+    cache: &BookCache,
+    max_age: Duration,
+) -> Option<(TokenId, Duration)> {
+    opportunity
+        .legs()
+        .iter()
+        .filter_map(|leg| Some((leg.token_id().clone(), cache.age(leg.token_id())?)))
+        .filter(|(_, age)| *age > max_age)
+        .max_by_key(|(_, age)| *age)
+}
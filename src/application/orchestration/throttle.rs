@@ -0,0 +1,195 @@
+//! Order rate limiting for trade execution.
+//!
+//! A flood of opportunities would otherwise submit orders as fast as they are
+//! detected and run into exchange API rate limits. [`RateLimiter`] is a token
+//! bucket consulted before each execution is spawned: executions take a
+//! token, wait briefly for the next one when the bucket is empty, and are
+//! dropped when the wait would be too long.
+
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+/// Token bucket state.
+#[derive(Debug)]
+struct Bucket {
+    /// Tokens available; negative while executions are queued for refills.
+    tokens: f64,
+    /// Last time the bucket was refilled.
+    updated: Instant,
+}
+
+/// Token-bucket limiter for execution submissions.
+///
+/// The bucket holds up to `burst` tokens and refills continuously at the
+/// configured rate. Each execution reserves one token. When none is left, an
+/// execution is scheduled for the moment the next token arrives, provided
+/// that is within the maximum wait; otherwise it is rejected and nothing is
+/// reserved.
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// Tokens added per second.
+    rate: f64,
+    /// Bucket capacity.
+    burst: f64,
+    /// Longest an execution may be delayed waiting for a token.
+    max_wait: Duration,
+    /// Bucket state, `None` when unlimited.
+    bucket: Option<Mutex<Bucket>>,
+}
+
+impl RateLimiter {
+    /// Create a limiter admitting `rate` executions per second with bursts
+    /// of up to `burst`. The bucket starts full.
+    #[must_use]
+    pub fn new(rate: f64, burst: u32) -> Self {
+        let burst = f64::from(burst.max(1));
+        Self {
+            rate,
+            burst,
+            max_wait: Duration::ZERO,
+            bucket: Some(Mutex::new(Bucket {
+                tokens: burst,
+                updated: Instant::now(),
+            })),
+        }
+    }
+
+    /// Create a limiter that admits every execution immediately.
+    #[must_use]
+    pub fn unlimited() -> Self {
+        Self {
+            rate: 0.0,
+            burst: 0.0,
+            max_wait: Duration::ZERO,
+            bucket: None,
+        }
+    }
+
+    /// Set how long an execution may wait for a token before being dropped.
+    #[must_use]
+    pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+
+    /// Longest an execution may wait for a token.
+    #[must_use]
+    pub const fn max_wait(&self) -> Duration {
+        self.max_wait
+    }
+
+    /// Reserve a token for one execution.
+    ///
+    /// Returns how long to wait before executing (zero when a token is
+    /// available now), or `None` if the wait would exceed the maximum, in
+    /// which case the execution should be dropped.
+    pub fn reserve(&self) -> Option<Duration> {
+        self.reserve_at(Instant::now())
+    }
+
+    fn reserve_at(&self, now: Instant) -> Option<Duration> {
+        let Some(bucket) = &self.bucket else {
+            return Some(Duration::ZERO);
+        };
+        let mut bucket = bucket.lock();
+
+        if now > bucket.updated {
+            let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.burst);
+            bucket.updated = now;
+        }
+
+        let wait = if bucket.tokens >= 1.0 {
+            Duration::ZERO
+        } else {
+            Duration::try_from_secs_f64((1.0 - bucket.tokens) / self.rate).unwrap_or(Duration::MAX)
+        };
+        if wait > self.max_wait {
+            return None;
+        }
+
+        bucket.tokens -= 1.0;
+        Some(wait)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_wait(wait: Option<Duration>, millis: u64) {
+        let wait = wait.expect("expected a reserved token");
+        let expected = Duration::from_millis(millis).as_secs_f64();
+        assert!(
+            (wait.as_secs_f64() - expected).abs() < 1e-6,
+            "waited {wait:?}, expected {millis}ms"
+        );
+    }
+
+    #[test]
+    fn burst_is_available_immediately() {
+        let limiter = RateLimiter::new(4.0, 3);
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            assert_eq!(limiter.reserve_at(start), Some(Duration::ZERO));
+        }
+        assert_eq!(limiter.reserve_at(start), None);
+    }
+
+    #[test]
+    fn bucket_refills_at_configured_rate() {
+        let limiter = RateLimiter::new(4.0, 3);
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.reserve_at(start);
+        }
+
+        // One token every 250ms at 4 per second
+        let later = start + Duration::from_millis(250);
+        assert_eq!(limiter.reserve_at(later), Some(Duration::ZERO));
+        assert_eq!(limiter.reserve_at(later), None);
+
+        let later = start + Duration::from_millis(750);
+        assert_eq!(limiter.reserve_at(later), Some(Duration::ZERO));
+        assert_eq!(limiter.reserve_at(later), Some(Duration::ZERO));
+        assert_eq!(limiter.reserve_at(later), None);
+    }
+
+    #[test]
+    fn refill_is_capped_at_burst() {
+        let limiter = RateLimiter::new(4.0, 3);
+        let start = Instant::now();
+        limiter.reserve_at(start);
+
+        let later = start + Duration::from_secs(60);
+        for _ in 0..3 {
+            assert_eq!(limiter.reserve_at(later), Some(Duration::ZERO));
+        }
+        assert_eq!(limiter.reserve_at(later), None);
+    }
+
+    #[test]
+    fn empty_bucket_queues_within_max_wait() {
+        let limiter = RateLimiter::new(10.0, 1).with_max_wait(Duration::from_millis(200));
+        let start = Instant::now();
+
+        assert_eq!(limiter.reserve_at(start), Some(Duration::ZERO));
+        assert_wait(limiter.reserve_at(start), 100);
+        assert_wait(limiter.reserve_at(start), 200);
+        assert_eq!(limiter.reserve_at(start), None);
+
+        // Queued reservations are honoured before new ones
+        assert_wait(limiter.reserve_at(start + Duration::from_millis(100)), 200);
+    }
+
+    #[test]
+    fn unlimited_never_waits() {
+        let limiter = RateLimiter::unlimited();
+
+        for _ in 0..1000 {
+            assert_eq!(limiter.reserve(), Some(Duration::ZERO));
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Pre-execution edge verification for opportunity handling.
+//!
+//! The slippage check bounds how far each leg may move on its own, but
+//! several legs moving within that bound can still erase the whole edge.
+//! Re-pricing every leg from the current top of book just before execution
+//! confirms the opportunity is still worth committing capital to.
+
+use rust_decimal::Decimal;
+
+use crate::application::cache::book::BookCache;
+use crate::domain::{opportunity::Opportunity, trade::OrderSide};
+use crate::error::RiskError;
+
+/// Confirm the opportunity still clears its strategy's minimum edge.
+///
+/// # Errors
+///
+/// Returns [`RiskError::EdgeUnpriced`] if a leg can no longer be priced,
+/// and [`RiskError::EdgeDisappeared`] if the current edge is not positive or
+/// falls below [`Opportunity::min_edge`].
+pub(crate) fn verify_edge(opportunity: &Opportunity, cache: &BookCache) -> Result<(), RiskError> {
+    let edge = current_edge(opportunity, cache)?;
+    let min_edge = opportunity.min_edge();
+    if edge <= Decimal::ZERO || edge < min_edge {
+        return Err(RiskError::EdgeDisappeared { edge, min_edge });
+    }
+    Ok(())
+}
+
+/// Calculate the opportunity's edge at the current top of book.
+///
+/// Buy legs are re-priced at the best ask and sell legs at the best bid,
+/// and fees are deducted as at detection.
+///
+/// # Errors
+///
+/// Returns [`RiskError::EdgeUnpriced`] for the first leg whose order book is
+/// missing or has no level on the side it trades against.
+fn current_edge(opportunity: &Opportunity, cache: &BookCache) -> Result<Decimal, RiskError> {
+    let mut total = Decimal::ZERO;
+    for leg in opportunity.legs() {
+        let price = cache.get(leg.token_id()).and_then(|book| {
+            let level = match leg.side() {
+                OrderSide::Buy => book.best_ask(),
+                OrderSide::Sell => book.best_bid(),
+            };
+            level.map(|level| level.price())
+        });
+        let Some(price) = price else {
+            return Err(RiskError::EdgeUnpriced {
+                token_id: leg.token_id().to_string(),
+            });
+        };
+        total += price;
+    }
+
+    if opportunity.is_sell() {
+        return Ok(total - opportunity.payout() - opportunity.fees());
+    }
+    Ok(opportunity.payout() - total - opportunity.fees())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::book::{Book, PriceLevel};
+    use crate::domain::id::{MarketId, TokenId};
+    use crate::domain::opportunity::OpportunityLeg;
+    use rust_decimal_macros::dec;
+
+    fn book(token_id: &str, bid: Decimal, ask: Decimal) -> Book {
+        Book::with_levels(
+            TokenId::from(token_id),
+            vec![PriceLevel::new(bid, dec!(100))],
+            vec![PriceLevel::new(ask, dec!(100))],
+        )
+    }
+
+    fn opportunity(legs: Vec<OpportunityLeg>) -> Opportunity {
+        Opportunity::new(MarketId::from("m1"), "Test?", legs, dec!(100), dec!(1))
+    }
+
+    #[test]
+    fn reprices_buy_legs_at_best_ask() {
+        let cache = BookCache::new();
+        cache.update(book("yes", dec!(0.41), dec!(0.42)));
+        cache.update(book("no", dec!(0.49), dec!(0.50)));
+        let opp = opportunity(vec![
+            OpportunityLeg::new(TokenId::from("yes"), dec!(0.40)),
+            OpportunityLeg::new(TokenId::from("no"), dec!(0.50)),
+        ]);
+
+        assert_eq!(current_edge(&opp, &cache).ok(), Some(dec!(0.08)));
+    }
+
+    #[test]
+    fn reprices_sell_legs_at_best_bid() {
+        let cache = BookCache::new();
+        cache.update(book("yes", dec!(0.52), dec!(0.53)));
+        cache.update(book("no", dec!(0.47), dec!(0.48)));
+        let opp = opportunity(vec![
+            OpportunityLeg::sell(TokenId::from("yes"), dec!(0.55)),
+            OpportunityLeg::sell(TokenId::from("no"), dec!(0.50)),
+        ]);
+
+        assert_eq!(current_edge(&opp, &cache).ok(), Some(dec!(-0.01)));
+    }
+
+    #[test]
+    fn missing_book_cannot_be_repriced() {
+        let cache = BookCache::new();
+        cache.update(book("yes", dec!(0.39), dec!(0.40)));
+        let opp = opportunity(vec![
+            OpportunityLeg::new(TokenId::from("yes"), dec!(0.40)),
+            OpportunityLeg::new(TokenId::from("no"), dec!(0.50)),
+        ]);
+
+        let Err(RiskError::EdgeUnpriced { token_id }) = current_edge(&opp, &cache) else {
+            panic!("expected the missing book to be unpriced");
+        };
+        assert_eq!(token_id, "no");
+    }
+
+    #[test]
+    fn verify_rejects_edge_below_strategy_minimum() {
+        let cache = BookCache::new();
+        cache.update(book("yes", dec!(0.41), dec!(0.42)));
+        cache.update(book("no", dec!(0.49), dec!(0.50)));
+        let opp = opportunity(vec![
+            OpportunityLeg::new(TokenId::from("yes"), dec!(0.40)),
+            OpportunityLeg::new(TokenId::from("no"), dec!(0.50)),
+        ]);
+
+        assert!(verify_edge(&opp.clone().with_min_edge(dec!(0.05)), &cache).is_ok());
+        let Err(RiskError::EdgeDisappeared { edge, min_edge }) =
+            verify_edge(&opp.with_min_edge(dec!(0.10)), &cache)
+        else {
+            panic!("expected the edge to fall short of the minimum");
+        };
+        assert_eq!(edge, dec!(0.08));
+        assert_eq!(min_edge, dec!(0.10));
+    }
+
+    #[test]
+    fn verify_rejects_emptied_book() {
+        let cache = BookCache::new();
+        cache.update(book("yes", dec!(0.39), dec!(0.40)));
+        cache.update(Book::with_levels(TokenId::from("no"), vec![], vec![]));
+        let opp = opportunity(vec![
+            OpportunityLeg::new(TokenId::from("yes"), dec!(0.40)),
+            OpportunityLeg::new(TokenId::from("no"), dec!(0.50)),
+        ]);
+
+        let error = verify_edge(&opp, &cache).unwrap_err();
+        assert!(error.to_string().starts_with("edge_disappeared"));
+    }
+}
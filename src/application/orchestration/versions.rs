@@ -0,0 +1,125 @@
+//! Skipping detection for markets whose books have not moved.
+//!
+//! Chatty streams resend books whose levels are unchanged. [`BookVersionTracker`]
+//! remembers the [`BookCache`] versions each market's tokens had at its last
+//! detection, so detection only reruns once one of them changes. Markets whose
+//! last detection found opportunities are forgotten, so an opportunity that
+//! persists is evaluated again on the next update even if the book is resent
+//! unchanged.
+
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+
+use crate::application::cache::book::BookCache;
+use crate::domain::id::MarketId;
+use crate::domain::market::Market;
+
+/// Book versions seen at each market's last detection.
+#[derive(Default)]
+pub struct BookVersionTracker {
+    /// Token versions per market, in outcome order.
+    seen: Mutex<HashMap<MarketId, Vec<Option<u64>>>>,
+}
+
+impl BookVersionTracker {
+    /// Create an empty tracker; every market is detected on first sight.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return whether any of the market's tokens changed since the last call
+    /// that returned `true` for it, recording the current versions if so.
+    pub fn should_detect(&self, market: &Market, cache: &BookCache) -> bool {
+        let token_ids: Vec<_> = market.token_ids().into_iter().cloned().collect();
+        let versions = cache.versions(&token_ids);
+
+        let mut seen = self.seen.lock();
+        if seen.get(market.market_id()) == Some(&versions) {
+            return false;
+        }
+        seen.insert(market.market_id().clone(), versions);
+        true
+    }
+
+    /// Forget a market's versions so its next update is always detected.
+    pub fn forget(&self, market_id: &MarketId) {
+        self.seen.lock().remove(market_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::book::{Book, PriceLevel};
+    use crate::domain::id::TokenId;
+    use crate::domain::market::Outcome;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn market() -> Market {
+        Market::new(
+            MarketId::from("m1"),
+            "Will it happen?",
+            vec![
+                Outcome::new(TokenId::from("yes"), "Yes"),
+                Outcome::new(TokenId::from("no"), "No"),
+            ],
+            dec!(1),
+        )
+    }
+
+    fn book(token_id: &str, ask: Decimal) -> Book {
+        Book::with_levels(
+            TokenId::from(token_id),
+            vec![PriceLevel::new(ask - dec!(0.01), dec!(100))],
+            vec![PriceLevel::new(ask, dec!(100))],
+        )
+    }
+
+    #[test]
+    fn detects_once_until_a_token_changes() {
+        let cache = BookCache::new();
+        let tracker = BookVersionTracker::new();
+        let market = market();
+        cache.update(book("yes", dec!(0.40)));
+        cache.update(book("no", dec!(0.50)));
+
+        assert!(tracker.should_detect(&market, &cache));
+        assert!(!tracker.should_detect(&market, &cache));
+
+        cache.update(book("no", dec!(0.48)));
+        assert!(tracker.should_detect(&market, &cache));
+    }
+
+    #[test]
+    fn unrelated_token_update_is_skipped() {
+        let cache = BookCache::new();
+        let tracker = BookVersionTracker::new();
+        let market = market();
+        cache.update(book("yes", dec!(0.40)));
+        cache.update(book("no", dec!(0.50)));
+        assert!(tracker.should_detect(&market, &cache));
+
+        cache.update(book("other", dec!(0.30)));
+        cache.update(book("yes", dec!(0.40)));
+
+        assert!(!tracker.should_detect(&market, &cache));
+    }
+
+    #[test]
+    fn forgotten_market_is_detected_again() {
+        let cache = BookCache::new();
+        let tracker = BookVersionTracker::new();
+        let market = market();
+        cache.update(book("yes", dec!(0.40)));
+        cache.update(book("no", dec!(0.50)));
+        assert!(tracker.should_detect(&market, &cache));
+
+        tracker.forget(market.market_id());
+
+        assert!(tracker.should_detect(&market, &cache));
+        assert!(!tracker.should_detect(&market, &cache));
+    }
+}
@@ -1,18 +1,31 @@
 //! Position lifecycle management service.
 //!
 //! Handles position state transitions from open to closed, integrating with
-//! the statistics recorder for trade tracking and PnL reporting.
+//! the statistics recorder for trade tracking and PnL reporting, and with an
+//! optional position store so open positions survive restarts.
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
+use std::time::Duration;
 
 use rust_decimal::Decimal;
-use tracing::{debug, info};
+use rust_decimal_macros::dec;
+use tracing::{debug, info, warn};
 
+use crate::application::cache::book::BookCache;
 use crate::application::cache::position::PositionTracker;
+use crate::application::state::AppState;
 use crate::domain::{
-    id::MarketId, id::PositionId, money::Price, position::Position, stats::TradeCloseEvent,
+    id::MarketId, id::PositionId, id::TokenId, money::Price, money::Volume, position::Position,
+    position::PositionLeg, stats::TradeCloseEvent,
 };
+use crate::error::Result;
+use crate::port::outbound::exchange::{ArbitrageExecutor, ExecutionResult, Holding};
 use crate::port::outbound::stats::StatsRecorder;
+use crate::port::outbound::store::PositionStore;
+
+/// Share difference below which a holding counts as matching its positions.
+const HOLDING_TOLERANCE: Volume = dec!(0.000001);
 
 /// Reason for closing a position.
 ///
@@ -60,6 +73,61 @@ impl std::fmt::Display for CloseReason {
     }
 }
 
+/// Stop-loss and take-profit thresholds for open positions.
+///
+/// Thresholds are fractions of entry cost (e.g., 0.10 = 10%). A `None`
+/// threshold disables that exit.
+#[derive(Debug, Clone, Default)]
+pub struct ExitPolicy {
+    /// Loss fraction of entry cost at which a position is closed.
+    pub stop_loss_pct: Option<Decimal>,
+    /// Gain fraction of entry cost at which a position is closed.
+    pub take_profit_pct: Option<Decimal>,
+    /// Skip positions flagged as held to settlement.
+    pub exempt_held_to_settlement: bool,
+}
+
+impl ExitPolicy {
+    /// Returns true if at least one exit threshold is configured.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.stop_loss_pct.is_some() || self.take_profit_pct.is_some()
+    }
+
+    /// Determine whether a position should exit given its mark value.
+    ///
+    /// Returns the close reason for the first breached threshold, with
+    /// stop loss taking precedence over take profit.
+    #[must_use]
+    pub fn evaluate(
+        &self,
+        entry_cost: Price,
+        mark_value: Price,
+        mark_price: Price,
+    ) -> Option<CloseReason> {
+        if entry_cost <= Decimal::ZERO {
+            return None;
+        }
+        let return_pct = (mark_value - entry_cost) / entry_cost;
+
+        if let Some(stop_loss) = self.stop_loss_pct {
+            if return_pct <= -stop_loss {
+                return Some(CloseReason::StopLoss {
+                    trigger_price: mark_price,
+                });
+            }
+        }
+        if let Some(take_profit) = self.take_profit_pct {
+            if return_pct >= take_profit {
+                return Some(CloseReason::TakeProfit {
+                    trigger_price: mark_price,
+                });
+            }
+        }
+        None
+    }
+}
+
 /// Result of a successful position close operation.
 #[derive(Debug, Clone)]
 pub struct CloseResult {
@@ -71,20 +139,136 @@ pub struct CloseResult {
     pub reason: CloseReason,
 }
 
+/// Outcome of draining open positions at shutdown.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DrainReport {
+    /// Open positions when the drain started.
+    pub positions: usize,
+    /// Positions whose legs were all sold and that were closed.
+    pub closed: usize,
+    /// Positions left open because a leg could not be sold.
+    pub failed: usize,
+    /// Whether the timeout elapsed before every position was attempted.
+    pub timed_out: bool,
+    /// Realized profit or loss across closed positions.
+    pub realized_pnl: Price,
+    /// Entry cost of positions still open after the drain.
+    pub remaining_exposure: Price,
+}
+
+/// Mismatch between tracked positions and exchange holdings for one token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HoldingDiscrepancy {
+    /// Token whose share counts disagree.
+    pub token_id: TokenId,
+    /// Shares the tracked positions expect to hold.
+    pub expected: Volume,
+    /// Shares the exchange reports as held.
+    pub held: Volume,
+}
+
 /// Manages position lifecycle transitions and statistics recording.
 ///
-/// Coordinates position closures across the tracker and stats recorder,
-/// ensuring consistent state and metrics.
+/// Coordinates position opens and closures across the tracker, stats
+/// recorder, and position store, ensuring consistent state and metrics.
 pub struct PositionManager {
     /// Statistics recorder for trade close events.
     stats: Arc<dyn StatsRecorder>,
+    /// Store persisting positions across restarts.
+    store: Option<Arc<dyn PositionStore>>,
 }
 
 impl PositionManager {
     /// Create a new position manager with the given stats recorder.
     #[must_use]
     pub fn new(stats: Arc<dyn StatsRecorder>) -> Self {
-        Self { stats }
+        Self { stats, store: None }
+    }
+
+    /// Persist positions to the given store on open and close.
+    #[must_use]
+    pub fn with_store(mut self, store: Arc<dyn PositionStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Load persisted open positions into the tracker.
+    ///
+    /// ID generation continues after the highest stored ID so new positions
+    /// never overwrite stored ones. Returns the number of positions restored,
+    /// which is 0 without a store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be read.
+    pub fn restore(&self, tracker: &mut PositionTracker) -> Result<usize> {
+        let Some(store) = &self.store else {
+            return Ok(0);
+        };
+        tracker.advance_ids_past(store.last_id()?);
+
+        let positions = store.load_open()?;
+        let count = positions.len();
+        for position in positions {
+            tracker.add(position);
+        }
+        if count > 0 {
+            info!(
+                positions = count,
+                exposure = %tracker.total_exposure(),
+                "Restored open positions"
+            );
+        }
+        Ok(count)
+    }
+
+    /// Persist a position, logging rather than failing if the store errors.
+    pub fn save(&self, position: &Position) {
+        if let Some(store) = &self.store {
+            if let Err(error) = store.save(position) {
+                warn!(position_id = %position.id(), error = %error, "Failed to persist position");
+            }
+        }
+    }
+
+    /// Record a newly opened position in the tracker and the store.
+    pub fn open_position(&self, tracker: &mut PositionTracker, position: Position) {
+        self.save(&position);
+        tracker.add(position);
+    }
+
+    /// Compare tracked positions against the holdings an exchange reports.
+    ///
+    /// Expected shares are summed per token across every position that is
+    /// not closed. Returns one discrepancy per token whose expected and held
+    /// shares differ, including tokens held without a tracked position,
+    /// sorted by token ID.
+    #[must_use]
+    pub fn reconcile(tracker: &PositionTracker, holdings: &[Holding]) -> Vec<HoldingDiscrepancy> {
+        let mut expected: BTreeMap<&str, Volume> = BTreeMap::new();
+        for position in tracker.all().filter(|p| !p.status().is_closed()) {
+            for leg in position.legs() {
+                *expected.entry(leg.token_id().as_str()).or_default() += leg.size();
+            }
+        }
+        let mut held: BTreeMap<&str, Volume> = BTreeMap::new();
+        for holding in holdings {
+            *held.entry(holding.token_id.as_str()).or_default() += holding.size;
+        }
+
+        let tokens: BTreeSet<&str> = expected.keys().chain(held.keys()).copied().collect();
+        tokens
+            .into_iter()
+            .filter_map(|token| {
+                let expected = expected.get(token).copied().unwrap_or_default();
+                let held = held.get(token).copied().unwrap_or_default();
+                ((expected - held).abs() > HOLDING_TOLERANCE).then(|| HoldingDiscrepancy {
+                    token_id: TokenId::from(token),
+                    expected,
+                    held,
+                })
+            })
+            .collect()
     }
 
     /// Close a position by ID with the given realized PnL.
@@ -112,6 +296,9 @@ impl PositionManager {
 
         // Close in tracker
         tracker.close(position_id, realized_pnl)?;
+        if let Some(position) = tracker.get(position_id) {
+            self.save(position);
+        }
 
         info!(
             position_id = %position_id,
@@ -173,6 +360,168 @@ impl PositionManager {
         total_pnl
     }
 
+    /// Close open positions that breach the exit policy thresholds.
+    ///
+    /// Each position is marked at the best bid of every leg from the book
+    /// cache, so the mark value is what the legs would fetch if sold now.
+    /// Positions with a leg lacking a bid are skipped, as are positions held
+    /// to settlement when the policy exempts them.
+    ///
+    /// Returns the results for every position that was closed.
+    pub fn check_exit_conditions(
+        &self,
+        tracker: &mut PositionTracker,
+        cache: &BookCache,
+        policy: &ExitPolicy,
+    ) -> Vec<CloseResult> {
+        if !policy.is_enabled() {
+            return Vec::new();
+        }
+
+        let exits: Vec<(PositionId, Price, CloseReason, Option<i32>)> = tracker
+            .open_positions()
+            .filter(|p| !(policy.exempt_held_to_settlement && p.is_held_to_settlement()))
+            .filter_map(|p| {
+                let mark_value = Self::mark_value(p, cache)?;
+                let mark_price = if p.guaranteed_payout() > Decimal::ZERO {
+                    mark_value / p.guaranteed_payout()
+                } else {
+                    mark_value
+                };
+                let reason = policy.evaluate(p.entry_cost(), mark_value, mark_price)?;
+                Some((p.id(), mark_value - p.entry_cost(), reason, p.trade_id()))
+            })
+            .collect();
+
+        exits
+            .into_iter()
+            .filter_map(|(pos_id, pnl, reason, trade_id)| {
+                self.close_position(tracker, pos_id, pnl, reason, trade_id)
+            })
+            .collect()
+    }
+
+    /// Exit every open position at market before shutdown.
+    ///
+    /// Each leg is sold at its best bid lowered by `discount` so the order
+    /// crosses the book. A position is closed once all of its legs sell;
+    /// otherwise it stays open and counts as failed. Positions not attempted
+    /// before `timeout` elapses are left open.
+    pub async fn drain(
+        &self,
+        state: &AppState,
+        executor: &dyn ArbitrageExecutor,
+        cache: &BookCache,
+        discount: Decimal,
+        timeout: Duration,
+    ) -> DrainReport {
+        let open: Vec<(PositionId, Vec<PositionLeg>, Price, Option<i32>)> = state
+            .positions()
+            .open_positions()
+            .map(|p| (p.id(), p.legs().to_vec(), p.entry_cost(), p.trade_id()))
+            .collect();
+        let mut report = DrainReport {
+            positions: open.len(),
+            ..DrainReport::default()
+        };
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        for (pos_id, legs, entry_cost, trade_id) in open {
+            let sale = tokio::time::timeout_at(
+                deadline,
+                Self::sell_legs(executor, cache, &legs, discount),
+            )
+            .await;
+            match sale {
+                Err(_) => {
+                    warn!(position_id = %pos_id, "Drain timed out");
+                    report.timed_out = true;
+                    break;
+                }
+                Ok(Err(error)) => {
+                    warn!(position_id = %pos_id, error = %error, "Failed to exit position");
+                    report.failed += 1;
+                }
+                Ok(Ok(proceeds)) => {
+                    let reason = CloseReason::SystemExit {
+                        reason: "shutdown drain".to_string(),
+                    };
+                    let mut positions = state.positions_mut();
+                    if let Some(result) = self.close_position(
+                        &mut positions,
+                        pos_id,
+                        proceeds - entry_cost,
+                        reason,
+                        trade_id,
+                    ) {
+                        report.closed += 1;
+                        report.realized_pnl += result.realized_pnl;
+                    }
+                }
+            }
+        }
+
+        report.remaining_exposure = state.positions().total_exposure();
+        report
+    }
+
+    /// Sell every leg at its discounted best bid, returning total proceeds.
+    ///
+    /// Stops at the first leg that has no bid or does not fully sell.
+    async fn sell_legs(
+        executor: &dyn ArbitrageExecutor,
+        cache: &BookCache,
+        legs: &[PositionLeg],
+        discount: Decimal,
+    ) -> Result<Price, String> {
+        let mut proceeds = Decimal::ZERO;
+        for leg in legs {
+            let token_id = leg.token_id();
+            let bid = cache
+                .get(token_id)
+                .and_then(|book| book.best_bid().map(|level| level.price()))
+                .ok_or_else(|| format!("no bid for {token_id}"))?;
+            let limit = bid * (Decimal::ONE - discount);
+
+            match executor.sell(token_id, leg.size(), limit).await {
+                Ok(ExecutionResult::Success {
+                    filled_amount,
+                    average_price,
+                    ..
+                }) => {
+                    debug!(token = %token_id, price = %average_price, "Position leg sold");
+                    proceeds += filled_amount * average_price;
+                }
+                Ok(ExecutionResult::PartialFill {
+                    filled_amount,
+                    remaining_amount,
+                    ..
+                }) => {
+                    return Err(format!(
+                        "{token_id} sold {filled_amount}, {remaining_amount} unsold"
+                    ));
+                }
+                Ok(ExecutionResult::Failed { reason }) => {
+                    return Err(format!("{token_id}: {reason}"));
+                }
+                Err(e) => return Err(format!("{token_id}: {e}")),
+            }
+        }
+        Ok(proceeds)
+    }
+
+    /// Value a position at the best bid of each of its legs.
+    ///
+    /// Returns `None` if any leg has no book or no bids.
+    #[must_use]
+    pub fn mark_value(position: &Position, cache: &BookCache) -> Option<Price> {
+        position.legs().iter().try_fold(Decimal::ZERO, |acc, leg| {
+            let book = cache.get(leg.token_id())?;
+            let bid = book.best_bid()?;
+            Some(acc + leg.size() * bid.price())
+        })
+    }
+
     /// Calculate settlement PnL for an arbitrage position.
     ///
     /// For arbitrage positions that hold all outcomes, the PnL is simply
@@ -208,7 +557,10 @@ pub struct MarketSettledEvent {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{id::TokenId, position::PositionLeg, position::PositionStatus};
+    use crate::application::orchestration::simulator::SimulatedExecutor;
+    use crate::domain::book::{Book, PriceLevel};
+    use crate::domain::{id::TokenId, position::PositionStatus};
+    use crate::testkit::stats::in_memory_stats_recorder;
     use chrono::Utc;
     use rust_decimal_macros::dec;
 
@@ -261,4 +613,430 @@ mod tests {
         let pnl = PositionManager::calculate_arbitrage_pnl(&position, dec!(1.00));
         assert_eq!(pnl, dec!(0));
     }
+
+    #[test]
+    fn calculate_arbitrage_pnl_loss_when_cost_exceeds_payout() {
+        let position = make_position(1, "market-1", dec!(102));
+        let pnl = PositionManager::calculate_arbitrage_pnl(&position, dec!(1.00));
+        assert_eq!(pnl, dec!(-2));
+    }
+
+    #[test]
+    fn calculate_arbitrage_pnl_zero_payout_forfeits_entry_cost() {
+        let position = make_position(1, "market-1", dec!(95));
+        let pnl = PositionManager::calculate_arbitrage_pnl(&position, Decimal::ZERO);
+        assert_eq!(pnl, dec!(-95));
+    }
+
+    #[test]
+    fn close_all_for_market_settles_only_that_market() {
+        let manager = PositionManager::new(in_memory_stats_recorder());
+        let mut tracker = PositionTracker::new();
+        tracker.add(make_position(1, "market-1", dec!(95)));
+        tracker.add(make_position(2, "market-1", dec!(102)));
+        tracker.add(make_position(3, "market-2", dec!(90)));
+
+        let total = manager.close_all_for_market(
+            &mut tracker,
+            &MarketId::new("market-1"),
+            |pos| PositionManager::calculate_arbitrage_pnl(pos, dec!(1.00)),
+            CloseReason::Settlement {
+                winning_outcome: "Yes".to_string(),
+            },
+        );
+
+        // +$5 on the first position, -$2 on the second
+        assert_eq!(total, dec!(3));
+        assert_eq!(tracker.open_count(), 1);
+        assert!(tracker.get(PositionId::new(3)).unwrap().is_open());
+    }
+
+    /// In-memory position store shared across simulated restarts.
+    #[derive(Default)]
+    struct MemoryPositionStore {
+        saved: parking_lot::Mutex<BTreeMap<u64, Position>>,
+    }
+
+    impl PositionStore for MemoryPositionStore {
+        fn save(&self, position: &Position) -> Result<()> {
+            self.saved
+                .lock()
+                .insert(position.id().value(), position.clone());
+            Ok(())
+        }
+
+        fn load_open(&self) -> Result<Vec<Position>> {
+            Ok(self
+                .saved
+                .lock()
+                .values()
+                .filter(|p| !p.status().is_closed())
+                .cloned()
+                .collect())
+        }
+
+        fn last_id(&self) -> Result<u64> {
+            Ok(self.saved.lock().keys().next_back().copied().unwrap_or(0))
+        }
+    }
+
+    #[test]
+    fn open_and_close_are_persisted() {
+        let store = Arc::new(MemoryPositionStore::default());
+        let manager = PositionManager::new(in_memory_stats_recorder()).with_store(store.clone());
+        let mut tracker = PositionTracker::new();
+
+        let id = tracker.next_id();
+        manager.open_position(&mut tracker, make_position(id.value(), "m1", dec!(95)));
+        assert_eq!(store.load_open().unwrap().len(), 1);
+
+        manager.close_position(&mut tracker, id, dec!(5), CloseReason::ManualExit, None);
+        assert!(store.load_open().unwrap().is_empty());
+        assert_eq!(
+            store.saved.lock()[&id.value()].status(),
+            &PositionStatus::Closed { pnl: dec!(5) }
+        );
+    }
+
+    #[test]
+    fn restore_survives_restart_and_continues_ids() {
+        let store = Arc::new(MemoryPositionStore::default());
+        let before = PositionManager::new(in_memory_stats_recorder()).with_store(store.clone());
+        let mut tracker = PositionTracker::new();
+        for cost in [dec!(95), dec!(90), dec!(80)] {
+            let id = tracker.next_id();
+            before.open_position(&mut tracker, make_position(id.value(), "m1", cost));
+        }
+        before.close_position(
+            &mut tracker,
+            PositionId::new(3),
+            dec!(20),
+            CloseReason::ManualExit,
+            None,
+        );
+
+        let after = PositionManager::new(in_memory_stats_recorder()).with_store(store);
+        let mut restored = PositionTracker::new();
+        let count = after.restore(&mut restored).unwrap();
+
+        assert_eq!(count, 2);
+        assert_eq!(restored.total_exposure(), dec!(185));
+        assert_eq!(restored.next_id(), PositionId::new(4));
+    }
+
+    #[test]
+    fn restore_without_store_is_a_no_op() {
+        let manager = PositionManager::new(in_memory_stats_recorder());
+        let mut tracker = PositionTracker::new();
+
+        assert_eq!(manager.restore(&mut tracker).unwrap(), 0);
+        assert_eq!(tracker.open_count(), 0);
+    }
+
+    fn holding(token_id: &str, size: Decimal) -> Holding {
+        Holding {
+            token_id: TokenId::new(token_id),
+            size,
+        }
+    }
+
+    #[test]
+    fn reconcile_matching_holdings_reports_nothing() {
+        let mut tracker = PositionTracker::new();
+        tracker.add(make_position(1, "m1", dec!(95)));
+        tracker.add(make_position(2, "m1", dec!(90)));
+        let mut closed = make_position(3, "m1", dec!(80));
+        closed.close(dec!(20));
+        tracker.add(closed);
+
+        let holdings = [holding("token-1", dec!(200))];
+
+        assert!(PositionManager::reconcile(&tracker, &holdings).is_empty());
+    }
+
+    #[test]
+    fn reconcile_reports_missing_short_and_untracked_tokens() {
+        let mut tracker = PositionTracker::new();
+        tracker.add(make_position(1, "m1", dec!(95)));
+        tracker.add(Position::new(
+            PositionId::new(2),
+            MarketId::new("m2"),
+            vec![
+                PositionLeg::new(TokenId::new("yes"), dec!(50), dec!(0.40)),
+                PositionLeg::new(TokenId::new("no"), dec!(50), dec!(0.55)),
+            ],
+            dec!(47.5),
+            dec!(50),
+            Utc::now(),
+            PositionStatus::Open,
+        ));
+
+        let holdings = [
+            holding("yes", dec!(50)),
+            holding("no", dec!(20)),
+            holding("stray", dec!(10)),
+        ];
+
+        let diff = PositionManager::reconcile(&tracker, &holdings);
+
+        assert_eq!(
+            diff,
+            vec![
+                HoldingDiscrepancy {
+                    token_id: TokenId::new("no"),
+                    expected: dec!(50),
+                    held: dec!(20),
+                },
+                HoldingDiscrepancy {
+                    token_id: TokenId::new("stray"),
+                    expected: dec!(0),
+                    held: dec!(10),
+                },
+                HoldingDiscrepancy {
+                    token_id: TokenId::new("token-1"),
+                    expected: dec!(100),
+                    held: dec!(0),
+                },
+            ]
+        );
+    }
+
+    fn cache_with_bid(token_id: &str, bid: Decimal) -> BookCache {
+        let cache = BookCache::new();
+        cache.update(Book::with_levels(
+            TokenId::new(token_id),
+            vec![PriceLevel::new(bid, dec!(500))],
+            vec![PriceLevel::new(bid + dec!(0.01), dec!(500))],
+        ));
+        cache
+    }
+
+    fn policy(stop_loss: Option<Decimal>, take_profit: Option<Decimal>) -> ExitPolicy {
+        ExitPolicy {
+            stop_loss_pct: stop_loss,
+            take_profit_pct: take_profit,
+            exempt_held_to_settlement: true,
+        }
+    }
+
+    #[test]
+    fn exit_policy_evaluate_thresholds() {
+        let policy = policy(Some(dec!(0.10)), Some(dec!(0.20)));
+        assert_eq!(
+            policy.evaluate(dec!(50), dec!(44), dec!(0.44)),
+            Some(CloseReason::StopLoss {
+                trigger_price: dec!(0.44)
+            })
+        );
+        assert_eq!(
+            policy.evaluate(dec!(50), dec!(61), dec!(0.61)),
+            Some(CloseReason::TakeProfit {
+                trigger_price: dec!(0.61)
+            })
+        );
+        assert_eq!(policy.evaluate(dec!(50), dec!(52), dec!(0.52)), None);
+        assert!(!ExitPolicy::default().is_enabled());
+    }
+
+    #[test]
+    fn check_exit_conditions_triggers_stop_loss() {
+        let manager = PositionManager::new(in_memory_stats_recorder());
+        let mut tracker = PositionTracker::new();
+        // 100 shares at $0.50, marked at $0.40 bid = 20% loss
+        tracker.add(make_position(1, "market-1", dec!(50)));
+        let cache = cache_with_bid("token-1", dec!(0.40));
+
+        let results =
+            manager.check_exit_conditions(&mut tracker, &cache, &policy(Some(dec!(0.10)), None));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].realized_pnl, dec!(-10));
+        assert_eq!(
+            results[0].reason,
+            CloseReason::StopLoss {
+                trigger_price: dec!(0.40)
+            }
+        );
+        assert_eq!(tracker.open_count(), 0);
+    }
+
+    #[test]
+    fn check_exit_conditions_triggers_take_profit() {
+        let manager = PositionManager::new(in_memory_stats_recorder());
+        let mut tracker = PositionTracker::new();
+        tracker.add(make_position(1, "market-1", dec!(50)));
+        let cache = cache_with_bid("token-1", dec!(0.65));
+
+        let results =
+            manager.check_exit_conditions(&mut tracker, &cache, &policy(None, Some(dec!(0.25))));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].realized_pnl, dec!(15));
+        assert!(matches!(results[0].reason, CloseReason::TakeProfit { .. }));
+    }
+
+    #[test]
+    fn check_exit_conditions_within_thresholds_keeps_position() {
+        let manager = PositionManager::new(in_memory_stats_recorder());
+        let mut tracker = PositionTracker::new();
+        tracker.add(make_position(1, "market-1", dec!(50)));
+        let cache = cache_with_bid("token-1", dec!(0.48));
+
+        let results = manager.check_exit_conditions(
+            &mut tracker,
+            &cache,
+            &policy(Some(dec!(0.10)), Some(dec!(0.25))),
+        );
+
+        assert!(results.is_empty());
+        assert_eq!(tracker.open_count(), 1);
+    }
+
+    #[test]
+    fn check_exit_conditions_skips_held_to_settlement() {
+        let manager = PositionManager::new(in_memory_stats_recorder());
+        let mut tracker = PositionTracker::new();
+        tracker.add(make_position(1, "market-1", dec!(50)).with_held_to_settlement());
+        let cache = cache_with_bid("token-1", dec!(0.10));
+
+        let results =
+            manager.check_exit_conditions(&mut tracker, &cache, &policy(Some(dec!(0.10)), None));
+        assert!(results.is_empty());
+
+        let mut not_exempt = policy(Some(dec!(0.10)), None);
+        not_exempt.exempt_held_to_settlement = false;
+        let results = manager.check_exit_conditions(&mut tracker, &cache, &not_exempt);
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn check_exit_conditions_skips_positions_without_bids() {
+        let manager = PositionManager::new(in_memory_stats_recorder());
+        let mut tracker = PositionTracker::new();
+        tracker.add(make_position(1, "market-1", dec!(50)));
+        let cache = BookCache::new();
+
+        let results =
+            manager.check_exit_conditions(&mut tracker, &cache, &policy(Some(dec!(0.10)), None));
+
+        assert!(results.is_empty());
+        assert_eq!(tracker.open_count(), 1);
+    }
+
+    /// Executor whose sell orders never complete.
+    struct HangingSeller;
+
+    #[async_trait::async_trait]
+    impl ArbitrageExecutor for HangingSeller {
+        async fn execute_arbitrage(
+            &self,
+            _opportunity: &crate::domain::opportunity::Opportunity,
+        ) -> Result<crate::domain::trade::TradeResult, crate::error::Error> {
+            unimplemented!("not used by drain")
+        }
+
+        async fn sell(
+            &self,
+            _token_id: &TokenId,
+            _size: Decimal,
+            _limit: Decimal,
+        ) -> Result<ExecutionResult, crate::error::Error> {
+            std::future::pending().await
+        }
+
+        async fn cancel(
+            &self,
+            _order_id: &crate::domain::id::OrderId,
+        ) -> Result<(), crate::error::Error> {
+            Ok(())
+        }
+
+        fn exchange_name(&self) -> &'static str {
+            "hanging"
+        }
+    }
+
+    fn state_with_position(entry_cost: Decimal) -> AppState {
+        let state = AppState::default();
+        state
+            .positions_mut()
+            .add(make_position(1, "market-1", entry_cost));
+        state
+    }
+
+    #[tokio::test]
+    async fn drain_sells_legs_and_closes_positions() {
+        let manager = PositionManager::new(in_memory_stats_recorder());
+        let state = state_with_position(dec!(50));
+        let cache = Arc::new(cache_with_bid("token-1", dec!(0.40)));
+        let executor = SimulatedExecutor::new(Arc::clone(&cache));
+
+        let report = manager
+            .drain(
+                &state,
+                &executor,
+                &cache,
+                dec!(0.02),
+                Duration::from_secs(5),
+            )
+            .await;
+
+        // 100 shares sold at the $0.40 bid against a $50 entry
+        assert_eq!(
+            report,
+            DrainReport {
+                positions: 1,
+                closed: 1,
+                failed: 0,
+                timed_out: false,
+                realized_pnl: dec!(-10),
+                remaining_exposure: Decimal::ZERO,
+            }
+        );
+        assert_eq!(state.positions().open_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn drain_leaves_positions_without_bids_open() {
+        let manager = PositionManager::new(in_memory_stats_recorder());
+        let state = state_with_position(dec!(50));
+        let cache = Arc::new(BookCache::new());
+        let executor = SimulatedExecutor::new(Arc::clone(&cache));
+
+        let report = manager
+            .drain(
+                &state,
+                &executor,
+                &cache,
+                dec!(0.02),
+                Duration::from_secs(5),
+            )
+            .await;
+
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.closed, 0);
+        assert_eq!(report.remaining_exposure, dec!(50));
+        assert_eq!(state.positions().open_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn drain_stops_at_timeout() {
+        let manager = PositionManager::new(in_memory_stats_recorder());
+        let state = state_with_position(dec!(50));
+        let cache = cache_with_bid("token-1", dec!(0.40));
+
+        let report = manager
+            .drain(
+                &state,
+                &HangingSeller,
+                &cache,
+                dec!(0.02),
+                Duration::from_millis(20),
+            )
+            .await;
+
+        assert!(report.timed_out);
+        assert_eq!(report.closed, 0);
+        assert_eq!(report.remaining_exposure, dec!(50));
+    }
 }
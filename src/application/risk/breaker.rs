@@ -0,0 +1,230 @@
+//! Execution failure circuit breaker.
+//!
+//! Counts consecutive failed executions and trips once a configured streak is
+//! reached, so a systematically broken exchange connection stops receiving
+//! orders until the cooldown elapses or an operator resumes trading.
+
+use std::time::{Duration, Instant};
+
+/// Tracks consecutive execution failures and when the breaker tripped.
+///
+/// Failures only count toward the streak while each follows the previous one
+/// within the window; a success or a stale streak starts the count over.
+#[derive(Debug, Clone)]
+pub struct FailureBreaker {
+    /// Failures in a row that trip the breaker. Zero disables the breaker.
+    max_consecutive_failures: u32,
+    /// Maximum gap between failures for them to count as one streak.
+    window: Duration,
+    /// How long the breaker stays tripped before trading resumes.
+    cooldown: Duration,
+    /// Length of the current failure streak.
+    consecutive_failures: u32,
+    /// Time of the most recent failure in the streak.
+    last_failure: Option<Instant>,
+    /// Time the breaker tripped, while it is tripped.
+    tripped_at: Option<Instant>,
+    /// Reason trading was paused, while the breaker is tripped.
+    trip_reason: Option<String>,
+}
+
+impl FailureBreaker {
+    /// Create a breaker that trips after `max_consecutive_failures` failures.
+    #[must_use]
+    pub const fn new(max_consecutive_failures: u32, window: Duration, cooldown: Duration) -> Self {
+        Self {
+            max_consecutive_failures,
+            window,
+            cooldown,
+            consecutive_failures: 0,
+            last_failure: None,
+            tripped_at: None,
+            trip_reason: None,
+        }
+    }
+
+    /// Create a breaker that never trips.
+    #[must_use]
+    pub const fn disabled() -> Self {
+        Self::new(0, Duration::ZERO, Duration::ZERO)
+    }
+
+    /// Return true if the breaker is configured to trip.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.max_consecutive_failures > 0
+    }
+
+    /// Return true if the breaker has tripped and not yet been reset.
+    #[must_use]
+    pub const fn is_tripped(&self) -> bool {
+        self.tripped_at.is_some()
+    }
+
+    /// Return the reason the breaker paused trading, while it is tripped.
+    #[must_use]
+    pub fn trip_reason(&self) -> Option<&str> {
+        self.trip_reason.as_deref()
+    }
+
+    /// Return the length of the current failure streak.
+    #[must_use]
+    pub const fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Return the configured failure streak that trips the breaker.
+    #[must_use]
+    pub const fn max_consecutive_failures(&self) -> u32 {
+        self.max_consecutive_failures
+    }
+
+    /// Record a failed execution observed at `now`.
+    ///
+    /// Returns true if this failure tripped the breaker.
+    pub fn record_failure_at(&mut self, now: Instant) -> bool {
+        if !self.is_enabled() || self.is_tripped() {
+            return false;
+        }
+
+        let continues_streak = match self.last_failure {
+            Some(last) => now.saturating_duration_since(last) <= self.window,
+            None => false,
+        };
+        self.consecutive_failures = if continues_streak {
+            self.consecutive_failures + 1
+        } else {
+            1
+        };
+        self.last_failure = Some(now);
+
+        if self.consecutive_failures >= self.max_consecutive_failures {
+            self.tripped_at = Some(now);
+            self.trip_reason = Some(format!(
+                "{} consecutive execution failures",
+                self.consecutive_failures
+            ));
+            return true;
+        }
+        false
+    }
+
+    /// Record a failed execution observed now.
+    pub fn record_failure(&mut self) -> bool {
+        self.record_failure_at(Instant::now())
+    }
+
+    /// Record a successful execution, ending any failure streak.
+    pub fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.last_failure = None;
+    }
+
+    /// Return true if the breaker is tripped and its cooldown has elapsed at `now`.
+    #[must_use]
+    pub fn cooldown_elapsed_at(&self, now: Instant) -> bool {
+        match self.tripped_at {
+            Some(tripped_at) => now.saturating_duration_since(tripped_at) >= self.cooldown,
+            None => false,
+        }
+    }
+
+    /// Clear the failure streak and any tripped state.
+    pub fn reset(&mut self) {
+        self.record_success();
+        self.tripped_at = None;
+        self.trip_reason = None;
+    }
+}
+
+impl Default for FailureBreaker {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker() -> FailureBreaker {
+        FailureBreaker::new(3, Duration::from_secs(60), Duration::from_secs(300))
+    }
+
+    #[test]
+    fn trips_after_consecutive_failures() {
+        let mut breaker = breaker();
+        let now = Instant::now();
+
+        assert!(!breaker.record_failure_at(now));
+        assert!(!breaker.record_failure_at(now + Duration::from_secs(1)));
+        assert!(breaker.record_failure_at(now + Duration::from_secs(2)));
+        assert!(breaker.is_tripped());
+        assert_eq!(
+            breaker.trip_reason(),
+            Some("3 consecutive execution failures")
+        );
+    }
+
+    #[test]
+    fn success_resets_the_streak() {
+        let mut breaker = breaker();
+        let now = Instant::now();
+
+        breaker.record_failure_at(now);
+        breaker.record_failure_at(now);
+        breaker.record_success();
+
+        assert!(!breaker.record_failure_at(now));
+        assert_eq!(breaker.consecutive_failures(), 1);
+    }
+
+    #[test]
+    fn failures_outside_window_start_a_new_streak() {
+        let mut breaker = breaker();
+        let now = Instant::now();
+
+        breaker.record_failure_at(now);
+        breaker.record_failure_at(now + Duration::from_secs(10));
+        assert!(!breaker.record_failure_at(now + Duration::from_secs(100)));
+        assert_eq!(breaker.consecutive_failures(), 1);
+    }
+
+    #[test]
+    fn cooldown_elapses_after_trip() {
+        let mut breaker = breaker();
+        let now = Instant::now();
+        for _ in 0..3 {
+            breaker.record_failure_at(now);
+        }
+
+        assert!(!breaker.cooldown_elapsed_at(now + Duration::from_secs(299)));
+        assert!(breaker.cooldown_elapsed_at(now + Duration::from_secs(300)));
+    }
+
+    #[test]
+    fn reset_clears_tripped_state() {
+        let mut breaker = breaker();
+        let now = Instant::now();
+        for _ in 0..3 {
+            breaker.record_failure_at(now);
+        }
+
+        breaker.reset();
+
+        assert!(!breaker.is_tripped());
+        assert!(breaker.trip_reason().is_none());
+        assert_eq!(breaker.consecutive_failures(), 0);
+    }
+
+    #[test]
+    fn disabled_breaker_never_trips() {
+        let mut breaker = FailureBreaker::disabled();
+        let now = Instant::now();
+
+        for _ in 0..100 {
+            assert!(!breaker.record_failure_at(now));
+        }
+        assert!(!breaker.is_tripped());
+    }
+}
@@ -0,0 +1,141 @@
+//! Native gas balance guard.
+//!
+//! On-chain exchanges charge gas for every order. Once the trading wallet
+//! runs out, executions fail with opaque RPC errors. [`GasGuard`] caches the
+//! wallet's native balance, refreshed on a timer rather than per trade, so
+//! the risk manager can reject executions up front while it is too low.
+
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+
+use crate::error::RiskError;
+
+/// Change in gas sufficiency produced by a balance update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasTransition {
+    /// The balance fell below the minimum.
+    Depleted,
+    /// The balance is back at or above the minimum.
+    Restored,
+    /// Sufficiency did not change.
+    Unchanged,
+}
+
+/// Cached native balance checked against a minimum before each execution.
+///
+/// Until the first update the balance is unknown and executions are allowed.
+#[derive(Debug)]
+pub struct GasGuard {
+    /// Balance below which executions are rejected.
+    min_balance: Decimal,
+    /// Most recently fetched balance, if any.
+    balance: Mutex<Option<Decimal>>,
+}
+
+impl GasGuard {
+    /// Create a guard requiring at least `min_balance` of the gas token.
+    #[must_use]
+    pub const fn new(min_balance: Decimal) -> Self {
+        Self {
+            min_balance,
+            balance: Mutex::new(None),
+        }
+    }
+
+    /// Balance below which executions are rejected.
+    #[must_use]
+    pub const fn min_balance(&self) -> Decimal {
+        self.min_balance
+    }
+
+    /// Most recently fetched balance, or `None` before the first update.
+    #[must_use]
+    pub fn balance(&self) -> Option<Decimal> {
+        *self.balance.lock()
+    }
+
+    /// Record a freshly fetched balance.
+    ///
+    /// Returns [`GasTransition::Depleted`] only when the balance drops below
+    /// the minimum from a sufficient or unknown balance, so callers can alert
+    /// once per outage.
+    pub fn update(&self, balance: Decimal) -> GasTransition {
+        let mut cached = self.balance.lock();
+        let was_low = cached.is_some_and(|previous| previous < self.min_balance);
+        *cached = Some(balance);
+
+        match (was_low, balance < self.min_balance) {
+            (false, true) => GasTransition::Depleted,
+            (true, false) => GasTransition::Restored,
+            _ => GasTransition::Unchanged,
+        }
+    }
+
+    /// Verify the cached balance covers the minimum.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RiskError::InsufficientGas`] when the balance is known and
+    /// below the minimum.
+    pub fn check(&self) -> Result<(), RiskError> {
+        match self.balance() {
+            Some(balance) if balance < self.min_balance => Err(RiskError::InsufficientGas {
+                balance,
+                min: self.min_balance,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn unknown_balance_allows_executions() {
+        let guard = GasGuard::new(dec!(0.1));
+        assert_eq!(guard.balance(), None);
+        assert!(guard.check().is_ok());
+    }
+
+    #[test]
+    fn low_balance_rejects_with_balance_and_minimum() {
+        let guard = GasGuard::new(dec!(0.1));
+        guard.update(dec!(0.02));
+
+        match guard.check() {
+            Err(RiskError::InsufficientGas { balance, min }) => {
+                assert_eq!(balance, dec!(0.02));
+                assert_eq!(min, dec!(0.1));
+            }
+            other => panic!("expected insufficient gas, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn balance_at_minimum_is_sufficient() {
+        let guard = GasGuard::new(dec!(0.1));
+        guard.update(dec!(0.1));
+        assert!(guard.check().is_ok());
+    }
+
+    #[test]
+    fn depletion_is_reported_once_per_outage() {
+        let guard = GasGuard::new(dec!(0.1));
+
+        assert_eq!(guard.update(dec!(0.5)), GasTransition::Unchanged);
+        assert_eq!(guard.update(dec!(0.05)), GasTransition::Depleted);
+        assert_eq!(guard.update(dec!(0.04)), GasTransition::Unchanged);
+        assert_eq!(guard.update(dec!(0.3)), GasTransition::Restored);
+        assert_eq!(guard.update(dec!(0.3)), GasTransition::Unchanged);
+        assert_eq!(guard.update(dec!(0.01)), GasTransition::Depleted);
+    }
+
+    #[test]
+    fn first_update_below_minimum_is_a_depletion() {
+        let guard = GasGuard::new(dec!(0.1));
+        assert_eq!(guard.update(dec!(0)), GasTransition::Depleted);
+    }
+}
@@ -4,49 +4,159 @@
 //! comply with configured risk limits before execution.
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use rust_decimal::Decimal;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
+use super::gas::GasGuard;
+use super::sizing::PositionSizer;
 use crate::application::state::AppState;
-use crate::domain::{opportunity::Opportunity, position::Position};
+use crate::domain::opportunity::Opportunity;
 use crate::error::RiskError;
 use crate::port::inbound::risk::RiskCheckResult;
 
 /// Risk manager that validates trades before execution.
 ///
 /// Performs comprehensive pre-trade checks including:
-/// - Circuit breaker status (halts all trading when active)
+/// - Trading state (rejects everything while paused or halted)
+/// - Opportunity age (rejects detections that waited too long to execute)
+/// - Market cooldown (rejects markets whose last execution just failed)
+/// - Gas balance (rejects while the wallet cannot pay for orders)
 /// - Profit threshold validation (filters unprofitable opportunities)
 /// - Position limits per market (prevents concentration risk)
 /// - Total exposure limits (caps overall risk exposure)
 ///
 /// On approval, atomically reserves exposure to prevent concurrent
 /// opportunities from exceeding configured limits.
+///
+/// Cloning is cheap; clones share the same state, including the execution
+/// failure circuit breaker.
+#[derive(Clone)]
 pub struct RiskManager {
     /// Shared application state containing risk limits and positions.
     state: Arc<AppState>,
+    /// Sizing applied to opportunities before they are checked.
+    sizer: PositionSizer,
+    /// Cached gas balance, when the minimum gas check is enabled.
+    gas: Option<Arc<GasGuard>>,
+    /// How long a market is left alone after a failed execution.
+    market_cooldown: Option<Duration>,
+    /// Oldest detection that may still be executed.
+    max_opportunity_age: Option<Duration>,
 }
 
 impl RiskManager {
     /// Create a new risk manager with the given shared state.
     pub const fn new(state: Arc<AppState>) -> Self {
-        Self { state }
+        Self {
+            state,
+            sizer: PositionSizer::Fixed,
+            gas: None,
+            market_cooldown: None,
+            max_opportunity_age: None,
+        }
+    }
+
+    /// Use the given position sizer.
+    #[must_use]
+    pub const fn with_sizer(mut self, sizer: PositionSizer) -> Self {
+        self.sizer = sizer;
+        self
+    }
+
+    /// Reject executions while the guard's cached gas balance is too low.
+    #[must_use]
+    pub fn with_gas_guard(mut self, guard: Arc<GasGuard>) -> Self {
+        self.gas = Some(guard);
+        self
+    }
+
+    /// Suppress executions on a market for `cooldown` after one fails.
+    #[must_use]
+    pub const fn with_market_cooldown(mut self, cooldown: Duration) -> Self {
+        self.market_cooldown = Some(cooldown);
+        self
+    }
+
+    /// Reject opportunities detected more than `max_age` ago.
+    #[must_use]
+    pub const fn with_max_opportunity_age(mut self, max_age: Duration) -> Self {
+        self.max_opportunity_age = Some(max_age);
+        self
+    }
+
+    /// Resize an opportunity to fit its edge and the remaining exposure budget.
+    ///
+    /// The budget is the smaller of the room left under the per-market
+    /// position limit and under the total exposure limit (including pending
+    /// reservations). When nothing fits, the opportunity is returned
+    /// unchanged so [`check`](Self::check) rejects it with the limit it hits.
+    #[must_use]
+    pub fn size(&self, opportunity: Opportunity) -> Opportunity {
+        let unit_cost = opportunity.total_cost();
+        if unit_cost <= Decimal::ZERO {
+            return opportunity;
+        }
+
+        let limits = self.state.risk_limits();
+        let market_room =
+            limits.max_position_per_market - self.state.market_exposure(opportunity.market_id());
+        let total_room =
+            limits.max_total_exposure - self.state.total_exposure() - self.state.pending_exposure();
+        let budget = market_room.min(total_room);
+
+        let edge = opportunity.edge() / unit_cost;
+        let volume = self
+            .sizer
+            .size(edge, opportunity.volume(), unit_cost, budget);
+        if volume <= Decimal::ZERO || volume == opportunity.volume() {
+            return opportunity;
+        }
+
+        debug!(
+            market_id = %opportunity.market_id(),
+            edge = %edge,
+            available = %opportunity.volume(),
+            sized = %volume,
+            "Sized opportunity"
+        );
+        opportunity.with_volume(volume)
     }
 
     /// Validate an opportunity against all risk checks.
     ///
-    /// Checks are performed in order: circuit breaker, profit threshold,
-    /// position limit, and exposure limit. On approval, atomically reserves
-    /// the required exposure to prevent concurrent opportunities from
-    /// exceeding limits.
+    /// Checks are performed in order: trading state, opportunity age, market
+    /// cooldown, leg count, gas balance, profit threshold, position limit,
+    /// and exposure limit. On approval, atomically reserves the required
+    /// exposure to prevent concurrent opportunities from exceeding limits.
     ///
     /// Returns [`RiskCheckResult::Approved`] if all checks pass, or
     /// [`RiskCheckResult::Rejected`] with the specific error if any check fails.
     #[must_use]
     pub fn check(&self, opportunity: &Opportunity) -> RiskCheckResult {
-        // Check circuit breaker first
-        if let Err(e) = self.check_circuit_breaker() {
+        // Check trading state first
+        if let Err(e) = self.check_trading_state() {
+            return RiskCheckResult::Rejected(e);
+        }
+
+        // Check the opportunity was detected recently enough to still hold
+        if let Err(e) = self.check_opportunity_age(opportunity) {
+            return RiskCheckResult::Rejected(e);
+        }
+
+        // Check the market is not cooling down after a failed execution
+        if let Err(e) = self.check_market_cooldown(opportunity) {
+            return RiskCheckResult::Rejected(e);
+        }
+
+        // Check the opportunity has few enough legs to execute safely
+        if let Err(e) = self.check_leg_count(opportunity) {
+            return RiskCheckResult::Rejected(e);
+        }
+
+        // Check the wallet can pay for the orders
+        if let Err(e) = self.check_gas_balance() {
             return RiskCheckResult::Rejected(e);
         }
 
@@ -92,23 +202,92 @@ impl RiskManager {
         self.state.release_exposure(amount);
     }
 
-    /// Activate the circuit breaker, halting all new trades.
+    /// Trip the circuit breaker, pausing all new trades.
+    ///
+    /// This is the automatic kill-switch: it pauses trading for the life of
+    /// the process and never overrides a manual halt.
     pub fn trigger_circuit_breaker(&self, reason: impl Into<String>) {
         let reason = reason.into();
         warn!(reason = %reason, "Triggering circuit breaker");
-        self.state.activate_circuit_breaker(reason);
+        self.state.pause(reason);
     }
 
-    /// Deactivate the circuit breaker, resuming normal trading.
+    /// Reset the circuit breaker, resuming normal trading.
     pub fn reset_circuit_breaker(&self) {
         info!("Resetting circuit breaker");
-        self.state.reset_circuit_breaker();
+        self.state.resume();
+    }
+
+    /// Record the outcome of an execution attempt in the failure breaker.
+    ///
+    /// A success ends the failure streak. A failure (including partial fills,
+    /// errors, and timeouts) extends it, and once the streak reaches the
+    /// configured limit the circuit breaker trips. Returns the trip reason
+    /// when this outcome tripped the breaker.
+    pub fn record_execution_outcome(&self, succeeded: bool) -> Option<String> {
+        let mut breaker = self.state.breaker();
+        if succeeded {
+            breaker.record_success();
+            return None;
+        }
+        if !breaker.record_failure() {
+            return None;
+        }
+        let reason = breaker.trip_reason()?.to_string();
+        drop(breaker);
+
+        self.trigger_circuit_breaker(reason.clone());
+        Some(reason)
+    }
+
+    /// Start the market cooldown after an execution on `market_id` failed.
+    ///
+    /// Does nothing unless a market cooldown is configured.
+    pub fn record_market_failure(&self, market_id: &str) {
+        self.record_market_failure_at(market_id, Instant::now());
+    }
+
+    /// Start the market cooldown as of `now`.
+    pub fn record_market_failure_at(&self, market_id: &str, now: Instant) {
+        let Some(cooldown) = self.market_cooldown else {
+            return;
+        };
+        debug!(
+            market_id = market_id,
+            cooldown_ms = cooldown.as_millis(),
+            "Market cooling down after failed execution"
+        );
+        self.state.start_market_cooldown(market_id, now + cooldown);
+    }
+
+    /// Resume trading if the failure breaker's cooldown has elapsed.
+    ///
+    /// Returns true if trading was resumed. Only the breaker's own pause is
+    /// lifted; a manual halt or a pause for any other reason set while the
+    /// breaker was tripped is left in place.
+    pub fn expire_circuit_breaker(&self) -> bool {
+        let mut breaker = self.state.breaker();
+        if !breaker.cooldown_elapsed_at(Instant::now()) {
+            return false;
+        }
+        let reason = breaker.trip_reason().map(str::to_string);
+        breaker.reset();
+        drop(breaker);
+
+        let Some(reason) = reason else {
+            return false;
+        };
+        if !self.state.resume_paused_for(&reason) {
+            return false;
+        }
+        info!("Circuit breaker cooldown elapsed");
+        true
     }
 
-    /// Return true if the circuit breaker is currently active.
+    /// Return true if trading is currently paused or halted.
     #[must_use]
     pub fn is_circuit_breaker_active(&self) -> bool {
-        self.state.is_circuit_breaker_active()
+        !self.state.is_trading_active()
     }
 
     /// Log a successful execution for monitoring.
@@ -121,22 +300,82 @@ impl RiskManager {
         );
     }
 
-    /// Verify the circuit breaker is not active.
-    fn check_circuit_breaker(&self) -> Result<(), RiskError> {
-        if self.state.is_circuit_breaker_active() {
-            let reason = self
-                .state
-                .circuit_breaker_reason()
-                .unwrap_or_else(|| "unknown".to_string());
-            warn!(reason = %reason, "Circuit breaker active");
-            return Err(RiskError::CircuitBreakerActive { reason });
+    /// Verify trading is neither paused nor halted.
+    fn check_trading_state(&self) -> Result<(), RiskError> {
+        let state = self.state.trading_state();
+        if !state.is_active() {
+            warn!(state = %state, "Trading stopped");
+            return Err(RiskError::TradingStopped {
+                state: state.to_string(),
+            });
         }
         Ok(())
     }
 
-    /// Verify expected profit meets the configured minimum threshold.
+    /// Verify the opportunity is no older than the configured maximum age.
+    ///
+    /// Opportunities can wait between detection and execution, e.g. in the
+    /// debounced cluster queue, while the prices behind them move on.
+    /// Execution repeats this check after waiting for the rate limiter.
+    pub fn check_opportunity_age(&self, opportunity: &Opportunity) -> Result<(), RiskError> {
+        let Some(max_age) = self.max_opportunity_age else {
+            return Ok(());
+        };
+        let age = opportunity.detected_at().elapsed();
+        if age <= max_age {
+            return Ok(());
+        }
+        debug!(
+            market_id = %opportunity.market_id(),
+            age_ms = age.as_millis(),
+            max_age_ms = max_age.as_millis(),
+            "Opportunity too old to execute"
+        );
+        Err(RiskError::OpportunityStale {
+            age_ms: age.as_millis(),
+            max_age_ms: max_age.as_millis(),
+        })
+    }
+
+    /// Verify the market is not cooling down after a failed execution.
+    fn check_market_cooldown(&self, opportunity: &Opportunity) -> Result<(), RiskError> {
+        let market_id = opportunity.market_id().as_str();
+        let now = Instant::now();
+        let Some(until) = self.state.market_cooldown_until(market_id, now) else {
+            return Ok(());
+        };
+        Err(RiskError::MarketCoolingDown {
+            market_id: market_id.to_string(),
+            remaining_ms: (until - now).as_millis(),
+        })
+    }
+
+    /// Verify the opportunity does not exceed the execution leg cap.
+    ///
+    /// Detection is bounded separately, so larger opportunities are still
+    /// reported but never executed.
+    fn check_leg_count(&self, opportunity: &Opportunity) -> Result<(), RiskError> {
+        let legs = opportunity.legs().len();
+        match self.state.risk_limits().max_execution_legs {
+            Some(max) if legs > max => Err(RiskError::TooManyLegs { legs, max }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Verify the cached gas balance covers the configured minimum.
+    fn check_gas_balance(&self) -> Result<(), RiskError> {
+        let Some(ref gas) = self.gas else {
+            return Ok(());
+        };
+        gas.check()
+            .inspect_err(|e| debug!(reason = %e, "Gas balance too low"))
+    }
+
+    /// Verify expected profit meets the configured minimum threshold, both
+    /// in dollars and as a fraction of the capital deployed.
     fn check_profit_threshold(&self, opportunity: &Opportunity) -> Result<(), RiskError> {
-        let threshold = self.state.risk_limits().min_profit_threshold;
+        let limits = self.state.risk_limits();
+        let threshold = limits.min_profit_threshold;
         let expected = opportunity.expected_profit();
 
         if expected < threshold {
@@ -145,6 +384,16 @@ impl RiskManager {
                 threshold,
             });
         }
+
+        let capital = opportunity.total_cost() * opportunity.volume();
+        if let Some(min) = limits.min_profit_pct {
+            if capital > Decimal::ZERO && expected / capital < min {
+                return Err(RiskError::ProfitPctBelowThreshold {
+                    pct: (expected / capital).round_dp(4),
+                    min,
+                });
+            }
+        }
         Ok(())
     }
 
@@ -153,15 +402,7 @@ impl RiskManager {
         let market_id = opportunity.market_id();
         let limit = self.state.risk_limits().max_position_per_market;
 
-        // Calculate current position in this market
-        let current = self
-            .state
-            .positions()
-            .open_positions()
-            .filter(|p| p.market_id() == market_id)
-            .map(Position::entry_cost)
-            .sum::<Decimal>();
-
+        let current = self.state.market_exposure(market_id);
         let additional = opportunity.total_cost() * opportunity.volume();
 
         if current + additional > limit {
@@ -175,6 +416,7 @@ impl RiskManager {
             return Err(RiskError::PositionLimitExceeded {
                 market_id: market_id.to_string(),
                 current,
+                additional,
                 limit,
             });
         }
@@ -216,9 +458,9 @@ mod tests {
     }
 
     #[test]
-    fn test_check_circuit_breaker() {
+    fn test_check_rejects_when_halted() {
         let state = Arc::new(AppState::default());
-        state.activate_circuit_breaker("test");
+        state.halt("test");
         let risk = RiskManager::new(state);
 
         let opp = make_opportunity(dec!(10), dec!(0.45), dec!(0.45));
@@ -227,10 +469,29 @@ mod tests {
         assert!(!result.is_approved());
         assert!(matches!(
             result.rejection_error(),
-            Some(RiskError::CircuitBreakerActive { .. })
+            Some(RiskError::TradingStopped { .. })
         ));
     }
 
+    #[test]
+    fn test_check_rejects_when_gas_below_minimum() {
+        let state = Arc::new(AppState::default());
+        let gas = Arc::new(GasGuard::new(dec!(0.1)));
+        let risk = RiskManager::new(Arc::clone(&state)).with_gas_guard(Arc::clone(&gas));
+        let opp = make_opportunity(dec!(10), dec!(0.45), dec!(0.45));
+
+        gas.update(dec!(0.01));
+        let result = risk.check(&opp);
+        assert!(matches!(
+            result.rejection_error(),
+            Some(RiskError::InsufficientGas { .. })
+        ));
+        assert_eq!(state.pending_exposure(), Decimal::ZERO);
+
+        gas.update(dec!(1));
+        assert!(risk.check(&opp).is_approved());
+    }
+
     #[test]
     fn test_check_profit_below_threshold() {
         let limits = RiskLimits {
@@ -251,6 +512,152 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_check_rejects_large_low_percentage_profit() {
+        let limits = RiskLimits {
+            max_position_per_market: dec!(100000),
+            max_total_exposure: dec!(100000),
+            min_profit_pct: Some(dec!(0.01)),
+            ..Default::default()
+        };
+        let risk = RiskManager::new(Arc::new(AppState::new(limits)));
+
+        // $0.005 edge on $0.995 cost * 10000 shares = $50 profit, but only
+        // 0.5% of the $9950 deployed.
+        let opp = make_opportunity(dec!(10000), dec!(0.495), dec!(0.50));
+        let result = risk.check(&opp);
+
+        assert!(matches!(
+            result.rejection_error(),
+            Some(RiskError::ProfitPctBelowThreshold { min, .. }) if *min == dec!(0.01)
+        ));
+    }
+
+    #[test]
+    fn test_check_approves_small_high_percentage_profit() {
+        let limits = RiskLimits {
+            min_profit_pct: Some(dec!(0.01)),
+            ..Default::default()
+        };
+        let risk = RiskManager::new(Arc::new(AppState::new(limits)));
+
+        // $0.10 edge on $0.90 cost is an 11% return, even at $1 profit.
+        let opp = make_opportunity(dec!(10), dec!(0.45), dec!(0.45));
+
+        assert!(risk.check(&opp).is_approved());
+    }
+
+    /// A rebalancing opportunity buying every outcome of an n-way market.
+    fn make_multi_leg_opportunity(outcomes: usize) -> Opportunity {
+        let legs = (0..outcomes)
+            .map(|i| OpportunityLeg::new(TokenId::from(format!("outcome-{i}")), dec!(0.13)))
+            .collect();
+        Opportunity::new(
+            MarketId::from("test-market"),
+            "Who wins?",
+            legs,
+            dec!(10),
+            Decimal::ONE,
+        )
+    }
+
+    #[test]
+    fn test_check_rejects_opportunity_above_execution_leg_cap() {
+        let limits = RiskLimits {
+            max_execution_legs: Some(5),
+            ..Default::default()
+        };
+        let state = Arc::new(AppState::new(limits));
+        let risk = RiskManager::new(Arc::clone(&state));
+
+        let result = risk.check(&make_multi_leg_opportunity(7));
+
+        assert!(matches!(
+            result.rejection_error(),
+            Some(RiskError::TooManyLegs { legs: 7, max: 5 })
+        ));
+        assert_eq!(state.pending_exposure(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_check_approves_opportunity_within_execution_leg_cap() {
+        let limits = RiskLimits {
+            max_execution_legs: Some(5),
+            ..Default::default()
+        };
+        let risk = RiskManager::new(Arc::new(AppState::new(limits)));
+
+        assert!(risk.check(&make_multi_leg_opportunity(5)).is_approved());
+    }
+
+    #[test]
+    fn test_stale_opportunity_rejected() {
+        let state = Arc::new(AppState::default());
+        let risk = RiskManager::new(state).with_max_opportunity_age(Duration::from_millis(100));
+        let opp = make_opportunity(dec!(10), dec!(0.45), dec!(0.45));
+
+        assert!(risk.check(&opp).is_approved());
+        risk.release_exposure(&opp);
+
+        let aged = opp.with_detected_at(Instant::now() - Duration::from_millis(500));
+        assert!(matches!(
+            risk.check(&aged).rejection_error(),
+            Some(RiskError::OpportunityStale {
+                max_age_ms: 100,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_market_cooldown_suppresses_execution() {
+        let state = Arc::new(AppState::default());
+        let risk = RiskManager::new(state).with_market_cooldown(Duration::from_secs(30));
+        let opp = make_opportunity(dec!(10), dec!(0.45), dec!(0.45));
+
+        risk.record_market_failure("test-market");
+
+        assert!(matches!(
+            risk.check(&opp).rejection_error(),
+            Some(RiskError::MarketCoolingDown { market_id, .. }) if market_id == "test-market"
+        ));
+    }
+
+    #[test]
+    fn test_market_cooldown_is_per_market() {
+        let state = Arc::new(AppState::default());
+        let risk = RiskManager::new(state).with_market_cooldown(Duration::from_secs(30));
+
+        risk.record_market_failure("other-market");
+
+        let opp = make_opportunity(dec!(10), dec!(0.45), dec!(0.45));
+        assert!(risk.check(&opp).is_approved());
+    }
+
+    #[test]
+    fn test_market_cooldown_lapses() {
+        let state = Arc::new(AppState::default());
+        let risk = RiskManager::new(state).with_market_cooldown(Duration::from_millis(500));
+        let opp = make_opportunity(dec!(10), dec!(0.45), dec!(0.45));
+
+        // The failure was recorded longer ago than the cooldown.
+        let failed_at = Instant::now() - Duration::from_secs(1);
+        risk.record_market_failure_at("test-market", failed_at);
+
+        assert!(risk.check(&opp).is_approved());
+    }
+
+    #[test]
+    fn test_market_cooldown_disabled_by_default() {
+        let state = Arc::new(AppState::default());
+        let risk = RiskManager::new(state);
+        let opp = make_opportunity(dec!(10), dec!(0.45), dec!(0.45));
+
+        risk.record_market_failure("test-market");
+
+        assert!(risk.check(&opp).is_approved());
+    }
+
     #[test]
     fn test_check_exposure_limit() {
         let limits = RiskLimits {
@@ -281,11 +688,111 @@ mod tests {
 
         risk.trigger_circuit_breaker("test reason");
         assert!(risk.is_circuit_breaker_active());
+        assert_eq!(risk.state.trading_state().reason(), Some("test reason"));
+        assert!(!risk.state.trading_state().is_halted());
 
         risk.reset_circuit_breaker();
         assert!(!risk.is_circuit_breaker_active());
     }
 
+    fn breaker_state(cooldown: std::time::Duration) -> Arc<AppState> {
+        use crate::application::risk::breaker::FailureBreaker;
+
+        let limits = RiskLimits {
+            min_profit_threshold: dec!(0),
+            ..Default::default()
+        };
+        Arc::new(
+            AppState::new(limits).with_failure_breaker(FailureBreaker::new(
+                3,
+                std::time::Duration::from_secs(60),
+                cooldown,
+            )),
+        )
+    }
+
+    #[test]
+    fn test_consecutive_failures_trip_breaker() {
+        let risk = RiskManager::new(breaker_state(std::time::Duration::from_secs(300)));
+        let opp = make_opportunity(dec!(10), dec!(0.45), dec!(0.45));
+
+        assert!(risk.record_execution_outcome(false).is_none());
+        assert!(risk.record_execution_outcome(false).is_none());
+        let reason = risk.record_execution_outcome(false).unwrap();
+
+        assert_eq!(reason, "3 consecutive execution failures");
+        assert!(risk.is_circuit_breaker_active());
+        assert!(matches!(
+            risk.check(&opp).rejection_error(),
+            Some(RiskError::TradingStopped { .. })
+        ));
+        assert!(!risk.expire_circuit_breaker());
+    }
+
+    #[test]
+    fn test_success_resets_failure_streak() {
+        let risk = RiskManager::new(breaker_state(std::time::Duration::from_secs(300)));
+
+        risk.record_execution_outcome(false);
+        risk.record_execution_outcome(false);
+        risk.record_execution_outcome(true);
+        risk.record_execution_outcome(false);
+        risk.record_execution_outcome(false);
+
+        assert!(!risk.is_circuit_breaker_active());
+    }
+
+    #[test]
+    fn test_breaker_resumes_after_cooldown() {
+        let risk = RiskManager::new(breaker_state(std::time::Duration::ZERO));
+        for _ in 0..3 {
+            risk.record_execution_outcome(false);
+        }
+        assert!(risk.is_circuit_breaker_active());
+
+        assert!(risk.expire_circuit_breaker());
+        assert!(!risk.is_circuit_breaker_active());
+        assert!(!risk.expire_circuit_breaker());
+    }
+
+    #[test]
+    fn test_breaker_cooldown_keeps_manual_halt() {
+        let risk = RiskManager::new(breaker_state(std::time::Duration::ZERO));
+        for _ in 0..3 {
+            risk.record_execution_outcome(false);
+        }
+        risk.state.halt("operator");
+
+        assert!(!risk.expire_circuit_breaker());
+        assert!(risk.state.trading_state().is_halted());
+    }
+
+    #[test]
+    fn test_breaker_cooldown_keeps_other_pause() {
+        let risk = RiskManager::new(breaker_state(std::time::Duration::ZERO));
+        for _ in 0..3 {
+            risk.record_execution_outcome(false);
+        }
+        risk.state.pause("operator");
+
+        assert!(!risk.expire_circuit_breaker());
+        assert_eq!(risk.state.trading_state().reason(), Some("operator"));
+        assert!(risk.is_circuit_breaker_active());
+    }
+
+    #[test]
+    fn test_resume_clears_tripped_breaker() {
+        let risk = RiskManager::new(breaker_state(std::time::Duration::from_secs(300)));
+        for _ in 0..3 {
+            risk.record_execution_outcome(false);
+        }
+
+        risk.state.resume();
+
+        assert!(!risk.state.breaker().is_tripped());
+        assert!(risk.record_execution_outcome(false).is_none());
+    }
+
     #[test]
     fn test_release_exposure() {
         let limits = RiskLimits {
@@ -306,6 +813,32 @@ mod tests {
         assert_eq!(state.pending_exposure(), Decimal::ZERO);
     }
 
+    #[test]
+    fn test_halt_does_not_cancel_approved_trade() {
+        let limits = RiskLimits {
+            min_profit_threshold: dec!(0),
+            ..Default::default()
+        };
+        let state = Arc::new(AppState::new(limits));
+        let risk = RiskManager::new(state.clone());
+
+        // Approved before the halt: exposure stays reserved for the in-flight legs
+        let in_flight = make_opportunity(dec!(50), dec!(0.45), dec!(0.45));
+        assert!(risk.check(&in_flight).is_approved());
+        state.halt("operator");
+        assert_eq!(state.pending_exposure(), dec!(45));
+
+        // New opportunities are rejected until trading resumes
+        let next = make_opportunity(dec!(10), dec!(0.45), dec!(0.45));
+        assert!(matches!(
+            risk.check(&next).rejection_error(),
+            Some(RiskError::TradingStopped { .. })
+        ));
+
+        state.resume();
+        assert!(risk.check(&next).is_approved());
+    }
+
     #[test]
     fn test_check_position_limit() {
         use crate::domain::{position::Position, position::PositionLeg, position::PositionStatus};
@@ -347,4 +880,133 @@ mod tests {
             Some(RiskError::PositionLimitExceeded { .. })
         ));
     }
+
+    fn fill_opportunity(state: &AppState, market: &str, cost: Decimal) {
+        use crate::domain::{position::Position, position::PositionLeg, position::PositionStatus};
+
+        let mut positions = state.positions_mut();
+        let position = Position::new(
+            positions.next_id(),
+            MarketId::from(market),
+            vec![
+                PositionLeg::new(TokenId::from("yes"), dec!(10), dec!(0.45)),
+                PositionLeg::new(TokenId::from("no"), dec!(10), dec!(0.45)),
+            ],
+            cost,
+            dec!(10),
+            chrono::Utc::now(),
+            PositionStatus::Open,
+        );
+        positions.add(position);
+    }
+
+    #[test]
+    fn test_position_limit_accumulates_across_trades() {
+        let limits = RiskLimits {
+            max_position_per_market: dec!(30),
+            min_profit_threshold: dec!(0),
+            ..Default::default()
+        };
+        let state = Arc::new(AppState::new(limits));
+        let risk = RiskManager::new(Arc::clone(&state));
+
+        // Each trade costs $0.90 * 10 = $9; three fit under $30, the fourth does not
+        let opp = make_opportunity(dec!(10), dec!(0.45), dec!(0.45));
+        for filled in 1..=3 {
+            assert!(risk.check(&opp).is_approved(), "trade {filled} rejected");
+            state.release_exposure(dec!(9));
+            fill_opportunity(&state, "test-market", dec!(9));
+        }
+        assert_eq!(
+            state.market_exposure(&MarketId::from("test-market")),
+            dec!(27)
+        );
+
+        match risk.check(&opp).rejection_error() {
+            Some(RiskError::PositionLimitExceeded {
+                market_id,
+                current,
+                additional,
+                limit,
+            }) => {
+                assert_eq!(market_id, "test-market");
+                assert_eq!(*current, dec!(27));
+                assert_eq!(*additional, dec!(9));
+                assert_eq!(*limit, dec!(30));
+            }
+            other => panic!("expected position limit rejection, got {other:?}"),
+        }
+        assert_eq!(state.pending_exposure(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_position_limit_is_per_market() {
+        let limits = RiskLimits {
+            max_position_per_market: dec!(30),
+            min_profit_threshold: dec!(0),
+            ..Default::default()
+        };
+        let state = Arc::new(AppState::new(limits));
+        fill_opportunity(&state, "other-market", dec!(27));
+        let risk = RiskManager::new(Arc::clone(&state));
+
+        // Exposure in another market does not count against this one
+        let opp = make_opportunity(dec!(10), dec!(0.45), dec!(0.45));
+        assert!(risk.check(&opp).is_approved());
+    }
+
+    #[test]
+    fn test_size_scales_volume_with_edge() {
+        let limits = RiskLimits {
+            max_position_per_market: dec!(100),
+            ..Default::default()
+        };
+        let state = Arc::new(AppState::new(limits));
+        let risk = RiskManager::new(state).with_sizer(PositionSizer::Proportional(dec!(0.20)));
+
+        // $0.10 edge on $0.90 cost is ~11% per dollar: ~56% of the $100 budget
+        let opp = make_opportunity(dec!(1000), dec!(0.45), dec!(0.45));
+        let sized = risk.size(opp);
+
+        assert_eq!(sized.volume(), dec!(61.72));
+        assert!(risk.check(&sized).is_approved());
+    }
+
+    #[test]
+    fn test_size_clamps_to_remaining_market_budget() {
+        let limits = RiskLimits {
+            max_position_per_market: dec!(30),
+            min_profit_threshold: dec!(0),
+            ..Default::default()
+        };
+        let state = Arc::new(AppState::new(limits));
+        fill_opportunity(&state, "test-market", dec!(21));
+        let risk = RiskManager::new(Arc::clone(&state));
+
+        // $9 of room left at $0.90 per share
+        let sized = risk.size(make_opportunity(dec!(100), dec!(0.45), dec!(0.45)));
+
+        assert_eq!(sized.volume(), dec!(10));
+        assert!(risk.check(&sized).is_approved());
+    }
+
+    #[test]
+    fn test_size_leaves_opportunity_when_budget_exhausted() {
+        let limits = RiskLimits {
+            max_position_per_market: dec!(30),
+            min_profit_threshold: dec!(0),
+            ..Default::default()
+        };
+        let state = Arc::new(AppState::new(limits));
+        fill_opportunity(&state, "test-market", dec!(30));
+        let risk = RiskManager::new(Arc::clone(&state));
+
+        let sized = risk.size(make_opportunity(dec!(10), dec!(0.45), dec!(0.45)));
+
+        assert_eq!(sized.volume(), dec!(10));
+        assert!(matches!(
+            risk.check(&sized).rejection_error(),
+            Some(RiskError::PositionLimitExceeded { .. })
+        ));
+    }
 }
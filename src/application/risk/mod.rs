@@ -1,6 +1,9 @@
 //! Risk management service module.
 //!
-//! Provides pre-execution validation and circuit breaker functionality
-//! for controlling trading risk.
+//! Provides pre-execution validation, position sizing, gas balance checks,
+//! and circuit breaker functionality for controlling trading risk.
 
+pub mod breaker;
+pub mod gas;
 pub mod manager;
+pub mod sizing;
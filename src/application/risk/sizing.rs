@@ -0,0 +1,150 @@
+//! Position sizing by edge magnitude.
+//!
+//! A [`PositionSizer`] turns an opportunity's edge, the liquidity on the
+//! book, and the exposure budget left under the risk limits into the number
+//! of shares to trade.
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Decimal places kept on sized share counts.
+const SIZE_DECIMALS: u32 = 2;
+
+/// Strategy for choosing how much of the exposure budget to commit.
+///
+/// Every mode is clamped to the available liquidity and the remaining
+/// exposure budget.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PositionSizer {
+    /// Commit the whole budget regardless of edge.
+    #[default]
+    Fixed,
+    /// Scale the budget linearly with edge, reaching full size at the given
+    /// edge per dollar (e.g. 0.10 commits half the budget at a 5% edge).
+    Proportional(Decimal),
+    /// Commit `fraction × edge` of the budget: the Kelly stake for an
+    /// expected return of `edge` per dollar at unit variance, scaled down by
+    /// the given fraction (0.5 is "half Kelly").
+    Kelly(Decimal),
+}
+
+impl PositionSizer {
+    /// Shares to trade for an opportunity.
+    ///
+    /// # Arguments
+    ///
+    /// * `edge` - Net profit per dollar spent (edge divided by unit cost)
+    /// * `liquidity` - Shares available at the quoted prices
+    /// * `unit_cost` - Cost of one share of every leg
+    /// * `budget` - Exposure in dollars that may still be committed
+    ///
+    /// Returns zero when there is no edge, budget, or liquidity.
+    #[must_use]
+    pub fn size(
+        &self,
+        edge: Decimal,
+        liquidity: Decimal,
+        unit_cost: Decimal,
+        budget: Decimal,
+    ) -> Decimal {
+        if edge <= Decimal::ZERO || unit_cost <= Decimal::ZERO || budget <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+
+        let dollars = budget * self.budget_fraction(edge);
+        let shares = (dollars / unit_cost).min(liquidity).max(Decimal::ZERO);
+        shares.round_dp_with_strategy(SIZE_DECIMALS, RoundingStrategy::ToZero)
+    }
+
+    /// Fraction of the budget to commit at the given edge, in `[0, 1]`.
+    fn budget_fraction(&self, edge: Decimal) -> Decimal {
+        let fraction = match *self {
+            Self::Fixed => Decimal::ONE,
+            Self::Proportional(full_size_edge) if full_size_edge > Decimal::ZERO => {
+                edge / full_size_edge
+            }
+            Self::Proportional(_) => Decimal::ONE,
+            Self::Kelly(fraction) => fraction * edge,
+        };
+        fraction.clamp(Decimal::ZERO, Decimal::ONE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn fixed_uses_whole_budget() {
+        let size = PositionSizer::Fixed.size(dec!(0.02), dec!(1000), dec!(0.50), dec!(100));
+
+        assert_eq!(size, dec!(200));
+    }
+
+    #[test]
+    fn fixed_is_capped_by_liquidity() {
+        let size = PositionSizer::Fixed.size(dec!(0.02), dec!(50), dec!(0.50), dec!(100));
+
+        assert_eq!(size, dec!(50));
+    }
+
+    #[test]
+    fn proportional_scales_with_edge() {
+        let sizer = PositionSizer::Proportional(dec!(0.10));
+
+        let small = sizer.size(dec!(0.02), dec!(10000), dec!(0.50), dec!(100));
+        let large = sizer.size(dec!(0.05), dec!(10000), dec!(0.50), dec!(100));
+
+        assert_eq!(small, dec!(40));
+        assert_eq!(large, dec!(100));
+    }
+
+    #[test]
+    fn proportional_clamps_to_budget_above_full_size_edge() {
+        let sizer = PositionSizer::Proportional(dec!(0.10));
+
+        let size = sizer.size(dec!(0.15), dec!(10000), dec!(0.50), dec!(100));
+
+        assert_eq!(size, dec!(200));
+    }
+
+    #[test]
+    fn kelly_stakes_fraction_of_edge() {
+        let sizer = PositionSizer::Kelly(dec!(0.5));
+
+        let size = sizer.size(dec!(0.10), dec!(10000), dec!(0.50), dec!(1000));
+
+        // 0.5 × 10% of $1000 = $50 at $0.50 per share.
+        assert_eq!(size, dec!(100));
+    }
+
+    #[test]
+    fn kelly_never_exceeds_budget() {
+        let sizer = PositionSizer::Kelly(dec!(20));
+
+        let size = sizer.size(dec!(0.15), dec!(10000), dec!(0.50), dec!(100));
+
+        assert_eq!(size, dec!(200));
+    }
+
+    #[test]
+    fn exhausted_budget_sizes_to_zero() {
+        for sizer in [
+            PositionSizer::Fixed,
+            PositionSizer::Proportional(dec!(0.10)),
+            PositionSizer::Kelly(dec!(0.5)),
+        ] {
+            assert_eq!(
+                sizer.size(dec!(0.10), dec!(1000), dec!(0.50), Decimal::ZERO),
+                Decimal::ZERO
+            );
+        }
+    }
+
+    #[test]
+    fn size_rounds_down_to_cents() {
+        let size = PositionSizer::Fixed.size(dec!(0.05), dec!(1000), dec!(0.30), dec!(10));
+
+        assert_eq!(size, dec!(33.33));
+    }
+}
@@ -0,0 +1,95 @@
+//! Sampling of high-volume log events.
+//!
+//! Detection and trace logs fire for every market event, which floods the
+//! output in high-throughput runs. Each hot-path call site owns a static
+//! [`LogSampler`] and only logs when it admits the event; all samplers share
+//! one process-wide rate set from the logging configuration at startup.
+//!
+//! Sampling is only applied to `debug` and `trace` events. Warnings and
+//! errors always go through the tracing filter unsampled.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Process-wide sample rate: one in this many events is logged.
+static SAMPLE_RATE: AtomicU64 = AtomicU64::new(1);
+
+/// Set the process-wide sample rate.
+///
+/// A rate of 1 (or 0) logs every event.
+pub fn set_sample_rate(rate: u64) {
+    SAMPLE_RATE.store(rate.max(1), Ordering::Relaxed);
+}
+
+/// Current process-wide sample rate.
+#[must_use]
+pub fn sample_rate() -> u64 {
+    SAMPLE_RATE.load(Ordering::Relaxed)
+}
+
+/// Counter gate admitting one in every N events at a call site.
+///
+/// Declared as a `static` next to the log call it guards:
+///
+/// ```
+/// use edgelord::application::sampling::LogSampler;
+///
+/// static DETECTION_LOGS: LogSampler = LogSampler::new();
+///
+/// if DETECTION_LOGS.sample() {
+///     tracing::debug!("Strategy detection complete");
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct LogSampler {
+    counter: AtomicU64,
+}
+
+impl LogSampler {
+    /// Create a sampler whose first event is admitted.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            counter: AtomicU64::new(0),
+        }
+    }
+
+    /// Whether the current event should be logged.
+    pub fn sample(&self) -> bool {
+        self.sample_every(sample_rate())
+    }
+
+    fn sample_every(&self, rate: u64) -> bool {
+        rate <= 1 || self.counter.fetch_add(1, Ordering::Relaxed) % rate == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_of_one_admits_every_event() {
+        let sampler = LogSampler::new();
+
+        assert!((0..5).all(|_| sampler.sample_every(1)));
+    }
+
+    #[test]
+    fn admits_one_in_every_n_events() {
+        let sampler = LogSampler::new();
+
+        let admitted: Vec<bool> = (0..7).map(|_| sampler.sample_every(3)).collect();
+
+        assert_eq!(admitted, vec![true, false, false, true, false, false, true]);
+    }
+
+    #[test]
+    fn samplers_count_independently() {
+        let first = LogSampler::new();
+        let second = LogSampler::new();
+
+        assert!(first.sample_every(2));
+        assert!(!first.sample_every(2));
+        assert!(second.sample_every(2));
+    }
+}
@@ -0,0 +1,197 @@
+//! Oracle backend selection for Frank-Wolfe projection.
+//!
+//! `HiGHS` handles any cluster but pays model-building overhead on every
+//! oracle call. The heuristic backend answers small all-binary clusters with
+//! [`HeuristicOracle`] and hands everything it cannot prove optimal to an
+//! exact backend.
+
+// Allow large error types - inherited from crate's unified Error type
+#![allow(clippy::result_large_err)]
+
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::heuristic::HeuristicOracle;
+use crate::error::Result;
+use crate::port::{
+    outbound::solver::IlpProblem, outbound::solver::SolverBackend, outbound::solver::Vertex,
+};
+
+/// Oracle backend used for Frank-Wolfe projection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SolverBackendKind {
+    /// Solve every oracle call with `HiGHS`.
+    #[default]
+    Highs,
+    /// Try the heuristic oracle on small clusters, falling back to `HiGHS`.
+    Heuristic,
+}
+
+/// Configuration for the combinatorial oracle backend.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SolverConfig {
+    /// Backend that solves the Frank-Wolfe oracle.
+    #[serde(default)]
+    pub backend: SolverBackendKind,
+
+    /// Largest cluster, in variables, the heuristic backend attempts.
+    /// Larger clusters go straight to `HiGHS`.
+    #[serde(default = "default_heuristic_max_vars")]
+    pub heuristic_max_vars: usize,
+}
+
+/// Upper limit for `heuristic_max_vars`; the search is exponential in the
+/// number of variables.
+pub const MAX_HEURISTIC_VARS: usize = 24;
+
+const fn default_heuristic_max_vars() -> usize {
+    12
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Self {
+            backend: SolverBackendKind::default(),
+            heuristic_max_vars: default_heuristic_max_vars(),
+        }
+    }
+}
+
+/// Backend that tries a heuristic oracle before an exact one.
+pub struct FallbackBackend {
+    /// Fast oracle tried first.
+    heuristic: HeuristicOracle,
+    /// Exact backend used when the heuristic cannot prove optimality.
+    exact: Arc<dyn SolverBackend>,
+}
+
+impl FallbackBackend {
+    /// Create a backend that falls back to `exact` when `heuristic` declines.
+    #[must_use]
+    pub fn new(heuristic: HeuristicOracle, exact: Arc<dyn SolverBackend>) -> Self {
+        Self { heuristic, exact }
+    }
+}
+
+impl SolverBackend for FallbackBackend {
+    fn name(&self) -> &'static str {
+        "heuristic"
+    }
+
+    fn solve_oracle(
+        &self,
+        gradient: &[Decimal],
+        constraints: &IlpProblem,
+    ) -> Result<Option<Vertex>> {
+        if let Some(vertex) = self.heuristic.solve_oracle(gradient, constraints)? {
+            return Ok(Some(vertex));
+        }
+        self.exact.solve_oracle(gradient, constraints)
+    }
+}
+
+/// Build the oracle backend selected by `config` on top of `exact`.
+#[must_use]
+pub fn select_backend(
+    config: &SolverConfig,
+    exact: Arc<dyn SolverBackend>,
+) -> Arc<dyn SolverBackend> {
+    match config.backend {
+        SolverBackendKind::Highs => exact,
+        SolverBackendKind::Heuristic => Arc::new(FallbackBackend::new(
+            HeuristicOracle::new(config.heuristic_max_vars),
+            exact,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::constraint::{Constraint, VariableBounds};
+    use crate::port::outbound::solver::LpProblem;
+    use parking_lot::Mutex;
+    use rust_decimal_macros::dec;
+
+    /// Exact backend that always picks the first variable and counts calls.
+    #[derive(Default)]
+    struct CountingBackend {
+        calls: Mutex<usize>,
+    }
+
+    impl SolverBackend for CountingBackend {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        fn solve_oracle(
+            &self,
+            gradient: &[Decimal],
+            _constraints: &IlpProblem,
+        ) -> Result<Option<Vertex>> {
+            *self.calls.lock() += 1;
+            let mut values = vec![Decimal::ZERO; gradient.len()];
+            values[0] = Decimal::ONE;
+            Ok(Some(Vertex {
+                values,
+                objective: gradient[0],
+            }))
+        }
+    }
+
+    fn exactly_one(n: usize) -> IlpProblem {
+        IlpProblem::all_binary(LpProblem {
+            objective: vec![Decimal::ZERO; n],
+            constraints: vec![Constraint::eq(vec![Decimal::ONE; n], Decimal::ONE)],
+            bounds: vec![VariableBounds::binary(); n],
+        })
+    }
+
+    #[test]
+    fn small_cluster_is_answered_by_heuristic() {
+        let exact = Arc::new(CountingBackend::default());
+        let backend = FallbackBackend::new(HeuristicOracle::new(4), exact.clone());
+
+        let vertex = backend
+            .solve_oracle(&[dec!(0.3), dec!(-0.2), dec!(0.1)], &exactly_one(3))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(vertex.values, vec![dec!(0), dec!(1), dec!(0)]);
+        assert_eq!(*exact.calls.lock(), 0);
+    }
+
+    #[test]
+    fn large_cluster_falls_back_to_exact() {
+        let exact = Arc::new(CountingBackend::default());
+        let backend = FallbackBackend::new(HeuristicOracle::new(2), exact.clone());
+
+        let vertex = backend
+            .solve_oracle(&[dec!(0.3), dec!(-0.2), dec!(0.1)], &exactly_one(3))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(vertex.values[0], Decimal::ONE);
+        assert_eq!(*exact.calls.lock(), 1);
+    }
+
+    #[test]
+    fn highs_kind_uses_exact_backend_directly() {
+        let exact: Arc<dyn SolverBackend> = Arc::new(CountingBackend::default());
+
+        let highs = select_backend(&SolverConfig::default(), Arc::clone(&exact));
+        let heuristic = select_backend(
+            &SolverConfig {
+                backend: SolverBackendKind::Heuristic,
+                ..SolverConfig::default()
+            },
+            exact,
+        );
+
+        assert_eq!(highs.name(), "counting");
+        assert_eq!(heuristic.name(), "heuristic");
+    }
+}
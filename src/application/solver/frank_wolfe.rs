@@ -20,10 +20,7 @@ use rust_decimal::Decimal;
 
 use super::bregman::{bregman_divergence, bregman_gradient};
 use crate::error::Result;
-use crate::port::{
-    outbound::solver::IlpProblem, outbound::solver::LpProblem, outbound::solver::SolutionStatus,
-    outbound::solver::Solver,
-};
+use crate::port::{outbound::solver::IlpProblem, outbound::solver::SolverBackend};
 
 /// Configuration for the Frank-Wolfe algorithm.
 #[derive(Debug, Clone)]
@@ -78,7 +75,7 @@ impl FrankWolfe {
     ///
     /// * `theta` - Current market prices (may be outside M).
     /// * `ilp_problem` - ILP defining the feasible set M via constraints.
-    /// * `solver` - ILP solver implementation to use.
+    /// * `solver` - Oracle backend that finds the minimizing vertex.
     ///
     /// # Returns
     ///
@@ -86,12 +83,32 @@ impl FrankWolfe {
     ///
     /// # Errors
     ///
-    /// Returns an error if the ILP solver fails during any iteration.
+    /// Returns an error if the oracle fails during any iteration.
     pub fn project(
         &self,
         theta: &[Decimal],
         ilp_problem: &IlpProblem,
-        solver: &dyn Solver,
+        solver: &dyn SolverBackend,
+    ) -> Result<FrankWolfeResult> {
+        self.project_from(theta, None, ilp_problem, solver)
+    }
+
+    /// Run Frank-Wolfe projection starting from an initial point.
+    ///
+    /// Behaves like [`FrankWolfe::project`], but seeds the iterate with
+    /// `initial` instead of `theta`. Seeding with the converged projection of
+    /// nearby prices lets a stable cluster converge in a single iteration.
+    /// An initial point whose length does not match `theta` is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the oracle fails during any iteration.
+    pub fn project_from(
+        &self,
+        theta: &[Decimal],
+        initial: Option<&[Decimal]>,
+        ilp_problem: &IlpProblem,
+        solver: &dyn SolverBackend,
     ) -> Result<FrankWolfeResult> {
         let n = theta.len();
         if n == 0 {
@@ -104,11 +121,15 @@ impl FrankWolfe {
         }
 
         // ========================================================================
-        // STEP 1: Initialize with current prices
+        // STEP 1: Initialize with current prices (or the warm-start point)
         // ========================================================================
         // Start with mu = theta. If theta is already in M, we converge immediately.
         // If theta is outside M, the algorithm will iteratively move mu toward M.
-        let mut mu = theta.to_vec();
+        // A warm-start point from a previous converged run replaces theta here.
+        let mut mu = match initial {
+            Some(initial) if initial.len() == n => initial.to_vec(),
+            _ => theta.to_vec(),
+        };
         let mut iterations = 0;
         let mut gap = Decimal::MAX;
 
@@ -133,22 +154,11 @@ impl FrankWolfe {
             // For prediction markets, M is the marginal polytope (valid probability
             // distributions), and the ILP finds the vertex (extreme point) of M
             // that most decreases the Bregman divergence.
-            let oracle_problem = IlpProblem {
-                lp: LpProblem {
-                    objective: grad.clone(),
-                    constraints: ilp_problem.lp.constraints.clone(),
-                    bounds: ilp_problem.lp.bounds.clone(),
-                },
-                integer_vars: ilp_problem.integer_vars.clone(),
-            };
-
-            let solution = solver.solve_ilp(&oracle_problem)?;
-
-            if solution.status != SolutionStatus::Optimal {
+            let Some(vertex) = solver.solve_oracle(&grad, ilp_problem)? else {
                 break;
-            }
+            };
 
-            let s = &solution.values;
+            let s = &vertex.values;
 
             // ====================================================================
             // STEP 5: Check convergence via duality gap
@@ -224,27 +234,36 @@ impl FrankWolfeResult {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::solver::heuristic::HeuristicOracle;
     use crate::domain::{
-        constraint::Constraint, constraint::ConstraintSense, constraint::VariableBounds,
+        cluster::Cluster, constraint::Constraint, constraint::ConstraintSense,
+        constraint::VariableBounds, id::MarketId, relation::Relation, relation::RelationKind,
     };
-    use crate::port::outbound::solver::LpSolution;
+    use crate::port::outbound::solver::{LpProblem, Vertex};
     use rust_decimal_macros::dec;
 
+    /// Oracle that picks the single variable with the lowest gradient.
     struct MockSolver;
 
-    impl MockSolver {
-        fn solve(problem: &LpProblem) -> LpSolution {
-            let n = problem.objective.len();
+    impl SolverBackend for MockSolver {
+        fn name(&self) -> &'static str {
+            "mock-solver"
+        }
+
+        fn solve_oracle(
+            &self,
+            gradient: &[Decimal],
+            _constraints: &IlpProblem,
+        ) -> Result<Option<Vertex>> {
+            let n = gradient.len();
             if n == 0 {
-                return LpSolution {
+                return Ok(Some(Vertex {
                     values: vec![],
                     objective: Decimal::ZERO,
-                    status: SolutionStatus::Optimal,
-                };
+                }));
             }
 
-            let best_idx = problem
-                .objective
+            let best_idx = gradient
                 .iter()
                 .enumerate()
                 .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
@@ -254,30 +273,10 @@ mod tests {
             let mut values = vec![Decimal::ZERO; n];
             values[best_idx] = Decimal::ONE;
 
-            let objective = values
-                .iter()
-                .zip(problem.objective.iter())
-                .fold(Decimal::ZERO, |acc, (x, c)| acc + (*x * *c));
-
-            LpSolution {
+            Ok(Some(Vertex {
                 values,
-                objective,
-                status: SolutionStatus::Optimal,
-            }
-        }
-    }
-
-    impl Solver for MockSolver {
-        fn name(&self) -> &'static str {
-            "mock-solver"
-        }
-
-        fn solve_lp(&self, problem: &LpProblem) -> Result<LpSolution> {
-            Ok(Self::solve(problem))
-        }
-
-        fn solve_ilp(&self, problem: &IlpProblem) -> Result<LpSolution> {
-            Ok(Self::solve(&problem.lp))
+                objective: gradient[best_idx],
+            }))
         }
     }
 
@@ -379,6 +378,122 @@ mod tests {
         );
     }
 
+    fn simplex_problem() -> IlpProblem {
+        IlpProblem {
+            lp: LpProblem {
+                objective: vec![Decimal::ZERO; 2],
+                constraints: vec![Constraint {
+                    coefficients: vec![Decimal::ONE, Decimal::ONE],
+                    sense: ConstraintSense::Equal,
+                    rhs: Decimal::ONE,
+                }],
+                bounds: vec![VariableBounds::binary(); 2],
+            },
+            integer_vars: vec![],
+        }
+    }
+
+    #[test]
+    fn test_frank_wolfe_warm_start_reduces_iterations() {
+        let fw = FrankWolfe::new(FrankWolfeConfig {
+            max_iterations: 100,
+            tolerance: dec!(0.001),
+        });
+        let solver = MockSolver;
+        let ilp = simplex_problem();
+        let theta = vec![dec!(0.3), dec!(0.5)];
+
+        let cold = fw.project(&theta, &ilp, &solver).unwrap();
+        assert!(cold.converged);
+        assert!(
+            cold.iterations > 10,
+            "cold start should need many iterations, got {}",
+            cold.iterations
+        );
+
+        // Stable cluster: same prices, seeded from the converged projection
+        let warm = fw
+            .project_from(&theta, Some(cold.mu.as_slice()), &ilp, &solver)
+            .unwrap();
+        assert!(warm.converged);
+        assert_eq!(warm.iterations, 1);
+        assert_eq!(warm.mu, cold.mu);
+    }
+
+    #[test]
+    fn test_frank_wolfe_ignores_mismatched_initial_point() {
+        let fw = FrankWolfe::new(FrankWolfeConfig {
+            max_iterations: 100,
+            tolerance: dec!(0.001),
+        });
+        let solver = MockSolver;
+        let ilp = simplex_problem();
+        let theta = vec![dec!(0.3), dec!(0.5)];
+
+        let cold = fw.project(&theta, &ilp, &solver).unwrap();
+        let seeded = fw
+            .project_from(&theta, Some(&[dec!(0.5)][..]), &ilp, &solver)
+            .unwrap();
+
+        assert_eq!(seeded.iterations, cold.iterations);
+        assert_eq!(seeded.mu, cold.mu);
+    }
+
+    /// Project `theta` onto the polytope of a cluster built from `kind`.
+    fn project_relation(kind: RelationKind, theta: &[Decimal]) -> Vec<Decimal> {
+        let cluster = Cluster::from_relations(vec![Relation::new(kind, 0.9, "test")]);
+        let ilp = IlpProblem::all_binary(LpProblem {
+            objective: theta.to_vec(),
+            constraints: cluster.constraints,
+            bounds: vec![VariableBounds::binary(); theta.len()],
+        });
+        let fw = FrankWolfe::new(FrankWolfeConfig {
+            max_iterations: 20,
+            tolerance: dec!(0.00001),
+        });
+
+        // Seeding from an even split keeps the oracle away from ties
+        fw.project_from(
+            theta,
+            Some(&[dec!(0.5), dec!(0.5)][..]),
+            &ilp,
+            &HeuristicOracle::new(12),
+        )
+        .unwrap()
+        .mu
+    }
+
+    #[test]
+    fn test_implication_projects_differently_from_mutual_exclusion() {
+        // Markets are ordered a, b; a is priced above b, violating a => b
+        let theta = vec![dec!(0.7), dec!(0.5)];
+
+        let implied = project_relation(
+            RelationKind::Implies {
+                if_yes: MarketId::new("a"),
+                then_yes: MarketId::new("b"),
+            },
+            &theta,
+        );
+        let exclusive = project_relation(
+            RelationKind::MutuallyExclusive {
+                markets: vec![MarketId::new("a"), MarketId::new("b")],
+            },
+            &theta,
+        );
+
+        // Implication only visits vertices with a <= b, so a never exceeds b
+        assert!(
+            implied[0] <= implied[1] + dec!(0.000001),
+            "implication should keep P(a) <= P(b), got {implied:?}"
+        );
+        // Mutual exclusion has no such ordering and leaves a well above b
+        assert!(
+            exclusive[0] - exclusive[1] > dec!(0.05),
+            "mutual exclusion should keep P(a) above P(b), got {exclusive:?}"
+        );
+    }
+
     #[test]
     fn test_frank_wolfe_result_has_arbitrage() {
         let result = FrankWolfeResult {
@@ -0,0 +1,365 @@
+//! Fast oracle for small binary clusters.
+//!
+//! Most clusters the combinatorial path sees have a handful of binary
+//! outcomes tied together by exactly-one and implication constraints. For
+//! those, a greedy guess followed by a pruned depth-first search finds the
+//! minimizing vertex far faster than building and solving a `HiGHS` model.
+//!
+//! The oracle only answers when it has proven its vertex optimal. Problems it
+//! cannot handle (too many variables, non-binary variables) and regions it
+//! finds infeasible return `None`, leaving the answer to a fallback backend.
+
+// Allow large error types - inherited from crate's unified Error type
+#![allow(clippy::result_large_err)]
+
+use rust_decimal::Decimal;
+
+use crate::domain::constraint::{Constraint, ConstraintSense};
+use crate::error::Result;
+use crate::port::{
+    outbound::solver::IlpProblem, outbound::solver::SolverBackend, outbound::solver::Vertex,
+};
+
+/// Oracle that solves small all-binary problems by greedy guess and search.
+#[derive(Debug, Clone, Copy)]
+pub struct HeuristicOracle {
+    /// Largest number of variables the oracle attempts.
+    max_vars: usize,
+}
+
+impl HeuristicOracle {
+    /// Create an oracle that attempts problems with up to `max_vars` variables.
+    #[must_use]
+    pub const fn new(max_vars: usize) -> Self {
+        Self { max_vars }
+    }
+
+    /// Return the largest number of variables the oracle attempts.
+    #[must_use]
+    pub const fn max_vars(&self) -> usize {
+        self.max_vars
+    }
+}
+
+impl SolverBackend for HeuristicOracle {
+    fn name(&self) -> &'static str {
+        "heuristic"
+    }
+
+    fn solve_oracle(
+        &self,
+        gradient: &[Decimal],
+        constraints: &IlpProblem,
+    ) -> Result<Option<Vertex>> {
+        if gradient.len() > self.max_vars || !is_binary_problem(gradient.len(), constraints) {
+            return Ok(None);
+        }
+
+        // Taking every variable with a negative gradient minimizes the
+        // objective over the unit box, so a feasible greedy guess is optimal.
+        let greedy: Vec<bool> = gradient.iter().map(|g| *g < Decimal::ZERO).collect();
+        if is_feasible(&constraints.lp.constraints, &greedy) {
+            return Ok(Some(vertex(gradient, &greedy)));
+        }
+
+        let best = Search::new(gradient, &constraints.lp.constraints).run();
+        Ok(best.map(|assignment| vertex(gradient, &assignment)))
+    }
+}
+
+/// Whether every variable is an integer bounded to `[0, 1]` and every
+/// constraint spans all `n` variables.
+fn is_binary_problem(n: usize, problem: &IlpProblem) -> bool {
+    problem.lp.bounds.len() == n
+        && problem
+            .lp
+            .bounds
+            .iter()
+            .all(|bounds| bounds.lower == Some(Decimal::ZERO) && bounds.upper == Some(Decimal::ONE))
+        && (0..n).all(|i| problem.integer_vars.contains(&i))
+        && problem
+            .lp
+            .constraints
+            .iter()
+            .all(|constraint| constraint.coefficients.len() == n)
+}
+
+fn is_feasible(constraints: &[Constraint], assignment: &[bool]) -> bool {
+    constraints.iter().all(|constraint| {
+        let lhs: Decimal = constraint
+            .coefficients
+            .iter()
+            .zip(assignment)
+            .filter(|(_, set)| **set)
+            .map(|(coefficient, _)| *coefficient)
+            .sum();
+        satisfies(constraint, lhs, lhs)
+    })
+}
+
+/// Whether some left-hand side within `[lo, hi]` satisfies `constraint`.
+fn satisfies(constraint: &Constraint, lo: Decimal, hi: Decimal) -> bool {
+    match constraint.sense {
+        ConstraintSense::LessEqual => lo <= constraint.rhs,
+        ConstraintSense::GreaterEqual => hi >= constraint.rhs,
+        ConstraintSense::Equal => lo <= constraint.rhs && constraint.rhs <= hi,
+    }
+}
+
+fn vertex(gradient: &[Decimal], assignment: &[bool]) -> Vertex {
+    let values = assignment
+        .iter()
+        .map(|set| if *set { Decimal::ONE } else { Decimal::ZERO })
+        .collect();
+    let objective = gradient
+        .iter()
+        .zip(assignment)
+        .filter(|(_, set)| **set)
+        .map(|(g, _)| *g)
+        .sum();
+    Vertex { values, objective }
+}
+
+/// Depth-first branch and bound over binary assignments.
+///
+/// Variables are fixed in order of decreasing gradient magnitude. A branch
+/// is cut when the constraints can no longer be met by the free variables or
+/// when even the best completion cannot beat the incumbent.
+struct Search<'a> {
+    gradient: &'a [Decimal],
+    constraints: &'a [Constraint],
+    /// Variable fixed at each depth.
+    order: Vec<usize>,
+    /// Lowest objective the free variables can add, per depth.
+    free_objective: Vec<Decimal>,
+    /// Lowest and highest left-hand side the free variables can add, per
+    /// constraint and depth.
+    free_lhs: Vec<Vec<(Decimal, Decimal)>>,
+    /// Left-hand side of each constraint from the fixed variables.
+    lhs: Vec<Decimal>,
+    assignment: Vec<bool>,
+    best: Option<(Decimal, Vec<bool>)>,
+}
+
+impl<'a> Search<'a> {
+    fn new(gradient: &'a [Decimal], constraints: &'a [Constraint]) -> Self {
+        let n = gradient.len();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|a, b| gradient[*b].abs().cmp(&gradient[*a].abs()));
+
+        let mut free_objective = vec![Decimal::ZERO; n + 1];
+        for depth in (0..n).rev() {
+            free_objective[depth] =
+                free_objective[depth + 1] + gradient[order[depth]].min(Decimal::ZERO);
+        }
+
+        let free_lhs = constraints
+            .iter()
+            .map(|constraint| {
+                let mut bounds = vec![(Decimal::ZERO, Decimal::ZERO); n + 1];
+                for depth in (0..n).rev() {
+                    let coefficient = constraint.coefficients[order[depth]];
+                    let (lo, hi) = bounds[depth + 1];
+                    bounds[depth] = (
+                        lo + coefficient.min(Decimal::ZERO),
+                        hi + coefficient.max(Decimal::ZERO),
+                    );
+                }
+                bounds
+            })
+            .collect();
+
+        Self {
+            gradient,
+            constraints,
+            order,
+            free_objective,
+            free_lhs,
+            lhs: vec![Decimal::ZERO; constraints.len()],
+            assignment: vec![false; n],
+            best: None,
+        }
+    }
+
+    fn run(mut self) -> Option<Vec<bool>> {
+        self.visit(0, Decimal::ZERO);
+        self.best.map(|(_, assignment)| assignment)
+    }
+
+    fn visit(&mut self, depth: usize, objective: Decimal) {
+        if let Some((best, _)) = &self.best {
+            if objective + self.free_objective[depth] >= *best {
+                return;
+            }
+        }
+        if !self.can_satisfy(depth) {
+            return;
+        }
+        if depth == self.order.len() {
+            self.best = Some((objective, self.assignment.clone()));
+            return;
+        }
+
+        // Try the value the gradient prefers first so good incumbents
+        // appear early and prune more of the tree.
+        let var = self.order[depth];
+        let preferred = self.gradient[var] < Decimal::ZERO;
+        for set in [preferred, !preferred] {
+            self.fix(var, set);
+            let added = if set {
+                self.gradient[var]
+            } else {
+                Decimal::ZERO
+            };
+            self.visit(depth + 1, objective + added);
+            self.unfix(var, set);
+        }
+    }
+
+    fn can_satisfy(&self, depth: usize) -> bool {
+        self.constraints
+            .iter()
+            .zip(&self.lhs)
+            .zip(&self.free_lhs)
+            .all(|((constraint, lhs), free)| {
+                let (lo, hi) = free[depth];
+                satisfies(constraint, *lhs + lo, *lhs + hi)
+            })
+    }
+
+    fn fix(&mut self, var: usize, set: bool) {
+        self.assignment[var] = set;
+        if set {
+            for (lhs, constraint) in self.lhs.iter_mut().zip(self.constraints) {
+                *lhs += constraint.coefficients[var];
+            }
+        }
+    }
+
+    fn unfix(&mut self, var: usize, set: bool) {
+        self.assignment[var] = false;
+        if set {
+            for (lhs, constraint) in self.lhs.iter_mut().zip(self.constraints) {
+                *lhs -= constraint.coefficients[var];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::constraint::VariableBounds;
+    use crate::port::outbound::solver::LpProblem;
+    use rust_decimal_macros::dec;
+
+    fn binary_problem(n: usize, constraints: Vec<Constraint>) -> IlpProblem {
+        IlpProblem::all_binary(LpProblem {
+            objective: vec![Decimal::ZERO; n],
+            constraints,
+            bounds: vec![VariableBounds::binary(); n],
+        })
+    }
+
+    fn exactly_one(n: usize) -> Constraint {
+        Constraint::eq(vec![Decimal::ONE; n], Decimal::ONE)
+    }
+
+    #[test]
+    fn feasible_greedy_guess_is_returned() {
+        let oracle = HeuristicOracle::new(8);
+        let problem = binary_problem(3, vec![Constraint::leq(vec![Decimal::ONE; 3], dec!(2))]);
+
+        let vertex = oracle
+            .solve_oracle(&[dec!(-0.3), dec!(0.2), dec!(-0.1)], &problem)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(vertex.values, vec![dec!(1), dec!(0), dec!(1)]);
+        assert_eq!(vertex.objective, dec!(-0.4));
+    }
+
+    #[test]
+    fn search_picks_best_vertex_of_exactly_one() {
+        let oracle = HeuristicOracle::new(8);
+        let problem = binary_problem(4, vec![exactly_one(4)]);
+
+        // Greedy takes both negative variables, which breaks exactly-one
+        let vertex = oracle
+            .solve_oracle(&[dec!(-0.2), dec!(0.4), dec!(-0.7), dec!(0.1)], &problem)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(vertex.values, vec![dec!(0), dec!(0), dec!(1), dec!(0)]);
+        assert_eq!(vertex.objective, dec!(-0.7));
+    }
+
+    #[test]
+    fn search_respects_implications() {
+        // x0 implies x1 (x0 - x1 <= 0), and exactly one of x1, x2
+        let oracle = HeuristicOracle::new(8);
+        let problem = binary_problem(
+            3,
+            vec![
+                Constraint::leq(vec![dec!(1), dec!(-1), dec!(0)], Decimal::ZERO),
+                Constraint::eq(vec![dec!(0), dec!(1), dec!(1)], Decimal::ONE),
+            ],
+        );
+
+        let vertex = oracle
+            .solve_oracle(&[dec!(-0.5), dec!(0.2), dec!(-0.1)], &problem)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(vertex.values, vec![dec!(1), dec!(1), dec!(0)]);
+        assert_eq!(vertex.objective, dec!(-0.3));
+    }
+
+    #[test]
+    fn infeasible_region_returns_none() {
+        let oracle = HeuristicOracle::new(8);
+        let problem = binary_problem(2, vec![Constraint::geq(vec![Decimal::ONE; 2], dec!(3))]);
+
+        let vertex = oracle
+            .solve_oracle(&[dec!(0.1), dec!(0.2)], &problem)
+            .unwrap();
+
+        assert!(vertex.is_none());
+    }
+
+    #[test]
+    fn oversized_problem_is_declined() {
+        let oracle = HeuristicOracle::new(2);
+        let problem = binary_problem(3, vec![exactly_one(3)]);
+
+        let vertex = oracle
+            .solve_oracle(&[dec!(0.1), dec!(0.2), dec!(0.3)], &problem)
+            .unwrap();
+
+        assert!(vertex.is_none());
+    }
+
+    #[test]
+    fn continuous_variables_are_declined() {
+        let oracle = HeuristicOracle::new(8);
+        let mut problem = binary_problem(2, vec![exactly_one(2)]);
+        problem.integer_vars.clear();
+
+        let vertex = oracle
+            .solve_oracle(&[dec!(0.1), dec!(0.2)], &problem)
+            .unwrap();
+
+        assert!(vertex.is_none());
+    }
+
+    #[test]
+    fn empty_problem_is_trivially_optimal() {
+        let oracle = HeuristicOracle::new(8);
+        let problem = binary_problem(0, vec![]);
+
+        let vertex = oracle.solve_oracle(&[], &problem).unwrap().unwrap();
+
+        assert!(vertex.values.is_empty());
+        assert_eq!(vertex.objective, Decimal::ZERO);
+    }
+}
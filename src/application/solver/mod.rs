@@ -2,10 +2,14 @@
 //!
 //! Implements mathematical algorithms for arbitrage detection in prediction markets:
 //!
+//! - [`backend`]: Oracle backend selection with heuristic fallback
 //! - [`bregman`]: Bregman divergence calculations for LMSR markets
 //! - [`frank_wolfe`]: Frank-Wolfe algorithm for projecting onto the marginal polytope
+//! - [`heuristic`]: Fast oracle for small binary clusters
 //! - [`projection`]: Adapter implementing the projection solver port
 
+pub mod backend;
 pub mod bregman;
 pub mod frank_wolfe;
+pub mod heuristic;
 pub mod projection;
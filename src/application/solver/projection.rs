@@ -1,7 +1,7 @@
 //! Projection solver adapter using Frank-Wolfe algorithm.
 //!
 //! Implements the [`ProjectionSolver`] port by combining the Frank-Wolfe
-//! algorithm with an ILP solver backend.
+//! algorithm with a [`SolverBackend`] oracle.
 
 use std::sync::Arc;
 
@@ -10,21 +10,21 @@ use rust_decimal::Decimal;
 use crate::error::Result;
 use crate::port::{
     outbound::solver::IlpProblem, outbound::solver::ProjectionResult,
-    outbound::solver::ProjectionSolver, outbound::solver::Solver,
+    outbound::solver::ProjectionSolver, outbound::solver::SolverBackend,
 };
 
 use super::frank_wolfe::{FrankWolfe, FrankWolfeConfig};
 
 /// Projection solver adapter for cluster and combinatorial detection.
 ///
-/// Combines the Frank-Wolfe algorithm with an ILP solver (e.g., HiGHS)
+/// Combines the Frank-Wolfe algorithm with an oracle backend (e.g., HiGHS)
 /// to project market prices onto the marginal polytope and detect arbitrage.
 #[derive(Clone)]
 pub struct FrankWolfeProjectionSolver {
     /// Frank-Wolfe algorithm instance.
     frank_wolfe: FrankWolfe,
-    /// ILP solver for the linear minimization oracle.
-    ilp_solver: Arc<dyn Solver>,
+    /// Backend for the linear minimization oracle.
+    oracle: Arc<dyn SolverBackend>,
 }
 
 impl FrankWolfeProjectionSolver {
@@ -33,12 +33,12 @@ impl FrankWolfeProjectionSolver {
     /// # Arguments
     ///
     /// * `config` - Configuration for the Frank-Wolfe algorithm.
-    /// * `ilp_solver` - ILP solver to use as the linear minimization oracle.
+    /// * `oracle` - Backend to use as the linear minimization oracle.
     #[must_use]
-    pub fn new(config: FrankWolfeConfig, ilp_solver: Arc<dyn Solver>) -> Self {
+    pub fn new(config: FrankWolfeConfig, oracle: Arc<dyn SolverBackend>) -> Self {
         Self {
             frank_wolfe: FrankWolfe::new(config),
-            ilp_solver,
+            oracle,
         }
     }
 }
@@ -49,9 +49,18 @@ impl ProjectionSolver for FrankWolfeProjectionSolver {
     }
 
     fn project(&self, theta: &[Decimal], problem: &IlpProblem) -> Result<ProjectionResult> {
-        let result = self
-            .frank_wolfe
-            .project(theta, problem, self.ilp_solver.as_ref())?;
+        self.project_from(theta, None, problem)
+    }
+
+    fn project_from(
+        &self,
+        theta: &[Decimal],
+        initial: Option<&[Decimal]>,
+        problem: &IlpProblem,
+    ) -> Result<ProjectionResult> {
+        let result =
+            self.frank_wolfe
+                .project_from(theta, initial, problem, self.oracle.as_ref())?;
 
         Ok(ProjectionResult {
             values: result.mu,
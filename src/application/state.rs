@@ -1,20 +1,25 @@
 //! Shared application state.
 //!
 //! Provides centralized, thread-safe state management for the application
-//! including position tracking, risk limits, and circuit breaker status.
+//! including position tracking, risk limits, trading state, and recent events.
 
-use std::collections::HashSet;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
 
 use parking_lot::{Mutex, RwLock};
 use rust_decimal::Decimal;
+use tracing::{info, warn};
 
+use crate::application::cache::event::EventRingBuffer;
 use crate::application::cache::position::PositionTracker;
-use crate::domain::{money::Price, position::PositionStatus};
+use crate::application::risk::breaker::FailureBreaker;
+use crate::domain::{id::MarketId, money::Price, position::PositionStatus, trading::TradingState};
 use crate::port::inbound::runtime::{
-    RuntimePosition, RuntimePositionStatus, RuntimeRiskLimitKind, RuntimeRiskLimitUpdateError,
-    RuntimeRiskLimits, RuntimeState,
+    RuntimeEvent, RuntimePosition, RuntimePositionStatus, RuntimeRiskLimitKind,
+    RuntimeRiskLimitUpdateError, RuntimeRiskLimits, RuntimeState,
 };
+use crate::port::outbound::store::TradingStateStore;
 
 /// Risk limits configuration.
 ///
@@ -28,10 +33,16 @@ pub struct RiskLimits {
     pub max_total_exposure: Decimal,
     /// Minimum expected profit required to execute a trade.
     pub min_profit_threshold: Decimal,
+    /// Minimum expected profit as a fraction of capital deployed; `None` disables the check.
+    pub min_profit_pct: Option<Decimal>,
     /// Maximum allowed slippage between detection and execution (e.g., 0.02 = 2%).
     pub max_slippage: Decimal,
     /// Timeout in seconds before cancelling an execution attempt.
     pub execution_timeout_secs: u64,
+    /// Maximum order book age in milliseconds for any leg; `None` disables the check.
+    pub max_book_age_ms: Option<u64>,
+    /// Maximum legs an opportunity may have to be executed; `None` disables the check.
+    pub max_execution_legs: Option<usize>,
 }
 
 impl Default for RiskLimits {
@@ -40,8 +51,11 @@ impl Default for RiskLimits {
             max_position_per_market: Decimal::from(1000),
             max_total_exposure: Decimal::from(10000),
             min_profit_threshold: Decimal::new(5, 2), // $0.05
-            max_slippage: Decimal::new(2, 2),         // 2%
+            min_profit_pct: None,
+            max_slippage: Decimal::new(2, 2), // 2%
             execution_timeout_secs: 30,
+            max_book_age_ms: None,
+            max_execution_legs: None,
         }
     }
 }
@@ -121,22 +135,28 @@ fn runtime_kind_to_app(kind: RuntimeRiskLimitKind) -> RiskLimitKind {
 
 /// Shared application state accessible by all services.
 ///
-/// Provides thread-safe access to positions, risk limits, circuit breaker
-/// status, and exposure tracking. All operations are safe for concurrent
+/// Provides thread-safe access to positions, risk limits, trading state,
+/// and exposure tracking. All operations are safe for concurrent
 /// access from multiple tasks.
 pub struct AppState {
     /// Position tracker for all open and closed positions.
     positions: RwLock<PositionTracker>,
     /// Current risk limits configuration.
     risk_limits: RwLock<RiskLimits>,
-    /// Circuit breaker flag. When true, no new trades are allowed.
-    circuit_breaker: AtomicBool,
-    /// Reason for circuit breaker activation, if any.
-    circuit_breaker_reason: RwLock<Option<String>>,
+    /// Whether new trades are allowed, and why not.
+    trading_state: RwLock<TradingState>,
+    /// Durable store for the trading state, if persistence is configured.
+    trading_store: Option<Arc<dyn TradingStateStore>>,
     /// Markets with in-flight executions (prevents duplicate trades).
     pending_executions: Mutex<HashSet<String>>,
+    /// Markets cooling down after a failed execution, with their expiry.
+    market_cooldowns: Mutex<HashMap<String, Instant>>,
     /// Reserved exposure from approved but not yet executed opportunities.
     pending_exposure: Mutex<Decimal>,
+    /// Consecutive execution failure tracking for the circuit breaker.
+    breaker: Mutex<FailureBreaker>,
+    /// Most recent notification events, for operator queries.
+    recent_events: EventRingBuffer,
 }
 
 impl AppState {
@@ -146,13 +166,55 @@ impl AppState {
         Self {
             positions: RwLock::new(PositionTracker::new()),
             risk_limits: RwLock::new(risk_limits),
-            circuit_breaker: AtomicBool::new(false),
-            circuit_breaker_reason: RwLock::new(None),
+            trading_state: RwLock::new(TradingState::Active),
+            trading_store: None,
             pending_executions: Mutex::new(HashSet::new()),
+            market_cooldowns: Mutex::new(HashMap::new()),
             pending_exposure: Mutex::new(Decimal::ZERO),
+            breaker: Mutex::new(FailureBreaker::disabled()),
+            recent_events: EventRingBuffer::default(),
         }
     }
 
+    /// Trip the circuit breaker according to `breaker` on execution failures.
+    #[must_use]
+    pub fn with_failure_breaker(mut self, breaker: FailureBreaker) -> Self {
+        *self.breaker.get_mut() = breaker;
+        self
+    }
+
+    /// Retain up to `capacity` recent notification events.
+    #[must_use]
+    pub fn with_recent_events(mut self, capacity: usize) -> Self {
+        self.recent_events = EventRingBuffer::new(capacity);
+        self
+    }
+
+    /// Persist the trading state through `store`, restoring a saved halt.
+    ///
+    /// Only [`TradingState::Halted`] is restored; a transient pause does not
+    /// outlive the process that set it.
+    #[must_use]
+    pub fn with_trading_store(mut self, store: Arc<dyn TradingStateStore>) -> Self {
+        match store.load() {
+            Ok(Some(saved @ TradingState::Halted { .. })) => {
+                warn!(state = %saved, "Restored trading halt from previous run");
+                *self.trading_state.get_mut() = saved;
+            }
+            Ok(_) => {}
+            Err(e) => warn!(error = %e, "Failed to load trading state"),
+        }
+        self.trading_store = Some(store);
+        self
+    }
+
+    /// Buffer of recent notification events.
+    ///
+    /// Register a clone with the notifier registry to fill it.
+    pub fn recent_events(&self) -> &EventRingBuffer {
+        &self.recent_events
+    }
+
     /// Acquire read access to the position tracker.
     pub fn positions(&self) -> parking_lot::RwLockReadGuard<'_, PositionTracker> {
         self.positions.read()
@@ -210,26 +272,77 @@ impl AppState {
         Ok(limits.clone())
     }
 
-    /// Return true if the circuit breaker is currently active.
-    pub fn is_circuit_breaker_active(&self) -> bool {
-        self.circuit_breaker.load(Ordering::SeqCst)
+    /// Return the current trading state.
+    pub fn trading_state(&self) -> TradingState {
+        self.trading_state.read().clone()
     }
 
-    /// Activate the circuit breaker, halting all new trades.
-    pub fn activate_circuit_breaker(&self, reason: impl Into<String>) {
-        self.circuit_breaker.store(true, Ordering::SeqCst);
-        *self.circuit_breaker_reason.write() = Some(reason.into());
+    /// Return true if new trades may be executed.
+    pub fn is_trading_active(&self) -> bool {
+        self.trading_state.read().is_active()
     }
 
-    /// Deactivate the circuit breaker, resuming normal trading.
-    pub fn reset_circuit_breaker(&self) {
-        self.circuit_breaker.store(false, Ordering::SeqCst);
-        *self.circuit_breaker_reason.write() = None;
+    /// Pause trading until resumed or the process restarts.
+    ///
+    /// Has no effect while trading is halted, so an automatic pause never
+    /// masks a manual halt.
+    pub fn pause(&self, reason: impl Into<String>) {
+        let mut state = self.trading_state.write();
+        if state.is_halted() {
+            return;
+        }
+        *state = TradingState::paused(reason);
+        warn!(state = %state, "Trading paused");
     }
 
-    /// Return the circuit breaker activation reason, if active.
-    pub fn circuit_breaker_reason(&self) -> Option<String> {
-        self.circuit_breaker_reason.read().clone()
+    /// Resume trading if it is paused for `reason`.
+    ///
+    /// Returns true if trading was resumed. Trading that is active, halted,
+    /// or paused for a different reason is left unchanged.
+    pub fn resume_paused_for(&self, reason: &str) -> bool {
+        let mut state = self.trading_state.write();
+        if !matches!(&*state, TradingState::Paused { reason: current, .. } if current == reason) {
+            return false;
+        }
+        *state = TradingState::Active;
+        info!("Trading resumed");
+        true
+    }
+
+    /// Halt trading until explicitly resumed, including across restarts.
+    pub fn halt(&self, reason: impl Into<String>) {
+        let mut state = self.trading_state.write();
+        *state = TradingState::halted(reason);
+        warn!(state = %state, "Trading halted");
+        self.persist_trading_state(&state);
+    }
+
+    /// Acquire access to the execution failure circuit breaker.
+    pub fn breaker(&self) -> parking_lot::MutexGuard<'_, FailureBreaker> {
+        self.breaker.lock()
+    }
+
+    /// Resume trading from any paused or halted state.
+    ///
+    /// Also resets the failure circuit breaker, so an operator resume starts
+    /// a fresh failure streak.
+    pub fn resume(&self) {
+        self.breaker.lock().reset();
+        let mut state = self.trading_state.write();
+        let was_halted = state.is_halted();
+        *state = TradingState::Active;
+        info!("Trading resumed");
+        if was_halted {
+            self.persist_trading_state(&state);
+        }
+    }
+
+    fn persist_trading_state(&self, state: &TradingState) {
+        if let Some(store) = &self.trading_store {
+            if let Err(e) = store.save(state) {
+                warn!(error = %e, "Failed to persist trading state");
+            }
+        }
     }
 
     /// Return the total exposure across all open positions.
@@ -237,6 +350,11 @@ impl AppState {
         self.positions.read().total_exposure()
     }
 
+    /// Return the exposure held in open positions for a single market.
+    pub fn market_exposure(&self, market_id: &MarketId) -> Price {
+        self.positions.read().market_exposure(market_id)
+    }
+
     /// Return the count of currently open positions.
     pub fn open_position_count(&self) -> usize {
         self.positions.read().open_count()
@@ -260,6 +378,31 @@ impl AppState {
         self.pending_executions.lock().len()
     }
 
+    /// Suppress executions on a market until `until`.
+    ///
+    /// An existing cooldown that ends later is kept.
+    pub fn start_market_cooldown(&self, market_id: &str, until: Instant) {
+        let mut cooldowns = self.market_cooldowns.lock();
+        let expiry = cooldowns.entry(market_id.to_string()).or_insert(until);
+        *expiry = (*expiry).max(until);
+    }
+
+    /// Return when the market's cooldown ends, if it is still cooling down
+    /// at `now`.
+    ///
+    /// Lapsed cooldowns are removed.
+    pub fn market_cooldown_until(&self, market_id: &str, now: Instant) -> Option<Instant> {
+        let mut cooldowns = self.market_cooldowns.lock();
+        match cooldowns.get(market_id) {
+            Some(&until) if until > now => Some(until),
+            Some(_) => {
+                cooldowns.remove(market_id);
+                None
+            }
+            None => None,
+        }
+    }
+
     /// Return the current pending (reserved but not yet committed) exposure.
     pub fn pending_exposure(&self) -> Price {
         *self.pending_exposure.lock()
@@ -309,20 +452,16 @@ impl RuntimeState for AppState {
             .map_err(|err| RuntimeRiskLimitUpdateError::new(err.reason()))
     }
 
-    fn is_circuit_breaker_active(&self) -> bool {
-        AppState::is_circuit_breaker_active(self)
+    fn trading_state(&self) -> TradingState {
+        AppState::trading_state(self)
     }
 
-    fn circuit_breaker_reason(&self) -> Option<String> {
-        AppState::circuit_breaker_reason(self)
+    fn halt(&self, reason: &str) {
+        AppState::halt(self, reason);
     }
 
-    fn activate_circuit_breaker(&self, reason: &str) {
-        AppState::activate_circuit_breaker(self, reason);
-    }
-
-    fn reset_circuit_breaker(&self) {
-        AppState::reset_circuit_breaker(self);
+    fn resume(&self) {
+        AppState::resume(self);
     }
 
     fn open_position_count(&self) -> usize {
@@ -357,6 +496,18 @@ impl RuntimeState for AppState {
             })
             .collect()
     }
+
+    fn recent_events(&self, limit: usize) -> Vec<RuntimeEvent> {
+        self.recent_events
+            .recent(limit)
+            .into_iter()
+            .map(|recent| RuntimeEvent {
+                at: recent.at,
+                kind: recent.event.kind(),
+                summary: recent.event.summary(),
+            })
+            .collect()
+    }
 }
 
 impl Default for AppState {
@@ -370,27 +521,96 @@ mod tests {
     use super::*;
     use rust_decimal_macros::dec;
 
+    /// In-memory trading state store shared across simulated restarts.
+    #[derive(Default)]
+    struct MemoryTradingStore {
+        saved: Mutex<Option<TradingState>>,
+    }
+
+    impl TradingStateStore for MemoryTradingStore {
+        fn load(&self) -> crate::error::Result<Option<TradingState>> {
+            Ok(self.saved.lock().clone())
+        }
+
+        fn save(&self, state: &TradingState) -> crate::error::Result<()> {
+            *self.saved.lock() = Some(state.clone());
+            Ok(())
+        }
+    }
+
     #[test]
     fn test_app_state_default() {
         let state = AppState::default();
-        assert!(!state.is_circuit_breaker_active());
-        assert!(state.circuit_breaker_reason().is_none());
+        assert!(state.is_trading_active());
+        assert_eq!(state.trading_state(), TradingState::Active);
     }
 
     #[test]
-    fn test_circuit_breaker() {
+    fn test_pause_and_resume() {
         let state = AppState::default();
 
-        state.activate_circuit_breaker("test reason");
-        assert!(state.is_circuit_breaker_active());
-        assert_eq!(
-            state.circuit_breaker_reason(),
-            Some("test reason".to_string())
-        );
+        state.pause("test reason");
+        assert!(!state.is_trading_active());
+        assert!(matches!(state.trading_state(), TradingState::Paused { .. }));
+        assert_eq!(state.trading_state().reason(), Some("test reason"));
 
-        state.reset_circuit_breaker();
-        assert!(!state.is_circuit_breaker_active());
-        assert!(state.circuit_breaker_reason().is_none());
+        state.resume();
+        assert!(state.is_trading_active());
+        assert!(state.trading_state().reason().is_none());
+    }
+
+    #[test]
+    fn test_pause_does_not_override_halt() {
+        let state = AppState::default();
+
+        state.halt("operator");
+        state.pause("kill-switch");
+
+        assert_eq!(state.trading_state(), TradingState::halted("operator"));
+    }
+
+    #[test]
+    fn test_resume_paused_for_matches_reason() {
+        let state = AppState::default();
+
+        state.pause("operator");
+        assert!(!state.resume_paused_for("kill-switch"));
+        assert_eq!(state.trading_state().reason(), Some("operator"));
+
+        assert!(state.resume_paused_for("operator"));
+        assert!(state.is_trading_active());
+
+        state.halt("operator");
+        assert!(!state.resume_paused_for("operator"));
+        assert!(state.trading_state().is_halted());
+    }
+
+    #[test]
+    fn test_halt_persists_across_restart() {
+        let store = Arc::new(MemoryTradingStore::default());
+
+        let before = AppState::default().with_trading_store(store.clone());
+        before.halt("manual halt");
+        drop(before);
+
+        let after = AppState::default().with_trading_store(store.clone());
+        assert_eq!(after.trading_state(), TradingState::halted("manual halt"));
+
+        after.resume();
+        let resumed = AppState::default().with_trading_store(store);
+        assert!(resumed.is_trading_active());
+    }
+
+    #[test]
+    fn test_pause_does_not_persist_across_restart() {
+        let store = Arc::new(MemoryTradingStore::default());
+
+        let before = AppState::default().with_trading_store(store.clone());
+        before.pause("kill-switch");
+        drop(before);
+
+        let after = AppState::default().with_trading_store(store);
+        assert!(after.is_trading_active());
     }
 
     #[test]
@@ -475,4 +695,20 @@ mod tests {
         // Now has exposure
         assert_eq!(state.total_exposure(), dec!(90));
     }
+
+    #[test]
+    fn recent_events_are_exposed_through_runtime_state() {
+        use crate::port::outbound::notifier::{Event, Notifier};
+
+        let state = AppState::default().with_recent_events(5);
+        state.recent_events().clone().notify(Event::TradingHalted {
+            reason: "manual".to_string(),
+        });
+
+        let events = RuntimeState::recent_events(&state, 10);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, "trading_halted");
+        assert_eq!(events[0].summary, "manual");
+    }
 }
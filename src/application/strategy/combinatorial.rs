@@ -26,23 +26,27 @@
 //! While combinatorial arbitrage captured only 0.24% ($95K) of historical profits,
 //! the mathematical infrastructure enables sophisticated cross-market strategies.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use parking_lot::RwLock;
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::application::cache::cluster::ClusterCache;
 use crate::application::cluster::detector::ClusterDetector;
 use crate::application::cluster::service::ClusterDetectionConfig;
+use crate::application::solver::backend::SolverConfig;
 use crate::application::solver::frank_wolfe::{FrankWolfe, FrankWolfeConfig};
-use crate::domain::{market::MarketRegistry, opportunity::Opportunity};
+use crate::domain::{id::ClusterId, market::MarketRegistry, opportunity::Opportunity};
 use crate::port::{
-    inbound::strategy::DetectionContext, inbound::strategy::MarketContext,
+    inbound::strategy::ClusterProjection, inbound::strategy::DetectionContext,
+    inbound::strategy::DetectionResult, inbound::strategy::MarketContext,
     inbound::strategy::Strategy, outbound::solver::ProjectionSolver,
 };
 
 /// Configuration for the combinatorial arbitrage strategy.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CombinatorialConfig {
     /// Maximum Frank-Wolfe iterations per detection cycle.
     #[serde(default = "default_max_iterations")]
@@ -58,6 +62,23 @@ pub struct CombinatorialConfig {
     #[serde(default = "default_gap_threshold")]
     pub gap_threshold: Decimal,
 
+    /// Maximum per-market price move for reusing the last projection.
+    /// When every price in a cluster is within this distance of the prices
+    /// behind its last converged projection, Frank-Wolfe starts from that
+    /// projection instead of the raw prices.
+    #[serde(default = "default_warm_start_threshold")]
+    pub warm_start_threshold: Decimal,
+
+    /// Minimum confidence required of every relation in a cluster.
+    /// Clusters with a relation below this (e.g. a low-confidence LLM
+    /// guess) are skipped during detection.
+    #[serde(default)]
+    pub min_relation_confidence: f64,
+
+    /// Oracle backend used by Frank-Wolfe.
+    #[serde(default)]
+    pub solver: SolverConfig,
+
     /// Whether this strategy is enabled.
     /// Disabled by default as it requires dependency configuration.
     #[serde(default = "default_enabled")]
@@ -76,6 +97,10 @@ fn default_gap_threshold() -> Decimal {
     Decimal::new(2, 2) // 0.02
 }
 
+fn default_warm_start_threshold() -> Decimal {
+    Decimal::new(1, 3) // 0.001
+}
+
 const fn default_enabled() -> bool {
     false // Disabled by default - requires dependency configuration
 }
@@ -86,6 +111,9 @@ impl Default for CombinatorialConfig {
             max_iterations: default_max_iterations(),
             tolerance: default_tolerance(),
             gap_threshold: default_gap_threshold(),
+            warm_start_threshold: default_warm_start_threshold(),
+            min_relation_confidence: 0.0,
+            solver: SolverConfig::default(),
             enabled: default_enabled(),
         }
     }
@@ -117,6 +145,8 @@ pub struct CombinatorialStrategy {
     detector: Option<ClusterDetector>,
     /// Projection solver implementation (injected from infrastructure).
     projection_solver: Option<Arc<dyn ProjectionSolver>>,
+    /// Last converged projection per cluster, used to warm-start detection.
+    projections: RwLock<HashMap<ClusterId, ClusterProjection>>,
 }
 
 impl CombinatorialStrategy {
@@ -137,6 +167,7 @@ impl CombinatorialStrategy {
             registry: None,
             detector: None,
             projection_solver: None,
+            projections: RwLock::new(HashMap::new()),
         }
     }
 
@@ -209,6 +240,18 @@ impl CombinatorialStrategy {
     pub const fn frank_wolfe(&self) -> &FrankWolfe {
         &self.fw
     }
+
+    /// Snapshot the stored projections as a warm-start hint.
+    ///
+    /// The result can be passed to [`Strategy::warm_start`] on another
+    /// instance to carry converged projections across restarts.
+    #[must_use]
+    pub fn detection_result(&self) -> DetectionResult {
+        DetectionResult {
+            projections: self.projections.read().clone(),
+            ..Default::default()
+        }
+    }
 }
 
 impl Strategy for CombinatorialStrategy {
@@ -264,6 +307,18 @@ impl Strategy for CombinatorialStrategy {
             None => return vec![], // No known relations
         };
 
+        if let Some(confidence) = cluster.min_confidence() {
+            if confidence < self.config.min_relation_confidence {
+                tracing::trace!(
+                    market_id = %ctx.market_id(),
+                    confidence,
+                    min_confidence = self.config.min_relation_confidence,
+                    "Skipping cluster with low-confidence relations"
+                );
+                return vec![];
+            }
+        }
+
         tracing::debug!(
             market_id = %ctx.market_id(),
             cluster_size = cluster.markets.len(),
@@ -274,8 +329,30 @@ impl Strategy for CombinatorialStrategy {
         // Create book lookup closure that uses the context
         let book_lookup = |token_id: &crate::domain::id::TokenId| ctx.order_book(token_id);
 
-        // Run cluster detection
-        match detector.detect(&cluster, &book_lookup, registry) {
+        // Run cluster detection, seeded from the last converged projection
+        let previous = self.projections.read().get(&cluster.id).cloned();
+        let detection = detector.detect_from(
+            &cluster,
+            &book_lookup,
+            registry,
+            previous.as_ref(),
+            self.config.warm_start_threshold,
+        );
+
+        let detection = detection.map(|detection| {
+            let mut projections = self.projections.write();
+            match detection.projection {
+                Some(projection) => {
+                    projections.insert(cluster.id.clone(), projection);
+                }
+                None => {
+                    projections.remove(&cluster.id);
+                }
+            }
+            detection.opportunity
+        });
+
+        match detection {
             Ok(Some(cluster_opp)) => {
                 tracing::info!(
                     market_id = %ctx.market_id(),
@@ -302,6 +379,15 @@ impl Strategy for CombinatorialStrategy {
         }
     }
 
+    fn warm_start(&mut self, previous: &DetectionResult) {
+        self.projections.get_mut().extend(
+            previous
+                .projections
+                .iter()
+                .map(|(id, projection)| (id.clone(), projection.clone())),
+        );
+    }
+
     fn set_market_registry(&mut self, registry: Arc<MarketRegistry>) {
         self.set_registry(registry);
     }
@@ -315,7 +401,7 @@ mod tests {
     use crate::domain::constraint::Constraint;
     use crate::domain::{
         book::PriceLevel, cluster::Cluster, id::ClusterId, id::MarketId, id::TokenId,
-        market::Market, market::Outcome,
+        market::Market, market::Outcome, relation::Relation, relation::RelationKind,
     };
     use chrono::{Duration, Utc};
     use rust_decimal_macros::dec;
@@ -326,6 +412,9 @@ mod tests {
             max_iterations: 20,
             tolerance: dec!(0.0001),
             gap_threshold: dec!(0.02),
+            warm_start_threshold: dec!(0.001),
+            min_relation_confidence: 0.0,
+            solver: SolverConfig::default(),
         }
     }
 
@@ -583,4 +672,232 @@ mod tests {
         // Should apply because cache has relations
         assert!(strategy.applies_to(&ctx));
     }
+
+    /// Projection solver that records whether each run was warm-started.
+    struct RecordingProjectionSolver {
+        warm_starts: parking_lot::Mutex<Vec<bool>>,
+    }
+
+    impl ProjectionSolver for RecordingProjectionSolver {
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        fn project(
+            &self,
+            theta: &[Decimal],
+            problem: &crate::port::outbound::solver::IlpProblem,
+        ) -> crate::error::Result<crate::port::outbound::solver::ProjectionResult> {
+            self.project_from(theta, None, problem)
+        }
+
+        fn project_from(
+            &self,
+            theta: &[Decimal],
+            initial: Option<&[Decimal]>,
+            _problem: &crate::port::outbound::solver::IlpProblem,
+        ) -> crate::error::Result<crate::port::outbound::solver::ProjectionResult> {
+            self.warm_starts.lock().push(initial.is_some());
+            Ok(crate::port::outbound::solver::ProjectionResult {
+                values: initial.map_or_else(|| theta.to_vec(), <[Decimal]>::to_vec),
+                gap: Decimal::ZERO,
+                iterations: if initial.is_some() { 1 } else { 10 },
+                converged: true,
+            })
+        }
+    }
+
+    fn set_ask(cache: &BookCache, token: &str, price: Decimal) {
+        cache.update(crate::domain::book::Book::with_levels(
+            TokenId::from(token),
+            vec![],
+            vec![PriceLevel::new(price, dec!(100))],
+        ));
+    }
+
+    fn make_warm_start_strategy() -> (
+        CombinatorialStrategy,
+        Arc<RecordingProjectionSolver>,
+        Market,
+        ClusterId,
+    ) {
+        let mut strategy = CombinatorialStrategy::new(make_test_config());
+
+        let m1 = make_binary_market("m1", "yes1", "no1");
+        let m2 = make_binary_market("m2", "yes2", "no2");
+        let mut registry = MarketRegistry::new();
+        registry.add(m1.clone());
+        registry.add(m2.clone());
+
+        let cluster = make_cluster(vec![m1.market_id().clone(), m2.market_id().clone()]);
+        let cluster_id = cluster.id.clone();
+        let cluster_cache = Arc::new(ClusterCache::new(Duration::hours(1)));
+        cluster_cache.put(cluster);
+
+        let solver = Arc::new(RecordingProjectionSolver {
+            warm_starts: parking_lot::Mutex::new(vec![]),
+        });
+        strategy.set_cache(cluster_cache);
+        strategy.set_registry(Arc::new(registry));
+        strategy.set_projection_solver(Arc::clone(&solver) as Arc<dyn ProjectionSolver>);
+
+        (strategy, solver, m1, cluster_id)
+    }
+
+    #[test]
+    fn test_detect_warm_starts_stable_cluster() {
+        let (strategy, solver, m1, cluster_id) = make_warm_start_strategy();
+        let cache = BookCache::new();
+        set_ask(&cache, "yes1", dec!(0.50));
+        set_ask(&cache, "yes2", dec!(0.50));
+        let ctx = ConcreteDetectionContext::new(&m1, &cache);
+
+        strategy.detect(&ctx);
+        strategy.detect(&ctx);
+
+        assert_eq!(*solver.warm_starts.lock(), vec![false, true]);
+        assert!(strategy
+            .detection_result()
+            .projections
+            .contains_key(&cluster_id));
+    }
+
+    #[test]
+    fn test_detect_uses_replaced_registry() {
+        let (mut strategy, solver, m1, cluster_id) = make_warm_start_strategy();
+        let mut registry = MarketRegistry::new();
+        registry.add(m1.clone());
+        strategy.set_registry(Arc::new(registry));
+        let cache = BookCache::new();
+        set_ask(&cache, "yes1", dec!(0.50));
+        set_ask(&cache, "yes2", dec!(0.50));
+
+        // m2 is not registered yet, so the cluster cannot be priced
+        strategy.detect(&ConcreteDetectionContext::new(&m1, &cache));
+        assert!(solver.warm_starts.lock().is_empty());
+
+        // A re-scan adds m2 and hands the new registry to the strategy
+        let mut rescanned = MarketRegistry::new();
+        rescanned.add(m1.clone());
+        rescanned.add(make_binary_market("m2", "yes2", "no2"));
+        strategy.set_market_registry(Arc::new(rescanned));
+        strategy.detect(&ConcreteDetectionContext::new(&m1, &cache));
+
+        assert_eq!(*solver.warm_starts.lock(), vec![false]);
+        assert!(strategy
+            .detection_result()
+            .projections
+            .contains_key(&cluster_id));
+    }
+
+    #[test]
+    fn test_detect_starts_cold_after_price_move() {
+        let (strategy, solver, m1, _) = make_warm_start_strategy();
+        let cache = BookCache::new();
+        set_ask(&cache, "yes1", dec!(0.50));
+        set_ask(&cache, "yes2", dec!(0.50));
+
+        strategy.detect(&ConcreteDetectionContext::new(&m1, &cache));
+        set_ask(&cache, "yes1", dec!(0.55));
+        strategy.detect(&ConcreteDetectionContext::new(&m1, &cache));
+
+        assert_eq!(*solver.warm_starts.lock(), vec![false, false]);
+    }
+
+    #[test]
+    fn test_warm_start_seeds_projection_from_previous_result() {
+        let (mut strategy, solver, m1, cluster_id) = make_warm_start_strategy();
+
+        let mut previous = DetectionResult::default();
+        previous.projections.insert(
+            cluster_id,
+            ClusterProjection {
+                prices: vec![dec!(0.50), dec!(0.50)],
+                mu: vec![dec!(0.50), dec!(0.50)],
+            },
+        );
+        strategy.warm_start(&previous);
+
+        let cache = BookCache::new();
+        set_ask(&cache, "yes1", dec!(0.50));
+        set_ask(&cache, "yes2", dec!(0.50));
+        strategy.detect(&ConcreteDetectionContext::new(&m1, &cache));
+
+        assert_eq!(*solver.warm_starts.lock(), vec![true]);
+    }
+
+    /// Strategy over a two-market cluster whose only relation has 0.6 confidence.
+    fn make_low_confidence_strategy(
+        min_relation_confidence: f64,
+    ) -> (
+        CombinatorialStrategy,
+        Arc<RecordingProjectionSolver>,
+        Market,
+    ) {
+        let mut strategy = CombinatorialStrategy::new(CombinatorialConfig {
+            min_relation_confidence,
+            ..make_test_config()
+        });
+
+        let m1 = make_binary_market("m1", "yes1", "no1");
+        let m2 = make_binary_market("m2", "yes2", "no2");
+        let mut registry = MarketRegistry::new();
+        registry.add(m1.clone());
+        registry.add(m2.clone());
+
+        let cluster_cache = Arc::new(ClusterCache::new(Duration::hours(1)));
+        cluster_cache.put_relations(vec![Relation::new(
+            RelationKind::MutuallyExclusive {
+                markets: vec![m1.market_id().clone(), m2.market_id().clone()],
+            },
+            0.6,
+            "guess",
+        )]);
+
+        let solver = Arc::new(RecordingProjectionSolver {
+            warm_starts: parking_lot::Mutex::new(vec![]),
+        });
+        strategy.set_cache(cluster_cache);
+        strategy.set_registry(Arc::new(registry));
+        strategy.set_projection_solver(Arc::clone(&solver) as Arc<dyn ProjectionSolver>);
+
+        (strategy, solver, m1)
+    }
+
+    #[test]
+    fn test_detect_skips_cluster_below_min_relation_confidence() {
+        let (strategy, solver, m1) = make_low_confidence_strategy(0.9);
+        let cache = BookCache::new();
+        set_ask(&cache, "yes1", dec!(0.60));
+        set_ask(&cache, "yes2", dec!(0.60));
+
+        let opps = strategy.detect(&ConcreteDetectionContext::new(&m1, &cache));
+
+        assert!(opps.is_empty());
+        assert!(solver.warm_starts.lock().is_empty());
+    }
+
+    #[test]
+    fn test_detect_runs_cluster_at_or_above_min_relation_confidence() {
+        let (strategy, solver, m1) = make_low_confidence_strategy(0.6);
+        let cache = BookCache::new();
+        set_ask(&cache, "yes1", dec!(0.60));
+        set_ask(&cache, "yes2", dec!(0.60));
+
+        strategy.detect(&ConcreteDetectionContext::new(&m1, &cache));
+
+        assert_eq!(solver.warm_starts.lock().len(), 1);
+    }
+
+    #[test]
+    fn test_cluster_projection_is_close_to() {
+        let projection = ClusterProjection {
+            prices: vec![dec!(0.40), dec!(0.60)],
+            mu: vec![dec!(0.40), dec!(0.60)],
+        };
+
+        assert!(projection.is_close_to(&[dec!(0.4005), dec!(0.60)], dec!(0.001)));
+        assert!(!projection.is_close_to(&[dec!(0.42), dec!(0.60)], dec!(0.001)));
+        assert!(!projection.is_close_to(&[dec!(0.40)], dec!(0.001)));
+    }
 }
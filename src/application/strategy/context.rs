@@ -1,7 +1,10 @@
 //! Context types for strategy detection.
 //!
 //! Provides concrete implementations of the [`DetectionContext`](DetectionContextTrait)
-//! trait that wrap market metadata and order book cache.
+//! trait that wrap market metadata and order book cache, and of
+//! [`MultiExchangeContext`] over one book cache per exchange.
+
+use std::time::Duration;
 
 use rust_decimal::Decimal;
 
@@ -9,6 +12,7 @@ use crate::application::cache::book::BookCache;
 use crate::domain::{book::Book, id::MarketId, id::TokenId, market::Market};
 use crate::port::{
     inbound::strategy::DetectionContext as DetectionContextTrait, inbound::strategy::MarketContext,
+    inbound::strategy::MultiExchangeContext,
 };
 
 /// Concrete detection context wrapping market metadata and order book cache.
@@ -94,11 +98,63 @@ impl<'a> DetectionContextTrait for ConcreteDetectionContext<'a> {
         self.cache.get(token_id)
     }
 
+    fn book_age(&self, token_id: &TokenId) -> Option<Duration> {
+        self.cache.age(token_id)
+    }
+
     fn market(&self) -> &Market {
         self.market
     }
 }
 
+/// Multi-exchange context over one order book cache per exchange.
+///
+/// Exchanges are looked up by name; an exchange that was never added has no
+/// books, so strategies fail closed for it.
+#[derive(Default)]
+pub struct ConcreteMultiExchangeContext<'a> {
+    /// Book caches keyed by exchange name, in insertion order.
+    caches: Vec<(&'a str, &'a BookCache)>,
+}
+
+impl<'a> ConcreteMultiExchangeContext<'a> {
+    /// Create a context with no exchanges.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an exchange's book cache, replacing any cache already registered
+    /// under the same name.
+    #[must_use]
+    pub fn with_exchange(mut self, exchange: &'a str, cache: &'a BookCache) -> Self {
+        self.caches.retain(|(name, _)| *name != exchange);
+        self.caches.push((exchange, cache));
+        self
+    }
+
+    fn cache(&self, exchange: &str) -> Option<&'a BookCache> {
+        self.caches
+            .iter()
+            .find(|(name, _)| *name == exchange)
+            .map(|(_, cache)| *cache)
+    }
+}
+
+impl<'a> MultiExchangeContext for ConcreteMultiExchangeContext<'a> {
+    fn exchanges(&self) -> Vec<&str> {
+        self.caches.iter().map(|(name, _)| *name).collect()
+    }
+
+    fn order_book(&self, exchange: &str, token_id: &TokenId) -> Option<Book> {
+        self.cache(exchange)?.get(token_id)
+    }
+
+    fn book_age(&self, exchange: &str, token_id: &TokenId) -> Option<Duration> {
+        self.cache(exchange)?.age(token_id)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,4 +254,37 @@ mod tests {
         assert!(ctx.market_context().is_multi_outcome());
         assert_eq!(ctx.token_ids().len(), 3);
     }
+
+    #[test]
+    fn test_multi_exchange_context_reads_each_exchange_cache() {
+        use crate::domain::book::PriceLevel;
+
+        let token = TokenId::from("shared-token");
+        let polymarket = BookCache::new();
+        let kalshi = BookCache::new();
+        polymarket.update(Book::with_levels(
+            token.clone(),
+            vec![],
+            vec![PriceLevel::new(dec!(0.40), dec!(10))],
+        ));
+        kalshi.update(Book::with_levels(
+            token.clone(),
+            vec![],
+            vec![PriceLevel::new(dec!(0.55), dec!(20))],
+        ));
+
+        let ctx = ConcreteMultiExchangeContext::new()
+            .with_exchange("polymarket", &polymarket)
+            .with_exchange("kalshi", &kalshi);
+
+        assert_eq!(ctx.exchanges(), vec!["polymarket", "kalshi"]);
+        let quote = ctx.quote("kalshi", &token).unwrap();
+        assert_eq!(quote.source(), "kalshi");
+        assert_eq!(quote.ask(), dec!(0.55));
+        assert_eq!(
+            ctx.quote("polymarket", &token).map(|q| q.ask()),
+            Some(dec!(0.40))
+        );
+        assert!(ctx.order_book("manifold", &token).is_none());
+    }
 }
@@ -0,0 +1,388 @@
+//! Cross-exchange arbitrage strategy.
+//!
+//! Detects when the same real-world event is priced differently on two
+//! exchanges. Buying YES on one exchange and NO on the other pays out exactly
+//! once whatever the outcome, so if the two asks plus fees cost less than the
+//! payout the pair locks in a profit.
+//!
+//! Markets are paired manually in configuration; each pair names the YES and
+//! NO tokens of the equivalent market on each exchange.
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::fee::FeeModel;
+use crate::domain::id::{MarketId, TokenId};
+use crate::domain::opportunity::{Opportunity, OpportunityLeg};
+use crate::port::inbound::strategy::{MultiExchangeContext, MultiExchangeStrategy};
+
+/// One exchange's side of a market pair.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ExchangeMarket {
+    /// Exchange the market trades on (e.g., "polymarket", "kalshi").
+    pub exchange: String,
+    /// Token paying out if the event happens.
+    pub yes_token: TokenId,
+    /// Token paying out if the event does not happen.
+    pub no_token: TokenId,
+}
+
+/// Two markets on different exchanges that resolve on the same event.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MarketPair {
+    /// Identifier for the pair, used as the opportunity's market ID.
+    pub name: String,
+    /// Human-readable description of the shared event.
+    #[serde(default)]
+    pub question: String,
+    /// Market on the first exchange.
+    pub first: ExchangeMarket,
+    /// Equivalent market on the second exchange.
+    pub second: ExchangeMarket,
+}
+
+/// Configuration for cross-exchange arbitrage detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CrossExchangeConfig {
+    /// Minimum edge per share, net of fees, required to report an opportunity.
+    #[serde(default = "default_min_edge")]
+    pub min_edge: Decimal,
+
+    /// Minimum expected profit in dollars required to report an opportunity.
+    #[serde(default = "default_min_profit")]
+    pub min_profit: Decimal,
+
+    /// Execution costs per exchange, keyed by exchange name.
+    ///
+    /// Each leg is charged its own exchange's flat and per-leg cost.
+    /// Exchanges without an entry are free.
+    #[serde(default)]
+    pub fees: HashMap<String, FeeModel>,
+
+    /// Manually mapped market pairs to compare.
+    #[serde(default)]
+    pub pairs: Vec<MarketPair>,
+}
+
+fn default_min_edge() -> Decimal {
+    Decimal::new(3, 2) // 0.03
+}
+
+fn default_min_profit() -> Decimal {
+    Decimal::new(10, 2) // 0.10
+}
+
+impl Default for CrossExchangeConfig {
+    fn default() -> Self {
+        Self {
+            min_edge: default_min_edge(),
+            min_profit: default_min_profit(),
+            fees: HashMap::new(),
+            pairs: Vec::new(),
+        }
+    }
+}
+
+impl CrossExchangeConfig {
+    /// Return the cost of executing one leg on `exchange`.
+    #[must_use]
+    pub fn leg_cost(&self, exchange: &str) -> Decimal {
+        self.fees
+            .get(exchange)
+            .map_or(Decimal::ZERO, |fees| fees.cost(1))
+    }
+}
+
+/// Cross-exchange arbitrage detector over configured market pairs.
+///
+/// For each pair, checks both hedges (YES on the first exchange with NO on
+/// the second, and the reverse) and reports the better one if it clears the
+/// thresholds. Both markets are assumed to pay $1 per winning share.
+pub struct CrossExchangeStrategy {
+    /// Strategy configuration.
+    config: CrossExchangeConfig,
+}
+
+impl CrossExchangeStrategy {
+    /// Create a new strategy with the given configuration.
+    #[must_use]
+    pub const fn new(config: CrossExchangeConfig) -> Self {
+        Self { config }
+    }
+
+    /// Return the current configuration.
+    #[must_use]
+    pub const fn config(&self) -> &CrossExchangeConfig {
+        &self.config
+    }
+}
+
+impl MultiExchangeStrategy for CrossExchangeStrategy {
+    fn name(&self) -> &'static str {
+        "cross_exchange"
+    }
+
+    fn detect(&self, ctx: &dyn MultiExchangeContext) -> Vec<Opportunity> {
+        self.config
+            .pairs
+            .iter()
+            .filter_map(|pair| detect_cross_exchange(ctx, pair, &self.config))
+            .collect()
+    }
+}
+
+/// Core detection logic for one market pair.
+///
+/// Prices both hedges and returns the one with the larger net edge.
+///
+/// Returns `None` if:
+/// - Any required order book is missing or has no asks
+/// - Neither hedge costs less than the payout after fees
+/// - The best edge or expected profit is below the configured minimum
+pub fn detect_cross_exchange(
+    ctx: &dyn MultiExchangeContext,
+    pair: &MarketPair,
+    config: &CrossExchangeConfig,
+) -> Option<Opportunity> {
+    let hedges = [
+        price_hedge(ctx, pair, config, &pair.first, &pair.second),
+        price_hedge(ctx, pair, config, &pair.second, &pair.first),
+    ];
+
+    hedges
+        .into_iter()
+        .flatten()
+        .filter(|opp| opp.edge() >= config.min_edge)
+        .filter(|opp| opp.expected_profit() >= config.min_profit)
+        .max_by(|a, b| a.edge().cmp(&b.edge()))
+}
+
+/// Price buying YES on `yes_side` and NO on `no_side`.
+fn price_hedge(
+    ctx: &dyn MultiExchangeContext,
+    pair: &MarketPair,
+    config: &CrossExchangeConfig,
+    yes_side: &ExchangeMarket,
+    no_side: &ExchangeMarket,
+) -> Option<Opportunity> {
+    let yes_quote = ctx.quote(&yes_side.exchange, &yes_side.yes_token)?;
+    let no_quote = ctx.quote(&no_side.exchange, &no_side.no_token)?;
+
+    let payout = Decimal::ONE;
+    if yes_quote.ask() + no_quote.ask() >= payout {
+        return None;
+    }
+
+    let fees = config.leg_cost(&yes_side.exchange) + config.leg_cost(&no_side.exchange);
+    let volume = yes_quote.size().min(no_quote.size());
+    let legs = vec![
+        OpportunityLeg::new(yes_side.yes_token.clone(), yes_quote.ask())
+            .with_source(yes_quote.source()),
+        OpportunityLeg::new(no_side.no_token.clone(), no_quote.ask())
+            .with_source(no_quote.source()),
+    ];
+    let question = if pair.question.is_empty() {
+        pair.name.as_str()
+    } else {
+        pair.question.as_str()
+    };
+
+    Some(
+        Opportunity::with_strategy(
+            MarketId::from(pair.name.clone()),
+            question,
+            legs,
+            volume,
+            payout,
+            "cross_exchange",
+        )
+        .with_fees(fees)
+        .with_min_edge(config.min_edge),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::cache::book::BookCache;
+    use crate::application::strategy::context::ConcreteMultiExchangeContext;
+    use crate::domain::book::{Book, PriceLevel};
+    use rust_decimal_macros::dec;
+
+    fn make_pair() -> MarketPair {
+        MarketPair {
+            name: "fed-march-cut".to_string(),
+            question: "Will the Fed cut rates in March?".to_string(),
+            first: ExchangeMarket {
+                exchange: "polymarket".to_string(),
+                yes_token: TokenId::from("pm-yes"),
+                no_token: TokenId::from("pm-no"),
+            },
+            second: ExchangeMarket {
+                exchange: "kalshi".to_string(),
+                yes_token: TokenId::from("ks-yes"),
+                no_token: TokenId::from("ks-no"),
+            },
+        }
+    }
+
+    fn make_config() -> CrossExchangeConfig {
+        CrossExchangeConfig {
+            min_edge: dec!(0.02),
+            min_profit: dec!(0.50),
+            fees: HashMap::new(),
+            pairs: vec![make_pair()],
+        }
+    }
+
+    fn ask(cache: &BookCache, token: &str, price: Decimal, size: Decimal) {
+        cache.update(Book::with_levels(
+            TokenId::from(token),
+            vec![],
+            vec![PriceLevel::new(price, size)],
+        ));
+    }
+
+    fn quote_both(polymarket: &BookCache, kalshi: &BookCache) {
+        // Polymarket prices YES cheaply, Kalshi prices NO cheaply
+        ask(polymarket, "pm-yes", dec!(0.40), dec!(100));
+        ask(polymarket, "pm-no", dec!(0.62), dec!(100));
+        ask(kalshi, "ks-yes", dec!(0.45), dec!(100));
+        ask(kalshi, "ks-no", dec!(0.52), dec!(50));
+    }
+
+    #[test]
+    fn detects_yes_on_one_exchange_and_no_on_the_other() {
+        let polymarket = BookCache::new();
+        let kalshi = BookCache::new();
+        quote_both(&polymarket, &kalshi);
+        let ctx = ConcreteMultiExchangeContext::new()
+            .with_exchange("polymarket", &polymarket)
+            .with_exchange("kalshi", &kalshi);
+
+        let opps = CrossExchangeStrategy::new(make_config()).detect(&ctx);
+
+        assert_eq!(opps.len(), 1);
+        let opp = &opps[0];
+        assert_eq!(opp.strategy(), "cross_exchange");
+        assert_eq!(opp.market_id().as_str(), "fed-march-cut");
+        assert_eq!(opp.edge(), dec!(0.08));
+        assert_eq!(opp.volume(), dec!(50));
+        assert_eq!(opp.legs()[0].token_id().as_str(), "pm-yes");
+        assert_eq!(opp.legs()[0].source(), "polymarket");
+        assert_eq!(opp.legs()[1].token_id().as_str(), "ks-no");
+        assert_eq!(opp.legs()[1].source(), "kalshi");
+    }
+
+    #[test]
+    fn picks_reverse_hedge_when_cheaper() {
+        let polymarket = BookCache::new();
+        let kalshi = BookCache::new();
+        ask(&polymarket, "pm-yes", dec!(0.60), dec!(100));
+        ask(&polymarket, "pm-no", dec!(0.35), dec!(100));
+        ask(&kalshi, "ks-yes", dec!(0.55), dec!(100));
+        ask(&kalshi, "ks-no", dec!(0.45), dec!(100));
+        let ctx = ConcreteMultiExchangeContext::new()
+            .with_exchange("polymarket", &polymarket)
+            .with_exchange("kalshi", &kalshi);
+
+        let opps = CrossExchangeStrategy::new(make_config()).detect(&ctx);
+
+        assert_eq!(opps.len(), 1);
+        assert_eq!(opps[0].legs()[0].token_id().as_str(), "ks-yes");
+        assert_eq!(opps[0].legs()[1].token_id().as_str(), "pm-no");
+        assert_eq!(opps[0].edge(), dec!(0.10));
+    }
+
+    #[test]
+    fn fees_are_charged_per_exchange() {
+        let polymarket = BookCache::new();
+        let kalshi = BookCache::new();
+        quote_both(&polymarket, &kalshi);
+        let ctx = ConcreteMultiExchangeContext::new()
+            .with_exchange("polymarket", &polymarket)
+            .with_exchange("kalshi", &kalshi);
+
+        let mut config = make_config();
+        config.fees.insert(
+            "polymarket".to_string(),
+            FeeModel::new(dec!(0.01), Decimal::ZERO),
+        );
+        config.fees.insert(
+            "kalshi".to_string(),
+            FeeModel::new(Decimal::ZERO, dec!(0.02)),
+        );
+
+        let opps = CrossExchangeStrategy::new(config.clone()).detect(&ctx);
+        assert_eq!(opps.len(), 1);
+        assert_eq!(opps[0].fees(), dec!(0.03));
+        assert_eq!(opps[0].edge(), dec!(0.05));
+
+        config.min_edge = dec!(0.06);
+        assert!(CrossExchangeStrategy::new(config).detect(&ctx).is_empty());
+    }
+
+    #[test]
+    fn no_opportunity_when_prices_agree() {
+        let polymarket = BookCache::new();
+        let kalshi = BookCache::new();
+        ask(&polymarket, "pm-yes", dec!(0.50), dec!(100));
+        ask(&polymarket, "pm-no", dec!(0.51), dec!(100));
+        ask(&kalshi, "ks-yes", dec!(0.50), dec!(100));
+        ask(&kalshi, "ks-no", dec!(0.51), dec!(100));
+        let ctx = ConcreteMultiExchangeContext::new()
+            .with_exchange("polymarket", &polymarket)
+            .with_exchange("kalshi", &kalshi);
+
+        assert!(CrossExchangeStrategy::new(make_config())
+            .detect(&ctx)
+            .is_empty());
+    }
+
+    #[test]
+    fn fails_closed_when_an_exchange_is_missing() {
+        let polymarket = BookCache::new();
+        let kalshi = BookCache::new();
+        quote_both(&polymarket, &kalshi);
+        let ctx = ConcreteMultiExchangeContext::new().with_exchange("polymarket", &polymarket);
+
+        assert!(CrossExchangeStrategy::new(make_config())
+            .detect(&ctx)
+            .is_empty());
+    }
+
+    #[test]
+    fn deserializes_pairs_and_fees() {
+        let config: CrossExchangeConfig = toml::from_str(
+            r#"
+min_edge = 0.04
+
+[fees.polymarket]
+flat_cost = 0.005
+
+[[pairs]]
+name = "fed-march-cut"
+
+[pairs.first]
+exchange = "polymarket"
+yes_token = "pm-yes"
+no_token = "pm-no"
+
+[pairs.second]
+exchange = "kalshi"
+yes_token = "ks-yes"
+no_token = "ks-no"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.min_edge, dec!(0.04));
+        assert_eq!(config.min_profit, default_min_profit());
+        assert_eq!(config.leg_cost("polymarket"), dec!(0.005));
+        assert_eq!(config.leg_cost("kalshi"), Decimal::ZERO);
+        assert_eq!(config.pairs.len(), 1);
+        assert_eq!(config.pairs[0].second.no_token, TokenId::from("ks-no"));
+    }
+}
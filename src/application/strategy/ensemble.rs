@@ -0,0 +1,323 @@
+//! Consensus ensemble over several strategies.
+//!
+//! Each child strategy votes on a market by detecting an opportunity in it.
+//! The [`EnsemblePolicy`] decides how many votes are needed before any
+//! opportunity is acted upon, so a price-based strategy and the
+//! combinatorial check can be required to agree before trading.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{id::MarketId, id::TokenId, market::MarketRegistry, opportunity::Opportunity};
+use crate::port::{
+    inbound::strategy::DetectionContext, inbound::strategy::DetectionResult,
+    inbound::strategy::MarketContext, inbound::strategy::Strategy,
+};
+
+/// How many child strategies must detect an opportunity for the ensemble to
+/// emit one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EnsemblePolicy {
+    /// At least one child.
+    #[default]
+    Any,
+    /// Every child.
+    All,
+    /// More than half of the children.
+    Majority,
+}
+
+impl EnsemblePolicy {
+    /// Whether `votes` out of `voters` children reach agreement.
+    #[must_use]
+    pub const fn agrees(self, votes: usize, voters: usize) -> bool {
+        if votes == 0 {
+            return false;
+        }
+        match self {
+            Self::Any => true,
+            Self::All => votes == voters,
+            Self::Majority => votes * 2 > voters,
+        }
+    }
+}
+
+/// Strategy that only emits opportunities its children agree on.
+///
+/// Every child is a voter, including children that do not apply to the
+/// market, so under [`EnsemblePolicy::All`] a market only one child can
+/// analyze is never traded. When the children agree, their opportunities
+/// are combined; if several children found the same trade (same market and
+/// legs), the one with the smallest edge is kept so agreement never
+/// overstates the mispricing.
+pub struct EnsembleStrategy {
+    /// Child strategies in evaluation order.
+    children: Vec<Box<dyn Strategy>>,
+    /// Votes required for agreement.
+    policy: EnsemblePolicy,
+}
+
+impl EnsembleStrategy {
+    /// Create an ensemble over `children` using `policy`.
+    #[must_use]
+    pub fn new(children: Vec<Box<dyn Strategy>>, policy: EnsemblePolicy) -> Self {
+        Self { children, policy }
+    }
+
+    /// Return the aggregation policy.
+    #[must_use]
+    pub const fn policy(&self) -> EnsemblePolicy {
+        self.policy
+    }
+
+    /// Return the names of the child strategies in evaluation order.
+    #[must_use]
+    pub fn child_names(&self) -> Vec<&'static str> {
+        self.children.iter().map(|child| child.name()).collect()
+    }
+}
+
+impl Strategy for EnsembleStrategy {
+    fn name(&self) -> &'static str {
+        "ensemble"
+    }
+
+    fn applies_to(&self, ctx: &MarketContext) -> bool {
+        self.children.iter().any(|child| child.applies_to(ctx))
+    }
+
+    fn detect(&self, ctx: &dyn DetectionContext) -> Vec<Opportunity> {
+        let market_ctx = ctx.market_context();
+        let detected: Vec<Vec<Opportunity>> = self
+            .children
+            .iter()
+            .filter(|child| child.applies_to(&market_ctx))
+            .map(|child| child.detect(ctx))
+            .filter(|found| !found.is_empty())
+            .collect();
+
+        if !self.policy.agrees(detected.len(), self.children.len()) {
+            return Vec::new();
+        }
+        combine(detected.into_iter().flatten())
+    }
+
+    fn warm_start(&mut self, previous: &DetectionResult) {
+        for child in &mut self.children {
+            child.warm_start(previous);
+        }
+    }
+
+    fn set_market_registry(&mut self, registry: Arc<MarketRegistry>) {
+        for child in &mut self.children {
+            child.set_market_registry(Arc::clone(&registry));
+        }
+    }
+}
+
+/// Merge opportunities, keeping the smallest edge for each distinct trade.
+fn combine(opportunities: impl Iterator<Item = Opportunity>) -> Vec<Opportunity> {
+    let mut combined: Vec<Opportunity> = Vec::new();
+    let mut seen: HashMap<(MarketId, Vec<TokenId>), usize> = HashMap::new();
+
+    for opportunity in opportunities {
+        let mut tokens: Vec<TokenId> = opportunity
+            .legs()
+            .iter()
+            .map(|leg| leg.token_id().clone())
+            .collect();
+        tokens.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        let key = (opportunity.market_id().clone(), tokens);
+
+        match seen.get(&key) {
+            Some(&index) => {
+                if opportunity.edge() < combined[index].edge() {
+                    combined[index] = opportunity;
+                }
+            }
+            None => {
+                seen.insert(key, combined.len());
+                combined.push(opportunity);
+            }
+        }
+    }
+
+    combined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::cache::book::BookCache;
+    use crate::application::strategy::context::ConcreteDetectionContext;
+    use crate::domain::{market::Market, market::Outcome, opportunity::OpportunityLeg};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    /// Child that detects a fixed opportunity, or nothing.
+    struct MockStrategy {
+        name: &'static str,
+        applies: bool,
+        found: Option<Opportunity>,
+    }
+
+    impl Strategy for MockStrategy {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn applies_to(&self, _ctx: &MarketContext) -> bool {
+            self.applies
+        }
+
+        fn detect(&self, _ctx: &dyn DetectionContext) -> Vec<Opportunity> {
+            self.found.clone().into_iter().collect()
+        }
+    }
+
+    fn market() -> Market {
+        Market::new(
+            MarketId::from("m1"),
+            "Will it happen?",
+            vec![
+                Outcome::new(TokenId::from("yes"), "Yes"),
+                Outcome::new(TokenId::from("no"), "No"),
+            ],
+            dec!(1),
+        )
+    }
+
+    fn opportunity(strategy: &str, yes: Decimal) -> Opportunity {
+        Opportunity::new(
+            MarketId::from("m1"),
+            "Will it happen?",
+            vec![
+                OpportunityLeg::new(TokenId::from("yes"), yes),
+                OpportunityLeg::new(TokenId::from("no"), dec!(0.50)),
+            ],
+            dec!(100),
+            dec!(1),
+        )
+        .with_strategy_name(strategy)
+    }
+
+    fn child(name: &'static str, found: Option<Decimal>) -> Box<dyn Strategy> {
+        Box::new(MockStrategy {
+            name,
+            applies: true,
+            found: found.map(|yes| opportunity(name, yes)),
+        })
+    }
+
+    fn detect(ensemble: &EnsembleStrategy) -> Vec<Opportunity> {
+        let market = market();
+        let cache = BookCache::new();
+        ensemble.detect(&ConcreteDetectionContext::new(&market, &cache))
+    }
+
+    #[test]
+    fn any_emits_when_one_child_detects() {
+        let ensemble = EnsembleStrategy::new(
+            vec![child("price", Some(dec!(0.40))), child("combo", None)],
+            EnsemblePolicy::Any,
+        );
+
+        let found = detect(&ensemble);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].strategy(), "price");
+    }
+
+    #[test]
+    fn any_emits_nothing_without_detections() {
+        let ensemble = EnsembleStrategy::new(
+            vec![child("price", None), child("combo", None)],
+            EnsemblePolicy::Any,
+        );
+        assert!(detect(&ensemble).is_empty());
+    }
+
+    #[test]
+    fn all_requires_every_child() {
+        let disagree = EnsembleStrategy::new(
+            vec![child("price", Some(dec!(0.40))), child("combo", None)],
+            EnsemblePolicy::All,
+        );
+        assert!(detect(&disagree).is_empty());
+
+        let agree = EnsembleStrategy::new(
+            vec![
+                child("price", Some(dec!(0.40))),
+                child("combo", Some(dec!(0.45))),
+            ],
+            EnsemblePolicy::All,
+        );
+        let found = detect(&agree);
+
+        // Both found the same trade; the smaller edge is kept.
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].strategy(), "combo");
+        assert_eq!(found[0].edge(), dec!(0.05));
+    }
+
+    #[test]
+    fn all_counts_inapplicable_children_as_disagreeing() {
+        let ensemble = EnsembleStrategy::new(
+            vec![
+                child("price", Some(dec!(0.40))),
+                Box::new(MockStrategy {
+                    name: "combo",
+                    applies: false,
+                    found: Some(opportunity("combo", dec!(0.40))),
+                }),
+            ],
+            EnsemblePolicy::All,
+        );
+        assert!(detect(&ensemble).is_empty());
+    }
+
+    #[test]
+    fn majority_requires_more_than_half() {
+        let split = EnsembleStrategy::new(
+            vec![child("a", Some(dec!(0.40))), child("b", None)],
+            EnsemblePolicy::Majority,
+        );
+        assert!(detect(&split).is_empty());
+
+        let two_of_three = EnsembleStrategy::new(
+            vec![
+                child("a", Some(dec!(0.40))),
+                child("b", Some(dec!(0.40))),
+                child("c", None),
+            ],
+            EnsemblePolicy::Majority,
+        );
+        assert_eq!(detect(&two_of_three).len(), 1);
+    }
+
+    #[test]
+    fn applies_when_any_child_applies() {
+        let ensemble = EnsembleStrategy::new(
+            vec![
+                Box::new(MockStrategy {
+                    name: "binary",
+                    applies: false,
+                    found: None,
+                }),
+                child("any", None),
+            ],
+            EnsemblePolicy::All,
+        );
+        assert!(ensemble.applies_to(&MarketContext::binary()));
+        assert_eq!(ensemble.child_names(), ["binary", "any"]);
+    }
+
+    #[test]
+    fn policy_parses_lowercase() {
+        let policy: EnsemblePolicy = serde_json::from_str("\"majority\"").unwrap();
+        assert_eq!(policy, EnsemblePolicy::Majority);
+    }
+}
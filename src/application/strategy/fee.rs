@@ -0,0 +1,81 @@
+//! Trading cost model for net edge calculation.
+//!
+//! Gross edge (`payout - total_cost`) ignores what it costs to actually
+//! execute: gas on Polygon and any maker/taker spread. [`FeeModel`] subtracts
+//! those costs so thresholds are checked against the edge that is really
+//! captured.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::market_rebalancing::RebalancingLeg;
+
+/// Per-share execution costs applied before edge thresholds.
+///
+/// Costs are expressed per share of payout, in the same units as edge.
+/// Both default to zero, which leaves the gross edge unchanged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FeeModel {
+    /// Fixed cost charged once per opportunity.
+    #[serde(default)]
+    pub flat_cost: Decimal,
+
+    /// Cost charged for each leg executed.
+    #[serde(default)]
+    pub per_leg_cost: Decimal,
+}
+
+impl FeeModel {
+    /// Create a fee model with the given flat and per-leg costs.
+    #[must_use]
+    pub const fn new(flat_cost: Decimal, per_leg_cost: Decimal) -> Self {
+        Self {
+            flat_cost,
+            per_leg_cost,
+        }
+    }
+
+    /// Return the total cost of executing an opportunity with `leg_count` legs.
+    #[must_use]
+    pub fn cost(&self, leg_count: usize) -> Decimal {
+        self.flat_cost + self.per_leg_cost * Decimal::from(leg_count)
+    }
+
+    /// Return the net edge after subtracting the cost of executing `legs`.
+    #[must_use]
+    pub fn apply(&self, gross_edge: Decimal, legs: &[RebalancingLeg]) -> Decimal {
+        gross_edge - self.cost(legs.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::id::TokenId;
+    use rust_decimal_macros::dec;
+
+    fn legs(count: usize) -> Vec<RebalancingLeg> {
+        (0..count)
+            .map(|i| RebalancingLeg::new(TokenId::from(format!("t{i}")), dec!(0.30), dec!(10)))
+            .collect()
+    }
+
+    #[test]
+    fn default_model_is_free() {
+        let fees = FeeModel::default();
+        assert_eq!(fees.apply(dec!(0.04), &legs(3)), dec!(0.04));
+    }
+
+    #[test]
+    fn apply_subtracts_flat_and_per_leg_costs() {
+        let fees = FeeModel::new(dec!(0.005), dec!(0.002));
+        assert_eq!(fees.cost(3), dec!(0.011));
+        assert_eq!(fees.apply(dec!(0.04), &legs(3)), dec!(0.029));
+    }
+
+    #[test]
+    fn deserializes_partial_table() {
+        let fees: FeeModel = toml::from_str("per_leg_cost = 0.01").unwrap();
+        assert_eq!(fees, FeeModel::new(Decimal::ZERO, dec!(0.01)));
+    }
+}
@@ -1,17 +1,22 @@
 //! Market rebalancing arbitrage strategy.
 //!
 //! Detects when the sum of all outcome ask prices is less than the guaranteed
-//! payout in multi-outcome markets (3+ outcomes).
+//! payout in multi-outcome markets (3+ outcomes). With `negative_risk`
+//! enabled it also detects the inverse: best bids summing above the payout,
+//! so selling every outcome locks in a profit.
 //!
 //! Historical data shows this strategy captured 73.1% ($29M) of arbitrage
 //! profits, making it the largest contributor by far.
 
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
+use super::fee::FeeModel;
 use crate::domain::{
-    id::MarketId, id::TokenId, money::Price, money::Volume, opportunity::Opportunity,
-    opportunity::OpportunityLeg,
+    book::Book, book::PriceLevel, id::MarketId, id::TokenId, money::Price, money::Volume,
+    opportunity::HoldPolicy, opportunity::Opportunity, opportunity::OpportunityKind,
+    opportunity::OpportunityLeg, quote::select_quote, quote::QuoteSelection,
+    quote::PRIMARY_BOOK_SOURCE,
 };
 use crate::port::{
     inbound::strategy::DetectionContext, inbound::strategy::MarketContext,
@@ -19,7 +24,7 @@ use crate::port::{
 };
 
 /// Configuration for market rebalancing arbitrage detection.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MarketRebalancingConfig {
     /// Minimum edge (profit per dollar) required to consider an opportunity.
     #[serde(default = "default_min_edge")]
@@ -33,6 +38,37 @@ pub struct MarketRebalancingConfig {
     /// Markets with more outcomes are skipped to avoid performance issues.
     #[serde(default = "default_max_outcomes")]
     pub max_outcomes: usize,
+
+    /// How to choose between price sources when an outcome has several.
+    /// Each leg records the source it will execute against.
+    #[serde(default)]
+    pub quote_selection: QuoteSelection,
+
+    /// Execution costs subtracted from the gross edge before `min_edge`
+    /// and `min_profit` are checked.
+    #[serde(default)]
+    pub fees: FeeModel,
+
+    /// Minimum dollar depth (size times price) every leg must offer.
+    /// Opportunities with a thinner leg are skipped. Zero disables the check.
+    #[serde(default)]
+    pub min_liquidity_per_leg: Decimal,
+
+    /// How far above the payout the outcome sum may sit and still be
+    /// reported as a watch-only opportunity. Zero disables watch mode.
+    #[serde(default)]
+    pub watch_threshold: Decimal,
+
+    /// Also detect sell-side opportunities where the best bids sum above the
+    /// payout. Only enable on exchanges that allow shorting or minting a
+    /// full outcome set.
+    #[serde(default)]
+    pub negative_risk: bool,
+
+    /// Whether positions are held to settlement or may exit early.
+    /// Defaults to holding, since a full outcome set pays out exactly.
+    #[serde(default)]
+    pub hold_policy: HoldPolicy,
 }
 
 fn default_min_edge() -> Decimal {
@@ -53,6 +89,12 @@ impl Default for MarketRebalancingConfig {
             min_edge: default_min_edge(),
             min_profit: default_min_profit(),
             max_outcomes: default_max_outcomes(),
+            quote_selection: QuoteSelection::default(),
+            fees: FeeModel::default(),
+            min_liquidity_per_leg: Decimal::ZERO,
+            watch_threshold: Decimal::ZERO,
+            negative_risk: false,
+            hold_policy: HoldPolicy::default(),
         }
     }
 }
@@ -112,31 +154,65 @@ impl Strategy for MarketRebalancingStrategy {
             .collect();
 
         let payout = ctx.payout();
+        let mut opportunities = Vec::new();
 
-        // Use the detection function
-        if let Some(rebal_opp) = detect_rebalancing(ctx, &token_ids, &self.config, payout) {
-            // Convert RebalancingOpportunity to standard Opportunity
-            let legs: Vec<OpportunityLeg> = rebal_opp
+        let found = match detect_rebalancing(ctx, &token_ids, &self.config, payout) {
+            Some(found) => Some((found, OpportunityKind::Actionable)),
+            None => detect_near_rebalancing(ctx, &token_ids, &self.config, payout)
+                .map(|found| (found, OpportunityKind::Watch)),
+        };
+        if let Some((rebal_opp, kind)) = found {
+            let legs = rebal_opp
                 .legs
                 .iter()
-                .map(|leg| OpportunityLeg::new(leg.token_id.clone(), leg.price))
+                .map(|leg| {
+                    OpportunityLeg::new(leg.token_id.clone(), leg.price).with_source(&leg.source)
+                })
                 .collect();
-
-            let opp = Opportunity::with_strategy(
-                rebal_opp.market_id.clone(),
-                &rebal_opp.question,
-                legs,
-                rebal_opp.volume,
-                payout,
-                "market_rebalancing",
+            opportunities.push(
+                to_opportunity(&rebal_opp, legs, payout)
+                    .with_kind(kind)
+                    .with_min_edge(self.config.min_edge)
+                    .with_hold_policy(self.config.hold_policy),
             );
-            return vec![opp];
         }
 
-        vec![]
+        if self.config.negative_risk {
+            if let Some(short_opp) = detect_negative_risk(ctx, &token_ids, &self.config, payout) {
+                let legs = short_opp
+                    .legs
+                    .iter()
+                    .map(|leg| OpportunityLeg::sell(leg.token_id.clone(), leg.price))
+                    .collect();
+                opportunities.push(
+                    to_opportunity(&short_opp, legs, payout)
+                        .with_min_edge(self.config.min_edge)
+                        .with_hold_policy(self.config.hold_policy),
+                );
+            }
+        }
+
+        opportunities
     }
 }
 
+/// Convert a [`RebalancingOpportunity`] into a standard [`Opportunity`].
+fn to_opportunity(
+    found: &RebalancingOpportunity,
+    legs: Vec<OpportunityLeg>,
+    payout: Decimal,
+) -> Opportunity {
+    Opportunity::with_strategy(
+        found.market_id.clone(),
+        &found.question,
+        legs,
+        found.volume,
+        payout,
+        "market_rebalancing",
+    )
+    .with_fees(found.fees)
+}
+
 /// A single leg in a rebalancing opportunity.
 #[derive(Debug, Clone)]
 pub struct RebalancingLeg {
@@ -146,18 +222,28 @@ pub struct RebalancingLeg {
     pub price: Price,
     /// Available volume at the ask price.
     pub volume: Volume,
+    /// Price source this leg executes against.
+    pub source: String,
 }
 
 impl RebalancingLeg {
-    /// Create a new rebalancing leg.
+    /// Create a new rebalancing leg priced from the primary order book.
     #[must_use]
-    pub const fn new(token_id: TokenId, price: Price, volume: Volume) -> Self {
+    pub fn new(token_id: TokenId, price: Price, volume: Volume) -> Self {
         Self {
             token_id,
             price,
             volume,
+            source: PRIMARY_BOOK_SOURCE.to_string(),
         }
     }
+
+    /// Record the price source this leg executes against.
+    #[must_use]
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = source.into();
+        self
+    }
 }
 
 /// A market rebalancing opportunity with multiple outcome legs.
@@ -174,8 +260,10 @@ pub struct RebalancingOpportunity {
     pub legs: Vec<RebalancingLeg>,
     /// Total cost to purchase all outcomes.
     pub total_cost: Price,
-    /// Edge (profit per dollar of payout).
+    /// Net edge (profit per share after fees).
     pub edge: Price,
+    /// Execution costs per share deducted from the gross edge.
+    pub fees: Price,
     /// Tradeable volume, limited by the smallest leg.
     pub volume: Volume,
     /// Expected profit at the tradeable volume.
@@ -195,8 +283,9 @@ impl RebalancingOpportunity {
 /// Returns `None` if:
 /// - The market has fewer than 3 or more than `max_outcomes` outcomes
 /// - Any required order book is missing or has no asks
+/// - Any leg's ask depth is worth less than `min_liquidity_per_leg`
 /// - The total cost equals or exceeds the payout
-/// - The edge is below the configured minimum
+/// - The edge net of fees is below the configured minimum
 /// - The expected profit is below the configured minimum
 pub fn detect_rebalancing(
     ctx: &dyn DetectionContext,
@@ -204,37 +293,131 @@ pub fn detect_rebalancing(
     config: &MarketRebalancingConfig,
     payout: Decimal,
 ) -> Option<RebalancingOpportunity> {
-    // Need at least 3 outcomes (2 is handled by single_condition)
+    let (legs, total_cost, min_volume) = top_of_book_legs(ctx, token_ids, config)?;
+
+    // Check if arbitrage exists
+    if total_cost >= payout {
+        return None;
+    }
+
+    let fees = config.fees.cost(legs.len());
+    let edge = config.fees.apply(payout - total_cost, &legs);
+
+    if edge < config.min_edge {
+        return None;
+    }
+
+    let expected_profit = edge * min_volume;
+
+    if expected_profit < config.min_profit {
+        return None;
+    }
+
+    Some(RebalancingOpportunity {
+        market_id: ctx.market_id().clone(),
+        question: ctx.question().to_string(),
+        legs,
+        total_cost,
+        edge,
+        fees,
+        volume: min_volume,
+        expected_profit,
+    })
+}
+
+/// Detect a market whose outcome sum sits just above the payout.
+///
+/// Used for watch mode: the returned opportunity has a negative edge and
+/// must never be executed. Returns `None` if:
+/// - `watch_threshold` is zero
+/// - The market has fewer than 3 or more than `max_outcomes` outcomes
+/// - Any required order book is missing or has no asks
+/// - Any leg's ask depth is worth less than `min_liquidity_per_leg`
+/// - The total cost is at or below the payout, or more than
+///   `watch_threshold` above it
+pub fn detect_near_rebalancing(
+    ctx: &dyn DetectionContext,
+    token_ids: &[TokenId],
+    config: &MarketRebalancingConfig,
+    payout: Decimal,
+) -> Option<RebalancingOpportunity> {
+    if config.watch_threshold <= Decimal::ZERO {
+        return None;
+    }
+
+    let (legs, total_cost, min_volume) = top_of_book_legs(ctx, token_ids, config)?;
+
+    if total_cost <= payout || total_cost > payout + config.watch_threshold {
+        return None;
+    }
+
+    let fees = config.fees.cost(legs.len());
+    let edge = config.fees.apply(payout - total_cost, &legs);
+
+    Some(RebalancingOpportunity {
+        market_id: ctx.market_id().clone(),
+        question: ctx.question().to_string(),
+        legs,
+        total_cost,
+        edge,
+        fees,
+        volume: min_volume,
+        expected_profit: edge * min_volume,
+    })
+}
+
+/// Detect a negative-risk opportunity: selling every outcome at its best bid.
+///
+/// When the best bids sum above the payout, selling one share of every
+/// outcome collects more than the single winning share pays out. The
+/// returned opportunity's `total_cost` is the summed proceeds and each leg's
+/// `price` is its best bid.
+///
+/// Returns `None` if:
+/// - The market has fewer than 3 or more than `max_outcomes` outcomes
+/// - Any required order book is missing or has no bids
+/// - Any leg's bid depth is worth less than `min_liquidity_per_leg`
+/// - The proceeds do not exceed the payout
+/// - The edge net of fees is below the configured minimum
+/// - The expected profit is below the configured minimum
+pub fn detect_negative_risk(
+    ctx: &dyn DetectionContext,
+    token_ids: &[TokenId],
+    config: &MarketRebalancingConfig,
+    payout: Decimal,
+) -> Option<RebalancingOpportunity> {
     if token_ids.len() < 3 || token_ids.len() > config.max_outcomes {
         return None;
     }
 
-    // Collect best asks for all outcomes
     let mut legs = Vec::with_capacity(token_ids.len());
-    let mut total_cost = Decimal::ZERO;
+    let mut proceeds = Decimal::ZERO;
     let mut min_volume = Decimal::MAX;
 
-    // Fail closed if any required order book is missing
     for token_id in token_ids {
+        // Fail closed if any leg lacks a bid to sell into
         let book = ctx.order_book(token_id)?;
-        let ask = book.best_ask()?;
+        let bid = book.best_bid()?;
 
-        total_cost += ask.price();
-        min_volume = min_volume.min(ask.size());
+        if bid.price() * bid.size() < config.min_liquidity_per_leg {
+            return None;
+        }
 
+        proceeds += bid.price();
+        min_volume = min_volume.min(bid.size());
         legs.push(RebalancingLeg::new(
             token_id.clone(),
-            ask.price(),
-            ask.size(),
+            bid.price(),
+            bid.size(),
         ));
     }
 
-    // Check if arbitrage exists
-    if total_cost >= payout {
+    if proceeds <= payout {
         return None;
     }
 
-    let edge = payout - total_cost;
+    let fees = config.fees.cost(legs.len());
+    let edge = config.fees.apply(proceeds - payout, &legs);
 
     if edge < config.min_edge {
         return None;
@@ -250,19 +433,201 @@ pub fn detect_rebalancing(
         market_id: ctx.market_id().clone(),
         question: ctx.question().to_string(),
         legs,
-        total_cost,
+        total_cost: proceeds,
         edge,
+        fees,
         volume: min_volume,
         expected_profit,
     })
 }
 
+/// Best-ask legs for every outcome, with their summed cost and smallest size.
+///
+/// Fails closed if the outcome count is out of range, any book is missing,
+/// or any leg is thinner than `min_liquidity_per_leg`.
+fn top_of_book_legs(
+    ctx: &dyn DetectionContext,
+    token_ids: &[TokenId],
+    config: &MarketRebalancingConfig,
+) -> Option<(Vec<RebalancingLeg>, Price, Volume)> {
+    // Need at least 3 outcomes (2 is handled by single_condition)
+    if token_ids.len() < 3 || token_ids.len() > config.max_outcomes {
+        return None;
+    }
+
+    let mut legs = Vec::with_capacity(token_ids.len());
+    let mut total_cost = Decimal::ZERO;
+    let mut min_volume = Decimal::MAX;
+
+    for token_id in token_ids {
+        let quotes = ctx.quotes(token_id);
+        let quote = select_quote(&quotes, config.quote_selection)?;
+
+        if quote.ask() * quote.size() < config.min_liquidity_per_leg {
+            return None;
+        }
+
+        total_cost += quote.ask();
+        min_volume = min_volume.min(quote.size());
+
+        legs.push(
+            RebalancingLeg::new(token_id.clone(), quote.ask(), quote.size())
+                .with_source(quote.source()),
+        );
+    }
+
+    Some((legs, total_cost, min_volume))
+}
+
+/// Detect a rebalancing opportunity by walking each leg's ask depth.
+///
+/// Unlike [`detect_rebalancing`], which stops at the top of each book, this
+/// keeps buying through successive ask levels for as long as the summed
+/// volume-weighted cost across all legs, plus fees, stays at least
+/// `min_edge` below the payout. Each returned leg's `price` is its blended
+/// price at the achievable size, and `volume` is that size.
+///
+/// Returns `None` if:
+/// - The market has fewer than 3 or more than `max_outcomes` outcomes
+/// - Any required order book is missing or has no asks
+/// - The best asks already leave less than `min_edge` after fees
+/// - Any leg's cost at the achievable size is below `min_liquidity_per_leg`
+/// - The expected profit is below the configured minimum
+pub fn detect_rebalancing_depth(
+    ctx: &dyn DetectionContext,
+    token_ids: &[TokenId],
+    config: &MarketRebalancingConfig,
+    payout: Decimal,
+) -> Option<RebalancingOpportunity> {
+    if token_ids.len() < 3 || token_ids.len() > config.max_outcomes {
+        return None;
+    }
+
+    // Fail closed if any required order book is missing or empty
+    let books = token_ids
+        .iter()
+        .map(|token_id| ctx.order_book(token_id))
+        .collect::<Option<Vec<Book>>>()?;
+    if books.iter().any(|book| book.asks().is_empty()) {
+        return None;
+    }
+
+    let fees = config.fees.cost(token_ids.len());
+    let max_cost = payout - fees - config.min_edge;
+    let volume = max_depth_volume(&books, max_cost);
+    if volume <= Decimal::ZERO {
+        return None;
+    }
+
+    let legs: Vec<RebalancingLeg> = token_ids
+        .iter()
+        .zip(&books)
+        .map(|(token_id, book)| {
+            RebalancingLeg::new(
+                token_id.clone(),
+                fill_cost(book.asks(), volume) / volume,
+                volume,
+            )
+        })
+        .collect();
+
+    if legs
+        .iter()
+        .any(|leg| leg.price * leg.volume < config.min_liquidity_per_leg)
+    {
+        return None;
+    }
+
+    let total_cost: Price = legs.iter().map(|leg| leg.price).sum();
+    let edge = config.fees.apply(payout - total_cost, &legs);
+    let expected_profit = edge * volume;
+
+    if expected_profit < config.min_profit {
+        return None;
+    }
+
+    Some(RebalancingOpportunity {
+        market_id: ctx.market_id().clone(),
+        question: ctx.question().to_string(),
+        legs,
+        total_cost,
+        edge,
+        fees,
+        volume,
+        expected_profit,
+    })
+}
+
+/// Cost of buying `volume` shares by walking `asks` from the best price.
+fn fill_cost(asks: &[PriceLevel], volume: Volume) -> Price {
+    let mut remaining = volume;
+    let mut cost = Decimal::ZERO;
+
+    for level in asks {
+        if remaining <= Decimal::ZERO {
+            break;
+        }
+        let take = remaining.min(level.size());
+        cost += take * level.price();
+        remaining -= take;
+    }
+
+    cost
+}
+
+/// Largest volume at which buying every book costs at most `max_cost` per share.
+///
+/// The summed fill cost is piecewise linear in volume with a non-decreasing
+/// slope, so the average cost only rises as more depth is taken. This walks
+/// the breakpoints where any book moves to its next level and solves for the
+/// exact crossing inside the first segment that would exceed `max_cost`.
+/// Running out of levels in any book caps the volume there.
+fn max_depth_volume(books: &[Book], max_cost: Price) -> Volume {
+    let mut level_index = vec![0usize; books.len()];
+    let mut level_used = vec![Decimal::ZERO; books.len()];
+    let mut volume = Decimal::ZERO;
+    let mut cost = Decimal::ZERO;
+
+    loop {
+        // Summed marginal price and the distance to the next breakpoint
+        let mut marginal = Decimal::ZERO;
+        let mut step = Decimal::MAX;
+        for (i, book) in books.iter().enumerate() {
+            let Some(level) = book.asks().get(level_index[i]) else {
+                return volume;
+            };
+            marginal += level.price();
+            step = step.min(level.size() - level_used[i]);
+        }
+
+        if cost + marginal * step <= max_cost * (volume + step) {
+            volume += step;
+            cost += marginal * step;
+            for (i, book) in books.iter().enumerate() {
+                level_used[i] += step;
+                if level_used[i] >= book.asks()[level_index[i]].size() {
+                    level_index[i] += 1;
+                    level_used[i] = Decimal::ZERO;
+                }
+            }
+            continue;
+        }
+
+        // Solve cost + marginal * x = max_cost * (volume + x) for the partial step
+        if marginal <= max_cost {
+            return volume;
+        }
+        let partial = (max_cost * volume - cost) / (marginal - max_cost);
+        return volume + partial.max(Decimal::ZERO);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::application::cache::book::BookCache;
     use crate::application::strategy::context::ConcreteDetectionContext;
-    use crate::domain::{book::Book, book::PriceLevel, market::Market, market::Outcome};
+    use crate::domain::{market::Market, market::Outcome, trade::OrderSide};
     use rust_decimal_macros::dec;
 
     fn make_config() -> MarketRebalancingConfig {
@@ -270,6 +635,12 @@ mod tests {
             min_edge: dec!(0.03),
             min_profit: dec!(1.00),
             max_outcomes: 10,
+            quote_selection: QuoteSelection::CheapestAsk,
+            fees: FeeModel::default(),
+            min_liquidity_per_leg: Decimal::ZERO,
+            watch_threshold: Decimal::ZERO,
+            negative_risk: false,
+            hold_policy: HoldPolicy::HoldToSettlement,
         }
     }
 
@@ -440,4 +811,318 @@ mod tests {
         // Should return None for binary markets (handled by single_condition)
         assert!(detect_rebalancing(&ctx, &tokens, &config, Decimal::ONE).is_none());
     }
+
+    #[test]
+    fn test_rejects_leg_below_liquidity_floor() {
+        // Leg "c" offers 20 shares at 0.25: $5 of depth
+        let (market, tokens, cache) = depth_market([
+            vec![PriceLevel::new(dec!(0.30), dec!(500))],
+            vec![PriceLevel::new(dec!(0.30), dec!(500))],
+            vec![PriceLevel::new(dec!(0.25), dec!(20))],
+        ]);
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+        let config = MarketRebalancingConfig {
+            min_profit: dec!(0.10),
+            ..make_config()
+        };
+        assert!(detect_rebalancing(&ctx, &tokens, &config, Decimal::ONE).is_some());
+
+        let config = MarketRebalancingConfig {
+            min_liquidity_per_leg: dec!(50),
+            ..config
+        };
+        assert!(detect_rebalancing(&ctx, &tokens, &config, Decimal::ONE).is_none());
+        assert!(detect_rebalancing_depth(&ctx, &tokens, &config, Decimal::ONE).is_none());
+    }
+
+    fn three_outcome_market_at(price: Decimal) -> (Market, Vec<TokenId>, BookCache) {
+        let level = vec![PriceLevel::new(price, dec!(100))];
+        depth_market([level.clone(), level.clone(), level])
+    }
+
+    #[test]
+    fn test_fees_push_edge_below_threshold() {
+        // 0.32 * 3 = 0.96 (4% gross edge, above the 3% threshold)
+        let (market, tokens, cache) = three_outcome_market_at(dec!(0.32));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        let config = make_config();
+        assert!(detect_rebalancing(&ctx, &tokens, &config, Decimal::ONE).is_some());
+
+        // A 2% fee leaves a 2% net edge, below the threshold
+        let config = MarketRebalancingConfig {
+            fees: FeeModel::new(dec!(0.02), Decimal::ZERO),
+            ..make_config()
+        };
+        assert!(detect_rebalancing(&ctx, &tokens, &config, Decimal::ONE).is_none());
+    }
+
+    #[test]
+    fn test_opportunity_reports_net_edge() {
+        let (market, tokens, cache) = three_outcome_market_at(dec!(0.30));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+        let strategy = MarketRebalancingStrategy::new(MarketRebalancingConfig {
+            fees: FeeModel::new(dec!(0.01), dec!(0.01)),
+            ..make_config()
+        });
+
+        let opportunities = strategy.detect(&ctx);
+
+        assert_eq!(opportunities.len(), 1);
+        assert_eq!(opportunities[0].fees(), dec!(0.04));
+        assert_eq!(opportunities[0].edge(), dec!(0.06));
+        assert_eq!(opportunities[0].expected_profit(), dec!(6.00));
+    }
+
+    #[test]
+    fn test_opportunity_carries_configured_hold_policy() {
+        let (market, _, cache) = three_outcome_market_at(dec!(0.30));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        let strategy = MarketRebalancingStrategy::new(make_config());
+        assert_eq!(
+            strategy.detect(&ctx)[0].hold_policy(),
+            HoldPolicy::HoldToSettlement
+        );
+
+        let strategy = MarketRebalancingStrategy::new(MarketRebalancingConfig {
+            hold_policy: HoldPolicy::ExitEarly,
+            ..make_config()
+        });
+        assert_eq!(
+            strategy.detect(&ctx)[0].hold_policy(),
+            HoldPolicy::ExitEarly
+        );
+    }
+
+    #[test]
+    fn test_watch_mode_flags_near_arbitrage() {
+        // 0.34 * 3 = 1.02, two cents above payout
+        let (market, _, cache) = three_outcome_market_at(dec!(0.34));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        let strategy = MarketRebalancingStrategy::new(make_config());
+        assert!(strategy.detect(&ctx).is_empty());
+
+        let strategy = MarketRebalancingStrategy::new(MarketRebalancingConfig {
+            watch_threshold: dec!(0.02),
+            ..make_config()
+        });
+        let opportunities = strategy.detect(&ctx);
+
+        assert_eq!(opportunities.len(), 1);
+        assert_eq!(opportunities[0].kind(), OpportunityKind::Watch);
+        assert!(!opportunities[0].is_actionable());
+        assert_eq!(opportunities[0].edge(), dec!(-0.02));
+    }
+
+    #[test]
+    fn test_watch_mode_ignores_sums_beyond_threshold() {
+        // 0.35 * 3 = 1.05, outside a two-cent watch window
+        let (market, tokens, cache) = three_outcome_market_at(dec!(0.35));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+        let config = MarketRebalancingConfig {
+            watch_threshold: dec!(0.02),
+            ..make_config()
+        };
+
+        assert!(detect_near_rebalancing(&ctx, &tokens, &config, Decimal::ONE).is_none());
+    }
+
+    #[test]
+    fn test_profitable_market_stays_actionable_in_watch_mode() {
+        let (market, _, cache) = three_outcome_market_at(dec!(0.30));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+        let strategy = MarketRebalancingStrategy::new(MarketRebalancingConfig {
+            watch_threshold: dec!(0.02),
+            ..make_config()
+        });
+
+        let opportunities = strategy.detect(&ctx);
+
+        assert_eq!(opportunities.len(), 1);
+        assert!(opportunities[0].is_actionable());
+    }
+
+    // -------------------------------------------------------------------------
+    // Negative risk
+    // -------------------------------------------------------------------------
+
+    fn bid_market(bids: [Option<Decimal>; 3]) -> (Market, Vec<TokenId>, BookCache) {
+        let (market, tokens, cache) = three_outcome_market_at(dec!(0.45));
+        for (token, bid) in tokens.iter().zip(bids) {
+            let bids = bid
+                .map(|price| vec![PriceLevel::new(price, dec!(100))])
+                .unwrap_or_default();
+            cache.update(Book::with_levels(
+                token.clone(),
+                bids,
+                vec![PriceLevel::new(dec!(0.45), dec!(100))],
+            ));
+        }
+        (market, tokens, cache)
+    }
+
+    #[test]
+    fn test_negative_risk_sells_when_bids_exceed_payout() {
+        // 0.36 * 3 = 1.08 in bids against a 1.00 payout
+        let (market, tokens, cache) =
+            bid_market([Some(dec!(0.36)), Some(dec!(0.36)), Some(dec!(0.36))]);
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        let opp = detect_negative_risk(&ctx, &tokens, &make_config(), Decimal::ONE).unwrap();
+
+        assert_eq!(opp.total_cost, dec!(1.08));
+        assert_eq!(opp.edge, dec!(0.08));
+        assert_eq!(opp.volume, dec!(100));
+        assert_eq!(opp.expected_profit, dec!(8.00));
+    }
+
+    #[test]
+    fn test_negative_risk_requires_edge_above_min_edge() {
+        // 0.34 * 3 = 1.02: above payout, but below the 3% minimum edge
+        let (market, tokens, cache) =
+            bid_market([Some(dec!(0.34)), Some(dec!(0.34)), Some(dec!(0.34))]);
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        assert!(detect_negative_risk(&ctx, &tokens, &make_config(), Decimal::ONE).is_none());
+    }
+
+    #[test]
+    fn test_negative_risk_fails_closed_without_bid() {
+        let (market, tokens, cache) = bid_market([Some(dec!(0.60)), Some(dec!(0.60)), None]);
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        assert!(detect_negative_risk(&ctx, &tokens, &make_config(), Decimal::ONE).is_none());
+    }
+
+    #[test]
+    fn test_negative_risk_only_emitted_when_enabled() {
+        let (market, _, cache) = bid_market([Some(dec!(0.36)), Some(dec!(0.36)), Some(dec!(0.36))]);
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        let strategy = MarketRebalancingStrategy::new(make_config());
+        assert!(strategy.detect(&ctx).is_empty());
+
+        let strategy = MarketRebalancingStrategy::new(MarketRebalancingConfig {
+            negative_risk: true,
+            ..make_config()
+        });
+        let opportunities = strategy.detect(&ctx);
+
+        assert_eq!(opportunities.len(), 1);
+        let opp = &opportunities[0];
+        assert!(opp.is_sell());
+        assert!(opp.is_actionable());
+        assert!(opp.legs().iter().all(|leg| leg.side() == OrderSide::Sell));
+        assert_eq!(opp.edge(), dec!(0.08));
+        assert_eq!(opp.expected_profit(), dec!(8.00));
+    }
+
+    // -------------------------------------------------------------------------
+    // Depth walking
+    // -------------------------------------------------------------------------
+
+    fn depth_market(asks: [Vec<PriceLevel>; 3]) -> (Market, Vec<TokenId>, BookCache) {
+        let tokens = vec![
+            TokenId::from("candidate-a"),
+            TokenId::from("candidate-b"),
+            TokenId::from("candidate-c"),
+        ];
+        let outcomes: Vec<Outcome> = tokens
+            .iter()
+            .map(|t| Outcome::new(t.clone(), t.as_str()))
+            .collect();
+        let market = Market::new(MarketId::from("election"), "Who wins?", outcomes, dec!(1));
+
+        let cache = BookCache::new();
+        for (token, levels) in tokens.iter().zip(asks) {
+            cache.update(Book::with_levels(token.clone(), vec![], levels));
+        }
+
+        (market, tokens, cache)
+    }
+
+    fn levels(levels: &[(Decimal, Decimal)]) -> Vec<PriceLevel> {
+        levels
+            .iter()
+            .map(|&(price, size)| PriceLevel::new(price, size))
+            .collect()
+    }
+
+    #[test]
+    fn test_depth_takes_size_beyond_top_of_book() {
+        let deep = levels(&[(dec!(0.30), dec!(10)), (dec!(0.31), dec!(100))]);
+        let (market, tokens, cache) = depth_market([deep.clone(), deep.clone(), deep]);
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+        let config = make_config();
+
+        let top = detect_rebalancing(&ctx, &tokens, &config, Decimal::ONE).unwrap();
+        let depth = detect_rebalancing_depth(&ctx, &tokens, &config, Decimal::ONE).unwrap();
+
+        assert_eq!(top.volume, dec!(10));
+        assert_eq!(depth.volume, dec!(110));
+        for leg in &depth.legs {
+            assert_eq!(leg.price, dec!(34) / dec!(110));
+            assert_eq!(leg.volume, dec!(110));
+        }
+        assert!(depth.edge >= config.min_edge);
+        assert!(depth.expected_profit > top.expected_profit);
+    }
+
+    #[test]
+    fn test_depth_stops_where_blended_cost_reaches_threshold() {
+        // Top level: 0.90 total. Second level: 1.00 total, which would erase the edge.
+        // Blended cost hits payout - min_edge (0.95) at 20 shares.
+        let (market, tokens, cache) = depth_market([
+            levels(&[(dec!(0.30), dec!(10)), (dec!(0.34), dec!(100))]),
+            levels(&[(dec!(0.30), dec!(10)), (dec!(0.33), dec!(100))]),
+            levels(&[(dec!(0.30), dec!(10)), (dec!(0.33), dec!(100))]),
+        ]);
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+        let config = MarketRebalancingConfig {
+            min_edge: dec!(0.05),
+            ..make_config()
+        };
+
+        let opp = detect_rebalancing_depth(&ctx, &tokens, &config, Decimal::ONE).unwrap();
+
+        assert_eq!(opp.volume, dec!(20));
+        assert_eq!(opp.total_cost, dec!(0.95));
+        assert_eq!(opp.edge, dec!(0.05));
+        assert_eq!(opp.legs[0].price, dec!(0.32));
+        assert_eq!(opp.legs[1].price, dec!(0.315));
+    }
+
+    #[test]
+    fn test_depth_capped_by_thin_book() {
+        let deep = levels(&[(dec!(0.30), dec!(10)), (dec!(0.30), dec!(500))]);
+        let thin = levels(&[(dec!(0.30), dec!(25))]);
+        let (market, tokens, cache) = depth_market([deep.clone(), deep, thin]);
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        let opp = detect_rebalancing_depth(&ctx, &tokens, &make_config(), Decimal::ONE).unwrap();
+
+        assert_eq!(opp.volume, dec!(25));
+        assert_eq!(opp.edge, dec!(0.10));
+    }
+
+    #[test]
+    fn test_depth_fails_closed_on_empty_book() {
+        let deep = levels(&[(dec!(0.30), dec!(100))]);
+        let (market, tokens, cache) = depth_market([deep.clone(), deep, vec![]]);
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        assert!(detect_rebalancing_depth(&ctx, &tokens, &make_config(), Decimal::ONE).is_none());
+    }
+
+    #[test]
+    fn test_depth_none_when_best_asks_lack_edge() {
+        let flat = levels(&[(dec!(0.33), dec!(100))]);
+        let (market, tokens, cache) = depth_market([flat.clone(), flat.clone(), flat]);
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        // 0.99 total leaves 1% edge, below the 3% threshold
+        assert!(detect_rebalancing_depth(&ctx, &tokens, &make_config(), Decimal::ONE).is_none());
+    }
 }
@@ -7,10 +7,20 @@
 //! - [`market_rebalancing`]: Multi-outcome arbitrage (sum of all outcomes < $1)
 //! - [`combinatorial`]: Cross-market arbitrage using Frank-Wolfe projection
 //!
+//! [`cross_exchange`] implements
+//! [`MultiExchangeStrategy`](crate::port::inbound::strategy::MultiExchangeStrategy)
+//! instead, pricing the same event across two exchanges.
+//!
 //! Use [`registry::StrategyRegistry`] to manage and run multiple strategies.
+//! [`ensemble::EnsembleStrategy`] wraps several strategies and only emits
+//! opportunities they agree on.
+//! [`fee::FeeModel`] converts gross edge to net edge before thresholds apply.
 
 pub mod combinatorial;
 pub mod context;
+pub mod cross_exchange;
+pub mod ensemble;
+pub mod fee;
 pub mod market_rebalancing;
 pub mod registry;
 pub mod single_condition;
@@ -1,16 +1,23 @@
 //! Strategy registry for managing and executing detection algorithms.
 
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+use parking_lot::RwLock;
+
+use crate::application::cache::book::BookCache;
 use crate::application::cache::cluster::ClusterCache;
-use crate::domain::{market::MarketRegistry, opportunity::Opportunity};
+use crate::domain::{id::MarketId, id::TokenId, market::MarketRegistry, opportunity::Opportunity};
 use crate::port::{
-    inbound::strategy::DetectionContext, inbound::strategy::MarketContext,
-    inbound::strategy::Strategy, inbound::strategy::StrategyEngine,
-    outbound::solver::ProjectionSolver,
+    inbound::runtime::RuntimeStrategyControl, inbound::runtime::RuntimeStrategyStatus,
+    inbound::runtime::UnknownStrategyError, inbound::strategy::DetectionContext,
+    inbound::strategy::MarketContext, inbound::strategy::Strategy,
+    inbound::strategy::StrategyEngine, outbound::solver::ProjectionSolver,
 };
 
 use super::combinatorial::{CombinatorialConfig, CombinatorialStrategy};
+use super::context::ConcreteDetectionContext;
+use super::ensemble::{EnsemblePolicy, EnsembleStrategy};
 use super::market_rebalancing::{MarketRebalancingConfig, MarketRebalancingStrategy};
 use super::single_condition::{SingleConditionConfig, SingleConditionStrategy};
 
@@ -20,6 +27,10 @@ use super::single_condition::{SingleConditionConfig, SingleConditionStrategy};
 /// strategies during opportunity detection. Strategies are executed in
 /// registration order.
 ///
+/// Strategies can be switched off at runtime through the handle returned by
+/// [`StrategyRegistry::toggles`]; disabled strategies stay registered but are
+/// skipped before [`Strategy::applies_to`] is consulted.
+///
 /// Use [`StrategyRegistryBuilder`] for convenient construction from configuration.
 ///
 /// # Example
@@ -38,6 +49,8 @@ use super::single_condition::{SingleConditionConfig, SingleConditionStrategy};
 pub struct StrategyRegistry {
     /// Registered strategies in execution order.
     strategies: Vec<Box<dyn Strategy>>,
+    /// Names of strategies disabled at runtime, shared with [`StrategyToggles`].
+    disabled: Arc<RwLock<HashSet<String>>>,
 }
 
 impl StrategyRegistry {
@@ -78,6 +91,38 @@ impl StrategyRegistry {
         self.strategies.is_empty()
     }
 
+    /// Return true unless the named strategy has been disabled at runtime.
+    #[must_use]
+    pub fn is_enabled(&self, name: &str) -> bool {
+        !self.disabled.read().contains(name)
+    }
+
+    /// Return a handle for enabling and disabling registered strategies.
+    ///
+    /// The handle shares state with the registry, so changes apply to the
+    /// next detection pass.
+    #[must_use]
+    pub fn toggles(&self) -> StrategyToggles {
+        StrategyToggles {
+            names: self.strategies.iter().map(|s| s.name()).collect(),
+            disabled: Arc::clone(&self.disabled),
+        }
+    }
+
+    /// Strategies that are enabled and apply to `market_ctx`.
+    ///
+    /// The runtime switch is checked first so disabled strategies never
+    /// reach [`Strategy::applies_to`].
+    fn active<'a>(
+        &'a self,
+        market_ctx: &'a MarketContext,
+    ) -> impl Iterator<Item = &'a dyn Strategy> + 'a {
+        self.strategies
+            .iter()
+            .map(AsRef::as_ref)
+            .filter(move |s| self.is_enabled(s.name()) && s.applies_to(market_ctx))
+    }
+
     /// Inject the market registry into strategies that require it.
     ///
     /// Must be called after the [`MarketRegistry`] is built, as markets are
@@ -90,14 +135,12 @@ impl StrategyRegistry {
 
     /// Run all applicable strategies and collect detected opportunities.
     ///
-    /// Only strategies where [`Strategy::applies_to`] returns true for the
-    /// current market context are executed.
+    /// Only strategies that are enabled and where [`Strategy::applies_to`]
+    /// returns true for the current market context are executed.
     #[must_use]
     pub fn detect_all(&self, ctx: &dyn DetectionContext) -> Vec<Opportunity> {
         let market_ctx = ctx.market_context();
-        self.strategies
-            .iter()
-            .filter(|s| s.applies_to(&market_ctx))
+        self.active(&market_ctx)
             .flat_map(|s| s.detect(ctx))
             .collect()
     }
@@ -112,12 +155,59 @@ impl StrategyRegistry {
         ctx: &dyn DetectionContext,
         market_ctx: &MarketContext,
     ) -> Vec<Opportunity> {
-        self.strategies
-            .iter()
-            .filter(|s| s.applies_to(market_ctx))
+        self.active(market_ctx)
             .flat_map(|s| s.detect(ctx))
             .collect()
     }
+
+    /// Run all applicable strategies over every market in a static snapshot.
+    ///
+    /// Intended for offline backtesting against recorded order books, without
+    /// the orchestrator loop. Each opportunity carries the name of the
+    /// strategy that found it. When several opportunities target the same
+    /// market and legs, only the one with the highest edge is kept.
+    #[must_use]
+    pub fn detect_markets(&self, markets: &MarketRegistry, cache: &BookCache) -> Vec<Opportunity> {
+        let mut opportunities: Vec<Opportunity> = Vec::new();
+        let mut seen: HashMap<(MarketId, Vec<TokenId>), usize> = HashMap::new();
+
+        for market in markets.markets() {
+            let ctx = ConcreteDetectionContext::new(market, cache);
+            let market_ctx = ctx.market_context();
+
+            for strategy in self.active(&market_ctx) {
+                for opportunity in strategy.detect(&ctx) {
+                    let opportunity = if opportunity.strategy().is_empty() {
+                        opportunity.with_strategy_name(strategy.name())
+                    } else {
+                        opportunity
+                    };
+
+                    let mut tokens: Vec<TokenId> = opportunity
+                        .legs()
+                        .iter()
+                        .map(|leg| leg.token_id().clone())
+                        .collect();
+                    tokens.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+                    let key = (opportunity.market_id().clone(), tokens);
+
+                    match seen.get(&key) {
+                        Some(&index) => {
+                            if opportunity.edge() > opportunities[index].edge() {
+                                opportunities[index] = opportunity;
+                            }
+                        }
+                        None => {
+                            seen.insert(key, opportunities.len());
+                            opportunities.push(opportunity);
+                        }
+                    }
+                }
+            }
+        }
+
+        opportunities
+    }
 }
 
 impl StrategyEngine for StrategyRegistry {
@@ -137,6 +227,51 @@ impl StrategyEngine for StrategyRegistry {
     }
 }
 
+/// Runtime on/off switches for the strategies of a [`StrategyRegistry`].
+///
+/// Cloned handles share state with the registry they came from.
+#[derive(Clone)]
+pub struct StrategyToggles {
+    /// Registered strategy names in execution order.
+    names: Vec<&'static str>,
+    /// Names of disabled strategies, shared with the registry.
+    disabled: Arc<RwLock<HashSet<String>>>,
+}
+
+impl RuntimeStrategyControl for StrategyToggles {
+    fn strategies(&self) -> Vec<RuntimeStrategyStatus> {
+        let disabled = self.disabled.read();
+        self.names
+            .iter()
+            .copied()
+            .map(|name| RuntimeStrategyStatus {
+                name,
+                enabled: !disabled.contains(name),
+            })
+            .collect()
+    }
+
+    fn set_strategy_enabled(
+        &self,
+        name: &str,
+        enabled: bool,
+    ) -> Result<RuntimeStrategyStatus, UnknownStrategyError> {
+        let Some(name) = self.names.iter().copied().find(|n| *n == name) else {
+            return Err(UnknownStrategyError::new(name));
+        };
+
+        let mut disabled = self.disabled.write();
+        if enabled {
+            disabled.remove(name);
+        } else {
+            disabled.insert(name.to_string());
+        }
+        tracing::info!(strategy = name, enabled, "Strategy toggled at runtime");
+
+        Ok(RuntimeStrategyStatus { name, enabled })
+    }
+}
+
 /// Builder for constructing a [`StrategyRegistry`] from configuration.
 ///
 /// Provides a fluent API for enabling strategies with their configurations
@@ -166,6 +301,8 @@ pub struct StrategyRegistryBuilder {
     market_rebalancing: Option<MarketRebalancingConfig>,
     /// Combinatorial strategy configuration (optional).
     combinatorial: Option<CombinatorialConfig>,
+    /// Wrap the strategies in a consensus ensemble (optional).
+    ensemble: Option<EnsemblePolicy>,
 }
 
 impl StrategyRegistryBuilder {
@@ -210,22 +347,30 @@ impl StrategyRegistryBuilder {
         self
     }
 
+    /// Wrap the enabled strategies in an [`EnsembleStrategy`] with `policy`.
+    #[must_use]
+    pub fn ensemble(mut self, policy: EnsemblePolicy) -> Self {
+        self.ensemble = Some(policy);
+        self
+    }
+
     /// Build the registry with all configured strategies.
     ///
     /// Strategies are registered in order: single-condition, market rebalancing,
-    /// then combinatorial.
+    /// then combinatorial. With an ensemble policy set, they are registered as
+    /// the children of a single ensemble strategy instead.
     #[must_use]
     pub fn build(self) -> StrategyRegistry {
         let cluster_cache = self.cluster_cache;
         let projection_solver = self.projection_solver;
-        let mut registry = StrategyRegistry::new();
+        let mut strategies: Vec<Box<dyn Strategy>> = Vec::new();
 
         if let Some(config) = self.single_condition {
-            registry.register(Box::new(SingleConditionStrategy::new(config)));
+            strategies.push(Box::new(SingleConditionStrategy::new(config)));
         }
 
         if let Some(config) = self.market_rebalancing {
-            registry.register(Box::new(MarketRebalancingStrategy::new(config)));
+            strategies.push(Box::new(MarketRebalancingStrategy::new(config)));
         }
 
         if let Some(config) = self.combinatorial {
@@ -236,9 +381,20 @@ impl StrategyRegistryBuilder {
             if let Some(solver) = projection_solver {
                 strategy.set_projection_solver(solver);
             }
-            registry.register(Box::new(strategy));
+            strategies.push(Box::new(strategy));
         }
 
+        let mut registry = StrategyRegistry::new();
+        match self.ensemble {
+            Some(policy) => {
+                registry.register(Box::new(EnsembleStrategy::new(strategies, policy)));
+            }
+            None => {
+                for strategy in strategies {
+                    registry.register(strategy);
+                }
+            }
+        }
         registry
     }
 }
@@ -246,7 +402,10 @@ impl StrategyRegistryBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::{market::Market, market::Outcome, opportunity::OpportunityLeg};
     use crate::port::inbound::strategy::MarketContext;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
 
     struct MockStrategy {
         name: &'static str,
@@ -285,4 +444,215 @@ mod tests {
         assert_eq!(registry.len(), 1);
         assert_eq!(registry.strategies()[0].name(), "test");
     }
+
+    /// Strategy that returns a fixed set of opportunities for every market.
+    struct FixedStrategy {
+        name: &'static str,
+        opportunities: Vec<Opportunity>,
+    }
+
+    impl Strategy for FixedStrategy {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn applies_to(&self, _ctx: &MarketContext) -> bool {
+            true
+        }
+
+        fn detect(&self, ctx: &dyn DetectionContext) -> Vec<Opportunity> {
+            self.opportunities
+                .iter()
+                .filter(|opp| opp.market_id() == ctx.market_id())
+                .cloned()
+                .collect()
+        }
+    }
+
+    fn binary_market(id: &str) -> Market {
+        Market::new(
+            MarketId::from(id),
+            "Will it happen?",
+            vec![
+                Outcome::new(TokenId::from(format!("{id}-yes")), "Yes"),
+                Outcome::new(TokenId::from(format!("{id}-no")), "No"),
+            ],
+            dec!(1),
+        )
+    }
+
+    fn opportunity(market: &str, yes: Decimal, no: Decimal) -> Opportunity {
+        Opportunity::new(
+            MarketId::from(market),
+            "Will it happen?",
+            vec![
+                OpportunityLeg::new(TokenId::from(format!("{market}-yes")), yes),
+                OpportunityLeg::new(TokenId::from(format!("{market}-no")), no),
+            ],
+            dec!(100),
+            dec!(1),
+        )
+    }
+
+    #[test]
+    fn test_detect_markets_tags_strategy_and_keeps_best_edge() {
+        let mut markets = MarketRegistry::new();
+        markets.add(binary_market("m1"));
+        markets.add(binary_market("m2"));
+
+        let mut registry = StrategyRegistry::new();
+        registry.register(Box::new(FixedStrategy {
+            name: "first",
+            opportunities: vec![
+                opportunity("m1", dec!(0.45), dec!(0.50)),
+                opportunity("m2", dec!(0.40), dec!(0.50)),
+            ],
+        }));
+        registry.register(Box::new(FixedStrategy {
+            name: "second",
+            opportunities: vec![opportunity("m1", dec!(0.40), dec!(0.50))],
+        }));
+
+        let found = registry.detect_markets(&markets, &BookCache::new());
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].market_id().as_str(), "m1");
+        assert_eq!(found[0].strategy(), "second");
+        assert_eq!(found[0].edge(), dec!(0.10));
+        assert_eq!(found[1].market_id().as_str(), "m2");
+        assert_eq!(found[1].strategy(), "first");
+    }
+
+    #[test]
+    fn test_detect_markets_preserves_existing_strategy_name() {
+        let mut markets = MarketRegistry::new();
+        markets.add(binary_market("m1"));
+
+        let mut registry = StrategyRegistry::new();
+        registry.register(Box::new(FixedStrategy {
+            name: "wrapper",
+            opportunities: vec![
+                opportunity("m1", dec!(0.45), dec!(0.50)).with_strategy_name("inner")
+            ],
+        }));
+
+        let found = registry.detect_markets(&markets, &BookCache::new());
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].strategy(), "inner");
+    }
+
+    /// Strategy that counts how often `applies_to` is consulted.
+    struct CountingStrategy {
+        name: &'static str,
+        applies_calls: Arc<std::sync::atomic::AtomicUsize>,
+        opportunities: Vec<Opportunity>,
+    }
+
+    impl Strategy for CountingStrategy {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn applies_to(&self, _ctx: &MarketContext) -> bool {
+            self.applies_calls
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            true
+        }
+
+        fn detect(&self, _ctx: &dyn DetectionContext) -> Vec<Opportunity> {
+            self.opportunities.clone()
+        }
+    }
+
+    #[test]
+    fn test_disabled_strategy_is_skipped_until_reenabled() {
+        let mut markets = MarketRegistry::new();
+        markets.add(binary_market("m1"));
+
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut registry = StrategyRegistry::new();
+        registry.register(Box::new(CountingStrategy {
+            name: "counted",
+            applies_calls: Arc::clone(&calls),
+            opportunities: vec![opportunity("m1", dec!(0.45), dec!(0.50))],
+        }));
+        let toggles = registry.toggles();
+
+        toggles.set_strategy_enabled("counted", false).unwrap();
+        assert!(!registry.is_enabled("counted"));
+        assert!(registry
+            .detect_markets(&markets, &BookCache::new())
+            .is_empty());
+        assert_eq!(calls.load(std::sync::atomic::Ordering::Relaxed), 0);
+
+        toggles.set_strategy_enabled("counted", true).unwrap();
+        assert_eq!(
+            registry.detect_markets(&markets, &BookCache::new()).len(),
+            1
+        );
+        assert_eq!(calls.load(std::sync::atomic::Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_toggles_list_strategies_in_order() {
+        let mut registry = StrategyRegistry::new();
+        for name in ["first", "second"] {
+            registry.register(Box::new(MockStrategy {
+                name,
+                applies: true,
+            }));
+        }
+        let toggles = registry.toggles();
+
+        let status = toggles.set_strategy_enabled("second", false).unwrap();
+        assert_eq!(
+            status,
+            RuntimeStrategyStatus {
+                name: "second",
+                enabled: false
+            }
+        );
+        assert_eq!(
+            toggles.strategies(),
+            vec![
+                RuntimeStrategyStatus {
+                    name: "first",
+                    enabled: true
+                },
+                RuntimeStrategyStatus {
+                    name: "second",
+                    enabled: false
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_toggles_reject_unknown_strategy() {
+        let mut registry = StrategyRegistry::new();
+        registry.register(Box::new(MockStrategy {
+            name: "known",
+            applies: true,
+        }));
+
+        let err = registry
+            .toggles()
+            .set_strategy_enabled("missing", false)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "unknown strategy `missing`");
+        assert!(registry.is_enabled("known"));
+    }
+
+    #[test]
+    fn test_builder_wraps_strategies_in_ensemble() {
+        let registry = StrategyRegistry::builder()
+            .single_condition(SingleConditionConfig::default())
+            .market_rebalancing(MarketRebalancingConfig::default())
+            .ensemble(EnsemblePolicy::All)
+            .build();
+
+        assert_eq!(registry.strategy_names(), ["ensemble"]);
+    }
 }
@@ -1,24 +1,29 @@
 //! Single-condition arbitrage strategy.
 //!
-//! Detects when YES + NO < $1.00 for binary markets, indicating risk-free
-//! profit by purchasing both outcomes.
+//! Detects when YES + NO costs less than the market's payout (usually
+//! $1.00) for binary markets, indicating risk-free profit by purchasing
+//! both outcomes. Markets with a non-standard payout, such as scalar
+//! markets paying $100, use their own payout.
 //!
 //! Historical data shows this strategy captured 26.7% ($10.5M) of arbitrage
 //! profits, making it the second-largest contributor after market rebalancing.
 
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::domain::opportunity::Opportunity;
+use super::fee::FeeModel;
+use crate::domain::opportunity::{HoldPolicy, Opportunity, OpportunityLeg};
+use crate::domain::quote::{select_quote, QuoteSelection};
 use crate::port::{
     inbound::strategy::DetectionContext, inbound::strategy::MarketContext,
     inbound::strategy::Strategy,
 };
 
 /// Configuration for single-condition arbitrage detection.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SingleConditionConfig {
-    /// Minimum edge (profit per dollar) required to consider an opportunity.
+    /// Minimum edge (profit per share, in payout units) required to consider
+    /// an opportunity. YES + NO must cost less than `payout - min_edge`.
     /// Helps filter out opportunities where transaction costs exceed profit.
     #[serde(default = "default_min_edge")]
     pub min_edge: Decimal,
@@ -27,6 +32,27 @@ pub struct SingleConditionConfig {
     /// Prevents trading on opportunities too small to be worthwhile.
     #[serde(default = "default_min_profit")]
     pub min_profit: Decimal,
+
+    /// How to choose between price sources when an outcome has several.
+    /// Each leg records the source it will execute against.
+    #[serde(default)]
+    pub quote_selection: QuoteSelection,
+
+    /// Execution costs subtracted from the gross edge before `min_edge`
+    /// and `min_profit` are checked.
+    #[serde(default)]
+    pub fees: FeeModel,
+
+    /// Minimum dollar depth (size times price) each side must offer.
+    /// Opportunities with a thinner side are skipped. Zero disables the check.
+    #[serde(default)]
+    pub min_liquidity_per_leg: Decimal,
+
+    /// Whether positions are held to settlement or may exit early.
+    /// Defaults to exiting early, so a YES/NO pair whose prices converge
+    /// can be sold before resolution when exits are configured.
+    #[serde(default = "default_hold_policy")]
+    pub hold_policy: HoldPolicy,
 }
 
 fn default_min_edge() -> Decimal {
@@ -37,11 +63,19 @@ fn default_min_profit() -> Decimal {
     Decimal::new(10, 2) // 0.10
 }
 
+const fn default_hold_policy() -> HoldPolicy {
+    HoldPolicy::ExitEarly
+}
+
 impl Default for SingleConditionConfig {
     fn default() -> Self {
         Self {
             min_edge: default_min_edge(),
             min_profit: default_min_profit(),
+            quote_selection: QuoteSelection::default(),
+            fees: FeeModel::default(),
+            min_liquidity_per_leg: Decimal::ZERO,
+            hold_policy: default_hold_policy(),
         }
     }
 }
@@ -90,12 +124,14 @@ impl Strategy for SingleConditionStrategy {
 /// Core detection logic for single-condition arbitrage.
 ///
 /// Checks if the sum of ask prices for both outcomes is less than the
-/// guaranteed payout, indicating a risk-free profit opportunity.
+/// market's payout minus `min_edge` and fees, indicating a risk-free profit
+/// opportunity.
 ///
 /// Returns `None` if:
 /// - The market does not have exactly 2 outcomes
 /// - Any required order book is missing or has no asks
-/// - The edge is below the configured minimum
+/// - Either side's ask depth is worth less than `min_liquidity_per_leg`
+/// - The edge net of fees is below the configured minimum
 /// - The expected profit is below the configured minimum
 pub fn detect_single_condition(
     ctx: &dyn DetectionContext,
@@ -112,14 +148,21 @@ pub fn detect_single_condition(
     let positive_outcome = &outcomes[0];
     let negative_outcome = &outcomes[1];
 
-    // Get order books and best asks
-    let positive_book = ctx.order_book(positive_outcome.token_id())?;
-    let negative_book = ctx.order_book(negative_outcome.token_id())?;
+    // Pick the executable quote for each side
+    let positive_quotes = ctx.quotes(positive_outcome.token_id());
+    let negative_quotes = ctx.quotes(negative_outcome.token_id());
 
-    let positive_ask = positive_book.best_ask()?;
-    let negative_ask = negative_book.best_ask()?;
+    let positive_ask = select_quote(&positive_quotes, config.quote_selection)?;
+    let negative_ask = select_quote(&negative_quotes, config.quote_selection)?;
 
-    let total_cost = positive_ask.price() + negative_ask.price();
+    if [positive_ask, negative_ask]
+        .iter()
+        .any(|quote| quote.ask() * quote.size() < config.min_liquidity_per_leg)
+    {
+        return None;
+    }
+
+    let total_cost = positive_ask.ask() + negative_ask.ask();
     let payout = ctx.payout();
 
     // No arbitrage if cost >= payout
@@ -127,7 +170,8 @@ pub fn detect_single_condition(
         return None;
     }
 
-    let edge = payout - total_cost;
+    let fees = config.fees.cost(2);
+    let edge = payout - total_cost - fees;
 
     // Skip if edge too small
     if edge < config.min_edge {
@@ -144,20 +188,26 @@ pub fn detect_single_condition(
     }
 
     // Build opportunity
-    use crate::domain::opportunity::OpportunityLeg;
     let legs = vec![
-        OpportunityLeg::new(positive_outcome.token_id().clone(), positive_ask.price()),
-        OpportunityLeg::new(negative_outcome.token_id().clone(), negative_ask.price()),
+        OpportunityLeg::new(positive_outcome.token_id().clone(), positive_ask.ask())
+            .with_source(positive_ask.source()),
+        OpportunityLeg::new(negative_outcome.token_id().clone(), negative_ask.ask())
+            .with_source(negative_ask.source()),
     ];
 
-    Some(Opportunity::with_strategy(
-        ctx.market_id().clone(),
-        ctx.question(),
-        legs,
-        volume,
-        payout,
-        "single_condition",
-    ))
+    Some(
+        Opportunity::with_strategy(
+            ctx.market_id().clone(),
+            ctx.question(),
+            legs,
+            volume,
+            payout,
+            "single_condition",
+        )
+        .with_fees(fees)
+        .with_min_edge(config.min_edge)
+        .with_hold_policy(config.hold_policy),
+    )
 }
 
 #[cfg(test)]
@@ -187,6 +237,10 @@ mod tests {
         SingleConditionConfig {
             min_edge: dec!(0.05),
             min_profit: dec!(0.50),
+            quote_selection: QuoteSelection::CheapestAsk,
+            fees: FeeModel::default(),
+            min_liquidity_per_leg: Decimal::ZERO,
+            hold_policy: HoldPolicy::ExitEarly,
         }
     }
 
@@ -342,6 +396,33 @@ mod tests {
         assert_eq!(opp.expected_profit(), dec!(5.00)); // 50 * 0.10
     }
 
+    #[test]
+    fn test_legs_record_execution_source() {
+        let market = make_market();
+        let cache = BookCache::new();
+        let config = make_config();
+
+        let outcomes = market.outcomes();
+        cache.update(Book::with_levels(
+            outcomes[0].token_id().clone(),
+            vec![],
+            vec![PriceLevel::new(dec!(0.40), dec!(100))],
+        ));
+        cache.update(Book::with_levels(
+            outcomes[1].token_id().clone(),
+            vec![],
+            vec![PriceLevel::new(dec!(0.50), dec!(100))],
+        ));
+
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+        let opp = detect_single_condition(&ctx, &config).unwrap();
+
+        assert!(opp
+            .legs()
+            .iter()
+            .all(|leg| leg.source() == crate::domain::quote::PRIMARY_BOOK_SOURCE));
+    }
+
     #[test]
     fn test_strategy_detect_uses_context() {
         let strategy = SingleConditionStrategy::new(make_config());
@@ -375,6 +456,10 @@ mod tests {
         let strategy = SingleConditionStrategy::new(SingleConditionConfig {
             min_edge: dec!(5.00), // $5 minimum edge
             min_profit: dec!(0.50),
+            quote_selection: QuoteSelection::CheapestAsk,
+            fees: FeeModel::default(),
+            min_liquidity_per_leg: Decimal::ZERO,
+            hold_policy: HoldPolicy::ExitEarly,
         });
 
         let market_outcomes = vec![
@@ -435,4 +520,158 @@ mod tests {
         assert_eq!(opp.edge(), dec!(10));
         assert_eq!(opp.payout(), dec!(100));
     }
+
+    /// Binary market paying $100 with the given YES and NO asks.
+    fn hundred_dollar_market(yes_ask: Decimal, no_ask: Decimal) -> (Market, BookCache) {
+        let market = Market::new(
+            MarketId::from("scalar-market"),
+            "Scalar question?",
+            vec![
+                Outcome::new(TokenId::from("yes-token"), "Yes"),
+                Outcome::new(TokenId::from("no-token"), "No"),
+            ],
+            dec!(100),
+        );
+        let cache = BookCache::new();
+        cache.update(Book::with_levels(
+            TokenId::from("yes-token"),
+            vec![],
+            vec![PriceLevel::new(yes_ask, dec!(10))],
+        ));
+        cache.update(Book::with_levels(
+            TokenId::from("no-token"),
+            vec![],
+            vec![PriceLevel::new(no_ask, dec!(10))],
+        ));
+        (market, cache)
+    }
+
+    #[test]
+    fn test_hundred_dollar_payout_threshold_is_payout_minus_min_edge() {
+        let config = SingleConditionConfig {
+            min_edge: dec!(5),
+            ..make_config()
+        };
+
+        // $95 total sits exactly at payout - min_edge
+        let (market, cache) = hundred_dollar_market(dec!(45), dec!(50));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+        let opp = detect_single_condition(&ctx, &config).unwrap();
+        assert_eq!(opp.payout(), dec!(100));
+        assert_eq!(opp.total_cost(), dec!(95));
+        assert_eq!(opp.edge(), dec!(5));
+        assert_eq!(opp.expected_profit(), dec!(50));
+
+        // $96 total leaves a $4 edge, below the $5 minimum
+        let (market, cache) = hundred_dollar_market(dec!(46), dec!(50));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+        assert!(detect_single_condition(&ctx, &config).is_none());
+    }
+
+    #[test]
+    fn test_opportunity_carries_configured_hold_policy() {
+        let (market, cache) = hundred_dollar_market(dec!(45), dec!(50));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        let opp = detect_single_condition(&ctx, &make_config()).unwrap();
+        assert_eq!(opp.hold_policy(), HoldPolicy::ExitEarly);
+
+        let config = SingleConditionConfig {
+            hold_policy: HoldPolicy::HoldToSettlement,
+            ..make_config()
+        };
+        let opp = detect_single_condition(&ctx, &config).unwrap();
+        assert_eq!(opp.hold_policy(), HoldPolicy::HoldToSettlement);
+    }
+
+    #[test]
+    fn test_hundred_dollar_payout_detects_costs_above_one_dollar() {
+        // $60 + $38 = $98 would never be arbitrage against a $1 payout
+        let (market, cache) = hundred_dollar_market(dec!(60), dec!(38));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        let opp = detect_single_condition(&ctx, &make_config()).unwrap();
+
+        assert_eq!(opp.edge(), dec!(2));
+        assert_eq!(opp.payout(), dec!(100));
+    }
+
+    #[test]
+    fn test_hundred_dollar_payout_rejects_cost_at_payout() {
+        let (market, cache) = hundred_dollar_market(dec!(50), dec!(50));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        assert!(detect_single_condition(&ctx, &make_config()).is_none());
+    }
+
+    #[test]
+    fn test_fees_push_edge_below_threshold() {
+        let market = make_market();
+        let cache = BookCache::new();
+        let outcomes = market.outcomes();
+
+        // 0.46 + 0.50 = 0.96 (4% gross edge)
+        cache.update(Book::with_levels(
+            outcomes[0].token_id().clone(),
+            vec![],
+            vec![PriceLevel::new(dec!(0.46), dec!(100))],
+        ));
+        cache.update(Book::with_levels(
+            outcomes[1].token_id().clone(),
+            vec![],
+            vec![PriceLevel::new(dec!(0.50), dec!(100))],
+        ));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        let config = SingleConditionConfig {
+            min_edge: dec!(0.03),
+            ..make_config()
+        };
+        let opp = detect_single_condition(&ctx, &config).unwrap();
+        assert_eq!(opp.edge(), dec!(0.04));
+
+        // A 2% fee leaves a 2% net edge, below the 3% threshold
+        let config = SingleConditionConfig {
+            fees: FeeModel::new(dec!(0.02), Decimal::ZERO),
+            ..config
+        };
+        assert!(detect_single_condition(&ctx, &config).is_none());
+
+        // A smaller fee still clears, and the opportunity reports the net edge
+        let config = SingleConditionConfig {
+            fees: FeeModel::new(Decimal::ZERO, dec!(0.0025)),
+            ..config
+        };
+        let opp = detect_single_condition(&ctx, &config).unwrap();
+        assert_eq!(opp.edge(), dec!(0.035));
+        assert_eq!(opp.expected_profit(), dec!(3.50));
+    }
+
+    #[test]
+    fn test_rejects_side_below_liquidity_floor() {
+        let market = make_market();
+        let cache = BookCache::new();
+        let outcomes = market.outcomes();
+
+        // The NO side offers 10 shares at 0.50: $5 of depth
+        cache.update(Book::with_levels(
+            outcomes[0].token_id().clone(),
+            vec![],
+            vec![PriceLevel::new(dec!(0.40), dec!(500))],
+        ));
+        cache.update(Book::with_levels(
+            outcomes[1].token_id().clone(),
+            vec![],
+            vec![PriceLevel::new(dec!(0.50), dec!(10))],
+        ));
+        let ctx = ConcreteDetectionContext::new(&market, &cache);
+
+        assert!(detect_single_condition(&ctx, &make_config()).is_some());
+
+        let config = SingleConditionConfig {
+            min_liquidity_per_leg: dec!(50),
+            ..make_config()
+        };
+        assert!(detect_single_condition(&ctx, &config).is_none());
+    }
 }
@@ -4,6 +4,7 @@
 //!
 //! - [`PriceLevel`] - A single price level with size
 //! - [`Book`] - Complete order book for a single token
+//! - [`BookSide`] - Bid or ask side of a book
 //!
 //! # Order Book Structure
 //!
@@ -33,11 +34,26 @@
 //!
 //! assert_eq!(book.best_bid().unwrap().price(), dec!(0.45));
 //! assert_eq!(book.best_ask().unwrap().price(), dec!(0.46));
+//! assert_eq!(book.spread(), Some(dec!(0.01)));
+//! assert_eq!(book.mid_price(), Some(dec!(0.455)));
 //! ```
 
+use std::cmp::Ordering;
+
+use rust_decimal::Decimal;
+
 use super::id::TokenId;
 use super::money::{Price, Volume};
 
+/// One side of an order book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookSide {
+    /// Buy orders.
+    Bid,
+    /// Sell orders.
+    Ask,
+}
+
 /// A single price level in an order book.
 ///
 /// Represents aggregated orders at a specific price point.
@@ -139,4 +155,217 @@ impl Book {
     pub fn best_ask(&self) -> Option<&PriceLevel> {
         self.asks.first()
     }
+
+    /// Returns the best ask minus the best bid.
+    ///
+    /// Returns `None` unless both sides have at least one level.
+    #[must_use]
+    pub fn spread(&self) -> Option<Price> {
+        Some(self.best_ask()?.price() - self.best_bid()?.price())
+    }
+
+    /// Returns the midpoint between the best bid and best ask.
+    ///
+    /// Returns `None` unless both sides have at least one level.
+    #[must_use]
+    pub fn mid_price(&self) -> Option<Price> {
+        Some((self.best_ask()?.price() + self.best_bid()?.price()) / Decimal::TWO)
+    }
+
+    /// Returns true if the best bid is at or above the best ask.
+    ///
+    /// Covers both crossed (bid above ask) and locked (bid equal to ask)
+    /// books. Neither can rest on a working exchange, so such a book is
+    /// stale or corrupt and its prices cannot be traded.
+    #[must_use]
+    pub fn is_crossed(&self) -> bool {
+        self.spread().is_some_and(|spread| spread <= Decimal::ZERO)
+    }
+
+    /// Returns the total volume resting on one side of the book.
+    #[must_use]
+    pub fn total_depth(&self, side: BookSide) -> Volume {
+        let levels = match side {
+            BookSide::Bid => &self.bids,
+            BookSide::Ask => &self.asks,
+        };
+        levels.iter().map(PriceLevel::size).sum()
+    }
+
+    /// Keeps at most `max_depth` of the best levels on each side.
+    pub fn truncate(&mut self, max_depth: usize) {
+        self.bids.truncate(max_depth);
+        self.asks.truncate(max_depth);
+    }
+
+    /// Merges incremental price level changes into this book.
+    ///
+    /// Each level in `delta` sets the size at its price: existing levels are
+    /// overwritten, levels with zero size are removed, and new levels are
+    /// inserted in sorted order.
+    pub fn apply_delta(&mut self, delta: &Self) {
+        for level in &delta.bids {
+            merge_level(&mut self.bids, level, |existing, price| price.cmp(existing));
+        }
+        for level in &delta.asks {
+            merge_level(&mut self.asks, level, |existing, price| existing.cmp(price));
+        }
+    }
+}
+
+/// Apply one level update to a side sorted according to `order`.
+fn merge_level(
+    levels: &mut Vec<PriceLevel>,
+    update: &PriceLevel,
+    order: impl Fn(&Price, &Price) -> Ordering,
+) {
+    let position = levels.binary_search_by(|level| order(&level.price, &update.price));
+    let remove = update.size <= Decimal::ZERO;
+    match position {
+        Ok(index) if remove => {
+            levels.remove(index);
+        }
+        Ok(index) => levels[index].size = update.size,
+        Err(_) if remove => {}
+        Err(index) => levels.insert(index, update.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn prices(levels: &[PriceLevel]) -> Vec<(Price, Volume)> {
+        levels.iter().map(|l| (l.price(), l.size())).collect()
+    }
+
+    fn top(bid: Price, ask: Price) -> Book {
+        Book::with_levels(
+            TokenId::new("t"),
+            vec![PriceLevel::new(bid, dec!(100))],
+            vec![PriceLevel::new(ask, dec!(100))],
+        )
+    }
+
+    #[test]
+    fn is_crossed_flags_crossed_and_locked_books() {
+        assert!(top(dec!(0.52), dec!(0.50)).is_crossed());
+        assert!(top(dec!(0.50), dec!(0.50)).is_crossed());
+        assert!(!top(dec!(0.49), dec!(0.50)).is_crossed());
+    }
+
+    #[test]
+    fn one_sided_book_is_not_crossed() {
+        let book = Book::with_levels(
+            TokenId::new("t"),
+            vec![PriceLevel::new(dec!(0.50), dec!(100))],
+            vec![],
+        );
+        assert!(!book.is_crossed());
+    }
+
+    #[test]
+    fn apply_delta_merges_levels_in_order() {
+        let mut book = Book::with_levels(
+            TokenId::new("t"),
+            vec![
+                PriceLevel::new(dec!(0.45), dec!(100)),
+                PriceLevel::new(dec!(0.43), dec!(50)),
+            ],
+            vec![
+                PriceLevel::new(dec!(0.47), dec!(100)),
+                PriceLevel::new(dec!(0.50), dec!(80)),
+            ],
+        );
+        let delta = Book::with_levels(
+            TokenId::new("t"),
+            vec![
+                PriceLevel::new(dec!(0.44), dec!(25)),
+                PriceLevel::new(dec!(0.45), dec!(0)),
+            ],
+            vec![
+                PriceLevel::new(dec!(0.47), dec!(40)),
+                PriceLevel::new(dec!(0.48), dec!(10)),
+            ],
+        );
+
+        book.apply_delta(&delta);
+
+        assert_eq!(
+            prices(book.bids()),
+            vec![(dec!(0.44), dec!(25)), (dec!(0.43), dec!(50))]
+        );
+        assert_eq!(
+            prices(book.asks()),
+            vec![
+                (dec!(0.47), dec!(40)),
+                (dec!(0.48), dec!(10)),
+                (dec!(0.50), dec!(80)),
+            ]
+        );
+    }
+
+    #[test]
+    fn apply_delta_ignores_removal_of_missing_level() {
+        let mut book = Book::with_levels(
+            TokenId::new("t"),
+            vec![],
+            vec![PriceLevel::new(dec!(0.47), dec!(100))],
+        );
+        let delta = Book::with_levels(
+            TokenId::new("t"),
+            vec![PriceLevel::new(dec!(0.40), dec!(0))],
+            vec![PriceLevel::new(dec!(0.49), dec!(0))],
+        );
+
+        book.apply_delta(&delta);
+
+        assert!(book.bids().is_empty());
+        assert_eq!(prices(book.asks()), vec![(dec!(0.47), dec!(100))]);
+    }
+
+    #[test]
+    fn empty_book_has_no_spread_mid_or_depth() {
+        let book = Book::new(TokenId::new("t"));
+
+        assert_eq!(book.spread(), None);
+        assert_eq!(book.mid_price(), None);
+        assert_eq!(book.total_depth(BookSide::Bid), Decimal::ZERO);
+        assert_eq!(book.total_depth(BookSide::Ask), Decimal::ZERO);
+    }
+
+    #[test]
+    fn one_sided_book_has_depth_but_no_spread_or_mid() {
+        let book = Book::with_levels(
+            TokenId::new("t"),
+            vec![],
+            vec![
+                PriceLevel::new(dec!(0.47), dec!(100)),
+                PriceLevel::new(dec!(0.50), dec!(80)),
+            ],
+        );
+
+        assert_eq!(book.spread(), None);
+        assert_eq!(book.mid_price(), None);
+        assert_eq!(book.total_depth(BookSide::Bid), Decimal::ZERO);
+        assert_eq!(book.total_depth(BookSide::Ask), dec!(180));
+    }
+
+    #[test]
+    fn two_sided_book_reports_spread_mid_and_depth() {
+        let book = Book::with_levels(
+            TokenId::new("t"),
+            vec![
+                PriceLevel::new(dec!(0.45), dec!(100)),
+                PriceLevel::new(dec!(0.43), dec!(50.5)),
+            ],
+            vec![PriceLevel::new(dec!(0.48), dec!(30))],
+        );
+
+        assert_eq!(book.spread(), Some(dec!(0.03)));
+        assert_eq!(book.mid_price(), Some(dec!(0.465)));
+        assert_eq!(book.total_depth(BookSide::Bid), dec!(150.5));
+        assert_eq!(book.total_depth(BookSide::Ask), dec!(30));
+    }
 }
@@ -101,6 +101,18 @@ impl Cluster {
         }
     }
 
+    /// Rebuilds a previously persisted cluster from its relations.
+    ///
+    /// Constraints are re-derived from the relations; the identifier and
+    /// update time are kept so TTL expiry continues from the original.
+    pub fn restore(id: ClusterId, relations: Vec<Relation>, updated_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            updated_at,
+            ..Self::from_relations(relations)
+        }
+    }
+
     /// Returns true if this cluster contains the specified market.
     pub fn contains_market(&self, market_id: &MarketId) -> bool {
         self.markets.iter().any(|m| m == market_id)
@@ -115,6 +127,16 @@ impl Cluster {
     pub fn constraint_count(&self) -> usize {
         self.constraints.len()
     }
+
+    /// Returns the lowest confidence among this cluster's relations.
+    ///
+    /// Returns `None` for a cluster without source relations.
+    pub fn min_confidence(&self) -> Option<f64> {
+        self.relations
+            .iter()
+            .map(|r| r.confidence)
+            .min_by(f64::total_cmp)
+    }
 }
 
 #[cfg(test)]
@@ -179,6 +201,31 @@ mod tests {
         assert_eq!(cluster.constraint_count(), 2);
     }
 
+    #[test]
+    fn cluster_min_confidence_is_lowest_relation() {
+        let relations = vec![
+            Relation::new(
+                RelationKind::Implies {
+                    if_yes: market("a"),
+                    then_yes: market("b"),
+                },
+                0.9,
+                "test",
+            ),
+            Relation::new(
+                RelationKind::MutuallyExclusive {
+                    markets: vec![market("b"), market("c")],
+                },
+                0.6,
+                "test",
+            ),
+        ];
+
+        let cluster = Cluster::from_relations(relations);
+
+        assert_eq!(cluster.min_confidence(), Some(0.6));
+    }
+
     #[test]
     fn cluster_markets_are_sorted() {
         let relations = vec![Relation::new(
@@ -209,4 +256,24 @@ mod tests {
 
         assert!(!cluster.contains_market(&market("c")));
     }
+
+    #[test]
+    fn restore_keeps_identity_and_rebuilds_constraints() {
+        let relations = vec![Relation::new(
+            RelationKind::MutuallyExclusive {
+                markets: vec![market("a"), market("b")],
+            },
+            0.9,
+            "test",
+        )];
+        let original = Cluster::from_relations(relations.clone());
+        let updated_at = original.updated_at - chrono::Duration::minutes(5);
+
+        let restored = Cluster::restore(original.id.clone(), relations, updated_at);
+
+        assert_eq!(restored.id, original.id);
+        assert_eq!(restored.updated_at, updated_at);
+        assert_eq!(restored.markets, original.markets);
+        assert_eq!(restored.constraint_count(), original.constraint_count());
+    }
 }
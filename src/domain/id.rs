@@ -251,6 +251,66 @@ impl From<&str> for ClusterId {
     }
 }
 
+/// Unique identifier for a detected opportunity.
+///
+/// Generated as UUID v4 when a strategy detects the opportunity and carried
+/// through recording, execution, and notifications so every log line and
+/// stored row for one opportunity can be traced back to the detection.
+///
+/// # Examples
+///
+/// ```
+/// use edgelord::domain::id::OpportunityId;
+///
+/// let first = OpportunityId::new();
+/// let second = OpportunityId::new();
+/// assert_ne!(first, second);
+///
+/// // Reconstruct from stored value
+/// let stored = OpportunityId::from(first.as_str());
+/// assert_eq!(stored, first);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OpportunityId(String);
+
+impl OpportunityId {
+    /// Creates a new opportunity identifier with a randomly generated UUID v4.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(uuid::Uuid::new_v4().to_string())
+    }
+
+    /// Returns the opportunity ID as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for OpportunityId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for OpportunityId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for OpportunityId {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+
+impl From<&str> for OpportunityId {
+    fn from(s: &str) -> Self {
+        Self(s.to_string())
+    }
+}
+
 /// Unique identifier for an exchange order.
 ///
 /// Order IDs are typically assigned by the exchange when an order is placed.
@@ -460,6 +520,22 @@ mod tests {
         assert_ne!(id1, id2);
     }
 
+    // OpportunityId tests
+    #[test]
+    fn opportunity_id_generates_unique_uuids() {
+        let id1 = OpportunityId::new();
+        let id2 = OpportunityId::new();
+        assert_ne!(id1, id2);
+        assert_eq!(id1.as_str().len(), 36);
+    }
+
+    #[test]
+    fn opportunity_id_from_str_round_trips() {
+        let id = OpportunityId::from("opp-stored");
+        assert_eq!(id.as_str(), "opp-stored");
+        assert_eq!(format!("{}", id), "opp-stored");
+    }
+
     // OrderId tests
     #[test]
     fn order_id_new_and_as_str() {
@@ -291,7 +291,7 @@ impl Market {
 /// let found = registry.get_by_market_id(&MarketId::new("market-1"));
 /// assert!(found.is_some());
 /// ```
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct MarketRegistry {
     /// Index from token ID to containing market.
     token_to_market: HashMap<TokenId, Market>,
@@ -323,6 +323,18 @@ impl MarketRegistry {
         self.markets.push(market);
     }
 
+    /// Removes a market and its token indices from the registry.
+    ///
+    /// Returns the removed market, or `None` if it was not registered.
+    pub fn remove(&mut self, market_id: &MarketId) -> Option<Market> {
+        let market = self.market_id_to_market.remove(market_id)?;
+        for outcome in market.outcomes() {
+            self.token_to_market.remove(outcome.token_id());
+        }
+        self.markets.retain(|m| m.market_id() != market_id);
+        Some(market)
+    }
+
     /// Looks up a market by its market ID.
     ///
     /// Returns `None` if no market with the given ID is registered.
@@ -607,6 +619,22 @@ mod tests {
         assert_eq!(market.market_id().as_str(), "m2");
     }
 
+    #[test]
+    fn registry_remove_drops_market_and_tokens() {
+        let mut registry = MarketRegistry::new();
+        registry.add(create_binary_market_with_id("m1", "yes-1", "no-1"));
+        registry.add(create_binary_market_with_id("m2", "yes-2", "no-2"));
+
+        let removed = registry.remove(&MarketId::from("m1"));
+
+        assert_eq!(removed.unwrap().market_id().as_str(), "m1");
+        assert_eq!(registry.len(), 1);
+        assert!(registry.get_by_market_id(&MarketId::from("m1")).is_none());
+        assert!(registry.get_by_token(&TokenId::from("yes-1")).is_none());
+        assert!(registry.get_by_token(&TokenId::from("yes-2")).is_some());
+        assert!(registry.remove(&MarketId::from("m1")).is_none());
+    }
+
     #[test]
     fn registry_binary_markets_filters_correctly() {
         let mut registry = MarketRegistry::new();
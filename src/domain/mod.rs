@@ -11,7 +11,9 @@
 //! - [`book`] - Order book price levels and depth
 //! - [`opportunity`] - Detected arbitrage opportunities
 //! - [`position`] - Open and closed trading positions
+//! - [`quote`] - Per-source leg quotes and execution source selection
 //! - [`trade`] - Trade execution results and fill information
+//! - [`trading`] - Whether trading is active, paused, or halted
 //! - [`relation`] - Logical relations between markets (implies, exclusive, etc.)
 //! - [`cluster`] - Groups of related markets with pre-computed constraints
 //! - [`constraint`] - Linear constraints for optimization problems
@@ -68,7 +70,9 @@ pub mod market;
 pub mod money;
 pub mod opportunity;
 pub mod position;
+pub mod quote;
 pub mod relation;
 pub mod score;
 pub mod stats;
 pub mod trade;
+pub mod trading;
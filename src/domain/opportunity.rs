@@ -6,9 +6,13 @@
 //!
 //! # Edge Calculation
 //!
-//! The "edge" is the profit per share: `payout - total_cost`.
+//! The "edge" is the profit per share: `payout - total_cost - fees`.
 //! For example, if YES costs $0.45 and NO costs $0.50 with a $1.00 payout,
-//! the edge is $1.00 - $0.95 = $0.05 per share.
+//! the edge is $1.00 - $0.95 = $0.05 per share before fees.
+//!
+//! Sell-side opportunities invert this: when every leg is an
+//! [`OrderSide::Sell`] at the best bid, the edge is
+//! `total_cost - payout - fees`, where `total_cost` is the summed proceeds.
 //!
 //! # Examples
 //!
@@ -38,16 +42,28 @@
 //! ```
 
 use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::result::Result;
+use std::time::Instant;
 
 use super::error::DomainError;
-use super::id::{MarketId, TokenId};
+use super::id::{ClusterId, MarketId, OpportunityId, TokenId};
 use super::money::Price;
+use super::quote::PRIMARY_BOOK_SOURCE;
+use super::trade::OrderSide;
+
+/// Width of the edge buckets hashed by [`Opportunity::content_hash`].
+///
+/// A tenth of a cent, so price noise below it does not change the hash.
+pub const CONTENT_HASH_EDGE_BUCKET: Decimal = dec!(0.001);
 
 /// A single leg of an opportunity representing one outcome to purchase.
 ///
 /// Each leg captures the token ID and current ask price for one outcome
-/// that must be purchased to complete the arbitrage.
+/// that must be purchased to complete the arbitrage, along with the price
+/// source the leg will execute against.
 ///
 /// # Examples
 ///
@@ -63,31 +79,103 @@ use super::money::Price;
 pub struct OpportunityLeg {
     /// Token ID of the outcome to purchase.
     token_id: TokenId,
-    /// Current ask price for this outcome.
+    /// Current ask price for this outcome, or the bid for a sell leg.
     ask_price: Price,
+    /// Price source this leg executes against.
+    source: String,
+    /// Whether this leg buys or sells the outcome.
+    side: OrderSide,
 }
 
 impl OpportunityLeg {
-    /// Creates a new opportunity leg.
+    /// Creates a new opportunity leg priced from the primary order book.
     #[must_use]
     pub fn new(token_id: TokenId, ask_price: Price) -> Self {
         Self {
             token_id,
             ask_price,
+            source: PRIMARY_BOOK_SOURCE.to_string(),
+            side: OrderSide::Buy,
+        }
+    }
+
+    /// Creates a sell leg priced at the best bid from the primary order book.
+    #[must_use]
+    pub fn sell(token_id: TokenId, bid_price: Price) -> Self {
+        Self {
+            side: OrderSide::Sell,
+            ..Self::new(token_id, bid_price)
         }
     }
 
+    /// Records the price source this leg executes against.
+    #[must_use]
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = source.into();
+        self
+    }
+
     /// Returns the token ID for this leg.
     #[must_use]
     pub fn token_id(&self) -> &TokenId {
         &self.token_id
     }
 
-    /// Returns the ask price for this leg.
+    /// Returns the ask price for this leg, or the bid for a sell leg.
     #[must_use]
     pub fn ask_price(&self) -> Price {
         self.ask_price
     }
+
+    /// Returns the price source this leg executes against.
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns whether this leg buys or sells the outcome.
+    #[must_use]
+    pub const fn side(&self) -> OrderSide {
+        self.side
+    }
+}
+
+/// Whether an opportunity may be traded or is only worth watching.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OpportunityKind {
+    /// Priced below payout and eligible for execution.
+    #[default]
+    Actionable,
+    /// Priced just above payout; surfaced for monitoring and never executed.
+    Watch,
+}
+
+/// Whether positions from an opportunity are held or may exit early.
+///
+/// Fully filled positions held to settlement collect the guaranteed payout
+/// and are skipped by stop-loss and take-profit checks when the exit policy
+/// exempts them. Positions that may exit early are monitored, so a price
+/// convergence can be captured before resolution at the cost of selling
+/// into the book.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HoldPolicy {
+    /// Hold the position until the market settles.
+    #[default]
+    HoldToSettlement,
+    /// Monitor the position for stop-loss and take-profit exits.
+    ExitEarly,
+}
+
+/// Cluster context for opportunities found by combinatorial detection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterContext {
+    /// Cluster the opportunity was found in.
+    pub cluster_id: ClusterId,
+    /// Markets in the cluster.
+    pub markets: Vec<MarketId>,
+    /// Frank-Wolfe gap between market prices and their projection.
+    pub gap: Decimal,
 }
 
 /// An arbitrage opportunity supporting any number of outcomes.
@@ -98,7 +186,7 @@ impl OpportunityLeg {
 ///
 /// Derived fields are calculated on access:
 /// - [`total_cost`](Self::total_cost): sum of all leg prices
-/// - [`edge`](Self::edge): payout minus total_cost (profit per share)
+/// - [`edge`](Self::edge): payout minus total_cost and fees (net profit per share)
 /// - [`expected_profit`](Self::expected_profit): edge times volume
 ///
 /// # Examples
@@ -125,6 +213,10 @@ impl OpportunityLeg {
 /// ```
 #[derive(Debug, Clone)]
 pub struct Opportunity {
+    /// Identifier generated at detection, used to trace the opportunity.
+    id: OpportunityId,
+    /// When the opportunity was detected.
+    detected_at: Instant,
     /// Market ID where the opportunity exists.
     market_id: MarketId,
     /// Human-readable market question.
@@ -137,6 +229,16 @@ pub struct Opportunity {
     payout: Decimal,
     /// Strategy that detected this opportunity.
     strategy: String,
+    /// Execution costs per share deducted from the edge.
+    fees: Decimal,
+    /// Whether this opportunity may be executed.
+    kind: OpportunityKind,
+    /// Cluster context when found by combinatorial detection.
+    cluster: Option<ClusterContext>,
+    /// Whether resulting positions are held to settlement.
+    hold_policy: HoldPolicy,
+    /// Smallest edge the detecting strategy accepts.
+    min_edge: Decimal,
 }
 
 impl Opportunity {
@@ -152,12 +254,19 @@ impl Opportunity {
         payout: Decimal,
     ) -> Self {
         Self {
+            id: OpportunityId::new(),
+            detected_at: Instant::now(),
             market_id,
             question: question.into(),
             legs,
             volume,
             payout,
             strategy: String::new(),
+            fees: Decimal::ZERO,
+            kind: OpportunityKind::Actionable,
+            cluster: None,
+            hold_policy: HoldPolicy::default(),
+            min_edge: Decimal::ZERO,
         }
     }
 
@@ -172,12 +281,19 @@ impl Opportunity {
         strategy: impl Into<String>,
     ) -> Self {
         Self {
+            id: OpportunityId::new(),
+            detected_at: Instant::now(),
             market_id,
             question: question.into(),
             legs,
             volume,
             payout,
             strategy: strategy.into(),
+            fees: Decimal::ZERO,
+            kind: OpportunityKind::Actionable,
+            cluster: None,
+            hold_policy: HoldPolicy::default(),
+            min_edge: Decimal::ZERO,
         }
     }
 
@@ -219,15 +335,90 @@ impl Opportunity {
         }
 
         Ok(Self {
+            id: OpportunityId::new(),
+            detected_at: Instant::now(),
             market_id,
             question: question.into(),
             legs,
             volume,
             payout,
             strategy: String::new(),
+            fees: Decimal::ZERO,
+            kind: OpportunityKind::Actionable,
+            cluster: None,
+            hold_policy: HoldPolicy::default(),
+            min_edge: Decimal::ZERO,
         })
     }
 
+    /// Records the strategy that detected this opportunity.
+    #[must_use]
+    pub fn with_strategy_name(mut self, strategy: impl Into<String>) -> Self {
+        self.strategy = strategy.into();
+        self
+    }
+
+    /// Records the per-share execution costs deducted from the edge.
+    #[must_use]
+    pub fn with_fees(mut self, fees: Decimal) -> Self {
+        self.fees = fees;
+        self
+    }
+
+    /// Replaces the number of shares to trade.
+    #[must_use]
+    pub fn with_volume(mut self, volume: Decimal) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    /// Marks whether this opportunity may be executed.
+    #[must_use]
+    pub fn with_kind(mut self, kind: OpportunityKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Attaches the cluster this opportunity was detected in.
+    #[must_use]
+    pub fn with_cluster(mut self, cluster: ClusterContext) -> Self {
+        self.cluster = Some(cluster);
+        self
+    }
+
+    /// Replaces the instant this opportunity was detected.
+    #[must_use]
+    pub fn with_detected_at(mut self, detected_at: Instant) -> Self {
+        self.detected_at = detected_at;
+        self
+    }
+
+    /// Sets whether resulting positions are held to settlement.
+    #[must_use]
+    pub fn with_hold_policy(mut self, hold_policy: HoldPolicy) -> Self {
+        self.hold_policy = hold_policy;
+        self
+    }
+
+    /// Sets the smallest edge the detecting strategy accepts.
+    #[must_use]
+    pub fn with_min_edge(mut self, min_edge: Decimal) -> Self {
+        self.min_edge = min_edge;
+        self
+    }
+
+    /// Returns the identifier generated when this opportunity was detected.
+    #[must_use]
+    pub fn id(&self) -> &OpportunityId {
+        &self.id
+    }
+
+    /// Returns when this opportunity was detected.
+    #[must_use]
+    pub fn detected_at(&self) -> Instant {
+        self.detected_at
+    }
+
     /// Returns the strategy name that detected this opportunity.
     #[must_use]
     pub fn strategy(&self) -> &str {
@@ -264,16 +455,111 @@ impl Opportunity {
         self.payout
     }
 
+    /// Returns the per-share execution costs deducted from the edge.
+    #[must_use]
+    pub fn fees(&self) -> Decimal {
+        self.fees
+    }
+
+    /// Returns whether this opportunity may be executed.
+    #[must_use]
+    pub fn kind(&self) -> OpportunityKind {
+        self.kind
+    }
+
+    /// Returns the cluster context for combinatorial opportunities.
+    #[must_use]
+    pub fn cluster(&self) -> Option<&ClusterContext> {
+        self.cluster.as_ref()
+    }
+
+    /// Returns whether resulting positions are held to settlement.
+    #[must_use]
+    pub fn hold_policy(&self) -> HoldPolicy {
+        self.hold_policy
+    }
+
+    /// Returns the smallest edge the detecting strategy accepts.
+    ///
+    /// Zero for strategies without an edge threshold.
+    #[must_use]
+    pub fn min_edge(&self) -> Decimal {
+        self.min_edge
+    }
+
+    /// Returns true if this opportunity is eligible for execution.
+    #[must_use]
+    pub fn is_actionable(&self) -> bool {
+        self.kind == OpportunityKind::Actionable
+    }
+
+    /// Returns true if every leg sells its outcome rather than buying it.
+    #[must_use]
+    pub fn is_sell(&self) -> bool {
+        !self.legs.is_empty() && self.legs.iter().all(|leg| leg.side == OrderSide::Sell)
+    }
+
     /// Calculates the total cost (sum of all leg prices).
+    ///
+    /// For sell-side opportunities this is the summed proceeds.
     #[must_use]
     pub fn total_cost(&self) -> Decimal {
         self.legs.iter().map(|leg| leg.ask_price).sum()
     }
 
-    /// Calculates the edge (payout minus total cost per share).
+    /// Calculates the net edge per share after fees.
+    ///
+    /// Payout minus total cost for buys; total proceeds minus payout for
+    /// sell-side opportunities.
     #[must_use]
     pub fn edge(&self) -> Decimal {
-        self.payout - self.total_cost()
+        if self.is_sell() {
+            return self.total_cost() - self.payout - self.fees;
+        }
+        self.payout - self.total_cost() - self.fees
+    }
+
+    /// Returns a stable hash of what this opportunity trades.
+    ///
+    /// Covers the market, each leg's token and side regardless of leg order,
+    /// the edge in [`CONTENT_HASH_EDGE_BUCKET`] buckets, and the volume
+    /// rounded to whole shares. Repeated detections of the same arbitrage
+    /// hash equally despite fresh identifiers, and the value is the same
+    /// across restarts.
+    #[must_use]
+    pub fn content_hash(&self) -> u64 {
+        self.content_hash_bucketed(CONTENT_HASH_EDGE_BUCKET)
+    }
+
+    /// Returns the [`content_hash`](Self::content_hash) with the edge
+    /// bucketed at `edge_bucket` instead of the default width.
+    ///
+    /// A zero or negative width hashes the exact edge.
+    #[must_use]
+    pub fn content_hash_bucketed(&self, edge_bucket: Decimal) -> u64 {
+        let mut legs: Vec<String> = self
+            .legs
+            .iter()
+            .map(|leg| format!("{}:{}", leg.token_id, leg.side.label()))
+            .collect();
+        legs.sort();
+
+        let edge_bucket = if edge_bucket > Decimal::ZERO {
+            (self.edge() / edge_bucket).floor()
+        } else {
+            self.edge()
+        };
+        let content = format!(
+            "{}|{}|{}|{}",
+            self.market_id,
+            legs.join(","),
+            edge_bucket.normalize(),
+            self.volume.round().normalize()
+        );
+        let digest = Sha256::digest(content.as_bytes());
+        let mut prefix = [0u8; 8];
+        prefix.copy_from_slice(&digest[..8]);
+        u64::from_be_bytes(prefix)
     }
 
     /// Calculates the expected profit (edge times volume).
@@ -281,6 +567,87 @@ impl Opportunity {
     pub fn expected_profit(&self) -> Decimal {
         self.edge() * self.volume
     }
+
+    /// Describes the arbitrage math in one line.
+    ///
+    /// For example `Bought A@0.30 + B@0.30 + C@0.30 = 0.90, payout 1.00,
+    /// edge 0.10 (10%), size 50, profit $5.00`. Cluster opportunities
+    /// summarize the cluster and its Frank-Wolfe gap instead, since their
+    /// legs are projected prices rather than quotes.
+    #[must_use]
+    pub fn explain(&self) -> String {
+        let legs = self
+            .legs
+            .iter()
+            .map(|leg| format!("{}@{}", short_token(&leg.token_id), amount(leg.ask_price)))
+            .collect::<Vec<_>>()
+            .join(" + ");
+
+        if let Some(cluster) = &self.cluster {
+            let mut markets = cluster
+                .markets
+                .iter()
+                .take(3)
+                .map(MarketId::as_str)
+                .collect::<Vec<_>>()
+                .join(", ");
+            if cluster.markets.len() > 3 {
+                markets.push_str(&format!(" +{}", cluster.markets.len() - 3));
+            }
+            return format!(
+                "Cluster {} over {} markets ({markets}), Frank-Wolfe gap {}, projected {legs}",
+                cluster.cluster_id,
+                cluster.markets.len(),
+                amount(cluster.gap)
+            );
+        }
+
+        let verb = if self.is_sell() { "Sold" } else { "Bought" };
+        let mut text = format!(
+            "{verb} {legs} = {}, payout {}",
+            amount(self.total_cost()),
+            amount(self.payout)
+        );
+        if !self.fees.is_zero() {
+            text.push_str(&format!(", fees {}", amount(self.fees)));
+        }
+        let edge_pct = if self.payout.is_zero() {
+            Decimal::ZERO
+        } else {
+            (self.edge() / self.payout * Decimal::ONE_HUNDRED).round_dp(2)
+        };
+        text.push_str(&format!(
+            ", edge {} ({}%), size {}, profit ${:.2}",
+            amount(self.edge()),
+            edge_pct.normalize(),
+            self.volume.normalize(),
+            self.expected_profit()
+        ));
+        if self.kind == OpportunityKind::Watch {
+            text.push_str(", watch only");
+        }
+        text
+    }
+}
+
+/// Format a price-like amount with at least two decimal places.
+fn amount(value: Decimal) -> String {
+    let value = value.normalize();
+    if value.scale() < 2 {
+        format!("{value:.2}")
+    } else {
+        value.to_string()
+    }
+}
+
+/// Shorten long token IDs so explanations stay readable.
+fn short_token(token_id: &TokenId) -> String {
+    let id = token_id.as_str();
+    if id.chars().count() <= 12 {
+        return id.to_string();
+    }
+    let head: String = id.chars().take(8).collect();
+    format!("{head}…")
 }
 
 #[cfg(test)]
@@ -302,6 +669,98 @@ mod tests {
 
         assert_eq!(leg.token_id().as_str(), "outcome-a");
         assert_eq!(leg.ask_price(), dec!(0.45));
+        assert_eq!(leg.source(), PRIMARY_BOOK_SOURCE);
+    }
+
+    #[test]
+    fn leg_with_source_records_source() {
+        let leg =
+            OpportunityLeg::new(make_token_id("outcome-a"), dec!(0.42)).with_source("secondary");
+
+        assert_eq!(leg.ask_price(), dec!(0.42));
+        assert_eq!(leg.source(), "secondary");
+    }
+
+    fn binary(legs: [(&str, Price); 2], volume: Decimal) -> Opportunity {
+        Opportunity::new(
+            make_market_id(),
+            "Will it rain?",
+            legs.iter()
+                .map(|(token, price)| OpportunityLeg::new(make_token_id(token), *price))
+                .collect(),
+            volume,
+            dec!(1.0),
+        )
+    }
+
+    #[test]
+    fn content_hash_ignores_leg_order_and_identity() {
+        let a = binary([("yes", dec!(0.40)), ("no", dec!(0.50))], dec!(100));
+        let b = binary([("no", dec!(0.50)), ("yes", dec!(0.40))], dec!(100));
+
+        assert_ne!(a.id(), b.id());
+        assert_eq!(a.content_hash(), b.content_hash());
+    }
+
+    #[test]
+    fn content_hash_buckets_edge_and_rounds_size() {
+        let base = binary([("yes", dec!(0.40)), ("no", dec!(0.50))], dec!(100));
+
+        // Sub-bucket price noise and fractional shares hash the same.
+        let noisy = binary([("yes", dec!(0.4000)), ("no", dec!(0.4996))], dec!(100.2));
+        assert_eq!(base.content_hash(), noisy.content_hash());
+
+        let wider = binary([("yes", dec!(0.39)), ("no", dec!(0.50))], dec!(100));
+        let larger = binary([("yes", dec!(0.40)), ("no", dec!(0.50))], dec!(150));
+        assert_ne!(base.content_hash(), wider.content_hash());
+        assert_ne!(base.content_hash(), larger.content_hash());
+    }
+
+    #[test]
+    fn content_hash_covers_market_and_side() {
+        let buy = binary([("yes", dec!(0.40)), ("no", dec!(0.50))], dec!(100));
+        let other_market = Opportunity::new(
+            MarketId::from("other-market"),
+            "Will it rain?",
+            buy.legs().to_vec(),
+            dec!(100),
+            dec!(1.0),
+        );
+        assert_ne!(buy.content_hash(), other_market.content_hash());
+
+        let sell = Opportunity::new(
+            make_market_id(),
+            "Will it rain?",
+            vec![
+                OpportunityLeg::sell(make_token_id("yes"), dec!(0.60)),
+                OpportunityLeg::sell(make_token_id("no"), dec!(0.50)),
+            ],
+            dec!(100),
+            dec!(1.0),
+        );
+        let buy_high = binary([("yes", dec!(0.35)), ("no", dec!(0.55))], dec!(100));
+        assert_eq!(sell.edge(), buy_high.edge());
+        assert_ne!(sell.content_hash(), buy_high.content_hash());
+    }
+
+    #[test]
+    fn content_hash_bucketed_uses_given_width() {
+        let base = binary([("yes", dec!(0.449)), ("no", dec!(0.50))], dec!(100));
+        let moved = binary([("yes", dec!(0.447)), ("no", dec!(0.50))], dec!(100));
+
+        assert_ne!(base.content_hash(), moved.content_hash());
+        assert_eq!(
+            base.content_hash_bucketed(dec!(0.005)),
+            moved.content_hash_bucketed(dec!(0.005))
+        );
+        assert_eq!(
+            base.content_hash_bucketed(CONTENT_HASH_EDGE_BUCKET),
+            base.content_hash()
+        );
+        assert_ne!(
+            base.content_hash_bucketed(Decimal::ZERO),
+            moved.content_hash_bucketed(Decimal::ZERO)
+        );
     }
 
     #[test]
@@ -378,6 +837,40 @@ mod tests {
         assert_eq!(opp.expected_profit(), dec!(10.00));
     }
 
+    #[test]
+    fn opportunities_are_actionable_by_default() {
+        let legs = vec![OpportunityLeg::new(make_token_id("yes"), dec!(0.40))];
+        let opp = Opportunity::new(make_market_id(), "Test", legs, dec!(10), dec!(1.0));
+
+        assert_eq!(opp.kind(), OpportunityKind::Actionable);
+        assert!(opp.is_actionable());
+    }
+
+    #[test]
+    fn watch_opportunity_is_not_actionable() {
+        let legs = vec![
+            OpportunityLeg::new(make_token_id("yes"), dec!(0.51)),
+            OpportunityLeg::new(make_token_id("no"), dec!(0.50)),
+        ];
+        let opp = Opportunity::new(make_market_id(), "Test", legs, dec!(10), dec!(1.0))
+            .with_kind(OpportunityKind::Watch);
+
+        assert!(!opp.is_actionable());
+        assert_eq!(opp.edge(), dec!(-0.01));
+    }
+
+    #[test]
+    fn opportunities_hold_to_settlement_by_default() {
+        let legs = vec![OpportunityLeg::new(make_token_id("yes"), dec!(0.40))];
+        let opp = Opportunity::new(make_market_id(), "Test", legs, dec!(10), dec!(1.0));
+
+        assert_eq!(opp.hold_policy(), HoldPolicy::HoldToSettlement);
+        assert_eq!(
+            opp.with_hold_policy(HoldPolicy::ExitEarly).hold_policy(),
+            HoldPolicy::ExitEarly
+        );
+    }
+
     #[test]
     fn three_outcome_market() {
         let legs = vec![
@@ -491,4 +984,138 @@ mod tests {
         let result = Opportunity::try_new(make_market_id(), "Test", legs, dec!(100), dec!(0.80));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn each_detection_gets_its_own_id() {
+        let legs = vec![
+            OpportunityLeg::new(make_token_id("yes"), dec!(0.40)),
+            OpportunityLeg::new(make_token_id("no"), dec!(0.50)),
+        ];
+        let first = Opportunity::new(make_market_id(), "Test", legs.clone(), dec!(100), dec!(1));
+        let second = Opportunity::new(make_market_id(), "Test", legs, dec!(100), dec!(1));
+
+        assert_ne!(first.id(), second.id());
+    }
+
+    #[test]
+    fn resizing_keeps_the_detection_id() {
+        let legs = vec![
+            OpportunityLeg::new(make_token_id("yes"), dec!(0.40)),
+            OpportunityLeg::new(make_token_id("no"), dec!(0.50)),
+        ];
+        let opp = Opportunity::new(make_market_id(), "Test", legs, dec!(100), dec!(1));
+        let id = opp.id().clone();
+        let detected_at = opp.detected_at();
+
+        let sized = opp.with_volume(dec!(25)).with_fees(dec!(0.01));
+
+        assert_eq!(sized.id(), &id);
+        assert_eq!(sized.detected_at(), detected_at);
+    }
+
+    #[test]
+    fn fees_reduce_edge_and_profit() {
+        let legs = vec![
+            OpportunityLeg::new(make_token_id("yes"), dec!(0.40)),
+            OpportunityLeg::new(make_token_id("no"), dec!(0.50)),
+        ];
+        let opp = Opportunity::new(make_market_id(), "Test", legs, dec!(100), dec!(1.00))
+            .with_fees(dec!(0.02));
+
+        assert_eq!(opp.total_cost(), dec!(0.90));
+        assert_eq!(opp.fees(), dec!(0.02));
+        assert_eq!(opp.edge(), dec!(0.08));
+        assert_eq!(opp.expected_profit(), dec!(8.00));
+    }
+
+    #[test]
+    fn sell_legs_earn_proceeds_above_payout() {
+        let legs = vec![
+            OpportunityLeg::sell(make_token_id("a"), dec!(0.40)),
+            OpportunityLeg::sell(make_token_id("b"), dec!(0.35)),
+            OpportunityLeg::sell(make_token_id("c"), dec!(0.30)),
+        ];
+        let opp = Opportunity::new(make_market_id(), "Test", legs, dec!(10), dec!(1.00))
+            .with_fees(dec!(0.01));
+
+        assert!(opp.is_sell());
+        assert_eq!(opp.legs()[0].side(), OrderSide::Sell);
+        assert_eq!(opp.total_cost(), dec!(1.05));
+        assert_eq!(opp.edge(), dec!(0.04));
+        assert_eq!(opp.expected_profit(), dec!(0.40));
+    }
+
+    #[test]
+    fn buy_legs_are_not_sell_side() {
+        let legs = vec![
+            OpportunityLeg::new(make_token_id("yes"), dec!(0.40)),
+            OpportunityLeg::new(make_token_id("no"), dec!(0.50)),
+        ];
+        let opp = Opportunity::new(make_market_id(), "Test", legs, dec!(100), dec!(1.00));
+
+        assert!(!opp.is_sell());
+        assert_eq!(opp.legs()[0].side(), OrderSide::Buy);
+    }
+
+    #[test]
+    fn explain_shows_buy_math() {
+        let legs = vec![
+            OpportunityLeg::new(make_token_id("A"), dec!(0.30)),
+            OpportunityLeg::new(make_token_id("B"), dec!(0.30)),
+            OpportunityLeg::new(make_token_id("C"), dec!(0.30)),
+        ];
+        let opp = Opportunity::new(make_market_id(), "Test", legs, dec!(50), dec!(1.00));
+
+        assert_eq!(
+            opp.explain(),
+            "Bought A@0.30 + B@0.30 + C@0.30 = 0.90, payout 1.00, edge 0.10 (10%), size 50, profit $5.00"
+        );
+    }
+
+    #[test]
+    fn explain_shows_sell_math_with_fees() {
+        let legs = vec![
+            OpportunityLeg::sell(make_token_id("a"), dec!(0.55)),
+            OpportunityLeg::sell(make_token_id("b"), dec!(0.50)),
+        ];
+        let opp = Opportunity::new(make_market_id(), "Test", legs, dec!(10), dec!(1.00))
+            .with_fees(dec!(0.01));
+
+        assert_eq!(
+            opp.explain(),
+            "Sold a@0.55 + b@0.50 = 1.05, payout 1.00, fees 0.01, edge 0.04 (4%), size 10, profit $0.40"
+        );
+    }
+
+    #[test]
+    fn explain_shortens_long_token_ids() {
+        let legs = vec![OpportunityLeg::new(
+            make_token_id(
+                "71321045679252212594626385532706912750332728571942532289631379312455583992563",
+            ),
+            dec!(0.455),
+        )];
+        let opp = Opportunity::new(make_market_id(), "Test", legs, dec!(1), dec!(1.00));
+
+        assert!(opp.explain().starts_with("Bought 71321045…@0.455 = 0.455"));
+    }
+
+    #[test]
+    fn explain_summarizes_cluster_and_gap() {
+        let legs = vec![
+            OpportunityLeg::new(make_token_id("a"), dec!(0.42)),
+            OpportunityLeg::new(make_token_id("b"), dec!(0.58)),
+        ];
+        let opp = Opportunity::new(make_market_id(), "Test", legs, dec!(0.05), dec!(1.00))
+            .with_cluster(ClusterContext {
+                cluster_id: ClusterId::from("c1"),
+                markets: vec![MarketId::from("m1"), MarketId::from("m2")],
+                gap: dec!(0.05),
+            });
+
+        assert_eq!(
+            opp.explain(),
+            "Cluster c1 over 2 markets (m1, m2), Frank-Wolfe gap 0.05, projected a@0.42 + b@0.58"
+        );
+    }
 }
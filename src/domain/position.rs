@@ -197,6 +197,8 @@ pub struct Position {
     status: PositionStatus,
     /// Associated trade ID for statistics tracking.
     trade_id: Option<i32>,
+    /// Whether this position is intended to be held until market settlement.
+    held_to_settlement: bool,
 }
 
 impl Position {
@@ -222,6 +224,7 @@ impl Position {
             opened_at,
             status,
             trade_id: None,
+            held_to_settlement: false,
         }
     }
 
@@ -232,6 +235,16 @@ impl Position {
         self
     }
 
+    /// Marks this position as held until market settlement.
+    ///
+    /// Settlement-held positions are exempt from stop-loss and take-profit
+    /// exits when the exit policy exempts them.
+    #[must_use]
+    pub const fn with_held_to_settlement(mut self) -> Self {
+        self.held_to_settlement = true;
+        self
+    }
+
     /// Creates a new position with domain invariant validation.
     ///
     /// # Domain Invariants
@@ -278,6 +291,7 @@ impl Position {
             opened_at,
             status,
             trade_id: None,
+            held_to_settlement: false,
         })
     }
 
@@ -329,6 +343,12 @@ impl Position {
         self.trade_id
     }
 
+    /// Returns true if this position is held until market settlement.
+    #[must_use]
+    pub const fn is_held_to_settlement(&self) -> bool {
+        self.held_to_settlement
+    }
+
     /// Calculates the expected profit (guaranteed payout minus entry cost).
     #[must_use]
     pub fn expected_profit(&self) -> Price {
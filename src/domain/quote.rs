@@ -0,0 +1,220 @@
+//! Price quotes and execution source selection for opportunity legs.
+//!
+//! An outcome can be priced by more than one source: several venues, or
+//! several views of the same book. A [`Quote`] captures the executable ask
+//! from one source, and [`select_quote`] picks the source a leg should execute
+//! against according to a [`QuoteSelection`] preference.
+//!
+//! # Examples
+//!
+//! ```
+//! use edgelord::domain::quote::{select_quote, Quote, QuoteSelection};
+//! use rust_decimal_macros::dec;
+//!
+//! let quotes = vec![
+//!     Quote::new("venue-a", Some(dec!(0.40)), dec!(0.46), dec!(100)),
+//!     Quote::new("venue-b", Some(dec!(0.43)), dec!(0.45), dec!(100)),
+//! ];
+//!
+//! let chosen = select_quote(&quotes, QuoteSelection::CheapestAsk).unwrap();
+//! assert_eq!(chosen.source(), "venue-b");
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use super::book::Book;
+use super::money::{Price, Volume};
+
+/// Source label for quotes taken from the primary order book's best ask.
+pub const PRIMARY_BOOK_SOURCE: &str = "book";
+
+/// Executable price for an outcome from a single source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quote {
+    /// Label identifying where this price comes from.
+    source: String,
+    /// Best bid at this source, if any.
+    bid: Option<Price>,
+    /// Best ask at this source (the price a buy executes against).
+    ask: Price,
+    /// Volume available at the ask.
+    size: Volume,
+}
+
+impl Quote {
+    /// Creates a new quote.
+    #[must_use]
+    pub fn new(source: impl Into<String>, bid: Option<Price>, ask: Price, size: Volume) -> Self {
+        Self {
+            source: source.into(),
+            bid,
+            ask,
+            size,
+        }
+    }
+
+    /// Builds a quote from the top of an order book.
+    ///
+    /// Returns `None` if the book has no asks.
+    #[must_use]
+    pub fn from_book(source: impl Into<String>, book: &Book) -> Option<Self> {
+        let ask = book.best_ask()?;
+        Some(Self::new(
+            source,
+            book.best_bid().map(|level| level.price()),
+            ask.price(),
+            ask.size(),
+        ))
+    }
+
+    /// Returns the source label.
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the best bid, if any.
+    #[must_use]
+    pub const fn bid(&self) -> Option<Price> {
+        self.bid
+    }
+
+    /// Returns the best ask.
+    #[must_use]
+    pub const fn ask(&self) -> Price {
+        self.ask
+    }
+
+    /// Returns the volume available at the ask.
+    #[must_use]
+    pub const fn size(&self) -> Volume {
+        self.size
+    }
+
+    /// Returns the bid-ask spread, or `None` when there is no bid.
+    #[must_use]
+    pub fn spread(&self) -> Option<Price> {
+        self.bid.map(|bid| self.ask - bid)
+    }
+}
+
+/// Preference used to choose between quotes for the same outcome.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteSelection {
+    /// Execute against the lowest ask.
+    #[default]
+    CheapestAsk,
+    /// Execute against the tightest bid-ask spread, breaking ties on ask.
+    ///
+    /// Quotes without a bid are treated as having the widest spread.
+    TightestSpread,
+}
+
+/// Selects the quote a leg should execute against.
+///
+/// Returns `None` if `quotes` is empty. Ties keep the earliest quote.
+#[must_use]
+pub fn select_quote(quotes: &[Quote], selection: QuoteSelection) -> Option<&Quote> {
+    quotes.iter().reduce(|best, candidate| {
+        let better = match selection {
+            QuoteSelection::CheapestAsk => candidate.ask < best.ask,
+            QuoteSelection::TightestSpread => match (candidate.spread(), best.spread()) {
+                (Some(c), Some(b)) if c != b => c < b,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                _ => candidate.ask < best.ask,
+            },
+        };
+        if better {
+            candidate
+        } else {
+            best
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::book::PriceLevel;
+    use crate::domain::id::TokenId;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn cheaper_ask_is_selected_between_two_sources() {
+        let quotes = vec![
+            Quote::new("primary", Some(dec!(0.40)), dec!(0.47), dec!(100)),
+            Quote::new("secondary", Some(dec!(0.40)), dec!(0.45), dec!(50)),
+        ];
+
+        let chosen = select_quote(&quotes, QuoteSelection::CheapestAsk).unwrap();
+
+        assert_eq!(chosen.source(), "secondary");
+        assert_eq!(chosen.ask(), dec!(0.45));
+    }
+
+    #[test]
+    fn tightest_spread_prefers_narrow_book() {
+        let quotes = vec![
+            Quote::new("wide", Some(dec!(0.30)), dec!(0.45), dec!(100)),
+            Quote::new("tight", Some(dec!(0.45)), dec!(0.46), dec!(100)),
+        ];
+
+        let chosen = select_quote(&quotes, QuoteSelection::TightestSpread).unwrap();
+
+        assert_eq!(chosen.source(), "tight");
+    }
+
+    #[test]
+    fn tightest_spread_breaks_ties_on_ask() {
+        let quotes = vec![
+            Quote::new("a", Some(dec!(0.44)), dec!(0.46), dec!(100)),
+            Quote::new("b", Some(dec!(0.43)), dec!(0.45), dec!(100)),
+        ];
+
+        let chosen = select_quote(&quotes, QuoteSelection::TightestSpread).unwrap();
+
+        assert_eq!(chosen.source(), "b");
+    }
+
+    #[test]
+    fn tightest_spread_ranks_missing_bid_last() {
+        let quotes = vec![
+            Quote::new("no-bid", None, dec!(0.40), dec!(100)),
+            Quote::new("with-bid", Some(dec!(0.30)), dec!(0.45), dec!(100)),
+        ];
+
+        let chosen = select_quote(&quotes, QuoteSelection::TightestSpread).unwrap();
+
+        assert_eq!(chosen.source(), "with-bid");
+    }
+
+    #[test]
+    fn empty_quotes_select_nothing() {
+        assert!(select_quote(&[], QuoteSelection::CheapestAsk).is_none());
+    }
+
+    #[test]
+    fn from_book_uses_top_of_book() {
+        let book = Book::with_levels(
+            TokenId::from("t"),
+            vec![PriceLevel::new(dec!(0.44), dec!(10))],
+            vec![PriceLevel::new(dec!(0.46), dec!(25))],
+        );
+
+        let quote = Quote::from_book(PRIMARY_BOOK_SOURCE, &book).unwrap();
+
+        assert_eq!(quote.source(), PRIMARY_BOOK_SOURCE);
+        assert_eq!(quote.bid(), Some(dec!(0.44)));
+        assert_eq!(quote.ask(), dec!(0.46));
+        assert_eq!(quote.size(), dec!(25));
+        assert_eq!(quote.spread(), Some(dec!(0.02)));
+    }
+
+    #[test]
+    fn from_book_requires_an_ask() {
+        let book = Book::new(TokenId::from("t"));
+        assert!(Quote::from_book(PRIMARY_BOOK_SOURCE, &book).is_none());
+    }
+}
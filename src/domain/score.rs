@@ -11,6 +11,7 @@
 //! - **Opportunity**: Historical arbitrage opportunity frequency
 //! - **Outcome Count**: Number of outcomes (more outcomes, more complexity)
 //! - **Activity**: Recent trading volume
+//! - **Time to close**: How soon the market stops trading
 //!
 //! Factors are normalized to 0.0-1.0 and combined using configurable weights.
 //!
@@ -60,6 +61,8 @@ pub struct ScoreFactors {
     pub outcome_count: f64,
     /// Recent trading activity level (0.0 to 1.0).
     pub activity: f64,
+    /// Proximity of the market's close time (0.0 to 1.0, higher means sooner).
+    pub time_to_close: f64,
 }
 
 impl ScoreFactors {
@@ -80,9 +83,17 @@ impl ScoreFactors {
             opportunity,
             outcome_count,
             activity,
+            time_to_close: 0.0,
         }
     }
 
+    /// Sets the time-to-close factor.
+    #[must_use]
+    pub const fn with_time_to_close(mut self, time_to_close: f64) -> Self {
+        self.time_to_close = time_to_close;
+        self
+    }
+
     /// Computes a weighted composite score from these factors.
     ///
     /// Returns the weighted average of all factors.
@@ -92,13 +103,10 @@ impl ScoreFactors {
             + self.spread * weights.spread
             + self.opportunity * weights.opportunity
             + self.outcome_count * weights.outcome_count
-            + self.activity * weights.activity;
+            + self.activity * weights.activity
+            + self.time_to_close * weights.time_to_close;
 
-        let weight_sum = weights.liquidity
-            + weights.spread
-            + weights.opportunity
-            + weights.outcome_count
-            + weights.activity;
+        let weight_sum = weights.total();
 
         if weight_sum == 0.0 {
             0.0
@@ -116,6 +124,7 @@ impl Default for ScoreFactors {
             opportunity: 0.0,
             outcome_count: 0.0,
             activity: 0.0,
+            time_to_close: 0.0,
         }
     }
 }
@@ -135,10 +144,15 @@ pub struct ScoreWeights {
     pub outcome_count: f64,
     /// Weight applied to the activity factor.
     pub activity: f64,
+    /// Weight applied to the time-to-close factor.
+    pub time_to_close: f64,
 }
 
 impl ScoreWeights {
     /// Creates new score weights.
+    ///
+    /// The time-to-close weight starts at zero; set it with
+    /// [`with_time_to_close`](Self::with_time_to_close).
     #[must_use]
     pub const fn new(
         liquidity: f64,
@@ -153,8 +167,27 @@ impl ScoreWeights {
             opportunity,
             outcome_count,
             activity,
+            time_to_close: 0.0,
         }
     }
+
+    /// Sets the weight applied to the time-to-close factor.
+    #[must_use]
+    pub const fn with_time_to_close(mut self, time_to_close: f64) -> Self {
+        self.time_to_close = time_to_close;
+        self
+    }
+
+    /// Returns the sum of all weights.
+    #[must_use]
+    pub fn total(&self) -> f64 {
+        self.liquidity
+            + self.spread
+            + self.opportunity
+            + self.outcome_count
+            + self.activity
+            + self.time_to_close
+    }
 }
 
 impl Default for ScoreWeights {
@@ -165,6 +198,7 @@ impl Default for ScoreWeights {
             opportunity: 1.0,
             outcome_count: 1.0,
             activity: 1.0,
+            time_to_close: 0.0,
         }
     }
 }
@@ -227,6 +261,15 @@ impl MarketScore {
     }
 }
 
+/// A scored market with its question, for explaining subscription priority.
+#[derive(Debug, Clone)]
+pub struct RankedMarket {
+    /// Human-readable market question.
+    pub question: String,
+    /// The market's score and factor breakdown.
+    pub score: MarketScore,
+}
+
 impl PartialEq for MarketScore {
     fn eq(&self, other: &Self) -> bool {
         self.composite == other.composite
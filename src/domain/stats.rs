@@ -28,14 +28,25 @@
 //! assert_eq!(summary.net_profit(), dec!(80.00));
 //! ```
 
+use std::time::Duration;
+
+use chrono::NaiveDate;
 use rust_decimal::Decimal;
 
+/// Variant label for opportunities detected by the primary strategy set.
+///
+/// Only the primary variant executes trades; other variants are shadow
+/// configurations recorded for comparison.
+pub const PRIMARY_VARIANT: &str = "primary";
+
 /// A recorded opportunity for statistics tracking.
 ///
 /// Captures details about a detected arbitrage opportunity, whether
 /// it was executed, and if rejected, why.
 #[derive(Debug, Clone)]
 pub struct RecordedOpportunity {
+    /// Identifier generated when the opportunity was detected.
+    pub uid: String,
     /// Name of the strategy that detected this opportunity.
     pub strategy: String,
     /// Market IDs involved in this opportunity.
@@ -48,6 +59,11 @@ pub struct RecordedOpportunity {
     pub executed: bool,
     /// Reason for rejection if not executed.
     pub rejected_reason: Option<String>,
+    /// Strategy variant that detected this opportunity.
+    pub variant: String,
+    /// [`Opportunity::content_hash`](crate::domain::opportunity::Opportunity::content_hash)
+    /// of the detection, used to skip repeats; `None` always records.
+    pub content_hash: Option<u64>,
 }
 
 /// Event recorded when a trade is opened.
@@ -57,6 +73,8 @@ pub struct RecordedOpportunity {
 pub struct TradeOpenEvent {
     /// ID of the opportunity that triggered this trade.
     pub opportunity_id: i32,
+    /// Identifier generated when the triggering opportunity was detected.
+    pub opportunity_uid: String,
     /// Strategy name that opened the trade.
     pub strategy: String,
     /// Market IDs involved in this trade.
@@ -70,7 +88,7 @@ pub struct TradeOpenEvent {
 }
 
 /// A single leg of a trade for statistics recording.
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TradeLeg {
     /// Token ID of this leg.
     pub token_id: String,
@@ -146,6 +164,9 @@ impl StatsSummary {
 pub struct OpportunitySummary {
     /// Unique identifier for this opportunity.
     pub id: i32,
+    /// Identifier generated at detection, absent for rows recorded before it
+    /// was stored.
+    pub uid: Option<String>,
     /// Strategy that detected this opportunity.
     pub strategy: String,
     /// Edge per share.
@@ -158,4 +179,44 @@ pub struct OpportunitySummary {
     pub rejected_reason: Option<String>,
     /// Timestamp when detected (ISO 8601 format).
     pub detected_at: String,
+    /// Strategy variant that detected this opportunity.
+    pub variant: String,
+}
+
+/// Criteria for querying recorded opportunities.
+///
+/// Every field is optional; unset fields do not constrain the query.
+/// Results are returned most recent first.
+#[derive(Debug, Clone, Default)]
+pub struct OpportunityFilter {
+    /// Only opportunities detected by this strategy.
+    pub strategy: Option<String>,
+    /// Only opportunities detected by this strategy variant.
+    pub variant: Option<String>,
+    /// Only executed (`true`) or rejected (`false`) opportunities.
+    pub executed: Option<bool>,
+    /// Only opportunities with at least this edge per share.
+    pub min_edge: Option<Decimal>,
+    /// Only opportunities detected on or after this date (UTC).
+    pub from: Option<NaiveDate>,
+    /// Only opportunities detected on or before this date (UTC).
+    pub to: Option<NaiveDate>,
+    /// Maximum number of opportunities to return.
+    pub limit: Option<u32>,
+}
+
+/// Detection latency percentiles over a recent window of market events.
+///
+/// Latency runs from receipt of a market event to the end of strategy
+/// detection, excluding risk checks and execution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LatencyPercentiles {
+    /// Median latency.
+    pub p50: Duration,
+    /// 95th percentile latency.
+    pub p95: Duration,
+    /// 99th percentile latency.
+    pub p99: Duration,
+    /// Number of events in the window.
+    pub samples: usize,
 }
@@ -6,12 +6,16 @@
 //! - [`TradeResult`] - Overall outcome of a trade execution
 //! - [`Fill`] - A successfully executed leg
 //! - [`Failure`] - A failed leg with error information
+//! - [`FailureKind`] - Broad class of an execution failure
+//! - [`PartialFillPolicy`] - How to respond when only some legs fill
+//! - [`OrderSide`] - Whether a leg buys or sells its outcome
 //!
 //! # Trade Outcomes
 //!
-//! Multi-leg trades can have three outcomes:
+//! Multi-leg trades can have four outcomes:
 //! - **Success**: All legs filled, arbitrage position established
 //! - **Partial**: Some legs filled, risk exposure exists
+//! - **Unwound**: Some legs filled and were sold back, no position held
 //! - **Failed**: No legs filled, no position opened
 //!
 //! # Examples
@@ -42,8 +46,47 @@
 //! assert!(partial.is_partial());
 //! ```
 
+use std::fmt;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
 use super::id::TokenId;
 
+/// Direction of an order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    /// Buy order (acquire shares).
+    Buy,
+
+    /// Sell order (dispose of shares).
+    Sell,
+}
+
+impl OrderSide {
+    /// Lowercase label, `buy` or `sell`.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Buy => "buy",
+            Self::Sell => "sell",
+        }
+    }
+}
+
+/// How an executor responds when only some legs of an arbitrage fill.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PartialFillPolicy {
+    /// Keep the filled legs and report the partial fill.
+    #[default]
+    Hold,
+    /// Resubmit failed legs at widened prices, unwinding if they still fail.
+    Retry,
+    /// Sell the filled legs back immediately.
+    Unwind,
+}
+
 /// A successfully executed leg in a multi-leg trade.
 ///
 /// Contains the token ID and exchange-assigned order ID for tracking.
@@ -65,25 +108,87 @@ impl Fill {
     }
 }
 
+/// Broad class of an execution failure.
+///
+/// Lets recovery and notifications branch on why an order failed without
+/// parsing exchange error messages.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureKind {
+    /// The wallet lacks the balance or allowance to pay for the order.
+    InsufficientBalance,
+    /// The book moved away from the limit price before the order matched.
+    PriceMoved,
+    /// The exchange refused the order for another reason.
+    RejectedByExchange,
+    /// The order did not fill or respond in time.
+    Timeout,
+    /// The exchange throttled the request.
+    RateLimited,
+    /// Any failure that could not be classified.
+    #[default]
+    Other,
+}
+
+impl FailureKind {
+    /// Short human-readable label, e.g. `insufficient balance`.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::InsufficientBalance => "insufficient balance",
+            Self::PriceMoved => "price moved",
+            Self::RejectedByExchange => "rejected by exchange",
+            Self::Timeout => "timeout",
+            Self::RateLimited => "rate limited",
+            Self::Other => "error",
+        }
+    }
+
+    /// Returns true if resubmitting the order could succeed.
+    ///
+    /// Orders refused for lack of funds or by exchange rules fail the same
+    /// way again, so retrying them only delays the unwind.
+    #[must_use]
+    pub const fn is_retryable(self) -> bool {
+        !matches!(self, Self::InsufficientBalance | Self::RejectedByExchange)
+    }
+}
+
+impl fmt::Display for FailureKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
 /// A failed leg in a multi-leg trade.
 ///
-/// Contains the token ID and error message describing the failure.
+/// Contains the token ID, error message, and class of the failure.
 #[derive(Debug, Clone)]
 pub struct Failure {
     /// Token ID of the outcome that failed to fill.
     pub token_id: TokenId,
     /// Human-readable error message.
     pub error: String,
+    /// Class of the failure.
+    pub kind: FailureKind,
 }
 
 impl Failure {
-    /// Creates a new failure record.
+    /// Creates a new failure record of kind [`FailureKind::Other`].
     pub fn new(token_id: TokenId, error: impl Into<String>) -> Self {
         Self {
             token_id,
             error: error.into(),
+            kind: FailureKind::Other,
         }
     }
+
+    /// Sets the class of the failure.
+    #[must_use]
+    pub const fn with_kind(mut self, kind: FailureKind) -> Self {
+        self.kind = kind;
+        self
+    }
 }
 
 /// Result of executing a multi-leg arbitrage trade.
@@ -104,10 +209,21 @@ pub enum TradeResult {
         /// Legs that failed to execute.
         failures: Vec<Failure>,
     },
+    /// Some legs filled and were sold back, leaving no position.
+    Unwound {
+        /// Buy legs that filled before the unwind.
+        fills: Vec<Fill>,
+        /// Sell orders that closed out the filled legs.
+        unwinds: Vec<Fill>,
+        /// Cost of the buys minus proceeds of the sells, per the limit prices.
+        net_cost: Decimal,
+    },
     /// All legs failed, no position opened.
     Failed {
         /// Description of why the trade failed.
         reason: String,
+        /// Class of the failure.
+        kind: FailureKind,
     },
 }
 
@@ -124,28 +240,57 @@ impl TradeResult {
         matches!(self, Self::Partial { .. })
     }
 
+    /// Returns true if filled legs were sold back after a partial fill.
+    #[must_use]
+    pub const fn is_unwound(&self) -> bool {
+        matches!(self, Self::Unwound { .. })
+    }
+
     /// Returns true if all legs failed to execute.
     #[must_use]
     pub const fn is_failed(&self) -> bool {
         matches!(self, Self::Failed { .. })
     }
 
+    /// Returns the net cost realized by unwinding, if an unwind happened.
+    #[must_use]
+    pub const fn net_cost(&self) -> Option<Decimal> {
+        match self {
+            Self::Unwound { net_cost, .. } => Some(*net_cost),
+            _ => None,
+        }
+    }
+
     /// Returns all successful fills, or an empty slice if none.
     #[must_use]
     pub fn fills(&self) -> &[Fill] {
         match self {
             Self::Success { fills } => fills,
             Self::Partial { fills, .. } => fills,
+            Self::Unwound { fills, .. } => fills,
             Self::Failed { .. } => &[],
         }
     }
 
+    /// Returns the class of the failure, if any leg failed.
+    ///
+    /// For partial fills this is the class of the first failed leg.
+    #[must_use]
+    pub fn failure_kind(&self) -> Option<FailureKind> {
+        match self {
+            Self::Failed { kind, .. } => Some(*kind),
+            Self::Partial { failures, .. } => failures.first().map(|failure| failure.kind),
+            Self::Success { .. } | Self::Unwound { .. } => None,
+        }
+    }
+
     /// Returns all failures, or an empty slice if none.
     #[must_use]
     pub fn failures(&self) -> &[Failure] {
         match self {
             Self::Success { .. } => &[],
             Self::Partial { failures, .. } => failures,
+            Self::Unwound { .. } => &[],
             Self::Failed { .. } => &[],
         }
     }
@@ -192,17 +337,45 @@ mod tests {
         assert!(!result.is_failed());
         assert_eq!(result.fills().len(), 1);
         assert_eq!(result.failures().len(), 1);
+        assert_eq!(result.failure_kind(), Some(FailureKind::Other));
+    }
+
+    #[test]
+    fn failure_kind_retryability() {
+        assert!(FailureKind::PriceMoved.is_retryable());
+        assert!(FailureKind::Timeout.is_retryable());
+        assert!(FailureKind::RateLimited.is_retryable());
+        assert!(FailureKind::Other.is_retryable());
+        assert!(!FailureKind::InsufficientBalance.is_retryable());
+        assert!(!FailureKind::RejectedByExchange.is_retryable());
     }
 
     #[test]
     fn result_failed() {
         let result = TradeResult::Failed {
             reason: "all legs failed".to_string(),
+            kind: FailureKind::Timeout,
         };
         assert!(!result.is_success());
         assert!(!result.is_partial());
         assert!(result.is_failed());
+        assert_eq!(result.failure_kind(), Some(FailureKind::Timeout));
         assert!(result.fills().is_empty());
         assert!(result.failures().is_empty());
     }
+
+    #[test]
+    fn result_unwound_reports_net_cost() {
+        let result = TradeResult::Unwound {
+            fills: vec![Fill::new(TokenId::from("t1"), "o1")],
+            unwinds: vec![Fill::new(TokenId::from("t1"), "o2")],
+            net_cost: Decimal::new(150, 2),
+        };
+        assert!(result.is_unwound());
+        assert!(!result.is_success());
+        assert!(!result.is_partial());
+        assert_eq!(result.net_cost(), Some(Decimal::new(150, 2)));
+        assert_eq!(result.fills().len(), 1);
+        assert!(result.failures().is_empty());
+    }
 }
@@ -0,0 +1,130 @@
+//! Trading state: whether new trades may be executed, and why not.
+//!
+//! Every pause source (operator commands, the risk kill-switch) sets a single
+//! [`TradingState`], and the execution gate reads it. A [`TradingState::Halted`]
+//! state is a manual stop that survives restarts; [`TradingState::Paused`] is
+//! transient and clears when the process exits.
+
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Whether trading is allowed, and the reason when it is not.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum TradingState {
+    /// Trading normally.
+    #[default]
+    Active,
+    /// Temporarily stopped, typically by an automatic safeguard.
+    Paused {
+        /// Why trading was paused.
+        reason: String,
+        /// When the pause began.
+        since: DateTime<Utc>,
+    },
+    /// Stopped by an operator until explicitly resumed, including across restarts.
+    Halted {
+        /// Why trading was halted.
+        reason: String,
+    },
+}
+
+impl TradingState {
+    /// Creates a paused state starting now.
+    #[must_use]
+    pub fn paused(reason: impl Into<String>) -> Self {
+        Self::Paused {
+            reason: reason.into(),
+            since: Utc::now(),
+        }
+    }
+
+    /// Creates a halted state.
+    #[must_use]
+    pub fn halted(reason: impl Into<String>) -> Self {
+        Self::Halted {
+            reason: reason.into(),
+        }
+    }
+
+    /// Returns true if new trades may be executed.
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        matches!(self, Self::Active)
+    }
+
+    /// Returns true if trading was halted manually.
+    #[must_use]
+    pub const fn is_halted(&self) -> bool {
+        matches!(self, Self::Halted { .. })
+    }
+
+    /// Returns the reason trading is stopped, if it is.
+    #[must_use]
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Active => None,
+            Self::Paused { reason, .. } | Self::Halted { reason } => Some(reason),
+        }
+    }
+
+    /// Returns a stable lowercase label for logs and command output.
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Paused { .. } => "paused",
+            Self::Halted { .. } => "halted",
+        }
+    }
+}
+
+impl fmt::Display for TradingState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Active => write!(f, "active"),
+            Self::Paused { reason, since } => {
+                write!(f, "paused since {}: {reason}", since.format("%H:%M:%S UTC"))
+            }
+            Self::Halted { reason } => write!(f, "halted: {reason}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_active() {
+        let state = TradingState::default();
+        assert!(state.is_active());
+        assert!(state.reason().is_none());
+        assert_eq!(state.label(), "active");
+    }
+
+    #[test]
+    fn paused_and_halted_carry_reason() {
+        let paused = TradingState::paused("kill-switch");
+        assert!(!paused.is_active());
+        assert!(!paused.is_halted());
+        assert_eq!(paused.reason(), Some("kill-switch"));
+
+        let halted = TradingState::halted("operator");
+        assert!(halted.is_halted());
+        assert_eq!(halted.reason(), Some("operator"));
+        assert_eq!(halted.to_string(), "halted: operator");
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let halted = TradingState::halted("maintenance");
+        let json = serde_json::to_string(&halted).unwrap();
+        assert_eq!(json, r#"{"state":"halted","reason":"maintenance"}"#);
+
+        let parsed: TradingState = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, halted);
+    }
+}
@@ -17,6 +17,9 @@ pub enum ConfigError {
     #[error("failed to parse config: {0}")]
     Parse(#[source] toml::de::Error),
 
+    #[error("invalid environment override {var}: {reason}")]
+    EnvOverride { var: String, reason: String },
+
     #[error("{0}")]
     Other(String),
 }
@@ -46,13 +49,14 @@ pub enum ExecutionError {
 /// Risk management errors.
 #[derive(Error, Debug, Clone)]
 pub enum RiskError {
-    #[error("circuit breaker active: {reason}")]
-    CircuitBreakerActive { reason: String },
+    #[error("trading is {state}")]
+    TradingStopped { state: String },
 
-    #[error("position limit exceeded: {current} >= {limit} for market {market_id}")]
+    #[error("position limit exceeded for market {market_id}: {current} + {additional} > {limit}")]
     PositionLimitExceeded {
         market_id: String,
         current: rust_decimal::Decimal,
+        additional: rust_decimal::Decimal,
         limit: rust_decimal::Decimal,
     },
 
@@ -69,11 +73,57 @@ pub enum RiskError {
         threshold: rust_decimal::Decimal,
     },
 
+    #[error("profit below {min} of capital deployed: {pct}")]
+    ProfitPctBelowThreshold {
+        pct: rust_decimal::Decimal,
+        min: rust_decimal::Decimal,
+    },
+
     #[error("slippage too high: {actual} > {max}")]
     SlippageTooHigh {
         actual: rust_decimal::Decimal,
         max: rust_decimal::Decimal,
     },
+
+    #[error("stale order book for {token_id}: {age_ms}ms > {max_age_ms}ms")]
+    StaleBook {
+        token_id: String,
+        age_ms: u128,
+        max_age_ms: u128,
+    },
+
+    #[error("too many legs to execute: {legs} > {max}")]
+    TooManyLegs { legs: usize, max: usize },
+
+    #[error("market {market_id} cooling down after a failed execution: {remaining_ms}ms left")]
+    MarketCoolingDown {
+        market_id: String,
+        remaining_ms: u128,
+    },
+
+    #[error("opportunity_stale: detected {age_ms}ms ago > {max_age_ms}ms")]
+    OpportunityStale { age_ms: u128, max_age_ms: u128 },
+
+    #[error("edge_disappeared: edge at current top of book is {edge}, below {min_edge}")]
+    EdgeDisappeared {
+        edge: rust_decimal::Decimal,
+        min_edge: rust_decimal::Decimal,
+    },
+
+    #[error("edge_disappeared: no current price for token {token_id}")]
+    EdgeUnpriced { token_id: String },
+
+    #[error("concurrency_limited: {max} executions already in flight")]
+    ConcurrencyLimited { max: usize },
+
+    #[error("order rate limit reached: queue longer than {max_wait_ms}ms")]
+    RateLimited { max_wait_ms: u128 },
+
+    #[error("gas balance too low: {balance} < {min}; top up the wallet to resume trading")]
+    InsufficientGas {
+        balance: rust_decimal::Decimal,
+        min: rust_decimal::Decimal,
+    },
 }
 
 #[derive(Error, Debug)]
@@ -14,8 +14,14 @@ pub use crate::infrastructure::factory::inference::{build_cluster_cache, build_i
 pub use crate::infrastructure::factory::llm::build_llm_client;
 pub use crate::infrastructure::factory::notifier::build_notifier_registry;
 pub use crate::infrastructure::factory::persistence::build_stats_recorder as init_stats_recorder;
+pub use crate::infrastructure::factory::persistence::{
+    build_cluster_store, build_memory_stats_recorder, build_position_store, build_relation_store,
+    build_trading_state_store,
+};
 pub use crate::infrastructure::factory::solver::build_projection_solver;
-pub use crate::infrastructure::factory::strategy::build_strategy_registry;
+pub use crate::infrastructure::factory::strategy::{
+    build_shadow_registry, build_strategy_registry,
+};
 
 #[cfg(test)]
 mod tests {
@@ -60,7 +66,8 @@ mod tests {
 
     #[test]
     fn build_projection_solver_re_export_works() {
-        let solver = build_projection_solver();
+        let config = minimal_config();
+        let solver = build_projection_solver(&config.strategies.combinatorial.solver);
         assert!(Arc::strong_count(&solver) >= 1);
     }
 
@@ -4,7 +4,7 @@
 //! that monitors related markets for pricing inefficiencies.
 
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::application::cluster::service::ClusterDetectionConfig as CoreConfig;
 
@@ -12,7 +12,7 @@ use crate::application::cluster::service::ClusterDetectionConfig as CoreConfig;
 ///
 /// Controls the behavior of the background service that detects arbitrage
 /// opportunities across clusters of related markets.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ClusterDetectionConfig {
     /// Enable the cluster detection service.
     ///
@@ -0,0 +1,40 @@
+//! Control API configuration.
+//!
+//! Provides configuration for the optional local WebSocket server that accepts
+//! JSON-RPC control commands. The server is only available when the crate is
+//! built with the `control-api` feature.
+
+use serde::{Deserialize, Serialize};
+
+/// Environment variable holding the token clients must present, if any.
+pub const CONTROL_TOKEN_ENV: &str = "EDGELORD_CONTROL_TOKEN";
+
+/// Control API server configuration.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ControlApiConfig {
+    /// Whether to serve the control API while the bot runs.
+    ///
+    /// Ignored unless built with the `control-api` feature. Defaults to false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Socket address the control server listens on.
+    ///
+    /// Defaults to "127.0.0.1:9091". Clients must present the token from
+    /// `EDGELORD_CONTROL_TOKEN` when that variable is set.
+    #[serde(default = "default_bind_addr")]
+    pub bind_addr: String,
+}
+
+fn default_bind_addr() -> String {
+    "127.0.0.1:9091".to_string()
+}
+
+impl Default for ControlApiConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind_addr: default_bind_addr(),
+        }
+    }
+}
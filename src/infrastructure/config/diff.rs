@@ -0,0 +1,184 @@
+//! Configuration comparison against defaults.
+//!
+//! Both configurations are serialized to their serde representation and
+//! walked together, producing one [`ConfigChange`] per leaf value that
+//! differs. Arrays and tagged enums compare as a whole.
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use serde_json::{Map, Value};
+
+use super::settings::Config;
+use crate::error::Result;
+
+/// A single setting whose value differs from the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    /// Dotted path to the setting (e.g. `risk.max_slippage`).
+    pub path: String,
+    /// Value in the baseline configuration, `Null` when absent.
+    pub default: Value,
+    /// Value in the compared configuration, `Null` when absent.
+    pub value: Value,
+}
+
+impl ConfigChange {
+    /// Top-level section the setting belongs to, `None` for top-level keys.
+    #[must_use]
+    pub fn section(&self) -> Option<&str> {
+        self.path.split_once('.').map(|(section, _)| section)
+    }
+
+    /// Setting name relative to its section.
+    #[must_use]
+    pub fn key(&self) -> &str {
+        self.path.split_once('.').map_or(&self.path, |(_, key)| key)
+    }
+}
+
+/// List the settings in `config` that differ from [`Config::default`].
+///
+/// # Errors
+///
+/// Returns an error if either configuration cannot be serialized.
+pub fn diff_from_default(config: &Config) -> Result<Vec<ConfigChange>> {
+    diff(&Config::default(), config)
+}
+
+/// List the settings in `config` that differ from `baseline`, in path order.
+///
+/// # Errors
+///
+/// Returns an error if either configuration cannot be serialized.
+pub fn diff(baseline: &Config, config: &Config) -> Result<Vec<ConfigChange>> {
+    let baseline = serde_json::to_value(baseline)?;
+    let config = serde_json::to_value(config)?;
+    let mut changes = Vec::new();
+    diff_values("", &baseline, &config, &mut changes);
+    Ok(changes)
+}
+
+fn diff_values(path: &str, baseline: &Value, value: &Value, changes: &mut Vec<ConfigChange>) {
+    match (baseline, value) {
+        (Value::Object(baseline), Value::Object(value)) if !is_tagged(baseline, value) => {
+            diff_objects(path, baseline, value, changes);
+        }
+        // Optional sections set on one side only are listed field by field.
+        (Value::Null, Value::Object(value)) => diff_objects(path, &Map::new(), value, changes),
+        (Value::Object(baseline), Value::Null) => {
+            diff_objects(path, baseline, &Map::new(), changes);
+        }
+        _ if !values_equal(baseline, value) => changes.push(ConfigChange {
+            path: path.to_string(),
+            default: baseline.clone(),
+            value: value.clone(),
+        }),
+        _ => {}
+    }
+}
+
+fn diff_objects(
+    path: &str,
+    baseline: &Map<String, Value>,
+    value: &Map<String, Value>,
+    changes: &mut Vec<ConfigChange>,
+) {
+    let mut keys: Vec<&String> = baseline.keys().chain(value.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    for key in keys {
+        let child = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+        diff_values(
+            &child,
+            baseline.get(key).unwrap_or(&Value::Null),
+            value.get(key).unwrap_or(&Value::Null),
+            changes,
+        );
+    }
+}
+
+/// Objects tagged with different variants are reported as one change.
+fn is_tagged(baseline: &Map<String, Value>, value: &Map<String, Value>) -> bool {
+    matches!(
+        (baseline.get("type"), value.get("type")),
+        (Some(a), Some(b)) if a != b
+    )
+}
+
+/// Decimals serialize as strings, so `0.10` and `0.1` compare numerically.
+fn values_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::String(a), Value::String(b)) if a != b => {
+            matches!(
+                (Decimal::from_str(a), Decimal::from_str(b)),
+                (Ok(a), Ok(b)) if a == b
+            )
+        }
+        _ => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn default_config_has_no_changes() {
+        assert!(diff_from_default(&Config::default()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn diff_reports_nested_and_top_level_changes() {
+        let mut config = Config::default();
+        config.dry_run = true;
+        config.risk.max_slippage = dec!(0.05);
+        config.strategies.enabled.push("combinatorial".to_string());
+
+        let changes = diff_from_default(&config).unwrap();
+        let paths: Vec<_> = changes.iter().map(|c| c.path.as_str()).collect();
+
+        assert_eq!(
+            paths,
+            vec!["dry_run", "risk.max_slippage", "strategies.enabled"]
+        );
+        assert_eq!(changes[0].section(), None);
+        assert_eq!(changes[1].section(), Some("risk"));
+        assert_eq!(changes[1].key(), "max_slippage");
+        assert_eq!(changes[0].value, Value::Bool(true));
+    }
+
+    #[test]
+    fn decimals_with_different_scale_are_equal() {
+        let mut config = Config::default();
+        config.risk.max_slippage = Config::default().risk.max_slippage * dec!(1.00);
+
+        assert!(diff_from_default(&config).unwrap().is_empty());
+    }
+
+    #[test]
+    fn optional_section_reports_each_field() {
+        let toml = r#"
+            [logging]
+            level = "info"
+            format = "pretty"
+
+            [replay]
+            path = "book.jsonl"
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+
+        let changes = diff_from_default(&config).unwrap();
+        let paths: Vec<_> = changes.iter().map(|c| c.path.as_str()).collect();
+
+        assert_eq!(paths, vec!["replay.path", "replay.speed"]);
+        assert_eq!(changes[0].default, Value::Null);
+        assert_eq!(changes[0].value, Value::String("book.jsonl".into()));
+    }
+}
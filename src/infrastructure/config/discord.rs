@@ -0,0 +1,52 @@
+//! Discord notification configuration.
+//!
+//! Provides configuration for Discord webhook notifications. Requires the
+//! `DISCORD_WEBHOOK_URL` environment variable.
+
+use serde::{Deserialize, Serialize};
+
+const fn default_true() -> bool {
+    true
+}
+
+/// Discord notification configuration.
+///
+/// Controls which events are posted to Discord. The webhook URL is read from
+/// the environment.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DiscordAppConfig {
+    /// Enable Discord notifications.
+    ///
+    /// Requires the `discord` feature. Defaults to false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Send alerts for detected opportunities.
+    ///
+    /// Can be noisy in active markets. Defaults to false.
+    #[serde(default)]
+    pub notify_opportunities: bool,
+
+    /// Send alerts for trade executions.
+    ///
+    /// Defaults to true.
+    #[serde(default = "default_true")]
+    pub notify_executions: bool,
+
+    /// Send alerts when trades are rejected by risk checks.
+    ///
+    /// Defaults to true.
+    #[serde(default = "default_true")]
+    pub notify_risk_rejections: bool,
+}
+
+impl Default for DiscordAppConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            notify_opportunities: false,
+            notify_executions: default_true(),
+            notify_risk_rejections: default_true(),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Market discovery configuration.
+//!
+//! Controls how often the exchange is re-scanned for markets created or
+//! closed since startup, so long-running sessions keep their coverage fresh.
+
+use serde::{Deserialize, Serialize};
+
+/// Periodic market re-scan configuration.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct MarketDiscoveryConfig {
+    /// Seconds between market re-scans.
+    ///
+    /// 0 disables re-scanning, so only the markets fetched at startup are
+    /// tracked. Ignored in replay mode. Defaults to 0.
+    #[serde(default)]
+    pub rescan_interval_secs: u64,
+}
+
+impl MarketDiscoveryConfig {
+    /// Return `true` when periodic re-scanning is enabled.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.rescan_interval_secs > 0
+    }
+}
@@ -0,0 +1,262 @@
+//! Environment variable overrides for configuration fields.
+//!
+//! Any setting can be overridden with a variable named
+//! `EDGELORD__<SECTION>__<FIELD>`: drop the `EDGELORD__` prefix, split the
+//! rest on `__`, and lowercase each segment to get the setting's path. For
+//! example `EDGELORD__RISK__MAX_TOTAL_EXPOSURE=5000` sets
+//! `risk.max_total_exposure`, and `EDGELORD__DRY_RUN=true` sets the top-level
+//! `dry_run`. Deeper paths such as
+//! `EDGELORD__EXCHANGE_CONFIG__MARKET_FILTER__MAX_MARKETS` work the same way.
+//!
+//! Values are parsed according to the type of the setting they replace:
+//! booleans accept `true` or `false`, integers and decimals must parse as
+//! numbers, and strings are taken verbatim. Unset optional settings accept
+//! booleans, numbers, or strings. Whole sections and lists cannot be replaced.
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use serde_json::{Map, Number, Value};
+
+use super::settings::Config;
+use crate::error::{ConfigError, Result};
+
+/// Prefix marking an environment variable as a configuration override.
+pub const ENV_PREFIX: &str = "EDGELORD__";
+
+/// Separator between path segments in an override variable name.
+pub const ENV_SEPARATOR: &str = "__";
+
+/// Apply configuration overrides from the process environment.
+///
+/// # Errors
+///
+/// Returns an error if an override names an unknown setting or carries a
+/// value that does not match the setting's type.
+#[allow(clippy::result_large_err)]
+pub fn apply_env_overrides(config: Config) -> Result<Config> {
+    let vars = std::env::vars_os()
+        .filter_map(|(name, value)| Some((name.into_string().ok()?, value.into_string().ok()?)));
+    apply_overrides(config, vars)
+}
+
+/// Apply `EDGELORD__<SECTION>__<FIELD>` overrides from `vars` to `config`.
+///
+/// Variables without the prefix are ignored. Overrides are applied in name
+/// order so the result does not depend on iteration order.
+///
+/// # Errors
+///
+/// Returns an error if an override names an unknown setting or carries a
+/// value that does not match the setting's type.
+#[allow(clippy::result_large_err)]
+pub fn apply_overrides<I>(config: Config, vars: I) -> Result<Config>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let mut overrides: Vec<(String, String)> = vars
+        .into_iter()
+        .filter(|(name, _)| name.starts_with(ENV_PREFIX))
+        .collect();
+    if overrides.is_empty() {
+        return Ok(config);
+    }
+    overrides.sort();
+
+    let mut root = serde_json::to_value(&config)?;
+    for (name, raw) in &overrides {
+        let path = setting_path(name)?;
+        set_value(&mut root, &path, raw).map_err(|reason| ConfigError::EnvOverride {
+            var: name.clone(),
+            reason,
+        })?;
+    }
+
+    serde_json::from_value(root).map_err(|error| {
+        ConfigError::EnvOverride {
+            var: overrides
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            reason: error.to_string(),
+        }
+        .into()
+    })
+}
+
+/// Convert an override variable name to a lowercase setting path.
+#[allow(clippy::result_large_err)]
+fn setting_path(name: &str) -> Result<Vec<String>> {
+    let path: Vec<String> = name[ENV_PREFIX.len()..]
+        .split(ENV_SEPARATOR)
+        .map(str::to_lowercase)
+        .collect();
+    if path.iter().any(String::is_empty) {
+        return Err(ConfigError::EnvOverride {
+            var: name.to_string(),
+            reason: format!("expected {ENV_PREFIX}<SECTION>{ENV_SEPARATOR}<FIELD>"),
+        }
+        .into());
+    }
+    Ok(path)
+}
+
+/// Replace the leaf at `path` with `raw`, parsed to match the current value.
+fn set_value(root: &mut Value, path: &[String], raw: &str) -> std::result::Result<(), String> {
+    let (leaf, sections) = path.split_last().expect("setting path is never empty");
+
+    let mut node = root;
+    let mut known = true;
+    for (depth, section) in sections.iter().enumerate() {
+        let table = as_table(node, &path[..depth])?;
+        known = known && table.contains_key(section);
+        node = table.entry(section.clone()).or_insert(Value::Null);
+    }
+
+    let table = as_table(node, sections)?;
+    let current = match table.get(leaf) {
+        Some(current) => current,
+        None if known => return Err(format!("unknown setting `{}`", path.join("."))),
+        None => &Value::Null,
+    };
+    let value = parse_like(current, raw)?;
+    table.insert(leaf.clone(), value);
+    Ok(())
+}
+
+/// View `node` as a table, turning an unset optional section into an empty one.
+fn as_table<'a>(
+    node: &'a mut Value,
+    path: &[String],
+) -> std::result::Result<&'a mut Map<String, Value>, String> {
+    if node.is_null() {
+        *node = Value::Object(Map::new());
+    }
+    node.as_object_mut()
+        .ok_or_else(|| format!("`{}` is not a section", path.join(".")))
+}
+
+/// Parse `raw` as the same kind of value as `current`.
+fn parse_like(current: &Value, raw: &str) -> std::result::Result<Value, String> {
+    match current {
+        Value::Bool(_) => raw
+            .parse::<bool>()
+            .map(Value::Bool)
+            .map_err(|_| format!("expected `true` or `false`, got `{raw}`")),
+        Value::Number(number) if number.is_f64() => raw
+            .parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .map(Value::Number)
+            .ok_or_else(|| format!("expected a number, got `{raw}`")),
+        Value::Number(_) => raw
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| format!("expected an integer, got `{raw}`")),
+        // Decimals serialize as strings; keep them numeric
+        Value::String(current) if Decimal::from_str(current).is_ok() => Decimal::from_str(raw)
+            .map(|_| Value::String(raw.to_string()))
+            .map_err(|_| format!("expected a decimal number, got `{raw}`")),
+        Value::String(_) => Ok(Value::String(raw.to_string())),
+        Value::Null => Ok(infer(raw)),
+        Value::Array(_) => Err("lists cannot be overridden from the environment".to_string()),
+        Value::Object(_) => Err("sections cannot be overridden; set individual fields".to_string()),
+    }
+}
+
+/// Infer a value for a setting with no current value.
+fn infer(raw: &str) -> Value {
+    if let Ok(flag) = raw.parse::<bool>() {
+        Value::Bool(flag)
+    } else if let Ok(integer) = raw.parse::<i64>() {
+        Value::from(integer)
+    } else if let Some(number) = raw.parse::<f64>().ok().and_then(Number::from_f64) {
+        Value::Number(number)
+    } else {
+        Value::String(raw.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn apply(pairs: &[(&str, &str)]) -> Result<Config> {
+        apply_overrides(Config::default(), vars(pairs))
+    }
+
+    #[test]
+    fn overrides_decimal_field() {
+        let config = apply(&[("EDGELORD__RISK__MAX_TOTAL_EXPOSURE", "5000")]).unwrap();
+        assert_eq!(config.risk.max_total_exposure, dec!(5000));
+    }
+
+    #[test]
+    fn overrides_top_level_bool() {
+        let config = apply(&[("EDGELORD__DRY_RUN", "true")]).unwrap();
+        assert!(config.dry_run);
+    }
+
+    #[test]
+    fn overrides_nested_integer() {
+        let config = apply(&[("EDGELORD__TELEGRAM__STATS_INTERVAL_SECS", "90")]).unwrap();
+        assert_eq!(config.telegram.stats_interval_secs, 90);
+    }
+
+    #[test]
+    fn ignores_unprefixed_variables() {
+        let config = apply(&[("EDGELORD_KEYSTORE_PASSWORD", "secret"), ("PATH", "/bin")]);
+        assert!(config.is_ok());
+    }
+
+    #[test]
+    fn rejects_malformed_decimal() {
+        let error = apply(&[("EDGELORD__RISK__MAX_TOTAL_EXPOSURE", "lots")]).unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("EDGELORD__RISK__MAX_TOTAL_EXPOSURE"));
+        assert!(message.contains("expected a decimal number"));
+    }
+
+    #[test]
+    fn rejects_malformed_bool() {
+        let error = apply(&[("EDGELORD__DRY_RUN", "yes")]).unwrap_err();
+        assert!(error.to_string().contains("expected `true` or `false`"));
+    }
+
+    #[test]
+    fn rejects_malformed_integer() {
+        let error = apply(&[("EDGELORD__TELEGRAM__STATS_INTERVAL_SECS", "1.5")]).unwrap_err();
+        assert!(error.to_string().contains("expected an integer"));
+    }
+
+    #[test]
+    fn rejects_unknown_setting() {
+        let error = apply(&[("EDGELORD__RISK__MAX_EXPOSRUE", "5000")]).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("unknown setting `risk.max_exposrue`"));
+    }
+
+    #[test]
+    fn rejects_section_override() {
+        let error = apply(&[("EDGELORD__RISK", "5000")]).unwrap_err();
+        assert!(error.to_string().contains("sections cannot be overridden"));
+    }
+
+    #[test]
+    fn rejects_empty_segment() {
+        let error = apply(&[("EDGELORD__RISK____MAX", "1")]).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("expected EDGELORD__<SECTION>__<FIELD>"));
+    }
+}
@@ -0,0 +1,237 @@
+//! Trade execution configuration.
+//!
+//! Controls how the executor responds when only some legs of an arbitrage
+//! trade fill, leaving an unhedged position, how long a leg may rest unfilled,
+//! how fast executions may be submitted and how many may run at once, which
+//! of several simultaneous opportunities is executed first, whether the edge
+//! is re-confirmed before execution, how old an opportunity may be when
+//! executed, and whether open positions are exited before shutdown.
+
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::adapter::outbound::polymarket::recovery::PartialFillRecovery;
+use crate::application::orchestration::concurrency::ExecutionSlots;
+use crate::application::orchestration::priority::OpportunityPriority;
+use crate::application::orchestration::throttle::RateLimiter;
+use crate::domain::trade::PartialFillPolicy;
+
+/// Trade execution configuration.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExecutionConfig {
+    /// What to do when only some legs fill: "hold", "retry", or "unwind".
+    ///
+    /// `retry` resubmits failed legs at widened prices and unwinds if they
+    /// still fail. Defaults to "hold".
+    #[serde(default)]
+    pub on_partial: PartialFillPolicy,
+
+    /// Rounds of resubmission for failed legs under the retry policy.
+    ///
+    /// Defaults to 2.
+    #[serde(default = "default_retry_attempts")]
+    pub retry_attempts: u32,
+
+    /// Fraction of the original limit price added per retry round.
+    ///
+    /// Defaults to 0.01 (1%).
+    #[serde(default = "default_retry_price_step")]
+    pub retry_price_step: Decimal,
+
+    /// Fraction below the fill price at which filled legs are sold back.
+    ///
+    /// Defaults to 0.02 (2%).
+    #[serde(default = "default_unwind_discount")]
+    pub unwind_discount: Decimal,
+
+    /// Milliseconds an arbitrage leg may rest unfilled before it is cancelled.
+    ///
+    /// A cancelled leg counts as failed and triggers `on_partial`. 0 treats
+    /// an accepted order as filled. Defaults to 5000.
+    #[serde(default = "default_leg_timeout_ms")]
+    pub leg_timeout_ms: u64,
+
+    /// Sustained arbitrage executions allowed per second.
+    ///
+    /// 0 disables rate limiting. Defaults to 10.
+    #[serde(default = "default_max_orders_per_sec")]
+    pub max_orders_per_sec: f64,
+
+    /// Executions allowed back to back before the rate limit applies.
+    ///
+    /// Defaults to 20.
+    #[serde(default = "default_burst")]
+    pub burst: u32,
+
+    /// Milliseconds an execution may wait for the rate limit before it is
+    /// dropped.
+    ///
+    /// Defaults to 250.
+    #[serde(default = "default_max_order_wait_ms")]
+    pub max_order_wait_ms: u64,
+
+    /// Executions allowed in flight at once.
+    ///
+    /// Opportunities approved while every slot is taken are dropped as
+    /// `concurrency_limited`. 0 disables the limit. Defaults to 0.
+    #[serde(default)]
+    pub max_concurrent: usize,
+
+    /// Order in which opportunities detected by one market event are
+    /// executed: "profit", "edge", or "volume", highest first.
+    ///
+    /// Earlier opportunities claim the exposure budget first. Defaults to
+    /// "profit" (expected profit).
+    #[serde(default)]
+    pub priority: OpportunityPriority,
+
+    /// Re-price every leg from the current top of book before executing.
+    ///
+    /// Opportunities whose edge is gone are rejected as `edge_disappeared`.
+    /// Defaults to false.
+    #[serde(default)]
+    pub verify_before_execute: bool,
+
+    /// Milliseconds a market is left alone after a failed or partial
+    /// execution.
+    ///
+    /// Detection keeps running, but opportunities on the market are
+    /// rejected until the cooldown lapses. 0 disables the cooldown.
+    /// Defaults to 0.
+    #[serde(default)]
+    pub market_cooldown_ms: u64,
+
+    /// Milliseconds after detection beyond which an opportunity is no
+    /// longer executed.
+    ///
+    /// Older opportunities are rejected as `opportunity_stale`. 0 disables
+    /// the check. Defaults to 0.
+    #[serde(default)]
+    pub max_opportunity_age_ms: u64,
+
+    /// Exit every open position at market when a shutdown signal arrives.
+    ///
+    /// Legs are sold at the best bid lowered by `unwind_discount`.
+    /// Defaults to false (positions are left open).
+    #[serde(default)]
+    pub drain_on_shutdown: bool,
+
+    /// Seconds allowed for the shutdown drain before giving up.
+    ///
+    /// Defaults to 30.
+    #[serde(default = "default_drain_timeout_secs")]
+    pub drain_timeout_secs: u64,
+}
+
+const fn default_retry_attempts() -> u32 {
+    2
+}
+
+fn default_retry_price_step() -> Decimal {
+    Decimal::new(1, 2)
+}
+
+fn default_unwind_discount() -> Decimal {
+    Decimal::new(2, 2)
+}
+
+const fn default_leg_timeout_ms() -> u64 {
+    5_000
+}
+
+const fn default_max_orders_per_sec() -> f64 {
+    10.0
+}
+
+const fn default_burst() -> u32 {
+    20
+}
+
+const fn default_max_order_wait_ms() -> u64 {
+    250
+}
+
+const fn default_drain_timeout_secs() -> u64 {
+    30
+}
+
+impl Default for ExecutionConfig {
+    fn default() -> Self {
+        Self {
+            on_partial: PartialFillPolicy::default(),
+            retry_attempts: default_retry_attempts(),
+            retry_price_step: default_retry_price_step(),
+            unwind_discount: default_unwind_discount(),
+            leg_timeout_ms: default_leg_timeout_ms(),
+            max_orders_per_sec: default_max_orders_per_sec(),
+            burst: default_burst(),
+            max_order_wait_ms: default_max_order_wait_ms(),
+            max_concurrent: 0,
+            priority: OpportunityPriority::default(),
+            verify_before_execute: false,
+            market_cooldown_ms: 0,
+            max_opportunity_age_ms: 0,
+            drain_on_shutdown: false,
+            drain_timeout_secs: default_drain_timeout_secs(),
+        }
+    }
+}
+
+impl ExecutionConfig {
+    /// Deadline for each arbitrage leg to fill, or `None` if disabled.
+    #[must_use]
+    pub const fn leg_timeout(&self) -> Option<Duration> {
+        if self.leg_timeout_ms == 0 {
+            return None;
+        }
+        Some(Duration::from_millis(self.leg_timeout_ms))
+    }
+
+    /// Cooldown applied to a market after a failed execution, or `None` if
+    /// disabled.
+    #[must_use]
+    pub const fn market_cooldown(&self) -> Option<Duration> {
+        if self.market_cooldown_ms == 0 {
+            return None;
+        }
+        Some(Duration::from_millis(self.market_cooldown_ms))
+    }
+
+    /// Oldest opportunity that may still be executed, or `None` if disabled.
+    #[must_use]
+    pub const fn max_opportunity_age(&self) -> Option<Duration> {
+        if self.max_opportunity_age_ms == 0 {
+            return None;
+        }
+        Some(Duration::from_millis(self.max_opportunity_age_ms))
+    }
+}
+
+impl From<&ExecutionConfig> for PartialFillRecovery {
+    fn from(config: &ExecutionConfig) -> Self {
+        Self {
+            policy: config.on_partial,
+            retry_attempts: config.retry_attempts,
+            retry_price_step: config.retry_price_step,
+            unwind_discount: config.unwind_discount,
+        }
+    }
+}
+
+impl From<&ExecutionConfig> for RateLimiter {
+    fn from(config: &ExecutionConfig) -> Self {
+        if config.max_orders_per_sec <= 0.0 {
+            return Self::unlimited();
+        }
+        Self::new(config.max_orders_per_sec, config.burst)
+            .with_max_wait(Duration::from_millis(config.max_order_wait_ms))
+    }
+}
+
+impl From<&ExecutionConfig> for ExecutionSlots {
+    fn from(config: &ExecutionConfig) -> Self {
+        Self::new(config.max_concurrent)
+    }
+}
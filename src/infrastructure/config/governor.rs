@@ -3,7 +3,11 @@
 //! Provides configuration for the governor subsystem that dynamically adjusts
 //! subscription counts based on system resource utilization and latency targets.
 
-use serde::Deserialize;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::governor::runtime::{GovernorConfig, LatencyTargets, ScalingConfig};
 
 const fn default_true() -> bool {
     true
@@ -53,7 +57,7 @@ const fn default_cooldown_secs() -> u64 {
 ///
 /// Defines acceptable latency percentiles. The governor uses these targets
 /// to determine when to scale subscriptions up or down.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct LatencyTargetsConfig {
     /// Target p50 (median) latency in milliseconds.
     ///
@@ -99,7 +103,7 @@ impl Default for LatencyTargetsConfig {
 ///
 /// Controls how aggressively the governor adjusts subscription counts
 /// based on resource utilization and latency measurements.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ScalingAppConfig {
     /// Interval between scaling checks in seconds.
     ///
@@ -158,7 +162,7 @@ impl Default for ScalingAppConfig {
 ///
 /// Top-level configuration for the governor subsystem that dynamically
 /// adjusts subscription counts based on observed performance.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct GovernorAppConfig {
     /// Enable the governor for adaptive scaling.
     ///
@@ -188,3 +192,28 @@ impl Default for GovernorAppConfig {
         }
     }
 }
+
+impl From<&GovernorAppConfig> for GovernorConfig {
+    fn from(config: &GovernorAppConfig) -> Self {
+        let latency = &config.latency;
+        let scaling = &config.scaling;
+        Self {
+            enabled: config.enabled,
+            latency: LatencyTargets {
+                target_p50: Duration::from_millis(latency.target_p50_ms),
+                target_p95: Duration::from_millis(latency.target_p95_ms),
+                target_p99: Duration::from_millis(latency.target_p99_ms),
+                max_p99: Duration::from_millis(latency.max_p99_ms),
+            },
+            scaling: ScalingConfig {
+                check_interval: Duration::from_secs(scaling.check_interval_secs),
+                expand_threshold: scaling.expand_threshold,
+                contract_threshold: scaling.contract_threshold,
+                expand_step: scaling.expand_step,
+                contract_step: scaling.contract_step,
+                cooldown: Duration::from_secs(scaling.cooldown_secs),
+                hysteresis: ScalingConfig::default().hysteresis,
+            },
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Layering an environment overlay over a base configuration.
+//!
+//! A configuration directory holds a `base.toml` shared by every environment
+//! and one overlay per environment, such as `mainnet.toml`. The overlay is
+//! deep-merged over the base as raw TOML before anything is deserialized, so
+//! it only needs the settings that differ.
+
+use toml::{Table, Value};
+
+use crate::error::{ConfigError, Result};
+
+/// Merge `overlay` over `base` and return the combined TOML content.
+///
+/// Tables are merged key by key at every depth. Any other value in the
+/// overlay, arrays included, replaces the base value outright.
+///
+/// # Errors
+///
+/// Returns an error if either input is not valid TOML or the merged table
+/// cannot be serialized.
+#[allow(clippy::result_large_err)]
+pub fn merge_toml(base: &str, overlay: &str) -> Result<String> {
+    let mut merged: Table = base.parse().map_err(ConfigError::Parse)?;
+    let overlay: Table = overlay.parse().map_err(ConfigError::Parse)?;
+    merge_tables(&mut merged, overlay);
+    toml::to_string(&merged)
+        .map_err(|e| ConfigError::Other(format!("failed to serialize config: {e}")).into())
+}
+
+fn merge_tables(base: &mut Table, overlay: Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Table(base)), Value::Table(overlay)) => merge_tables(base, overlay),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlay_overrides_nested_keys_and_keeps_the_rest() {
+        let base = r#"
+dry_run = true
+
+[risk]
+max_slippage = 0.02
+max_total_exposure = 1000
+"#;
+        let overlay = r#"
+dry_run = false
+
+[risk]
+max_total_exposure = 5000
+"#;
+
+        let merged: Table = merge_toml(base, overlay).unwrap().parse().unwrap();
+
+        assert_eq!(merged["dry_run"].as_bool(), Some(false));
+        assert_eq!(merged["risk"]["max_slippage"].as_float(), Some(0.02));
+        assert_eq!(
+            merged["risk"]["max_total_exposure"].as_integer(),
+            Some(5000)
+        );
+    }
+
+    #[test]
+    fn overlay_replaces_arrays_and_adds_sections() {
+        let base = "[strategies]\nenabled = [\"single_condition\", \"market_rebalancing\"]\n";
+        let overlay =
+            "[strategies]\nenabled = [\"single_condition\"]\n\n[telegram]\nenabled = true\n";
+
+        let merged: Table = merge_toml(base, overlay).unwrap().parse().unwrap();
+
+        assert_eq!(
+            merged["strategies"]["enabled"].as_array().map(Vec::len),
+            Some(1)
+        );
+        assert_eq!(merged["telegram"]["enabled"].as_bool(), Some(true));
+    }
+
+    #[test]
+    fn overlay_value_replaces_base_table() {
+        let merged: Table = merge_toml("[a]\nb = 1\n", "a = 2\n")
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(merged["a"].as_integer(), Some(2));
+    }
+
+    #[test]
+    fn invalid_overlay_is_rejected() {
+        assert!(merge_toml("dry_run = true\n", "dry_run = ").is_err());
+    }
+}
@@ -3,14 +3,14 @@
 //! Provides configuration for Large Language Model providers used for
 //! market relationship inference.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// LLM provider configuration.
 ///
 /// Configures which LLM provider to use and provider-specific settings.
 /// API keys are read from environment variables (`ANTHROPIC_API_KEY` or
 /// `OPENAI_API_KEY`) at runtime.
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct LlmConfig {
     /// LLM provider to use for inference.
     ///
@@ -34,7 +34,7 @@ pub struct LlmConfig {
 /// LLM provider selection.
 ///
 /// Determines which LLM API to use for market relationship inference.
-#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum LlmProvider {
     /// Anthropic Claude models.
@@ -44,11 +44,22 @@ pub enum LlmProvider {
     OpenAi,
 }
 
+impl LlmProvider {
+    /// Environment variable holding the provider's API key.
+    #[must_use]
+    pub const fn api_key_var(self) -> &'static str {
+        match self {
+            Self::Anthropic => "ANTHROPIC_API_KEY",
+            Self::OpenAi => "OPENAI_API_KEY",
+        }
+    }
+}
+
 /// Anthropic-specific configuration.
 ///
 /// Settings for Anthropic Claude API. Requires `ANTHROPIC_API_KEY`
 /// environment variable.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AnthropicConfig {
     /// Model identifier.
     ///
@@ -83,7 +94,7 @@ impl Default for AnthropicConfig {
 /// OpenAI-specific configuration.
 ///
 /// Settings for OpenAI API. Requires `OPENAI_API_KEY` environment variable.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OpenAiConfig {
     /// Model identifier.
     ///
@@ -1,16 +1,19 @@
 //! Logging configuration and initialization.
 //!
 //! Provides configuration for the tracing subscriber used throughout the
-//! application. Supports both pretty-printed and JSON output formats.
+//! application. Supports both pretty-printed and JSON output formats, and
+//! sampling of high-volume detection logs.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tracing_subscriber::{fmt, EnvFilter};
 
+use crate::application::sampling;
+
 /// Logging configuration.
 ///
 /// Controls log level filtering and output format. The `RUST_LOG` environment
 /// variable takes precedence over the configured level.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct LoggingConfig {
     /// Log level filter string.
     ///
@@ -24,14 +27,27 @@ pub struct LoggingConfig {
     /// Supported values: "pretty" (human-readable) or "json" (structured).
     /// Defaults to "pretty".
     pub format: String,
+
+    /// Log one in this many hot-path detection and trace events.
+    ///
+    /// Applies to per-event `debug` and `trace` logs in the event handler and
+    /// cluster detection service; warnings and errors are never sampled.
+    /// Defaults to 1 (log everything).
+    #[serde(default = "default_sample_rate")]
+    pub sample_rate: u64,
+}
+
+const fn default_sample_rate() -> u64 {
+    1
 }
 
 impl LoggingConfig {
     /// Initialize the tracing subscriber with this configuration.
     ///
-    /// Configures the global tracing subscriber. Should be called once at
-    /// application startup.
+    /// Configures the global tracing subscriber and the log sample rate.
+    /// Should be called once at application startup.
     pub fn init(&self) {
+        sampling::set_sample_rate(self.sample_rate);
         let filter =
             EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&self.level));
 
@@ -51,6 +67,7 @@ impl Default for LoggingConfig {
         Self {
             level: "info".into(),
             format: "pretty".into(),
+            sample_rate: default_sample_rate(),
         }
     }
 }
@@ -0,0 +1,36 @@
+//! Prometheus metrics export configuration.
+//!
+//! Provides configuration for the optional HTTP endpoint that serves runtime
+//! metrics in Prometheus text format. The server is only available when the
+//! crate is built with the `metrics` feature.
+
+use serde::{Deserialize, Serialize};
+
+/// Prometheus metrics endpoint configuration.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MetricsConfig {
+    /// Whether to serve `/metrics` while the bot runs.
+    ///
+    /// Ignored unless built with the `metrics` feature. Defaults to false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Socket address the metrics server listens on.
+    ///
+    /// Defaults to "127.0.0.1:9090".
+    #[serde(default = "default_bind_addr")]
+    pub bind_addr: String,
+}
+
+fn default_bind_addr() -> String {
+    "127.0.0.1:9090".to_string()
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind_addr: default_bind_addr(),
+        }
+    }
+}
@@ -0,0 +1,348 @@
+//! Upgrading configuration files written for older releases.
+//!
+//! The file is handled as a raw [`toml::Table`] so that keys this release no
+//! longer understands can be found rather than silently ignored. Legacy keys
+//! are renamed or removed, settings added since the file was written are
+//! filled in with their defaults, and unrecognized keys are reported but
+//! kept. Comments are not preserved.
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use toml::{Table, Value};
+
+use super::logging::LoggingConfig;
+use super::settings::Config;
+use crate::error::{ConfigError, Result};
+
+/// Keys renamed since earlier releases, as `(old, new)` dotted paths.
+const RENAMED: &[(&str, &str)] = &[("polymarket", "exchange_config")];
+
+/// Keys no longer read from the file, with the reason they were dropped.
+const REMOVED: &[(&str, &str)] = &[(
+    "wallet.private_key",
+    "the private key is only read from WALLET_PRIVATE_KEY, a keystore, or the OS keyring",
+)];
+
+/// A single change made, or issue found, while migrating a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationChange {
+    /// A legacy key was moved to its current name.
+    Renamed { from: String, to: String },
+    /// A legacy key was dropped.
+    Removed { path: String, reason: String },
+    /// A key this release does not recognize; it is kept but ignored.
+    Unknown { path: String },
+    /// A missing setting was filled in with its default.
+    Defaulted { path: String },
+}
+
+/// Result of migrating a configuration file.
+#[derive(Debug, Clone)]
+pub struct Migration {
+    /// The upgraded file content.
+    pub config_toml: String,
+    /// Changes applied and issues found, in the order they were made.
+    pub changes: Vec<MigrationChange>,
+}
+
+impl Migration {
+    /// True when the file needed no changes.
+    ///
+    /// Unknown keys alone do not count, since they are left in place.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes
+            .iter()
+            .all(|change| matches!(change, MigrationChange::Unknown { .. }))
+    }
+}
+
+/// Upgrade raw TOML configuration content to the current format.
+///
+/// # Errors
+///
+/// Returns an error if the content is not valid TOML, has values of the
+/// wrong type, or the upgraded file cannot be serialized.
+#[allow(clippy::result_large_err)]
+pub fn migrate(content: &str) -> Result<Migration> {
+    let mut table: Table = content.parse().map_err(ConfigError::Parse)?;
+    let mut changes = Vec::new();
+
+    for (from, to) in RENAMED {
+        rename(&mut table, from, to, &mut changes);
+    }
+    for (path, reason) in REMOVED {
+        if remove_path(&mut table, path).is_some() {
+            changes.push(MigrationChange::Removed {
+                path: (*path).to_string(),
+                reason: (*reason).to_string(),
+            });
+        }
+    }
+    // `logging` has no default, so an old file without it cannot be read.
+    if !table.contains_key("logging") {
+        table.insert("logging".to_string(), to_value(&LoggingConfig::default())?);
+        changes.push(MigrationChange::Defaulted {
+            path: "logging".to_string(),
+        });
+    }
+
+    let config = Config::from_file_toml(&to_string(&table)?)?;
+    let Value::Table(current) = to_value(&config)? else {
+        return Err(
+            ConfigError::Other("configuration must serialize to a table".to_string()).into(),
+        );
+    };
+    find_unknown("", &table, &current, &mut changes);
+    fill_defaults("", &mut table, &current, &mut changes);
+
+    let config_toml = to_string(&table)?;
+    // The upgraded file must load, or the user is left worse off.
+    Config::from_file_toml(&config_toml)?;
+    Ok(Migration {
+        config_toml,
+        changes,
+    })
+}
+
+/// Move the value at `from` to `to` unless `to` is already set.
+fn rename(table: &mut Table, from: &str, to: &str, changes: &mut Vec<MigrationChange>) {
+    let Some(mut value) = remove_path(table, from) else {
+        return;
+    };
+    if get_path(table, to).is_some() {
+        changes.push(MigrationChange::Removed {
+            path: from.to_string(),
+            reason: format!("superseded by `{to}`"),
+        });
+        return;
+    }
+
+    // The legacy `[polymarket]` section predates the exchange type tag.
+    if from == "polymarket" {
+        if let Some(section) = value.as_table_mut() {
+            section
+                .entry("type")
+                .or_insert_with(|| Value::String("polymarket".to_string()));
+        }
+    }
+    insert_path(table, to, value);
+    changes.push(MigrationChange::Renamed {
+        from: from.to_string(),
+        to: to.to_string(),
+    });
+}
+
+/// Report keys in `table` that the current configuration does not have.
+fn find_unknown(prefix: &str, table: &Table, current: &Table, changes: &mut Vec<MigrationChange>) {
+    for (key, value) in table {
+        let path = join(prefix, key);
+        match (value, current.get(key)) {
+            (_, None) => changes.push(MigrationChange::Unknown { path }),
+            (Value::Table(table), Some(Value::Table(current))) => {
+                find_unknown(&path, table, current, changes);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Insert every setting present in `current` but missing from `table`.
+///
+/// A missing section is inserted whole and reported once.
+fn fill_defaults(
+    prefix: &str,
+    table: &mut Table,
+    current: &Table,
+    changes: &mut Vec<MigrationChange>,
+) {
+    for (key, default) in current {
+        let path = join(prefix, key);
+        match (table.get_mut(key), default) {
+            (None, _) => {
+                table.insert(key.clone(), plain_numbers(default.clone()));
+                changes.push(MigrationChange::Defaulted { path });
+            }
+            (Some(Value::Table(table)), Value::Table(current)) => {
+                fill_defaults(&path, table, current, changes);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Write decimals, which serialize as strings, as TOML numbers.
+fn plain_numbers(value: Value) -> Value {
+    match value {
+        Value::String(s) if Decimal::from_str(&s).is_ok() => {
+            s.parse::<f64>().map_or(Value::String(s), Value::Float)
+        }
+        Value::Table(table) => Value::Table(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, plain_numbers(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(plain_numbers).collect()),
+        other => other,
+    }
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+fn get_path<'a>(table: &'a Table, path: &str) -> Option<&'a Value> {
+    match path.split_once('.') {
+        None => table.get(path),
+        Some((head, rest)) => get_path(table.get(head)?.as_table()?, rest),
+    }
+}
+
+fn remove_path(table: &mut Table, path: &str) -> Option<Value> {
+    match path.split_once('.') {
+        None => table.remove(path),
+        Some((head, rest)) => remove_path(table.get_mut(head)?.as_table_mut()?, rest),
+    }
+}
+
+fn insert_path(table: &mut Table, path: &str, value: Value) {
+    match path.split_once('.') {
+        None => {
+            table.insert(path.to_string(), value);
+        }
+        Some((head, rest)) => {
+            let child = table
+                .entry(head)
+                .or_insert_with(|| Value::Table(Table::new()));
+            if let Some(child) = child.as_table_mut() {
+                insert_path(child, rest, value);
+            }
+        }
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn to_value<T: serde::Serialize>(value: &T) -> Result<Value> {
+    Value::try_from(value)
+        .map_err(|e| ConfigError::Other(format!("failed to serialize config: {e}")).into())
+}
+
+#[allow(clippy::result_large_err)]
+fn to_string(table: &Table) -> Result<String> {
+    toml::to_string_pretty(table)
+        .map_err(|e| ConfigError::Other(format!("failed to serialize config: {e}")).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    const LOGGING: &str = r#"
+[logging]
+level = "info"
+format = "pretty"
+"#;
+
+    #[test]
+    fn current_config_needs_no_changes() {
+        let current = to_string(match &to_value(&Config::default()).unwrap() {
+            Value::Table(table) => table,
+            _ => unreachable!(),
+        })
+        .unwrap();
+
+        let migration = migrate(&current).unwrap();
+
+        assert!(migration.is_empty(), "{:?}", migration.changes);
+    }
+
+    #[test]
+    fn fills_missing_settings_with_defaults() {
+        let content = format!("dry_run = true\n{LOGGING}\n[risk]\nmax_slippage = 0.05\n");
+
+        let migration = migrate(&content).unwrap();
+
+        assert!(migration.changes.contains(&MigrationChange::Defaulted {
+            path: "risk.max_total_exposure".to_string(),
+        }));
+        assert!(migration.changes.contains(&MigrationChange::Defaulted {
+            path: "connection_pool".to_string(),
+        }));
+        let config = Config::from_file_toml(&migration.config_toml).unwrap();
+        assert!(config.dry_run);
+        assert_eq!(config.risk.max_slippage, dec!(0.05));
+        assert_eq!(
+            config.risk.max_total_exposure,
+            Config::default().risk.max_total_exposure
+        );
+    }
+
+    #[test]
+    fn renames_legacy_polymarket_section() {
+        let content = format!("{LOGGING}\n[polymarket]\nchain_id = 137\n");
+
+        let migration = migrate(&content).unwrap();
+
+        assert_eq!(
+            migration.changes[0],
+            MigrationChange::Renamed {
+                from: "polymarket".to_string(),
+                to: "exchange_config".to_string(),
+            }
+        );
+        let table: Table = migration.config_toml.parse().unwrap();
+        assert!(!table.contains_key("polymarket"));
+        assert_eq!(
+            get_path(&table, "exchange_config.type").and_then(Value::as_str),
+            Some("polymarket")
+        );
+        assert_eq!(
+            get_path(&table, "exchange_config.chain_id").and_then(Value::as_integer),
+            Some(137)
+        );
+    }
+
+    #[test]
+    fn removes_private_key_and_reports_unknown_keys() {
+        let content =
+            format!("{LOGGING}\n[wallet]\nprivate_key = \"0xabc\"\n\n[risk]\nmax_slipage = 0.05\n");
+
+        let migration = migrate(&content).unwrap();
+
+        assert!(matches!(
+            &migration.changes[0],
+            MigrationChange::Removed { path, .. } if path == "wallet.private_key"
+        ));
+        assert!(migration.changes.contains(&MigrationChange::Unknown {
+            path: "risk.max_slipage".to_string(),
+        }));
+        assert!(!migration.config_toml.contains("0xabc"));
+        // Unknown keys are kept so typos can be fixed by hand.
+        assert!(migration.config_toml.contains("max_slipage"));
+    }
+
+    #[test]
+    fn missing_logging_section_is_added() {
+        let migration = migrate("dry_run = true\n").unwrap();
+
+        assert_eq!(
+            migration.changes[0],
+            MigrationChange::Defaulted {
+                path: "logging".to_string(),
+            }
+        );
+        assert!(Config::from_file_toml(&migration.config_toml).is_ok());
+    }
+
+    #[test]
+    fn invalid_toml_is_rejected() {
+        assert!(migrate("dry_run = ").is_err());
+    }
+}
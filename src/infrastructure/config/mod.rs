@@ -2,30 +2,63 @@
 //!
 //! This module provides configuration types for all infrastructure components.
 //! Configuration is typically loaded from TOML files with environment variable
-//! overrides for sensitive values.
+//! overrides for sensitive values and `EDGELORD__<SECTION>__<FIELD>` overrides
+//! for any other setting.
 //!
 //! # Submodules
 //!
 //! - [`cluster`] - Cluster detection service configuration
+//! - [`control`] - Control API server configuration
+//! - [`diff`] - Comparison of a configuration against defaults
+//! - [`discord`] - Discord notification configuration
+//! - [`discovery`] - Periodic market re-scan configuration
+//! - [`env`] - Environment variable overrides for configuration fields
+//! - [`execution`] - Partial fill handling for trade execution
 //! - [`governor`] - Adaptive subscription scaling configuration
+//! - [`layer`] - Layering environment overlays over a base configuration
 //! - [`llm`] - LLM provider configuration for inference
 //! - [`logging`] - Logging and tracing configuration
+//! - [`metrics`] - Prometheus metrics endpoint configuration
+//! - [`migrate`] - Upgrading configuration files from older releases
 //! - [`pool`] - WebSocket connection pool configuration
 //! - [`profile`] - Resource profile configuration
+//! - [`replay`] - Order book replay configuration
 //! - [`risk`] - Risk management limits
+//! - [`scoring`] - Market scoring weights for subscription priority
 //! - [`settings`] - Main application configuration
+//! - [`settlement`] - Market settlement polling
+//! - [`shadow`] - Shadow strategy set for side-by-side comparison
+//! - [`slack`] - Slack notification configuration
+//! - [`snapshot`] - Order book snapshot for warm restarts
 //! - [`strategy`] - Detection strategy configuration
 //! - [`telegram`] - Telegram notification configuration
 //! - [`wallet`] - Wallet and signing configuration
+//! - [`webhook`] - Generic HTTP webhook notification configuration
 
 pub mod cluster;
+pub mod control;
+pub mod diff;
+pub mod discord;
+pub mod discovery;
+pub mod env;
+pub mod execution;
 pub mod governor;
+pub mod layer;
 pub mod llm;
 pub mod logging;
+pub mod metrics;
+pub mod migrate;
 pub mod pool;
 pub mod profile;
+pub mod replay;
 pub mod risk;
+pub mod scoring;
 pub mod settings;
+pub mod settlement;
+pub mod shadow;
+pub mod slack;
+pub mod snapshot;
 pub mod strategy;
 pub mod telegram;
 pub mod wallet;
+pub mod webhook;
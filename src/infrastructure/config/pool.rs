@@ -3,13 +3,15 @@
 //! Provides configuration for WebSocket connection pooling and automatic
 //! reconnection with exponential backoff.
 
-use serde::Deserialize;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
 
 /// WebSocket reconnection configuration.
 ///
 /// Controls automatic reconnection behavior with exponential backoff and
 /// circuit breaker protection against repeated failures.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ReconnectionConfig {
     /// Initial delay before first reconnection attempt in milliseconds.
     ///
@@ -42,6 +44,52 @@ pub struct ReconnectionConfig {
     /// Defaults to 300000 (5 minutes).
     #[serde(default = "default_circuit_breaker_cooldown_ms")]
     pub circuit_breaker_cooldown_ms: u64,
+
+    /// Randomization applied to each backoff delay.
+    ///
+    /// Spreads reconnections from many connections so they do not retry in
+    /// lockstep after an outage. Defaults to `additive`.
+    #[serde(default)]
+    pub jitter: BackoffJitter,
+
+    /// Minimum delay before reconnecting after a rate-limit disconnect in
+    /// milliseconds.
+    ///
+    /// Applies when the exchange answers with HTTP 429 or closes with a
+    /// rate-limit reason. Defaults to 60000 (60 seconds).
+    #[serde(default = "default_rate_limit_delay_ms")]
+    pub rate_limit_delay_ms: u64,
+
+    /// Consecutive authentication failures before reconnection stops.
+    ///
+    /// Retrying rejected credentials cannot succeed, so the stream ends
+    /// after this many. Defaults to 3.
+    #[serde(default = "default_max_auth_failures")]
+    pub max_auth_failures: u32,
+
+    /// Maximum time without market events before the stream is restarted,
+    /// in milliseconds.
+    ///
+    /// Catches connections that stay open but stop delivering data. Set it
+    /// well above the quietest expected period. Zero disables the check.
+    /// Defaults to 300000 (5 minutes).
+    #[serde(default = "default_idle_timeout_ms")]
+    pub idle_timeout_ms: u64,
+}
+
+/// Jitter strategy for reconnection backoff delays.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackoffJitter {
+    /// Add up to 20% of the computed delay on top of it.
+    #[default]
+    Additive,
+    /// Use the computed delay as-is.
+    None,
+    /// Pick a random delay between zero and the computed delay.
+    Full,
+    /// Keep half the computed delay and randomize the other half.
+    Equal,
 }
 
 fn default_initial_delay_ms() -> u64 {
@@ -64,6 +112,18 @@ fn default_circuit_breaker_cooldown_ms() -> u64 {
     300000 // 5 minutes
 }
 
+fn default_rate_limit_delay_ms() -> u64 {
+    60000 // 60 seconds
+}
+
+fn default_max_auth_failures() -> u32 {
+    3
+}
+
+fn default_idle_timeout_ms() -> u64 {
+    300000 // 5 minutes
+}
+
 impl Default for ReconnectionConfig {
     fn default() -> Self {
         Self {
@@ -72,16 +132,29 @@ impl Default for ReconnectionConfig {
             backoff_multiplier: default_backoff_multiplier(),
             max_consecutive_failures: default_max_consecutive_failures(),
             circuit_breaker_cooldown_ms: default_circuit_breaker_cooldown_ms(),
+            jitter: BackoffJitter::default(),
+            rate_limit_delay_ms: default_rate_limit_delay_ms(),
+            max_auth_failures: default_max_auth_failures(),
+            idle_timeout_ms: default_idle_timeout_ms(),
         }
     }
 }
 
+impl ReconnectionConfig {
+    /// Idle time after which the market stream is restarted, or `None` when
+    /// stall detection is disabled.
+    #[must_use]
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        (self.idle_timeout_ms > 0).then(|| Duration::from_millis(self.idle_timeout_ms))
+    }
+}
+
 /// Connection pool configuration for WebSocket multiplexing.
 ///
 /// Controls how multiple WebSocket connections are managed to distribute
 /// subscriptions across connections. This helps avoid per-connection
 /// subscription limits imposed by exchanges.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ConnectionPoolConfig {
     /// Maximum number of connections in the pool.
     ///
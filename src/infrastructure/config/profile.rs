@@ -2,16 +2,22 @@
 //!
 //! Provides configuration for resource budgets that control how many
 //! subscriptions the system can handle based on available memory and CPU.
+//! The sized profiles (`low`, `medium`, `high`) also bundle market and
+//! connection limits that `run --profile` applies on top of a config file.
 
-use serde::Deserialize;
+use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
+
+use super::settings::{Config, ExchangeSpecificConfig};
+use crate::error::ConfigError;
 use crate::infrastructure::governor::resource::ResourceBudget;
 
 /// Application profile for resource allocation.
 ///
 /// Provides preset resource configurations suitable for different deployment
 /// scenarios.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Profile {
     /// Local development with conservative resource usage.
@@ -27,13 +33,113 @@ pub enum Profile {
     ///
     /// Ignores preset limits and uses only explicitly configured values.
     Custom,
+    /// Laptop-sized preset: the [`Local`](Self::Local) budget with small
+    /// market and connection limits.
+    Low,
+    /// Mid-sized preset for a small dedicated host.
+    Medium,
+    /// Server-sized preset: the [`Production`](Self::Production) budget with
+    /// large market and connection limits.
+    High,
+}
+
+/// Market and connection limits bundled with a sized [`Profile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProfilePreset {
+    /// Maximum markets fetched and tracked.
+    pub max_markets: usize,
+    /// Maximum total subscriptions (Polymarket market filter).
+    pub max_subscriptions: usize,
+    /// Maximum WebSocket connections in the pool.
+    pub max_connections: usize,
+    /// Subscriptions per pooled connection.
+    pub subscriptions_per_connection: usize,
+}
+
+impl Profile {
+    /// Return the limits bundled with this profile.
+    ///
+    /// Only the sized profiles carry a preset; `local`, `production`, and
+    /// `custom` return `None`.
+    #[must_use]
+    pub const fn preset(self) -> Option<ProfilePreset> {
+        match self {
+            Self::Low => Some(ProfilePreset {
+                max_markets: 100,
+                max_subscriptions: 400,
+                max_connections: 2,
+                subscriptions_per_connection: 200,
+            }),
+            Self::Medium => Some(ProfilePreset {
+                max_markets: 500,
+                max_subscriptions: 2000,
+                max_connections: 5,
+                subscriptions_per_connection: 400,
+            }),
+            Self::High => Some(ProfilePreset {
+                max_markets: 2000,
+                max_subscriptions: 8000,
+                max_connections: 20,
+                subscriptions_per_connection: 500,
+            }),
+            Self::Local | Self::Production | Self::Custom => None,
+        }
+    }
+
+    /// Select this profile and apply its preset limits to `config`.
+    ///
+    /// Overwrites the exchange's `max_markets` (and `max_subscriptions` on
+    /// Polymarket) and the connection pool size. Profiles without a preset
+    /// only change the resource budget.
+    pub fn apply_to(self, config: &mut Config) {
+        config.profile = self;
+        let Some(preset) = self.preset() else {
+            return;
+        };
+
+        match &mut config.exchange_config {
+            ExchangeSpecificConfig::Polymarket(exchange) => {
+                exchange.market_filter.max_markets = preset.max_markets;
+                exchange.market_filter.max_subscriptions = preset.max_subscriptions;
+            }
+            #[cfg(feature = "kalshi")]
+            ExchangeSpecificConfig::Kalshi(exchange) => {
+                exchange.market_filter.max_markets = preset.max_markets;
+            }
+            #[cfg(feature = "manifold")]
+            ExchangeSpecificConfig::Manifold(exchange) => {
+                exchange.market_filter.max_markets = preset.max_markets;
+            }
+        }
+        config.connection_pool.max_connections = preset.max_connections;
+        config.connection_pool.subscriptions_per_connection = preset.subscriptions_per_connection;
+    }
+}
+
+impl FromStr for Profile {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "local" => Ok(Self::Local),
+            "production" => Ok(Self::Production),
+            "custom" => Ok(Self::Custom),
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            other => Err(ConfigError::InvalidValue {
+                field: "profile",
+                reason: format!("unknown profile \"{other}\""),
+            }),
+        }
+    }
 }
 
 /// Resource configuration for adaptive subscription management.
 ///
 /// Fine-grained control over memory and CPU budgets. Used by the governor
 /// to determine maximum subscription capacity.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ResourceConfig {
     /// Enable automatic system resource detection at startup.
     ///
@@ -67,6 +173,13 @@ pub struct ResourceConfig {
     /// of available capacity. Defaults to 0.70 (70%).
     #[serde(default = "default_cpu_usage_target")]
     pub cpu_usage_target: f64,
+
+    /// Maximum order book levels kept per side for each token.
+    ///
+    /// Levels beyond the best `max_book_depth` are dropped on every update.
+    /// Defaults to `None` (keep every level).
+    #[serde(default)]
+    pub max_book_depth: Option<usize>,
 }
 
 fn default_memory_usage_target() -> f64 {
@@ -85,6 +198,7 @@ impl Default for ResourceConfig {
             worker_threads: None,
             memory_usage_target: default_memory_usage_target(),
             cpu_usage_target: default_cpu_usage_target(),
+            max_book_depth: None,
         }
     }
 }
@@ -98,8 +212,9 @@ impl ResourceConfig {
     pub fn to_budget(&self, profile: Profile) -> ResourceBudget {
         // Start with profile-based defaults
         let base = match profile {
-            Profile::Local => ResourceBudget::local(),
-            Profile::Production => ResourceBudget::production(),
+            Profile::Local | Profile::Low => ResourceBudget::local(),
+            Profile::Medium => ResourceBudget::medium(),
+            Profile::Production | Profile::High => ResourceBudget::production(),
             Profile::Custom => ResourceBudget::local(), // Start with local as base for custom
         };
 
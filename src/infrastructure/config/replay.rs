@@ -0,0 +1,20 @@
+//! Order book replay configuration.
+//!
+//! When set, the runtime reads market data from a recorded file instead of
+//! connecting to the exchange WebSocket.
+
+use serde::{Deserialize, Serialize};
+
+/// Replay source for backtesting against recorded order books.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReplayConfig {
+    /// Path to an NDJSON or CSV (`.csv`) recording of order book events.
+    pub path: String,
+
+    /// Playback speed multiplier for inter-event timing.
+    ///
+    /// 1.0 replays in real time, 2.0 twice as fast. Zero or below replays
+    /// without delay. Defaults to 0.0.
+    #[serde(default)]
+    pub speed: f64,
+}
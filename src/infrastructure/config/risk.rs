@@ -3,16 +3,21 @@
 //! Provides configuration for position sizing, exposure limits, and trade
 //! execution parameters.
 
+use std::time::Duration;
+
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
+use crate::application::position::manager::ExitPolicy;
+use crate::application::risk::breaker::FailureBreaker;
+use crate::application::risk::sizing::PositionSizer;
 use crate::application::state::RiskLimits;
 
 /// Risk management configuration.
 ///
 /// Controls position sizing limits, exposure caps, and execution parameters
 /// to manage trading risk.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RiskConfig {
     /// Maximum position size per market in dollars.
     ///
@@ -33,6 +38,13 @@ pub struct RiskConfig {
     #[serde(default = "default_min_profit_threshold")]
     pub min_profit_threshold: Decimal,
 
+    /// Minimum profit as a fraction of the capital deployed.
+    ///
+    /// E.g., 0.01 rejects opportunities whose expected profit is less than
+    /// 1% of their total cost. Disabled if unset.
+    #[serde(default)]
+    pub min_profit_pct: Option<Decimal>,
+
     /// Maximum slippage tolerance as a decimal fraction.
     ///
     /// E.g., 0.02 means 2% maximum slippage. Trades exceeding this
@@ -46,6 +58,143 @@ pub struct RiskConfig {
     /// Defaults to 30.
     #[serde(default = "default_execution_timeout_secs")]
     pub execution_timeout_secs: u64,
+
+    /// Maximum order book age in milliseconds.
+    ///
+    /// Opportunities with any leg priced from a book older than this are
+    /// rejected before execution. Disabled by default.
+    #[serde(default)]
+    pub max_book_age_ms: Option<u64>,
+
+    /// Maximum legs an opportunity may have to be executed.
+    ///
+    /// Opportunities with more legs are still detected and notified but
+    /// rejected before execution, since every extra leg adds partial-fill
+    /// risk. Disabled by default.
+    #[serde(default)]
+    pub max_execution_legs: Option<usize>,
+
+    /// Minimum native gas token balance (e.g., POL) required to execute.
+    ///
+    /// Executions are rejected while the wallet holds less. 0 disables the
+    /// check. Defaults to 0.
+    #[serde(default)]
+    pub min_gas_balance: Decimal,
+
+    /// Interval between gas balance refreshes in seconds.
+    ///
+    /// Defaults to 60.
+    #[serde(default = "default_gas_refresh_secs")]
+    pub gas_refresh_secs: u64,
+
+    /// Stop-loss and take-profit exit configuration.
+    #[serde(default)]
+    pub exits: ExitsConfig,
+
+    /// Execution failure circuit breaker configuration.
+    #[serde(default)]
+    pub breaker: BreakerConfig,
+
+    /// Position sizing configuration.
+    #[serde(default)]
+    pub sizing: SizingConfig,
+}
+
+/// Position sizing configuration.
+///
+/// Chooses how much of the remaining exposure budget an opportunity may
+/// use. Sizes are always capped by book liquidity and the position and
+/// exposure limits.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SizingConfig {
+    /// Sizing mode.
+    ///
+    /// Defaults to `fixed`.
+    #[serde(default)]
+    pub mode: SizingMode,
+
+    /// Edge per dollar at which `proportional` sizing reaches full size.
+    ///
+    /// E.g., 0.10 commits half the budget at a 5% edge. Defaults to 0.10.
+    #[serde(default = "default_full_size_edge")]
+    pub full_size_edge: Decimal,
+
+    /// Fraction of the Kelly stake used by `kelly` sizing.
+    ///
+    /// E.g., 0.5 is "half Kelly". Defaults to 0.5.
+    #[serde(default = "default_kelly_fraction")]
+    pub kelly_fraction: Decimal,
+}
+
+/// Position sizing mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SizingMode {
+    /// Trade as much as liquidity and limits allow.
+    #[default]
+    Fixed,
+    /// Scale size linearly with edge.
+    Proportional,
+    /// Fractional Kelly stake.
+    Kelly,
+}
+
+/// Stop-loss and take-profit exit configuration.
+///
+/// Thresholds are fractions of a position's entry cost. Open positions are
+/// marked against the best bid on each check and closed when a threshold
+/// is breached. Both thresholds are disabled by default.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExitsConfig {
+    /// Loss fraction at which to close a position.
+    ///
+    /// E.g., 0.10 closes a position once it is down 10%. Disabled if unset.
+    #[serde(default)]
+    pub stop_loss_pct: Option<Decimal>,
+
+    /// Gain fraction at which to close a position.
+    ///
+    /// E.g., 0.20 closes a position once it is up 20%. Disabled if unset.
+    #[serde(default)]
+    pub take_profit_pct: Option<Decimal>,
+
+    /// Exempt fully hedged arbitrage positions held to settlement.
+    ///
+    /// Defaults to true.
+    #[serde(default = "default_exempt_held_to_settlement")]
+    pub exempt_held_to_settlement: bool,
+
+    /// Interval between exit checks in seconds.
+    ///
+    /// Defaults to 5.
+    #[serde(default = "default_exit_check_interval_secs")]
+    pub check_interval_secs: u64,
+}
+
+/// Execution failure circuit breaker configuration.
+///
+/// After a streak of failed or partially filled executions, trading pauses
+/// until the cooldown elapses or an operator resumes it. A successful
+/// execution ends the streak.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BreakerConfig {
+    /// Consecutive failed executions that trip the breaker.
+    ///
+    /// Set to 0 to disable the breaker. Defaults to 5.
+    #[serde(default = "default_max_consecutive_failures")]
+    pub max_consecutive_failures: u32,
+
+    /// Maximum gap between failures, in seconds, for them to form a streak.
+    ///
+    /// Defaults to 300.
+    #[serde(default = "default_breaker_window_secs")]
+    pub window_secs: u64,
+
+    /// Seconds to pause trading after the breaker trips.
+    ///
+    /// Defaults to 300.
+    #[serde(default = "default_breaker_cooldown_secs")]
+    pub cooldown_secs: u64,
 }
 
 fn default_max_position_per_market() -> Decimal {
@@ -68,14 +217,115 @@ const fn default_execution_timeout_secs() -> u64 {
     30
 }
 
+const fn default_gas_refresh_secs() -> u64 {
+    60
+}
+
+const fn default_max_consecutive_failures() -> u32 {
+    5
+}
+
+const fn default_breaker_window_secs() -> u64 {
+    300
+}
+
+const fn default_breaker_cooldown_secs() -> u64 {
+    300
+}
+
+const fn default_exempt_held_to_settlement() -> bool {
+    true
+}
+
+const fn default_exit_check_interval_secs() -> u64 {
+    5
+}
+
+fn default_full_size_edge() -> Decimal {
+    Decimal::new(10, 2) // 10%
+}
+
+fn default_kelly_fraction() -> Decimal {
+    Decimal::new(5, 1) // half Kelly
+}
+
 impl Default for RiskConfig {
     fn default() -> Self {
         Self {
             max_position_per_market: default_max_position_per_market(),
             max_total_exposure: default_max_total_exposure(),
             min_profit_threshold: default_min_profit_threshold(),
+            min_profit_pct: None,
             max_slippage: default_max_slippage(),
             execution_timeout_secs: default_execution_timeout_secs(),
+            max_book_age_ms: None,
+            max_execution_legs: None,
+            min_gas_balance: Decimal::ZERO,
+            gas_refresh_secs: default_gas_refresh_secs(),
+            exits: ExitsConfig::default(),
+            breaker: BreakerConfig::default(),
+            sizing: SizingConfig::default(),
+        }
+    }
+}
+
+impl Default for SizingConfig {
+    fn default() -> Self {
+        Self {
+            mode: SizingMode::default(),
+            full_size_edge: default_full_size_edge(),
+            kelly_fraction: default_kelly_fraction(),
+        }
+    }
+}
+
+impl Default for BreakerConfig {
+    fn default() -> Self {
+        Self {
+            max_consecutive_failures: default_max_consecutive_failures(),
+            window_secs: default_breaker_window_secs(),
+            cooldown_secs: default_breaker_cooldown_secs(),
+        }
+    }
+}
+
+impl Default for ExitsConfig {
+    fn default() -> Self {
+        Self {
+            stop_loss_pct: None,
+            take_profit_pct: None,
+            exempt_held_to_settlement: default_exempt_held_to_settlement(),
+            check_interval_secs: default_exit_check_interval_secs(),
+        }
+    }
+}
+
+impl From<&ExitsConfig> for ExitPolicy {
+    fn from(config: &ExitsConfig) -> Self {
+        Self {
+            stop_loss_pct: config.stop_loss_pct,
+            take_profit_pct: config.take_profit_pct,
+            exempt_held_to_settlement: config.exempt_held_to_settlement,
+        }
+    }
+}
+
+impl From<&BreakerConfig> for FailureBreaker {
+    fn from(config: &BreakerConfig) -> Self {
+        Self::new(
+            config.max_consecutive_failures,
+            Duration::from_secs(config.window_secs),
+            Duration::from_secs(config.cooldown_secs),
+        )
+    }
+}
+
+impl From<&SizingConfig> for PositionSizer {
+    fn from(config: &SizingConfig) -> Self {
+        match config.mode {
+            SizingMode::Fixed => Self::Fixed,
+            SizingMode::Proportional => Self::Proportional(config.full_size_edge),
+            SizingMode::Kelly => Self::Kelly(config.kelly_fraction),
         }
     }
 }
@@ -86,8 +336,11 @@ impl From<RiskConfig> for RiskLimits {
             max_position_per_market: config.max_position_per_market,
             max_total_exposure: config.max_total_exposure,
             min_profit_threshold: config.min_profit_threshold,
+            min_profit_pct: config.min_profit_pct,
             max_slippage: config.max_slippage,
             execution_timeout_secs: config.execution_timeout_secs,
+            max_book_age_ms: config.max_book_age_ms,
+            max_execution_legs: config.max_execution_legs,
         }
     }
 }
@@ -0,0 +1,141 @@
+//! Market scoring configuration.
+//!
+//! Controls how markets are ranked for subscription priority. Each factor is
+//! normalized to 0.0-1.0 and combined with weights that must sum to 1.0.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::score::ScoreWeights;
+
+/// Allowed deviation of the weight sum from 1.0.
+pub const WEIGHT_SUM_TOLERANCE: f64 = 0.01;
+
+/// Market scoring configuration.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScoringConfig {
+    /// Factor weights for the composite score.
+    #[serde(default)]
+    pub weights: ScoringWeights,
+
+    /// 24-hour volume in USD that earns a full volume score.
+    ///
+    /// Volume is log-scaled against this reference. Defaults to 100000.
+    #[serde(default = "default_volume_reference")]
+    pub volume_reference: f64,
+
+    /// Liquidity in USD that earns a full liquidity score.
+    ///
+    /// Liquidity is log-scaled against this reference. Defaults to 50000.
+    #[serde(default = "default_liquidity_reference")]
+    pub liquidity_reference: f64,
+
+    /// Seconds to close beyond which a market earns no time-to-close score.
+    ///
+    /// Markets closing sooner score higher. Defaults to 2592000 (30 days).
+    #[serde(default = "default_close_horizon_secs")]
+    pub close_horizon_secs: u64,
+
+    /// Number of top-ranked markets recorded for the `status` command.
+    ///
+    /// Defaults to 10.
+    #[serde(default = "default_report_top")]
+    pub report_top: usize,
+}
+
+/// Weights for each scoring factor.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScoringWeights {
+    /// Weight for 24-hour trading volume. Defaults to 0.35.
+    #[serde(default = "default_weight_volume")]
+    pub volume: f64,
+
+    /// Weight for reported liquidity. Defaults to 0.35.
+    #[serde(default = "default_weight_liquidity")]
+    pub liquidity: f64,
+
+    /// Weight for spread tightness. Defaults to 0.2.
+    #[serde(default = "default_weight_spread")]
+    pub spread: f64,
+
+    /// Weight for proximity of the close time. Defaults to 0.1.
+    #[serde(default = "default_weight_time_to_close")]
+    pub time_to_close: f64,
+}
+
+impl ScoringWeights {
+    /// Sum of all weights.
+    #[must_use]
+    pub fn sum(&self) -> f64 {
+        self.volume + self.liquidity + self.spread + self.time_to_close
+    }
+
+    /// Whether every weight is non-negative and the sum is close to 1.0.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        let weights = [self.volume, self.liquidity, self.spread, self.time_to_close];
+        weights.iter().all(|w| w.is_finite() && *w >= 0.0)
+            && (self.sum() - 1.0).abs() <= WEIGHT_SUM_TOLERANCE
+    }
+}
+
+impl From<&ScoringWeights> for ScoreWeights {
+    fn from(weights: &ScoringWeights) -> Self {
+        Self::new(weights.liquidity, weights.spread, 0.0, 0.0, weights.volume)
+            .with_time_to_close(weights.time_to_close)
+    }
+}
+
+fn default_weight_volume() -> f64 {
+    0.35
+}
+
+fn default_weight_liquidity() -> f64 {
+    0.35
+}
+
+fn default_weight_spread() -> f64 {
+    0.2
+}
+
+fn default_weight_time_to_close() -> f64 {
+    0.1
+}
+
+fn default_volume_reference() -> f64 {
+    100_000.0
+}
+
+fn default_liquidity_reference() -> f64 {
+    50_000.0
+}
+
+const fn default_close_horizon_secs() -> u64 {
+    30 * 24 * 60 * 60
+}
+
+const fn default_report_top() -> usize {
+    10
+}
+
+impl Default for ScoringWeights {
+    fn default() -> Self {
+        Self {
+            volume: default_weight_volume(),
+            liquidity: default_weight_liquidity(),
+            spread: default_weight_spread(),
+            time_to_close: default_weight_time_to_close(),
+        }
+    }
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            weights: ScoringWeights::default(),
+            volume_reference: default_volume_reference(),
+            liquidity_reference: default_liquidity_reference(),
+            close_horizon_secs: default_close_horizon_secs(),
+            report_top: default_report_top(),
+        }
+    }
+}
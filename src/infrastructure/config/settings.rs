@@ -2,7 +2,8 @@
 //!
 //! Provides the main [`Config`] struct that aggregates all application settings.
 //! Configuration is loaded from a TOML file with environment variable overrides
-//! for sensitive values like `WALLET_PRIVATE_KEY`.
+//! for sensitive values like `WALLET_PRIVATE_KEY`. Any other setting can be
+//! overridden with `EDGELORD__<SECTION>__<FIELD>`; see [`super::env`].
 //!
 //! # Example
 //!
@@ -17,44 +18,129 @@
 //! ```
 
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
 
 use super::cluster::ClusterDetectionConfig;
+use super::control::ControlApiConfig;
+use super::discord::DiscordAppConfig;
+use super::discovery::MarketDiscoveryConfig;
+use super::execution::ExecutionConfig;
 use super::governor::GovernorAppConfig;
 use super::llm::LlmConfig;
 use super::logging::LoggingConfig;
+use super::metrics::MetricsConfig;
 use super::pool::{ConnectionPoolConfig, ReconnectionConfig};
 use super::profile::{Profile, ResourceConfig};
+use super::replay::ReplayConfig;
 use super::risk::RiskConfig;
+use super::scoring::{ScoringConfig, WEIGHT_SUM_TOLERANCE};
+use super::settlement::SettlementConfig;
+use super::shadow::ShadowConfig;
+use super::slack::SlackAppConfig;
+use super::snapshot::SnapshotConfig;
 use super::strategy::StrategiesConfig;
 use super::telegram::TelegramAppConfig;
-use super::wallet::WalletConfig;
+use super::wallet::{KeySource, WalletConfig};
+use super::webhook::WebhookAppConfig;
+#[cfg(feature = "kalshi")]
+use crate::adapter::outbound::kalshi::settings::KalshiConfig;
+#[cfg(feature = "manifold")]
+use crate::adapter::outbound::manifold::settings::ManifoldConfig;
 use crate::adapter::outbound::polymarket::settings::{Environment, PolymarketConfig};
 use crate::application::inference::config::InferenceConfig;
+use crate::application::inference::normalize::conflicting_alias;
+use crate::application::solver::backend::MAX_HEURISTIC_VARS;
+use crate::domain::stats::PRIMARY_VARIANT;
 use crate::error::{ConfigError, Result};
 
 /// Supported exchange platforms.
 ///
 /// Determines which exchange adapter to use for market data and execution.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Exchange {
     /// Polymarket prediction market exchange.
     #[default]
     Polymarket,
+    /// Kalshi prediction market exchange (market data only).
+    #[cfg(feature = "kalshi")]
+    Kalshi,
+    /// Manifold Markets play-money exchange (market data only).
+    #[cfg(feature = "manifold")]
+    Manifold,
+}
+
+impl Exchange {
+    /// Return the lowercase exchange name used in configuration and logs.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Polymarket => "polymarket",
+            #[cfg(feature = "kalshi")]
+            Self::Kalshi => "kalshi",
+            #[cfg(feature = "manifold")]
+            Self::Manifold => "manifold",
+        }
+    }
 }
 
 /// Exchange-specific configuration variant.
 ///
 /// Contains the configuration settings specific to each supported exchange.
 /// The active variant is determined by the `type` field in the TOML config.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum ExchangeSpecificConfig {
     /// Polymarket exchange configuration.
     Polymarket(PolymarketConfig),
+    /// Kalshi exchange configuration.
+    #[cfg(feature = "kalshi")]
+    Kalshi(KalshiConfig),
+    /// Manifold Markets configuration.
+    #[cfg(feature = "manifold")]
+    Manifold(ManifoldConfig),
+}
+
+impl ExchangeSpecificConfig {
+    /// Return the exchange this configuration belongs to.
+    #[must_use]
+    pub const fn exchange(&self) -> Exchange {
+        match self {
+            Self::Polymarket(_) => Exchange::Polymarket,
+            #[cfg(feature = "kalshi")]
+            Self::Kalshi(_) => Exchange::Kalshi,
+            #[cfg(feature = "manifold")]
+            Self::Manifold(_) => Exchange::Manifold,
+        }
+    }
+
+    /// Return the price increment order limit prices must align to.
+    #[must_use]
+    pub fn price_tick(&self) -> Decimal {
+        match self {
+            Self::Polymarket(poly) => poly.price_tick,
+            // Both quote whole-cent prices.
+            #[cfg(feature = "kalshi")]
+            Self::Kalshi(_) => Decimal::new(1, 2),
+            #[cfg(feature = "manifold")]
+            Self::Manifold(_) => Decimal::new(1, 2),
+        }
+    }
+
+    /// Return the increment order sizes must align to.
+    #[must_use]
+    pub fn size_increment(&self) -> Decimal {
+        match self {
+            Self::Polymarket(poly) => poly.size_increment,
+            // Kalshi trades whole contracts and Manifold whole mana.
+            #[cfg(feature = "kalshi")]
+            Self::Kalshi(_) => Decimal::ONE,
+            #[cfg(feature = "manifold")]
+            Self::Manifold(_) => Decimal::ONE,
+        }
+    }
 }
 
 impl Default for ExchangeSpecificConfig {
@@ -76,6 +162,8 @@ pub struct NetworkConfig {
     /// REST API URL for order submission and market queries.
     pub api_url: String,
     /// Blockchain chain ID for transaction signing.
+    ///
+    /// Zero for exchanges that do not settle on chain.
     pub chain_id: u64,
 }
 
@@ -103,7 +191,7 @@ impl NetworkConfig {
 ///
 /// Aggregates all configuration settings for the application. Load from a TOML
 /// file using [`Config::load`] or parse directly with [`Config::parse_toml`].
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Config {
     /// Application profile for resource allocation.
     ///
@@ -150,16 +238,62 @@ pub struct Config {
     #[serde(default)]
     pub risk: RiskConfig,
 
+    /// Trade execution configuration.
+    ///
+    /// Controls retry and unwind behavior for partial fills.
+    #[serde(default)]
+    pub execution: ExecutionConfig,
+
+    /// Market settlement polling configuration.
+    ///
+    /// Controls how often markets with open positions are checked for
+    /// resolution.
+    #[serde(default)]
+    pub settlement: SettlementConfig,
+
+    /// Periodic market re-scan configuration.
+    ///
+    /// Controls how often newly created markets are picked up and closed
+    /// ones dropped without a restart.
+    #[serde(default)]
+    pub market_discovery: MarketDiscoveryConfig,
+
     /// Telegram notification configuration.
     #[serde(default)]
     pub telegram: TelegramAppConfig,
 
+    /// Discord notification configuration.
+    #[serde(default)]
+    pub discord: DiscordAppConfig,
+
+    /// Slack notification configuration.
+    #[serde(default)]
+    pub slack: SlackAppConfig,
+
+    /// Generic HTTP webhook notification configuration.
+    #[serde(default)]
+    pub webhook: WebhookAppConfig,
+
+    /// Prometheus metrics endpoint configuration.
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+
+    /// Local JSON-RPC control API configuration.
+    #[serde(default)]
+    pub control_api: ControlApiConfig,
+
     /// Governor configuration for adaptive subscription scaling.
     ///
     /// Controls latency targets and scaling behavior.
     #[serde(default)]
     pub governor: GovernorAppConfig,
 
+    /// Market scoring configuration for subscription priority.
+    ///
+    /// Controls factor weights and normalization references.
+    #[serde(default)]
+    pub scoring: ScoringConfig,
+
     /// Enable dry-run mode.
     ///
     /// When true, detects opportunities but does not execute trades.
@@ -167,6 +301,47 @@ pub struct Config {
     #[serde(default)]
     pub dry_run: bool,
 
+    /// Simulate fills against cached order book depth in dry-run mode.
+    ///
+    /// When true (and `dry_run` is enabled), approved opportunities are
+    /// executed by a simulator and recorded as trades and positions.
+    /// Defaults to false (dry-run only logs opportunities).
+    #[serde(default)]
+    pub dry_run_simulate_fills: bool,
+
+    /// Window in milliseconds during which repeat opportunities are suppressed.
+    ///
+    /// Repeats (same content hash, with the edge in `dedup_edge_bucket`
+    /// buckets) are not recorded or notified until the window elapses, but
+    /// are still evaluated and executed. 0 disables deduplication.
+    /// Defaults to 5000.
+    #[serde(default = "default_dedup_window_ms")]
+    pub dedup_window_ms: u64,
+
+    /// Edge bucket width for opportunity deduplication.
+    ///
+    /// An edge change that crosses into another bucket counts as a new
+    /// opportunity. Defaults to 0.005.
+    #[serde(default = "default_dedup_edge_bucket")]
+    pub dedup_edge_bucket: Decimal,
+
+    /// Window in milliseconds during which the stats recorder skips
+    /// opportunities identical to one already recorded.
+    ///
+    /// Guards stats against duplicate events that reach the recorder, keyed
+    /// on the opportunity's content hash and outcome. Applies after, and
+    /// independently of, `dedup_window_ms`. 0 disables it.
+    /// Defaults to 0.
+    #[serde(default)]
+    pub stats_dedup_window_ms: u64,
+
+    /// Number of recent notification events kept in memory.
+    ///
+    /// Served by the Telegram `/recent` command and the metrics server's
+    /// `/events` endpoint. 0 disables the buffer. Defaults to 100.
+    #[serde(default = "default_recent_events")]
+    pub recent_events: usize,
+
     /// WebSocket reconnection settings.
     ///
     /// Controls backoff delays and circuit breaker behavior.
@@ -198,12 +373,84 @@ pub struct Config {
     /// Defaults to "edgelord.db" in the current directory.
     #[serde(default = "default_database_path")]
     pub database: String,
+
+    /// Recorded order book file to replay instead of live market data.
+    ///
+    /// Replay always runs in dry-run mode. Defaults to none (live data).
+    #[serde(default)]
+    pub replay: Option<ReplayConfig>,
+
+    /// Order book snapshot to warm the cache from at startup.
+    ///
+    /// The cache is written back on exit. Defaults to none (cold start).
+    #[serde(default)]
+    pub snapshot: Option<SnapshotConfig>,
+
+    /// Candidate strategy set recorded alongside the primary one.
+    ///
+    /// Shadow opportunities are tagged with their variant and never executed.
+    /// Defaults to none.
+    #[serde(default)]
+    pub shadow: Option<ShadowConfig>,
+}
+
+const fn default_dedup_window_ms() -> u64 {
+    5000
+}
+
+fn default_dedup_edge_bucket() -> Decimal {
+    Decimal::new(5, 3)
+}
+
+const fn default_recent_events() -> usize {
+    crate::application::cache::event::DEFAULT_CAPACITY
 }
 
 fn default_database_path() -> String {
     "edgelord.db".to_string()
 }
 
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            profile: Profile::default(),
+            resources: ResourceConfig::default(),
+            exchange: Exchange::default(),
+            exchange_config: ExchangeSpecificConfig::default(),
+            logging: LoggingConfig::default(),
+            strategies: StrategiesConfig::default(),
+            wallet: WalletConfig::default(),
+            risk: RiskConfig::default(),
+            execution: ExecutionConfig::default(),
+            settlement: SettlementConfig::default(),
+            market_discovery: MarketDiscoveryConfig::default(),
+            telegram: TelegramAppConfig::default(),
+            discord: DiscordAppConfig::default(),
+            slack: SlackAppConfig::default(),
+            webhook: WebhookAppConfig::default(),
+            metrics: MetricsConfig::default(),
+            control_api: ControlApiConfig::default(),
+            governor: GovernorAppConfig::default(),
+            scoring: ScoringConfig::default(),
+            dry_run: false,
+            dry_run_simulate_fills: false,
+            dedup_window_ms: default_dedup_window_ms(),
+            dedup_edge_bucket: default_dedup_edge_bucket(),
+            stats_dedup_window_ms: 0,
+            recent_events: default_recent_events(),
+            reconnection: ReconnectionConfig::default(),
+            connection_pool: ConnectionPoolConfig::default(),
+            llm: LlmConfig::default(),
+            inference: InferenceConfig::default(),
+            cluster_detection: ClusterDetectionConfig::default(),
+            database: default_database_path(),
+            replay: None,
+            snapshot: None,
+            shadow: None,
+        }
+    }
+}
+
 fn read_keystore_password() -> Result<String> {
     if let Ok(password) = std::env::var("EDGELORD_KEYSTORE_PASSWORD") {
         return Ok(password);
@@ -248,24 +495,45 @@ fn decrypt_keystore_private_key(_path: &str, _password: &str) -> Result<String>
     .into())
 }
 
+/// Return true if the TOML content configures exchange settings explicitly.
+#[cfg(any(feature = "kalshi", feature = "manifold"))]
+fn has_exchange_section(content: &str) -> bool {
+    content.parse::<toml::Table>().is_ok_and(|table| {
+        table.contains_key("exchange_config") || table.contains_key("polymarket")
+    })
+}
+
 impl Config {
     /// Parse configuration from TOML content.
     ///
-    /// Loads the private key from the `WALLET_PRIVATE_KEY` environment variable
-    /// or decrypts it from a keystore file if `keystore_path` is configured.
+    /// Applies `EDGELORD__<SECTION>__<FIELD>` environment overrides, then loads
+    /// the private key from the `WALLET_PRIVATE_KEY` environment variable or
+    /// decrypts it from a keystore file if `keystore_path` is configured. With
+    /// `wallet.key_source = "keyring"` the key is read from the OS keyring
+    /// instead.
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - The TOML content is malformed
+    /// - An environment override names an unknown setting or has a malformed value
     /// - Validation fails (e.g., invalid slippage values)
     /// - Keystore decryption fails when using keystore authentication
+    /// - The keyring entry is missing when using keyring authentication
     #[allow(clippy::result_large_err)]
     pub fn parse_toml(content: &str) -> Result<Self> {
-        let mut config: Self = toml::from_str(content).map_err(ConfigError::Parse)?;
+        let config = Self::from_file_toml(content)?;
+
+        // Apply `EDGELORD__<SECTION>__<FIELD>` overrides on top of the file
+        let mut config = super::env::apply_env_overrides(config)?;
+
+        config.validate()?;
 
-        // Load private key from environment variable (never from config file for security)
-        config.wallet.private_key = std::env::var("WALLET_PRIVATE_KEY").ok();
+        // Load private key from the keyring or environment (never from config file for security)
+        config.wallet.private_key = match config.wallet.key_source {
+            KeySource::Keyring => Some(config.wallet.read_keyring_private_key()?),
+            KeySource::Env => std::env::var("WALLET_PRIVATE_KEY").ok(),
+        };
         if config.wallet.private_key.is_none() {
             if let Some(ref keystore_path) = config.wallet.keystore_path {
                 let password = read_keystore_password()?;
@@ -274,7 +542,34 @@ impl Config {
             }
         }
 
-        config.validate()?;
+        Ok(config)
+    }
+
+    /// Deserialize TOML content exactly as written in the file.
+    ///
+    /// Selects exchange defaults for `exchange = "..."` without an exchange
+    /// section, but applies no environment overrides, loads no secrets, and
+    /// skips validation. Unknown keys are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TOML content is malformed or has values of the
+    /// wrong type.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn from_file_toml(content: &str) -> Result<Self> {
+        let mut config: Self = toml::from_str(content).map_err(ConfigError::Parse)?;
+
+        // `exchange = "kalshi"` without an exchange section selects Kalshi defaults
+        #[cfg(feature = "kalshi")]
+        if config.exchange == Exchange::Kalshi && !has_exchange_section(content) {
+            config.exchange_config = ExchangeSpecificConfig::Kalshi(KalshiConfig::default());
+        }
+
+        // `exchange = "manifold"` without an exchange section selects Manifold defaults
+        #[cfg(feature = "manifold")]
+        if config.exchange == Exchange::Manifold && !has_exchange_section(content) {
+            config.exchange_config = ExchangeSpecificConfig::Manifold(ManifoldConfig::default());
+        }
 
         Ok(config)
     }
@@ -299,6 +594,13 @@ impl Config {
     /// acceptable ranges.
     #[allow(clippy::result_large_err)]
     fn validate(&self) -> Result<()> {
+        if self.exchange_config.exchange() != self.exchange {
+            return Err(ConfigError::InvalidValue {
+                field: "exchange_config.type",
+                reason: format!("must match exchange \"{}\"", self.exchange.name()),
+            }
+            .into());
+        }
         let network = self.network();
         if network.ws_url.is_empty() {
             return Err(ConfigError::MissingField { field: "ws_url" }.into());
@@ -334,6 +636,66 @@ impl Config {
             }
             .into());
         }
+        if self
+            .risk
+            .min_profit_pct
+            .is_some_and(|pct| pct <= Decimal::ZERO || pct > Decimal::ONE)
+        {
+            return Err(ConfigError::InvalidValue {
+                field: "min_profit_pct",
+                reason: "must be greater than 0 and at most 1".to_string(),
+            }
+            .into());
+        }
+        if self.risk.max_execution_legs.is_some_and(|legs| legs < 2) {
+            return Err(ConfigError::InvalidValue {
+                field: "max_execution_legs",
+                reason: "must be at least 2".to_string(),
+            }
+            .into());
+        }
+        if self.risk.max_book_age_ms == Some(0) {
+            return Err(ConfigError::InvalidValue {
+                field: "max_book_age_ms",
+                reason: "must be greater than 0".to_string(),
+            }
+            .into());
+        }
+        if self.risk.min_gas_balance < Decimal::ZERO {
+            return Err(ConfigError::InvalidValue {
+                field: "min_gas_balance",
+                reason: "must be 0 or greater".to_string(),
+            }
+            .into());
+        }
+        if self.risk.min_gas_balance > Decimal::ZERO && self.risk.gas_refresh_secs == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "gas_refresh_secs",
+                reason: "must be greater than 0 when min_gas_balance is set".to_string(),
+            }
+            .into());
+        }
+        if self.resources.max_book_depth == Some(0) {
+            return Err(ConfigError::InvalidValue {
+                field: "max_book_depth",
+                reason: "must be greater than 0".to_string(),
+            }
+            .into());
+        }
+        if self.risk.sizing.full_size_edge <= Decimal::ZERO {
+            return Err(ConfigError::InvalidValue {
+                field: "full_size_edge",
+                reason: "must be greater than 0".to_string(),
+            }
+            .into());
+        }
+        if self.risk.sizing.kelly_fraction <= Decimal::ZERO {
+            return Err(ConfigError::InvalidValue {
+                field: "kelly_fraction",
+                reason: "must be greater than 0".to_string(),
+            }
+            .into());
+        }
 
         if self.reconnection.initial_delay_ms == 0 {
             return Err(ConfigError::InvalidValue {
@@ -370,6 +732,20 @@ impl Config {
             }
             .into());
         }
+        if self.reconnection.max_auth_failures == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "max_auth_failures",
+                reason: "must be greater than 0".to_string(),
+            }
+            .into());
+        }
+        if self.logging.sample_rate == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "sample_rate",
+                reason: "must be greater than 0".to_string(),
+            }
+            .into());
+        }
 
         let latency = &self.governor.latency;
         if latency.target_p50_ms == 0
@@ -414,6 +790,85 @@ impl Config {
             .into());
         }
 
+        let scoring = &self.scoring;
+        if !scoring.weights.is_valid() {
+            return Err(ConfigError::InvalidValue {
+                field: "scoring.weights",
+                reason: format!(
+                    "weights must be non-negative and sum to 1.0 (±{WEIGHT_SUM_TOLERANCE}), got {:.3}",
+                    scoring.weights.sum()
+                ),
+            }
+            .into());
+        }
+        if !(scoring.volume_reference > 0.0 && scoring.liquidity_reference > 0.0) {
+            return Err(ConfigError::InvalidValue {
+                field: "scoring",
+                reason: "volume_reference and liquidity_reference must be greater than 0"
+                    .to_string(),
+            }
+            .into());
+        }
+        if scoring.close_horizon_secs == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "close_horizon_secs",
+                reason: "must be greater than 0".to_string(),
+            }
+            .into());
+        }
+
+        if let Some(variant) = conflicting_alias(&self.inference.aliases) {
+            return Err(ConfigError::InvalidValue {
+                field: "aliases",
+                reason: format!("variant \"{variant}\" is listed under more than one name"),
+            }
+            .into());
+        }
+
+        let min_relation_confidence = self.strategies.combinatorial.min_relation_confidence;
+        if !(0.0..=1.0).contains(&min_relation_confidence) {
+            return Err(ConfigError::InvalidValue {
+                field: "min_relation_confidence",
+                reason: "must be between 0.0 and 1.0".to_string(),
+            }
+            .into());
+        }
+
+        let solver = &self.strategies.combinatorial.solver;
+        if solver.heuristic_max_vars == 0 || solver.heuristic_max_vars > MAX_HEURISTIC_VARS {
+            return Err(ConfigError::InvalidValue {
+                field: "heuristic_max_vars",
+                reason: format!("must be between 1 and {MAX_HEURISTIC_VARS}"),
+            }
+            .into());
+        }
+
+        if self.strategies.ensemble.is_some() && self.strategies.enabled.len() < 2 {
+            return Err(ConfigError::InvalidValue {
+                field: "ensemble",
+                reason: "requires at least two enabled strategies".to_string(),
+            }
+            .into());
+        }
+
+        if let Some(shadow) = &self.shadow {
+            let variant = shadow.variant.trim();
+            if variant.is_empty() || variant == PRIMARY_VARIANT {
+                return Err(ConfigError::InvalidValue {
+                    field: "shadow.variant",
+                    reason: format!("must be non-empty and not \"{PRIMARY_VARIANT}\""),
+                }
+                .into());
+            }
+            if shadow.strategies.enabled.is_empty() {
+                return Err(ConfigError::InvalidValue {
+                    field: "shadow.strategies",
+                    reason: "at least one strategy must be enabled".to_string(),
+                }
+                .into());
+            }
+        }
+
         if self.cluster_detection.enabled {
             if self.cluster_detection.debounce_ms == 0 {
                 return Err(ConfigError::InvalidValue {
@@ -446,6 +901,106 @@ impl Config {
                 .into());
             }
         }
+
+        if self.execution.retry_price_step < Decimal::ZERO {
+            return Err(ConfigError::InvalidValue {
+                field: "retry_price_step",
+                reason: "must be non-negative".to_string(),
+            }
+            .into());
+        }
+        if self.execution.unwind_discount < Decimal::ZERO
+            || self.execution.unwind_discount >= Decimal::ONE
+        {
+            return Err(ConfigError::InvalidValue {
+                field: "unwind_discount",
+                reason: "must be at least 0 and less than 1".to_string(),
+            }
+            .into());
+        }
+        if !self.execution.max_orders_per_sec.is_finite() || self.execution.max_orders_per_sec < 0.0
+        {
+            return Err(ConfigError::InvalidValue {
+                field: "max_orders_per_sec",
+                reason: "must be 0 or greater".to_string(),
+            }
+            .into());
+        }
+        if self.execution.burst == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "burst",
+                reason: "must be greater than 0".to_string(),
+            }
+            .into());
+        }
+        if self.execution.drain_on_shutdown && self.execution.drain_timeout_secs == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "drain_timeout_secs",
+                reason: "must be greater than 0".to_string(),
+            }
+            .into());
+        }
+
+        if self.metrics.enabled
+            && self
+                .metrics
+                .bind_addr
+                .parse::<std::net::SocketAddr>()
+                .is_err()
+        {
+            return Err(ConfigError::InvalidValue {
+                field: "bind_addr",
+                reason: "must be a socket address like 127.0.0.1:9090".to_string(),
+            }
+            .into());
+        }
+
+        if self.control_api.enabled
+            && self
+                .control_api
+                .bind_addr
+                .parse::<std::net::SocketAddr>()
+                .is_err()
+        {
+            return Err(ConfigError::InvalidValue {
+                field: "bind_addr",
+                reason: "must be a socket address like 127.0.0.1:9091".to_string(),
+            }
+            .into());
+        }
+
+        let price_tick = self.exchange_config.price_tick();
+        if price_tick <= Decimal::ZERO || price_tick >= Decimal::ONE {
+            return Err(ConfigError::InvalidValue {
+                field: "price_tick",
+                reason: "must be between 0 and 1 (exclusive)".to_string(),
+            }
+            .into());
+        }
+        if self.exchange_config.size_increment() <= Decimal::ZERO {
+            return Err(ConfigError::InvalidValue {
+                field: "size_increment",
+                reason: "must be greater than 0".to_string(),
+            }
+            .into());
+        }
+
+        if self.wallet.key_source == KeySource::Keyring {
+            if self.wallet.keyring_service.is_empty() || self.wallet.keyring_account.is_empty() {
+                return Err(ConfigError::InvalidValue {
+                    field: "wallet.keyring_service",
+                    reason: "keyring_service and keyring_account must not be empty".to_string(),
+                }
+                .into());
+            }
+            if self.wallet.keystore_path.is_some() {
+                return Err(ConfigError::InvalidValue {
+                    field: "wallet.keystore_path",
+                    reason: "cannot be combined with key_source = \"keyring\"".to_string(),
+                }
+                .into());
+            }
+        }
         Ok(())
     }
 
@@ -459,6 +1014,20 @@ impl Config {
                 api_url: poly.api_url.clone(),
                 chain_id: poly.chain_id,
             },
+            #[cfg(feature = "kalshi")]
+            ExchangeSpecificConfig::Kalshi(kalshi) => NetworkConfig {
+                environment: kalshi.environment,
+                ws_url: kalshi.ws_url.clone(),
+                api_url: kalshi.api_url.clone(),
+                chain_id: 0,
+            },
+            #[cfg(feature = "manifold")]
+            ExchangeSpecificConfig::Manifold(manifold) => NetworkConfig {
+                environment: manifold.environment,
+                ws_url: manifold.ws_url.clone(),
+                api_url: manifold.api_url.clone(),
+                chain_id: 0,
+            },
         }
     }
 
@@ -467,6 +1036,34 @@ impl Config {
     pub fn polymarket_config(&self) -> Option<&PolymarketConfig> {
         match &self.exchange_config {
             ExchangeSpecificConfig::Polymarket(config) => Some(config),
+            #[cfg(feature = "kalshi")]
+            ExchangeSpecificConfig::Kalshi(_) => None,
+            #[cfg(feature = "manifold")]
+            ExchangeSpecificConfig::Manifold(_) => None,
+        }
+    }
+
+    /// Get Kalshi-specific config if this is a Kalshi exchange.
+    #[cfg(feature = "kalshi")]
+    #[must_use]
+    pub fn kalshi_config(&self) -> Option<&KalshiConfig> {
+        match &self.exchange_config {
+            ExchangeSpecificConfig::Kalshi(config) => Some(config),
+            ExchangeSpecificConfig::Polymarket(_) => None,
+            #[cfg(feature = "manifold")]
+            ExchangeSpecificConfig::Manifold(_) => None,
+        }
+    }
+
+    /// Get Manifold-specific config if this is a Manifold exchange.
+    #[cfg(feature = "manifold")]
+    #[must_use]
+    pub fn manifold_config(&self) -> Option<&ManifoldConfig> {
+        match &self.exchange_config {
+            ExchangeSpecificConfig::Manifold(config) => Some(config),
+            ExchangeSpecificConfig::Polymarket(_) => None,
+            #[cfg(feature = "kalshi")]
+            ExchangeSpecificConfig::Kalshi(_) => None,
         }
     }
 
@@ -477,6 +1074,11 @@ impl Config {
                 config.chain_id = 137;
                 config.environment = Environment::Mainnet;
             }
+            #[cfg(feature = "kalshi")]
+            ExchangeSpecificConfig::Kalshi(config) => config.set_environment(Environment::Mainnet),
+            // Manifold trades play money on a single public API
+            #[cfg(feature = "manifold")]
+            ExchangeSpecificConfig::Manifold(config) => config.environment = Environment::Mainnet,
         }
     }
 
@@ -487,6 +1089,10 @@ impl Config {
                 config.chain_id = 80002;
                 config.environment = Environment::Testnet;
             }
+            #[cfg(feature = "kalshi")]
+            ExchangeSpecificConfig::Kalshi(config) => config.set_environment(Environment::Testnet),
+            #[cfg(feature = "manifold")]
+            ExchangeSpecificConfig::Manifold(config) => config.environment = Environment::Testnet,
         }
     }
 
@@ -0,0 +1,38 @@
+//! Market settlement polling configuration.
+//!
+//! Controls how often the exchange is asked whether markets holding open
+//! positions have resolved, so those positions can be closed at their
+//! settlement value.
+
+use serde::{Deserialize, Serialize};
+
+/// Settlement polling configuration.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SettlementConfig {
+    /// Seconds between resolution checks for markets with open positions.
+    ///
+    /// 0 disables polling. Ignored in replay mode and on exchanges without
+    /// a settlement API. Defaults to 300.
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+}
+
+const fn default_poll_interval_secs() -> u64 {
+    300
+}
+
+impl Default for SettlementConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_secs: default_poll_interval_secs(),
+        }
+    }
+}
+
+impl SettlementConfig {
+    /// Return `true` when settlement polling is enabled.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.poll_interval_secs > 0
+    }
+}
@@ -0,0 +1,30 @@
+//! Shadow strategy configuration.
+//!
+//! When set, a second strategy registry runs over the same market events as
+//! the primary one. Its opportunities are recorded under a separate variant
+//! so a candidate configuration can be compared against production without
+//! trading on it.
+
+use serde::{Deserialize, Serialize};
+
+use super::strategy::StrategiesConfig;
+
+/// Candidate strategy set evaluated alongside the primary one.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ShadowConfig {
+    /// Label recorded with every shadow opportunity.
+    ///
+    /// Must differ from "primary". Defaults to "shadow".
+    #[serde(default = "default_variant")]
+    pub variant: String,
+
+    /// Strategy configuration for the shadow registry.
+    ///
+    /// Same shape as the top-level `[strategies]` section.
+    #[serde(default)]
+    pub strategies: StrategiesConfig,
+}
+
+fn default_variant() -> String {
+    "shadow".to_string()
+}
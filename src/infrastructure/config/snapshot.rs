@@ -0,0 +1,35 @@
+//! Order book snapshot configuration.
+//!
+//! When set, the runtime preloads the book cache from a snapshot file at
+//! startup and writes the cache back to it on exit, shrinking the window
+//! after a restart in which no books are available for detection.
+
+use serde::{Deserialize, Serialize};
+
+/// Book cache snapshot used for warm restarts.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SnapshotConfig {
+    /// Path to the binary snapshot file.
+    pub path: String,
+
+    /// Books older than this many seconds are dropped on load.
+    ///
+    /// Defaults to 300 (5 minutes).
+    #[serde(default = "default_max_age_secs")]
+    pub max_age_secs: u64,
+}
+
+const fn default_max_age_secs() -> u64 {
+    300
+}
+
+impl SnapshotConfig {
+    /// Create a snapshot configuration for `path` with the default max age.
+    #[must_use]
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            max_age_secs: default_max_age_secs(),
+        }
+    }
+}
@@ -3,16 +3,17 @@
 //! Provides configuration for enabling and tuning the various arbitrage
 //! detection strategies.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::application::strategy::combinatorial::CombinatorialConfig;
+use crate::application::strategy::ensemble::EnsemblePolicy;
 use crate::application::strategy::market_rebalancing::MarketRebalancingConfig;
 use crate::application::strategy::single_condition::SingleConditionConfig;
 
 /// Configuration for all detection strategies.
 ///
 /// Controls which strategies are active and their individual parameters.
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct StrategiesConfig {
     /// List of enabled strategy names.
     ///
@@ -39,8 +40,28 @@ pub struct StrategiesConfig {
     /// arbitrage detection.
     #[serde(default)]
     pub combinatorial: CombinatorialConfig,
+
+    /// Consensus mode across the enabled strategies.
+    ///
+    /// When set, the enabled strategies vote on each market and an
+    /// opportunity is only acted upon if `any`, `all`, or a `majority` of
+    /// them detect one. Unset runs every strategy independently.
+    #[serde(default)]
+    pub ensemble: Option<EnsemblePolicy>,
 }
 
 fn default_enabled_strategies() -> Vec<String> {
     vec!["single_condition".to_string()]
 }
+
+impl Default for StrategiesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled_strategies(),
+            single_condition: SingleConditionConfig::default(),
+            market_rebalancing: MarketRebalancingConfig::default(),
+            combinatorial: CombinatorialConfig::default(),
+            ensemble: None,
+        }
+    }
+}
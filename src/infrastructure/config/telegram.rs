@@ -3,7 +3,7 @@
 //! Provides configuration for Telegram bot notifications. Requires
 //! `TELEGRAM_BOT_TOKEN` and `TELEGRAM_CHAT_ID` environment variables.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 const fn default_true() -> bool {
     true
@@ -13,7 +13,7 @@ const fn default_true() -> bool {
 ///
 /// Controls which events trigger Telegram notifications and display settings.
 /// The bot token and chat ID are read from environment variables.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TelegramAppConfig {
     /// Enable Telegram notifications.
     ///
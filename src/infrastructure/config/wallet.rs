@@ -1,17 +1,36 @@
 //! Wallet configuration for signing orders.
 //!
 //! Provides configuration for wallet-based order signing. Private keys are
-//! never stored in configuration files for security.
+//! never stored in configuration files for security. They come from the
+//! environment, an encrypted keystore, or the OS keyring.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ConfigError, Result};
+
+/// Where the wallet private key is loaded from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeySource {
+    /// `WALLET_PRIVATE_KEY`, falling back to `keystore_path` when unset.
+    #[default]
+    Env,
+    /// The OS keyring entry named by `keyring_service` and `keyring_account`.
+    Keyring,
+}
 
 /// Wallet configuration for signing orders.
 ///
 /// The private key is loaded from the `WALLET_PRIVATE_KEY` environment
-/// variable at runtime, or decrypted from a keystore file if configured.
-/// Private keys are never stored in configuration files.
-#[derive(Debug, Clone, Default, Deserialize)]
+/// variable at runtime, decrypted from a keystore file if configured, or
+/// read from the OS keyring when `key_source = "keyring"`. Private keys are
+/// never stored in configuration files.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WalletConfig {
+    /// Where the private key is loaded from. Defaults to [`KeySource::Env`].
+    #[serde(default)]
+    pub key_source: KeySource,
+
     /// Path to an encrypted keystore file.
     ///
     /// When set, the keystore is decrypted using the password from
@@ -20,6 +39,14 @@ pub struct WalletConfig {
     #[serde(default)]
     pub keystore_path: Option<String>,
 
+    /// Keyring service name used when `key_source = "keyring"`.
+    #[serde(default = "default_keyring_service")]
+    pub keyring_service: String,
+
+    /// Keyring account name used when `key_source = "keyring"`.
+    #[serde(default = "default_keyring_account")]
+    pub keyring_account: String,
+
     /// Private key for order signing.
     ///
     /// Loaded from `WALLET_PRIVATE_KEY` environment variable at runtime.
@@ -27,3 +54,110 @@ pub struct WalletConfig {
     #[serde(skip)]
     pub private_key: Option<String>,
 }
+
+fn default_keyring_service() -> String {
+    "edgelord".to_string()
+}
+
+fn default_keyring_account() -> String {
+    "wallet".to_string()
+}
+
+impl Default for WalletConfig {
+    fn default() -> Self {
+        Self {
+            key_source: KeySource::default(),
+            keystore_path: None,
+            keyring_service: default_keyring_service(),
+            keyring_account: default_keyring_account(),
+            private_key: None,
+        }
+    }
+}
+
+impl WalletConfig {
+    /// Read the private key from the configured keyring entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry does not exist or the keyring cannot be
+    /// accessed.
+    #[cfg(feature = "keyring")]
+    #[allow(clippy::result_large_err)]
+    pub fn read_keyring_private_key(&self) -> Result<String> {
+        match self.keyring_entry()?.get_password() {
+            Ok(private_key) => Ok(private_key),
+            Err(keyring::Error::NoEntry) => Err(ConfigError::InvalidValue {
+                field: "wallet.key_source",
+                reason: format!(
+                    "no keyring entry for service \"{}\" account \"{}\"; \
+                     store the key with `edgelord wallet import-keyring`",
+                    self.keyring_service, self.keyring_account
+                ),
+            }
+            .into()),
+            Err(e) => Err(keyring_error(&e)),
+        }
+    }
+
+    /// Store `private_key` in the configured keyring entry, replacing any
+    /// existing key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the keyring cannot be accessed.
+    #[cfg(feature = "keyring")]
+    #[allow(clippy::result_large_err)]
+    pub fn store_keyring_private_key(&self, private_key: &str) -> Result<()> {
+        self.keyring_entry()?
+            .set_password(private_key)
+            .map_err(|e| keyring_error(&e))
+    }
+
+    #[cfg(feature = "keyring")]
+    #[allow(clippy::result_large_err)]
+    fn keyring_entry(&self) -> Result<keyring::Entry> {
+        keyring::Entry::new(&self.keyring_service, &self.keyring_account)
+            .map_err(|e| keyring_error(&e))
+    }
+
+    /// Read the private key from the configured keyring entry.
+    ///
+    /// # Errors
+    ///
+    /// Always returns an error: keyring support is not compiled in.
+    #[cfg(not(feature = "keyring"))]
+    #[allow(clippy::result_large_err)]
+    pub fn read_keyring_private_key(&self) -> Result<String> {
+        Err(keyring_unsupported())
+    }
+
+    /// Store `private_key` in the configured keyring entry.
+    ///
+    /// # Errors
+    ///
+    /// Always returns an error: keyring support is not compiled in.
+    #[cfg(not(feature = "keyring"))]
+    #[allow(clippy::result_large_err)]
+    pub fn store_keyring_private_key(&self, _private_key: &str) -> Result<()> {
+        Err(keyring_unsupported())
+    }
+}
+
+#[cfg(feature = "keyring")]
+fn keyring_error(error: &keyring::Error) -> crate::error::Error {
+    ConfigError::InvalidValue {
+        field: "wallet.key_source",
+        reason: format!("keyring access failed: {error}"),
+    }
+    .into()
+}
+
+#[cfg(not(feature = "keyring"))]
+fn keyring_unsupported() -> crate::error::Error {
+    ConfigError::InvalidValue {
+        field: "wallet.key_source",
+        reason: "keyring support requires the keyring feature".to_string(),
+    }
+    .into()
+}
@@ -0,0 +1,47 @@
+//! Generic HTTP webhook notification configuration.
+//!
+//! Provides configuration for posting every notification event as JSON to a
+//! custom endpoint. Requests are signed when the `WEBHOOK_SECRET` environment
+//! variable is set.
+
+use serde::{Deserialize, Serialize};
+
+/// HTTP webhook notification configuration.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WebhookAppConfig {
+    /// Endpoint that receives events.
+    ///
+    /// Webhook notifications are disabled when unset. Defaults to none.
+    #[serde(default)]
+    pub url: Option<String>,
+
+    /// Timeout for each delivery attempt in milliseconds.
+    ///
+    /// Defaults to 5000 (5 seconds).
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// Maximum retries after a 5xx response or failed request.
+    ///
+    /// Retries back off exponentially from 500ms. Defaults to 3.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+}
+
+const fn default_timeout_ms() -> u64 {
+    5000
+}
+
+const fn default_max_retries() -> u32 {
+    3
+}
+
+impl Default for WebhookAppConfig {
+    fn default() -> Self {
+        Self {
+            url: None,
+            timeout_ms: default_timeout_ms(),
+            max_retries: default_max_retries(),
+        }
+    }
+}
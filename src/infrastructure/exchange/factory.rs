@@ -6,26 +6,44 @@
 
 use std::sync::Arc;
 
-use crate::adapter::outbound::polymarket::client::PolymarketClient;
+#[cfg(feature = "kalshi")]
+use crate::adapter::outbound::kalshi::{
+    client::KalshiClient, filter::KalshiFilter, market::KalshiMarketParser,
+    stream::KalshiDataStream,
+};
+#[cfg(feature = "manifold")]
+use crate::adapter::outbound::manifold::{
+    client::ManifoldClient, filter::ManifoldFilter, market::ManifoldMarketParser,
+    settings::ManifoldConfig, stream::ManifoldDataStream,
+};
+use crate::adapter::outbound::polymarket::approval::PolymarketApproval;
+use crate::adapter::outbound::polymarket::client::{PolymarketClient, PolymarketHoldings};
 use crate::adapter::outbound::polymarket::dedup::PolymarketDeduplicator;
 use crate::adapter::outbound::polymarket::executor::PolymarketExecutor;
 use crate::adapter::outbound::polymarket::filter::PolymarketFilter;
 use crate::adapter::outbound::polymarket::market::PolymarketMarketParser;
+use crate::adapter::outbound::polymarket::recovery::PartialFillRecovery;
 use crate::adapter::outbound::polymarket::scorer::PolymarketScorer;
 use crate::adapter::outbound::polymarket::settings::{PolymarketConfig, PolymarketRuntimeConfig};
 use crate::adapter::outbound::polymarket::stream::PolymarketDataStream;
 use crate::error::ConfigError;
 use crate::error::Result;
 use crate::infrastructure::config::settings::{Config, Exchange};
+#[cfg(any(feature = "kalshi", feature = "manifold"))]
+use crate::infrastructure::subscription::scorer::WeightedMarketScorer;
+use crate::infrastructure::wallet::WalletService;
 
 use super::pool::ConnectionPool;
 use super::pool::StreamFactory;
 
 use crate::port::outbound::dedup::MessageDeduplicator;
 use crate::port::outbound::exchange::{
-    ArbitrageExecutor, MarketDataStream, MarketFetcher, MarketParser, OrderExecutor,
+    ArbitrageExecutor, GasBalanceFetcher, HoldingsFetcher, MarketDataStream, MarketFetcher,
+    MarketParser, OrderExecutor, SettlementFetcher,
 };
 use crate::port::outbound::filter::{MarketFilter, MarketScorer};
+#[cfg(any(feature = "kalshi", feature = "manifold"))]
+use tracing::warn;
 
 /// Factory for creating exchange-specific components.
 ///
@@ -81,6 +99,22 @@ impl ExchangeFactory {
         })
     }
 
+    /// Build the error returned when an exchange lacks a component.
+    #[cfg(any(feature = "kalshi", feature = "manifold"))]
+    fn unsupported(exchange: &str, component: &str) -> crate::error::Error {
+        ConfigError::InvalidValue {
+            field: "exchange",
+            reason: format!("{component} is not supported for {exchange}"),
+        }
+        .into()
+    }
+
+    /// Return Manifold configuration, falling back to defaults.
+    #[cfg(feature = "manifold")]
+    fn manifold_config(config: &Config) -> ManifoldConfig {
+        config.manifold_config().cloned().unwrap_or_default()
+    }
+
     /// Create a market fetcher for the configured exchange.
     ///
     /// Returns a fetcher that can retrieve market metadata from the exchange API.
@@ -93,6 +127,80 @@ impl ExchangeFactory {
                     .unwrap_or_else(|| PolymarketClient::new(config.network().api_url.clone()));
                 Box::new(client)
             }
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => {
+                let client = config
+                    .kalshi_config()
+                    .map(KalshiClient::from_config)
+                    .unwrap_or_else(|| KalshiClient::new(config.network().api_url));
+                Box::new(client)
+            }
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => {
+                Box::new(ManifoldClient::from_config(&Self::manifold_config(config)))
+            }
+        }
+    }
+
+    /// Create a settlement fetcher for the configured exchange.
+    ///
+    /// Returns `None` for exchanges without a resolution status API.
+    pub fn create_settlement_fetcher(config: &Config) -> Option<Box<dyn SettlementFetcher>> {
+        match config.exchange {
+            Exchange::Polymarket => {
+                let client = config
+                    .polymarket_config()
+                    .map(PolymarketClient::from_config)
+                    .unwrap_or_else(|| PolymarketClient::new(config.network().api_url.clone()));
+                Some(Box::new(client))
+            }
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => None,
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => None,
+        }
+    }
+
+    /// Create a holdings fetcher for the configured wallet.
+    ///
+    /// Returns `None` when no wallet is configured, when its address cannot
+    /// be derived, or for exchanges without a holdings API.
+    pub fn create_holdings_fetcher(config: &Config) -> Option<Box<dyn HoldingsFetcher>> {
+        config.wallet.private_key.as_ref()?;
+
+        match config.exchange {
+            Exchange::Polymarket => {
+                let address = WalletService::wallet_address(config).ok()?;
+                let client = config
+                    .polymarket_config()
+                    .map(PolymarketClient::from_config)
+                    .unwrap_or_else(|| PolymarketClient::new(config.network().api_url.clone()));
+                Some(Box::new(PolymarketHoldings::new(client, address)))
+            }
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => None,
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => None,
+        }
+    }
+
+    /// Create a gas balance fetcher for the configured wallet.
+    ///
+    /// Returns `None` when no wallet is configured, when it cannot be
+    /// loaded, or for exchanges that do not charge gas.
+    pub fn create_gas_balance_fetcher(config: &Config) -> Option<Box<dyn GasBalanceFetcher>> {
+        config.wallet.private_key.as_ref()?;
+
+        match config.exchange {
+            Exchange::Polymarket => {
+                let runtime = Self::polymarket_runtime_config(config).ok()?;
+                let approval = PolymarketApproval::new(&runtime).ok()?;
+                Some(Box::new(approval))
+            }
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => None,
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => None,
         }
     }
 
@@ -104,6 +212,12 @@ impl ExchangeFactory {
             Exchange::Polymarket => {
                 Box::new(PolymarketDataStream::new(config.network().ws_url.clone()))
             }
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => Box::new(KalshiDataStream::new(config.network().ws_url)),
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => Box::new(ManifoldDataStream::from_config(
+                &Self::manifold_config(config),
+            )),
         }
     }
 
@@ -122,9 +236,25 @@ impl ExchangeFactory {
         match config.exchange {
             Exchange::Polymarket => {
                 let runtime = Self::polymarket_runtime_config(config)?;
-                let executor = PolymarketExecutor::new(&runtime).await?;
+                let executor = PolymarketExecutor::new(&runtime)
+                    .await?
+                    .with_slippage_buffer(config.risk.max_slippage)
+                    .with_increments(
+                        config.exchange_config.price_tick(),
+                        config.exchange_config.size_increment(),
+                    );
                 Ok(Some(Box::new(executor)))
             }
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => {
+                warn!("Kalshi order execution is not supported, running detection only");
+                Ok(None)
+            }
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => {
+                warn!("Manifold is play money without order execution, running detection only");
+                Ok(None)
+            }
         }
     }
 
@@ -146,9 +276,27 @@ impl ExchangeFactory {
         match config.exchange {
             Exchange::Polymarket => {
                 let runtime = Self::polymarket_runtime_config(config)?;
-                let executor = PolymarketExecutor::new(&runtime).await?;
+                let executor = PolymarketExecutor::new(&runtime)
+                    .await?
+                    .with_slippage_buffer(config.risk.max_slippage)
+                    .with_increments(
+                        config.exchange_config.price_tick(),
+                        config.exchange_config.size_increment(),
+                    )
+                    .with_partial_fill_recovery(PartialFillRecovery::from(&config.execution))
+                    .with_leg_timeout(config.execution.leg_timeout());
                 Ok(Some(Arc::new(executor)))
             }
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => {
+                warn!("Kalshi order execution is not supported, running detection only");
+                Ok(None)
+            }
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => {
+                warn!("Manifold is play money without order execution, running detection only");
+                Ok(None)
+            }
         }
     }
 
@@ -159,12 +307,18 @@ impl ExchangeFactory {
     pub fn create_market_parser(config: &Config) -> Box<dyn MarketParser> {
         match config.exchange {
             Exchange::Polymarket => Box::new(PolymarketMarketParser),
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => Box::new(KalshiMarketParser),
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => Box::new(ManifoldMarketParser),
         }
     }
 
     /// Create a market scorer for the configured exchange.
     ///
     /// Returns a scorer that ranks markets by desirability for subscription.
+    /// Exchanges without a dedicated scorer use the weighted `[scoring]`
+    /// factors.
     ///
     /// # Errors
     ///
@@ -175,6 +329,16 @@ impl ExchangeFactory {
                 let poly_config = Self::require_polymarket_config(config)?;
                 Ok(Box::new(PolymarketScorer::new(&poly_config.scoring)))
             }
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => Ok(Box::new(WeightedMarketScorer::new(
+                &config.scoring,
+                Exchange::Kalshi.name(),
+            ))),
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => Ok(Box::new(WeightedMarketScorer::new(
+                &config.scoring,
+                Exchange::Manifold.name(),
+            ))),
         }
     }
 
@@ -191,6 +355,23 @@ impl ExchangeFactory {
                 let poly_config = Self::require_polymarket_config(config)?;
                 Ok(Box::new(PolymarketFilter::new(&poly_config.market_filter)))
             }
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => {
+                let kalshi_config = config.kalshi_config().ok_or(ConfigError::MissingField {
+                    field: "kalshi_config",
+                })?;
+                Ok(Box::new(KalshiFilter::new(&kalshi_config.market_filter)))
+            }
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => {
+                let manifold_config =
+                    config.manifold_config().ok_or(ConfigError::MissingField {
+                        field: "manifold_config",
+                    })?;
+                Ok(Box::new(ManifoldFilter::new(
+                    &manifold_config.market_filter,
+                )))
+            }
         }
     }
 
@@ -207,6 +388,10 @@ impl ExchangeFactory {
                 let poly_config = Self::require_polymarket_config(config)?;
                 Ok(Box::new(PolymarketDeduplicator::new(&poly_config.dedup)))
             }
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => Err(Self::unsupported("Kalshi", "message deduplication")),
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => Err(Self::unsupported("Manifold", "message deduplication")),
         }
     }
 
@@ -230,15 +415,23 @@ impl ExchangeFactory {
                     channel_capacity: pm.connections.channel_capacity,
                 }
             }
+            // Kalshi streams every market over a single connection
+            #[cfg(feature = "kalshi")]
+            crate::infrastructure::config::settings::ExchangeSpecificConfig::Kalshi(_) => {
+                return Ok(None);
+            }
+            // Manifold polls every market through a single stream
+            #[cfg(feature = "manifold")]
+            crate::infrastructure::config::settings::ExchangeSpecificConfig::Manifold(_) => {
+                return Ok(None);
+            }
         };
 
         if pool_config.max_connections <= 1 {
             return Ok(None);
         }
 
-        let exchange_name = match config.exchange {
-            Exchange::Polymarket => "polymarket",
-        };
+        let exchange_name = config.exchange.name();
 
         let stream_factory = Self::create_stream_factory(config);
 
@@ -262,6 +455,18 @@ impl ExchangeFactory {
             Exchange::Polymarket => Arc::new(move || {
                 Box::new(PolymarketDataStream::new(ws_url.clone())) as Box<dyn MarketDataStream>
             }),
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => Arc::new(move || {
+                Box::new(KalshiDataStream::new(ws_url.clone())) as Box<dyn MarketDataStream>
+            }),
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => {
+                let manifold_config = Self::manifold_config(config);
+                Arc::new(move || {
+                    Box::new(ManifoldDataStream::from_config(&manifold_config))
+                        as Box<dyn MarketDataStream>
+                })
+            }
         }
     }
 }
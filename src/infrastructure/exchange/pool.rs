@@ -301,6 +301,11 @@ impl MarketDataStream for ConnectionPool {
         Ok(())
     }
 
+    async fn resubscribe(&mut self, token_ids: &[TokenId]) -> Result<()> {
+        // Subscribing already rebuilds every connection for the new set.
+        self.subscribe(token_ids).await
+    }
+
     async fn next_event(&mut self) -> Option<MarketEvent> {
         self.event_rx.recv().await
     }
@@ -3,17 +3,24 @@
 //! Provides automatic reconnection with exponential backoff and circuit breaker
 //! protection for any [`MarketDataStream`] implementation. The wrapper
 //! transparently handles disconnections and resubscribes to tracked tokens.
+//! Rate-limit and authentication disconnects are passed on to the consumer so
+//! they can be alerted on; rate limits back off longer and repeated auth
+//! failures end the stream instead of retrying forever.
 
-use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
+use rand::Rng;
 use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 
 use crate::domain::id::TokenId;
 use crate::error::Error;
-use crate::infrastructure::config::pool::ReconnectionConfig;
-use crate::port::{outbound::exchange::MarketDataStream, outbound::exchange::MarketEvent};
+use crate::infrastructure::config::pool::{BackoffJitter, ReconnectionConfig};
+use crate::port::{
+    outbound::exchange::DisconnectReason, outbound::exchange::MarketDataStream,
+    outbound::exchange::MarketEvent,
+};
 
 /// Circuit breaker state for connection attempts.
 ///
@@ -54,6 +61,10 @@ pub struct ReconnectingDataStream<S: MarketDataStream> {
     circuit_state: CircuitState,
     /// Whether the stream is currently connected.
     connected: bool,
+    /// Consecutive disconnects caused by rejected credentials.
+    auth_failures: u32,
+    /// Set once authentication failures exhaust their retries.
+    gave_up: bool,
 }
 
 impl<S: MarketDataStream> ReconnectingDataStream<S> {
@@ -71,6 +82,8 @@ impl<S: MarketDataStream> ReconnectingDataStream<S> {
             current_delay_ms: initial_delay,
             circuit_state: CircuitState::Closed,
             connected: false,
+            auth_failures: 0,
+            gave_up: false,
         }
     }
 
@@ -88,9 +101,7 @@ impl<S: MarketDataStream> ReconnectingDataStream<S> {
     /// Returns the current delay and advances the internal delay state for
     /// the next call.
     fn next_delay(&mut self) -> Duration {
-        let base_delay = Duration::from_millis(self.current_delay_ms);
-        let jitter_ms = self.jitter_ms(base_delay);
-        let delay = base_delay + Duration::from_millis(jitter_ms);
+        let delay = Duration::from_millis(self.jittered_ms(self.current_delay_ms));
 
         // Increase delay for next attempt
         let next_delay = (self.current_delay_ms as f64 * self.config.backoff_multiplier) as u64;
@@ -99,20 +110,27 @@ impl<S: MarketDataStream> ReconnectingDataStream<S> {
         delay
     }
 
-    /// Calculate jitter to add to the base delay.
+    /// Apply the configured jitter to a base delay.
     ///
-    /// Adds up to 20% random jitter to prevent synchronized reconnection storms.
-    fn jitter_ms(&self, base_delay: Duration) -> u64 {
-        let jitter_range_ms = (base_delay.as_millis() as u64) / 5;
-        if jitter_range_ms == 0 {
+    /// Additive jitter adds up to 20% of the base to prevent synchronized
+    /// reconnection storms. Full jitter picks from `0..=base`; equal jitter
+    /// keeps half the base and randomizes the rest, so the delay never drops
+    /// below `base / 2`.
+    fn jittered_ms(&self, base_ms: u64) -> u64 {
+        if base_ms == 0 {
             return 0;
         }
 
-        let nanos = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map(|d| d.subsec_nanos())
-            .unwrap_or(0);
-        (nanos as u64) % (jitter_range_ms + 1)
+        let mut rng = rand::thread_rng();
+        match self.config.jitter {
+            BackoffJitter::Additive => base_ms + rng.gen_range(0..=base_ms / 5),
+            BackoffJitter::None => base_ms,
+            BackoffJitter::Full => rng.gen_range(0..=base_ms),
+            BackoffJitter::Equal => {
+                let half = base_ms / 2;
+                half + rng.gen_range(0..=base_ms - half)
+            }
+        }
     }
 
     /// Check if the circuit breaker allows connection attempts.
@@ -155,6 +173,44 @@ impl<S: MarketDataStream> ReconnectingDataStream<S> {
         }
     }
 
+    /// Classify a disconnect or connection error and adjust the policy.
+    ///
+    /// Rate limits raise the next delay to at least `rate_limit_delay_ms`.
+    /// Authentication failures count towards `max_auth_failures`, after
+    /// which the stream gives up.
+    fn apply_disconnect(&mut self, reason: &str) -> DisconnectReason {
+        let cause = DisconnectReason::classify(reason);
+        match cause {
+            DisconnectReason::RateLimited => {
+                self.current_delay_ms = self.current_delay_ms.max(self.config.rate_limit_delay_ms);
+                warn!(
+                    reason = %reason,
+                    delay_ms = self.current_delay_ms,
+                    "Rate limited by exchange, backing off"
+                );
+            }
+            DisconnectReason::AuthFailure => {
+                self.auth_failures += 1;
+                if self.auth_failures >= self.config.max_auth_failures {
+                    self.gave_up = true;
+                    error!(
+                        reason = %reason,
+                        failures = self.auth_failures,
+                        "Authentication rejected repeatedly, giving up on reconnection"
+                    );
+                } else {
+                    warn!(
+                        reason = %reason,
+                        failures = self.auth_failures,
+                        "Authentication rejected by exchange"
+                    );
+                }
+            }
+            DisconnectReason::Transient | DisconnectReason::Stalled => {}
+        }
+        cause
+    }
+
     /// Attempt to reconnect with backoff.
     ///
     /// Waits for the backoff delay, then attempts to reconnect and resubscribe
@@ -235,12 +291,30 @@ impl<S: MarketDataStream + Send> MarketDataStream for ReconnectingDataStream<S>
         self.inner.subscribe(token_ids).await
     }
 
+    async fn resubscribe(&mut self, token_ids: &[TokenId]) -> Result<(), Error> {
+        self.subscribed_tokens = token_ids.to_vec();
+        let result = self.inner.resubscribe(token_ids).await;
+        if result.is_err() {
+            // Reconnect with the new token set on the next read.
+            self.connected = false;
+        }
+        result
+    }
+
     async fn next_event(&mut self) -> Option<MarketEvent> {
         loop {
+            if self.gave_up {
+                return None;
+            }
+
             // If not connected, try to reconnect
             if !self.connected {
                 if let Err(e) = self.reconnect().await {
-                    warn!(error = %e, "Reconnection attempt failed, will retry");
+                    let reason = e.to_string();
+                    if self.apply_disconnect(&reason) != DisconnectReason::Transient {
+                        return Some(MarketEvent::Disconnected { reason });
+                    }
+                    warn!(error = %reason, "Reconnection attempt failed, will retry");
                     continue;
                 }
             }
@@ -251,10 +325,15 @@ impl<S: MarketDataStream + Send> MarketDataStream for ReconnectingDataStream<S>
                     warn!(reason = %reason, "Connection lost, will reconnect");
                     self.connected = false;
                     self.record_failure();
-                    // Don't return the disconnected event, just reconnect
+                    // Surface throttling and auth failures so they can be
+                    // alerted on; transient drops just reconnect.
+                    if self.apply_disconnect(&reason) != DisconnectReason::Transient {
+                        return Some(MarketEvent::Disconnected { reason });
+                    }
                     continue;
                 }
                 Some(event) => {
+                    self.auth_failures = 0;
                     // Reset failure count on successful event
                     if self.consecutive_failures > 0 {
                         debug!("Received event after reconnection, resetting failure count");
@@ -295,6 +374,10 @@ mod tests {
             backoff_multiplier: 2.0,
             max_consecutive_failures: 3,
             circuit_breaker_cooldown_ms: 50,
+            jitter: BackoffJitter::default(),
+            rate_limit_delay_ms: 50,
+            max_auth_failures: 2,
+            idle_timeout_ms: 0,
         }
     }
 
@@ -306,6 +389,10 @@ mod tests {
             backoff_multiplier: 2.0,
             max_consecutive_failures: 3,
             circuit_breaker_cooldown_ms: 10,
+            jitter: BackoffJitter::default(),
+            rate_limit_delay_ms: 5,
+            max_auth_failures: 2,
+            idle_timeout_ms: 0,
         }
     }
 
@@ -400,6 +487,10 @@ mod tests {
             backoff_multiplier: 1.0,
             max_consecutive_failures: 2,
             circuit_breaker_cooldown_ms: 10, // 10ms cooldown
+            jitter: BackoffJitter::default(),
+            rate_limit_delay_ms: 10,
+            max_auth_failures: 3,
+            idle_timeout_ms: 0,
         };
 
         let mut stream = ReconnectingDataStream::new(ScriptedStream::new(), config);
@@ -480,6 +571,10 @@ mod tests {
             backoff_multiplier: 10.0, // Large multiplier
             max_consecutive_failures: 10,
             circuit_breaker_cooldown_ms: 1000,
+            jitter: BackoffJitter::default(),
+            rate_limit_delay_ms: 1000,
+            max_auth_failures: 3,
+            idle_timeout_ms: 0,
         };
 
         let mut stream = ReconnectingDataStream::new(ScriptedStream::new(), config);
@@ -493,17 +588,27 @@ mod tests {
         assert!(delay2.as_millis() <= 120); // 100 + 20% jitter
     }
 
-    #[tokio::test]
-    async fn test_jitter_is_bounded() {
-        let config = ReconnectionConfig {
+    /// Config with a constant 100ms base delay to isolate jitter.
+    fn jitter_config(jitter: BackoffJitter) -> ReconnectionConfig {
+        ReconnectionConfig {
             initial_delay_ms: 100,
             max_delay_ms: 1000,
-            backoff_multiplier: 1.0, // No increase to isolate jitter testing
+            backoff_multiplier: 1.0,
             max_consecutive_failures: 10,
             circuit_breaker_cooldown_ms: 1000,
-        };
+            jitter,
+            rate_limit_delay_ms: 1000,
+            max_auth_failures: 3,
+            idle_timeout_ms: 0,
+        }
+    }
 
-        let mut stream = ReconnectingDataStream::new(ScriptedStream::new(), config);
+    #[tokio::test]
+    async fn test_jitter_is_bounded() {
+        let mut stream = ReconnectingDataStream::new(
+            ScriptedStream::new(),
+            jitter_config(BackoffJitter::default()),
+        );
 
         // Collect several delays to verify jitter bounds
         for _ in 0..10 {
@@ -514,20 +619,67 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_full_jitter_varies_within_bounds() {
+        let mut stream =
+            ReconnectingDataStream::new(ScriptedStream::new(), jitter_config(BackoffJitter::Full));
+
+        let delays: Vec<u64> = (0..50)
+            .map(|_| stream.next_delay().as_millis() as u64)
+            .collect();
+
+        assert!(delays.iter().all(|ms| *ms <= 100), "delays were {delays:?}");
+        assert!(
+            delays.iter().any(|ms| *ms != delays[0]),
+            "delays never varied"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_equal_jitter_varies_within_bounds() {
+        let mut stream =
+            ReconnectingDataStream::new(ScriptedStream::new(), jitter_config(BackoffJitter::Equal));
+
+        let delays: Vec<u64> = (0..50)
+            .map(|_| stream.next_delay().as_millis() as u64)
+            .collect();
+
+        assert!(
+            delays.iter().all(|ms| (50..=100).contains(ms)),
+            "delays were {delays:?}"
+        );
+        assert!(
+            delays.iter().any(|ms| *ms != delays[0]),
+            "delays never varied"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_no_jitter_is_deterministic() {
+        let mut stream =
+            ReconnectingDataStream::new(ScriptedStream::new(), jitter_config(BackoffJitter::None));
+
+        for _ in 0..10 {
+            assert_eq!(stream.next_delay(), Duration::from_millis(100));
+        }
+    }
+
     #[tokio::test]
     async fn test_zero_base_delay_zero_jitter() {
         // When base delay is 0, jitter should also be 0
-        let config = ReconnectionConfig {
-            initial_delay_ms: 0,
-            max_delay_ms: 0,
-            backoff_multiplier: 2.0,
-            max_consecutive_failures: 10,
-            circuit_breaker_cooldown_ms: 1000,
-        };
+        let stream = ReconnectingDataStream::new(
+            ScriptedStream::new(),
+            jitter_config(BackoffJitter::Additive),
+        );
+        assert_eq!(stream.jittered_ms(0), 0);
+
+        let stream =
+            ReconnectingDataStream::new(ScriptedStream::new(), jitter_config(BackoffJitter::Full));
+        assert_eq!(stream.jittered_ms(0), 0);
 
-        let stream = ReconnectingDataStream::new(ScriptedStream::new(), config);
-        let jitter = stream.jitter_ms(Duration::from_millis(0));
-        assert_eq!(jitter, 0);
+        let stream =
+            ReconnectingDataStream::new(ScriptedStream::new(), jitter_config(BackoffJitter::Equal));
+        assert_eq!(stream.jittered_ms(0), 0);
     }
 
     // -----------------------------------------------------------------------
@@ -569,6 +721,29 @@ mod tests {
         assert_eq!(stream.subscribed_tokens[0].as_str(), "new_token");
     }
 
+    #[tokio::test]
+    async fn test_failed_resubscribe_reconnects_with_new_tokens() {
+        let mock = ScriptedStream::new().with_connect_results(vec![
+            Ok(()),
+            Err(Error::Connection("test failure".to_string())),
+        ]);
+        let mut stream = ReconnectingDataStream::new(mock, backoff_config());
+        stream.connect().await.unwrap();
+        stream
+            .subscribe(&[testkit::domain::token("old_token")])
+            .await
+            .unwrap();
+
+        let result = stream
+            .resubscribe(&[testkit::domain::token("new_token")])
+            .await;
+
+        assert!(result.is_err());
+        assert!(!stream.connected);
+        assert_eq!(stream.subscribed_tokens.len(), 1);
+        assert_eq!(stream.subscribed_tokens[0].as_str(), "new_token");
+    }
+
     // -----------------------------------------------------------------------
     // Connection Error Handling Tests
     // -----------------------------------------------------------------------
@@ -662,4 +837,125 @@ mod tests {
         // Failures should be reset after successful event
         assert_eq!(stream.consecutive_failures, 0);
     }
+
+    // -----------------------------------------------------------------------
+    // Disconnect Classification Tests
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_classify_disconnect_reasons() {
+        for reason in [
+            "HTTP error: 429 Too Many Requests",
+            "rate limit exceeded",
+            "Throttled",
+        ] {
+            assert_eq!(
+                DisconnectReason::classify(reason),
+                DisconnectReason::RateLimited
+            );
+        }
+        for reason in ["HTTP error: 401 Unauthorized", "403 Forbidden"] {
+            assert_eq!(
+                DisconnectReason::classify(reason),
+                DisconnectReason::AuthFailure
+            );
+        }
+        assert_eq!(
+            DisconnectReason::classify("Connection reset by peer"),
+            DisconnectReason::Transient
+        );
+        assert_eq!(DisconnectReason::classify(""), DisconnectReason::Transient);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_disconnect_is_surfaced_with_longer_backoff() {
+        let mock = ScriptedStream::new().with_events(vec![
+            Some(testkit::domain::disconnect_event("429 Too Many Requests")),
+            Some(testkit::domain::snapshot_event("token1")),
+        ]);
+
+        let mut stream = ReconnectingDataStream::new(mock, fast_config());
+        stream.connect().await.unwrap();
+
+        let event = stream.next_event().await;
+        assert!(matches!(event, Some(MarketEvent::Disconnected { .. })));
+        assert_eq!(stream.current_delay_ms, 5);
+
+        let event = stream.next_event().await;
+        assert!(matches!(event, Some(MarketEvent::BookSnapshot { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_reconnect_is_surfaced() {
+        let mock = ScriptedStream::new()
+            .with_connect_results(vec![
+                Ok(()),
+                Err(Error::Connection(
+                    "HTTP error: 429 Too Many Requests".to_string(),
+                )),
+            ])
+            .with_events(vec![
+                Some(testkit::domain::disconnect_event("connection reset")),
+                Some(testkit::domain::snapshot_event("token1")),
+            ]);
+
+        let mut stream = ReconnectingDataStream::new(mock, fast_config());
+        stream.connect().await.unwrap();
+
+        match stream.next_event().await {
+            Some(MarketEvent::Disconnected { reason }) => assert!(reason.contains("429")),
+            other => panic!("Expected rate-limit disconnect, got {other:?}"),
+        }
+        let event = stream.next_event().await;
+        assert!(matches!(event, Some(MarketEvent::BookSnapshot { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_repeated_auth_failures_end_stream() {
+        let mock = ScriptedStream::new().with_events(vec![
+            Some(testkit::domain::disconnect_event("401 Unauthorized")),
+            Some(testkit::domain::disconnect_event("401 Unauthorized")),
+            Some(testkit::domain::snapshot_event("token1")),
+        ]);
+        let (connect_count, _) = mock.counts();
+
+        let mut stream = ReconnectingDataStream::new(mock, fast_config());
+        stream.connect().await.unwrap();
+
+        assert!(matches!(
+            stream.next_event().await,
+            Some(MarketEvent::Disconnected { .. })
+        ));
+        assert!(matches!(
+            stream.next_event().await,
+            Some(MarketEvent::Disconnected { .. })
+        ));
+        assert!(stream.next_event().await.is_none());
+        assert_eq!(connect_count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_auth_failures_reset_after_event() {
+        let mock = ScriptedStream::new().with_events(vec![
+            Some(testkit::domain::disconnect_event("401 Unauthorized")),
+            Some(testkit::domain::snapshot_event("token1")),
+            Some(testkit::domain::disconnect_event("401 Unauthorized")),
+            Some(testkit::domain::snapshot_event("token2")),
+        ]);
+
+        let mut stream = ReconnectingDataStream::new(mock, fast_config());
+        stream.connect().await.unwrap();
+
+        for _ in 0..2 {
+            assert!(matches!(
+                stream.next_event().await,
+                Some(MarketEvent::Disconnected { .. })
+            ));
+            assert!(matches!(
+                stream.next_event().await,
+                Some(MarketEvent::BookSnapshot { .. })
+            ));
+        }
+        assert!(!stream.gave_up);
+    }
 }
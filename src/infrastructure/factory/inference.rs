@@ -9,6 +9,7 @@ use chrono::Duration;
 
 use crate::adapter::outbound::inference::inferrer::LlmInferrer;
 use crate::application::cache::cluster::ClusterCache;
+use crate::application::inference::normalize::{NormalizingInferrer, OutcomeNormalizer};
 use crate::infrastructure::config::settings::Config;
 use crate::port::outbound::inference::RelationInferrer;
 use crate::port::outbound::llm::Llm;
@@ -24,9 +25,14 @@ pub fn build_cluster_cache(config: &Config) -> Arc<ClusterCache> {
 
 /// Build the inference service adapter.
 ///
-/// Creates an LLM-based inferrer that detects relationships between markets.
+/// Creates an LLM-based inferrer that detects relationships between markets,
+/// with outcome names normalized through the configured aliases first.
 /// Requires a configured LLM client.
 pub fn build_inferrer(config: &Config, llm: Arc<dyn Llm>) -> Arc<dyn RelationInferrer> {
     let ttl = Duration::seconds(config.inference.ttl_seconds as i64);
-    Arc::new(LlmInferrer::new(llm, ttl))
+    let normalizer = OutcomeNormalizer::new(&config.inference.aliases);
+    Arc::new(NormalizingInferrer::new(
+        Arc::new(LlmInferrer::new(llm, ttl)),
+        normalizer,
+    ))
 }
@@ -28,37 +28,25 @@ pub fn build_llm_client(config: &Config) -> Option<Arc<dyn Llm>> {
         return None;
     }
 
+    let key_var = config.llm.provider.api_key_var();
+    let Ok(api_key) = std::env::var(key_var) else {
+        warn!("{key_var} not set, inference disabled");
+        return None;
+    };
+
     let client: Arc<dyn Llm> = match config.llm.provider {
-        LlmProvider::Anthropic => {
-            let api_key = match std::env::var("ANTHROPIC_API_KEY") {
-                Ok(key) => key,
-                Err(_) => {
-                    warn!("ANTHROPIC_API_KEY not set, inference disabled");
-                    return None;
-                }
-            };
-            Arc::new(Anthropic::new(
-                api_key,
-                &config.llm.anthropic.model,
-                config.llm.anthropic.max_tokens,
-                config.llm.anthropic.temperature,
-            ))
-        }
-        LlmProvider::OpenAi => {
-            let api_key = match std::env::var("OPENAI_API_KEY") {
-                Ok(key) => key,
-                Err(_) => {
-                    warn!("OPENAI_API_KEY not set, inference disabled");
-                    return None;
-                }
-            };
-            Arc::new(OpenAi::new(
-                api_key,
-                &config.llm.openai.model,
-                config.llm.openai.max_tokens,
-                config.llm.openai.temperature,
-            ))
-        }
+        LlmProvider::Anthropic => Arc::new(Anthropic::new(
+            api_key,
+            &config.llm.anthropic.model,
+            config.llm.anthropic.max_tokens,
+            config.llm.anthropic.temperature,
+        )),
+        LlmProvider::OpenAi => Arc::new(OpenAi::new(
+            api_key,
+            &config.llm.openai.model,
+            config.llm.openai.max_tokens,
+            config.llm.openai.temperature,
+        )),
     };
 
     info!(provider = client.name(), "LLM client initialized");
@@ -1,17 +1,22 @@
 //! Notifier registry factory.
 //!
 //! Provides factory functions for constructing the notification registry
-//! with configured notifiers (logging, Telegram, etc.).
+//! with configured notifiers (logging, Telegram, Discord, Slack, etc.).
 
 use std::sync::Arc;
 
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
+use crate::adapter::outbound::notifier::webhook::{WebhookConfig, WebhookNotifier};
 use crate::application::state::AppState;
 use crate::infrastructure::config::settings::Config;
 use crate::port::outbound::notifier::{LogNotifier, NotifierRegistry};
 use crate::port::outbound::stats::StatsRecorder;
 
+#[cfg(feature = "discord")]
+use crate::adapter::outbound::notifier::discord::notifier::{DiscordConfig, DiscordNotifier};
+#[cfg(feature = "slack")]
+use crate::adapter::outbound::notifier::slack::notifier::{SlackConfig, SlackNotifier};
 #[cfg(feature = "telegram")]
 use crate::adapter::outbound::notifier::telegram::control::RuntimeStats;
 #[cfg(feature = "telegram")]
@@ -20,9 +25,11 @@ use crate::adapter::outbound::notifier::telegram::notifier::{TelegramConfig, Tel
 /// Build the notifier registry from configuration.
 ///
 /// Creates a registry containing all configured notifiers. Always includes
-/// the log notifier. When the `telegram` feature is enabled and configured,
-/// also creates a Telegram notifier and returns the associated `RuntimeStats`
-/// instance for the orchestrator to update.
+/// the log notifier and, unless disabled, the state's recent-event buffer,
+/// plus the Discord, Slack, and webhook notifiers when configured.
+/// When the `telegram` feature is enabled and configured, also creates a
+/// Telegram notifier and returns the associated `RuntimeStats` instance for
+/// the orchestrator to update.
 ///
 /// # Returns
 ///
@@ -36,6 +43,10 @@ pub fn build_notifier_registry(
 ) -> (NotifierRegistry, Option<Arc<RuntimeStats>>) {
     let mut registry = NotifierRegistry::new();
     registry.register(Box::new(LogNotifier));
+    register_recent_events(&state, &mut registry);
+    register_discord(config, &mut registry);
+    register_slack(config, &mut registry);
+    register_webhook(config, &mut registry);
 
     let runtime_stats = if config.telegram.enabled {
         if let Some(tg_config) = TelegramConfig::from_env() {
@@ -57,7 +68,9 @@ pub fn build_notifier_registry(
             info!("Telegram notifier enabled with full control");
             Some(runtime_stats)
         } else {
-            warn!("Telegram enabled but TELEGRAM_BOT_TOKEN or TELEGRAM_CHAT_ID not set");
+            warn!(
+                "Telegram enabled but TELEGRAM_BOT_TOKEN or TELEGRAM_CHAT_ID(S) not set or invalid"
+            );
             None
         }
     } else {
@@ -69,15 +82,103 @@ pub fn build_notifier_registry(
 
 /// Build the notifier registry from configuration (non-Telegram variant).
 ///
-/// Creates a registry containing only the log notifier when the `telegram`
-/// feature is not enabled.
+/// Creates a registry containing the log notifier and recent-event buffer,
+/// plus the Discord, Slack, and webhook notifiers when configured, when the
+/// `telegram` feature is not enabled.
 #[cfg(not(feature = "telegram"))]
 pub fn build_notifier_registry(
-    _config: &Config,
-    _state: Arc<AppState>,
+    config: &Config,
+    state: Arc<AppState>,
     _stats_recorder: Arc<dyn StatsRecorder>,
 ) -> (NotifierRegistry, Option<()>) {
     let mut registry = NotifierRegistry::new();
     registry.register(Box::new(LogNotifier));
+    register_recent_events(&state, &mut registry);
+    register_discord(config, &mut registry);
+    register_slack(config, &mut registry);
+    register_webhook(config, &mut registry);
     (registry, None)
 }
+
+/// Register the state's recent-event buffer unless it is disabled.
+fn register_recent_events(state: &AppState, registry: &mut NotifierRegistry) {
+    let buffer = state.recent_events();
+    if buffer.capacity() == 0 {
+        return;
+    }
+    registry.register(Box::new(buffer.clone()));
+    debug!(capacity = buffer.capacity(), "Recent event buffer enabled");
+}
+
+/// Register the webhook notifier when `webhook.url` is configured.
+fn register_webhook(config: &Config, registry: &mut NotifierRegistry) {
+    let Some(ref url) = config.webhook.url else {
+        return;
+    };
+
+    let webhook_config = WebhookConfig {
+        timeout: std::time::Duration::from_millis(config.webhook.timeout_ms),
+        max_retries: config.webhook.max_retries,
+        ..WebhookConfig::new(url.clone())
+    };
+    registry.register(Box::new(WebhookNotifier::new(webhook_config)));
+    info!("Webhook notifier enabled");
+}
+
+/// Register the Discord notifier when enabled and `DISCORD_WEBHOOK_URL` is set.
+#[cfg(feature = "discord")]
+fn register_discord(config: &Config, registry: &mut NotifierRegistry) {
+    if !config.discord.enabled {
+        return;
+    }
+
+    if let Some(discord_config) = DiscordConfig::from_env() {
+        let discord_config = DiscordConfig {
+            notify_opportunities: config.discord.notify_opportunities,
+            notify_executions: config.discord.notify_executions,
+            notify_risk_rejections: config.discord.notify_risk_rejections,
+            ..discord_config
+        };
+        registry.register(Box::new(DiscordNotifier::new(discord_config)));
+        info!("Discord notifier enabled");
+    } else {
+        warn!("Discord enabled but DISCORD_WEBHOOK_URL not set");
+    }
+}
+
+/// Warn when Discord is enabled in config but not compiled in.
+#[cfg(not(feature = "discord"))]
+fn register_discord(config: &Config, _registry: &mut NotifierRegistry) {
+    if config.discord.enabled {
+        warn!("Discord enabled but edgelord was built without the discord feature");
+    }
+}
+
+/// Register the Slack notifier when enabled and `SLACK_WEBHOOK_URL` is set.
+#[cfg(feature = "slack")]
+fn register_slack(config: &Config, registry: &mut NotifierRegistry) {
+    if !config.slack.enabled {
+        return;
+    }
+
+    if let Some(slack_config) = SlackConfig::from_env() {
+        let slack_config = SlackConfig {
+            notify_opportunities: config.slack.notify_opportunities,
+            notify_executions: config.slack.notify_executions,
+            notify_risk_rejections: config.slack.notify_risk_rejections,
+            ..slack_config
+        };
+        registry.register(Box::new(SlackNotifier::new(slack_config)));
+        info!("Slack notifier enabled");
+    } else {
+        warn!("Slack enabled but SLACK_WEBHOOK_URL not set");
+    }
+}
+
+/// Warn when Slack is enabled in config but not compiled in.
+#[cfg(not(feature = "slack"))]
+fn register_slack(config: &Config, _registry: &mut NotifierRegistry) {
+    if config.slack.enabled {
+        warn!("Slack enabled but edgelord was built without the slack feature");
+    }
+}
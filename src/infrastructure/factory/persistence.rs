@@ -1,20 +1,29 @@
 //! Persistence factory for database and recording.
 //!
-//! Provides factory functions for constructing database connections and
-//! statistics recorders.
+//! Provides factory functions for constructing database connections,
+//! statistics recorders, the cluster and relation stores, the trading state
+//! store, and the position store.
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::adapter::outbound::sqlite::database::connection::{create_pool, run_migrations};
-use crate::adapter::outbound::sqlite::recorder;
+use crate::adapter::outbound::sqlite::database::connection::{
+    create_memory_pool, create_pool, run_migrations,
+};
+use crate::adapter::outbound::sqlite::recorder::{self, SqliteRecorder};
+use crate::adapter::outbound::sqlite::store::{
+    SqliteClusterStore, SqlitePositionStore, SqliteRelationStore, SqliteTradingStateStore,
+};
 use crate::error::Result;
 use crate::infrastructure::config::settings::Config;
 use crate::port::outbound::stats::StatsRecorder;
+use crate::port::outbound::store::{PositionStore, TradingStateStore};
 
 /// Build the stats recorder backed by SQLite.
 ///
 /// Creates a connection pool to the configured database, runs migrations,
-/// and returns a stats recorder for persisting runtime statistics.
+/// and returns a stats recorder for persisting runtime statistics. Identical
+/// opportunities are recorded once per `stats_dedup_window_ms`.
 ///
 /// # Errors
 ///
@@ -25,5 +34,96 @@ pub fn build_stats_recorder(config: &Config) -> Result<Arc<dyn StatsRecorder>> {
     let db_url = format!("sqlite://{}", config.database);
     let db_pool = create_pool(&db_url)?;
     run_migrations(&db_pool)?;
+    let window = Duration::from_millis(config.stats_dedup_window_ms);
+    Ok(Arc::new(
+        SqliteRecorder::new(db_pool).with_opportunity_dedup(window),
+    ))
+}
+
+/// Build a stats recorder backed by a private in-memory SQLite database.
+///
+/// Used by backtests, which must leave the configured database untouched.
+/// Everything recorded is discarded when the recorder is dropped.
+///
+/// # Errors
+///
+/// Returns an error if the in-memory database cannot be created or migrated.
+pub fn build_memory_stats_recorder() -> Result<Arc<dyn StatsRecorder>> {
+    let db_pool = create_memory_pool()?;
+    run_migrations(&db_pool)?;
     Ok(recorder::create_recorder(db_pool))
 }
+
+/// Build the trading state store backed by SQLite.
+///
+/// Shares the configured database with the stats recorder so a manual halt
+/// is remembered across restarts.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The database connection cannot be established
+/// - Migrations fail to run
+pub fn build_trading_state_store(config: &Config) -> Result<Arc<dyn TradingStateStore>> {
+    let db_url = format!("sqlite://{}", config.database);
+    let db_pool = create_pool(&db_url)?;
+    run_migrations(&db_pool)?;
+    Ok(Arc::new(SqliteTradingStateStore::new(db_pool)))
+}
+
+/// Build the position store backed by SQLite.
+///
+/// Shares the configured database with the stats recorder so open positions
+/// and their exposure are restored after a restart.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The database connection cannot be established
+/// - Migrations fail to run
+pub fn build_position_store(config: &Config) -> Result<Arc<dyn PositionStore>> {
+    let db_url = format!("sqlite://{}", config.database);
+    let db_pool = create_pool(&db_url)?;
+    run_migrations(&db_pool)?;
+    Ok(Arc::new(SqlitePositionStore::new(db_pool)))
+}
+
+/// Build the cluster store backed by SQLite.
+///
+/// Returns `None` when `inference.persist_clusters` is disabled, in which
+/// case clusters live only in memory for the lifetime of the process.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The database connection cannot be established
+/// - Migrations fail to run
+pub fn build_cluster_store(config: &Config) -> Result<Option<Arc<SqliteClusterStore>>> {
+    if !config.inference.persist_clusters {
+        return Ok(None);
+    }
+    let db_url = format!("sqlite://{}", config.database);
+    let db_pool = create_pool(&db_url)?;
+    run_migrations(&db_pool)?;
+    Ok(Some(Arc::new(SqliteClusterStore::new(db_pool))))
+}
+
+/// Build the relation store backed by SQLite.
+///
+/// Relations are only written alongside persisted clusters, so this returns
+/// `None` when `inference.persist_clusters` is disabled.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The database connection cannot be established
+/// - Migrations fail to run
+pub fn build_relation_store(config: &Config) -> Result<Option<Arc<SqliteRelationStore>>> {
+    if !config.inference.persist_clusters {
+        return Ok(None);
+    }
+    let db_url = format!("sqlite://{}", config.database);
+    let db_pool = create_pool(&db_url)?;
+    run_migrations(&db_pool)?;
+    Ok(Some(Arc::new(SqliteRelationStore::new(db_pool))))
+}
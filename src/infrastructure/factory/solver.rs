@@ -6,18 +6,19 @@
 use std::sync::Arc;
 
 use crate::adapter::outbound::solver::highs::HiGHSSolver;
+use crate::application::solver::backend::{select_backend, SolverConfig};
 use crate::application::solver::frank_wolfe::FrankWolfeConfig;
 use crate::application::solver::projection::FrankWolfeProjectionSolver;
 use crate::port::outbound::solver::ProjectionSolver;
 
-/// Build the default projection solver for cluster and combinatorial detection.
+/// Build the projection solver for cluster and combinatorial detection.
 ///
-/// Creates a Frank-Wolfe projection solver backed by the HiGHS linear
-/// programming solver. Used for optimizing trade allocations across
-/// multi-market arbitrage opportunities.
-pub fn build_projection_solver() -> Arc<dyn ProjectionSolver> {
+/// Creates a Frank-Wolfe projection solver whose oracle is the backend
+/// selected by `config`, with the HiGHS solver as the exact backend. Used for
+/// optimizing trade allocations across multi-market arbitrage opportunities.
+pub fn build_projection_solver(config: &SolverConfig) -> Arc<dyn ProjectionSolver> {
     Arc::new(FrankWolfeProjectionSolver::new(
         FrankWolfeConfig::default(),
-        Arc::new(HiGHSSolver::new()),
+        select_backend(config, Arc::new(HiGHSSolver::new())),
     ))
 }
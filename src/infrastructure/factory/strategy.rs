@@ -10,6 +10,7 @@ use tracing::warn;
 use crate::application::cache::cluster::ClusterCache;
 use crate::application::strategy::registry::StrategyRegistry;
 use crate::infrastructure::config::settings::Config;
+use crate::infrastructure::config::strategy::StrategiesConfig;
 
 use super::solver::build_projection_solver;
 
@@ -21,22 +22,41 @@ use super::solver::build_projection_solver;
 pub fn build_strategy_registry(
     config: &Config,
     cluster_cache: Arc<ClusterCache>,
+) -> StrategyRegistry {
+    build_registry_from(&config.strategies, cluster_cache)
+}
+
+/// Build the shadow strategy registry, if a shadow configuration is set.
+///
+/// Returns the shadow variant label together with its registry.
+pub fn build_shadow_registry(
+    config: &Config,
+    cluster_cache: Arc<ClusterCache>,
+) -> Option<(String, StrategyRegistry)> {
+    let shadow = config.shadow.as_ref()?;
+    let registry = build_registry_from(&shadow.strategies, cluster_cache);
+    Some((shadow.variant.trim().to_string(), registry))
+}
+
+fn build_registry_from(
+    strategies: &StrategiesConfig,
+    cluster_cache: Arc<ClusterCache>,
 ) -> StrategyRegistry {
     let mut builder = StrategyRegistry::builder()
         .cluster_cache(cluster_cache)
-        .projection_solver(build_projection_solver());
+        .projection_solver(build_projection_solver(&strategies.combinatorial.solver));
 
-    for name in &config.strategies.enabled {
+    for name in &strategies.enabled {
         let normalized = normalize_strategy_name(name);
         match normalized.as_str() {
             "single_condition" => {
-                builder = builder.single_condition(config.strategies.single_condition.clone());
+                builder = builder.single_condition(strategies.single_condition.clone());
             }
             "market_rebalancing" => {
-                builder = builder.market_rebalancing(config.strategies.market_rebalancing.clone());
+                builder = builder.market_rebalancing(strategies.market_rebalancing.clone());
             }
             "combinatorial" => {
-                builder = builder.combinatorial(config.strategies.combinatorial.clone());
+                builder = builder.combinatorial(strategies.combinatorial.clone());
             }
             unknown => {
                 warn!(
@@ -48,6 +68,10 @@ pub fn build_strategy_registry(
         }
     }
 
+    if let Some(policy) = strategies.ensemble {
+        builder = builder.ensemble(policy);
+    }
+
     builder.build()
 }
 
@@ -294,10 +294,23 @@ mod inference_factory {
 mod solver_factory {
     use super::*;
 
+    use crate::application::solver::backend::{SolverBackendKind, SolverConfig};
+    use crate::port::outbound::solver::ProjectionSolver;
+
     #[test]
     fn builds_projection_solver() {
-        let solver = solver::build_projection_solver();
+        let solver = solver::build_projection_solver(&SolverConfig::default());
         // Solver should be created successfully
         assert!(Arc::strong_count(&solver) >= 1);
     }
+
+    #[test]
+    fn builds_projection_solver_with_heuristic_backend() {
+        let config = SolverConfig {
+            backend: SolverBackendKind::Heuristic,
+            ..SolverConfig::default()
+        };
+        let solver = solver::build_projection_solver(&config);
+        assert_eq!(solver.name(), "frank_wolfe");
+    }
 }
@@ -3,4 +3,5 @@
 pub mod latency;
 pub mod resource;
 pub mod runtime;
+pub mod scaler;
 pub mod scaling;
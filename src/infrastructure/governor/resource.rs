@@ -72,6 +72,23 @@ impl ResourceBudget {
         }
     }
 
+    /// Create a resource budget preset for mid-sized hosts.
+    ///
+    /// Sits between [`local`](Self::local) and [`production`](Self::production):
+    /// - 2GB memory budget
+    /// - 4 worker threads
+    /// - 0.7 memory target
+    /// - 0.6 CPU target
+    #[must_use]
+    pub const fn medium() -> Self {
+        Self {
+            max_memory_bytes: 2 * 1024 * 1024 * 1024, // 2GB
+            worker_threads: 4,
+            memory_target: 0.7,
+            cpu_target: 0.6,
+        }
+    }
+
     /// Create a resource budget preset for production environments.
     ///
     /// Uses higher capacity settings suitable for production:
@@ -138,6 +155,18 @@ mod tests {
         assert!((budget.cpu_target - 0.5).abs() < f64::EPSILON);
     }
 
+    // --- ResourceBudget::medium tests ---
+
+    #[test]
+    fn resource_budget_medium_preset() {
+        let budget = ResourceBudget::medium();
+
+        assert_eq!(budget.max_memory_bytes, 2 * 1024 * 1024 * 1024); // 2GB
+        assert_eq!(budget.worker_threads, 4);
+        assert!((budget.memory_target - 0.7).abs() < f64::EPSILON);
+        assert!((budget.cpu_target - 0.6).abs() < f64::EPSILON);
+    }
+
     // --- ResourceBudget::production tests ---
 
     #[test]
@@ -8,6 +8,7 @@ use std::time::Duration;
 
 use super::resource::ResourceBudget;
 use super::scaling::ScalingRecommendation;
+use crate::domain::stats::LatencyPercentiles;
 
 /// Latency targets for the adaptive governor.
 ///
@@ -171,6 +172,17 @@ pub struct LatencyMetrics {
     pub sample_count: usize,
 }
 
+impl From<LatencyMetrics> for LatencyPercentiles {
+    fn from(metrics: LatencyMetrics) -> Self {
+        Self {
+            p50: metrics.p50,
+            p95: metrics.p95,
+            p99: metrics.p99,
+            samples: metrics.sample_count,
+        }
+    }
+}
+
 impl Default for LatencyMetrics {
     fn default() -> Self {
         Self {
@@ -0,0 +1,197 @@
+//! Adaptive subscription scaling.
+//!
+//! The [`SubscriptionScaler`] connects an [`AdaptiveGovernor`] to a
+//! [`SubscriptionManager`]. On every check it asks the governor for a
+//! recommendation and expands or contracts the active subscriptions to match,
+//! bounded by the manager's subscription limit and the scaling cooldown.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use super::runtime::AdaptiveGovernor;
+use super::scaling::ScalingRecommendation;
+use crate::domain::id::TokenId;
+use crate::error::Result;
+use crate::infrastructure::subscription::manager::SubscriptionManager;
+
+/// Result of a single scaling check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScalingOutcome {
+    /// No subscriptions changed.
+    Held,
+    /// Tokens added to the active subscriptions.
+    Expanded(Vec<TokenId>),
+    /// Tokens removed from the active subscriptions.
+    Contracted(Vec<TokenId>),
+}
+
+/// Applies governor recommendations to a subscription manager.
+///
+/// Each check moves the active count at most one configured step towards the
+/// governor's suggested count, never above the manager's
+/// [`max_subscriptions`](SubscriptionManager::max_subscriptions). After a
+/// change the governor is notified and further changes wait out the cooldown.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use edgelord::infrastructure::governor::latency::LatencyGovernor;
+/// use edgelord::infrastructure::governor::runtime::GovernorConfig;
+/// use edgelord::infrastructure::governor::scaler::SubscriptionScaler;
+/// use edgelord::infrastructure::subscription::priority::PrioritySubscriptionManager;
+///
+/// let scaler = SubscriptionScaler::new(
+///     Arc::new(LatencyGovernor::new(GovernorConfig::default())),
+///     Arc::new(PrioritySubscriptionManager::new(100)),
+/// );
+/// ```
+pub struct SubscriptionScaler {
+    governor: Arc<dyn AdaptiveGovernor>,
+    subscriptions: Arc<dyn SubscriptionManager>,
+    /// Timestamp of the last applied scaling action.
+    last_scaled: Mutex<Option<Instant>>,
+}
+
+impl SubscriptionScaler {
+    /// Create a scaler driving `subscriptions` from `governor`.
+    #[must_use]
+    pub fn new(
+        governor: Arc<dyn AdaptiveGovernor>,
+        subscriptions: Arc<dyn SubscriptionManager>,
+    ) -> Self {
+        Self {
+            governor,
+            subscriptions,
+            last_scaled: Mutex::new(None),
+        }
+    }
+
+    /// Feed a market event processing time to the governor.
+    pub fn record_latency(&self, latency: Duration) {
+        self.governor.record_latency(latency);
+    }
+
+    /// How often [`check`](Self::check) should run.
+    #[must_use]
+    pub fn check_interval(&self) -> Duration {
+        self.governor.config().scaling.check_interval
+    }
+
+    /// The subscription manager being scaled.
+    #[must_use]
+    pub fn subscriptions(&self) -> &Arc<dyn SubscriptionManager> {
+        &self.subscriptions
+    }
+
+    /// Evaluate the governor's recommendation and apply it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription manager fails to expand or contract.
+    pub async fn check(&self) -> Result<ScalingOutcome> {
+        let config = self.governor.config();
+        if !config.enabled || self.in_cooldown() {
+            return Ok(ScalingOutcome::Held);
+        }
+
+        let active = self.subscriptions.active_count();
+        let outcome = match self.governor.recommendation() {
+            ScalingRecommendation::Hold => ScalingOutcome::Held,
+            ScalingRecommendation::Expand { suggested_count } => {
+                let target = suggested_count
+                    .min(active + config.scaling.expand_step)
+                    .min(self.subscriptions.max_subscriptions());
+                match target.saturating_sub(active) {
+                    0 => ScalingOutcome::Held,
+                    count => non_empty(self.subscriptions.expand(count).await?)
+                        .map_or(ScalingOutcome::Held, ScalingOutcome::Expanded),
+                }
+            }
+            ScalingRecommendation::Contract { suggested_count } => {
+                let target =
+                    suggested_count.min(active.saturating_sub(config.scaling.contract_step));
+                match active.saturating_sub(target) {
+                    0 => ScalingOutcome::Held,
+                    count => non_empty(self.subscriptions.contract(count).await?)
+                        .map_or(ScalingOutcome::Held, ScalingOutcome::Contracted),
+                }
+            }
+        };
+
+        match &outcome {
+            ScalingOutcome::Held => {
+                debug!(active, "Subscription scaling held");
+                return Ok(outcome);
+            }
+            ScalingOutcome::Expanded(tokens) => {
+                info!(
+                    added = tokens.len(),
+                    active = self.subscriptions.active_count(),
+                    "Expanded subscriptions"
+                );
+            }
+            ScalingOutcome::Contracted(tokens) => {
+                info!(
+                    removed = tokens.len(),
+                    active = self.subscriptions.active_count(),
+                    "Contracted subscriptions under load"
+                );
+            }
+        }
+
+        self.governor.notify_scaled();
+        *self.last_scaled_lock() = Some(Instant::now());
+        Ok(outcome)
+    }
+
+    fn in_cooldown(&self) -> bool {
+        let cooldown = self.governor.config().scaling.cooldown;
+        self.last_scaled_lock()
+            .is_some_and(|instant| instant.elapsed() < cooldown)
+    }
+
+    fn last_scaled_lock(&self) -> std::sync::MutexGuard<'_, Option<Instant>> {
+        self.last_scaled
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+fn non_empty(tokens: Vec<TokenId>) -> Option<Vec<TokenId>> {
+    (!tokens.is_empty()).then_some(tokens)
+}
+
+/// Run scaling checks every check interval until shutdown.
+pub fn spawn_scaler(
+    scaler: Arc<SubscriptionScaler>,
+    mut shutdown: watch::Receiver<bool>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(scaler.check_interval());
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        // The first tick completes immediately; skip it so the governor has
+        // a full interval of samples before the first check.
+        interval.tick().await;
+
+        loop {
+            tokio::select! {
+                result = shutdown.changed() => {
+                    if result.is_err() || *shutdown.borrow() {
+                        break;
+                    }
+                }
+                _ = interval.tick() => {
+                    if let Err(e) = scaler.check().await {
+                        warn!(error = %e, "Subscription scaling check failed");
+                    }
+                }
+            }
+        }
+        debug!("Subscription scaler stopped");
+    })
+}
@@ -1,10 +1,16 @@
 //! Configuration operator implementation.
 
+use serde_json::Value;
+
 use crate::error::Result;
 use crate::infrastructure::config;
+use crate::infrastructure::config::diff::diff_from_default;
+use crate::infrastructure::config::layer::merge_toml;
+use crate::infrastructure::config::migrate::{migrate, MigrationChange};
 use crate::port::inbound::operator::config::{
-    ConfigClusterDetection, ConfigInference, ConfigRiskLimits, ConfigValidationReport, ConfigView,
-    ConfigurationOperator,
+    ConfigClusterDetection, ConfigDiff, ConfigDiffEntry, ConfigDiffSection, ConfigInference,
+    ConfigMigration, ConfigMigrationChange, ConfigRiskLimits, ConfigStrategyDefaults,
+    ConfigValidationReport, ConfigView, ConfigurationOperator,
 };
 
 use super::entry::Operator;
@@ -73,4 +79,91 @@ impl ConfigurationOperator for Operator {
 
         Ok(ConfigValidationReport { warnings })
     }
+
+    fn diff_config(&self, config_toml: &str) -> Result<ConfigDiff> {
+        let config = config::settings::Config::parse_toml(config_toml)?;
+        let mut general = Vec::new();
+        let mut sections: Vec<ConfigDiffSection> = Vec::new();
+
+        // Changes arrive in path order, so each section's entries are contiguous.
+        for change in diff_from_default(&config)? {
+            let entry = ConfigDiffEntry {
+                key: change.key().to_string(),
+                default: render_value(&change.default),
+                value: render_value(&change.value),
+            };
+            match change.section() {
+                None => general.push(entry),
+                Some(name) => match sections.last_mut() {
+                    Some(section) if section.name == name => section.entries.push(entry),
+                    _ => sections.push(ConfigDiffSection {
+                        name: name.to_string(),
+                        entries: vec![entry],
+                    }),
+                },
+            }
+        }
+
+        if !general.is_empty() {
+            sections.insert(
+                0,
+                ConfigDiffSection {
+                    name: "general".to_string(),
+                    entries: general,
+                },
+            );
+        }
+        Ok(ConfigDiff { sections })
+    }
+
+    fn migrate_config(&self, config_toml: &str) -> Result<ConfigMigration> {
+        let migration = migrate(config_toml)?;
+        let changed = !migration.is_empty();
+        let changes = migration
+            .changes
+            .into_iter()
+            .map(|change| match change {
+                MigrationChange::Renamed { from, to } => {
+                    ConfigMigrationChange::Renamed { from, to }
+                }
+                MigrationChange::Removed { path, reason } => {
+                    ConfigMigrationChange::Removed { path, reason }
+                }
+                MigrationChange::Unknown { path } => ConfigMigrationChange::Unknown { path },
+                MigrationChange::Defaulted { path } => ConfigMigrationChange::Defaulted { path },
+            })
+            .collect();
+        Ok(ConfigMigration {
+            config_toml: migration.config_toml,
+            changes,
+            changed,
+        })
+    }
+
+    fn layer_config(&self, base_toml: &str, overlay_toml: &str) -> Result<String> {
+        merge_toml(base_toml, overlay_toml)
+    }
+
+    fn strategy_defaults(&self) -> ConfigStrategyDefaults {
+        let strategies = config::strategy::StrategiesConfig::default();
+        ConfigStrategyDefaults {
+            enabled: strategies.enabled,
+            single_condition_min_edge: strategies.single_condition.min_edge,
+            single_condition_min_profit: strategies.single_condition.min_profit,
+            market_rebalancing_min_edge: strategies.market_rebalancing.min_edge,
+            market_rebalancing_min_profit: strategies.market_rebalancing.min_profit,
+            market_rebalancing_max_outcomes: strategies.market_rebalancing.max_outcomes,
+            combinatorial_gap_threshold: strategies.combinatorial.gap_threshold,
+            combinatorial_enabled: strategies.combinatorial.enabled,
+        }
+    }
+}
+
+/// Render a serialized setting the way it would be written in TOML.
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::Null => "(unset)".to_string(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
 }
@@ -1,16 +1,21 @@
 //! Diagnostic operator implementation.
 
+use std::time::Duration;
+
 use async_trait::async_trait;
 
 use crate::error::{ConfigError, Error, Result};
 use crate::infrastructure::config;
+use crate::infrastructure::config::settings::ExchangeSpecificConfig;
+use crate::infrastructure::exchange::factory::ExchangeFactory;
 use crate::infrastructure::orchestration::orchestrator::{self, HealthStatus};
 use crate::port::inbound::operator::diagnostic::{
-    ConfigCheckReport, ConnectionCheckTarget, DiagnosticOperator, HealthCheckEntry,
-    HealthCheckReport, HealthCheckStatus, LiveReadinessReport, TelegramTestReceipt,
+    BookParseReport, ConfigCheckReport, ConnectionCheckTarget, DetectionBenchReport,
+    DiagnosticOperator, HealthCheckEntry, HealthCheckReport, HealthCheckStatus,
+    LiveReadinessReport, MarketScanReport, ScannedOpportunity, TelegramTestReceipt,
 };
 
-use super::{entry::Operator, shared};
+use super::{entry::Operator, fixture::BookFixture, shared};
 
 #[async_trait]
 impl DiagnosticOperator for Operator {
@@ -102,6 +107,24 @@ impl DiagnosticOperator for Operator {
         Ok(HealthCheckReport { checks })
     }
 
+    fn check_book_parse(&self, config_toml: &str, fixture_json: &str) -> Result<BookParseReport> {
+        let config = config::settings::Config::parse_toml(config_toml)?;
+        let fixture = BookFixture::parse(fixture_json)?;
+        let parser = ExchangeFactory::create_market_parser(&config);
+
+        let (books_parsed, mismatches) = match parser.parse_order_book(&fixture.raw_message()) {
+            Ok(books) => (books.len(), fixture.compare(&books)),
+            Err(error) => (0, vec![format!("parser rejected message: {error}")]),
+        };
+
+        Ok(BookParseReport {
+            exchange: parser.name().to_string(),
+            books_parsed,
+            books_expected: fixture.expected_len(),
+            mismatches,
+        })
+    }
+
     async fn send_telegram_test(&self, config_toml: &str) -> Result<TelegramTestReceipt> {
         let config = config::settings::Config::parse_toml(config_toml)?;
 
@@ -109,9 +132,25 @@ impl DiagnosticOperator for Operator {
             field: "TELEGRAM_BOT_TOKEN environment variable",
         })?;
 
-        let chat_id = std::env::var("TELEGRAM_CHAT_ID").map_err(|_| ConfigError::MissingField {
-            field: "TELEGRAM_CHAT_ID environment variable",
-        })?;
+        // TELEGRAM_CHAT_IDS entries may carry `:kind+kind` event filters;
+        // the test message goes to every listed chat regardless.
+        let chat_ids: Vec<String> = std::env::var("TELEGRAM_CHAT_IDS")
+            .or_else(|_| std::env::var("TELEGRAM_CHAT_ID"))
+            .map_err(|_| ConfigError::MissingField {
+                field: "TELEGRAM_CHAT_IDS or TELEGRAM_CHAT_ID environment variable",
+            })?
+            .split(',')
+            .filter_map(|entry| entry.split(':').next())
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect();
+        if chat_ids.is_empty() {
+            return Err(ConfigError::MissingField {
+                field: "TELEGRAM_CHAT_IDS or TELEGRAM_CHAT_ID environment variable",
+            }
+            .into());
+        }
 
         let message = format!(
             "🧪 *Edgelord Test Message*\n\n\
@@ -127,31 +166,115 @@ impl DiagnosticOperator for Operator {
         let client = reqwest::Client::new();
         let url = format!("https://api.telegram.org/bot{token}/sendMessage");
 
-        let response = client
-            .post(&url)
-            .json(&serde_json::json!({
-                "chat_id": chat_id,
-                "text": message,
-                "parse_mode": "MarkdownV2",
-            }))
-            .send()
-            .await
-            .map_err(|error| Error::Connection(error.to_string()))?;
+        for chat_id in &chat_ids {
+            let response = client
+                .post(&url)
+                .json(&serde_json::json!({
+                    "chat_id": chat_id,
+                    "text": message,
+                    "parse_mode": "MarkdownV2",
+                }))
+                .send()
+                .await
+                .map_err(|error| Error::Connection(error.to_string()))?;
 
-        if response.status().is_success() {
-            Ok(TelegramTestReceipt {
-                masked_token: shared::mask_token(&token),
-                chat_id,
+            if !response.status().is_success() {
+                let status = response.status();
+                let body: String = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "Unknown error".to_string());
+                return Err(Error::Connection(format!(
+                    "failed to send telegram message to chat {chat_id}: {status} {body}"
+                )));
+            }
+        }
+
+        Ok(TelegramTestReceipt {
+            masked_token: shared::mask_token(&token),
+            chat_id: chat_ids.join(", "),
+        })
+    }
+
+    async fn scan_markets(
+        &self,
+        config_toml: &str,
+        max_markets: usize,
+        duration: Duration,
+    ) -> Result<MarketScanReport> {
+        let mut config = config::settings::Config::parse_toml(config_toml)?;
+        match &mut config.exchange_config {
+            ExchangeSpecificConfig::Polymarket(pm_config) => {
+                pm_config.market_filter.max_markets = max_markets;
+            }
+            #[cfg(feature = "kalshi")]
+            ExchangeSpecificConfig::Kalshi(kalshi_config) => {
+                kalshi_config.market_filter.max_markets = max_markets;
+            }
+            #[cfg(feature = "manifold")]
+            ExchangeSpecificConfig::Manifold(manifold_config) => {
+                manifold_config.market_filter.max_markets = max_markets;
+            }
+        }
+
+        let scan = orchestrator::scan_markets(&config, duration).await?;
+        let mut opportunities: Vec<ScannedOpportunity> = scan
+            .opportunities
+            .iter()
+            .map(|opp| ScannedOpportunity {
+                strategy: opp.strategy().to_string(),
+                market_id: opp.market_id().to_string(),
+                question: opp.question().to_string(),
+                edge: opp.edge(),
+                volume: opp.volume(),
+                expected_profit: opp.expected_profit(),
+                explanation: opp.explain(),
             })
-        } else {
-            let status = response.status();
-            let body: String = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            Err(Error::Connection(format!(
-                "failed to send telegram message: {status} {body}"
-            )))
+            .collect();
+        opportunities.sort_by(|a, b| b.expected_profit.cmp(&a.expected_profit));
+
+        Ok(MarketScanReport {
+            exchange: format!("{:?}", config.exchange),
+            markets: scan.markets,
+            tokens: scan.tokens,
+            books: scan.books,
+            duration,
+            opportunities,
+        })
+    }
+
+    fn bench_detection(
+        &self,
+        config_toml: Option<&str>,
+        strategies: Option<&[String]>,
+        markets: usize,
+        iterations: usize,
+        seed: u64,
+    ) -> Result<DetectionBenchReport> {
+        let mut config = match config_toml {
+            Some(toml) => config::settings::Config::parse_toml(toml)?,
+            None => config::settings::Config::default(),
+        };
+        if let Some(strategies) = strategies {
+            config.strategies.enabled = strategies.to_vec();
         }
+
+        let bench = orchestrator::bench_detection(&config, markets, iterations, seed);
+        let detections_per_sec = bench.detections_per_sec();
+        let opportunities_per_sec = bench.opportunities_per_sec();
+        Ok(DetectionBenchReport {
+            markets: bench.markets,
+            tokens: bench.tokens,
+            strategies: bench.strategies,
+            iterations: bench.iterations,
+            detections: bench.detections,
+            opportunities: bench.opportunities,
+            elapsed: bench.elapsed,
+            detections_per_sec,
+            opportunities_per_sec,
+            p50: bench.latency.p50,
+            p95: bench.latency.p95,
+            p99: bench.latency.p99,
+        })
     }
 }
@@ -0,0 +1,200 @@
+//! Golden-file fixtures for order book parser diagnostics.
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::domain::book::{Book, PriceLevel};
+use crate::error::Result;
+
+/// Stored parser fixture: a raw exchange message and the books it must yield.
+#[derive(Debug, Deserialize)]
+pub(super) struct BookFixture {
+    /// Raw message, either as a JSON string or as inline JSON.
+    message: serde_json::Value,
+    /// Books the parser is expected to produce, in message order.
+    expected: Vec<ExpectedBook>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExpectedBook {
+    token_id: String,
+    #[serde(default)]
+    bids: Vec<ExpectedLevel>,
+    #[serde(default)]
+    asks: Vec<ExpectedLevel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExpectedLevel {
+    price: Decimal,
+    size: Decimal,
+}
+
+impl BookFixture {
+    /// Parse a fixture from JSON content.
+    pub(super) fn parse(content: &str) -> Result<Self> {
+        Ok(serde_json::from_str(content)?)
+    }
+
+    /// Return the raw message payload to feed into the exchange parser.
+    pub(super) fn raw_message(&self) -> String {
+        match &self.message {
+            serde_json::Value::String(raw) => raw.clone(),
+            other => other.to_string(),
+        }
+    }
+
+    /// Return the number of books the fixture expects.
+    pub(super) fn expected_len(&self) -> usize {
+        self.expected.len()
+    }
+
+    /// Compare parsed books against the expected snapshot.
+    ///
+    /// Returns one entry per difference; an empty result means a match.
+    pub(super) fn compare(&self, actual: &[Book]) -> Vec<String> {
+        let mut mismatches = Vec::new();
+
+        if actual.len() != self.expected.len() {
+            mismatches.push(format!(
+                "expected {} books, parsed {}",
+                self.expected.len(),
+                actual.len()
+            ));
+        }
+
+        for (index, (expected, book)) in self.expected.iter().zip(actual).enumerate() {
+            if book.token_id().as_str() != expected.token_id {
+                mismatches.push(format!(
+                    "book {index}: expected token {}, parsed {}",
+                    expected.token_id,
+                    book.token_id()
+                ));
+            }
+            compare_levels(
+                &mut mismatches,
+                &expected.token_id,
+                "bids",
+                &expected.bids,
+                book.bids(),
+            );
+            compare_levels(
+                &mut mismatches,
+                &expected.token_id,
+                "asks",
+                &expected.asks,
+                book.asks(),
+            );
+        }
+
+        mismatches
+    }
+}
+
+fn compare_levels(
+    mismatches: &mut Vec<String>,
+    token_id: &str,
+    side: &str,
+    expected: &[ExpectedLevel],
+    actual: &[PriceLevel],
+) {
+    if expected.len() != actual.len() {
+        mismatches.push(format!(
+            "{token_id} {side}: expected {} levels, parsed {}",
+            expected.len(),
+            actual.len()
+        ));
+    }
+
+    for (index, (want, got)) in expected.iter().zip(actual).enumerate() {
+        if want.price != got.price() || want.size != got.size() {
+            mismatches.push(format!(
+                "{token_id} {side}[{index}]: expected {}@{}, parsed {}@{}",
+                want.size,
+                want.price,
+                got.size(),
+                got.price()
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::outbound::polymarket::market::PolymarketMarketParser;
+    use crate::port::outbound::exchange::MarketParser;
+
+    const GOOD_FIXTURE: &str = r#"{
+        "message": [{
+            "asset_id": "token-yes",
+            "market": "0xmarket",
+            "bids": [{"price": "0.45", "size": "100"}, {"price": "0.44", "size": "250"}],
+            "asks": [{"price": "0.47", "size": "150"}]
+        }],
+        "expected": [{
+            "token_id": "token-yes",
+            "bids": [{"price": "0.45", "size": "100"}, {"price": "0.44", "size": "250"}],
+            "asks": [{"price": "0.47", "size": "150"}]
+        }]
+    }"#;
+
+    const CORRUPTED_FIXTURE: &str = r#"{
+        "message": [{
+            "asset_id": "token-yes",
+            "bids": [{"price": "0.45", "size": "100"}, {"price": "bad", "size": "250"}],
+            "asks": [{"price": "0.48", "size": "150"}]
+        }],
+        "expected": [{
+            "token_id": "token-yes",
+            "bids": [{"price": "0.45", "size": "100"}, {"price": "0.44", "size": "250"}],
+            "asks": [{"price": "0.47", "size": "150"}]
+        }]
+    }"#;
+
+    fn parse_with_polymarket(fixture: &BookFixture) -> Vec<Book> {
+        PolymarketMarketParser
+            .parse_order_book(&fixture.raw_message())
+            .unwrap()
+    }
+
+    #[test]
+    fn known_good_fixture_matches() {
+        let fixture = BookFixture::parse(GOOD_FIXTURE).unwrap();
+        let books = parse_with_polymarket(&fixture);
+
+        assert!(fixture.compare(&books).is_empty());
+    }
+
+    #[test]
+    fn corrupted_fixture_is_flagged() {
+        let fixture = BookFixture::parse(CORRUPTED_FIXTURE).unwrap();
+        let books = parse_with_polymarket(&fixture);
+        let mismatches = fixture.compare(&books);
+
+        assert!(mismatches
+            .iter()
+            .any(|m| m.contains("bids: expected 2 levels")));
+        assert!(mismatches.iter().any(|m| m.contains("asks[0]")));
+    }
+
+    #[test]
+    fn string_message_is_passed_through_verbatim() {
+        let fixture = BookFixture::parse(
+            r#"{"message": "[{\"asset_id\": \"t\", \"bids\": [], \"asks\": []}]", "expected": [{"token_id": "t"}]}"#,
+        )
+        .unwrap();
+        let books = parse_with_polymarket(&fixture);
+
+        assert!(fixture.compare(&books).is_empty());
+    }
+
+    #[test]
+    fn book_count_mismatch_is_flagged() {
+        let fixture =
+            BookFixture::parse(r#"{"message": [], "expected": [{"token_id": "t"}]}"#).unwrap();
+        let books = parse_with_polymarket(&fixture);
+
+        assert_eq!(fixture.compare(&books), vec!["expected 1 books, parsed 0"]);
+    }
+}
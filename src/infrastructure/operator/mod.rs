@@ -3,9 +3,11 @@
 pub mod config;
 pub mod diagnostic;
 pub mod entry;
+pub mod position;
 pub mod runtime;
 pub mod stats;
 pub mod status;
 pub mod wallet;
 
+mod fixture;
 mod shared;
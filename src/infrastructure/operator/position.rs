@@ -0,0 +1,173 @@
+//! Position operator implementation.
+
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+use crate::adapter::outbound::sqlite::recorder::f32_to_decimal;
+use crate::adapter::outbound::sqlite::report::SqliteReportReader;
+use crate::application::cache::book::BookCache;
+use crate::application::position::manager::PositionManager;
+use crate::domain::id::{MarketId, PositionId, TokenId};
+use crate::domain::position::{Position, PositionLeg, PositionStatus};
+use crate::error::Result;
+use crate::infrastructure::config;
+use crate::infrastructure::orchestration::orchestrator;
+use crate::port::inbound::operator::position::{
+    OpenPositionView, PositionLegView, PositionOperator, PositionsReport,
+};
+use crate::port::outbound::report::{OpenTradeRecord, StatusReportReader};
+
+use super::entry::Operator;
+
+#[async_trait]
+impl PositionOperator for Operator {
+    async fn load_positions(
+        &self,
+        database_url: &str,
+        config_toml: Option<&str>,
+        duration: Duration,
+    ) -> Result<PositionsReport> {
+        let trades = SqliteReportReader::new(database_url).load_open_trades()?;
+
+        let (cache, marked) = match config_toml {
+            Some(toml) if !trades.is_empty() => {
+                let config = config::settings::Config::parse_toml(toml)?;
+                let token_ids: Vec<TokenId> = trades
+                    .iter()
+                    .flat_map(|trade| trade.legs.iter().map(|leg| leg.token_id.clone()))
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .map(TokenId::from)
+                    .collect();
+                let cache = orchestrator::collect_marks(&config, &token_ids, duration).await?;
+                (cache, true)
+            }
+            _ => (BookCache::new(), false),
+        };
+
+        let positions = trades
+            .iter()
+            .map(|trade| value_position(trade, &cache))
+            .collect();
+
+        Ok(PositionsReport { marked, positions })
+    }
+}
+
+/// Value an open trade against the books in `cache`.
+///
+/// The trade is rebuilt as a domain [`Position`] so marking and the $1
+/// settlement estimate share the position manager's arithmetic.
+fn value_position(trade: &OpenTradeRecord, cache: &BookCache) -> OpenPositionView {
+    let legs: Vec<PositionLeg> = trade
+        .legs
+        .iter()
+        .map(|leg| PositionLeg::new(TokenId::from(leg.token_id.clone()), leg.size, leg.price))
+        .collect();
+    let entry_cost: Decimal = legs.iter().map(PositionLeg::cost).sum();
+    let opened_at = DateTime::parse_from_rfc3339(&trade.opened_at)
+        .map_or_else(|_| Utc::now(), |ts| ts.with_timezone(&Utc));
+
+    let position = Position::new(
+        PositionId::new(u64::try_from(trade.id).unwrap_or_default()),
+        MarketId::from(trade.market_ids.first().cloned().unwrap_or_default()),
+        legs,
+        entry_cost,
+        f32_to_decimal(trade.size),
+        opened_at,
+        PositionStatus::Open,
+    );
+
+    let current_value = if trade.legs.is_empty() {
+        None
+    } else {
+        PositionManager::mark_value(&position, cache)
+    };
+
+    OpenPositionView {
+        trade_id: trade.id,
+        strategy: trade.strategy.clone(),
+        market_ids: trade.market_ids.clone(),
+        opened_at: trade.opened_at.clone(),
+        legs: trade
+            .legs
+            .iter()
+            .map(|leg| PositionLegView {
+                token_id: leg.token_id.clone(),
+                side: leg.side.clone(),
+                size: leg.size,
+                entry_price: leg.price,
+                mark: cache
+                    .get(&TokenId::from(leg.token_id.clone()))
+                    .and_then(|book| book.best_bid().map(|level| level.price())),
+            })
+            .collect(),
+        entry_cost,
+        current_value,
+        unrealized_pnl: current_value.map(|value| value - entry_cost),
+        settlement_pnl: PositionManager::calculate_arbitrage_pnl(&position, Decimal::ONE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::book::{Book, PriceLevel};
+    use crate::domain::stats::TradeLeg;
+    use rust_decimal_macros::dec;
+
+    fn trade() -> OpenTradeRecord {
+        let leg = |token: &str, price| TradeLeg {
+            token_id: token.to_string(),
+            side: "buy".to_string(),
+            price,
+            size: dec!(10),
+        };
+        OpenTradeRecord {
+            id: 7,
+            strategy: "single_condition".to_string(),
+            market_ids: vec!["m1".to_string()],
+            legs: vec![leg("yes", dec!(0.45)), leg("no", dec!(0.50))],
+            size: 10.0,
+            expected_profit: 0.5,
+            opened_at: "2024-01-01T10:00:00+00:00".to_string(),
+        }
+    }
+
+    fn book(token: &str, bid: Decimal) -> Book {
+        Book::with_levels(
+            TokenId::from(token),
+            vec![PriceLevel::new(bid, dec!(100))],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn value_position_marks_every_leg_at_best_bid() {
+        let cache = BookCache::new();
+        cache.update(book("yes", dec!(0.48)));
+        cache.update(book("no", dec!(0.49)));
+
+        let view = value_position(&trade(), &cache);
+
+        assert_eq!(view.entry_cost, dec!(9.50));
+        assert_eq!(view.current_value, Some(dec!(9.70)));
+        assert_eq!(view.unrealized_pnl, Some(dec!(0.20)));
+        assert_eq!(view.settlement_pnl, dec!(0.50));
+        assert_eq!(view.legs[0].mark, Some(dec!(0.48)));
+    }
+
+    #[test]
+    fn value_position_without_books_leaves_marks_empty() {
+        let view = value_position(&trade(), &BookCache::new());
+
+        assert_eq!(view.current_value, None);
+        assert_eq!(view.unrealized_pnl, None);
+        assert!(view.legs.iter().all(|leg| leg.mark.is_none()));
+        assert_eq!(view.settlement_pnl, dec!(0.50));
+    }
+}
@@ -1,5 +1,7 @@
 //! Runtime operator implementation.
 
+use std::path::Path;
+
 use async_trait::async_trait;
 use tokio::signal;
 #[cfg(feature = "polymarket")]
@@ -8,10 +10,13 @@ use tracing::info;
 
 use crate::error::Result;
 use crate::infrastructure::config;
+use crate::infrastructure::orchestration::orchestrator;
 #[cfg(feature = "polymarket")]
 use crate::infrastructure::orchestration::orchestrator::Orchestrator;
 use crate::infrastructure::wallet;
-use crate::port::inbound::operator::runtime::{RunRequest, RunStartupSnapshot, RuntimeOperator};
+use crate::port::inbound::operator::runtime::{
+    BacktestReport, RunRequest, RunStartupSnapshot, RuntimeOperator,
+};
 
 use super::{entry::Operator, shared};
 
@@ -84,11 +89,42 @@ impl RuntimeOperator for Operator {
         info!("edgelord stopped");
         Ok(())
     }
+
+    async fn simulate(
+        &self,
+        config_toml: &str,
+        data_path: &str,
+        runs: usize,
+        seed: u64,
+    ) -> Result<BacktestReport> {
+        let config = config::settings::Config::parse_toml(config_toml)?;
+        let backtest =
+            orchestrator::simulate_backtest(&config, Path::new(data_path), runs, seed).await?;
+
+        Ok(BacktestReport {
+            markets: backtest.markets,
+            events: backtest.events,
+            opportunities: backtest.opportunities,
+            trades: backtest.trades(),
+            wins: backtest.wins(),
+            pnl: backtest.pnl(),
+            max_drawdown: backtest.max_drawdown(),
+            win_rate: backtest.win_rate(),
+            runs: backtest.runs,
+            drawdown_p50: backtest.drawdown_p50,
+            drawdown_p95: backtest.drawdown_p95,
+        })
+    }
 }
 
 impl Operator {
     fn load_run_config(&self, request: &RunRequest) -> Result<config::settings::Config> {
         let mut config = config::settings::Config::parse_toml(&request.config_toml)?;
+        if let Some(ref profile) = request.profile {
+            profile
+                .parse::<config::profile::Profile>()?
+                .apply_to(&mut config);
+        }
         Self::apply_run_overrides(&mut config, request);
         Ok(config)
     }
@@ -99,6 +135,11 @@ impl Operator {
                 config::settings::ExchangeSpecificConfig::Polymarket(exchange) => {
                     exchange.chain_id = chain_id;
                 }
+                // Kalshi does not settle on chain
+                #[cfg(feature = "kalshi")]
+                config::settings::ExchangeSpecificConfig::Kalshi(_) => {}
+                #[cfg(feature = "manifold")]
+                config::settings::ExchangeSpecificConfig::Manifold(_) => {}
             }
         }
 
@@ -157,6 +198,14 @@ impl Operator {
                 config::settings::ExchangeSpecificConfig::Polymarket(exchange) => {
                     exchange.market_filter.max_markets = max_markets;
                 }
+                #[cfg(feature = "kalshi")]
+                config::settings::ExchangeSpecificConfig::Kalshi(exchange) => {
+                    exchange.market_filter.max_markets = max_markets;
+                }
+                #[cfg(feature = "manifold")]
+                config::settings::ExchangeSpecificConfig::Manifold(exchange) => {
+                    exchange.market_filter.max_markets = max_markets;
+                }
             }
         }
 
@@ -165,6 +214,14 @@ impl Operator {
                 config::settings::ExchangeSpecificConfig::Polymarket(exchange) => {
                     exchange.market_filter.min_volume_24h = min_volume;
                 }
+                #[cfg(feature = "kalshi")]
+                config::settings::ExchangeSpecificConfig::Kalshi(exchange) => {
+                    exchange.market_filter.min_volume_24h = min_volume;
+                }
+                #[cfg(feature = "manifold")]
+                config::settings::ExchangeSpecificConfig::Manifold(exchange) => {
+                    exchange.market_filter.min_volume_24h = min_volume;
+                }
             }
         }
 
@@ -173,6 +230,14 @@ impl Operator {
                 config::settings::ExchangeSpecificConfig::Polymarket(exchange) => {
                     exchange.market_filter.min_liquidity = min_liquidity;
                 }
+                #[cfg(feature = "kalshi")]
+                config::settings::ExchangeSpecificConfig::Kalshi(exchange) => {
+                    exchange.market_filter.min_liquidity = min_liquidity;
+                }
+                #[cfg(feature = "manifold")]
+                config::settings::ExchangeSpecificConfig::Manifold(exchange) => {
+                    exchange.market_filter.min_liquidity = min_liquidity;
+                }
             }
         }
 
@@ -196,6 +261,26 @@ impl Operator {
             config.database = database_path.clone();
         }
 
+        if let Some(ref replay_path) = request.replay_path {
+            config.replay = Some(config::replay::ReplayConfig {
+                path: replay_path.clone(),
+                speed: request.replay_speed.unwrap_or_default(),
+            });
+        }
+
+        if let Some(ref warm_from) = request.warm_from {
+            match config.snapshot {
+                Some(ref mut snapshot) => snapshot.path = warm_from.clone(),
+                None => {
+                    config.snapshot = Some(config::snapshot::SnapshotConfig::new(warm_from));
+                }
+            }
+        }
+
+        if config.replay.is_some() {
+            config.dry_run = true;
+        }
+
         if request.mainnet {
             config.set_mainnet();
         }
@@ -46,6 +46,22 @@ pub(super) fn validate_sweep_inputs(
                 .into());
             }
         }
+        #[cfg(feature = "kalshi")]
+        config::settings::Exchange::Kalshi => {
+            return Err(ConfigError::InvalidValue {
+                field: "exchange",
+                reason: "sweeps are not supported for Kalshi".to_string(),
+            }
+            .into());
+        }
+        #[cfg(feature = "manifold")]
+        config::settings::Exchange::Manifold => {
+            return Err(ConfigError::InvalidValue {
+                field: "exchange",
+                reason: "sweeps are not supported for Manifold".to_string(),
+            }
+            .into());
+        }
     }
 
     Ok(())
@@ -53,6 +69,7 @@ pub(super) fn validate_sweep_inputs(
 
 pub(super) fn chain_name(chain_id: u64) -> &'static str {
     match chain_id {
+        0 => "off-chain",
         137 => "polygon",
         80002 => "amoy",
         _ => "unknown",
@@ -1,9 +1,13 @@
 //! Statistics operator implementation.
 
+use std::collections::HashMap;
+
 use chrono::NaiveDate;
 
+use crate::adapter::outbound::sqlite::database::connection::create_pool;
+use crate::adapter::outbound::sqlite::recorder::SqliteRecorder;
 use crate::adapter::outbound::sqlite::report::SqliteReportReader;
-use crate::domain::stats::StatsSummary;
+use crate::domain::stats::{OpportunityFilter, OpportunitySummary, StatsSummary};
 use crate::error::Result;
 use crate::port::inbound::operator::stats::{
     DailyStatsRecord, StatisticsOperator, StrategyStatsRecord,
@@ -40,10 +44,21 @@ impl StatisticsOperator for Operator {
                 profit_realized: row.profit_realized,
                 win_count: row.win_count,
                 loss_count: row.loss_count,
+                loss_realized: row.loss_realized,
             })
             .collect())
     }
 
+    fn load_strategy_summary(
+        &self,
+        database_url: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<HashMap<String, StatsSummary>> {
+        let recorder = SqliteRecorder::new(create_pool(database_url)?);
+        Ok(recorder.get_strategy_summary(from, to))
+    }
+
     fn load_open_positions(&self, database_url: &str) -> Result<i64> {
         SqliteReportReader::new(database_url).load_open_positions()
     }
@@ -85,4 +100,13 @@ impl StatisticsOperator for Operator {
     fn prune_old_records(&self, database_url: &str, retention_days: u32) -> Result<()> {
         SqliteReportReader::new(database_url).prune_old_records(retention_days)
     }
+
+    fn query_opportunities(
+        &self,
+        database_url: &str,
+        filter: OpportunityFilter,
+    ) -> Result<Vec<OpportunitySummary>> {
+        let recorder = SqliteRecorder::new(create_pool(database_url)?);
+        Ok(recorder.query_opportunities(filter))
+    }
 }
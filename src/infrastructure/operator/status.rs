@@ -54,6 +54,10 @@ impl StatusOperator for Operator {
             distinct_markets: snapshot.distinct_markets,
             current_exposure: snapshot.current_exposure,
             recent_activity,
+            trading_state: snapshot.trading_state,
+            last_event_at: snapshot.last_event_at,
+            latency: snapshot.latency,
+            top_markets: snapshot.top_markets,
         })
     }
 }
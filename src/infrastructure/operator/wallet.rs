@@ -6,7 +6,8 @@ use rust_decimal::Decimal;
 use crate::error::Result;
 use crate::infrastructure::{config, wallet};
 use crate::port::inbound::operator::wallet::{
-    ApprovalOutcome, SweepOutcome, WalletApprovalStatus, WalletOperator,
+    ApprovalOutcome, KeyringImport, SweepOutcome, WalletApprovalStatus, WalletBalances,
+    WalletOperator,
 };
 
 use super::{entry::Operator, shared};
@@ -50,6 +51,19 @@ impl WalletOperator for Operator {
         wallet::WalletService::usdc_balance(&config).await
     }
 
+    async fn wallet_balances(&self, config_toml: &str) -> Result<WalletBalances> {
+        let config = config::settings::Config::parse_toml(config_toml)?;
+        let balances = wallet::WalletService::balances(&config).await?;
+        Ok(WalletBalances {
+            exchange: balances.exchange,
+            wallet_address: balances.wallet_address,
+            usdc: balances.usdc,
+            gas_token: balances.gas_token,
+            gas: balances.gas,
+            min_gas: balances.min_gas,
+        })
+    }
+
     async fn wallet_sweep(
         &self,
         config_toml: &str,
@@ -68,4 +82,20 @@ impl WalletOperator for Operator {
             }
         })
     }
+
+    fn wallet_import_keyring(&self, config_toml: &str, private_key: &str) -> Result<KeyringImport> {
+        // The file is read without loading a key: the keyring entry being
+        // written may not exist yet.
+        let mut config = config::settings::Config::from_file_toml(config_toml)?;
+        config.wallet.private_key = Some(private_key.trim().to_string());
+        let wallet_address = wallet::WalletService::wallet_address(&config)?;
+
+        let private_key = config.wallet.private_key.as_deref().unwrap_or_default();
+        config.wallet.store_keyring_private_key(private_key)?;
+        Ok(KeyringImport {
+            service: config.wallet.keyring_service,
+            account: config.wallet.keyring_account,
+            wallet_address,
+        })
+    }
 }
@@ -0,0 +1,252 @@
+//! Offline detection throughput benchmark.
+//!
+//! Generates synthetic markets with random order books in memory and runs
+//! the configured strategy registry over them in a tight loop. Never touches
+//! the network or the database.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rust_decimal::Decimal;
+
+use crate::application::cache::book::BookCache;
+use crate::application::orchestration::handler::detect_market_opportunities;
+use crate::domain::book::{Book, PriceLevel};
+use crate::domain::market::{Market, MarketRegistry, Outcome};
+use crate::domain::stats::LatencyPercentiles;
+use crate::infrastructure::bootstrap::{build_cluster_cache, build_strategy_registry};
+use crate::infrastructure::config::settings::Config;
+use crate::port::inbound::strategy::StrategyEngine;
+use crate::testkit::domain::{make_tokens, market_id};
+
+/// Price levels generated on each side of a synthetic book.
+const BOOK_DEPTH: u32 = 3;
+
+/// Outcome of a detection benchmark run.
+#[derive(Debug, Clone)]
+pub struct DetectionBench {
+    /// Synthetic markets generated.
+    pub markets: usize,
+    /// Tokens across all synthetic markets.
+    pub tokens: usize,
+    /// Strategies that ran, as named by the registry.
+    pub strategies: Vec<String>,
+    /// Passes made over every market.
+    pub iterations: usize,
+    /// Per-market detection calls timed.
+    pub detections: usize,
+    /// Opportunities found across all detection calls.
+    pub opportunities: usize,
+    /// Wall time spent in detection.
+    pub elapsed: Duration,
+    /// Per-detection latency percentiles.
+    pub latency: LatencyPercentiles,
+}
+
+impl DetectionBench {
+    /// Per-market detection calls completed per second.
+    #[must_use]
+    pub fn detections_per_sec(&self) -> f64 {
+        per_sec(self.detections, self.elapsed)
+    }
+
+    /// Opportunities found per second of detection.
+    #[must_use]
+    pub fn opportunities_per_sec(&self) -> f64 {
+        per_sec(self.opportunities, self.elapsed)
+    }
+}
+
+/// Run every configured strategy over `markets` synthetic markets.
+///
+/// Each of the `iterations` passes times detection on every market
+/// separately. Books are generated once from `seed`, so runs with the same
+/// arguments evaluate identical markets.
+pub fn bench_detection(
+    config: &Config,
+    markets: usize,
+    iterations: usize,
+    seed: u64,
+) -> DetectionBench {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let registry = Arc::new(synthetic_markets(markets, &mut rng));
+    let cache = BookCache::new();
+    for market in registry.markets() {
+        for book in synthetic_books(market, &mut rng) {
+            cache.update(book);
+        }
+    }
+
+    let mut strategies = build_strategy_registry(config, build_cluster_cache(config));
+    strategies.set_registry(Arc::clone(&registry));
+
+    let mut latencies = Vec::with_capacity(registry.len() * iterations);
+    let mut opportunities = 0;
+    let started = Instant::now();
+    for _ in 0..iterations {
+        for market in registry.markets() {
+            let detection = Instant::now();
+            opportunities += detect_market_opportunities(market, &cache, &strategies).len();
+            latencies.push(detection.elapsed());
+        }
+    }
+    let elapsed = started.elapsed();
+    latencies.sort_unstable();
+
+    DetectionBench {
+        markets: registry.len(),
+        tokens: registry.all_token_ids().count(),
+        strategies: strategies
+            .strategy_names()
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+        iterations,
+        detections: latencies.len(),
+        opportunities,
+        elapsed,
+        latency: LatencyPercentiles {
+            p50: percentile(&latencies, 0.50),
+            p95: percentile(&latencies, 0.95),
+            p99: percentile(&latencies, 0.99),
+            samples: latencies.len(),
+        },
+    }
+}
+
+/// Generate `count` markets, two thirds binary and the rest with 3-6 outcomes.
+fn synthetic_markets(count: usize, rng: &mut StdRng) -> MarketRegistry {
+    let outcome_counts: Vec<usize> = (0..count)
+        .map(|i| if i % 3 == 2 { rng.gen_range(3..=6) } else { 2 })
+        .collect();
+    let mut tokens = make_tokens(outcome_counts.iter().sum()).into_iter();
+
+    let mut registry = MarketRegistry::new();
+    for (i, outcomes) in outcome_counts.into_iter().enumerate() {
+        let outcomes = tokens
+            .by_ref()
+            .take(outcomes)
+            .enumerate()
+            .map(|(j, token)| Outcome::new(token, format!("Outcome {j}")))
+            .collect();
+        registry.add(Market::new(
+            market_id(&format!("bench-{i}")),
+            format!("Synthetic market {i}"),
+            outcomes,
+            Decimal::ONE,
+        ));
+    }
+    registry
+}
+
+/// Generate a book per outcome with asks summing near the payout.
+///
+/// Each market draws an overround between -4% and +6%, so some markets
+/// price below the payout and give strategies something to find.
+fn synthetic_books(market: &Market, rng: &mut StdRng) -> Vec<Book> {
+    let weights: Vec<f64> = market
+        .outcomes()
+        .iter()
+        .map(|_| rng.gen_range(0.5..1.5))
+        .collect();
+    let total: f64 = weights.iter().sum();
+    let overround: f64 = rng.gen_range(-0.04..0.06);
+
+    market
+        .outcomes()
+        .iter()
+        .zip(weights)
+        .map(|(outcome, weight)| {
+            let fair = weight / total * (1.0 + overround);
+            let ask = ((fair * 100.0).round() as u32).clamp(1, 99);
+            let asks = (0..BOOK_DEPTH)
+                .map(|level| level_at(rng, (ask + level).min(99)))
+                .collect();
+            let bids = (1..=BOOK_DEPTH)
+                .map(|level| level_at(rng, ask.saturating_sub(level).max(1)))
+                .collect();
+            Book::with_levels(outcome.token_id().clone(), bids, asks)
+        })
+        .collect()
+}
+
+fn level_at(rng: &mut StdRng, cents: u32) -> PriceLevel {
+    PriceLevel::new(
+        Decimal::new(i64::from(cents), 2),
+        Decimal::from(rng.gen_range(10_u32..500)),
+    )
+}
+
+/// Value at percentile `p` (0.0 to 1.0) of sorted `samples`.
+fn percentile(samples: &[Duration], p: f64) -> Duration {
+    if samples.is_empty() {
+        return Duration::ZERO;
+    }
+
+    let index = ((samples.len() as f64 - 1.0) * p).round() as usize;
+    samples[index.min(samples.len() - 1)]
+}
+
+fn per_sec(count: usize, elapsed: Duration) -> f64 {
+    let secs = elapsed.as_secs_f64();
+    if secs == 0.0 {
+        return 0.0;
+    }
+    count as f64 / secs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bench_times_every_market_each_iteration() {
+        let bench = bench_detection(&Config::default(), 30, 4, 7);
+
+        assert_eq!(bench.markets, 30);
+        assert_eq!(bench.iterations, 4);
+        assert_eq!(bench.detections, 120);
+        assert_eq!(bench.latency.samples, 120);
+        assert!(bench.latency.p50 <= bench.latency.p99);
+        assert_eq!(bench.strategies, vec!["single_condition".to_string()]);
+    }
+
+    #[test]
+    fn synthetic_markets_mix_binary_and_multi_outcome() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let registry = synthetic_markets(9, &mut rng);
+
+        assert_eq!(registry.len(), 9);
+        assert_eq!(registry.binary_markets().count(), 6);
+        assert_eq!(registry.multi_outcome_markets().count(), 3);
+        let tokens: std::collections::HashSet<_> = registry.all_token_ids().collect();
+        assert_eq!(tokens.len(), registry.all_token_ids().count());
+    }
+
+    #[test]
+    fn synthetic_books_quote_every_outcome() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let registry = synthetic_markets(3, &mut rng);
+
+        for market in registry.markets() {
+            let books = synthetic_books(market, &mut rng);
+            assert_eq!(books.len(), market.outcome_count());
+            for book in books {
+                let ask = book.best_ask().unwrap().price();
+                assert!(book.spread().unwrap() >= Decimal::ZERO);
+                assert!(ask > Decimal::ZERO && ask < Decimal::ONE);
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_finds_same_opportunities() {
+        let first = bench_detection(&Config::default(), 60, 1, 11);
+        let second = bench_detection(&Config::default(), 60, 1, 11);
+
+        assert_eq!(first.opportunities, second.opportunities);
+        assert_eq!(first.tokens, second.tokens);
+    }
+}
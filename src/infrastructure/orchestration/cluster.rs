@@ -22,14 +22,14 @@ pub(crate) fn setup_cluster_detection(
     if config.cluster_detection.enabled {
         let (cache, update_rx) =
             BookCache::with_notifications(config.cluster_detection.channel_capacity);
-        let cache = Arc::new(cache);
+        let cache = Arc::new(cache.with_max_depth(config.resources.max_book_depth));
 
         let service = ClusterDetectionService::new(
             config.cluster_detection.to_core_config(),
             Arc::clone(&cache),
             Arc::clone(&cluster_cache),
             Arc::clone(&registry),
-            build_projection_solver(),
+            build_projection_solver(&config.strategies.combinatorial.solver),
         );
         let (handle, mut opp_rx) = service.start(update_rx);
 
@@ -49,6 +49,7 @@ pub(crate) fn setup_cluster_detection(
         info!("Cluster detection service started");
         (cache, Some(handle))
     } else {
-        (Arc::new(BookCache::new()), None)
+        let cache = BookCache::new().with_max_depth(config.resources.max_book_depth);
+        (Arc::new(cache), None)
     }
 }
@@ -1,9 +1,15 @@
 //! Infrastructure-level event processing context.
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use crate::application::cache::book::BookCache;
-use crate::application::orchestration::handler::MarketEventHandlingContext;
+use crate::application::orchestration::concurrency::ExecutionSlots;
+use crate::application::orchestration::dedup::DedupTracker;
+use crate::application::orchestration::handler::{MarketEventHandlingContext, ShadowDetection};
+use crate::application::orchestration::priority::OpportunityPriority;
+use crate::application::orchestration::throttle::RateLimiter;
+use crate::application::orchestration::versions::BookVersionTracker;
 use crate::application::position::manager::PositionManager;
 use crate::application::risk::manager::RiskManager;
 use crate::application::state::AppState;
@@ -24,7 +30,15 @@ pub struct EventProcessingContext<'a> {
     pub state: &'a Arc<AppState>,
     pub stats: &'a Arc<dyn StatsRecorder>,
     pub position_manager: &'a Arc<PositionManager>,
+    pub dedup: &'a DedupTracker,
+    pub versions: &'a BookVersionTracker,
+    pub rate_limiter: &'a RateLimiter,
+    pub execution_slots: &'a ExecutionSlots,
+    pub verify_before_execute: bool,
+    pub priority: OpportunityPriority,
+    pub received_at: Instant,
     pub dry_run: bool,
+    pub shadow: Option<ShadowDetection<'a>>,
 }
 
 impl<'a> EventProcessingContext<'a> {
@@ -39,7 +53,15 @@ impl<'a> EventProcessingContext<'a> {
             state: self.state,
             stats: self.stats,
             position_manager: self.position_manager,
+            dedup: self.dedup,
+            versions: self.versions,
+            rate_limiter: self.rate_limiter,
+            execution_slots: self.execution_slots,
+            verify_before_execute: self.verify_before_execute,
+            priority: self.priority,
+            received_at: self.received_at,
             dry_run: self.dry_run,
+            shadow: self.shadow,
         }
     }
 }
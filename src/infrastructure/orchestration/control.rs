@@ -0,0 +1,434 @@
+//! Local JSON-RPC control server.
+//!
+//! Accepts WebSocket connections and answers JSON-RPC 2.0 requests with the
+//! commands the Telegram bot offers, executed by the same [`TelegramControl`].
+//! A request's method is the command name and its positional params are the
+//! command's arguments, so `{"method": "set_risk", "params": ["max_exposure",
+//! "500"]}` behaves like `/set_risk max_exposure 500`. The result is the text
+//! the bot would reply with.
+//!
+//! When `EDGELORD_CONTROL_TOKEN` is set, the WebSocket handshake must carry it
+//! as an `Authorization: Bearer` header or a `token` query parameter.
+
+use std::sync::Arc;
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio_tungstenite::accept_hdr_async;
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::tungstenite::http::{header, StatusCode};
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, info, warn};
+
+use crate::adapter::outbound::notifier::telegram::command::{parse_invocation, CommandParseError};
+use crate::adapter::outbound::notifier::telegram::control::{RuntimeStats, TelegramControl};
+use crate::application::state::AppState;
+use crate::error::Result;
+use crate::infrastructure::config::control::CONTROL_TOKEN_ENV;
+use crate::infrastructure::config::settings::Config;
+use crate::port::inbound::runtime::RuntimeState;
+use crate::port::outbound::stats::StatsRecorder;
+
+/// Invalid JSON was received.
+const PARSE_ERROR: i64 = -32700;
+
+/// The JSON sent is not a valid request object.
+const INVALID_REQUEST: i64 = -32600;
+
+/// The method is not a known command.
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// The params do not form valid arguments for the command.
+const INVALID_PARAMS: i64 = -32602;
+
+/// A JSON-RPC error code and message.
+type RpcError = (i64, String);
+
+/// Executes JSON-RPC requests against the runtime.
+pub(crate) struct ControlServer {
+    control: TelegramControl,
+    /// Token clients must present during the handshake, if any.
+    token: Option<String>,
+}
+
+impl ControlServer {
+    pub(crate) fn new(control: TelegramControl, token: Option<String>) -> Self {
+        Self { control, token }
+    }
+
+    /// Answer one JSON-RPC message.
+    ///
+    /// Returns `None` for notifications, which are executed without a reply.
+    pub(crate) fn respond(&self, text: &str) -> Option<String> {
+        let Ok(request) = serde_json::from_str::<Value>(text) else {
+            return Some(error_response(
+                Value::Null,
+                (PARSE_ERROR, "parse error".to_string()),
+            ));
+        };
+
+        let result = self.call(&request);
+        let id = match request.get("id") {
+            Some(id) => id.clone(),
+            None if request.get("method").is_some() => return None,
+            None => Value::Null,
+        };
+
+        let response = match result {
+            Ok(text) => json!({ "jsonrpc": "2.0", "result": text, "id": id }).to_string(),
+            Err(error) => error_response(id, error),
+        };
+        Some(response)
+    }
+
+    fn call(&self, request: &Value) -> std::result::Result<String, RpcError> {
+        if request.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+            return Err((INVALID_REQUEST, "expected a JSON-RPC 2.0 request".into()));
+        }
+        let method = request
+            .get("method")
+            .and_then(Value::as_str)
+            .ok_or_else(|| (INVALID_REQUEST, "missing method".to_string()))?;
+        let args = arguments(request.get("params"))?;
+
+        let command =
+            parse_invocation(method, args.iter().map(String::as_str)).map_err(|err| match err {
+                CommandParseError::UnknownCommand(_) => {
+                    (METHOD_NOT_FOUND, format!("unknown method `{method}`"))
+                }
+                other => (INVALID_PARAMS, other.to_string()),
+            })?;
+        Ok(self.control.execute(command))
+    }
+
+    /// Return true if the handshake carries the configured token.
+    fn authorized(&self, request: &Request) -> bool {
+        let Some(ref expected) = self.token else {
+            return true;
+        };
+        presented_token(request).is_some_and(|token| tokens_match(token, expected))
+    }
+}
+
+/// Convert positional params into command arguments.
+fn arguments(params: Option<&Value>) -> std::result::Result<Vec<String>, RpcError> {
+    let values = match params {
+        None | Some(Value::Null) => return Ok(Vec::new()),
+        Some(Value::Array(values)) => values,
+        Some(_) => return Err((INVALID_PARAMS, "params must be an array".into())),
+    };
+    values
+        .iter()
+        .map(|value| match value {
+            Value::String(s) => Ok(s.clone()),
+            Value::Number(n) => Ok(n.to_string()),
+            Value::Bool(true) => Ok("on".to_string()),
+            Value::Bool(false) => Ok("off".to_string()),
+            _ => Err((
+                INVALID_PARAMS,
+                "params must be strings, numbers, or booleans".into(),
+            )),
+        })
+        .collect()
+}
+
+fn error_response(id: Value, (code, message): RpcError) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "error": { "code": code, "message": message },
+        "id": id,
+    })
+    .to_string()
+}
+
+/// Extract the token from the `Authorization` header or `token` query parameter.
+fn presented_token(request: &Request) -> Option<&str> {
+    let bearer = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    bearer.or_else(|| {
+        request
+            .uri()
+            .query()?
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("token="))
+    })
+}
+
+/// Compare tokens without exiting early on the first mismatch.
+fn tokens_match(presented: &str, expected: &str) -> bool {
+    presented.len() == expected.len()
+        && presented
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn unauthorized() -> ErrorResponse {
+    let mut response = ErrorResponse::new(Some("unauthorized".to_string()));
+    *response.status_mut() = StatusCode::UNAUTHORIZED;
+    response
+}
+
+/// Start the control server if enabled in configuration.
+///
+/// `runtime_stats` should be the instance the runtime loop updates, so pool,
+/// market, and latency figures are reported.
+pub(crate) async fn start_control_api(
+    config: &Config,
+    state: Arc<AppState>,
+    stats: Arc<dyn StatsRecorder>,
+    runtime_stats: Arc<RuntimeStats>,
+    shutdown: watch::Receiver<bool>,
+) -> Result<()> {
+    if !config.control_api.enabled {
+        return Ok(());
+    }
+
+    let token = std::env::var(CONTROL_TOKEN_ENV)
+        .ok()
+        .filter(|token| !token.is_empty());
+    let bind_addr = &config.control_api.bind_addr;
+    let loopback = bind_addr
+        .parse::<std::net::SocketAddr>()
+        .is_ok_and(|addr| addr.ip().is_loopback());
+    if token.is_none() && !loopback {
+        warn!(addr = %bind_addr, "Control API exposed beyond localhost without {CONTROL_TOKEN_ENV}");
+    }
+
+    let runtime: Arc<dyn RuntimeState> = state;
+    let control = TelegramControl::with_config(
+        runtime,
+        stats,
+        runtime_stats,
+        config.telegram.position_display_limit,
+    );
+    let server = Arc::new(ControlServer::new(control, token));
+
+    let listener = TcpListener::bind(bind_addr).await?;
+    info!(addr = %bind_addr, "Control API listening");
+    tokio::spawn(serve(listener, server, shutdown));
+    Ok(())
+}
+
+async fn serve(
+    listener: TcpListener,
+    server: Arc<ControlServer>,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    loop {
+        tokio::select! {
+            result = shutdown.changed() => {
+                if result.is_err() || *shutdown.borrow() {
+                    break;
+                }
+            }
+            accepted = listener.accept() => {
+                match accepted {
+                    Ok((stream, peer)) => {
+                        let server = Arc::clone(&server);
+                        let shutdown = shutdown.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = handle_connection(stream, &server, shutdown).await {
+                                debug!(peer = %peer, error = %e, "Control connection failed");
+                            }
+                        });
+                    }
+                    Err(e) => warn!(error = %e, "Failed to accept control connection"),
+                }
+            }
+        }
+    }
+    debug!("Control API stopped");
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    server: &ControlServer,
+    mut shutdown: watch::Receiver<bool>,
+) -> Result<()> {
+    let mut ws = accept_hdr_async(stream, |request: &Request, response: Response| {
+        if server.authorized(request) {
+            Ok(response)
+        } else {
+            Err(unauthorized())
+        }
+    })
+    .await?;
+
+    loop {
+        tokio::select! {
+            result = shutdown.changed() => {
+                if result.is_err() || *shutdown.borrow() {
+                    break;
+                }
+            }
+            message = ws.next() => {
+                match message {
+                    Some(Ok(Message::Text(text))) => {
+                        if let Some(reply) = server.respond(&text) {
+                            ws.send(Message::Text(reply)).await?;
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => return Err(e.into()),
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::trading::TradingState;
+    use tokio_tungstenite::connect_async;
+    use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+
+    fn server(state: &Arc<AppState>, token: Option<&str>) -> ControlServer {
+        let runtime: Arc<dyn RuntimeState> = Arc::clone(state);
+        ControlServer::new(TelegramControl::new(runtime), token.map(str::to_string))
+    }
+
+    fn reply(server: &ControlServer, request: &str) -> Value {
+        serde_json::from_str(&server.respond(request).expect("reply")).unwrap()
+    }
+
+    #[test]
+    fn status_returns_the_bot_reply() {
+        let state = Arc::new(AppState::default());
+        let response = reply(
+            &server(&state, None),
+            r#"{"jsonrpc":"2.0","method":"status","id":1}"#,
+        );
+
+        assert_eq!(response["id"], 1);
+        assert!(response["result"].as_str().unwrap().contains("Status"));
+    }
+
+    #[test]
+    fn pause_and_resume_change_trading_state() {
+        let state = Arc::new(AppState::default());
+        let server = server(&state, None);
+
+        reply(&server, r#"{"jsonrpc":"2.0","method":"pause","id":1}"#);
+        assert!(matches!(state.trading_state(), TradingState::Halted { .. }));
+
+        reply(&server, r#"{"jsonrpc":"2.0","method":"resume","id":2}"#);
+        assert!(state.trading_state().is_active());
+    }
+
+    #[test]
+    fn params_are_passed_as_command_arguments() {
+        let state = Arc::new(AppState::default());
+        let response = reply(
+            &server(&state, None),
+            r#"{"jsonrpc":"2.0","method":"set_risk","params":["max_exposure",500],"id":"a"}"#,
+        );
+
+        assert_eq!(response["id"], "a");
+        assert!(response["result"]
+            .as_str()
+            .unwrap()
+            .contains("max_exposure"));
+        assert_eq!(
+            state.risk_limits().max_total_exposure,
+            rust_decimal::Decimal::from(500)
+        );
+    }
+
+    #[test]
+    fn errors_use_json_rpc_codes() {
+        let state = Arc::new(AppState::default());
+        let server = server(&state, None);
+
+        let cases = [
+            ("{not json", PARSE_ERROR),
+            (r#"{"method":"status","id":1}"#, INVALID_REQUEST),
+            (
+                r#"{"jsonrpc":"2.0","method":"launch","id":1}"#,
+                METHOD_NOT_FOUND,
+            ),
+            (
+                r#"{"jsonrpc":"2.0","method":"set_risk","params":["leverage","2"],"id":1}"#,
+                INVALID_PARAMS,
+            ),
+            (
+                r#"{"jsonrpc":"2.0","method":"recent","params":{"limit":5},"id":1}"#,
+                INVALID_PARAMS,
+            ),
+        ];
+        for (request, code) in cases {
+            assert_eq!(reply(&server, request)["error"]["code"], code, "{request}");
+        }
+    }
+
+    #[test]
+    fn notifications_are_executed_without_a_reply() {
+        let state = Arc::new(AppState::default());
+        let server = server(&state, None);
+
+        assert!(server
+            .respond(r#"{"jsonrpc":"2.0","method":"pause"}"#)
+            .is_none());
+        assert!(matches!(state.trading_state(), TradingState::Halted { .. }));
+    }
+
+    #[test]
+    fn token_is_read_from_header_or_query() {
+        let state = Arc::new(AppState::default());
+        let server = server(&state, Some("secret"));
+
+        let mut request = "ws://127.0.0.1/".into_client_request().unwrap();
+        assert!(!server.authorized(&request));
+        request
+            .headers_mut()
+            .insert(header::AUTHORIZATION, "Bearer secret".parse().unwrap());
+        assert!(server.authorized(&request));
+
+        let request = "ws://127.0.0.1/?token=secret"
+            .into_client_request()
+            .unwrap();
+        assert!(server.authorized(&request));
+        let request = "ws://127.0.0.1/?token=wrong".into_client_request().unwrap();
+        assert!(!server.authorized(&request));
+    }
+
+    #[tokio::test]
+    async fn serves_requests_over_websocket() {
+        let state = Arc::new(AppState::default());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+        tokio::spawn(serve(
+            listener,
+            Arc::new(server(&state, Some("secret"))),
+            shutdown_rx,
+        ));
+
+        assert!(connect_async(format!("ws://{addr}/")).await.is_err());
+
+        let (mut ws, _) = connect_async(format!("ws://{addr}/?token=secret"))
+            .await
+            .unwrap();
+        ws.send(Message::Text(
+            r#"{"jsonrpc":"2.0","method":"pause","id":7}"#.to_string(),
+        ))
+        .await
+        .unwrap();
+        let Some(Ok(Message::Text(text))) = ws.next().await else {
+            panic!("expected a text reply");
+        };
+
+        let response: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(response["id"], 7);
+        assert!(matches!(state.trading_state(), TradingState::Halted { .. }));
+    }
+}
@@ -0,0 +1,320 @@
+//! Periodic market re-scan.
+//!
+//! Markets are fetched once at startup. With
+//! `market_discovery.rescan_interval_secs` set, the exchange is fetched again
+//! on that schedule and the qualifying markets are diffed against the live
+//! [`MarketRegistry`]. New markets are added and markets that closed or no
+//! longer pass the filter are removed. The resulting token set is forwarded
+//! to the runtime loop, which resubscribes the market stream.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::RwLock;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use super::startup;
+use crate::domain::id::{MarketId, TokenId};
+use crate::domain::market::{Market, MarketRegistry};
+use crate::error::Result;
+use crate::infrastructure::config::settings::Config;
+use crate::infrastructure::exchange::factory::ExchangeFactory;
+use crate::infrastructure::subscription::manager::SubscriptionManager;
+use crate::infrastructure::subscription::priority::PrioritySubscriptionManager;
+use crate::infrastructure::subscription::scorer::WeightedMarketScorer;
+use crate::port::outbound::exchange::{MarketFetcher, MarketInfo, MarketParser};
+use crate::port::outbound::filter::{MarketFilter, MarketScorer};
+
+/// Token sets buffered between the re-scan task and the runtime loop.
+const UPDATE_BUFFER: usize = 4;
+
+/// Running re-scan task and the token sets it emits.
+///
+/// Each update is the full set of tokens to subscribe to after a scan that
+/// changed the registry.
+pub(crate) struct MarketRescan {
+    pub updates: mpsc::Receiver<Vec<TokenId>>,
+    pub handle: JoinHandle<()>,
+}
+
+/// Exchange adapters used to fetch and qualify markets on each scan.
+struct MarketSource {
+    fetcher: Box<dyn MarketFetcher>,
+    filter: Box<dyn MarketFilter>,
+    parser: Box<dyn MarketParser>,
+    max_markets: usize,
+}
+
+impl MarketSource {
+    /// Fetch, filter, and parse the currently qualifying markets.
+    async fn fetch(&self) -> Result<(Vec<MarketInfo>, Vec<Market>)> {
+        let market_infos = self.fetcher.get_markets(self.max_markets).await?;
+        let market_infos = self.filter.filter(&market_infos);
+        let markets = self.parser.parse_markets(&market_infos);
+        Ok((market_infos, markets))
+    }
+}
+
+/// Start re-scanning the exchange for new and closed markets.
+///
+/// Newly found markets are registered with `scaling` when adaptive scaling
+/// is enabled, so the governor decides when they become active.
+///
+/// Returns `None` when re-scanning is disabled or when replaying recorded
+/// data.
+///
+/// # Errors
+///
+/// Returns an error if the exchange market filter cannot be built.
+pub(crate) fn start_market_rescan(
+    config: &Config,
+    registry: Arc<RwLock<MarketRegistry>>,
+    scaling: Option<Arc<PrioritySubscriptionManager>>,
+) -> Result<Option<MarketRescan>> {
+    if !config.market_discovery.is_enabled() || config.replay.is_some() {
+        return Ok(None);
+    }
+
+    let source = MarketSource {
+        fetcher: ExchangeFactory::create_market_fetcher(config),
+        filter: ExchangeFactory::create_filter(config)?,
+        parser: ExchangeFactory::create_market_parser(config),
+        max_markets: startup::max_markets(config),
+    };
+    let scorer = WeightedMarketScorer::new(&config.scoring, config.exchange.name());
+    let rescan_interval = Duration::from_secs(config.market_discovery.rescan_interval_secs);
+    let (tx, updates) = mpsc::channel(UPDATE_BUFFER);
+
+    let handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(rescan_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        // The first tick fires immediately; markets were just fetched.
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            let (market_infos, markets) = match source.fetch().await {
+                Ok(fetched) => fetched,
+                Err(error) => {
+                    warn!(
+                        exchange = source.fetcher.exchange_name(),
+                        error = %error,
+                        "Market re-scan failed"
+                    );
+                    continue;
+                }
+            };
+            if markets.is_empty() {
+                // An empty result is far more likely an API hiccup than every
+                // market closing at once.
+                warn!("Market re-scan found no markets, keeping current set");
+                continue;
+            }
+
+            let diff = MarketDiff::between(&registry.read(), &markets);
+            info!(
+                added = diff.added.len(),
+                removed = diff.removed.len(),
+                "Market re-scan complete"
+            );
+            if diff.is_empty() {
+                continue;
+            }
+
+            if let Some(ref manager) = scaling {
+                enqueue_added(manager, &scorer, &market_infos, &diff.added).await;
+            }
+
+            let token_ids = {
+                let mut registry = registry.write();
+                diff.apply(&mut registry);
+                subscribed_tokens(&registry)
+            };
+            if tx.send(token_ids).await.is_err() {
+                return;
+            }
+        }
+    });
+
+    info!(
+        interval_secs = rescan_interval.as_secs(),
+        "Market re-scan started"
+    );
+    Ok(Some(MarketRescan { updates, handle }))
+}
+
+/// Register added markets with the scaler, ranked by their weighted score.
+async fn enqueue_added(
+    manager: &PrioritySubscriptionManager,
+    scorer: &WeightedMarketScorer,
+    market_infos: &[MarketInfo],
+    added: &[Market],
+) {
+    let added_ids: HashSet<&MarketId> = added.iter().map(Market::market_id).collect();
+    let scores = match scorer.score_batch(market_infos).await {
+        Ok(scores) => scores,
+        Err(error) => {
+            warn!(error = %error, "Scoring re-scanned markets failed");
+            return;
+        }
+    };
+
+    for market in added {
+        manager.register_market_tokens(
+            market.market_id().clone(),
+            market.token_ids().into_iter().cloned().collect(),
+        );
+    }
+    manager.enqueue(
+        scores
+            .into_iter()
+            .filter(|score| added_ids.contains(score.market_id()))
+            .collect(),
+    );
+}
+
+/// Every outcome token of every registered market.
+fn subscribed_tokens(registry: &MarketRegistry) -> Vec<TokenId> {
+    registry
+        .markets()
+        .iter()
+        .flat_map(|m| m.outcomes().iter().map(|o| o.token_id().clone()))
+        .collect()
+}
+
+/// Changes between the registered markets and a fresh scan.
+#[derive(Debug, Default)]
+struct MarketDiff {
+    /// Scanned markets that are not registered yet.
+    added: Vec<Market>,
+    /// Registered markets missing from the scan.
+    removed: Vec<MarketId>,
+}
+
+impl MarketDiff {
+    /// Diff `registry` against the markets that currently qualify.
+    fn between(registry: &MarketRegistry, scanned: &[Market]) -> Self {
+        let scanned_ids: HashSet<&MarketId> = scanned.iter().map(Market::market_id).collect();
+        let removed = registry
+            .markets()
+            .iter()
+            .map(Market::market_id)
+            .filter(|id| !scanned_ids.contains(id))
+            .cloned()
+            .collect();
+        let added = scanned
+            .iter()
+            .filter(|market| registry.get_by_market_id(market.market_id()).is_none())
+            .cloned()
+            .collect();
+        Self { added, removed }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// Apply the diff, skipping markets already added or removed.
+    fn apply(self, registry: &mut MarketRegistry) {
+        for market_id in &self.removed {
+            if registry.remove(market_id).is_some() {
+                debug!(market_id = %market_id, "Dropping market");
+            }
+        }
+        for market in self.added {
+            if registry.get_by_market_id(market.market_id()).is_none() {
+                debug!(
+                    market_id = %market.market_id(),
+                    question = %market.question(),
+                    "Tracking market"
+                );
+                registry.add(market);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::market::Outcome;
+    use rust_decimal_macros::dec;
+
+    fn market(id: &str) -> Market {
+        Market::new(
+            MarketId::from(id),
+            "Test?",
+            vec![
+                Outcome::new(TokenId::from(format!("{id}-yes")), "Yes"),
+                Outcome::new(TokenId::from(format!("{id}-no")), "No"),
+            ],
+            dec!(1.00),
+        )
+    }
+
+    fn registry(ids: &[&str]) -> MarketRegistry {
+        let mut registry = MarketRegistry::new();
+        for id in ids {
+            registry.add(market(id));
+        }
+        registry
+    }
+
+    #[test]
+    fn diff_finds_added_and_removed_markets() {
+        let registry = registry(&["kept", "closed"]);
+
+        let diff = MarketDiff::between(&registry, &[market("kept"), market("new")]);
+
+        let added: Vec<_> = diff.added.iter().map(|m| m.market_id().as_str()).collect();
+        assert_eq!(added, ["new"]);
+        assert_eq!(diff.removed, [MarketId::from("closed")]);
+    }
+
+    #[test]
+    fn diff_of_unchanged_markets_is_empty() {
+        let registry = registry(&["a", "b"]);
+        let diff = MarketDiff::between(&registry, &[market("b"), market("a")]);
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn apply_updates_registry_and_tokens() {
+        let mut registry = registry(&["kept", "closed"]);
+        let diff = MarketDiff::between(&registry, &[market("kept"), market("new")]);
+
+        diff.apply(&mut registry);
+
+        assert_eq!(registry.len(), 2);
+        assert!(registry
+            .get_by_market_id(&MarketId::from("closed"))
+            .is_none());
+        assert!(registry.get_by_token(&TokenId::from("new-yes")).is_some());
+        let tokens = subscribed_tokens(&registry);
+        assert_eq!(tokens.len(), 4);
+        assert!(!tokens.contains(&TokenId::from("closed-no")));
+    }
+
+    #[test]
+    fn apply_is_idempotent() {
+        let mut registry = registry(&["kept"]);
+        let scanned = vec![market("kept"), market("new")];
+
+        MarketDiff::between(&registry, &scanned).apply(&mut registry);
+        MarketDiff::between(&MarketRegistry::new(), &scanned).apply(&mut registry);
+
+        assert_eq!(registry.len(), 2);
+    }
+
+    #[test]
+    fn rescan_is_disabled_by_default() {
+        let config = Config::default();
+        let registry = Arc::new(RwLock::new(MarketRegistry::new()));
+        assert!(start_market_rescan(&config, registry, None)
+            .unwrap()
+            .is_none());
+    }
+}
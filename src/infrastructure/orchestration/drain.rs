@@ -0,0 +1,66 @@
+//! Position drain on shutdown.
+//!
+//! With `execution.drain_on_shutdown` set, open positions are exited at
+//! market after the runtime loop stops, so a restart does not leave
+//! unhedged exposure behind.
+
+use std::time::Duration;
+
+use tracing::{info, warn};
+
+use crate::application::cache::book::BookCache;
+use crate::application::position::manager::PositionManager;
+use crate::application::state::AppState;
+use crate::infrastructure::config::settings::Config;
+use crate::port::outbound::exchange::ArbitrageExecutor;
+use crate::port::outbound::notifier::{DrainEvent, Event, NotifierRegistry};
+
+/// Exit open positions and report the outcome.
+pub(crate) async fn drain_positions(
+    config: &Config,
+    state: &AppState,
+    position_manager: &PositionManager,
+    executor: &dyn ArbitrageExecutor,
+    cache: &BookCache,
+    notifiers: &NotifierRegistry,
+) {
+    let open = state.positions().open_count();
+    if open == 0 {
+        info!("No open positions to drain");
+        return;
+    }
+
+    let timeout = Duration::from_secs(config.execution.drain_timeout_secs);
+    info!(
+        positions = open,
+        timeout_secs = timeout.as_secs(),
+        "Draining open positions before shutdown"
+    );
+    let report = position_manager
+        .drain(
+            state,
+            executor,
+            cache,
+            config.execution.unwind_discount,
+            timeout,
+        )
+        .await;
+
+    if report.failed > 0 || report.timed_out {
+        warn!(
+            closed = report.closed,
+            failed = report.failed,
+            timed_out = report.timed_out,
+            remaining_exposure = %report.remaining_exposure,
+            "Drain left positions open"
+        );
+    }
+    notifiers.notify_all(Event::DrainCompleted(DrainEvent {
+        positions: report.positions,
+        closed: report.closed,
+        failed: report.failed,
+        timed_out: report.timed_out,
+        realized_pnl: report.realized_pnl,
+        remaining_exposure: report.remaining_exposure,
+    }));
+}
@@ -0,0 +1,196 @@
+//! Gas balance monitoring for the trading wallet.
+//!
+//! Periodically fetches the wallet's native gas balance into the
+//! [`GasGuard`] the risk manager checks before each execution. Notifiers
+//! hear once when the balance drops below the minimum and again when it
+//! recovers, rather than on every rejected execution.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::application::risk::gas::{GasGuard, GasTransition};
+use crate::infrastructure::config::settings::Config;
+use crate::infrastructure::exchange::factory::ExchangeFactory;
+use crate::port::outbound::exchange::GasBalanceFetcher;
+use crate::port::outbound::notifier::{Event, NotifierRegistry};
+
+/// Gas guard paired with the fetcher that keeps it current.
+pub(crate) struct GasMonitor {
+    guard: Arc<GasGuard>,
+    fetcher: Box<dyn GasBalanceFetcher>,
+}
+
+impl GasMonitor {
+    /// Guard to attach to the risk manager.
+    pub(crate) fn guard(&self) -> Arc<GasGuard> {
+        Arc::clone(&self.guard)
+    }
+}
+
+/// Build the gas monitor for the configured wallet.
+///
+/// Returns `None` when `min_gas_balance` is 0, in dry-run mode (which
+/// includes replay), or when the exchange has no gas balance API.
+pub(crate) fn build_gas_monitor(config: &Config) -> Option<GasMonitor> {
+    if config.risk.min_gas_balance.is_zero() || config.dry_run {
+        return None;
+    }
+    let Some(fetcher) = ExchangeFactory::create_gas_balance_fetcher(config) else {
+        debug!("Gas balance unavailable, skipping minimum gas check");
+        return None;
+    };
+    Some(GasMonitor {
+        guard: Arc::new(GasGuard::new(config.risk.min_gas_balance)),
+        fetcher,
+    })
+}
+
+/// Fetch the balance once, then keep refreshing it every `gas_refresh_secs`.
+///
+/// The first fetch completes before this returns so trading starts with a
+/// known balance.
+pub(crate) async fn start_gas_refresh(
+    config: &Config,
+    monitor: GasMonitor,
+    notifiers: Arc<NotifierRegistry>,
+) -> JoinHandle<()> {
+    let refresh_interval = Duration::from_secs(config.risk.gas_refresh_secs);
+    refresh_gas_balance(monitor.fetcher.as_ref(), &monitor.guard, &notifiers).await;
+
+    let handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(refresh_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        // The first tick fires immediately; the balance was just fetched.
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            refresh_gas_balance(monitor.fetcher.as_ref(), &monitor.guard, &notifiers).await;
+        }
+    });
+
+    info!(
+        min_balance = %config.risk.min_gas_balance,
+        interval_secs = refresh_interval.as_secs(),
+        "Gas balance monitoring started"
+    );
+    handle
+}
+
+/// Fetch the balance into the guard, alerting when sufficiency changes.
+///
+/// Fetch failures are logged and leave the last known balance in place.
+async fn refresh_gas_balance(
+    fetcher: &dyn GasBalanceFetcher,
+    guard: &GasGuard,
+    notifiers: &NotifierRegistry,
+) {
+    let token = fetcher.gas_token();
+    let balance = match fetcher.get_gas_balance().await {
+        Ok(balance) => balance,
+        Err(error) => {
+            warn!(error = %error, "Gas balance refresh failed");
+            return;
+        }
+    };
+
+    match guard.update(balance) {
+        GasTransition::Depleted => {
+            let reason = format!(
+                "Gas balance {balance} {token} is below the {} {token} minimum; \
+                 executions are rejected until the wallet is topped up",
+                guard.min_balance()
+            );
+            warn!(balance = %balance, min = %guard.min_balance(), "Gas balance too low");
+            notifiers.notify_all(Event::CircuitBreakerActivated { reason });
+        }
+        GasTransition::Restored => {
+            info!(balance = %balance, "Gas balance restored, executions resume");
+            notifiers.notify_all(Event::CircuitBreakerReset);
+        }
+        GasTransition::Unchanged => {
+            debug!(balance = %balance, token, "Gas balance refreshed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::error::Error;
+    use crate::testkit::notifier::RecordingNotifier;
+
+    /// Fetcher returning queued balances, or an error once they run out.
+    struct QueuedFetcher(Mutex<Vec<Decimal>>);
+
+    #[async_trait]
+    impl GasBalanceFetcher for QueuedFetcher {
+        async fn get_gas_balance(&self) -> Result<Decimal, Error> {
+            self.0
+                .lock()
+                .pop()
+                .ok_or_else(|| Error::Connection("rpc unreachable".to_string()))
+        }
+
+        fn gas_token(&self) -> &'static str {
+            "POL"
+        }
+    }
+
+    #[tokio::test]
+    async fn refresh_alerts_once_per_outage() {
+        // Popped from the back: fine, low, still low, restored.
+        let fetcher = QueuedFetcher(Mutex::new(vec![dec!(1), dec!(0.02), dec!(0.01), dec!(0.5)]));
+        let guard = GasGuard::new(dec!(0.1));
+        let (notifier, notifiers) = RecordingNotifier::registry();
+
+        refresh_gas_balance(&fetcher, &guard, &notifiers).await;
+        refresh_gas_balance(&fetcher, &guard, &notifiers).await;
+        refresh_gas_balance(&fetcher, &guard, &notifiers).await;
+        {
+            let events = notifier.events();
+            let [Event::CircuitBreakerActivated { reason }] = events.as_slice() else {
+                panic!("expected one circuit breaker activation, got {events:?}");
+            };
+            assert!(reason.contains("0.01 POL"), "unexpected reason: {reason}");
+        }
+        assert!(guard.check().is_err());
+
+        refresh_gas_balance(&fetcher, &guard, &notifiers).await;
+        assert_eq!(
+            notifier.count(|event| matches!(event, Event::CircuitBreakerReset)),
+            1
+        );
+        assert!(guard.check().is_ok());
+    }
+
+    #[tokio::test]
+    async fn failed_refresh_keeps_last_balance() {
+        let fetcher = QueuedFetcher(Mutex::new(vec![dec!(0.01)]));
+        let guard = GasGuard::new(dec!(0.1));
+        let notifiers = NotifierRegistry::new();
+
+        refresh_gas_balance(&fetcher, &guard, &notifiers).await;
+        refresh_gas_balance(&fetcher, &guard, &notifiers).await;
+
+        assert_eq!(guard.balance(), Some(dec!(0.01)));
+    }
+
+    #[test]
+    fn monitor_is_disabled_by_default_and_in_dry_run() {
+        let mut config = Config::default();
+        assert!(build_gas_monitor(&config).is_none());
+
+        config.risk.min_gas_balance = dec!(0.1);
+        config.dry_run = true;
+        assert!(build_gas_monitor(&config).is_none());
+    }
+}
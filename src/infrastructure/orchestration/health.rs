@@ -95,6 +95,21 @@ pub fn health_check(config: &Config) -> HealthReport {
         },
     });
 
+    // Inference is optional: without an LLM only the combinatorial strategy
+    // loses its clusters, so this check never fails the report.
+    if config.inference.enabled {
+        let key_var = config.llm.provider.api_key_var();
+        checks.push(HealthCheck {
+            name: "llm",
+            critical: false,
+            status: if std::env::var(key_var).is_ok() {
+                HealthStatus::Healthy
+            } else {
+                HealthStatus::Unhealthy(format!("{key_var} not set; inference disabled"))
+            },
+        });
+    }
+
     HealthReport { checks }
 }
 
@@ -203,6 +218,19 @@ mod tests {
         assert!(check_names.contains(&"strategies"));
     }
 
+    #[test]
+    fn health_check_reports_llm_only_when_inference_enabled() {
+        let mut config = Config::default();
+        config.inference.enabled = true;
+        let report = health_check(&config);
+        let llm = report.checks().iter().find(|c| c.name() == "llm").unwrap();
+        assert!(!llm.critical());
+
+        config.inference.enabled = false;
+        let report = health_check(&config);
+        assert!(report.checks().iter().all(|c| c.name() != "llm"));
+    }
+
     #[test]
     fn health_check_detects_empty_database_path() {
         let config = Config {
@@ -227,6 +255,10 @@ mod tests {
             ExchangeSpecificConfig::Polymarket(pm) => {
                 pm.api_url = String::new();
             }
+            #[cfg(feature = "kalshi")]
+            ExchangeSpecificConfig::Kalshi(_) => unreachable!("default config is polymarket"),
+            #[cfg(feature = "manifold")]
+            ExchangeSpecificConfig::Manifold(_) => unreachable!("default config is polymarket"),
         }
 
         let report = health_check(&config);
@@ -246,6 +278,10 @@ mod tests {
             ExchangeSpecificConfig::Polymarket(pm) => {
                 pm.ws_url = String::new();
             }
+            #[cfg(feature = "kalshi")]
+            ExchangeSpecificConfig::Kalshi(_) => unreachable!("default config is polymarket"),
+            #[cfg(feature = "manifold")]
+            ExchangeSpecificConfig::Manifold(_) => unreachable!("default config is polymarket"),
         }
 
         let report = health_check(&config);
@@ -1,48 +1,141 @@
 //! Inference startup and background wiring.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
-use tracing::info;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
 
 use crate::application::cache::cluster::ClusterCache;
 use crate::application::inference::service::{
-    run_full_inference, InferenceService, InferenceServiceHandle,
+    run_full_inference, InferenceBudget, InferenceResult, InferenceService, InferenceServiceHandle,
 };
 use crate::infrastructure::config::settings::Config;
 use crate::port::outbound::inference::{MarketSummary, RelationInferrer};
 use crate::port::outbound::notifier::{Event, NotifierRegistry, RelationDetail, RelationsEvent};
+use crate::port::outbound::store::{ClusterStore, RelationStore};
+
+/// Tracks whether the LLM answered the latest inference run.
+///
+/// An unreachable LLM only costs the combinatorial strategy its clusters, so
+/// it is reported once per outage instead of on every failed run, and the
+/// periodic schedule keeps retrying.
+#[derive(Debug, Default)]
+pub(crate) struct InferenceAvailability {
+    degraded: AtomicBool,
+}
+
+impl InferenceAvailability {
+    /// Record the outcome of an inference run.
+    ///
+    /// Warns and notifies when inference first becomes unavailable, and logs
+    /// once it recovers.
+    pub(crate) fn observe(&self, result: &InferenceResult, notifiers: &NotifierRegistry) {
+        if result.is_unavailable() {
+            if !self.degraded.swap(true, Ordering::Relaxed) {
+                let reason = result
+                    .last_error
+                    .clone()
+                    .unwrap_or_else(|| "every inference batch failed".to_string());
+                warn!(
+                    reason = %reason,
+                    "LLM unreachable, inference degraded; other strategies keep running"
+                );
+                notifiers.notify_all(Event::InferenceDegraded { reason });
+            }
+        } else if result.batches_run > 0 && self.degraded.swap(false, Ordering::Relaxed) {
+            info!("LLM reachable again, inference restored");
+        }
+    }
+}
+
+/// Load persisted clusters into the cache, skipping any past the cache TTL.
+pub(crate) async fn restore_clusters<S: ClusterStore>(
+    store: Option<&S>,
+    cluster_cache: &ClusterCache,
+) {
+    let Some(store) = store else {
+        return;
+    };
+
+    match store.list().await {
+        Ok(clusters) => {
+            let stored = clusters.len();
+            let restored = cluster_cache.restore(clusters);
+            info!(
+                restored,
+                stale = stored - restored,
+                "Restored persisted clusters"
+            );
+        }
+        Err(e) => warn!(error = %e, "Failed to load persisted clusters"),
+    }
+}
+
+/// Save every live cluster in the cache through the store.
+pub(crate) async fn persist_clusters<S: ClusterStore>(store: &S, cluster_cache: &ClusterCache) {
+    for cluster in cluster_cache.all_clusters() {
+        if let Err(e) = store.save(&cluster).await {
+            warn!(cluster = %cluster.id, error = %e, "Failed to persist cluster");
+        }
+    }
+}
 
 /// Run startup inference pass and notify on discovered relations.
-pub(crate) async fn run_startup_inference(
+///
+/// Markets already covered by a cached cluster (for example one restored from
+/// the database) are skipped; periodic inference still rescans every market.
+/// An unreachable LLM is reported through `availability` and never fails
+/// startup.
+pub(crate) async fn run_startup_inference<S: ClusterStore>(
     config: &Config,
     inferrer: Option<&Arc<dyn RelationInferrer>>,
     market_summaries: &[MarketSummary],
     cluster_cache: &ClusterCache,
+    cluster_store: Option<&S>,
     notifiers: &Arc<NotifierRegistry>,
+    availability: &InferenceAvailability,
 ) {
     let Some(inferrer) = inferrer else {
         return;
     };
 
+    let pending: Vec<MarketSummary> = market_summaries
+        .iter()
+        .filter(|summary| !cluster_cache.has_relations(&summary.id))
+        .cloned()
+        .collect();
+
     info!(
-        markets = market_summaries.len(),
+        markets = pending.len(),
+        cached = market_summaries.len() - pending.len(),
         batch_size = config.inference.batch_size,
         "Running full startup inference"
     );
     let result = run_full_inference(
         inferrer.as_ref(),
-        market_summaries,
+        &pending,
         config.inference.batch_size,
+        InferenceBudget::from_config(&config.inference),
         cluster_cache,
     )
     .await;
 
+    if let Some(store) = cluster_store {
+        persist_clusters(store, cluster_cache).await;
+    }
+
     info!(
         markets = result.markets_processed,
         relations = result.relations_discovered,
         batches = result.batches_run,
+        skipped = result.batches_skipped,
+        tokens = result.tokens_used,
+        failed = result.batches_failed,
         "Startup inference complete"
     );
+    availability.observe(&result, notifiers);
 
     if result.relations.is_empty() {
         return;
@@ -80,11 +173,17 @@ pub(crate) async fn run_startup_inference(
 }
 
 /// Start periodic inference service and attach logging task.
-pub(crate) fn start_continuous_inference(
+///
+/// Each periodic run also retries an LLM that was unreachable, reporting
+/// outages and recoveries through `availability`.
+pub(crate) fn start_continuous_inference<S: ClusterStore + 'static>(
     config: &Config,
     inferrer: Option<Arc<dyn RelationInferrer>>,
     cluster_cache: Arc<ClusterCache>,
+    cluster_store: Option<Arc<S>>,
     market_summaries: Arc<Vec<MarketSummary>>,
+    notifiers: Arc<NotifierRegistry>,
+    availability: Arc<InferenceAvailability>,
 ) -> Option<InferenceServiceHandle> {
     if !config.inference.enabled {
         return None;
@@ -105,8 +204,15 @@ pub(crate) fn start_continuous_inference(
                 markets = result.markets_processed,
                 relations = result.relations_discovered,
                 batches = result.batches_run,
+                skipped = result.batches_skipped,
+                tokens = result.tokens_used,
+                failed = result.batches_failed,
                 "Periodic inference complete"
             );
+            availability.observe(&result, &notifiers);
+            if let Some(ref store) = cluster_store {
+                persist_clusters(store.as_ref(), &cluster_cache).await;
+            }
         }
     });
 
@@ -117,3 +223,149 @@ pub(crate) fn start_continuous_inference(
 
     Some(handle)
 }
+
+/// Drop expired relations from the cache and the store.
+///
+/// Clusters left without live relations are removed from the cache.
+pub(crate) async fn prune_relations<S: RelationStore>(
+    relation_store: Option<&S>,
+    cluster_cache: &ClusterCache,
+) {
+    let counts = cluster_cache.prune_relations();
+    let stored = match relation_store {
+        Some(store) => match store.prune_expired().await {
+            Ok(pruned) => pruned,
+            Err(e) => {
+                warn!(error = %e, "Failed to prune stored relations");
+                0
+            }
+        },
+        None => 0,
+    };
+
+    if counts.relations + counts.clusters + stored > 0 {
+        info!(
+            relations = counts.relations,
+            clusters = counts.clusters,
+            stored,
+            "Pruned expired relations"
+        );
+    } else {
+        debug!("No expired relations to prune");
+    }
+}
+
+/// Start the periodic expired-relation pruning task.
+///
+/// Returns `None` when inference is disabled or `prune_interval_secs` is 0.
+pub(crate) fn start_relation_pruning<S: RelationStore + 'static>(
+    config: &Config,
+    cluster_cache: Arc<ClusterCache>,
+    relation_store: Option<Arc<S>>,
+) -> Option<JoinHandle<()>> {
+    if !config.inference.enabled || config.inference.prune_interval_secs == 0 {
+        return None;
+    }
+    let prune_interval = Duration::from_secs(config.inference.prune_interval_secs);
+
+    let handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(prune_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        // The first tick fires immediately; restored clusters were filtered by TTL.
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            prune_relations(relation_store.as_deref(), &cluster_cache).await;
+        }
+    });
+
+    info!(
+        interval_secs = prune_interval.as_secs(),
+        "Relation pruning started"
+    );
+    Some(handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::outbound::memory::MemoryStore;
+    use crate::domain::id::MarketId;
+    use crate::domain::relation::{Relation, RelationKind};
+    use crate::testkit::notifier::RecordingNotifier;
+    use chrono::Duration as ChronoDuration;
+
+    fn degraded_count(notifier: &RecordingNotifier) -> usize {
+        notifier.count(|event| matches!(event, Event::InferenceDegraded { .. }))
+    }
+
+    fn inference_result(batches_run: usize, batches_failed: usize) -> InferenceResult {
+        InferenceResult {
+            markets_processed: 10,
+            relations_discovered: 0,
+            batches_run,
+            batches_skipped: 0,
+            batches_failed,
+            last_error: (batches_failed > 0).then(|| "connection refused".to_string()),
+            tokens_used: 0,
+            relations: vec![],
+        }
+    }
+
+    #[test]
+    fn availability_notifies_once_per_outage() {
+        let (notifier, notifiers) = RecordingNotifier::registry();
+        let availability = InferenceAvailability::default();
+
+        availability.observe(&inference_result(0, 3), &notifiers);
+        availability.observe(&inference_result(0, 3), &notifiers);
+        assert_eq!(degraded_count(&notifier), 1);
+
+        availability.observe(&inference_result(2, 0), &notifiers);
+        availability.observe(&inference_result(0, 3), &notifiers);
+        assert_eq!(degraded_count(&notifier), 2);
+    }
+
+    #[test]
+    fn availability_ignores_runs_with_some_successes() {
+        let (notifier, notifiers) = RecordingNotifier::registry();
+        let availability = InferenceAvailability::default();
+
+        availability.observe(&inference_result(1, 2), &notifiers);
+        availability.observe(&inference_result(0, 0), &notifiers);
+
+        assert_eq!(degraded_count(&notifier), 0);
+    }
+
+    #[tokio::test]
+    async fn prune_relations_removes_expired_relation_from_cache_and_store() {
+        let relation = Relation::new(
+            RelationKind::MutuallyExclusive {
+                markets: vec![MarketId::new("m1"), MarketId::new("m2")],
+            },
+            0.9,
+            "test",
+        )
+        .with_ttl(ChronoDuration::milliseconds(10));
+        let store = MemoryStore::new();
+        RelationStore::save(&store, &relation).await.unwrap();
+        let cache = ClusterCache::new(ChronoDuration::hours(1));
+        cache.put_relations(vec![relation]);
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        prune_relations(Some(&store), &cache).await;
+
+        assert!(!cache.has_relations(&MarketId::new("m1")));
+        assert!(RelationStore::list(&store, true).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn pruning_disabled_when_interval_is_zero() {
+        let mut config = Config::default();
+        config.inference.prune_interval_secs = 0;
+        let cache = Arc::new(ClusterCache::new(ChronoDuration::hours(1)));
+
+        assert!(start_relation_pruning::<MemoryStore>(&config, cache, None).is_none());
+    }
+}
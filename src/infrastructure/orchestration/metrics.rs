@@ -0,0 +1,442 @@
+//! Prometheus metrics endpoint.
+//!
+//! Serves `GET /metrics` in the Prometheus text exposition format. Values are
+//! read on every scrape from the shared [`AppState`], the [`StatsRecorder`],
+//! and the [`AdaptiveGovernor`]; stream-level counts the runtime loop observes
+//! are pushed into the registry as they change. `GET /events` returns the
+//! recent notification events retained in the [`AppState`] as JSON.
+//!
+//! The server is a minimal HTTP/1.1 responder over a tokio listener. Each
+//! connection serves one request and is then closed.
+
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tracing::{debug, info, warn};
+
+use crate::application::cluster::queue::ClusterQueueStats;
+use crate::application::state::AppState;
+use crate::error::Result;
+use crate::infrastructure::config::metrics::MetricsConfig;
+use crate::infrastructure::config::settings::Config;
+use crate::infrastructure::governor::runtime::AdaptiveGovernor;
+use crate::port::outbound::exchange::{MarketEvent, PoolStats};
+use crate::port::outbound::stats::StatsRecorder;
+
+/// Largest request head read before the request is rejected.
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// Time allowed for a client to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Content type of the Prometheus text exposition format.
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Content type of the recent events listing.
+const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Sources read when rendering a scrape.
+pub(crate) struct MetricsRegistry {
+    state: Arc<AppState>,
+    stats: Arc<dyn StatsRecorder>,
+    governor: Arc<dyn AdaptiveGovernor>,
+    /// Tokens subscribed on the market data stream.
+    active_subscriptions: AtomicUsize,
+    /// Disconnects reported by the market data stream.
+    disconnects: AtomicU64,
+    /// Connection restarts reported by the connection pool.
+    pool_restarts: AtomicU64,
+    /// Backpressure counts of the cluster detection service, once started.
+    cluster_queue: OnceLock<Arc<ClusterQueueStats>>,
+}
+
+impl MetricsRegistry {
+    pub(crate) fn new(
+        state: Arc<AppState>,
+        stats: Arc<dyn StatsRecorder>,
+        governor: Arc<dyn AdaptiveGovernor>,
+    ) -> Self {
+        Self {
+            state,
+            stats,
+            governor,
+            active_subscriptions: AtomicUsize::new(0),
+            disconnects: AtomicU64::new(0),
+            pool_restarts: AtomicU64::new(0),
+            cluster_queue: OnceLock::new(),
+        }
+    }
+
+    /// Report the cluster detection service's backpressure counts.
+    pub(crate) fn watch_cluster_queue(&self, stats: Arc<ClusterQueueStats>) {
+        let _ = self.cluster_queue.set(stats);
+    }
+
+    pub(crate) fn set_active_subscriptions(&self, count: usize) {
+        self.active_subscriptions.store(count, Ordering::Relaxed);
+    }
+
+    /// Count disconnects seen on the market data stream.
+    pub(crate) fn observe_event(&self, event: &MarketEvent) {
+        if matches!(event, MarketEvent::Disconnected { .. }) {
+            self.disconnects.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn update_pool_stats(&self, stats: PoolStats) {
+        self.pool_restarts
+            .store(stats.total_restarts, Ordering::Relaxed);
+    }
+
+    /// Render all metrics in Prometheus text format.
+    pub(crate) fn render(&self) -> String {
+        let today = self.stats.get_today();
+        let latency = self.governor.latency_metrics();
+        let reconnects =
+            self.disconnects.load(Ordering::Relaxed) + self.pool_restarts.load(Ordering::Relaxed);
+
+        let mut out = String::new();
+        metric(
+            &mut out,
+            "edgelord_opportunities_detected_total",
+            "counter",
+            "Opportunities detected since UTC midnight.",
+            today.opportunities_detected,
+        );
+        metric(
+            &mut out,
+            "edgelord_opportunities_executed_total",
+            "counter",
+            "Opportunities executed since UTC midnight.",
+            today.opportunities_executed,
+        );
+        metric(
+            &mut out,
+            "edgelord_trades_executed_total",
+            "counter",
+            "Trades opened since UTC midnight.",
+            today.trades_opened,
+        );
+        metric(
+            &mut out,
+            "edgelord_exposure",
+            "gauge",
+            "Exposure held in open positions.",
+            decimal(self.state.total_exposure()),
+        );
+        metric(
+            &mut out,
+            "edgelord_pending_exposure",
+            "gauge",
+            "Exposure reserved for in-flight executions.",
+            decimal(self.state.pending_exposure()),
+        );
+        metric(
+            &mut out,
+            "edgelord_open_positions",
+            "gauge",
+            "Number of open positions.",
+            self.state.open_position_count(),
+        );
+        metric(
+            &mut out,
+            "edgelord_active_subscriptions",
+            "gauge",
+            "Tokens subscribed on the market data stream.",
+            self.active_subscriptions.load(Ordering::Relaxed),
+        );
+        metric(
+            &mut out,
+            "edgelord_websocket_reconnects_total",
+            "counter",
+            "Market data connection drops and restarts.",
+            reconnects,
+        );
+
+        let cluster_queue = self.cluster_queue.get();
+        metric(
+            &mut out,
+            "edgelord_cluster_opportunities_coalesced_total",
+            "counter",
+            "Cluster opportunities replaced by a newer one for the same cluster under backpressure.",
+            cluster_queue.map_or(0, |stats| stats.coalesced()),
+        );
+        metric(
+            &mut out,
+            "edgelord_cluster_opportunities_dropped_total",
+            "counter",
+            "Cluster opportunities dropped under backpressure.",
+            cluster_queue.map_or(0, |stats| stats.dropped()),
+        );
+
+        let _ = writeln!(
+            out,
+            "# HELP edgelord_latency_seconds Market event detection latency percentiles."
+        );
+        let _ = writeln!(out, "# TYPE edgelord_latency_seconds gauge");
+        for (quantile, value) in [
+            ("0.5", latency.p50),
+            ("0.95", latency.p95),
+            ("0.99", latency.p99),
+        ] {
+            let _ = writeln!(
+                out,
+                "edgelord_latency_seconds{{quantile=\"{quantile}\"}} {}",
+                value.as_secs_f64()
+            );
+        }
+        metric(
+            &mut out,
+            "edgelord_latency_samples",
+            "gauge",
+            "Latency samples in the current window.",
+            latency.sample_count,
+        );
+        out
+    }
+
+    /// Render every retained event, newest first, as a JSON array.
+    pub(crate) fn render_events(&self) -> String {
+        let buffer = self.state.recent_events();
+        serde_json::to_string(&buffer.recent(buffer.capacity()))
+            .unwrap_or_else(|_| "[]".to_string())
+    }
+}
+
+/// Append a single unlabelled metric with its help and type lines.
+fn metric(out: &mut String, name: &str, kind: &str, help: &str, value: impl std::fmt::Display) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+    let _ = writeln!(out, "{name} {value}");
+}
+
+fn decimal(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+/// Start the metrics server if enabled in configuration.
+///
+/// Returns the registry the runtime loop reports into, or `None` when
+/// metrics are disabled.
+pub(crate) async fn start_metrics(
+    config: &Config,
+    state: Arc<AppState>,
+    stats: Arc<dyn StatsRecorder>,
+    governor: Arc<dyn AdaptiveGovernor>,
+    shutdown: watch::Receiver<bool>,
+) -> Result<Option<Arc<MetricsRegistry>>> {
+    if !config.metrics.enabled {
+        return Ok(None);
+    }
+
+    let registry = Arc::new(MetricsRegistry::new(state, stats, governor));
+    spawn_metrics_server(&config.metrics, Arc::clone(&registry), shutdown).await?;
+    Ok(Some(registry))
+}
+
+/// Bind the configured address and serve metrics until shutdown.
+pub(crate) async fn spawn_metrics_server(
+    config: &MetricsConfig,
+    registry: Arc<MetricsRegistry>,
+    shutdown: watch::Receiver<bool>,
+) -> Result<()> {
+    let listener = TcpListener::bind(&config.bind_addr).await?;
+    info!(addr = %config.bind_addr, "Metrics server listening");
+    tokio::spawn(serve(listener, registry, shutdown));
+    Ok(())
+}
+
+async fn serve(
+    listener: TcpListener,
+    registry: Arc<MetricsRegistry>,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    loop {
+        tokio::select! {
+            result = shutdown.changed() => {
+                if result.is_err() || *shutdown.borrow() {
+                    break;
+                }
+            }
+            accepted = listener.accept() => {
+                match accepted {
+                    Ok((stream, peer)) => {
+                        let registry = Arc::clone(&registry);
+                        tokio::spawn(async move {
+                            if let Err(e) = handle_connection(stream, &registry).await {
+                                debug!(peer = %peer, error = %e, "Metrics request failed");
+                            }
+                        });
+                    }
+                    Err(e) => warn!(error = %e, "Failed to accept metrics connection"),
+                }
+            }
+        }
+    }
+    debug!("Metrics server stopped");
+}
+
+async fn handle_connection(mut stream: TcpStream, registry: &MetricsRegistry) -> Result<()> {
+    let head = match tokio::time::timeout(READ_TIMEOUT, read_request_head(&mut stream)).await {
+        Ok(head) => head?,
+        Err(_) => return Ok(()),
+    };
+
+    let (status, content_type, body) = match request_target(&head) {
+        Some(("GET", "/metrics")) => ("200 OK", CONTENT_TYPE, registry.render()),
+        Some(("GET", "/events")) => ("200 OK", JSON_CONTENT_TYPE, registry.render_events()),
+        Some(("GET", _)) => ("404 Not Found", CONTENT_TYPE, "not found\n".to_string()),
+        Some(_) => (
+            "405 Method Not Allowed",
+            CONTENT_TYPE,
+            "method not allowed\n".to_string(),
+        ),
+        None => ("400 Bad Request", CONTENT_TYPE, "bad request\n".to_string()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Read until the end of the request head or the size limit.
+async fn read_request_head(stream: &mut TcpStream) -> Result<String> {
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while head.len() < MAX_REQUEST_BYTES && !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        head.extend_from_slice(&buf[..n]);
+    }
+    Ok(String::from_utf8_lossy(&head).into_owned())
+}
+
+/// Extract the method and path from an HTTP request line.
+fn request_target(head: &str) -> Option<(&str, &str)> {
+    let mut parts = head.lines().next()?.split_whitespace();
+    let method = parts.next()?;
+    let target = parts.next()?;
+    let path = target.split('?').next().unwrap_or(target);
+    Some((method, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::governor::latency::LatencyGovernor;
+    use crate::infrastructure::governor::runtime::GovernorConfig;
+    use crate::testkit::stats::in_memory_stats_recorder;
+
+    fn registry() -> MetricsRegistry {
+        MetricsRegistry::new(
+            Arc::new(AppState::default()),
+            in_memory_stats_recorder(),
+            Arc::new(LatencyGovernor::new(GovernorConfig::default())),
+        )
+    }
+
+    #[test]
+    fn render_includes_every_metric() {
+        let registry = registry();
+        registry.set_active_subscriptions(42);
+        registry.observe_event(&MarketEvent::Disconnected {
+            reason: "eof".into(),
+        });
+        registry.observe_event(&MarketEvent::Connected);
+        registry.update_pool_stats(PoolStats {
+            total_restarts: 2,
+            ..Default::default()
+        });
+
+        let body = registry.render();
+
+        assert!(body.contains("# TYPE edgelord_opportunities_detected_total counter\n"));
+        assert!(body.contains("edgelord_trades_executed_total 0\n"));
+        assert!(body.contains("edgelord_exposure 0\n"));
+        assert!(body.contains("edgelord_active_subscriptions 42\n"));
+        assert!(body.contains("edgelord_websocket_reconnects_total 3\n"));
+        assert!(body.contains("edgelord_cluster_opportunities_dropped_total 0\n"));
+        assert!(body.contains("edgelord_latency_seconds{quantile=\"0.99\"} 0\n"));
+    }
+
+    #[test]
+    fn render_reports_governor_latency() {
+        let governor = Arc::new(LatencyGovernor::new(GovernorConfig::default()));
+        for ms in [10, 20, 30] {
+            governor.record_latency(Duration::from_millis(ms));
+        }
+        let registry = MetricsRegistry::new(
+            Arc::new(AppState::default()),
+            in_memory_stats_recorder(),
+            governor,
+        );
+
+        let body = registry.render();
+
+        assert!(body.contains("edgelord_latency_seconds{quantile=\"0.5\"} 0.02\n"));
+        assert!(body.contains("edgelord_latency_samples 3\n"));
+    }
+
+    #[test]
+    fn render_events_lists_newest_first_as_json() {
+        use crate::port::outbound::notifier::{Event, Notifier};
+
+        let registry = registry();
+        let buffer = registry.state.recent_events().clone();
+        buffer.notify(Event::TradingHalted {
+            reason: "manual".into(),
+        });
+        buffer.notify(Event::CircuitBreakerReset);
+
+        let events: serde_json::Value = serde_json::from_str(&registry.render_events()).unwrap();
+
+        assert_eq!(events[0]["event"]["type"], "circuit_breaker_reset");
+        assert_eq!(events[1]["event"]["type"], "trading_halted");
+        assert_eq!(events[1]["event"]["reason"], "manual");
+        assert!(events[1]["at"].is_string());
+    }
+
+    #[test]
+    fn request_target_parses_method_and_path() {
+        assert_eq!(
+            request_target("GET /metrics?x=1 HTTP/1.1\r\nHost: a\r\n\r\n"),
+            Some(("GET", "/metrics"))
+        );
+        assert_eq!(request_target(""), None);
+    }
+
+    #[tokio::test]
+    async fn server_serves_metrics_until_shutdown() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let handle = tokio::spawn(serve(listener, Arc::new(registry()), shutdown_rx));
+
+        let mut client = TcpStream::connect(addr).await.unwrap();
+        client
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("edgelord_open_positions 0\n"));
+
+        shutdown_tx.send(true).unwrap();
+        handle.await.unwrap();
+    }
+}
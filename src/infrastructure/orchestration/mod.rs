@@ -2,11 +2,26 @@
 //!
 //! Runtime wiring and lifecycle management for application orchestration.
 
+mod bench;
 mod cluster;
 mod context;
+#[cfg(feature = "control-api")]
+mod control;
+mod discovery;
+mod drain;
+mod gas;
 mod health;
 mod inference;
+#[cfg(feature = "metrics")]
+mod metrics;
 pub mod orchestrator;
+mod reconcile;
 mod runtime;
+mod scaling;
+mod scan;
+mod settlement;
+mod simulate;
+mod snapshot;
 mod startup;
 mod stream;
+mod watchdog;
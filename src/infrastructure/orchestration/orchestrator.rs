@@ -1,16 +1,27 @@
 //! Infrastructure orchestration façade.
 
+use std::time::Duration;
+
 use crate::application::orchestration::handler::handle_market_event;
 use crate::port::outbound::exchange::MarketEvent;
 
+pub use super::bench::{bench_detection, DetectionBench};
 pub use super::context::EventProcessingContext;
 pub use super::health::{health_check, HealthCheck, HealthReport, HealthStatus};
 pub use super::runtime::run_with_shutdown;
+pub use super::scan::{collect_marks, scan_markets, MarketScan};
+pub use super::simulate::{simulate_backtest, Backtest};
 
 /// Main application orchestrator.
 pub struct Orchestrator;
 
 /// Process a single market event through the orchestrator pipeline.
-pub fn process_market_event(event: MarketEvent, context: EventProcessingContext<'_>) {
-    handle_market_event(event, context.into_handler_context());
+///
+/// Returns the detection latency for order book events, measured from the
+/// context's `received_at`.
+pub fn process_market_event(
+    event: MarketEvent,
+    context: EventProcessingContext<'_>,
+) -> Option<Duration> {
+    handle_market_event(event, context.into_handler_context())
 }
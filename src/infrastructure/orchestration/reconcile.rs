@@ -0,0 +1,60 @@
+//! Startup reconciliation of restored positions against exchange holdings.
+//!
+//! Compares the shares that restored positions expect to hold with what the
+//! exchange reports for the trading wallet and logs every discrepancy. It
+//! never changes positions; resolving a mismatch is left to the operator.
+
+use tracing::{debug, info, warn};
+
+use crate::application::position::manager::PositionManager;
+use crate::application::state::AppState;
+use crate::infrastructure::config::settings::Config;
+use crate::infrastructure::exchange::factory::ExchangeFactory;
+
+/// Reconcile open positions against the holdings the exchange reports.
+///
+/// Skipped when replaying recorded data or when the exchange has no
+/// holdings API or no wallet is configured. Fetch failures are logged and
+/// do not stop startup.
+pub(crate) async fn reconcile_holdings(config: &Config, state: &AppState) {
+    if config.replay.is_some() {
+        return;
+    }
+    let Some(fetcher) = ExchangeFactory::create_holdings_fetcher(config) else {
+        debug!("Holdings unavailable, skipping position reconciliation");
+        return;
+    };
+
+    let holdings = match fetcher.get_holdings().await {
+        Ok(holdings) => holdings,
+        Err(error) => {
+            warn!(
+                exchange = fetcher.exchange_name(),
+                error = %error,
+                "Failed to fetch holdings, skipping position reconciliation"
+            );
+            return;
+        }
+    };
+
+    let discrepancies = PositionManager::reconcile(&state.positions(), &holdings);
+    if discrepancies.is_empty() {
+        info!(
+            holdings = holdings.len(),
+            "Restored positions match exchange holdings"
+        );
+        return;
+    }
+    for discrepancy in &discrepancies {
+        warn!(
+            token_id = %discrepancy.token_id,
+            expected = %discrepancy.expected,
+            held = %discrepancy.held,
+            "Restored positions disagree with exchange holdings"
+        );
+    }
+    warn!(
+        tokens = discrepancies.len(),
+        "Position reconciliation found discrepancies"
+    );
+}
@@ -2,27 +2,58 @@
 
 use std::sync::Arc;
 
+use parking_lot::RwLock;
 use tokio::sync::watch;
 use tracing::{info, warn};
 
 use super::cluster;
 use super::context::EventProcessingContext;
+#[cfg(feature = "control-api")]
+use super::control;
+use super::discovery;
+use super::drain;
+use super::gas;
 use super::inference;
+#[cfg(feature = "metrics")]
+use super::metrics;
 use super::orchestrator::{process_market_event, Orchestrator};
+use super::reconcile;
+use super::scaling;
+use super::settlement;
+use super::snapshot;
 use super::startup;
 use super::stream;
-use crate::application::position::manager::PositionManager;
+use super::watchdog::{self, StreamWatchdog};
+#[cfg(feature = "control-api")]
+use crate::adapter::outbound::notifier::telegram::control::RuntimeStats;
+use crate::application::orchestration::concurrency::ExecutionSlots;
+use crate::application::orchestration::dedup::DedupTracker;
+use crate::application::orchestration::handler::ShadowDetection;
+use crate::application::orchestration::simulator::SimulatedExecutor;
+use crate::application::orchestration::throttle::RateLimiter;
+use crate::application::orchestration::versions::BookVersionTracker;
+use crate::application::position::manager::{ExitPolicy, PositionManager};
+use crate::application::risk::breaker::FailureBreaker;
 use crate::application::risk::manager::RiskManager;
+use crate::application::risk::sizing::PositionSizer;
 use crate::application::state::AppState;
+use crate::domain::market::MarketRegistry;
+use crate::domain::stats::LatencyPercentiles;
+use crate::domain::trading::TradingState;
 use crate::error::Result;
 use crate::infrastructure::bootstrap::{
-    build_cluster_cache, build_inferrer, build_llm_client, build_notifier_registry,
-    build_strategy_registry, init_executor, init_stats_recorder,
+    build_cluster_cache, build_cluster_store, build_inferrer, build_llm_client,
+    build_notifier_registry, build_position_store, build_relation_store, build_shadow_registry,
+    build_strategy_registry, build_trading_state_store, init_executor, init_stats_recorder,
 };
 use crate::infrastructure::config::settings::Config;
+use crate::infrastructure::governor::latency::LatencyGovernor;
+use crate::infrastructure::governor::runtime::{AdaptiveGovernor, GovernorConfig};
 #[cfg(feature = "telegram")]
 use crate::port::inbound::runtime::RuntimeClusterView;
+use crate::port::outbound::exchange::ArbitrageExecutor;
 use crate::port::outbound::inference::RelationInferrer;
+use crate::port::outbound::notifier::Event;
 
 impl Orchestrator {
     /// Run the main application loop.
@@ -41,22 +72,69 @@ impl Orchestrator {
 pub async fn run_with_shutdown(config: Config, mut shutdown: watch::Receiver<bool>) -> Result<()> {
     info!(exchange = ?config.exchange, dry_run = config.dry_run, "Starting edgelord");
 
-    let state = Arc::new(AppState::new(config.risk.clone().into()));
+    let trading_store = build_trading_state_store(&config)?;
+    let state = Arc::new(
+        AppState::new(config.risk.clone().into())
+            .with_recent_events(config.recent_events)
+            .with_trading_store(trading_store)
+            .with_failure_breaker(FailureBreaker::from(&config.risk.breaker)),
+    );
     let stats_recorder = init_stats_recorder(&config)?;
-    let position_manager = Arc::new(PositionManager::new(Arc::clone(&stats_recorder)));
+    let position_manager = Arc::new(
+        PositionManager::new(Arc::clone(&stats_recorder))
+            .with_store(build_position_store(&config)?),
+    );
+    let restored = position_manager.restore(&mut state.positions_mut())?;
     info!(database = %config.database, "Database initialized");
 
-    let risk_manager = Arc::new(RiskManager::new(state.clone()));
+    let gas_monitor = gas::build_gas_monitor(&config);
+    let mut risk_manager =
+        RiskManager::new(state.clone()).with_sizer(PositionSizer::from(&config.risk.sizing));
+    if let Some(ref monitor) = gas_monitor {
+        risk_manager = risk_manager.with_gas_guard(monitor.guard());
+    }
+    if let Some(cooldown) = config.execution.market_cooldown() {
+        risk_manager = risk_manager.with_market_cooldown(cooldown);
+    }
+    if let Some(max_age) = config.execution.max_opportunity_age() {
+        risk_manager = risk_manager.with_max_opportunity_age(max_age);
+    }
+    let risk_manager = Arc::new(risk_manager);
 
     #[allow(unused_variables)]
     let (notifiers, runtime_stats) =
         build_notifier_registry(&config, Arc::clone(&state), Arc::clone(&stats_recorder));
     let notifiers = Arc::new(notifiers);
     info!(notifiers = notifiers.len(), "Notifiers initialized");
+    // The control API reports the same runtime figures as the Telegram bot,
+    // so it needs them collected even when Telegram is off.
+    #[cfg(feature = "control-api")]
+    let runtime_stats = runtime_stats.or_else(|| {
+        config
+            .control_api
+            .enabled
+            .then(|| Arc::new(RuntimeStats::new()))
+    });
+
+    if let TradingState::Halted { reason } = state.trading_state() {
+        notifiers.notify_all(Event::TradingHalted { reason });
+    }
+
+    let gas_refresh = match gas_monitor {
+        Some(monitor) => {
+            Some(gas::start_gas_refresh(&config, monitor, Arc::clone(&notifiers)).await)
+        }
+        None => None,
+    };
 
     let executor = init_executor(&config).await;
+    if restored > 0 {
+        reconcile::reconcile_holdings(&config, &state).await;
+    }
 
     let cluster_cache = build_cluster_cache(&config);
+    let cluster_store = build_cluster_store(&config)?;
+    inference::restore_clusters(cluster_store.as_deref(), cluster_cache.as_ref()).await;
     let llm_client = build_llm_client(&config);
     let inferrer: Option<Arc<dyn RelationInferrer>> =
         llm_client.map(|llm| build_inferrer(&config, llm));
@@ -70,21 +148,72 @@ pub async fn run_with_shutdown(config: Config, mut shutdown: watch::Receiver<boo
     }
 
     let strategies = build_strategy_registry(&config, Arc::clone(&cluster_cache));
+    #[cfg(feature = "telegram")]
+    if let Some(ref stats) = runtime_stats {
+        stats.set_strategy_control(Arc::new(strategies.toggles()));
+    }
     let Some(prepared) = startup::prepare_markets(&config, strategies).await? else {
         return Ok(());
     };
 
+    let mut shadow = build_shadow_registry(&config, Arc::clone(&cluster_cache)).map(
+        |(variant, mut strategies)| {
+            strategies.set_registry(Arc::clone(&prepared.registry));
+            info!(
+                variant = %variant,
+                strategies = ?strategies.strategy_names(),
+                "Shadow strategies enabled"
+            );
+            (variant, strategies)
+        },
+    );
+
+    stats_recorder.record_market_scores(
+        &prepared.ranked[..prepared.ranked.len().min(config.scoring.report_top)],
+    );
+
     #[cfg(feature = "telegram")]
     if let Some(ref stats) = runtime_stats {
         stats.update_market_counts(prepared.registry.len(), prepared.token_ids.len());
     }
 
+    let governor: Arc<dyn AdaptiveGovernor> =
+        Arc::new(LatencyGovernor::new(GovernorConfig::from(&config.governor)));
+
+    #[cfg(feature = "metrics")]
+    let metrics = metrics::start_metrics(
+        &config,
+        Arc::clone(&state),
+        Arc::clone(&stats_recorder),
+        Arc::clone(&governor),
+        shutdown.clone(),
+    )
+    .await?;
+    #[cfg(feature = "metrics")]
+    if let Some(ref metrics) = metrics {
+        metrics.set_active_subscriptions(prepared.token_ids.len());
+    }
+    #[cfg(feature = "control-api")]
+    if let Some(ref stats) = runtime_stats {
+        control::start_control_api(
+            &config,
+            Arc::clone(&state),
+            Arc::clone(&stats_recorder),
+            Arc::clone(stats),
+            shutdown.clone(),
+        )
+        .await?;
+    }
+
+    let inference_availability = Arc::new(inference::InferenceAvailability::default());
     inference::run_startup_inference(
         &config,
         inferrer.as_ref(),
         &prepared.market_summaries,
         cluster_cache.as_ref(),
+        cluster_store.as_deref(),
         &notifiers,
+        &inference_availability,
     )
     .await;
 
@@ -94,23 +223,87 @@ pub async fn run_with_shutdown(config: Config, mut shutdown: watch::Receiver<boo
         Arc::clone(&cluster_cache),
         Arc::clone(&notifiers),
     );
+    #[cfg(feature = "metrics")]
+    if let (Some(ref metrics), Some(ref handle)) = (&metrics, &cluster_handle) {
+        metrics.watch_cluster_queue(handle.queue_stats());
+    }
+    snapshot::warm_cache(&config, &cache);
 
+    let pruning = inference::start_relation_pruning(
+        &config,
+        Arc::clone(&cluster_cache),
+        build_relation_store(&config)?,
+    );
     let _inference_handle = inference::start_continuous_inference(
         &config,
         inferrer,
         Arc::clone(&cluster_cache),
+        cluster_store,
         Arc::new(prepared.market_summaries.clone()),
+        Arc::clone(&notifiers),
+        inference_availability,
     );
 
     let mut data_stream = stream::create_connected_stream(&config, &prepared.token_ids).await?;
+    // Tokens the stream is subscribed to, for restarting it after a stall.
+    let mut subscribed_tokens = prepared.token_ids.clone();
+    let mut watchdog = StreamWatchdog::new(&config);
+    let mut settlement = settlement::start_settlement_polling(&config, Arc::clone(&state));
+    let scaling = scaling::start_scaling(
+        &config,
+        &prepared.registry,
+        &prepared.ranked,
+        Arc::clone(&governor),
+        shutdown.clone(),
+    )
+    .await?;
+    // Market re-scans update the live registry; each change is handed on to
+    // the strategies and cluster detection as a fresh snapshot.
+    let mut strategies = prepared.strategies;
+    let registry = Arc::new(RwLock::new(MarketRegistry::clone(&prepared.registry)));
+    let mut rescan = discovery::start_market_rescan(
+        &config,
+        Arc::clone(&registry),
+        scaling.as_ref().map(|scaling| Arc::clone(&scaling.manager)),
+    )?;
     info!("Listening for market events...");
 
-    let dry_run = config.dry_run;
+    // Simulated fills run through the live execution path, so the dry-run
+    // gate only applies when fills are not being simulated.
+    let simulate_fills = config.dry_run && config.dry_run_simulate_fills;
+    let executor: Option<Arc<dyn ArbitrageExecutor + Send + Sync>> = if simulate_fills {
+        info!("Dry-run fill simulation enabled");
+        Some(Arc::new(
+            SimulatedExecutor::new(Arc::clone(&cache))
+                .with_slippage_buffer(config.risk.max_slippage),
+        ))
+    } else {
+        executor
+    };
+    let dry_run = config.dry_run && !simulate_fills;
+    let dedup = DedupTracker::new(
+        std::time::Duration::from_millis(config.dedup_window_ms),
+        config.dedup_edge_bucket,
+    );
+    let shadow_dedup = DedupTracker::new(
+        std::time::Duration::from_millis(config.dedup_window_ms),
+        config.dedup_edge_bucket,
+    );
+    let versions = BookVersionTracker::new();
+    let rate_limiter = Arc::new(RateLimiter::from(&config.execution));
+    let execution_slots = ExecutionSlots::from(&config.execution);
     let stats_interval_secs = config.telegram.stats_interval_secs;
     let mut stats_interval =
         tokio::time::interval(std::time::Duration::from_secs(stats_interval_secs));
     stats_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
+    let exit_policy = ExitPolicy::from(&config.risk.exits);
+    let mut exit_interval = tokio::time::interval(std::time::Duration::from_secs(
+        config.risk.exits.check_interval_secs.max(1),
+    ));
+    exit_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    let mut shutdown_requested = false;
     loop {
         tokio::select! {
             result = shutdown.changed() => {
@@ -118,50 +311,184 @@ pub async fn run_with_shutdown(config: Config, mut shutdown: watch::Receiver<boo
                     Ok(_) => {
                         if *shutdown.borrow() {
                             info!("Shutdown signal received");
+                            shutdown_requested = true;
                             break;
                         }
                     }
                     Err(_) => {
                         info!("Shutdown channel closed");
+                        shutdown_requested = true;
                         break;
                     }
                 }
             }
             _ = stats_interval.tick() => {
+                let latency = LatencyPercentiles::from(governor.latency_metrics());
+                stats_recorder.record_latency_percentiles(&latency);
+                #[cfg(feature = "telegram")]
+                if let Some(ref stats) = runtime_stats {
+                    stats.update_latency(latency);
+                }
                 #[cfg(feature = "telegram")]
                 if let Some(ref stats) = runtime_stats {
                     if let Some(pool_stats) = data_stream.pool_stats() {
                         stats.update_pool_stats(pool_stats);
                     }
                 }
+                #[cfg(feature = "metrics")]
+                if let Some(ref metrics) = metrics {
+                    if let Some(pool_stats) = data_stream.pool_stats() {
+                        metrics.update_pool_stats(pool_stats);
+                    }
+                }
+            }
+            _ = exit_interval.tick(), if exit_policy.is_enabled() => {
+                let mut positions = state.positions_mut();
+                position_manager.check_exit_conditions(&mut positions, &cache, &exit_policy);
+            }
+            Some(event) = async { settlement.as_mut()?.events.recv().await }, if settlement.is_some() => {
+                process_market_event(
+                    event,
+                    EventProcessingContext {
+                        cache: &cache,
+                        registry: &registry.read(),
+                        strategies: &strategies,
+                        executor: executor.clone(),
+                        risk_manager: &risk_manager,
+                        notifiers: &notifiers,
+                        state: &state,
+                        stats: &stats_recorder,
+                        position_manager: &position_manager,
+                        dedup: &dedup,
+                        versions: &versions,
+                        rate_limiter: &*rate_limiter,
+                        execution_slots: &execution_slots,
+                        verify_before_execute: config.execution.verify_before_execute,
+                        priority: config.execution.priority,
+                        received_at: std::time::Instant::now(),
+                        dry_run,
+                        shadow: None,
+                    },
+                );
+            }
+            Some(token_ids) = async { rescan.as_mut()?.updates.recv().await }, if rescan.is_some() => {
+                if let Err(error) = data_stream.resubscribe(&token_ids).await {
+                    warn!(error = %error, "Resubscribing after market re-scan failed");
+                }
+                subscribed_tokens.clone_from(&token_ids);
+                let snapshot = Arc::new(MarketRegistry::clone(&registry.read()));
+                strategies.set_registry(Arc::clone(&snapshot));
+                if let Some((_, shadow)) = shadow.as_mut() {
+                    shadow.set_registry(Arc::clone(&snapshot));
+                }
+                if let Some(ref handle) = cluster_handle {
+                    handle.set_registry(snapshot);
+                }
+                #[cfg(feature = "telegram")]
+                if let Some(ref stats) = runtime_stats {
+                    stats.update_market_counts(registry.read().len(), token_ids.len());
+                }
+                #[cfg(feature = "metrics")]
+                if let Some(ref metrics) = metrics {
+                    metrics.set_active_subscriptions(token_ids.len());
+                }
+            }
+            () = watchdog.stalled(), if watchdog.is_enabled() => {
+                watchdog::restart_stream(
+                    &config,
+                    &mut data_stream,
+                    &subscribed_tokens,
+                    &mut watchdog,
+                    &notifiers,
+                )
+                .await;
             }
             event = data_stream.next_event() => {
+                let received_at = std::time::Instant::now();
                 let Some(event) = event else {
                     warn!("Market data stream ended");
                     break;
                 };
-                process_market_event(
+                watchdog.feed();
+                #[cfg(feature = "metrics")]
+                if let Some(ref metrics) = metrics {
+                    metrics.observe_event(&event);
+                }
+                let registry = registry.read();
+                if let Some(ref scaling) = scaling {
+                    if !scaling::is_active(&event, &registry, &scaling.scaler) {
+                        continue;
+                    }
+                }
+                let latency = process_market_event(
                     event,
                     EventProcessingContext {
                         cache: &cache,
-                        registry: &prepared.registry,
-                        strategies: prepared.strategies.as_ref(),
+                        registry: &registry,
+                        strategies: &strategies,
                         executor: executor.clone(),
                         risk_manager: &risk_manager,
                         notifiers: &notifiers,
                         state: &state,
                         stats: &stats_recorder,
                         position_manager: &position_manager,
+                        dedup: &dedup,
+                        versions: &versions,
+                        rate_limiter: &*rate_limiter,
+                        execution_slots: &execution_slots,
+                        verify_before_execute: config.execution.verify_before_execute,
+                        priority: config.execution.priority,
+                        received_at,
                         dry_run,
+                        shadow: shadow.as_ref().map(|(variant, strategies)| ShadowDetection {
+                            variant,
+                            strategies,
+                            dedup: &shadow_dedup,
+                        }),
                     },
                 );
+                if let Some(latency) = latency {
+                    governor.record_latency(latency);
+                }
             }
         }
     }
 
+    // The loop has exited, so no new trades are opened during the drain.
+    if shutdown_requested && config.execution.drain_on_shutdown && !dry_run {
+        if let Some(ref executor) = executor {
+            drain::drain_positions(
+                &config,
+                &state,
+                &position_manager,
+                executor.as_ref(),
+                &cache,
+                &notifiers,
+            )
+            .await;
+        }
+    }
+
+    snapshot::save_cache(&config, &cache);
+
     if let Some(handle) = cluster_handle {
         handle.shutdown().await;
     }
+    if let Some(scaling) = scaling {
+        scaling.handle.abort();
+    }
+    if let Some(settlement) = settlement {
+        settlement.handle.abort();
+    }
+    if let Some(rescan) = rescan {
+        rescan.handle.abort();
+    }
+    if let Some(pruning) = pruning {
+        pruning.abort();
+    }
+    if let Some(gas_refresh) = gas_refresh {
+        gas_refresh.abort();
+    }
 
     Ok(())
 }
@@ -0,0 +1,96 @@
+//! Adaptive subscription scaling setup.
+//!
+//! The market data stream keeps its startup subscriptions; the subscription
+//! manager decides which of those markets the runtime loop processes. The
+//! governor sheds markets when processing latency climbs and restores them
+//! when there is headroom again.
+
+use std::sync::Arc;
+
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tracing::info;
+
+use crate::domain::market::MarketRegistry;
+use crate::domain::score::RankedMarket;
+use crate::error::Result;
+use crate::infrastructure::config::settings::Config;
+use crate::infrastructure::governor::runtime::AdaptiveGovernor;
+use crate::infrastructure::governor::scaler::{spawn_scaler, SubscriptionScaler};
+use crate::infrastructure::subscription::manager::SubscriptionManager;
+use crate::infrastructure::subscription::priority::PrioritySubscriptionManager;
+use crate::port::outbound::exchange::MarketEvent;
+
+/// Running scaler and its background task.
+pub(crate) struct Scaling {
+    pub scaler: Arc<SubscriptionScaler>,
+    /// Manager the scaler drives, for registering markets found later.
+    pub manager: Arc<PrioritySubscriptionManager>,
+    pub handle: JoinHandle<()>,
+}
+
+/// Activate the prepared markets and start the scaling task.
+///
+/// Markets are enqueued by their weighted score, so contraction sheds the
+/// lowest-ranked markets first and expansion restores the highest.
+///
+/// Returns `None` when the governor is disabled, in which case every
+/// subscribed market is processed.
+pub(crate) async fn start_scaling(
+    config: &Config,
+    registry: &MarketRegistry,
+    ranked: &[RankedMarket],
+    governor: Arc<dyn AdaptiveGovernor>,
+    shutdown: watch::Receiver<bool>,
+) -> Result<Option<Scaling>> {
+    if !config.governor.enabled {
+        return Ok(None);
+    }
+
+    let budget = config.resources.to_budget(config.profile);
+    let manager = Arc::new(PrioritySubscriptionManager::new(
+        budget.estimate_max_subscriptions(),
+    ));
+    governor.set_resource_budget(budget);
+
+    for market in registry.markets() {
+        manager.register_market_tokens(
+            market.market_id().clone(),
+            market.token_ids().into_iter().cloned().collect(),
+        );
+    }
+    manager.enqueue(ranked.iter().map(|market| market.score.clone()).collect());
+    manager.expand(registry.len()).await?;
+    info!(
+        active = manager.active_count(),
+        max = manager.max_subscriptions(),
+        "Adaptive subscription scaling enabled"
+    );
+
+    let scaler = Arc::new(SubscriptionScaler::new(
+        governor,
+        Arc::clone(&manager) as Arc<dyn SubscriptionManager>,
+    ));
+    let handle = spawn_scaler(Arc::clone(&scaler), shutdown);
+    Ok(Some(Scaling {
+        scaler,
+        manager,
+        handle,
+    }))
+}
+
+/// Whether the event belongs to a market the scaler currently keeps active.
+///
+/// Events without a token and events for unknown tokens are always processed.
+pub(crate) fn is_active(
+    event: &MarketEvent,
+    registry: &MarketRegistry,
+    scaler: &SubscriptionScaler,
+) -> bool {
+    event
+        .token_id()
+        .and_then(|token_id| registry.get_by_token(token_id))
+        .map_or(true, |market| {
+            scaler.subscriptions().is_subscribed(market.market_id())
+        })
+}
@@ -0,0 +1,91 @@
+//! One-shot market scan for diagnostics.
+//!
+//! Connects to the market stream, collects order books for a fixed period,
+//! and runs strategy detection once. Never executes trades.
+
+use std::time::Duration;
+
+use tracing::info;
+
+use super::startup;
+use super::stream;
+use crate::application::cache::book::BookCache;
+use crate::application::orchestration::handler::detect_market_opportunities;
+use crate::domain::id::TokenId;
+use crate::domain::opportunity::Opportunity;
+use crate::error::Result;
+use crate::infrastructure::bootstrap::{build_cluster_cache, build_strategy_registry};
+use crate::infrastructure::config::settings::Config;
+
+/// Outcome of a one-shot market scan.
+#[derive(Debug, Default)]
+pub struct MarketScan {
+    /// Markets that passed filtering and were subscribed.
+    pub markets: usize,
+    /// Tokens subscribed on the market stream.
+    pub tokens: usize,
+    /// Distinct tokens with an order book at evaluation time.
+    pub books: usize,
+    /// Opportunities detected across all markets.
+    pub opportunities: Vec<Opportunity>,
+}
+
+/// Collect order books for `duration`, then run every strategy once.
+///
+/// Returns an empty scan if no markets pass startup filtering.
+///
+/// # Errors
+///
+/// Returns an error if market discovery or the stream connection fails.
+pub async fn scan_markets(config: &Config, duration: Duration) -> Result<MarketScan> {
+    let strategies = build_strategy_registry(config, build_cluster_cache(config));
+    let Some(prepared) = startup::prepare_markets(config, strategies).await? else {
+        return Ok(MarketScan::default());
+    };
+
+    let mut data_stream = stream::create_connected_stream(config, &prepared.token_ids).await?;
+
+    info!(seconds = duration.as_secs(), "Collecting order books");
+    let cache = stream::collect_books(data_stream.as_mut(), duration).await;
+
+    let opportunities = prepared
+        .registry
+        .markets()
+        .iter()
+        .flat_map(|market| detect_market_opportunities(market, &cache, &prepared.strategies))
+        .collect();
+
+    Ok(MarketScan {
+        markets: prepared.registry.len(),
+        tokens: prepared.token_ids.len(),
+        books: cache.len(),
+        opportunities,
+    })
+}
+
+/// Collect current order books for `token_ids` over `duration`.
+///
+/// Used to mark open positions without starting the full runtime. Returns
+/// an empty cache when `token_ids` is empty.
+///
+/// # Errors
+///
+/// Returns an error if the stream connection fails.
+pub async fn collect_marks(
+    config: &Config,
+    token_ids: &[TokenId],
+    duration: Duration,
+) -> Result<BookCache> {
+    if token_ids.is_empty() {
+        return Ok(BookCache::new());
+    }
+
+    let mut data_stream = stream::create_connected_stream(config, token_ids).await?;
+
+    info!(
+        seconds = duration.as_secs(),
+        tokens = token_ids.len(),
+        "Collecting marks"
+    );
+    Ok(stream::collect_books(data_stream.as_mut(), duration).await)
+}
@@ -0,0 +1,140 @@
+//! Settlement polling for markets holding open positions.
+//!
+//! Periodically asks the exchange whether markets with open positions have
+//! resolved and forwards the resulting settlement events to the runtime
+//! loop, which closes the positions and records their realized P&L.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::application::cache::position::PositionTracker;
+use crate::application::state::AppState;
+use crate::domain::id::MarketId;
+use crate::infrastructure::config::settings::Config;
+use crate::infrastructure::exchange::factory::ExchangeFactory;
+use crate::port::outbound::exchange::MarketEvent;
+
+/// Settlement events buffered between the poller and the runtime loop.
+const EVENT_BUFFER: usize = 64;
+
+/// Running settlement poller and the events it emits.
+pub(crate) struct SettlementPolling {
+    pub events: mpsc::Receiver<MarketEvent>,
+    pub handle: JoinHandle<()>,
+}
+
+/// Start polling the exchange for resolution of markets with open positions.
+///
+/// Returns `None` when polling is disabled, when replaying recorded data, or
+/// when the exchange has no settlement API.
+pub(crate) fn start_settlement_polling(
+    config: &Config,
+    state: Arc<AppState>,
+) -> Option<SettlementPolling> {
+    if !config.settlement.is_enabled() || config.replay.is_some() {
+        return None;
+    }
+    let fetcher = ExchangeFactory::create_settlement_fetcher(config)?;
+    let poll_interval = Duration::from_secs(config.settlement.poll_interval_secs);
+    let (tx, events) = mpsc::channel(EVENT_BUFFER);
+
+    let handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(poll_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        // The first tick fires immediately; no positions exist yet at startup.
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            let market_ids = unsettled_markets(&state.positions());
+            if market_ids.is_empty() {
+                continue;
+            }
+
+            debug!(
+                markets = market_ids.len(),
+                "Checking markets for settlement"
+            );
+            match fetcher.get_settlements(&market_ids).await {
+                Ok(settlements) => {
+                    for event in settlements {
+                        if tx.send(event).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Err(error) => {
+                    warn!(
+                        exchange = fetcher.exchange_name(),
+                        error = %error,
+                        "Settlement poll failed"
+                    );
+                }
+            }
+        }
+    });
+
+    info!(
+        interval_secs = poll_interval.as_secs(),
+        "Settlement polling started"
+    );
+    Some(SettlementPolling { events, handle })
+}
+
+/// Distinct markets that still hold positions which are not closed.
+fn unsettled_markets(tracker: &PositionTracker) -> Vec<MarketId> {
+    tracker
+        .all()
+        .filter(|position| !position.status().is_closed())
+        .map(|position| position.market_id().clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::id::{PositionId, TokenId};
+    use crate::domain::position::{Position, PositionLeg, PositionStatus};
+    use rust_decimal_macros::dec;
+
+    fn position(id: u64, market_id: &str) -> Position {
+        Position::new(
+            PositionId::new(id),
+            MarketId::from(market_id),
+            vec![PositionLeg::new(TokenId::from("yes"), dec!(10), dec!(0.45))],
+            dec!(4.5),
+            dec!(10),
+            chrono::Utc::now(),
+            PositionStatus::Open,
+        )
+    }
+
+    #[test]
+    fn unsettled_markets_are_distinct_and_skip_closed_positions() {
+        let mut tracker = PositionTracker::new();
+        tracker.add(position(1, "m1"));
+        tracker.add(position(2, "m1"));
+        let mut closed = position(3, "m2");
+        closed.close(dec!(0.5));
+        tracker.add(closed);
+
+        let markets = unsettled_markets(&tracker);
+
+        assert_eq!(markets, vec![MarketId::from("m1")]);
+    }
+
+    #[test]
+    fn polling_disabled_when_interval_is_zero() {
+        let mut config = Config::default();
+        config.settlement.poll_interval_secs = 0;
+
+        assert!(start_settlement_polling(&config, Arc::new(AppState::default())).is_none());
+    }
+}
@@ -0,0 +1,359 @@
+//! Backtest over a recorded session.
+//!
+//! Replays a recording through the same detection, risk, and execution path
+//! as `run`, filling trades with the [`SimulatedExecutor`] against the
+//! replayed books. Markets come from the recording itself and statistics go
+//! to a private in-memory database, so no exchange is contacted and the
+//! configured database is never opened.
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rust_decimal::Decimal;
+
+use super::context::EventProcessingContext;
+use super::orchestrator::process_market_event;
+use crate::adapter::outbound::replay::{load_recorded_markets, ReplayDataStream};
+use crate::application::cache::book::BookCache;
+use crate::application::orchestration::concurrency::ExecutionSlots;
+use crate::application::orchestration::dedup::DedupTracker;
+use crate::application::orchestration::simulator::SimulatedExecutor;
+use crate::application::orchestration::throttle::RateLimiter;
+use crate::application::orchestration::versions::BookVersionTracker;
+use crate::application::position::manager::PositionManager;
+use crate::application::risk::breaker::FailureBreaker;
+use crate::application::risk::manager::RiskManager;
+use crate::application::risk::sizing::PositionSizer;
+use crate::application::state::AppState;
+use crate::domain::id::TokenId;
+use crate::domain::market::MarketRegistry;
+use crate::domain::position::{Position, PositionStatus};
+use crate::error::{Error, Result};
+use crate::infrastructure::bootstrap::{
+    build_cluster_cache, build_memory_stats_recorder, build_strategy_registry,
+};
+use crate::infrastructure::config::settings::Config;
+use crate::port::outbound::exchange::{ArbitrageExecutor, MarketDataStream};
+use crate::port::outbound::notifier::NotifierRegistry;
+
+/// Outcome of a backtest over a recording.
+#[derive(Debug, Clone)]
+pub struct Backtest {
+    /// Markets defined by the recording.
+    pub markets: usize,
+    /// Book events replayed.
+    pub events: usize,
+    /// Opportunities detected.
+    pub opportunities: usize,
+    /// P&L of each simulated trade, in the order trades were opened.
+    pub trade_pnls: Vec<Decimal>,
+    /// Shuffled trade orderings drawn for the drawdown distribution.
+    pub runs: usize,
+    /// Median maximum drawdown across shuffled orderings.
+    pub drawdown_p50: Decimal,
+    /// 95th percentile maximum drawdown across shuffled orderings.
+    pub drawdown_p95: Decimal,
+}
+
+impl Backtest {
+    /// Simulated trades opened.
+    #[must_use]
+    pub fn trades(&self) -> usize {
+        self.trade_pnls.len()
+    }
+
+    /// Trades with a positive P&L.
+    #[must_use]
+    pub fn wins(&self) -> usize {
+        self.trade_pnls
+            .iter()
+            .filter(|pnl| **pnl > Decimal::ZERO)
+            .count()
+    }
+
+    /// Hypothetical P&L summed across all trades.
+    #[must_use]
+    pub fn pnl(&self) -> Decimal {
+        self.trade_pnls.iter().sum()
+    }
+
+    /// Largest peak-to-trough fall in cumulative P&L, in recorded order.
+    #[must_use]
+    pub fn max_drawdown(&self) -> Decimal {
+        max_drawdown(&self.trade_pnls)
+    }
+
+    /// Share of trades with a positive P&L as a percentage.
+    ///
+    /// Returns `None` if no trades were opened.
+    #[must_use]
+    pub fn win_rate(&self) -> Option<f64> {
+        let trades = self.trades();
+        (trades > 0).then(|| self.wins() as f64 / trades as f64 * 100.0)
+    }
+}
+
+/// Replay the recording at `data` through detection and simulated execution.
+///
+/// Events are replayed without delay, and each simulated execution finishes
+/// before the next event is applied, so fills see the books they were
+/// detected on. Positions stay open until the recording ends, so exposure
+/// accumulates against the risk limits as it would in a live session. Open
+/// positions are valued at their payout and partial fills at the final best
+/// bid of their filled legs.
+///
+/// The trade P&Ls are then reshuffled `runs` times from `seed` to estimate
+/// how deep the drawdown could have been had the same trades arrived in a
+/// different order.
+///
+/// # Errors
+///
+/// Returns an error if the recording cannot be read, defines no markets, or
+/// the in-memory database cannot be created.
+pub async fn simulate_backtest(
+    config: &Config,
+    data: &Path,
+    runs: usize,
+    seed: u64,
+) -> Result<Backtest> {
+    let mut registry = MarketRegistry::new();
+    for market in load_recorded_markets(data).await? {
+        registry.add(market);
+    }
+    if registry.is_empty() {
+        return Err(Error::Parse(format!(
+            "{} defines no markets; add `\"type\": \"market\"` lines to the recording",
+            data.display()
+        )));
+    }
+    let registry = Arc::new(registry);
+    let token_ids: Vec<TokenId> = registry.all_token_ids().cloned().collect();
+
+    let state = Arc::new(
+        AppState::new(config.risk.clone().into())
+            .with_failure_breaker(FailureBreaker::from(&config.risk.breaker)),
+    );
+    let stats = build_memory_stats_recorder()?;
+    let position_manager = Arc::new(PositionManager::new(Arc::clone(&stats)));
+    let mut risk_manager =
+        RiskManager::new(Arc::clone(&state)).with_sizer(PositionSizer::from(&config.risk.sizing));
+    if let Some(cooldown) = config.execution.market_cooldown() {
+        risk_manager = risk_manager.with_market_cooldown(cooldown);
+    }
+    if let Some(max_age) = config.execution.max_opportunity_age() {
+        risk_manager = risk_manager.with_max_opportunity_age(max_age);
+    }
+    let notifiers = Arc::new(NotifierRegistry::new());
+    let mut strategies = build_strategy_registry(config, build_cluster_cache(config));
+    strategies.set_registry(Arc::clone(&registry));
+
+    let cache = Arc::new(BookCache::new().with_max_depth(config.resources.max_book_depth));
+    let executor: Arc<dyn ArbitrageExecutor + Send + Sync> = Arc::new(
+        SimulatedExecutor::new(Arc::clone(&cache)).with_slippage_buffer(config.risk.max_slippage),
+    );
+    let dedup = DedupTracker::new(
+        Duration::from_millis(config.dedup_window_ms),
+        config.dedup_edge_bucket,
+    );
+    let versions = BookVersionTracker::new();
+    // Replay runs far faster than the recording, so pacing would only stall it.
+    let rate_limiter = RateLimiter::unlimited();
+    let execution_slots = ExecutionSlots::unlimited();
+
+    let mut stream = ReplayDataStream::new(data, 0.0);
+    stream.connect().await?;
+    stream.subscribe(&token_ids).await?;
+
+    let started = Utc::now().date_naive();
+    let mut events = 0;
+    while let Some(event) = stream.next_event().await {
+        events += 1;
+        process_market_event(
+            event,
+            EventProcessingContext {
+                cache: &cache,
+                registry: &registry,
+                strategies: &strategies,
+                executor: Some(Arc::clone(&executor)),
+                risk_manager: &risk_manager,
+                notifiers: &notifiers,
+                state: &state,
+                stats: &stats,
+                position_manager: &position_manager,
+                dedup: &dedup,
+                versions: &versions,
+                rate_limiter: &rate_limiter,
+                execution_slots: &execution_slots,
+                verify_before_execute: config.execution.verify_before_execute,
+                priority: config.execution.priority,
+                received_at: Instant::now(),
+                dry_run: false,
+                shadow: None,
+            },
+        );
+        // Executions run on spawned tasks; let them settle before the books move.
+        while state.pending_execution_count() > 0 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    let summary = stats.get_summary(started, Utc::now().date_naive());
+    let trade_pnls: Vec<Decimal> = state
+        .positions()
+        .all()
+        .map(|position| position_pnl(position, &cache))
+        .collect();
+    let drawdowns = shuffled_drawdowns(&trade_pnls, runs, seed);
+
+    Ok(Backtest {
+        markets: registry.len(),
+        events,
+        opportunities: usize::try_from(summary.opportunities_detected).unwrap_or_default(),
+        runs,
+        drawdown_p50: percentile(&drawdowns, 0.50),
+        drawdown_p95: percentile(&drawdowns, 0.95),
+        trade_pnls,
+    })
+}
+
+/// P&L of a position at the end of the replay.
+fn position_pnl(position: &Position, cache: &BookCache) -> Decimal {
+    match position.status() {
+        PositionStatus::Open => position.expected_profit(),
+        PositionStatus::PartialFill { .. } => {
+            PositionManager::mark_value(position, cache).unwrap_or_default() - position.entry_cost()
+        }
+        PositionStatus::Closed { pnl } => *pnl,
+    }
+}
+
+/// Largest peak-to-trough fall in the cumulative sum of `pnls`.
+fn max_drawdown(pnls: &[Decimal]) -> Decimal {
+    let mut cumulative = Decimal::ZERO;
+    let mut peak = Decimal::ZERO;
+    let mut drawdown = Decimal::ZERO;
+    for pnl in pnls {
+        cumulative += pnl;
+        peak = peak.max(cumulative);
+        drawdown = drawdown.max(peak - cumulative);
+    }
+    drawdown
+}
+
+/// Maximum drawdowns of `runs` random orderings of `pnls`, sorted ascending.
+fn shuffled_drawdowns(pnls: &[Decimal], runs: usize, seed: u64) -> Vec<Decimal> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut order = pnls.to_vec();
+    let mut drawdowns: Vec<Decimal> = (0..runs)
+        .map(|_| {
+            order.shuffle(&mut rng);
+            max_drawdown(&order)
+        })
+        .collect();
+    drawdowns.sort_unstable();
+    drawdowns
+}
+
+fn percentile(sorted: &[Decimal], quantile: f64) -> Decimal {
+    if sorted.is_empty() {
+        return Decimal::ZERO;
+    }
+    let index = ((sorted.len() - 1) as f64 * quantile).round() as usize;
+    sorted[index.min(sorted.len() - 1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use std::io::Write;
+
+    fn write_recording(content: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::Builder::new()
+            .suffix(".jsonl")
+            .tempfile()
+            .unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn max_drawdown_tracks_peak_to_trough() {
+        let pnls = [dec!(5), dec!(-3), dec!(2), dec!(-6), dec!(10)];
+
+        assert_eq!(max_drawdown(&pnls), dec!(7));
+        assert_eq!(max_drawdown(&[dec!(1), dec!(2)]), Decimal::ZERO);
+        assert_eq!(max_drawdown(&[]), Decimal::ZERO);
+    }
+
+    #[test]
+    fn shuffled_drawdowns_are_reproducible_and_bounded() {
+        let pnls = [dec!(5), dec!(-3), dec!(2), dec!(-6), dec!(10)];
+
+        let first = shuffled_drawdowns(&pnls, 50, 7);
+        let second = shuffled_drawdowns(&pnls, 50, 7);
+
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 50);
+        assert!(first.iter().all(|drawdown| *drawdown <= dec!(9)));
+        assert_eq!(percentile(&[], 0.95), Decimal::ZERO);
+    }
+
+    #[test]
+    fn win_rate_counts_positive_trades() {
+        let backtest = Backtest {
+            markets: 1,
+            events: 4,
+            opportunities: 4,
+            trade_pnls: vec![dec!(2), dec!(-1), dec!(0), dec!(3)],
+            runs: 0,
+            drawdown_p50: Decimal::ZERO,
+            drawdown_p95: Decimal::ZERO,
+        };
+
+        assert_eq!(backtest.trades(), 4);
+        assert_eq!(backtest.wins(), 2);
+        assert_eq!(backtest.pnl(), dec!(4));
+        assert_eq!(backtest.win_rate(), Some(50.0));
+    }
+
+    #[tokio::test]
+    async fn simulate_backtest_fills_recorded_arbitrage() {
+        let file = write_recording(concat!(
+            r#"{"type": "market", "market_id": "m1", "question": "Rain?", "outcomes": [{"token_id": "yes", "name": "Yes"}, {"token_id": "no", "name": "No"}]}"#,
+            "\n",
+            r#"{"timestamp": 1000, "token_id": "yes", "bids": [["0.38", "100"]], "asks": [["0.40", "100"]]}"#,
+            "\n",
+            r#"{"timestamp": 1100, "token_id": "no", "bids": [["0.38", "100"]], "asks": [["0.40", "100"]]}"#,
+            "\n",
+        ));
+
+        let backtest = simulate_backtest(&Config::default(), file.path(), 10, 42)
+            .await
+            .unwrap();
+
+        assert_eq!(backtest.markets, 1);
+        assert_eq!(backtest.events, 2);
+        assert!(backtest.opportunities >= 1);
+        assert_eq!(backtest.trades(), 1);
+        assert!(backtest.pnl() > Decimal::ZERO);
+        assert_eq!(backtest.max_drawdown(), Decimal::ZERO);
+    }
+
+    #[tokio::test]
+    async fn simulate_backtest_requires_market_definitions() {
+        let file =
+            write_recording(r#"{"timestamp": 1000, "token_id": "yes", "asks": [["0.40", "100"]]}"#);
+
+        let err = simulate_backtest(&Config::default(), file.path(), 10, 42)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("defines no markets"), "{err}");
+    }
+}
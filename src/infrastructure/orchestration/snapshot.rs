@@ -0,0 +1,90 @@
+//! Book cache snapshot wiring for warm restarts.
+
+use std::path::Path;
+use std::time::Duration;
+
+use tracing::{info, warn};
+
+use crate::application::cache::book::BookCache;
+use crate::infrastructure::config::settings::Config;
+
+/// Preload the book cache from the configured snapshot, if any.
+///
+/// A missing or unreadable snapshot is logged and the runtime starts cold.
+pub(crate) fn warm_cache(config: &Config, cache: &BookCache) {
+    let Some(snapshot) = &config.snapshot else {
+        return;
+    };
+    let path = Path::new(&snapshot.path);
+    if !path.exists() {
+        info!(path = %snapshot.path, "No book snapshot found, starting cold");
+        return;
+    }
+
+    match cache.load_snapshot(path, Duration::from_secs(snapshot.max_age_secs)) {
+        Ok(loaded) => {
+            info!(path = %snapshot.path, books = loaded, "Book cache warmed from snapshot")
+        }
+        Err(error) => warn!(path = %snapshot.path, error = %error, "Failed to load book snapshot"),
+    }
+}
+
+/// Write the book cache to the configured snapshot, if any.
+///
+/// Skipped in replay mode, where the cached books are historical.
+pub(crate) fn save_cache(config: &Config, cache: &BookCache) {
+    let Some(snapshot) = &config.snapshot else {
+        return;
+    };
+    if config.replay.is_some() || cache.is_empty() {
+        return;
+    }
+
+    match cache.save_snapshot(Path::new(&snapshot.path)) {
+        Ok(saved) => info!(path = %snapshot.path, books = saved, "Book snapshot saved"),
+        Err(error) => warn!(path = %snapshot.path, error = %error, "Failed to save book snapshot"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::book::Book;
+    use crate::domain::id::TokenId;
+    use crate::infrastructure::config::snapshot::SnapshotConfig;
+
+    #[test]
+    fn save_then_warm_restores_books() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.snapshot = Some(SnapshotConfig::new(
+            dir.path().join("books.bin").to_string_lossy(),
+        ));
+        let cache = BookCache::new();
+        cache.update(Book::new(TokenId::from("yes")));
+
+        save_cache(&config, &cache);
+        let warmed = BookCache::new();
+        warm_cache(&config, &warmed);
+
+        assert_eq!(warmed.len(), 1);
+    }
+
+    #[test]
+    fn replay_does_not_overwrite_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("books.bin");
+        let mut config = Config::default();
+        config.snapshot = Some(SnapshotConfig::new(path.to_string_lossy()));
+        config.replay = Some(crate::infrastructure::config::replay::ReplayConfig {
+            path: "books.jsonl".to_string(),
+            speed: 0.0,
+        });
+        let cache = BookCache::new();
+        cache.update(Book::new(TokenId::from("yes")));
+
+        save_cache(&config, &cache);
+
+        assert!(!path.exists());
+    }
+}
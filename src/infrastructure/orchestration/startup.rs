@@ -5,19 +5,23 @@ use std::sync::Arc;
 use tracing::{debug, info, warn};
 
 use crate::application::strategy::registry::StrategyRegistry;
-use crate::domain::{id::TokenId, market::MarketRegistry};
+use crate::domain::{id::TokenId, market::MarketRegistry, score::RankedMarket};
 use crate::error::Result;
 use crate::infrastructure::config::settings::{Config, ExchangeSpecificConfig};
 use crate::infrastructure::exchange::factory::ExchangeFactory;
+use crate::infrastructure::subscription::scorer::WeightedMarketScorer;
 use crate::port::inbound::strategy::StrategyEngine;
+use crate::port::outbound::filter::MarketScorer;
 use crate::port::outbound::inference::MarketSummary;
 
 /// Startup artifacts needed by the runtime event loop.
 pub(crate) struct PreparedMarkets {
     pub registry: Arc<MarketRegistry>,
-    pub strategies: Arc<StrategyRegistry>,
+    pub strategies: StrategyRegistry,
     pub token_ids: Vec<TokenId>,
     pub market_summaries: Vec<MarketSummary>,
+    /// Registered markets ordered by descending score.
+    pub ranked: Vec<RankedMarket>,
 }
 
 /// Fetch, filter, parse, and wire markets into strategy runtime state.
@@ -30,9 +34,7 @@ pub(crate) async fn prepare_markets(
         "Strategies loaded"
     );
 
-    let max_markets = match &config.exchange_config {
-        ExchangeSpecificConfig::Polymarket(pm_config) => pm_config.market_filter.max_markets,
-    };
+    let max_markets = max_markets(config);
 
     let market_fetcher = ExchangeFactory::create_market_fetcher(config);
     info!(
@@ -92,6 +94,28 @@ pub(crate) async fn prepare_markets(
         );
     }
 
+    let scorer = WeightedMarketScorer::new(&config.scoring, config.exchange.name());
+    let mut ranked: Vec<RankedMarket> = scorer
+        .score_batch(&market_infos)
+        .await?
+        .into_iter()
+        .filter_map(|score| {
+            let market = registry.get_by_market_id(score.market_id())?;
+            Some(RankedMarket {
+                question: market.question().to_string(),
+                score,
+            })
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.score.cmp(&a.score));
+    if let Some(top) = ranked.first() {
+        info!(
+            market_id = %top.score.market_id(),
+            score = top.score.composite(),
+            "Markets scored"
+        );
+    }
+
     let market_summaries: Vec<MarketSummary> = registry
         .markets()
         .iter()
@@ -112,12 +136,25 @@ pub(crate) async fn prepare_markets(
 
     let registry = Arc::new(registry);
     strategies.set_registry(Arc::clone(&registry));
-    let strategies = Arc::new(strategies);
 
     Ok(Some(PreparedMarkets {
         registry,
         strategies,
         token_ids,
         market_summaries,
+        ranked,
     }))
 }
+
+/// Maximum number of markets to fetch from the configured exchange.
+pub(crate) fn max_markets(config: &Config) -> usize {
+    match &config.exchange_config {
+        ExchangeSpecificConfig::Polymarket(pm_config) => pm_config.market_filter.max_markets,
+        #[cfg(feature = "kalshi")]
+        ExchangeSpecificConfig::Kalshi(kalshi_config) => kalshi_config.market_filter.max_markets,
+        #[cfg(feature = "manifold")]
+        ExchangeSpecificConfig::Manifold(manifold_config) => {
+            manifold_config.market_filter.max_markets
+        }
+    }
+}
@@ -1,11 +1,17 @@
 //! Market stream setup.
 
+use std::time::Duration;
+
+use tokio::time::{timeout_at, Instant};
+
+use crate::adapter::outbound::replay::ReplayDataStream;
+use crate::application::cache::book::BookCache;
 use crate::domain::id::TokenId;
 use crate::error::Result;
 use crate::infrastructure::config::settings::Config;
 use crate::infrastructure::exchange::factory::ExchangeFactory;
 use crate::infrastructure::exchange::reconnecting::ReconnectingDataStream;
-use crate::port::outbound::exchange::MarketDataStream;
+use crate::port::outbound::exchange::{MarketDataStream, MarketEvent};
 use tracing::info;
 
 /// Build and connect the market stream with optional pooling.
@@ -13,20 +19,42 @@ pub(crate) async fn create_connected_stream(
     config: &Config,
     token_ids: &[TokenId],
 ) -> Result<Box<dyn MarketDataStream>> {
-    let mut data_stream: Box<dyn MarketDataStream> =
-        if let Some(pool) = ExchangeFactory::create_connection_pool(config)? {
-            info!(exchange = pool.exchange_name(), "Using connection pool");
-            Box::new(pool)
-        } else {
-            info!("Using single connection");
-            let inner = ExchangeFactory::create_data_stream(config);
-            Box::new(ReconnectingDataStream::new(
-                inner,
-                config.reconnection.clone(),
-            ))
-        };
+    let mut data_stream: Box<dyn MarketDataStream> = if let Some(replay) = &config.replay {
+        info!(path = %replay.path, "Replaying recorded market data");
+        Box::new(ReplayDataStream::new(&replay.path, replay.speed))
+    } else if let Some(pool) = ExchangeFactory::create_connection_pool(config)? {
+        info!(exchange = pool.exchange_name(), "Using connection pool");
+        Box::new(pool)
+    } else {
+        info!("Using single connection");
+        let inner = ExchangeFactory::create_data_stream(config);
+        Box::new(ReconnectingDataStream::new(
+            inner,
+            config.reconnection.clone(),
+        ))
+    };
 
     data_stream.connect().await?;
     data_stream.subscribe(token_ids).await?;
     Ok(data_stream)
 }
+
+/// Apply book snapshots and deltas from `data_stream` to a fresh cache
+/// until `duration` elapses or the stream ends.
+pub(crate) async fn collect_books(
+    data_stream: &mut dyn MarketDataStream,
+    duration: Duration,
+) -> BookCache {
+    let cache = BookCache::new();
+    let deadline = Instant::now() + duration;
+
+    while let Ok(Some(event)) = timeout_at(deadline, data_stream.next_event()).await {
+        match event {
+            MarketEvent::BookSnapshot { book, .. } => cache.update(book),
+            MarketEvent::BookDelta { token_id, book } => cache.apply_delta(&token_id, &book),
+            _ => {}
+        }
+    }
+
+    cache
+}
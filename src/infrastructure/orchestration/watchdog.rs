@@ -0,0 +1,157 @@
+//! Market stream stall detection.
+//!
+//! A WebSocket can stay open while the exchange stops sending data, and the
+//! stream never notices because nothing fails. [`StreamWatchdog`] tracks
+//! when the runtime loop last received a market event. Once nothing has
+//! arrived for `reconnection.idle_timeout_ms`, the loop replaces the stream
+//! with a freshly connected one.
+
+use std::time::Duration;
+
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+use super::stream;
+use crate::domain::id::TokenId;
+use crate::infrastructure::config::settings::Config;
+use crate::port::outbound::exchange::{DisconnectReason, MarketDataStream};
+use crate::port::outbound::notifier::{Event, NotifierRegistry};
+
+/// Time since the last market event, checked against the idle timeout.
+pub(crate) struct StreamWatchdog {
+    /// Idle time after which the stream counts as stalled; `None` disables
+    /// the watchdog.
+    idle_timeout: Option<Duration>,
+    /// When the last event arrived, or the stream was last (re)started.
+    last_event: Instant,
+}
+
+impl StreamWatchdog {
+    /// Create a watchdog for `config`.
+    ///
+    /// Disabled when `idle_timeout_ms` is zero or when replaying recorded
+    /// data, whose gaps are part of the recording.
+    pub(crate) fn new(config: &Config) -> Self {
+        let idle_timeout = config
+            .reconnection
+            .idle_timeout()
+            .filter(|_| config.replay.is_none());
+        Self {
+            idle_timeout,
+            last_event: Instant::now(),
+        }
+    }
+
+    /// Whether stall detection is enabled.
+    pub(crate) const fn is_enabled(&self) -> bool {
+        self.idle_timeout.is_some()
+    }
+
+    /// Record that the stream is alive.
+    pub(crate) fn feed(&mut self) {
+        self.last_event = Instant::now();
+    }
+
+    /// How long the stream has been silent.
+    pub(crate) fn idle_for(&self) -> Duration {
+        self.last_event.elapsed()
+    }
+
+    /// Wait until the stream has been idle for the timeout.
+    ///
+    /// Never completes when the watchdog is disabled.
+    pub(crate) async fn stalled(&self) {
+        match self.idle_timeout {
+            Some(idle_timeout) => tokio::time::sleep_until(self.last_event + idle_timeout).await,
+            None => std::future::pending().await,
+        }
+    }
+}
+
+/// Replace a stalled stream with a newly connected one subscribed to
+/// `token_ids`, alerting the configured notifiers.
+///
+/// If the new stream cannot connect, the stalled one is kept and the
+/// watchdog tries again after another idle timeout.
+pub(crate) async fn restart_stream(
+    config: &Config,
+    data_stream: &mut Box<dyn MarketDataStream>,
+    token_ids: &[TokenId],
+    watchdog: &mut StreamWatchdog,
+    notifiers: &NotifierRegistry,
+) {
+    let idle_secs = watchdog.idle_for().as_secs();
+    warn!(
+        idle_secs,
+        exchange = data_stream.exchange_name(),
+        "Market data stream stalled, reconnecting"
+    );
+    notifiers.notify_all(Event::StreamDisconnected {
+        cause: DisconnectReason::Stalled,
+        reason: format!("no market events for {idle_secs}s"),
+    });
+
+    match stream::create_connected_stream(config, token_ids).await {
+        Ok(stream) => {
+            *data_stream = stream;
+            info!(tokens = token_ids.len(), "Market data stream restarted");
+        }
+        Err(error) => warn!(error = %error, "Restarting stalled market data stream failed"),
+    }
+    watchdog.feed();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::config::replay::ReplayConfig;
+
+    #[test]
+    fn enabled_by_default() {
+        let watchdog = StreamWatchdog::new(&Config::default());
+        assert!(watchdog.is_enabled());
+    }
+
+    #[test]
+    fn zero_timeout_disables_watchdog() {
+        let mut config = Config::default();
+        config.reconnection.idle_timeout_ms = 0;
+        assert!(!StreamWatchdog::new(&config).is_enabled());
+    }
+
+    #[test]
+    fn replay_disables_watchdog() {
+        let config = Config {
+            replay: Some(ReplayConfig {
+                path: "day.jsonl".to_string(),
+                speed: 1.0,
+            }),
+            ..Default::default()
+        };
+        assert!(!StreamWatchdog::new(&config).is_enabled());
+    }
+
+    #[tokio::test]
+    async fn stalls_after_idle_timeout() {
+        let mut config = Config::default();
+        config.reconnection.idle_timeout_ms = 20;
+        let mut watchdog = StreamWatchdog::new(&config);
+
+        let stalled = tokio::time::timeout(Duration::from_secs(1), watchdog.stalled()).await;
+        assert!(stalled.is_ok());
+        assert!(watchdog.idle_for() >= Duration::from_millis(20));
+
+        watchdog.feed();
+        assert!(watchdog.idle_for() < Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn disabled_watchdog_never_stalls() {
+        let mut config = Config::default();
+        config.reconnection.idle_timeout_ms = 0;
+        let watchdog = StreamWatchdog::new(&config);
+
+        let stalled = tokio::time::timeout(Duration::from_millis(20), watchdog.stalled()).await;
+        assert!(stalled.is_err());
+    }
+}
@@ -2,3 +2,4 @@
 
 pub mod manager;
 pub mod priority;
+pub mod scorer;
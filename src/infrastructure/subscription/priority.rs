@@ -32,7 +32,8 @@ use state::write_lock_or_recover;
 ///
 /// Markets are ordered by their composite score (highest priority first).
 /// When expanding, the highest-scoring markets are selected. When contracting,
-/// the most recently added tokens are removed (LIFO) for simplicity.
+/// the most recently added tokens are removed (LIFO) for simplicity, and
+/// markets left without any active token return to the queue.
 ///
 /// # Example
 ///
@@ -67,6 +68,10 @@ pub struct PrioritySubscriptionManager {
     /// Mapping from market ID to its associated tokens.
     market_tokens: RwLock<HashMap<MarketId, Vec<TokenId>>>,
 
+    /// Latest score seen for each enqueued market, used to requeue
+    /// markets dropped by contraction.
+    scores: RwLock<HashMap<MarketId, MarketScore>>,
+
     /// Maximum number of subscriptions allowed.
     max_subscriptions: usize,
 }
@@ -94,6 +99,7 @@ impl PrioritySubscriptionManager {
             active_markets: RwLock::new(HashSet::new()),
             active_tokens: RwLock::new(Vec::new()),
             market_tokens: RwLock::new(HashMap::new()),
+            scores: RwLock::new(HashMap::new()),
             max_subscriptions,
         }
     }
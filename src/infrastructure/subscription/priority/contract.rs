@@ -2,10 +2,10 @@ use std::collections::HashSet;
 
 use tracing::{debug, info};
 
-use crate::domain::id::TokenId;
+use crate::domain::id::{MarketId, TokenId};
 use crate::error::Result;
 
-use super::state::{read_lock, write_lock};
+use super::state::{read_lock, read_lock_or_recover, write_lock, write_lock_or_recover};
 use super::PrioritySubscriptionManager;
 
 impl PrioritySubscriptionManager {
@@ -55,6 +55,23 @@ impl PrioritySubscriptionManager {
             "Contracted subscriptions"
         );
 
+        drop(market_tokens);
+        drop(active_markets);
+        drop(active_tokens);
+        self.requeue_markets(&markets_to_remove);
+
         Ok(removed_tokens)
     }
+
+    /// Return dropped markets to the pending queue with their last known score.
+    fn requeue_markets(&self, market_ids: &[MarketId]) {
+        let requeued: Vec<_> = {
+            let scores = read_lock_or_recover(&self.scores);
+            market_ids
+                .iter()
+                .filter_map(|market_id| scores.get(market_id).cloned())
+                .collect()
+        };
+        write_lock_or_recover(&self.pending).extend(requeued);
+    }
 }
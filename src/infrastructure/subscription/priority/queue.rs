@@ -10,6 +10,7 @@ impl PrioritySubscriptionManager {
     pub(super) fn enqueue_markets(&self, markets: Vec<MarketScore>) {
         let mut pending = write_lock_or_recover(&self.pending);
         let active_markets = read_lock_or_recover(&self.active_markets);
+        let mut scores = write_lock_or_recover(&self.scores);
 
         for market in markets {
             // Skip markets that are already subscribed.
@@ -26,6 +27,7 @@ impl PrioritySubscriptionManager {
                 score = market.composite(),
                 "Enqueueing market for subscription"
             );
+            scores.insert(market.market_id().clone(), market.clone());
             pending.push(market);
         }
     }
@@ -281,6 +281,24 @@ async fn contract_returns_empty_when_no_active() {
     assert!(removed.is_empty());
 }
 
+#[tokio::test]
+async fn contract_requeues_dropped_markets() {
+    let manager = PrioritySubscriptionManager::new(100);
+
+    manager.register_market_tokens(make_market_id("market-1"), vec![make_token_id("token-1")]);
+
+    manager.enqueue(vec![make_market_score("market-1", 0.5)]);
+    manager.expand(1).await.unwrap();
+    manager.contract(1).await.unwrap();
+
+    assert_eq!(manager.pending_count(), 1);
+
+    let added = manager.expand(1).await.unwrap();
+
+    assert_eq!(added, vec![make_token_id("token-1")]);
+    assert!(manager.is_subscribed(&make_market_id("market-1")));
+}
+
 // --- is_subscribed tests ---
 
 #[test]
@@ -0,0 +1,291 @@
+//! Exchange-agnostic weighted market scorer.
+//!
+//! [`WeightedMarketScorer`] ranks markets from the fields every exchange
+//! reports in [`MarketInfo`]: 24-hour volume, liquidity, outcome prices, and
+//! close time. Each factor is normalized to 0.0-1.0 and combined with the
+//! weights from [`ScoringConfig`].
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{id::MarketId, score::MarketScore, score::ScoreFactors, score::ScoreWeights};
+use crate::error::Result;
+use crate::infrastructure::config::scoring::ScoringConfig;
+use crate::port::{outbound::exchange::MarketInfo, outbound::filter::MarketScorer};
+
+/// Score used when a market does not report the data a factor needs.
+const NEUTRAL: f64 = 0.5;
+
+/// Price-sum deviation at which spread tightness bottoms out.
+const MAX_PRICE_DEVIATION: f64 = 0.10;
+
+/// Weighted multi-factor scorer for subscription prioritization.
+///
+/// Factors map onto [`ScoreFactors`] as follows: volume is `activity`,
+/// liquidity is `liquidity`, spread tightness is `spread`, and close-time
+/// proximity is `time_to_close`. When every outcome reports its own
+/// liquidity, the liquidity factor is scaled by
+/// [`MarketInfo::outcome_liquidity_balance`], so a market with one deep book
+/// and several empty ones ranks below a uniformly liquid one.
+#[derive(Debug, Clone)]
+pub struct WeightedMarketScorer {
+    weights: ScoreWeights,
+    volume_reference: f64,
+    liquidity_reference: f64,
+    close_horizon_secs: f64,
+    exchange: &'static str,
+}
+
+impl WeightedMarketScorer {
+    /// Create a scorer from scoring configuration.
+    #[must_use]
+    pub fn new(config: &ScoringConfig, exchange: &'static str) -> Self {
+        Self {
+            weights: ScoreWeights::from(&config.weights),
+            volume_reference: config.volume_reference,
+            liquidity_reference: config.liquidity_reference,
+            close_horizon_secs: config.close_horizon_secs as f64,
+            exchange,
+        }
+    }
+
+    /// Compute the normalized factors for a market as of `now`.
+    #[must_use]
+    pub fn factors_at(&self, market: &MarketInfo, now: DateTime<Utc>) -> ScoreFactors {
+        let liquidity = log_scaled(market.liquidity, self.liquidity_reference)
+            * market.outcome_liquidity_balance().unwrap_or(1.0);
+        ScoreFactors::new(
+            liquidity,
+            spread_tightness(market),
+            0.0,
+            0.0,
+            log_scaled(market.volume_24h, self.volume_reference),
+        )
+        .with_time_to_close(self.time_to_close(market.close_time, now))
+    }
+
+    /// Score a market as of `now`.
+    #[must_use]
+    pub fn score_at(&self, market: &MarketInfo, now: DateTime<Utc>) -> MarketScore {
+        MarketScore::from_factors(
+            MarketId::from(market.id.as_str()),
+            self.factors_at(market, now),
+            &self.weights,
+        )
+    }
+
+    /// Markets closing sooner score higher; past the horizon scores zero.
+    fn time_to_close(&self, close_time: Option<DateTime<Utc>>, now: DateTime<Utc>) -> f64 {
+        let Some(close_time) = close_time else {
+            return NEUTRAL;
+        };
+        let remaining = (close_time - now).num_seconds();
+        if remaining <= 0 {
+            return 0.0;
+        }
+        1.0 - (remaining as f64 / self.close_horizon_secs).min(1.0)
+    }
+}
+
+/// Log-scale a USD amount against a reference, capped at 1.0.
+fn log_scaled(value: Option<f64>, reference: f64) -> f64 {
+    match value {
+        Some(value) if value > 0.0 => (value.ln_1p() / reference.ln_1p()).min(1.0),
+        Some(_) => 0.0,
+        None => NEUTRAL,
+    }
+}
+
+/// Spread tightness from how closely outcome prices sum to 1.0.
+///
+/// Quoted prices on a tight book sum to roughly 1.0; wide spreads push the
+/// sum away from it.
+fn spread_tightness(market: &MarketInfo) -> f64 {
+    let prices: Vec<f64> = market.outcomes.iter().filter_map(|o| o.price).collect();
+    if prices.is_empty() || prices.len() != market.outcomes.len() {
+        return NEUTRAL;
+    }
+
+    let deviation = (prices.iter().sum::<f64>() - 1.0).abs();
+    1.0 - (deviation / MAX_PRICE_DEVIATION).min(1.0)
+}
+
+#[async_trait]
+impl MarketScorer for WeightedMarketScorer {
+    async fn score(&self, market: &MarketInfo) -> Result<MarketScore> {
+        Ok(self.score_at(market, Utc::now()))
+    }
+
+    fn weights(&self) -> &ScoreWeights {
+        &self.weights
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        self.exchange
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::outbound::exchange::OutcomeInfo;
+    use chrono::Duration;
+
+    const TOLERANCE: f64 = 1e-9;
+
+    fn scorer() -> WeightedMarketScorer {
+        WeightedMarketScorer::new(&ScoringConfig::default(), "test")
+    }
+
+    fn market(prices: &[Option<f64>]) -> MarketInfo {
+        MarketInfo {
+            id: "market".to_string(),
+            question: "Will it happen?".to_string(),
+            outcomes: prices
+                .iter()
+                .enumerate()
+                .map(|(i, price)| OutcomeInfo {
+                    token_id: format!("token-{i}"),
+                    name: format!("Outcome {i}"),
+                    price: *price,
+                    volume_24h: None,
+                    liquidity: None,
+                })
+                .collect(),
+            active: true,
+            volume_24h: None,
+            liquidity: None,
+            close_time: None,
+        }
+    }
+
+    #[test]
+    fn missing_data_scores_neutral() {
+        let factors = scorer().factors_at(&market(&[None, None]), Utc::now());
+
+        assert!((factors.activity - NEUTRAL).abs() < TOLERANCE);
+        assert!((factors.liquidity - NEUTRAL).abs() < TOLERANCE);
+        assert!((factors.spread - NEUTRAL).abs() < TOLERANCE);
+        assert!((factors.time_to_close - NEUTRAL).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn volume_is_log_scaled_and_capped() {
+        let scorer = scorer();
+        let mut info = market(&[]);
+
+        info.volume_24h = Some(100_000.0);
+        assert!((scorer.factors_at(&info, Utc::now()).activity - 1.0).abs() < TOLERANCE);
+
+        info.volume_24h = Some(1_000_000.0);
+        assert!((scorer.factors_at(&info, Utc::now()).activity - 1.0).abs() < TOLERANCE);
+
+        info.volume_24h = Some(1_000.0);
+        let partial = scorer.factors_at(&info, Utc::now()).activity;
+        assert!(partial > 0.5 && partial < 1.0);
+
+        info.volume_24h = Some(0.0);
+        assert!(scorer.factors_at(&info, Utc::now()).activity.abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn spread_tightness_falls_with_price_deviation() {
+        let scorer = scorer();
+        let tight = scorer.factors_at(&market(&[Some(0.5), Some(0.5)]), Utc::now());
+        let wide = scorer.factors_at(&market(&[Some(0.5), Some(0.45)]), Utc::now());
+        let blown = scorer.factors_at(&market(&[Some(0.4), Some(0.4)]), Utc::now());
+
+        assert!((tight.spread - 1.0).abs() < TOLERANCE);
+        assert!((wide.spread - 0.5).abs() < TOLERANCE);
+        assert!(blown.spread.abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn sooner_close_scores_higher() {
+        let scorer = scorer();
+        let now = Utc::now();
+        let mut info = market(&[]);
+
+        info.close_time = Some(now + Duration::days(3));
+        let soon = scorer.factors_at(&info, now).time_to_close;
+        info.close_time = Some(now + Duration::days(20));
+        let later = scorer.factors_at(&info, now).time_to_close;
+        info.close_time = Some(now + Duration::days(60));
+        let beyond = scorer.factors_at(&info, now).time_to_close;
+        info.close_time = Some(now - Duration::days(1));
+        let closed = scorer.factors_at(&info, now).time_to_close;
+
+        assert!((soon - 0.9).abs() < TOLERANCE);
+        assert!(soon > later);
+        assert!(beyond.abs() < TOLERANCE);
+        assert!(closed.abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn composite_applies_configured_weights() {
+        let mut config = ScoringConfig::default();
+        config.weights.volume = 1.0;
+        config.weights.liquidity = 0.0;
+        config.weights.spread = 0.0;
+        config.weights.time_to_close = 0.0;
+        let scorer = WeightedMarketScorer::new(&config, "test");
+
+        let mut info = market(&[Some(0.4), Some(0.4)]);
+        info.volume_24h = Some(100_000.0);
+        let score = scorer.score_at(&info, Utc::now());
+
+        assert!((score.composite() - 1.0).abs() < TOLERANCE);
+    }
+
+    #[tokio::test]
+    async fn higher_volume_and_liquidity_rank_first() {
+        let scorer = scorer();
+        let mut busy = market(&[Some(0.5), Some(0.5)]);
+        busy.volume_24h = Some(80_000.0);
+        busy.liquidity = Some(40_000.0);
+        let mut quiet = market(&[Some(0.5), Some(0.5)]);
+        quiet.volume_24h = Some(500.0);
+        quiet.liquidity = Some(200.0);
+
+        let busy = scorer.score(&busy).await.unwrap();
+        let quiet = scorer.score(&quiet).await.unwrap();
+
+        assert!(busy > quiet);
+    }
+
+    fn with_outcome_liquidity(liquidity: &[f64]) -> MarketInfo {
+        let mut info = market(&vec![Some(0.25); liquidity.len()]);
+        info.liquidity = Some(liquidity.iter().sum());
+        for (outcome, &depth) in info.outcomes.iter_mut().zip(liquidity) {
+            outcome.liquidity = Some(depth);
+        }
+        info
+    }
+
+    #[test]
+    fn uniform_outcome_liquidity_ranks_above_lopsided() {
+        let scorer = scorer();
+        let uniform = with_outcome_liquidity(&[2_500.0, 2_500.0, 2_500.0, 2_500.0]);
+        let lopsided = with_outcome_liquidity(&[9_700.0, 100.0, 100.0, 100.0]);
+
+        let uniform = scorer.factors_at(&uniform, Utc::now()).liquidity;
+        let lopsided = scorer.factors_at(&lopsided, Utc::now()).liquidity;
+
+        assert!(uniform > lopsided);
+        assert!(lopsided < 0.05);
+    }
+
+    #[test]
+    fn missing_outcome_liquidity_leaves_factor_unscaled() {
+        let scorer = scorer();
+        let mut partial = with_outcome_liquidity(&[9_700.0, 100.0]);
+        partial.outcomes[1].liquidity = None;
+        let mut market_only = market(&[Some(0.5), Some(0.5)]);
+        market_only.liquidity = partial.liquidity;
+
+        let partial = scorer.factors_at(&partial, Utc::now()).liquidity;
+        let market_only = scorer.factors_at(&market_only, Utc::now()).liquidity;
+
+        assert!((partial - market_only).abs() < TOLERANCE);
+    }
+}
@@ -80,6 +80,29 @@ pub enum SweepOutcome {
     },
 }
 
+/// Token and gas balances for CLI display.
+#[derive(Debug, Clone)]
+pub struct WalletBalances {
+    /// Name of the exchange.
+    pub exchange: String,
+    /// Wallet address holding the balances.
+    pub wallet_address: String,
+    /// Spendable USDC balance in dollars.
+    pub usdc: Decimal,
+    /// Symbol of the native gas token (e.g., "POL").
+    pub gas_token: String,
+    /// Native gas token balance in whole tokens.
+    pub gas: Decimal,
+    /// Gas balance below which on-chain operations may fail.
+    pub min_gas: Decimal,
+}
+
+/// Minimum POL balance to cover approval and transfer transactions.
+#[cfg(feature = "polymarket")]
+fn polymarket_min_gas() -> Decimal {
+    Decimal::new(1, 1) // 0.1 POL
+}
+
 /// Wallet service providing CLI operations.
 ///
 /// Dispatches to the appropriate exchange-specific implementation
@@ -120,6 +143,10 @@ impl WalletService {
     pub async fn get_approval_status(config: &Config) -> Result<WalletApprovalStatus> {
         match config.exchange {
             Exchange::Polymarket => Self::get_polymarket_status(config).await,
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => Err(Self::unsupported_exchange(config.exchange)),
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => Err(Self::unsupported_exchange(config.exchange)),
         }
     }
 
@@ -139,6 +166,10 @@ impl WalletService {
     pub async fn approve(config: &Config, amount: Decimal) -> Result<ApprovalOutcome> {
         match config.exchange {
             Exchange::Polymarket => Self::approve_polymarket(config, amount).await,
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => Err(Self::unsupported_exchange(config.exchange)),
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => Err(Self::unsupported_exchange(config.exchange)),
         }
     }
 
@@ -150,6 +181,10 @@ impl WalletService {
     pub fn wallet_address(config: &Config) -> Result<String> {
         match config.exchange {
             Exchange::Polymarket => Self::polymarket_wallet_address(config),
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => Err(Self::unsupported_exchange(config.exchange)),
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => Err(Self::unsupported_exchange(config.exchange)),
         }
     }
 
@@ -162,6 +197,26 @@ impl WalletService {
     pub async fn usdc_balance(config: &Config) -> Result<Decimal> {
         match config.exchange {
             Exchange::Polymarket => Self::polymarket_usdc_balance(config).await,
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => Err(Self::unsupported_exchange(config.exchange)),
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => Err(Self::unsupported_exchange(config.exchange)),
+        }
+    }
+
+    /// Get USDC and native gas balances for the configured exchange.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wallet is not configured or either balance
+    /// query fails.
+    pub async fn balances(config: &Config) -> Result<WalletBalances> {
+        match config.exchange {
+            Exchange::Polymarket => Self::polymarket_balances(config).await,
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => Err(Self::unsupported_exchange(config.exchange)),
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => Err(Self::unsupported_exchange(config.exchange)),
         }
     }
 
@@ -176,9 +231,26 @@ impl WalletService {
     pub async fn sweep_usdc(config: &Config, to: &str) -> Result<SweepOutcome> {
         match config.exchange {
             Exchange::Polymarket => Self::sweep_polymarket(config, to).await,
+            #[cfg(feature = "kalshi")]
+            Exchange::Kalshi => Err(Self::unsupported_exchange(config.exchange)),
+            #[cfg(feature = "manifold")]
+            Exchange::Manifold => Err(Self::unsupported_exchange(config.exchange)),
         }
     }
 
+    /// Build the error returned for exchanges without wallet support.
+    #[cfg(any(feature = "kalshi", feature = "manifold"))]
+    fn unsupported_exchange(exchange: Exchange) -> crate::error::Error {
+        crate::error::ConfigError::InvalidValue {
+            field: "exchange",
+            reason: format!(
+                "wallet operations are not supported for {}",
+                exchange.name()
+            ),
+        }
+        .into()
+    }
+
     // --- Polymarket implementation ---
 
     #[cfg(feature = "polymarket")]
@@ -265,6 +337,31 @@ impl WalletService {
         .into())
     }
 
+    #[cfg(feature = "polymarket")]
+    async fn polymarket_balances(config: &Config) -> Result<WalletBalances> {
+        let runtime = Self::polymarket_runtime_config(config)?;
+        let approval = PolymarketApproval::new(&runtime)?;
+        let (usdc, gas) = tokio::try_join!(approval.usdc_balance(), approval.native_balance())?;
+
+        Ok(WalletBalances {
+            exchange: "Polymarket".to_string(),
+            wallet_address: approval.wallet_address().to_string(),
+            usdc,
+            gas_token: "POL".to_string(),
+            gas,
+            min_gas: polymarket_min_gas(),
+        })
+    }
+
+    #[cfg(not(feature = "polymarket"))]
+    async fn polymarket_balances(_config: &Config) -> Result<WalletBalances> {
+        Err(crate::error::ConfigError::InvalidValue {
+            field: "exchange",
+            reason: "Polymarket support requires the 'polymarket' feature".to_string(),
+        }
+        .into())
+    }
+
     #[cfg(feature = "polymarket")]
     async fn sweep_polymarket(config: &Config, to: &str) -> Result<SweepOutcome> {
         use alloy_primitives::Address;
@@ -456,6 +553,16 @@ mod tests {
             assert!(err.to_string().contains("polymarket"));
         }
 
+        #[tokio::test]
+        async fn balances_errors_without_polymarket() {
+            let config = minimal_config();
+            let result = WalletService::balances(&config).await;
+
+            assert!(result.is_err());
+            let err = result.unwrap_err();
+            assert!(err.to_string().contains("polymarket"));
+        }
+
         #[tokio::test]
         async fn sweep_usdc_errors_without_polymarket() {
             let config = minimal_config();
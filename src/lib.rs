@@ -37,5 +37,4 @@ pub mod error;
 pub mod infrastructure;
 pub mod port;
 
-#[cfg(any(test, feature = "testkit"))]
 pub mod testkit;
@@ -47,12 +47,19 @@ async fn main() {
             cli::status::execute(&args.db, args.config.as_deref());
             Ok(())
         }
+        Commands::Positions(args) => cli::positions::execute(&args).await,
         Commands::Statistics(cmd) => match cmd {
             StatsCommand::Today(args) => cli::stats::handler::execute_today(&args.db),
             StatsCommand::Week(args) => cli::stats::handler::execute_week(&args.db),
             StatsCommand::History(args) => {
                 cli::stats::handler::execute_history(&args.db, args.days)
             }
+            StatsCommand::Range(args) => cli::stats::handler::execute_range(&args),
+            StatsCommand::Strategy(args) => {
+                cli::stats::handler::execute_strategy(&args.db, args.days)
+            }
+            StatsCommand::Opportunities(args) => cli::stats::handler::execute_opportunities(&args),
+            StatsCommand::WhatIf(args) => cli::stats::handler::execute_whatif(&args),
             StatsCommand::Export(args) => {
                 cli::stats::handler::execute_export(&args.db, args.days, args.output.as_deref())
             }
@@ -62,6 +69,10 @@ async fn main() {
             ConfigCommand::Init(args) => cli::config::execute_init(&args.path, args.force),
             ConfigCommand::Show(args) => cli::config::execute_show(&args.config),
             ConfigCommand::Validate(args) => cli::config::execute_validate(&args.config),
+            ConfigCommand::Diff(args) => cli::config::execute_diff(&args.config),
+            ConfigCommand::Migrate(args) => {
+                cli::config::execute_migrate(&args.config, args.dry_run)
+            }
         },
         Commands::Provision(cmd) => cli::provision::command::execute(cmd).await,
         Commands::Check(cmd) => match cmd {
@@ -74,13 +85,24 @@ async fn main() {
             CheckCommand::Telegram(args) => {
                 cli::check::telegram::execute_telegram(&args.config).await
             }
+            CheckCommand::BookParse(args) => {
+                cli::check::book::execute_book_parse(&args.config, &args.fixture)
+            }
+            CheckCommand::Markets(args) => {
+                cli::check::markets::execute_markets(&args.config, args.markets, args.duration)
+                    .await
+            }
         },
+        Commands::Doctor(args) => cli::doctor::execute(&args.config).await,
         Commands::Wallet(cmd) => match cmd {
             WalletCommand::Approve(args) => {
                 cli::wallet::approve::execute_approve(&args.config, args.amount, args.yes).await
             }
             WalletCommand::Status(args) => cli::wallet::status::execute_status(&args.config).await,
             WalletCommand::Address(args) => cli::wallet::address::execute_address(&args.config),
+            WalletCommand::Balance(args) => {
+                cli::wallet::balance::execute_balance(&args.config).await
+            }
             WalletCommand::Sweep(args) => {
                 cli::wallet::sweep::execute_sweep(
                     &args.config,
@@ -91,12 +113,17 @@ async fn main() {
                 )
                 .await
             }
+            WalletCommand::ImportKeyring(args) => {
+                cli::wallet::import_keyring::execute_import_keyring(&args.config)
+            }
         },
         Commands::Init(args) => cli::init::execute(args.path, args.force),
         Commands::Strategies(cmd) => match cmd {
             StrategyCommand::List => cli::strategy::list(),
             StrategyCommand::Explain { name } => cli::strategy::explain(&name),
         },
+        Commands::Bench(args) => cli::bench::execute(&args),
+        Commands::Simulate(args) => cli::simulate::execute(&args).await,
     };
 
     if let Err(e) = result {
@@ -129,8 +156,8 @@ fn render_error(error: &edgelord::error::Error) {
         Error::Execution(ExecutionError::AuthFailed(_)) => {
             output::hint("verify your API credentials in the config file");
         }
-        Error::Risk(RiskError::CircuitBreakerActive { .. }) => {
-            output::hint("use `edgelord status` to check circuit breaker state");
+        Error::Risk(RiskError::TradingStopped { .. }) => {
+            output::hint("use `edgelord status` to check why trading is stopped");
         }
         Error::Risk(RiskError::ExposureLimitExceeded { .. }) => {
             output::hint("adjust max_total_exposure in your risk config or close some positions");
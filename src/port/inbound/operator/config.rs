@@ -49,6 +49,34 @@ pub struct ConfigClusterDetection {
     pub min_gap: Decimal,
 }
 
+/// Default detection thresholds of each built-in strategy.
+#[derive(Debug, Clone)]
+pub struct ConfigStrategyDefaults {
+    /// Strategies enabled when the configuration does not list any.
+    pub enabled: Vec<String>,
+
+    /// Minimum edge per share for single-condition opportunities.
+    pub single_condition_min_edge: Decimal,
+
+    /// Minimum expected profit in USD for single-condition opportunities.
+    pub single_condition_min_profit: Decimal,
+
+    /// Minimum edge per share for market rebalancing opportunities.
+    pub market_rebalancing_min_edge: Decimal,
+
+    /// Minimum expected profit in USD for market rebalancing opportunities.
+    pub market_rebalancing_min_profit: Decimal,
+
+    /// Largest outcome count market rebalancing analyzes.
+    pub market_rebalancing_max_outcomes: usize,
+
+    /// Minimum arbitrage gap for combinatorial opportunities.
+    pub combinatorial_gap_threshold: Decimal,
+
+    /// Whether the combinatorial strategy is enabled by default.
+    pub combinatorial_enabled: bool,
+}
+
 /// Complete configuration projection for operator-facing display.
 ///
 /// Contains all configuration settings needed for the `config show` command.
@@ -104,6 +132,86 @@ pub struct ConfigValidationReport {
     pub warnings: Vec<String>,
 }
 
+/// A setting whose value differs from its default.
+#[derive(Debug, Clone)]
+pub struct ConfigDiffEntry {
+    /// Setting name relative to its section (e.g. `max_slippage`).
+    pub key: String,
+
+    /// Default value, rendered for display.
+    pub default: String,
+
+    /// Configured value, rendered for display.
+    pub value: String,
+}
+
+/// Overridden settings within one configuration section.
+#[derive(Debug, Clone)]
+pub struct ConfigDiffSection {
+    /// Section name (e.g. `risk`), or `general` for top-level settings.
+    pub name: String,
+
+    /// Settings that differ from their defaults.
+    pub entries: Vec<ConfigDiffEntry>,
+}
+
+/// Settings in a configuration file that differ from the defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigDiff {
+    /// Overridden settings grouped by section, in section order.
+    pub sections: Vec<ConfigDiffSection>,
+}
+
+impl ConfigDiff {
+    /// True when the configuration matches the defaults.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+}
+
+/// A change made, or issue found, while migrating a configuration file.
+#[derive(Debug, Clone)]
+pub enum ConfigMigrationChange {
+    /// A legacy setting was moved to its current name.
+    Renamed {
+        /// Old dotted path.
+        from: String,
+        /// New dotted path.
+        to: String,
+    },
+    /// A legacy setting was dropped.
+    Removed {
+        /// Dotted path of the dropped setting.
+        path: String,
+        /// Why the setting is no longer read.
+        reason: String,
+    },
+    /// An unrecognized setting, kept in the file but ignored.
+    Unknown {
+        /// Dotted path of the setting.
+        path: String,
+    },
+    /// A missing setting or section was filled in with its default.
+    Defaulted {
+        /// Dotted path of the setting or section.
+        path: String,
+    },
+}
+
+/// Upgraded configuration file and the changes made to it.
+#[derive(Debug, Clone)]
+pub struct ConfigMigration {
+    /// Upgraded TOML configuration content.
+    pub config_toml: String,
+
+    /// Changes applied and issues found, in order.
+    pub changes: Vec<ConfigMigrationChange>,
+
+    /// Whether the file needed changes; unknown settings alone do not count.
+    pub changed: bool,
+}
+
 /// Configuration use-cases for operator-facing adapters.
 ///
 /// # Thread Safety
@@ -131,4 +239,48 @@ pub trait ConfigurationOperator: Send + Sync {
     ///
     /// Returns an error if the configuration has fatal validation errors.
     fn validate_config(&self, config_toml: &str) -> Result<ConfigValidationReport>;
+
+    /// List settings that differ from the default configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_toml` - Raw TOML configuration content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration cannot be parsed.
+    fn diff_config(&self, config_toml: &str) -> Result<ConfigDiff>;
+
+    /// Upgrade a configuration file written for an older release.
+    ///
+    /// Renames and removes legacy settings, fills in missing settings with
+    /// their defaults, and reports settings this release does not recognize.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_toml` - Raw TOML configuration content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not valid TOML or cannot be
+    /// upgraded into a loadable configuration.
+    fn migrate_config(&self, config_toml: &str) -> Result<ConfigMigration>;
+
+    /// Layer an environment overlay over a base configuration.
+    ///
+    /// Tables are merged at every depth; any other overlay value replaces
+    /// the base value. The result is not validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_toml` - Raw TOML content shared by every environment.
+    /// * `overlay_toml` - Raw TOML content for one environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either input is not valid TOML.
+    fn layer_config(&self, base_toml: &str, overlay_toml: &str) -> Result<String>;
+
+    /// Return the default thresholds of the built-in strategies.
+    fn strategy_defaults(&self) -> ConfigStrategyDefaults;
 }
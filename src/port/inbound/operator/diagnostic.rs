@@ -3,7 +3,10 @@
 //! Defines view models for health checks, connectivity tests, and system
 //! diagnostics exposed through operator interfaces.
 
+use std::time::Duration;
+
 use async_trait::async_trait;
+use rust_decimal::Decimal;
 
 use crate::error::Result;
 
@@ -141,10 +144,121 @@ pub struct TelegramTestReceipt {
     /// Masked bot token (for secure display).
     pub masked_token: String,
 
-    /// Chat ID where the message was sent.
+    /// Chat IDs (comma-separated) where the message was sent.
     pub chat_id: String,
 }
 
+/// Result of checking an exchange book parser against a stored fixture.
+#[derive(Debug, Clone)]
+pub struct BookParseReport {
+    /// Exchange whose parser was exercised.
+    pub exchange: String,
+
+    /// Number of books produced by the parser.
+    pub books_parsed: usize,
+
+    /// Number of books the fixture expects.
+    pub books_expected: usize,
+
+    /// Human-readable descriptions of every difference found.
+    pub mismatches: Vec<String>,
+}
+
+impl BookParseReport {
+    /// Return `true` when the parsed books match the expected snapshot.
+    #[must_use]
+    pub fn is_match(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// Opportunity found during a market scan.
+#[derive(Debug, Clone)]
+pub struct ScannedOpportunity {
+    /// Strategy that detected the opportunity.
+    pub strategy: String,
+
+    /// Market identifier.
+    pub market_id: String,
+
+    /// Market question.
+    pub question: String,
+
+    /// Per-share edge after fees.
+    pub edge: Decimal,
+
+    /// Executable volume in shares.
+    pub volume: Decimal,
+
+    /// Expected profit at the detected volume.
+    pub expected_profit: Decimal,
+
+    /// One-line summary of the arbitrage math.
+    pub explanation: String,
+}
+
+/// Result of a one-shot scan of live markets.
+#[derive(Debug, Clone)]
+pub struct MarketScanReport {
+    /// Exchange name.
+    pub exchange: String,
+
+    /// Markets subscribed after filtering.
+    pub markets: usize,
+
+    /// Tokens subscribed on the market stream.
+    pub tokens: usize,
+
+    /// Tokens with an order book when strategies ran.
+    pub books: usize,
+
+    /// Time spent collecting order books.
+    pub duration: Duration,
+
+    /// Opportunities detected, ordered by expected profit (highest first).
+    pub opportunities: Vec<ScannedOpportunity>,
+}
+
+/// Result of benchmarking strategy detection on synthetic markets.
+#[derive(Debug, Clone)]
+pub struct DetectionBenchReport {
+    /// Synthetic markets generated.
+    pub markets: usize,
+
+    /// Tokens across all synthetic markets.
+    pub tokens: usize,
+
+    /// Strategies that ran.
+    pub strategies: Vec<String>,
+
+    /// Passes made over every market.
+    pub iterations: usize,
+
+    /// Per-market detection calls timed.
+    pub detections: usize,
+
+    /// Opportunities found across all detection calls.
+    pub opportunities: usize,
+
+    /// Wall time spent in detection.
+    pub elapsed: Duration,
+
+    /// Per-market detection calls completed per second.
+    pub detections_per_sec: f64,
+
+    /// Opportunities found per second of detection.
+    pub opportunities_per_sec: f64,
+
+    /// Median per-detection latency.
+    pub p50: Duration,
+
+    /// 95th percentile per-detection latency.
+    pub p95: Duration,
+
+    /// 99th percentile per-detection latency.
+    pub p99: Duration,
+}
+
 /// Diagnostic use-cases for operator-facing adapters.
 ///
 /// Provides health checks, connectivity verification, and system diagnostics.
@@ -220,6 +334,18 @@ pub trait DiagnosticOperator: Send + Sync {
     /// Returns an error if health checks cannot be executed.
     fn health_report(&self, config_toml: &str) -> Result<HealthCheckReport>;
 
+    /// Run the exchange book parser against a golden fixture.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_toml` - Raw TOML configuration content.
+    /// * `fixture_json` - Fixture containing a raw `message` and `expected` books.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration or fixture cannot be parsed.
+    fn check_book_parse(&self, config_toml: &str, fixture_json: &str) -> Result<BookParseReport>;
+
     /// Send a test message via Telegram.
     ///
     /// # Arguments
@@ -230,4 +356,49 @@ pub trait DiagnosticOperator: Send + Sync {
     ///
     /// Returns an error if Telegram is not configured or the message fails.
     async fn send_telegram_test(&self, config_toml: &str) -> Result<TelegramTestReceipt>;
+
+    /// Subscribe to live markets, collect books, and run detection once.
+    ///
+    /// Never places orders.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_toml` - Raw TOML configuration content.
+    /// * `max_markets` - Maximum number of markets to subscribe to.
+    /// * `duration` - How long to collect order books before detection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if configuration, market discovery, or the stream
+    /// connection fails.
+    async fn scan_markets(
+        &self,
+        config_toml: &str,
+        max_markets: usize,
+        duration: Duration,
+    ) -> Result<MarketScanReport>;
+
+    /// Measure strategy detection throughput on synthetic in-memory markets.
+    ///
+    /// Never touches the network or the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_toml` - Raw TOML configuration content, or `None` for defaults.
+    /// * `strategies` - Strategies to enable instead of the configured ones.
+    /// * `markets` - Number of synthetic markets to generate.
+    /// * `iterations` - Passes to make over every market.
+    /// * `seed` - Seed for generating markets and order books.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration cannot be parsed.
+    fn bench_detection(
+        &self,
+        config_toml: Option<&str>,
+        strategies: Option<&[String]>,
+        markets: usize,
+        iterations: usize,
+        seed: u64,
+    ) -> Result<DetectionBenchReport>;
 }
@@ -8,6 +8,7 @@
 //! - [`config`]: Configuration display and validation
 //! - [`diagnostic`]: Health checks and connectivity diagnostics
 //! - [`port`]: Unified operator capability surface
+//! - [`position`]: Open positions and mark-to-market valuation
 //! - [`runtime`]: Runtime control and monitoring
 //! - [`stats`]: Trading statistics and reporting
 //! - [`status`]: Current status snapshots
@@ -16,6 +17,7 @@
 pub mod config;
 pub mod diagnostic;
 pub mod port;
+pub mod position;
 pub mod runtime;
 pub mod stats;
 pub mod status;
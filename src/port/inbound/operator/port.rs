@@ -5,6 +5,7 @@
 
 use super::config::ConfigurationOperator;
 use super::diagnostic::DiagnosticOperator;
+use super::position::PositionOperator;
 use super::runtime::RuntimeOperator;
 use super::stats::StatisticsOperator;
 use super::status::StatusOperator;
@@ -22,6 +23,7 @@ use super::wallet::WalletOperator;
 ///
 /// - [`ConfigurationOperator`]: Configuration display and validation
 /// - [`DiagnosticOperator`]: Health checks and diagnostics
+/// - [`PositionOperator`]: Open positions and mark-to-market valuation
 /// - [`RuntimeOperator`]: Runtime control and monitoring
 /// - [`StatisticsOperator`]: Trading statistics queries
 /// - [`StatusOperator`]: Current status snapshots
@@ -29,6 +31,7 @@ use super::wallet::WalletOperator;
 pub trait OperatorPort:
     ConfigurationOperator
     + DiagnosticOperator
+    + PositionOperator
     + RuntimeOperator
     + StatisticsOperator
     + StatusOperator
@@ -40,6 +43,7 @@ pub trait OperatorPort:
 impl<T> OperatorPort for T where
     T: ConfigurationOperator
         + DiagnosticOperator
+        + PositionOperator
         + RuntimeOperator
         + StatisticsOperator
         + StatusOperator
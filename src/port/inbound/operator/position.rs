@@ -0,0 +1,119 @@
+//! Position projection types for operator-facing adapters.
+//!
+//! Defines view models for open positions valued against current order
+//! book marks through operator interfaces like the CLI.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+use crate::error::Result;
+
+/// Single filled leg of an open position.
+#[derive(Debug, Clone)]
+pub struct PositionLegView {
+    /// Token held by this leg.
+    pub token_id: String,
+
+    /// Order side ("buy" or "sell").
+    pub side: String,
+
+    /// Shares held.
+    pub size: Decimal,
+
+    /// Price paid per share.
+    pub entry_price: Decimal,
+
+    /// Current best bid, if a book was collected for this token.
+    pub mark: Option<Decimal>,
+}
+
+/// Open position valued at current marks.
+#[derive(Debug, Clone)]
+pub struct OpenPositionView {
+    /// Trade identifier in the statistics database.
+    pub trade_id: i32,
+
+    /// Strategy that opened the position.
+    pub strategy: String,
+
+    /// Markets the position spans.
+    pub market_ids: Vec<String>,
+
+    /// RFC 3339 timestamp when the position was opened.
+    pub opened_at: String,
+
+    /// Filled legs of the position.
+    pub legs: Vec<PositionLegView>,
+
+    /// Total cost paid across all legs.
+    pub entry_cost: Decimal,
+
+    /// Value at the best bid of every leg, if all legs could be marked.
+    pub current_value: Option<Decimal>,
+
+    /// Current value minus entry cost, if all legs could be marked.
+    pub unrealized_pnl: Option<Decimal>,
+
+    /// Estimated P&L if the position is held to a $1 settlement.
+    pub settlement_pnl: Decimal,
+}
+
+/// Open positions report.
+#[derive(Debug, Clone, Default)]
+pub struct PositionsReport {
+    /// Whether current order books were fetched to mark positions.
+    pub marked: bool,
+
+    /// Open positions, oldest first.
+    pub positions: Vec<OpenPositionView>,
+}
+
+impl PositionsReport {
+    /// Sum of entry cost across all positions.
+    #[must_use]
+    pub fn total_entry_cost(&self) -> Decimal {
+        self.positions.iter().map(|p| p.entry_cost).sum()
+    }
+
+    /// Sum of unrealized P&L across positions that could be marked.
+    #[must_use]
+    pub fn total_unrealized_pnl(&self) -> Decimal {
+        self.positions.iter().filter_map(|p| p.unrealized_pnl).sum()
+    }
+
+    /// Sum of settlement P&L estimates across all positions.
+    #[must_use]
+    pub fn total_settlement_pnl(&self) -> Decimal {
+        self.positions.iter().map(|p| p.settlement_pnl).sum()
+    }
+}
+
+/// Position use-cases for operator-facing adapters.
+///
+/// # Thread Safety
+///
+/// Implementations must be thread-safe (`Send + Sync`).
+#[async_trait]
+pub trait PositionOperator: Send + Sync {
+    /// Load open positions and value them at current marks.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_url` - Path to the statistics database.
+    /// * `config_toml` - Raw TOML configuration content used to connect to
+    ///   the exchange, or `None` to skip marking.
+    /// * `duration` - How long to collect order books for marks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be accessed, the configuration
+    /// cannot be parsed, or the stream connection fails.
+    async fn load_positions(
+        &self,
+        database_url: &str,
+        config_toml: Option<&str>,
+        duration: Duration,
+    ) -> Result<PositionsReport>;
+}
@@ -17,6 +17,10 @@ pub struct RunRequest {
     /// Raw TOML configuration content.
     pub config_toml: String,
 
+    /// Resource profile preset (`low`, `medium`, `high`) applied before
+    /// the individual overrides.
+    pub profile: Option<String>,
+
     /// Override for blockchain chain ID.
     pub chain_id: Option<u64>,
 
@@ -77,6 +81,15 @@ pub struct RunRequest {
     /// Override for database file path.
     pub database_path: Option<String>,
 
+    /// Recorded order book file to replay instead of live market data.
+    pub replay_path: Option<String>,
+
+    /// Playback speed multiplier for replay.
+    pub replay_speed: Option<f64>,
+
+    /// Order book snapshot to warm the cache from and save to on exit.
+    pub warm_from: Option<String>,
+
     /// Force mainnet environment.
     pub mainnet: bool,
 
@@ -105,6 +118,43 @@ pub struct RunStartupSnapshot {
     pub dry_run: bool,
 }
 
+/// Result of backtesting a recorded session.
+#[derive(Debug, Clone)]
+pub struct BacktestReport {
+    /// Markets defined by the recording.
+    pub markets: usize,
+
+    /// Book events replayed.
+    pub events: usize,
+
+    /// Opportunities detected.
+    pub opportunities: usize,
+
+    /// Simulated trades opened.
+    pub trades: usize,
+
+    /// Trades with a positive P&L.
+    pub wins: usize,
+
+    /// Hypothetical P&L across all trades.
+    pub pnl: Decimal,
+
+    /// Largest peak-to-trough fall in cumulative P&L, in recorded order.
+    pub max_drawdown: Decimal,
+
+    /// Percentage of trades with a positive P&L, if any trades were opened.
+    pub win_rate: Option<f64>,
+
+    /// Shuffled trade orderings drawn for the drawdown distribution.
+    pub runs: usize,
+
+    /// Median maximum drawdown across shuffled orderings.
+    pub drawdown_p50: Decimal,
+
+    /// 95th percentile maximum drawdown across shuffled orderings.
+    pub drawdown_p95: Decimal,
+}
+
 /// Runtime control use-cases for operator-facing adapters.
 ///
 /// # Thread Safety
@@ -133,4 +183,25 @@ pub trait RuntimeOperator: Send + Sync {
     ///
     /// Returns an error if runtime initialization or execution fails.
     async fn execute_run(&self, request: RunRequest) -> Result<()>;
+
+    /// Backtest a recorded session with simulated execution.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_toml` - Raw TOML configuration content.
+    /// * `data_path` - Path of the recording to replay.
+    /// * `runs` - Shuffled trade orderings drawn for the drawdown distribution.
+    /// * `seed` - Seed for the shuffles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the recording
+    /// cannot be replayed.
+    async fn simulate(
+        &self,
+        config_toml: &str,
+        data_path: &str,
+        runs: usize,
+        seed: u64,
+    ) -> Result<BacktestReport>;
 }
@@ -3,9 +3,11 @@
 //! Defines view models for trading statistics and performance reporting
 //! through operator interfaces like the CLI.
 
+use std::collections::HashMap;
+
 use chrono::NaiveDate;
 
-use crate::domain::stats::StatsSummary;
+use crate::domain::stats::{OpportunityFilter, OpportunitySummary, StatsSummary};
 use crate::error::Result;
 
 /// Per-strategy aggregate statistics record.
@@ -36,6 +38,9 @@ pub struct StrategyStatsRecord {
 
     /// Number of losing trades.
     pub loss_count: i32,
+
+    /// Total realized loss in USD.
+    pub loss_realized: f32,
 }
 
 /// Daily aggregate statistics record.
@@ -119,6 +124,24 @@ pub trait StatisticsOperator: Send + Sync {
         to: NaiveDate,
     ) -> Result<Vec<StrategyStatsRecord>>;
 
+    /// Load aggregate statistics per strategy for a date range.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_url` - Path to the statistics database.
+    /// * `from` - Start date (inclusive).
+    /// * `to` - End date (inclusive).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be accessed.
+    fn load_strategy_summary(
+        &self,
+        database_url: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<HashMap<String, StatsSummary>>;
+
     /// Load the count of currently open positions.
     ///
     /// # Arguments
@@ -177,4 +200,20 @@ pub trait StatisticsOperator: Send + Sync {
     ///
     /// Returns an error if the database cannot be accessed.
     fn prune_old_records(&self, database_url: &str, retention_days: u32) -> Result<()>;
+
+    /// Query recorded opportunities matching a filter, most recent first.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_url` - Path to the statistics database.
+    /// * `filter` - Criteria the opportunities must match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be accessed.
+    fn query_opportunities(
+        &self,
+        database_url: &str,
+        filter: OpportunityFilter,
+    ) -> Result<Vec<OpportunitySummary>>;
 }
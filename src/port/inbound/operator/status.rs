@@ -3,6 +3,9 @@
 //! Defines view models for current runtime status displays through
 //! operator interfaces like the CLI and Telegram bot.
 
+use crate::domain::score::RankedMarket;
+use crate::domain::stats::LatencyPercentiles;
+use crate::domain::trading::TradingState;
 use crate::error::Result;
 
 /// Recent activity item for status displays.
@@ -70,6 +73,18 @@ pub struct StatusSnapshot {
 
     /// Recent activity items for display.
     pub recent_activity: Vec<RecentActivity>,
+
+    /// Persisted trading state, if one has been saved.
+    pub trading_state: Option<TradingState>,
+
+    /// RFC 3339 timestamp of the most recent recorded event, if any.
+    pub last_event_at: Option<String>,
+
+    /// Latest detection latency percentiles, if the runtime has recorded any.
+    pub latency: Option<LatencyPercentiles>,
+
+    /// Top-ranked markets from the latest startup, highest score first.
+    pub top_markets: Vec<RankedMarket>,
 }
 
 /// Status use-cases for operator-facing adapters.
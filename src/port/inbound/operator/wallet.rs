@@ -30,6 +30,36 @@ pub struct WalletApprovalStatus {
     pub needs_approval: bool,
 }
 
+/// Token and gas balances for display.
+#[derive(Debug, Clone)]
+pub struct WalletBalances {
+    /// Exchange name.
+    pub exchange: String,
+
+    /// Wallet address.
+    pub wallet_address: String,
+
+    /// Spendable USDC balance.
+    pub usdc: Decimal,
+
+    /// Native gas token symbol (e.g., "POL").
+    pub gas_token: String,
+
+    /// Native gas token balance.
+    pub gas: Decimal,
+
+    /// Gas balance below which on-chain operations may fail.
+    pub min_gas: Decimal,
+}
+
+impl WalletBalances {
+    /// Whether the gas balance is below the minimum for trading.
+    #[must_use]
+    pub fn low_gas(&self) -> bool {
+        self.gas < self.min_gas
+    }
+}
+
 /// Outcome of a token approval operation.
 #[derive(Debug, Clone)]
 pub enum ApprovalOutcome {
@@ -74,6 +104,19 @@ pub enum SweepOutcome {
     },
 }
 
+/// Result of storing a private key in the OS keyring.
+#[derive(Debug, Clone)]
+pub struct KeyringImport {
+    /// Keyring service name the key was stored under.
+    pub service: String,
+
+    /// Keyring account name the key was stored under.
+    pub account: String,
+
+    /// Wallet address derived from the stored key.
+    pub wallet_address: String,
+}
+
 /// Wallet management use-cases for operator-facing adapters.
 ///
 /// # Thread Safety
@@ -126,6 +169,18 @@ pub trait WalletOperator: Send + Sync {
     /// Returns an error if the balance cannot be retrieved.
     async fn wallet_balance(&self, config_toml: &str) -> Result<Decimal>;
 
+    /// Retrieve USDC and native gas balances.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_toml` - Raw TOML configuration content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wallet is not configured or a balance cannot
+    /// be retrieved.
+    async fn wallet_balances(&self, config_toml: &str) -> Result<WalletBalances>;
+
     /// Sweep wallet balance to a destination address.
     ///
     /// # Arguments
@@ -145,4 +200,18 @@ pub trait WalletOperator: Send + Sync {
         asset: &str,
         network: &str,
     ) -> Result<SweepOutcome>;
+
+    /// Store a private key in the OS keyring entry named by the configuration.
+    ///
+    /// The key is checked by deriving its wallet address before it is stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_toml` - Raw TOML configuration content.
+    /// * `private_key` - Hex-encoded private key to store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is invalid or the keyring cannot be written.
+    fn wallet_import_keyring(&self, config_toml: &str, private_key: &str) -> Result<KeyringImport>;
 }
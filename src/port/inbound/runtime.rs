@@ -9,11 +9,15 @@
 //! - [`RuntimeState`]: Mutable runtime state and operator controls
 //! - [`RuntimeClusterView`]: Read-only access to discovered market clusters
 //! - [`RuntimeRiskLimits`]: Current risk limit configuration
+//! - [`RuntimeStrategyControl`]: Runtime enable/disable of detection strategies
+//! - [`RuntimeEvent`]: Recently emitted event for operator-facing listings
 
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 
 use crate::domain::cluster::Cluster;
 use crate::domain::money::Price;
+use crate::domain::trading::TradingState;
 
 /// Snapshot of current risk limit settings.
 ///
@@ -122,6 +126,19 @@ pub struct RuntimePosition {
     pub expected_profit: Price,
 }
 
+/// Recently emitted event for operator-facing listings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeEvent {
+    /// When the event was emitted.
+    pub at: DateTime<Utc>,
+
+    /// Event type name (e.g., "risk_rejected").
+    pub kind: &'static str,
+
+    /// One-line description of the event.
+    pub summary: String,
+}
+
 /// Read-only view of discovered market clusters.
 ///
 /// # Thread Safety
@@ -134,6 +151,64 @@ pub trait RuntimeClusterView: Send + Sync {
     fn all_clusters(&self) -> Vec<Cluster>;
 }
 
+/// On/off state of a registered detection strategy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeStrategyStatus {
+    /// Strategy name as reported by the strategy itself.
+    pub name: &'static str,
+
+    /// Whether the strategy currently runs during detection.
+    pub enabled: bool,
+}
+
+/// Error returned when toggling a strategy that is not registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownStrategyError {
+    /// Name that did not match any registered strategy.
+    name: String,
+}
+
+impl UnknownStrategyError {
+    /// Create a new error for the given strategy name.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl std::fmt::Display for UnknownStrategyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown strategy `{}`", self.name)
+    }
+}
+
+impl std::error::Error for UnknownStrategyError {}
+
+/// Runtime enable/disable control for detection strategies.
+///
+/// Disabling a strategy skips it during detection without removing it from
+/// the registry. Changes are process-local and reset on restart.
+///
+/// # Thread Safety
+///
+/// Implementations must be thread-safe (`Send + Sync`).
+pub trait RuntimeStrategyControl: Send + Sync {
+    /// Return every registered strategy with its current state, in
+    /// registration order.
+    fn strategies(&self) -> Vec<RuntimeStrategyStatus>;
+
+    /// Enable or disable a registered strategy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no registered strategy has the given name.
+    fn set_strategy_enabled(
+        &self,
+        name: &str,
+        enabled: bool,
+    ) -> Result<RuntimeStrategyStatus, UnknownStrategyError>;
+}
+
 /// Mutable runtime state and operator control interface.
 ///
 /// Provides access to runtime state and controls for operator-facing adapters
@@ -163,21 +238,18 @@ pub trait RuntimeState: Send + Sync {
         value: Decimal,
     ) -> Result<RuntimeRiskLimits, RuntimeRiskLimitUpdateError>;
 
-    /// Return `true` if the circuit breaker is currently active.
-    fn is_circuit_breaker_active(&self) -> bool;
+    /// Return the current trading state.
+    fn trading_state(&self) -> TradingState;
 
-    /// Return the reason for the active circuit breaker, if any.
-    fn circuit_breaker_reason(&self) -> Option<String>;
-
-    /// Activate the circuit breaker, halting all trading.
+    /// Halt trading until explicitly resumed, including across restarts.
     ///
     /// # Arguments
     ///
     /// * `reason` - Human-readable description of why trading was halted.
-    fn activate_circuit_breaker(&self, reason: &str);
+    fn halt(&self, reason: &str);
 
-    /// Reset the circuit breaker, resuming normal trading.
-    fn reset_circuit_breaker(&self);
+    /// Resume normal trading from a paused or halted state.
+    fn resume(&self);
 
     /// Return the number of currently open positions.
     fn open_position_count(&self) -> usize;
@@ -193,4 +265,7 @@ pub trait RuntimeState: Send + Sync {
 
     /// Return all active positions for operator-facing display.
     fn active_positions(&self) -> Vec<RuntimePosition>;
+
+    /// Return up to `limit` recently emitted events, newest first.
+    fn recent_events(&self, limit: usize) -> Vec<RuntimeEvent>;
 }
@@ -11,6 +11,8 @@
 //! - Implement [`Strategy`] to add new detection algorithms
 //! - Use [`DetectionContext`] to access market data during detection
 //! - Use [`MarketContext`] to filter which markets a strategy applies to
+//! - Implement [`MultiExchangeStrategy`] for detection that spans exchanges,
+//!   reading books through a [`MultiExchangeContext`]
 //!
 //! # Example
 //!
@@ -34,13 +36,15 @@
 //! }
 //! ```
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use rust_decimal::Decimal;
 
 use crate::domain::{
-    book::Book, id::MarketId, id::TokenId, market::Market, market::MarketRegistry,
-    opportunity::Opportunity,
+    book::Book, id::ClusterId, id::MarketId, id::TokenId, market::Market, market::MarketRegistry,
+    opportunity::Opportunity, quote::Quote, quote::PRIMARY_BOOK_SOURCE,
 };
 
 /// Metadata describing the structure of a market being analyzed.
@@ -144,6 +148,40 @@ pub struct DetectionResult {
     /// Strategies can compare current prices against these values to detect
     /// significant changes worth re-analyzing.
     pub last_prices: Vec<(TokenId, Decimal)>,
+
+    /// Last converged projection for each cluster.
+    ///
+    /// Projection-based strategies seed the next solver run from these
+    /// points when cluster prices have not moved much.
+    pub projections: HashMap<ClusterId, ClusterProjection>,
+}
+
+/// Converged projection of a cluster's prices onto the marginal polytope.
+///
+/// Stored so later detections on the same cluster can warm-start the
+/// solver instead of starting from the raw prices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterProjection {
+    /// Prices the projection was computed from.
+    pub prices: Vec<Decimal>,
+    /// Converged projected prices (`mu*`).
+    pub mu: Vec<Decimal>,
+}
+
+impl ClusterProjection {
+    /// Return `true` if `prices` are within `max_drift` of the stored prices.
+    ///
+    /// Every price must be within `max_drift` in absolute terms, and the
+    /// vectors must have the same length.
+    #[must_use]
+    pub fn is_close_to(&self, prices: &[Decimal], max_drift: Decimal) -> bool {
+        self.prices.len() == prices.len()
+            && self
+                .prices
+                .iter()
+                .zip(prices)
+                .all(|(prev, cur)| (*prev - *cur).abs() <= max_drift)
+    }
 }
 
 impl DetectionResult {
@@ -232,6 +270,34 @@ pub trait DetectionContext: Send + Sync {
     /// Returns `None` if no order book data is available for this token.
     fn order_book(&self, token_id: &TokenId) -> Option<Book>;
 
+    /// Return every executable quote available for the specified token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - Token to query.
+    ///
+    /// Contexts backed by several venues or price feeds return one quote per
+    /// source. The default implementation returns the primary book's top of
+    /// book, or an empty vector if no asks exist.
+    fn quotes(&self, token_id: &TokenId) -> Vec<Quote> {
+        self.order_book(token_id)
+            .and_then(|book| Quote::from_book(PRIMARY_BOOK_SOURCE, &book))
+            .into_iter()
+            .collect()
+    }
+
+    /// Return how long ago the specified token's order book was updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - Token to query.
+    ///
+    /// Returns `None` if no order book data is available or the context does
+    /// not track update times.
+    fn book_age(&self, _token_id: &TokenId) -> Option<Duration> {
+        None
+    }
+
     /// Return a reference to the underlying market.
     fn market(&self) -> &Market;
 }
@@ -316,6 +382,68 @@ pub trait Strategy: Send + Sync {
     fn set_market_registry(&mut self, _registry: Arc<MarketRegistry>) {}
 }
 
+/// Read-only access to order books held by several exchanges at once.
+///
+/// Each exchange keeps its own book cache, keyed by that exchange's token
+/// identifiers. Cross-exchange strategies read both sides of a trade through
+/// this context.
+///
+/// # Thread Safety
+///
+/// Implementations must be thread-safe (`Send + Sync`).
+pub trait MultiExchangeContext: Send + Sync {
+    /// Return the names of the exchanges available in this context.
+    fn exchanges(&self) -> Vec<&str>;
+
+    /// Return the order book for a token on the named exchange.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange` - Exchange name (e.g., "polymarket", "kalshi").
+    /// * `token_id` - Token to query, as identified by that exchange.
+    ///
+    /// Returns `None` if the exchange is unknown or has no book for the token.
+    fn order_book(&self, exchange: &str, token_id: &TokenId) -> Option<Book>;
+
+    /// Return the executable top-of-book quote for a token on the named exchange.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange` - Exchange name.
+    /// * `token_id` - Token to query, as identified by that exchange.
+    ///
+    /// The quote's source is the exchange name. Returns `None` if no asks exist.
+    fn quote(&self, exchange: &str, token_id: &TokenId) -> Option<Quote> {
+        self.order_book(exchange, token_id)
+            .and_then(|book| Quote::from_book(exchange, &book))
+    }
+
+    /// Return how long ago a token's order book was updated on the named exchange.
+    ///
+    /// Returns `None` if no book is available or update times are not tracked.
+    fn book_age(&self, _exchange: &str, _token_id: &TokenId) -> Option<Duration> {
+        None
+    }
+}
+
+/// Arbitrage detection strategy spanning several exchanges.
+///
+/// Where a [`Strategy`] looks at one market on one exchange, a multi-exchange
+/// strategy compares markets that track the same event on different
+/// exchanges. Each opportunity leg records the exchange it executes on as its
+/// source.
+pub trait MultiExchangeStrategy: Send + Sync {
+    /// Return the unique identifier for this strategy.
+    fn name(&self) -> &'static str;
+
+    /// Detect arbitrage opportunities across the exchanges in the context.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - Read-only access to every exchange's order books.
+    fn detect(&self, ctx: &dyn MultiExchangeContext) -> Vec<Opportunity>;
+}
+
 /// Runtime strategy engine for orchestrating detection across multiple strategies.
 ///
 /// Abstracts over strategy registry implementations so application orchestration
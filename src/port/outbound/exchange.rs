@@ -6,18 +6,25 @@
 //! # Overview
 //!
 //! - [`MarketFetcher`]: Fetch market listings from REST APIs
+//! - [`SettlementFetcher`]: Poll market resolution status from REST APIs
+//! - [`HoldingsFetcher`]: Query the shares held by the trading account
+//! - [`GasBalanceFetcher`]: Query the native token that pays for orders
 //! - [`MarketParser`]: Parse exchange-specific formats into domain types
 //! - [`MarketDataStream`]: Real-time order book updates via WebSocket
 //! - [`OrderExecutor`]: Submit and cancel orders
 //! - [`ArbitrageExecutor`]: Execute multi-leg arbitrage trades
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
+use serde::Serialize;
 
 use crate::domain::{
     book::Book, id::MarketId, id::OrderId, id::TokenId, market::Market, market::Outcome,
     opportunity::Opportunity, trade::TradeResult,
 };
+
+pub use crate::domain::trade::OrderSide;
 use crate::error::Error;
 
 /// Runtime statistics for a WebSocket connection pool.
@@ -128,16 +135,6 @@ pub struct OrderRequest {
     pub price: Decimal,
 }
 
-/// Direction of an order.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum OrderSide {
-    /// Buy order (acquire shares).
-    Buy,
-
-    /// Sell order (dispose of shares).
-    Sell,
-}
-
 /// Order executor for submitting and cancelling orders on an exchange.
 ///
 /// # Thread Safety
@@ -206,6 +203,11 @@ pub struct MarketInfo {
     ///
     /// `None` if liquidity data is not available from the exchange API.
     pub liquidity: Option<f64>,
+
+    /// When the market stops trading ahead of resolution.
+    ///
+    /// `None` if the exchange API does not report a close time.
+    pub close_time: Option<DateTime<Utc>>,
 }
 
 /// Information about a single outcome in a market.
@@ -221,6 +223,16 @@ pub struct OutcomeInfo {
     ///
     /// `None` if price data is not available from the REST API.
     pub price: Option<f64>,
+
+    /// Trading volume in this outcome over the last 24 hours in USD.
+    ///
+    /// `None` if the exchange does not report per-outcome volume.
+    pub volume_24h: Option<f64>,
+
+    /// Liquidity depth in this outcome's book in USD.
+    ///
+    /// `None` if the exchange does not report per-outcome liquidity.
+    pub liquidity: Option<f64>,
 }
 
 impl MarketInfo {
@@ -235,6 +247,26 @@ impl MarketInfo {
     pub fn is_binary(&self) -> bool {
         self.outcomes.len() == 2
     }
+
+    /// Return how evenly liquidity is spread across outcomes, from 0.0 to 1.0.
+    ///
+    /// This is the thinnest outcome's liquidity divided by the deepest's, so
+    /// a market with one deep book and several empty ones scores near zero.
+    /// `None` unless every outcome reports its liquidity.
+    #[must_use]
+    pub fn outcome_liquidity_balance(&self) -> Option<f64> {
+        let liquidity: Vec<f64> = self
+            .outcomes
+            .iter()
+            .map(|o| o.liquidity)
+            .collect::<Option<_>>()?;
+        let deepest = liquidity.iter().copied().reduce(f64::max)?;
+        let thinnest = liquidity.iter().copied().reduce(f64::min)?;
+        if deepest <= 0.0 {
+            return Some(0.0);
+        }
+        Some((thinnest.max(0.0) / deepest).min(1.0))
+    }
 }
 
 /// Parser for converting exchange-specific market data into domain types.
@@ -327,6 +359,27 @@ pub trait MarketParser: Send + Sync {
 
         markets
     }
+
+    /// Parse a raw market data message into order book snapshots.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - Raw message payload exactly as received from the exchange.
+    ///
+    /// Used by diagnostics to validate parsing against stored fixtures after
+    /// an exchange API change.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message cannot be parsed. The default
+    /// implementation reports that book parsing is not supported.
+    fn parse_order_book(&self, raw: &str) -> Result<Vec<Book>, Error> {
+        let _ = raw;
+        Err(Error::Parse(format!(
+            "{} does not support order book parsing",
+            self.name()
+        )))
+    }
 }
 
 /// Fetcher for retrieving market listings from an exchange REST API.
@@ -355,6 +408,86 @@ pub trait MarketFetcher: Send + Sync {
     fn exchange_name(&self) -> &'static str;
 }
 
+/// Fetcher for polling market resolution status from an exchange REST API.
+///
+/// Used to settle open positions on exchanges whose data streams do not
+/// announce resolution.
+///
+/// # Thread Safety
+///
+/// Implementations must be thread-safe (`Send + Sync`).
+#[async_trait]
+pub trait SettlementFetcher: Send + Sync {
+    /// Check the given markets and report the ones that have resolved.
+    ///
+    /// Returns a [`MarketEvent::MarketSettled`] for every resolved market.
+    /// Markets that are still open, or that could not be fetched, are
+    /// omitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `market_ids` - Markets to check.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the exchange cannot be reached at all.
+    async fn get_settlements(&self, market_ids: &[MarketId]) -> Result<Vec<MarketEvent>, Error>;
+
+    /// Return the exchange name for logging and debugging.
+    fn exchange_name(&self) -> &'static str;
+}
+
+/// Shares of one outcome token held by the trading account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Holding {
+    /// Token the shares belong to.
+    pub token_id: TokenId,
+    /// Number of shares held.
+    pub size: Decimal,
+}
+
+/// Fetcher for the outcome shares held by the trading account.
+///
+/// Used at startup to reconcile persisted positions against what the
+/// exchange reports.
+///
+/// # Thread Safety
+///
+/// Implementations must be thread-safe (`Send + Sync`).
+#[async_trait]
+pub trait HoldingsFetcher: Send + Sync {
+    /// Fetch every non-zero holding of the trading account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns invalid data.
+    async fn get_holdings(&self) -> Result<Vec<Holding>, Error>;
+
+    /// Return the exchange name for logging and debugging.
+    fn exchange_name(&self) -> &'static str;
+}
+
+/// Fetcher for the trading account's native gas token balance.
+///
+/// Polled periodically so executions can be rejected before an empty wallet
+/// makes them fail on chain.
+///
+/// # Thread Safety
+///
+/// Implementations must be thread-safe (`Send + Sync`).
+#[async_trait]
+pub trait GasBalanceFetcher: Send + Sync {
+    /// Fetch the balance in whole tokens.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the RPC request fails.
+    async fn get_gas_balance(&self) -> Result<Decimal, Error>;
+
+    /// Return the gas token symbol for logging and notifications.
+    fn gas_token(&self) -> &'static str;
+}
+
 /// Event received from a real-time market data stream.
 ///
 /// Represents the different types of updates that can arrive from an exchange
@@ -439,6 +572,59 @@ impl MarketEvent {
     }
 }
 
+/// Classification of why a market data connection was lost.
+///
+/// Exchanges report disconnects as free-form text (close frame reasons,
+/// HTTP handshake errors), so the reason is classified by its wording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisconnectReason {
+    /// The exchange throttled or banned the connection (e.g. HTTP 429).
+    RateLimited,
+    /// The exchange rejected the connection's credentials (HTTP 401/403).
+    AuthFailure,
+    /// Any other disconnect, such as a network error or server restart.
+    Transient,
+    /// The connection stayed open but stopped delivering events.
+    ///
+    /// Detected by the runtime's idle watchdog, never by [`Self::classify`].
+    Stalled,
+}
+
+impl DisconnectReason {
+    /// Classify a disconnect or connection error message.
+    #[must_use]
+    pub fn classify(reason: &str) -> Self {
+        let reason = reason.to_ascii_lowercase();
+        let mentions = |needles: &[&str]| needles.iter().any(|n| reason.contains(n));
+
+        if mentions(&[
+            "429",
+            "too many requests",
+            "rate limit",
+            "rate-limit",
+            "throttl",
+        ]) {
+            Self::RateLimited
+        } else if mentions(&["401", "403", "unauthorized", "forbidden", "authentication"]) {
+            Self::AuthFailure
+        } else {
+            Self::Transient
+        }
+    }
+}
+
+impl std::fmt::Display for DisconnectReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::RateLimited => "rate limited",
+            Self::AuthFailure => "authentication failure",
+            Self::Transient => "transient",
+            Self::Stalled => "stalled",
+        })
+    }
+}
+
 /// Real-time market data stream from an exchange via WebSocket.
 ///
 /// Implementations handle connection lifecycle management, subscription
@@ -476,6 +662,20 @@ pub trait MarketDataStream: Send {
     /// Returns an error if the subscription request fails.
     async fn subscribe(&mut self, token_ids: &[TokenId]) -> Result<(), Error>;
 
+    /// Replace the current subscriptions with `token_ids`.
+    ///
+    /// Used when the tracked market set changes at runtime. The default
+    /// reconnects and subscribes from scratch; streams that can change
+    /// subscriptions in place should override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reconnecting or subscribing fails.
+    async fn resubscribe(&mut self, token_ids: &[TokenId]) -> Result<(), Error> {
+        self.connect().await?;
+        self.subscribe(token_ids).await
+    }
+
     /// Receive the next market event from the stream.
     ///
     /// Blocks asynchronously until an event is available or the connection
@@ -507,6 +707,10 @@ impl MarketDataStream for Box<dyn MarketDataStream> {
         (**self).subscribe(token_ids).await
     }
 
+    async fn resubscribe(&mut self, token_ids: &[TokenId]) -> Result<(), Error> {
+        (**self).resubscribe(token_ids).await
+    }
+
     async fn next_event(&mut self) -> Option<MarketEvent> {
         (**self).next_event().await
     }
@@ -548,6 +752,25 @@ pub trait ArbitrageExecutor: Send + Sync {
     /// are captured in [`TradeResult::Partial`].
     async fn execute_arbitrage(&self, opportunity: &Opportunity) -> Result<TradeResult, Error>;
 
+    /// Sell shares of a single outcome, e.g. to exit an open position.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - Outcome token to sell.
+    /// * `size` - Number of shares to sell.
+    /// * `limit` - Minimum acceptable price per share.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order cannot be submitted. Unfilled orders are
+    /// reported as [`ExecutionResult::Failed`].
+    async fn sell(
+        &self,
+        token_id: &TokenId,
+        size: Decimal,
+        limit: Decimal,
+    ) -> Result<ExecutionResult, Error>;
+
     /// Cancel an order by its identifier.
     ///
     /// # Arguments
@@ -561,4 +784,12 @@ pub trait ArbitrageExecutor: Send + Sync {
 
     /// Return the exchange name for logging and debugging.
     fn exchange_name(&self) -> &'static str;
+
+    /// Whether sell-side (negative risk) opportunities can be executed.
+    ///
+    /// Opportunities the executor cannot trade are recorded as detected
+    /// without being submitted. Defaults to `false`.
+    fn supports_sell_arbitrage(&self) -> bool {
+        false
+    }
 }
@@ -9,7 +9,10 @@
 //! - [`MarketScorer`]: Score markets for prioritized subscription
 //! - [`MarketFilterConfig`]: Configuration for filtering criteria
 
+use std::time::Duration;
+
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use crate::domain::{score::MarketScore, score::ScoreWeights};
 use crate::error::Result;
@@ -41,6 +44,25 @@ pub struct MarketFilterConfig {
 
     /// Maximum number of outcomes allowed in a market.
     pub max_outcomes: usize,
+
+    /// Skip markets where any outcome is priced above this (e.g., 0.97).
+    ///
+    /// `None` disables the check.
+    pub max_outcome_price: Option<f64>,
+
+    /// Skip markets that close within this long.
+    ///
+    /// `None` disables the check.
+    pub min_time_to_close: Option<Duration>,
+
+    /// Market IDs that bypass the volume floor.
+    pub whitelist: Vec<String>,
+
+    /// Market IDs that are always dropped.
+    pub blacklist: Vec<String>,
+
+    /// Markets whose question contains any of these (case-insensitive) are dropped.
+    pub exclude_keywords: Vec<String>,
 }
 
 impl Default for MarketFilterConfig {
@@ -54,6 +76,65 @@ impl Default for MarketFilterConfig {
             include_binary: true,
             include_multi_outcome: true,
             max_outcomes: 10,
+            max_outcome_price: None,
+            min_time_to_close: None,
+            whitelist: Vec::new(),
+            blacklist: Vec::new(),
+            exclude_keywords: Vec::new(),
+        }
+    }
+}
+
+impl MarketFilterConfig {
+    /// Return `true` if the market is pinned by the whitelist.
+    #[must_use]
+    pub fn is_whitelisted(&self, market: &MarketInfo) -> bool {
+        self.whitelist.iter().any(|id| *id == market.id)
+    }
+
+    /// Return `true` if the market is blacklisted or its question matches an
+    /// excluded keyword.
+    ///
+    /// Exclusion wins over the whitelist.
+    #[must_use]
+    pub fn is_excluded(&self, market: &MarketInfo) -> bool {
+        if self.blacklist.iter().any(|id| *id == market.id) {
+            return true;
+        }
+        let question = market.question.to_lowercase();
+        self.exclude_keywords
+            .iter()
+            .any(|keyword| question.contains(&keyword.to_lowercase()))
+    }
+
+    /// Return `true` if the market looks resolved or about to resolve.
+    ///
+    /// Prices near 1.0 and imminent closes usually mean stale or unfillable
+    /// books, so apparent arbitrage there is rarely real. Markets missing
+    /// prices or a close time pass the corresponding check.
+    ///
+    /// # Arguments
+    ///
+    /// * `market` - Market information to evaluate.
+    /// * `now` - Current time, used to measure time to close.
+    #[must_use]
+    pub fn is_near_resolution(&self, market: &MarketInfo, now: DateTime<Utc>) -> bool {
+        if let Some(max_price) = self.max_outcome_price {
+            if market
+                .outcomes
+                .iter()
+                .any(|outcome| outcome.price.is_some_and(|price| price > max_price))
+            {
+                return true;
+            }
+        }
+
+        match (self.min_time_to_close, market.close_time) {
+            (Some(floor), Some(close_time)) => {
+                let remaining = (close_time - now).to_std().unwrap_or(Duration::ZERO);
+                remaining < floor
+            }
+            _ => false,
         }
     }
 }
@@ -77,11 +158,26 @@ pub trait MarketFilter: Send + Sync {
     ///
     /// * `markets` - Markets to filter.
     ///
-    /// Default implementation calls `is_eligible` for each market.
+    /// Default implementation drops blacklisted and keyword-matched markets,
+    /// then calls `is_eligible` for the rest. Whitelisted markets are checked
+    /// without their 24h volume, so they skip the volume floor but still need
+    /// to pass every other criterion.
     fn filter(&self, markets: &[MarketInfo]) -> Vec<MarketInfo> {
+        let config = self.config();
         markets
             .iter()
-            .filter(|m| self.is_eligible(m))
+            .filter(|m| !config.is_excluded(m))
+            .filter(|m| {
+                if config.is_whitelisted(m) {
+                    // Markets without volume data pass the volume floor
+                    self.is_eligible(&MarketInfo {
+                        volume_24h: None,
+                        ..(*m).clone()
+                    })
+                } else {
+                    self.is_eligible(m)
+                }
+            })
             .cloned()
             .collect()
     }
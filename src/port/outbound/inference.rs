@@ -7,6 +7,7 @@
 //!
 //! - [`RelationInferrer`]: Discovers logical relations between markets
 //! - [`MarketSummary`]: Minimal market data for inference
+//! - [`InferenceOutput`]: Relations from one call with the tokens it used
 //!
 //! # Relation Types
 //!
@@ -20,6 +21,7 @@ use async_trait::async_trait;
 
 use crate::domain::{id::MarketId, relation::Relation};
 use crate::error::Result;
+use crate::port::outbound::llm::TokenUsage;
 
 /// Minimal market information for relation inference.
 ///
@@ -61,6 +63,15 @@ impl MarketSummary {
     }
 }
 
+/// Relations discovered by one inference call.
+#[derive(Debug, Clone, Default)]
+pub struct InferenceOutput {
+    /// Discovered relations.
+    pub relations: Vec<Relation>,
+    /// Tokens consumed by the call, if the backend reports them.
+    pub usage: Option<TokenUsage>,
+}
+
 /// Inferrer for discovering logical relations between markets.
 ///
 /// Implementations analyze market questions to discover dependencies that
@@ -95,6 +106,22 @@ pub trait RelationInferrer: Send + Sync {
     /// rate limit, invalid response).
     async fn infer(&self, markets: &[MarketSummary]) -> Result<Vec<Relation>>;
 
+    /// Infer relations and report the tokens consumed doing so.
+    ///
+    /// The default implementation delegates to [`infer`](Self::infer) and
+    /// reports no usage. LLM-backed inferrers override it so callers can
+    /// enforce a token budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`infer`](Self::infer).
+    async fn infer_with_usage(&self, markets: &[MarketSummary]) -> Result<InferenceOutput> {
+        Ok(InferenceOutput {
+            relations: self.infer(markets).await?,
+            usage: None,
+        })
+    }
+
     /// Return the maximum number of markets per inference call.
     ///
     /// Implementations can override this based on their backend's context
@@ -111,11 +138,32 @@ pub mod tests {
     /// Mock inferrer for testing.
     pub struct MockInferrer {
         relations: Vec<Relation>,
+        usage: Option<TokenUsage>,
+        failure: Option<String>,
     }
 
     impl MockInferrer {
         pub fn new(relations: Vec<Relation>) -> Self {
-            Self { relations }
+            Self {
+                relations,
+                usage: None,
+                failure: None,
+            }
+        }
+
+        /// Fail every inference call with `error`, as an unreachable LLM would.
+        pub fn failing(error: &str) -> Self {
+            Self {
+                relations: Vec::new(),
+                usage: None,
+                failure: Some(error.to_string()),
+            }
+        }
+
+        /// Report `usage` for every inference call.
+        pub fn with_usage(mut self, usage: TokenUsage) -> Self {
+            self.usage = Some(usage);
+            self
         }
     }
 
@@ -126,7 +174,17 @@ pub mod tests {
         }
 
         async fn infer(&self, _markets: &[MarketSummary]) -> Result<Vec<Relation>> {
-            Ok(self.relations.clone())
+            match &self.failure {
+                Some(error) => Err(crate::error::Error::Connection(error.clone())),
+                None => Ok(self.relations.clone()),
+            }
+        }
+
+        async fn infer_with_usage(&self, markets: &[MarketSummary]) -> Result<InferenceOutput> {
+            Ok(InferenceOutput {
+                relations: self.infer(markets).await?,
+                usage: self.usage,
+            })
         }
     }
 }
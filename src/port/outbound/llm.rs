@@ -7,6 +7,51 @@ use async_trait::async_trait;
 
 use crate::error::Result;
 
+/// Approximate characters per token, used when a provider reports no usage.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Tokens consumed by a single completion request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenUsage {
+    /// Tokens in the prompt.
+    pub input_tokens: u64,
+    /// Tokens in the generated response.
+    pub output_tokens: u64,
+}
+
+impl TokenUsage {
+    /// Create a usage record from prompt and response token counts.
+    #[must_use]
+    pub const fn new(input_tokens: u64, output_tokens: u64) -> Self {
+        Self {
+            input_tokens,
+            output_tokens,
+        }
+    }
+
+    /// Estimate usage from text length when the provider reports none.
+    #[must_use]
+    pub fn estimate(prompt: &str, response: &str) -> Self {
+        let tokens = |text: &str| text.chars().count().div_ceil(CHARS_PER_TOKEN) as u64;
+        Self::new(tokens(prompt), tokens(response))
+    }
+
+    /// Return prompt and response tokens combined.
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.input_tokens + self.output_tokens
+    }
+}
+
+/// Generated text together with the tokens it consumed.
+#[derive(Debug, Clone)]
+pub struct Completion {
+    /// Generated text.
+    pub text: String,
+    /// Token usage reported by the provider, if any.
+    pub usage: Option<TokenUsage>,
+}
+
 /// Client for large language model text completion.
 ///
 /// Implementations wrap specific LLM providers (OpenAI, Anthropic, etc.) and
@@ -37,4 +82,37 @@ pub trait Llm: Send + Sync {
     /// Returns an error if the API request fails, the response is invalid,
     /// or rate limits are exceeded.
     async fn complete(&self, prompt: &str) -> Result<String>;
+
+    /// Send a completion request and return the text with its token usage.
+    ///
+    /// The default implementation delegates to [`complete`](Self::complete)
+    /// and reports no usage. Providers that return usage should override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`complete`](Self::complete).
+    async fn complete_with_usage(&self, prompt: &str) -> Result<Completion> {
+        Ok(Completion {
+            text: self.complete(prompt).await?,
+            usage: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_rounds_up_per_text() {
+        let usage = TokenUsage::estimate("abcde", "abc");
+
+        assert_eq!(usage, TokenUsage::new(2, 1));
+        assert_eq!(usage.total(), 3);
+    }
+
+    #[test]
+    fn estimate_of_empty_text_is_zero() {
+        assert_eq!(TokenUsage::estimate("", "").total(), 0);
+    }
 }
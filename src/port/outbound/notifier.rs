@@ -9,20 +9,29 @@
 //! - [`Notifier`]: Core notification trait
 //! - [`NotifierRegistry`]: Composite notifier for broadcasting to multiple handlers
 //! - [`Event`]: Enum of all notification event types
+//! - [`RecentEvent`]: Event retained with the time it was emitted
 
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
+use serde::Serialize;
 
-use crate::domain::{opportunity::Opportunity, trade::TradeResult};
+use crate::domain::{opportunity::Opportunity, trade::FailureKind, trade::TradeResult};
 use crate::error::RiskError;
+use crate::port::outbound::exchange::DisconnectReason;
 
 /// System event that triggers a notification.
 ///
 /// Represents all types of events that can be sent to notification handlers.
-#[derive(Debug, Clone)]
+/// Serializes with a snake_case `type` tag alongside the event fields.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum Event {
     /// Arbitrage opportunity detected and ready for execution.
     OpportunityDetected(OpportunityEvent),
 
+    /// Market priced just above payout, surfaced for monitoring only.
+    NearArbitrage(OpportunityEvent),
+
     /// Trade execution completed (success, partial, or failure).
     ExecutionCompleted(ExecutionEvent),
 
@@ -38,16 +47,105 @@ pub enum Event {
     /// Circuit breaker reset, resuming trading.
     CircuitBreakerReset,
 
+    /// Trading is halted by an operator and stays halted until resumed.
+    TradingHalted {
+        /// Human-readable description of why trading was halted.
+        reason: String,
+    },
+
+    /// Market data connection was rate limited, had its credentials rejected,
+    /// or stalled.
+    StreamDisconnected {
+        /// Classified cause of the disconnect.
+        cause: DisconnectReason,
+        /// Disconnect reason reported by the exchange.
+        reason: String,
+    },
+
     /// Daily trading summary.
     DailySummary(SummaryEvent),
 
+    /// Open positions exited before shutdown.
+    DrainCompleted(DrainEvent),
+
     /// Market relations discovered by LLM inference.
     RelationsDiscovered(RelationsEvent),
+
+    /// LLM inference failed entirely; other strategies keep running.
+    InferenceDegraded {
+        /// Error reported by the last failed inference batch.
+        reason: String,
+    },
+}
+
+impl Event {
+    /// Name of the event type, matching its serialized `type` tag.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::OpportunityDetected(_) => "opportunity_detected",
+            Self::NearArbitrage(_) => "near_arbitrage",
+            Self::ExecutionCompleted(_) => "execution_completed",
+            Self::RiskRejected(_) => "risk_rejected",
+            Self::CircuitBreakerActivated { .. } => "circuit_breaker_activated",
+            Self::CircuitBreakerReset => "circuit_breaker_reset",
+            Self::TradingHalted { .. } => "trading_halted",
+            Self::StreamDisconnected { .. } => "stream_disconnected",
+            Self::DailySummary(_) => "daily_summary",
+            Self::DrainCompleted(_) => "drain_completed",
+            Self::RelationsDiscovered(_) => "relations_discovered",
+            Self::InferenceDegraded { .. } => "inference_degraded",
+        }
+    }
+
+    /// One-line description of the event for compact listings.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        match self {
+            Self::OpportunityDetected(e) => format!(
+                "{} edge {} profit ${}",
+                e.market_id, e.edge, e.expected_profit
+            ),
+            Self::NearArbitrage(e) => format!("{} edge {}", e.market_id, e.edge),
+            Self::ExecutionCompleted(e) => {
+                let outcome = if e.success { "succeeded" } else { "failed" };
+                format!("{} {outcome}: {}", e.market_id, e.details)
+            }
+            Self::RiskRejected(e) => format!("{}: {}", e.market_id, e.reason),
+            Self::CircuitBreakerActivated { reason }
+            | Self::TradingHalted { reason }
+            | Self::InferenceDegraded { reason } => reason.clone(),
+            Self::CircuitBreakerReset => "trading resumed".to_string(),
+            Self::StreamDisconnected { cause, reason } => format!("{cause}: {reason}"),
+            Self::DailySummary(e) => format!(
+                "{}: {} opportunities, {} trades, ${} profit",
+                e.date, e.opportunities_detected, e.trades_executed, e.total_profit
+            ),
+            Self::DrainCompleted(e) => format!(
+                "{}/{} positions closed, P&L ${}",
+                e.closed, e.positions, e.realized_pnl
+            ),
+            Self::RelationsDiscovered(e) => format!("{} relations", e.relations_count),
+        }
+    }
+}
+
+/// Event retained with the time it was emitted.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecentEvent {
+    /// When the event was emitted.
+    pub at: DateTime<Utc>,
+
+    /// The emitted event.
+    pub event: Event,
 }
 
 /// Event data for a detected arbitrage opportunity.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct OpportunityEvent {
+    /// Identifier generated when the opportunity was detected.
+    pub opportunity_id: String,
+
     /// Identifier of the market where the opportunity was found.
     pub market_id: String,
 
@@ -62,23 +160,31 @@ pub struct OpportunityEvent {
 
     /// Expected profit from executing this opportunity.
     pub expected_profit: Decimal,
+
+    /// One-line summary of the arbitrage math (see [`Opportunity::explain`]).
+    pub explanation: String,
 }
 
 impl From<&Opportunity> for OpportunityEvent {
     fn from(opp: &Opportunity) -> Self {
         Self {
+            opportunity_id: opp.id().to_string(),
             market_id: opp.market_id().to_string(),
             question: opp.question().to_string(),
             edge: opp.edge(),
             volume: opp.volume(),
             expected_profit: opp.expected_profit(),
+            explanation: opp.explain(),
         }
     }
 }
 
 /// Event data for a completed trade execution.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ExecutionEvent {
+    /// Identifier of the opportunity that was executed.
+    pub opportunity_id: String,
+
     /// Identifier of the market where the trade was executed.
     pub market_id: String,
 
@@ -87,6 +193,9 @@ pub struct ExecutionEvent {
 
     /// Human-readable execution details or error message.
     pub details: String,
+
+    /// Class of the failure, if the execution failed.
+    pub failure_kind: Option<FailureKind>,
 }
 
 impl ExecutionEvent {
@@ -94,43 +203,71 @@ impl ExecutionEvent {
     ///
     /// # Arguments
     ///
+    /// * `opportunity_id` - Identifier of the opportunity that was executed.
     /// * `market_id` - Identifier of the market where the trade was executed.
     /// * `result` - Outcome of the trade execution.
     #[must_use]
-    pub fn from_result(market_id: &str, result: &TradeResult) -> Self {
+    pub fn from_result(opportunity_id: &str, market_id: &str, result: &TradeResult) -> Self {
         match result {
             TradeResult::Success { fills } => {
                 let order_ids: Vec<_> = fills.iter().map(|f| f.order_id.as_str()).collect();
                 Self {
+                    opportunity_id: opportunity_id.to_string(),
                     market_id: market_id.to_string(),
                     success: true,
                     details: format!("Orders: {}", order_ids.join(", ")),
+                    failure_kind: None,
                 }
             }
             TradeResult::Partial { fills, failures } => {
                 let fill_ids: Vec<_> = fills.iter().map(|f| f.token_id.to_string()).collect();
                 let failure_ids: Vec<_> = failures.iter().map(|f| f.token_id.to_string()).collect();
                 Self {
+                    opportunity_id: opportunity_id.to_string(),
                     market_id: market_id.to_string(),
                     success: false,
                     details: format!(
                         "Partial fill - fills: {:?}, failures: {:?}",
                         fill_ids, failure_ids
                     ),
+                    failure_kind: result.failure_kind(),
                 }
             }
-            TradeResult::Failed { reason } => Self {
+            TradeResult::Unwound {
+                fills, net_cost, ..
+            } => {
+                let fill_ids: Vec<_> = fills.iter().map(|f| f.token_id.to_string()).collect();
+                Self {
+                    opportunity_id: opportunity_id.to_string(),
+                    market_id: market_id.to_string(),
+                    success: false,
+                    details: format!(
+                        "Partial fill unwound - sold back: {:?}, net cost: ${:.2}",
+                        fill_ids, net_cost
+                    ),
+                    failure_kind: None,
+                }
+            }
+            TradeResult::Failed { reason, kind } => Self {
+                opportunity_id: opportunity_id.to_string(),
                 market_id: market_id.to_string(),
                 success: false,
-                details: format!("Failed: {reason}"),
+                details: match kind {
+                    FailureKind::Other => format!("Failed: {reason}"),
+                    kind => format!("Rejected: {kind} ({reason})"),
+                },
+                failure_kind: Some(*kind),
             },
         }
     }
 }
 
 /// Event data for a risk-rejected trade.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RiskEvent {
+    /// Identifier of the rejected opportunity.
+    pub opportunity_id: String,
+
     /// Identifier of the market for the rejected trade.
     pub market_id: String,
 
@@ -139,15 +276,17 @@ pub struct RiskEvent {
 }
 
 impl RiskEvent {
-    /// Create a risk event from a market identifier and risk error.
+    /// Create a risk event from an opportunity's identifiers and risk error.
     ///
     /// # Arguments
     ///
+    /// * `opportunity_id` - Identifier of the rejected opportunity.
     /// * `market_id` - Identifier of the market for the rejected trade.
     /// * `error` - Risk error describing the rejection reason.
     #[must_use]
-    pub fn new(market_id: &str, error: &RiskError) -> Self {
+    pub fn new(opportunity_id: &str, market_id: &str, error: &RiskError) -> Self {
         Self {
+            opportunity_id: opportunity_id.to_string(),
             market_id: market_id.to_string(),
             reason: error.to_string(),
         }
@@ -155,7 +294,7 @@ impl RiskEvent {
 }
 
 /// Event data for a daily trading summary.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SummaryEvent {
     /// Date covered by this summary.
     pub date: chrono::NaiveDate,
@@ -176,8 +315,30 @@ pub struct SummaryEvent {
     pub current_exposure: Decimal,
 }
 
+/// Event data for the position drain run at shutdown.
+#[derive(Debug, Clone, Serialize)]
+pub struct DrainEvent {
+    /// Open positions when the drain started.
+    pub positions: usize,
+
+    /// Positions exited and closed.
+    pub closed: usize,
+
+    /// Positions left open because a leg could not be sold.
+    pub failed: usize,
+
+    /// Whether the drain timed out before every position was attempted.
+    pub timed_out: bool,
+
+    /// Realized profit or loss from the exits.
+    pub realized_pnl: Decimal,
+
+    /// Exposure still open after the drain.
+    pub remaining_exposure: Decimal,
+}
+
 /// Event data for discovered market relations.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RelationsEvent {
     /// Number of relations discovered in this inference batch.
     pub relations_count: usize,
@@ -187,7 +348,7 @@ pub struct RelationsEvent {
 }
 
 /// Detail of a single discovered market relation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RelationDetail {
     /// Type of logical relation (e.g., "mutually_exclusive", "implies", "exactly_one").
     pub relation_type: String,
@@ -312,6 +473,13 @@ impl Notifier for LogNotifier {
                     "Opportunity detected"
                 );
             }
+            Event::NearArbitrage(e) => {
+                info!(
+                    market_id = %e.market_id,
+                    edge = %e.edge,
+                    "Near-arbitrage market"
+                );
+            }
             Event::ExecutionCompleted(e) => {
                 info!(
                     market_id = %e.market_id,
@@ -333,6 +501,12 @@ impl Notifier for LogNotifier {
             Event::CircuitBreakerReset => {
                 info!("Circuit breaker reset");
             }
+            Event::TradingHalted { reason } => {
+                info!(reason = %reason, "Trading halted");
+            }
+            Event::StreamDisconnected { cause, reason } => {
+                info!(cause = %cause, reason = %reason, "Market data stream disconnected");
+            }
             Event::DailySummary(e) => {
                 info!(
                     date = %e.date,
@@ -343,9 +517,23 @@ impl Notifier for LogNotifier {
                     "Daily summary"
                 );
             }
+            Event::DrainCompleted(e) => {
+                info!(
+                    positions = e.positions,
+                    closed = e.closed,
+                    failed = e.failed,
+                    timed_out = e.timed_out,
+                    pnl = %e.realized_pnl,
+                    remaining_exposure = %e.remaining_exposure,
+                    "Position drain completed"
+                );
+            }
             Event::RelationsDiscovered(e) => {
                 info!(relations = e.relations_count, "Relations discovered");
             }
+            Event::InferenceDegraded { reason } => {
+                info!(reason = %reason, "Inference degraded");
+            }
         }
     }
 }
@@ -10,7 +10,9 @@
 
 use chrono::NaiveDate;
 
-use crate::domain::stats::StatsSummary;
+use crate::domain::score::RankedMarket;
+use crate::domain::stats::{LatencyPercentiles, StatsSummary, TradeLeg};
+use crate::domain::trading::TradingState;
 use crate::error::Result;
 
 /// Daily aggregate statistics record from storage.
@@ -88,6 +90,9 @@ pub struct StrategyDailyStatsRecord {
 
     /// Losing trade count.
     pub loss_count: i32,
+
+    /// Realized loss from this strategy in USD.
+    pub loss_realized: f32,
 }
 
 /// Recent activity item for status displays.
@@ -135,6 +140,43 @@ pub struct StatusSnapshot {
 
     /// Recent activity items.
     pub recent_activity: Vec<RecentActivity>,
+
+    /// Persisted trading state, if one has been saved.
+    pub trading_state: Option<TradingState>,
+
+    /// RFC 3339 timestamp of the most recent recorded event, if any.
+    pub last_event_at: Option<String>,
+
+    /// Latest detection latency percentiles, if the runtime has recorded any.
+    pub latency: Option<LatencyPercentiles>,
+
+    /// Top-ranked markets from the latest startup, highest score first.
+    pub top_markets: Vec<RankedMarket>,
+}
+
+/// Open trade record from storage.
+#[derive(Debug, Clone)]
+pub struct OpenTradeRecord {
+    /// Trade row identifier.
+    pub id: i32,
+
+    /// Strategy that opened the trade.
+    pub strategy: String,
+
+    /// Markets the trade spans.
+    pub market_ids: Vec<String>,
+
+    /// Filled legs of the trade.
+    pub legs: Vec<TradeLeg>,
+
+    /// Number of shares traded.
+    pub size: f32,
+
+    /// Expected profit recorded at open.
+    pub expected_profit: f32,
+
+    /// RFC 3339 timestamp when the trade was opened.
+    pub opened_at: String,
 }
 
 /// Read-side port for loading current status.
@@ -149,6 +191,13 @@ pub trait StatusReportReader: Send + Sync {
     ///
     /// Returns an error if storage cannot be accessed.
     fn load_status(&self) -> Result<StatusSnapshot>;
+
+    /// Load every trade that is still open, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if storage cannot be accessed.
+    fn load_open_trades(&self) -> Result<Vec<OpenTradeRecord>>;
 }
 
 /// Read-side port for querying historical statistics.
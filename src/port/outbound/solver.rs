@@ -7,6 +7,7 @@
 //! # Overview
 //!
 //! - [`Solver`]: Core LP/ILP solver interface
+//! - [`SolverBackend`]: Linear minimization oracle used by Frank-Wolfe
 //! - [`ProjectionSolver`]: Projection-based optimization (e.g., Frank-Wolfe)
 //! - [`LpProblem`] / [`IlpProblem`]: Problem definitions
 //! - [`LpSolution`]: Solution representation
@@ -65,6 +66,48 @@ pub trait Solver: Send + Sync {
     fn solve_ilp(&self, problem: &IlpProblem) -> Result<LpSolution>;
 }
 
+/// Vertex of a feasible region returned by a [`SolverBackend`] oracle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vertex {
+    /// Value of each decision variable at the vertex.
+    pub values: Vec<Decimal>,
+
+    /// Objective value `<gradient, values>` at the vertex.
+    pub objective: Decimal,
+}
+
+/// Linear minimization oracle over a feasible region.
+///
+/// Frank-Wolfe calls the oracle once per iteration to find the vertex that
+/// most decreases the divergence. Backends range from exact ILP solvers to
+/// heuristics that are only trusted when they can prove their answer optimal.
+///
+/// # Thread Safety
+///
+/// Implementations must be thread-safe (`Send + Sync`).
+pub trait SolverBackend: Send + Sync {
+    /// Return the backend name for logging and configuration.
+    fn name(&self) -> &'static str;
+
+    /// Find the vertex minimizing `<gradient, x>` over the feasible region.
+    ///
+    /// The objective of `constraints` is ignored; only its constraints,
+    /// bounds, and integer variables define the region.
+    ///
+    /// Returns `None` when no vertex was proven optimal: the region is
+    /// infeasible or unbounded, or a heuristic backend could not prove
+    /// optimality.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend encounters an internal error.
+    fn solve_oracle(
+        &self,
+        gradient: &[Decimal],
+        constraints: &IlpProblem,
+    ) -> Result<Option<Vertex>>;
+}
+
 /// Result of projecting prices onto a feasible polytope.
 ///
 /// Used by projection-based arbitrage detection algorithms to find the nearest
@@ -111,6 +154,32 @@ pub trait ProjectionSolver: Send + Sync {
     /// Returns an error if the projection fails to converge or the problem
     /// has no feasible region.
     fn project(&self, theta: &[Decimal], problem: &IlpProblem) -> Result<ProjectionResult>;
+
+    /// Project values starting from an initial point in the feasible region.
+    ///
+    /// # Arguments
+    ///
+    /// * `theta` - Input price vector to project.
+    /// * `initial` - Optional starting point, typically a previous projection.
+    /// * `problem` - Problem defining the feasible region.
+    ///
+    /// The default implementation ignores `initial` and calls
+    /// [`ProjectionSolver::project`]. Iterative solvers override this to
+    /// warm-start from the given point.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the projection fails to converge or the problem
+    /// has no feasible region.
+    fn project_from(
+        &self,
+        theta: &[Decimal],
+        initial: Option<&[Decimal]>,
+        problem: &IlpProblem,
+    ) -> Result<ProjectionResult> {
+        let _ = initial;
+        self.project(theta, problem)
+    }
 }
 
 /// Linear programming problem definition.
@@ -3,11 +3,15 @@
 //! Defines the write-side interface for persisting trading statistics.
 //! This is the command side of the CQRS pattern for statistics.
 
+use std::collections::HashMap;
+
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
 
 use crate::domain::{
-    stats::RecordedOpportunity, stats::StatsSummary, stats::TradeCloseEvent, stats::TradeOpenEvent,
+    score::RankedMarket, stats::LatencyPercentiles, stats::OpportunityFilter,
+    stats::OpportunitySummary, stats::RecordedOpportunity, stats::StatsSummary,
+    stats::TradeCloseEvent, stats::TradeOpenEvent,
 };
 
 /// Write-side port for recording trading statistics.
@@ -30,6 +34,17 @@ pub trait StatsRecorder: Send + Sync {
     /// if recording fails.
     fn record_opportunity(&self, event: &RecordedOpportunity) -> Option<i32>;
 
+    /// Mark a recorded opportunity as rejected.
+    ///
+    /// For opportunities dropped after being recorded as executed, e.g. when
+    /// they age out while waiting for the rate limiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `opportunity_id` - ID returned by [`Self::record_opportunity`].
+    /// * `reason` - Rejection reason, e.g. `opportunity_stale`.
+    fn record_opportunity_rejected(&self, opportunity_id: i32, reason: &str);
+
     /// Record a trade opening.
     ///
     /// # Arguments
@@ -54,6 +69,26 @@ pub trait StatsRecorder: Send + Sync {
     /// * `latency_ms` - Measured latency in milliseconds.
     fn record_latency(&self, latency_ms: u32);
 
+    /// Persist the latest detection latency percentiles.
+    ///
+    /// Replaces any previously recorded percentiles so readers such as the
+    /// `status` command see the current window.
+    ///
+    /// # Arguments
+    ///
+    /// * `percentiles` - Percentiles over the recent latency window.
+    fn record_latency_percentiles(&self, percentiles: &LatencyPercentiles);
+
+    /// Persist the top-ranked markets and their score breakdowns.
+    ///
+    /// Replaces any previously recorded ranking so readers such as the
+    /// `status` command can explain the current subscription priority.
+    ///
+    /// # Arguments
+    ///
+    /// * `markets` - Markets in descending score order.
+    fn record_market_scores(&self, markets: &[RankedMarket]);
+
     /// Update the peak exposure metric if the current value exceeds the record.
     ///
     /// # Arguments
@@ -71,4 +106,26 @@ pub trait StatsRecorder: Send + Sync {
 
     /// Retrieve summary statistics for the current day.
     fn get_today(&self) -> StatsSummary;
+
+    /// Retrieve summary statistics per strategy for a date range.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Start date (inclusive).
+    /// * `to` - End date (inclusive).
+    ///
+    /// Returns a map from strategy name to its summary. Rejections and
+    /// volume are not tracked per strategy and are reported as zero.
+    fn get_strategy_summary(&self, from: NaiveDate, to: NaiveDate)
+        -> HashMap<String, StatsSummary>;
+
+    /// Retrieve recorded opportunities matching a filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Strategy, execution status, minimum edge, date range,
+    ///   and result limit to apply.
+    ///
+    /// Returns matching opportunities, most recent first.
+    fn query_opportunities(&self, filter: OpportunityFilter) -> Vec<OpportunitySummary>;
 }
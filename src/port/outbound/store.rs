@@ -1,16 +1,22 @@
-//! Persistence ports for relations and clusters.
+//! Persistence ports for relations, clusters, trading state, and positions.
 //!
-//! Defines storage traits for persisting discovered market relations and
-//! computed clusters.
+//! Defines storage traits for persisting discovered market relations,
+//! computed clusters, the operator-controlled trading state, and tracked
+//! positions.
 //!
 //! # Overview
 //!
 //! - [`RelationStore`]: CRUD operations for market relations
 //! - [`ClusterStore`]: CRUD operations for market clusters
+//! - [`TradingStateStore`]: Durable trading state across restarts
+//! - [`PositionStore`]: Durable positions across restarts
 
 use std::future::Future;
 
-use crate::domain::{cluster::Cluster, id::ClusterId, id::RelationId, relation::Relation};
+use crate::domain::{
+    cluster::Cluster, id::ClusterId, id::RelationId, position::Position, relation::Relation,
+    trading::TradingState,
+};
 use crate::error::Result;
 
 /// Storage port for market relations.
@@ -142,3 +148,68 @@ pub trait ClusterStore: Send + Sync {
     /// Returns an error if the storage operation fails.
     fn list(&self) -> impl Future<Output = Result<Vec<Cluster>>> + Send;
 }
+
+/// Storage port for the trading state.
+///
+/// Lets a manual halt survive restarts so trading does not resume
+/// accidentally when the process comes back up.
+///
+/// # Thread Safety
+///
+/// Implementations must be thread-safe (`Send + Sync`).
+pub trait TradingStateStore: Send + Sync {
+    /// Load the last saved trading state.
+    ///
+    /// Returns `None` if no state has been saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage operation fails.
+    fn load(&self) -> Result<Option<TradingState>>;
+
+    /// Save the trading state, replacing any previously saved state.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - Trading state to persist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage operation fails.
+    fn save(&self, state: &TradingState) -> Result<()>;
+}
+
+/// Storage port for tracked positions.
+///
+/// Keeps open positions across restarts so exposure accounting does not
+/// forget legs that are still held until settlement.
+///
+/// # Thread Safety
+///
+/// Implementations must be thread-safe (`Send + Sync`).
+pub trait PositionStore: Send + Sync {
+    /// Save a position, replacing any previously saved copy with the same ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Position to persist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage operation fails.
+    fn save(&self, position: &Position) -> Result<()>;
+
+    /// Load every position that is not closed, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage operation fails.
+    fn load_open(&self) -> Result<Vec<Position>>;
+
+    /// Return the highest position ID ever saved, or 0 if none were.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage operation fails.
+    fn last_id(&self) -> Result<u64>;
+}
@@ -3,7 +3,9 @@
 //! Single source of truth for config structs used across tests.
 //! Avoids each test module defining its own slightly-different defaults.
 
-use crate::infrastructure::config::pool::{ConnectionPoolConfig, ReconnectionConfig};
+use crate::infrastructure::config::pool::{
+    BackoffJitter, ConnectionPoolConfig, ReconnectionConfig,
+};
 
 /// Fast reconnection config with zero delays — no waiting in tests.
 pub fn reconnection() -> ReconnectionConfig {
@@ -13,6 +15,9 @@ pub fn reconnection() -> ReconnectionConfig {
         backoff_multiplier: 1.0,
         max_consecutive_failures: 3,
         circuit_breaker_cooldown_ms: 0,
+        jitter: BackoffJitter::None,
+        rate_limit_delay_ms: 0,
+        max_auth_failures: 3,
     }
 }
 
@@ -1,7 +1,8 @@
 //! Shared test utilities available to both unit and integration tests.
 //!
 //! Enabled via `#[cfg(test)]` (unit tests) or the `testkit` feature
-//! (integration tests).
+//! (integration tests). The [`domain`] builders are always compiled because
+//! `edgelord bench` uses them to generate synthetic markets.
 //!
 //! # Modules
 //!
@@ -9,8 +10,14 @@
 //!   implementations: `ScriptedStream`, `CyclingStream`, `ChannelStream`.
 //! - [`domain`] — Builders for domain primitives: tokens, markets, events.
 //! - [`config`] — Canonical test configurations (reconnection, pool, etc.).
+//! - [`notifier`] — `RecordingNotifier`, which captures emitted events.
 
+#[cfg(any(test, feature = "testkit"))]
 pub mod config;
 pub mod domain;
+#[cfg(any(test, feature = "testkit"))]
+pub mod notifier;
+#[cfg(any(test, feature = "testkit"))]
 pub mod stats;
+#[cfg(any(test, feature = "testkit"))]
 pub mod stream;
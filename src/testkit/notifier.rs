@@ -0,0 +1,51 @@
+//! Test helpers for capturing notifier events.
+
+use std::sync::Arc;
+
+use parking_lot::{Mutex, MutexGuard};
+
+use crate::port::outbound::notifier::{Event, Notifier, NotifierRegistry};
+
+/// Notifier that keeps every event it receives.
+///
+/// Clones share the same event log, so a test can register one clone and
+/// assert on another.
+#[derive(Clone, Default)]
+pub struct RecordingNotifier {
+    events: Arc<Mutex<Vec<Event>>>,
+}
+
+impl RecordingNotifier {
+    /// Create a notifier with an empty event log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a notifier together with a registry that only holds it.
+    pub fn registry() -> (Self, Arc<NotifierRegistry>) {
+        let notifier = Self::new();
+        let mut registry = NotifierRegistry::new();
+        registry.register(Box::new(notifier.clone()));
+        (notifier, Arc::new(registry))
+    }
+
+    /// Events received so far, oldest first.
+    pub fn events(&self) -> MutexGuard<'_, Vec<Event>> {
+        self.events.lock()
+    }
+
+    /// Number of received events matching `predicate`.
+    pub fn count(&self, predicate: impl Fn(&Event) -> bool) -> usize {
+        self.events
+            .lock()
+            .iter()
+            .filter(|event| predicate(event))
+            .count()
+    }
+}
+
+impl Notifier for RecordingNotifier {
+    fn notify(&self, event: Event) {
+        self.events.lock().push(event);
+    }
+}
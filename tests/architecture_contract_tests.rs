@@ -103,6 +103,23 @@ fn market_event_handler_uses_context_parameter() {
     );
 }
 
+#[test]
+fn execution_spawner_uses_context_parameter() {
+    let source = read_relative("src/application/orchestration/execution.rs");
+    assert!(
+        source.contains("pub(crate) struct ExecutionContext"),
+        "execution spawner should define an ExecutionContext"
+    );
+    assert!(
+        source.contains("context: ExecutionContext)"),
+        "spawn_execution should accept one context object"
+    );
+    assert!(
+        !source.contains("#[allow(clippy::too_many_arguments)]"),
+        "execution spawner should not need too_many_arguments allow"
+    );
+}
+
 #[test]
 fn orchestration_processing_lives_in_application_layer() {
     assert!(
@@ -1,10 +1,22 @@
 use std::fs;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use edgelord::application::orchestration::priority::OpportunityPriority;
+use edgelord::application::risk::sizing::PositionSizer;
+use edgelord::application::solver::backend::SolverBackendKind;
+use edgelord::application::strategy::ensemble::EnsemblePolicy;
+use edgelord::domain::opportunity::HoldPolicy;
+use edgelord::domain::trade::PartialFillPolicy;
 use edgelord::error::{ConfigError, Error};
-use edgelord::infrastructure::config::settings::Config;
+use edgelord::infrastructure::config::diff::diff_from_default;
+use edgelord::infrastructure::config::layer::merge_toml;
+use edgelord::infrastructure::config::profile::Profile;
+use edgelord::infrastructure::config::risk::SizingMode;
+use edgelord::infrastructure::config::settings::{Config, ExchangeSpecificConfig};
+use edgelord::infrastructure::config::wallet::KeySource;
+use rust_decimal::Decimal;
 
 static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
@@ -180,6 +192,230 @@ target_p95_ms = 50
     }
 }
 
+#[test]
+fn config_parses_scoring_weights() {
+    let toml = r#"
+exchange = "polymarket"
+
+[exchange_config]
+type = "polymarket"
+ws_url = "wss://ws-subscriptions-clob.polymarket.com/ws/market"
+api_url = "https://clob.polymarket.com"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[scoring]
+close_horizon_secs = 86400
+
+[scoring.weights]
+volume = 0.5
+liquidity = 0.3
+spread = 0.2
+time_to_close = 0.0
+"#;
+
+    let path = write_temp_config(toml);
+    let config = Config::load(&path).expect("scoring config should parse");
+    let _ = fs::remove_file(&path);
+
+    assert!((config.scoring.weights.volume - 0.5).abs() < f64::EPSILON);
+    assert!((config.scoring.weights.time_to_close).abs() < f64::EPSILON);
+    assert_eq!(config.scoring.close_horizon_secs, 86_400);
+    assert_eq!(config.scoring.report_top, 10);
+}
+
+#[test]
+fn config_rejects_scoring_weights_not_summing_to_one() {
+    let toml = r#"
+exchange = "polymarket"
+
+[exchange_config]
+type = "polymarket"
+ws_url = "wss://ws-subscriptions-clob.polymarket.com/ws/market"
+api_url = "https://clob.polymarket.com"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[scoring.weights]
+volume = 0.6
+liquidity = 0.6
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "scoring.weights",
+            ..
+        })) => {}
+        Err(err) => panic!("Expected invalid scoring weights error, got {err}"),
+        Ok(_) => panic!("Expected invalid scoring weights to be rejected"),
+    }
+}
+
+#[test]
+fn config_parses_shadow_strategies() {
+    let toml = r#"
+exchange = "polymarket"
+
+[exchange_config]
+type = "polymarket"
+ws_url = "wss://ws-subscriptions-clob.polymarket.com/ws/market"
+api_url = "https://clob.polymarket.com"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[shadow]
+variant = "candidate"
+
+[shadow.strategies]
+enabled = ["single_condition"]
+
+[shadow.strategies.single_condition]
+min_edge = 0.02
+"#;
+
+    let path = write_temp_config(toml);
+    let config = Config::load(&path).expect("shadow config should parse");
+    let _ = fs::remove_file(&path);
+
+    let shadow = config.shadow.expect("shadow section should be set");
+    assert_eq!(shadow.variant, "candidate");
+    assert_eq!(shadow.strategies.enabled, vec!["single_condition"]);
+    assert_eq!(
+        shadow.strategies.single_condition.min_edge,
+        Decimal::new(2, 2)
+    );
+}
+
+#[test]
+fn config_parses_strategy_ensemble() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[strategies]
+enabled = ["single_condition", "combinatorial"]
+ensemble = "all"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("ensemble config should load");
+    assert_eq!(config.strategies.ensemble, Some(EnsemblePolicy::All));
+    assert_eq!(Config::default().strategies.ensemble, None);
+}
+
+#[test]
+fn config_parses_strategy_hold_policies() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[strategies.single_condition]
+hold_policy = "hold_to_settlement"
+
+[strategies.market_rebalancing]
+hold_policy = "exit_early"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("hold policy config should load");
+    assert_eq!(
+        config.strategies.single_condition.hold_policy,
+        HoldPolicy::HoldToSettlement
+    );
+    assert_eq!(
+        config.strategies.market_rebalancing.hold_policy,
+        HoldPolicy::ExitEarly
+    );
+
+    let defaults = Config::default().strategies;
+    assert_eq!(defaults.single_condition.hold_policy, HoldPolicy::ExitEarly);
+    assert_eq!(
+        defaults.market_rebalancing.hold_policy,
+        HoldPolicy::HoldToSettlement
+    );
+}
+
+#[test]
+fn config_rejects_ensemble_with_one_strategy() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[strategies]
+enabled = ["single_condition"]
+ensemble = "majority"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "ensemble", ..
+        })) => {}
+        Err(err) => panic!("Expected invalid ensemble error, got {err}"),
+        Ok(_) => panic!("Expected single-strategy ensemble to be rejected"),
+    }
+}
+
+#[test]
+fn config_rejects_shadow_named_primary() {
+    let toml = r#"
+exchange = "polymarket"
+
+[exchange_config]
+type = "polymarket"
+ws_url = "wss://ws-subscriptions-clob.polymarket.com/ws/market"
+api_url = "https://clob.polymarket.com"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[shadow]
+variant = "primary"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "shadow.variant",
+            ..
+        })) => {}
+        Err(err) => panic!("Expected invalid shadow variant error, got {err}"),
+        Ok(_) => panic!("Expected shadow variant \"primary\" to be rejected"),
+    }
+}
+
 #[test]
 fn config_rejects_invalid_cluster_min_gap() {
     let toml = r#"
@@ -211,3 +447,1279 @@ min_gap = 1.5
         Ok(_) => panic!("Expected invalid min_gap to be rejected"),
     }
 }
+
+#[cfg(feature = "kalshi")]
+#[test]
+fn config_selects_kalshi_defaults_from_exchange_name() {
+    let toml = r#"
+exchange = "kalshi"
+
+[logging]
+level = "info"
+format = "pretty"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("kalshi config should load");
+    assert!(config.kalshi_config().is_some());
+    assert!(config.polymarket_config().is_none());
+    assert_eq!(config.network().chain_id, 0);
+}
+
+#[cfg(feature = "manifold")]
+#[test]
+fn config_selects_manifold_defaults_from_exchange_name() {
+    let toml = r#"
+exchange = "manifold"
+
+[logging]
+level = "info"
+format = "pretty"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("manifold config should load");
+    let manifold = config.manifold_config().expect("manifold config selected");
+    assert_eq!(manifold.api_url, "https://api.manifold.markets/v0");
+    assert!(config.polymarket_config().is_none());
+    assert_eq!(config.network().chain_id, 0);
+}
+
+#[cfg(feature = "kalshi")]
+#[test]
+fn config_rejects_mismatched_exchange_section() {
+    let toml = r#"
+exchange = "kalshi"
+
+[exchange_config]
+type = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "exchange_config.type",
+            ..
+        })) => {}
+        Err(err) => panic!("Expected exchange mismatch error, got {err}"),
+        Ok(_) => panic!("Expected mismatched exchange section to be rejected"),
+    }
+}
+
+#[test]
+fn config_parses_replay_section() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[replay]
+path = "books.jsonl"
+speed = 5.0
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("replay config should load");
+    let replay = config.replay.expect("replay section should be set");
+    assert_eq!(replay.path, "books.jsonl");
+    assert_eq!(replay.speed, 5.0);
+}
+
+#[test]
+fn config_parses_snapshot_section_with_default_max_age() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[snapshot]
+path = "books.bin"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("snapshot config should load");
+    let snapshot = config.snapshot.expect("snapshot section should be set");
+    assert_eq!(snapshot.path, "books.bin");
+    assert_eq!(snapshot.max_age_secs, 300);
+}
+
+#[test]
+fn config_parses_webhook_section_with_defaults() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[webhook]
+url = "https://example.com/hook"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("webhook config should load");
+    assert_eq!(
+        config.webhook.url.as_deref(),
+        Some("https://example.com/hook")
+    );
+    assert_eq!(config.webhook.timeout_ms, 5000);
+    assert_eq!(config.webhook.max_retries, 3);
+}
+
+#[test]
+fn config_parses_metrics_section() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[metrics]
+enabled = true
+bind_addr = "0.0.0.0:9100"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("metrics config should load");
+    assert!(config.metrics.enabled);
+    assert_eq!(config.metrics.bind_addr, "0.0.0.0:9100");
+}
+
+#[test]
+fn config_rejects_invalid_metrics_bind_addr() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[metrics]
+enabled = true
+bind_addr = "localhost"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn config_parses_control_api_section() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[control_api]
+enabled = true
+bind_addr = "127.0.0.1:9191"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("control api config should load");
+    assert!(config.control_api.enabled);
+    assert_eq!(config.control_api.bind_addr, "127.0.0.1:9191");
+    assert_eq!(Config::default().control_api.bind_addr, "127.0.0.1:9091");
+}
+
+#[test]
+fn config_rejects_invalid_control_api_bind_addr() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[control_api]
+enabled = true
+bind_addr = "localhost"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn config_parses_risk_exits_section() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[risk.exits]
+stop_loss_pct = 0.10
+take_profit_pct = 0.25
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("risk exits config should load");
+    let exits = config.risk.exits;
+    assert_eq!(exits.stop_loss_pct, Some(Decimal::new(10, 2)));
+    assert_eq!(exits.take_profit_pct, Some(Decimal::new(25, 2)));
+    assert!(exits.exempt_held_to_settlement);
+    assert_eq!(exits.check_interval_secs, 5);
+}
+
+#[test]
+fn config_parses_risk_breaker_section() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[risk.breaker]
+max_consecutive_failures = 3
+cooldown_secs = 60
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("risk breaker config should load");
+    let breaker = config.risk.breaker;
+    assert_eq!(breaker.max_consecutive_failures, 3);
+    assert_eq!(breaker.cooldown_secs, 60);
+    assert_eq!(breaker.window_secs, 300);
+}
+
+#[test]
+fn config_parses_risk_max_book_age() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[risk]
+max_book_age_ms = 2500
+min_profit_pct = 0.01
+max_execution_legs = 5
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("risk config should load");
+    assert_eq!(config.risk.max_book_age_ms, Some(2500));
+    assert_eq!(Config::default().risk.max_book_age_ms, None);
+    assert_eq!(config.risk.min_profit_pct, Some(Decimal::new(1, 2)));
+    assert_eq!(Config::default().risk.min_profit_pct, None);
+    assert_eq!(config.risk.max_execution_legs, Some(5));
+    assert_eq!(Config::default().risk.max_execution_legs, None);
+}
+
+#[test]
+fn config_parses_market_discovery_section() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[market_discovery]
+rescan_interval_secs = 900
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("market discovery config should load");
+    assert_eq!(config.market_discovery.rescan_interval_secs, 900);
+    assert!(config.market_discovery.is_enabled());
+    assert!(!Config::default().market_discovery.is_enabled());
+}
+
+#[test]
+fn config_parses_risk_min_gas_balance() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[risk]
+min_gas_balance = 0.5
+gas_refresh_secs = 120
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("risk gas config should load");
+    assert_eq!(config.risk.min_gas_balance, Decimal::new(5, 1));
+    assert_eq!(config.risk.gas_refresh_secs, 120);
+
+    let defaults = Config::default().risk;
+    assert_eq!(defaults.min_gas_balance, Decimal::ZERO);
+    assert_eq!(defaults.gas_refresh_secs, 60);
+}
+
+#[test]
+fn config_rejects_zero_gas_refresh_interval() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[risk]
+min_gas_balance = 0.5
+gas_refresh_secs = 0
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "gas_refresh_secs",
+            ..
+        })) => {}
+        Err(err) => panic!("Expected invalid gas refresh error, got {err}"),
+        Ok(_) => panic!("Expected zero gas refresh interval to be rejected"),
+    }
+}
+
+#[test]
+fn config_parses_risk_sizing_section() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[risk.sizing]
+mode = "kelly"
+kelly_fraction = 0.25
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("risk sizing config should load");
+    let sizing = &config.risk.sizing;
+    assert_eq!(sizing.mode, SizingMode::Kelly);
+    assert_eq!(sizing.full_size_edge, Decimal::new(10, 2));
+    assert_eq!(
+        PositionSizer::from(sizing),
+        PositionSizer::Kelly(Decimal::new(25, 2))
+    );
+    assert_eq!(Config::default().risk.sizing.mode, SizingMode::Fixed);
+}
+
+#[test]
+fn config_rejects_non_positive_full_size_edge() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[risk.sizing]
+mode = "proportional"
+full_size_edge = 0
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "full_size_edge",
+            ..
+        })) => {}
+        Err(err) => panic!("Expected invalid sizing error, got {err}"),
+        Ok(_) => panic!("Expected non-positive full_size_edge to be rejected"),
+    }
+}
+
+#[test]
+fn config_parses_execution_partial_policy() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[execution]
+on_partial = "retry"
+retry_attempts = 3
+retry_price_step = 0.005
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("execution config should load");
+    assert_eq!(config.execution.on_partial, PartialFillPolicy::Retry);
+    assert_eq!(config.execution.retry_attempts, 3);
+    assert_eq!(config.execution.retry_price_step, Decimal::new(5, 3));
+    assert_eq!(config.execution.unwind_discount, Decimal::new(2, 2));
+    assert_eq!(
+        Config::default().execution.on_partial,
+        PartialFillPolicy::Hold
+    );
+}
+
+#[test]
+fn config_parses_drain_on_shutdown() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[execution]
+drain_on_shutdown = true
+drain_timeout_secs = 10
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("execution config should load");
+    assert!(config.execution.drain_on_shutdown);
+    assert_eq!(config.execution.drain_timeout_secs, 10);
+    assert!(!Config::default().execution.drain_on_shutdown);
+    assert_eq!(Config::default().execution.drain_timeout_secs, 30);
+}
+
+#[test]
+fn config_parses_leg_timeout() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[execution]
+leg_timeout_ms = 1500
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("execution config should load");
+    assert_eq!(config.execution.leg_timeout_ms, 1500);
+    assert_eq!(
+        config.execution.leg_timeout(),
+        Some(Duration::from_millis(1500))
+    );
+    assert_eq!(Config::default().execution.leg_timeout_ms, 5000);
+}
+
+#[test]
+fn config_disables_leg_timeout_at_zero() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[execution]
+leg_timeout_ms = 0
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("execution config should load");
+    assert_eq!(config.execution.leg_timeout(), None);
+}
+
+#[test]
+fn config_parses_execution_rate_limit() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[execution]
+max_orders_per_sec = 2.5
+burst = 5
+max_order_wait_ms = 1000
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("execution config should load");
+    assert!((config.execution.max_orders_per_sec - 2.5).abs() < f64::EPSILON);
+    assert_eq!(config.execution.burst, 5);
+    assert_eq!(config.execution.max_order_wait_ms, 1000);
+
+    let defaults = Config::default().execution;
+    assert!((defaults.max_orders_per_sec - 10.0).abs() < f64::EPSILON);
+    assert_eq!(defaults.burst, 20);
+    assert_eq!(defaults.max_order_wait_ms, 250);
+}
+
+#[test]
+fn config_parses_execution_priority() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[execution]
+priority = "edge"
+verify_before_execute = true
+market_cooldown_ms = 2000
+max_opportunity_age_ms = 150
+max_concurrent = 4
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("execution config should load");
+    assert_eq!(config.execution.priority, OpportunityPriority::Edge);
+    assert!(config.execution.verify_before_execute);
+    assert_eq!(
+        Config::default().execution.priority,
+        OpportunityPriority::Profit
+    );
+    assert!(!Config::default().execution.verify_before_execute);
+    assert_eq!(
+        config.execution.market_cooldown(),
+        Some(Duration::from_millis(2000))
+    );
+    assert_eq!(Config::default().execution.market_cooldown(), None);
+    assert_eq!(
+        config.execution.max_opportunity_age(),
+        Some(Duration::from_millis(150))
+    );
+    assert_eq!(Config::default().execution.max_opportunity_age(), None);
+    assert_eq!(config.execution.max_concurrent, 4);
+    assert_eq!(Config::default().execution.max_concurrent, 0);
+}
+
+#[test]
+fn config_rejects_zero_burst() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[execution]
+burst = 0
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue { field: "burst", .. })) => {}
+        Err(err) => panic!("Expected invalid burst error, got {err}"),
+        Ok(_) => panic!("Expected zero burst to be rejected"),
+    }
+}
+
+#[test]
+fn config_rejects_unwind_discount_of_one() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[execution]
+on_partial = "unwind"
+unwind_discount = 1.0
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "unwind_discount",
+            ..
+        })) => {}
+        Err(err) => panic!("Expected invalid unwind discount error, got {err}"),
+        Ok(_) => panic!("Expected unwind discount of 1 to be rejected"),
+    }
+}
+
+#[test]
+fn config_parses_dry_run_simulate_fills() {
+    let toml = r#"
+exchange = "polymarket"
+dry_run = true
+dry_run_simulate_fills = true
+
+[logging]
+level = "info"
+format = "pretty"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("dry-run simulation config should load");
+    assert!(config.dry_run);
+    assert!(config.dry_run_simulate_fills);
+}
+
+#[test]
+fn config_parses_opportunity_dedup_settings() {
+    let toml = r#"
+exchange = "polymarket"
+dedup_window_ms = 250
+dedup_edge_bucket = 0.01
+stats_dedup_window_ms = 2000
+
+[logging]
+level = "info"
+format = "pretty"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("dedup config should load");
+    assert_eq!(config.dedup_window_ms, 250);
+    assert_eq!(config.dedup_edge_bucket, Decimal::new(1, 2));
+    assert_eq!(config.stats_dedup_window_ms, 2000);
+    assert_eq!(Config::default().stats_dedup_window_ms, 0);
+}
+
+#[test]
+fn config_diff_of_minimal_file_is_empty() {
+    let toml = r#"
+[logging]
+level = "info"
+format = "pretty"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("minimal config should load");
+    assert!(diff_from_default(&config).unwrap().is_empty());
+}
+
+#[test]
+fn config_diff_lists_only_overridden_settings() {
+    let toml = r#"
+dry_run = true
+
+[logging]
+level = "debug"
+format = "pretty"
+
+[risk]
+max_slippage = 0.05
+max_total_exposure = 5000
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("config should load");
+    let paths: Vec<_> = diff_from_default(&config)
+        .unwrap()
+        .into_iter()
+        .map(|change| change.path)
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "dry_run",
+            "logging.level",
+            "risk.max_slippage",
+            "risk.max_total_exposure"
+        ]
+    );
+}
+
+#[test]
+fn config_parses_combinatorial_solver_backend() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[strategies.combinatorial.solver]
+backend = "heuristic"
+heuristic_max_vars = 8
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("solver config should load");
+    let solver = &config.strategies.combinatorial.solver;
+    assert_eq!(solver.backend, SolverBackendKind::Heuristic);
+    assert_eq!(solver.heuristic_max_vars, 8);
+    assert_eq!(
+        Config::default().strategies.combinatorial.solver.backend,
+        SolverBackendKind::Highs
+    );
+}
+
+#[test]
+fn config_rejects_out_of_range_min_relation_confidence() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[strategies.combinatorial]
+min_relation_confidence = 1.5
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "min_relation_confidence",
+            ..
+        })) => {}
+        Err(err) => panic!("Expected invalid min_relation_confidence error, got {err}"),
+        Ok(_) => panic!("Expected min_relation_confidence above 1.0 to be rejected"),
+    }
+}
+
+#[test]
+fn config_rejects_oversized_heuristic_clusters() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[strategies.combinatorial.solver]
+heuristic_max_vars = 64
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "heuristic_max_vars",
+            ..
+        })) => {}
+        Err(err) => panic!("Expected invalid heuristic_max_vars error, got {err}"),
+        Ok(_) => panic!("Expected heuristic_max_vars above the limit to be rejected"),
+    }
+}
+
+#[test]
+fn config_parses_logging_sample_rate() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "debug"
+format = "json"
+sample_rate = 100
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("config with sample rate should load");
+    assert_eq!(config.logging.sample_rate, 100);
+}
+
+#[test]
+fn config_rejects_zero_logging_sample_rate() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+sample_rate = 0
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "sample_rate",
+            ..
+        })) => {}
+        Err(err) => panic!("Expected invalid sample_rate error, got {err}"),
+        Ok(_) => panic!("Expected zero sample_rate to be rejected"),
+    }
+}
+
+#[test]
+fn config_parses_inference_aliases() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[inference.aliases]
+trump = ["Donald Trump", "Donald J. Trump"]
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("config with inference aliases should load");
+    assert_eq!(
+        config.inference.aliases.get("trump"),
+        Some(&vec![
+            "Donald Trump".to_string(),
+            "Donald J. Trump".to_string()
+        ])
+    );
+}
+
+#[test]
+fn config_rejects_variant_aliased_to_two_names() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[inference.aliases]
+trump = ["Donald Trump"]
+donald = ["donald  trump"]
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "aliases", ..
+        })) => {}
+        Err(err) => panic!("Expected invalid aliases error, got {err}"),
+        Ok(_) => panic!("Expected conflicting aliases to be rejected"),
+    }
+}
+
+#[test]
+fn config_parses_inference_budget() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[inference]
+max_batches_per_run = 20
+max_tokens_per_run = 500000
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("config with inference budget should load");
+    assert_eq!(config.inference.max_batches_per_run, 20);
+    assert_eq!(config.inference.max_tokens_per_run, 500_000);
+}
+
+#[test]
+fn config_defaults_inference_budget_to_unlimited() {
+    let config = Config::default();
+
+    assert_eq!(config.inference.max_batches_per_run, 0);
+    assert_eq!(config.inference.max_tokens_per_run, 0);
+}
+
+#[test]
+fn config_parses_inference_prune_interval() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[inference]
+prune_interval_secs = 60
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("config with prune interval should load");
+    assert_eq!(config.inference.prune_interval_secs, 60);
+    assert_eq!(Config::default().inference.prune_interval_secs, 300);
+}
+
+#[test]
+fn config_parses_max_book_depth() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[resources]
+max_book_depth = 20
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("config with max book depth should load");
+    assert_eq!(config.resources.max_book_depth, Some(20));
+    assert_eq!(Config::default().resources.max_book_depth, None);
+}
+
+#[test]
+fn config_rejects_zero_max_book_depth() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[resources]
+max_book_depth = 0
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "max_book_depth",
+            ..
+        })) => {}
+        Err(err) => panic!("Expected invalid max_book_depth error, got {err}"),
+        Ok(_) => panic!("Expected zero max_book_depth to be rejected"),
+    }
+}
+
+#[test]
+fn profile_preset_applies_market_and_connection_limits() {
+    let mut config = Config::default();
+    Profile::Low.apply_to(&mut config);
+
+    assert_eq!(config.profile, Profile::Low);
+    assert_eq!(config.connection_pool.max_connections, 2);
+    assert_eq!(config.connection_pool.subscriptions_per_connection, 200);
+    match &config.exchange_config {
+        ExchangeSpecificConfig::Polymarket(exchange) => {
+            assert_eq!(exchange.market_filter.max_markets, 100);
+            assert_eq!(exchange.market_filter.max_subscriptions, 400);
+        }
+        #[allow(unreachable_patterns)]
+        _ => panic!("default exchange should be polymarket"),
+    }
+}
+
+#[test]
+fn profile_without_preset_only_sets_budget() {
+    let mut config = Config::default();
+    let pool_before = config.connection_pool.max_connections;
+
+    Profile::Production.apply_to(&mut config);
+
+    assert_eq!(config.profile, Profile::Production);
+    assert!(Profile::Production.preset().is_none());
+    assert_eq!(config.connection_pool.max_connections, pool_before);
+}
+
+#[test]
+fn profile_parses_from_cli_names() {
+    assert_eq!("high".parse::<Profile>().unwrap(), Profile::High);
+    assert_eq!("Medium".parse::<Profile>().unwrap(), Profile::Medium);
+
+    let error = "huge".parse::<Profile>().unwrap_err();
+    assert!(matches!(
+        error,
+        ConfigError::InvalidValue {
+            field: "profile",
+            ..
+        }
+    ));
+}
+
+#[test]
+fn config_parses_sized_profile() {
+    let toml = r#"
+profile = "medium"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("sized profile should load");
+    assert_eq!(config.profile, Profile::Medium);
+}
+
+#[test]
+fn wallet_key_source_defaults_to_env() {
+    let config = Config::default();
+    assert_eq!(config.wallet.key_source, KeySource::Env);
+    assert_eq!(config.wallet.keyring_service, "edgelord");
+    assert_eq!(config.wallet.keyring_account, "wallet");
+}
+
+#[test]
+fn config_rejects_keyring_with_keystore_path() {
+    let toml = r#"
+exchange = "polymarket"
+
+[logging]
+level = "info"
+format = "pretty"
+
+[wallet]
+key_source = "keyring"
+keystore_path = "/tmp/keystore.json"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    match result {
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "wallet.keystore_path",
+            ..
+        })) => {}
+        Err(err) => panic!("Expected keystore_path error, got {err}"),
+        Ok(_) => panic!("Expected keyring with keystore_path to be rejected"),
+    }
+}
+
+#[test]
+fn exchange_config_exposes_order_increments() {
+    let toml = r#"
+exchange = "polymarket"
+
+[exchange_config]
+type = "polymarket"
+price_tick = 0.001
+
+[logging]
+level = "info"
+format = "pretty"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    let config = result.expect("config should load");
+    assert_eq!(config.exchange_config.price_tick(), Decimal::new(1, 3));
+    assert_eq!(config.exchange_config.size_increment(), Decimal::new(1, 2));
+}
+
+#[test]
+fn config_rejects_zero_price_tick() {
+    let toml = r#"
+exchange = "polymarket"
+
+[exchange_config]
+type = "polymarket"
+price_tick = 0
+
+[logging]
+level = "info"
+format = "pretty"
+"#;
+
+    let path = write_temp_config(toml);
+    let result = Config::load(&path);
+    let _ = fs::remove_file(&path);
+
+    assert!(matches!(
+        result,
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "price_tick",
+            ..
+        }))
+    ));
+}
+
+const LAYER_BASE: &str = r#"
+dry_run = true
+
+[logging]
+level = "info"
+format = "pretty"
+
+[risk]
+max_slippage = 0.02
+max_total_exposure = 1000
+"#;
+
+#[test]
+fn config_layers_environment_overlay_over_base() {
+    let overlay = r#"
+dry_run = false
+
+[risk]
+max_total_exposure = 5000
+"#;
+
+    let merged = merge_toml(LAYER_BASE, overlay).expect("layers should merge");
+    let config = Config::parse_toml(&merged).expect("merged config should load");
+
+    assert!(!config.dry_run);
+    assert_eq!(config.risk.max_slippage, Decimal::new(2, 2));
+    assert_eq!(config.risk.max_total_exposure, Decimal::new(5000, 0));
+}
+
+#[test]
+fn config_rejects_invalid_layered_result() {
+    let merged =
+        merge_toml(LAYER_BASE, "[risk]\nmax_slippage = 1.5\n").expect("layers should merge");
+
+    assert!(matches!(
+        Config::parse_toml(&merged),
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "max_slippage",
+            ..
+        }))
+    ));
+}
+
+#[test]
+fn config_rejects_min_profit_pct_out_of_range() {
+    for pct in ["0", "1.5"] {
+        let toml = format!(
+            "[logging]\nlevel = \"info\"\nformat = \"pretty\"\n\n[risk]\nmin_profit_pct = {pct}\n"
+        );
+
+        assert!(matches!(
+            Config::parse_toml(&toml),
+            Err(Error::Config(ConfigError::InvalidValue {
+                field: "min_profit_pct",
+                ..
+            }))
+        ));
+    }
+}
+
+#[test]
+fn config_rejects_max_execution_legs_below_two() {
+    let toml =
+        "[logging]\nlevel = \"info\"\nformat = \"pretty\"\n\n[risk]\nmax_execution_legs = 1\n";
+
+    assert!(matches!(
+        Config::parse_toml(toml),
+        Err(Error::Config(ConfigError::InvalidValue {
+            field: "max_execution_legs",
+            ..
+        }))
+    ));
+}
@@ -2,9 +2,14 @@ mod harness;
 mod support;
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use edgelord::adapter::outbound::sqlite::recorder::create_recorder;
 use edgelord::application::cache::book::BookCache;
+use edgelord::application::orchestration::dedup::DedupTracker;
+use edgelord::application::orchestration::priority::OpportunityPriority;
+use edgelord::application::orchestration::throttle::RateLimiter;
+use edgelord::application::orchestration::versions::BookVersionTracker;
 use edgelord::application::position::manager::PositionManager;
 use edgelord::application::risk::manager::RiskManager;
 use edgelord::application::state::{AppState, RiskLimits};
@@ -48,6 +53,9 @@ fn e2e_ingest_detect_persist_notify() {
     )));
 
     let cache = BookCache::new();
+    let dedup = DedupTracker::new(std::time::Duration::from_secs(5), dec!(0.005));
+    let versions = BookVersionTracker::new();
+    let rate_limiter = RateLimiter::unlimited();
     let state = Arc::new(AppState::new(RiskLimits {
         min_profit_threshold: Decimal::ZERO,
         ..Default::default()
@@ -81,7 +89,14 @@ fn e2e_ingest_detect_persist_notify() {
             state: &state,
             stats: &stats,
             position_manager: &position_manager,
+            dedup: &dedup,
+            versions: &versions,
+            rate_limiter: &rate_limiter,
+            verify_before_execute: false,
+            priority: OpportunityPriority::default(),
+            received_at: Instant::now(),
             dry_run: true,
+            shadow: None,
         },
     );
 
@@ -100,7 +115,14 @@ fn e2e_ingest_detect_persist_notify() {
             state: &state,
             stats: &stats,
             position_manager: &position_manager,
+            dedup: &dedup,
+            versions: &versions,
+            rate_limiter: &rate_limiter,
+            verify_before_execute: false,
+            priority: OpportunityPriority::default(),
+            received_at: Instant::now(),
             dry_run: true,
+            shadow: None,
         },
     );
 
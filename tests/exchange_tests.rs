@@ -16,7 +16,7 @@ use edgelord::infrastructure::exchange::reconnecting::ReconnectingDataStream;
 use edgelord::port::{outbound::exchange::MarketDataStream, outbound::exchange::MarketEvent};
 use edgelord::testkit;
 use edgelord::testkit::stream::ScriptedStream;
-use tokio::io::AsyncReadExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpListener;
 
 #[test]
@@ -119,6 +119,7 @@ async fn client_times_out_on_slow_response() {
             connect_timeout_ms: 10,
             retry_max_attempts: 2,
             retry_backoff_ms: 0,
+            ..Default::default()
         },
         ..Default::default()
     };
@@ -137,3 +138,107 @@ async fn client_times_out_on_slow_response() {
 
     let _ = server.await;
 }
+
+/// Serve keep-alive HTTP responses with the scripted statuses, then 200s.
+///
+/// Returns the server URL with counters of accepted connections and
+/// requests served.
+async fn spawn_scripted_http(statuses: Vec<u16>) -> (String, Arc<AtomicU32>, Arc<AtomicU32>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let connections = Arc::new(AtomicU32::new(0));
+    let requests = Arc::new(AtomicU32::new(0));
+    let statuses = Arc::new(statuses);
+
+    let (connections_clone, requests_clone) = (Arc::clone(&connections), Arc::clone(&requests));
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            connections_clone.fetch_add(1, Ordering::SeqCst);
+            let requests = Arc::clone(&requests_clone);
+            let statuses = Arc::clone(&statuses);
+            tokio::spawn(async move {
+                let mut buf = [0u8; 4096];
+                while matches!(socket.read(&mut buf).await, Ok(n) if n > 0) {
+                    let served = requests.fetch_add(1, Ordering::SeqCst) as usize;
+                    let status = statuses.get(served).copied().unwrap_or(200);
+                    let body = if status == 200 { r#"{"data":[]}"# } else { "" };
+                    let response = format!(
+                        "HTTP/1.1 {status} Scripted\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+                        body.len()
+                    );
+                    if socket.write_all(response.as_bytes()).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+
+    (url, connections, requests)
+}
+
+fn scripted_config(api_url: String, timeout_ms: u64, retry_max_attempts: u32) -> PolymarketConfig {
+    PolymarketConfig {
+        api_url,
+        http: PolymarketHttpConfig {
+            timeout_ms,
+            retry_max_attempts,
+            retry_backoff_ms: 1,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn client_retries_intermittent_server_errors() {
+    let (url, _, requests) = spawn_scripted_http(vec![503, 503]).await;
+    let client = PolymarketClient::from_config(&scripted_config(url, 1001, 3));
+
+    let markets = client.get_active_markets(1).await.unwrap();
+
+    assert!(markets.is_empty());
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn client_gives_up_after_max_attempts() {
+    let (url, _, requests) = spawn_scripted_http(vec![503, 503, 503]).await;
+    let client = PolymarketClient::from_config(&scripted_config(url, 1002, 2));
+
+    let result = client.get_active_markets(1).await;
+
+    assert!(
+        matches!(result, Err(Error::Http(err)) if err.status().map(|s| s.as_u16()) == Some(503)),
+        "Expected the last 503 to be returned"
+    );
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn client_does_not_retry_client_errors() {
+    let (url, _, requests) = spawn_scripted_http(vec![404]).await;
+    let client = PolymarketClient::from_config(&scripted_config(url, 1003, 3));
+
+    assert!(client.get_active_markets(1).await.is_err());
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn clients_with_same_settings_share_connections() {
+    let (url, connections, requests) = spawn_scripted_http(Vec::new()).await;
+    let config = scripted_config(url, 1004, 1);
+
+    PolymarketClient::from_config(&config)
+        .get_active_markets(1)
+        .await
+        .unwrap();
+    PolymarketClient::from_config(&config)
+        .get_active_markets(1)
+        .await
+        .unwrap();
+
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,287 @@
+//! Integration tests for adaptive subscription scaling.
+//!
+//! Drives a [`SubscriptionScaler`] with a governor that returns scripted
+//! recommendations and checks the effect on a real priority subscription
+//! manager.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use edgelord::domain::{id::MarketId, id::TokenId, score::MarketScore, score::ScoreFactors};
+use edgelord::infrastructure::governor::resource::ResourceBudget;
+use edgelord::infrastructure::governor::runtime::{
+    AdaptiveGovernor, GovernorConfig, LatencyMetrics, ScalingConfig,
+};
+use edgelord::infrastructure::governor::scaler::{
+    spawn_scaler, ScalingOutcome, SubscriptionScaler,
+};
+use edgelord::infrastructure::governor::scaling::ScalingRecommendation;
+use edgelord::infrastructure::subscription::manager::SubscriptionManager;
+use edgelord::infrastructure::subscription::priority::PrioritySubscriptionManager;
+use tokio::sync::watch;
+
+/// Governor that replays scripted recommendations, then holds.
+struct ScriptedGovernor {
+    config: GovernorConfig,
+    script: Mutex<VecDeque<ScalingRecommendation>>,
+    latencies: Mutex<Vec<Duration>>,
+    scaled: Mutex<usize>,
+}
+
+impl ScriptedGovernor {
+    fn new(script: Vec<ScalingRecommendation>) -> Self {
+        Self::with_scaling(script, Duration::ZERO, Duration::from_secs(5))
+    }
+
+    fn with_scaling(
+        script: Vec<ScalingRecommendation>,
+        cooldown: Duration,
+        check_interval: Duration,
+    ) -> Self {
+        Self {
+            config: GovernorConfig {
+                scaling: ScalingConfig {
+                    check_interval,
+                    expand_step: 4,
+                    contract_step: 2,
+                    cooldown,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            script: Mutex::new(script.into()),
+            latencies: Mutex::new(Vec::new()),
+            scaled: Mutex::new(0),
+        }
+    }
+
+    fn scaled(&self) -> usize {
+        *self.scaled.lock().unwrap()
+    }
+
+    fn remaining(&self) -> usize {
+        self.script.lock().unwrap().len()
+    }
+}
+
+impl AdaptiveGovernor for ScriptedGovernor {
+    fn record_latency(&self, latency: Duration) {
+        self.latencies.lock().unwrap().push(latency);
+    }
+
+    fn record_throughput(&self, _messages_per_sec: f64) {}
+
+    fn latency_metrics(&self) -> LatencyMetrics {
+        LatencyMetrics::default()
+    }
+
+    fn recommendation(&self) -> ScalingRecommendation {
+        self.script
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or(ScalingRecommendation::Hold)
+    }
+
+    fn notify_scaled(&self) {
+        *self.scaled.lock().unwrap() += 1;
+    }
+
+    fn set_resource_budget(&self, _budget: ResourceBudget) {}
+
+    fn config(&self) -> &GovernorConfig {
+        &self.config
+    }
+}
+
+/// Manager with `markets` single-token markets queued and `active` of them subscribed.
+async fn manager(max: usize, markets: usize, active: usize) -> Arc<PrioritySubscriptionManager> {
+    let manager = PrioritySubscriptionManager::new(max);
+    let scores = (0..markets)
+        .map(|i| {
+            let market_id = MarketId::new(format!("market-{i}"));
+            manager.register_market_tokens(
+                market_id.clone(),
+                vec![TokenId::new(format!("token-{i}"))],
+            );
+            MarketScore::new(market_id, ScoreFactors::default(), 1.0 - i as f64 / 100.0)
+        })
+        .collect();
+    manager.enqueue(scores);
+    manager.expand(active).await.unwrap();
+    Arc::new(manager)
+}
+
+fn scaler(
+    governor: &Arc<ScriptedGovernor>,
+    manager: &Arc<PrioritySubscriptionManager>,
+) -> SubscriptionScaler {
+    SubscriptionScaler::new(
+        Arc::clone(governor) as Arc<dyn AdaptiveGovernor>,
+        Arc::clone(manager) as Arc<dyn SubscriptionManager>,
+    )
+}
+
+#[tokio::test]
+async fn expand_adds_one_step_of_subscriptions() {
+    let governor = Arc::new(ScriptedGovernor::new(vec![ScalingRecommendation::expand(
+        1_000,
+    )]));
+    let manager = manager(100, 20, 5).await;
+
+    let outcome = scaler(&governor, &manager).check().await.unwrap();
+
+    let ScalingOutcome::Expanded(added) = outcome else {
+        panic!("expected expansion, got {outcome:?}");
+    };
+    assert_eq!(added.len(), 4);
+    assert_eq!(manager.active_count(), 9);
+    assert_eq!(governor.scaled(), 1);
+}
+
+#[tokio::test]
+async fn expand_respects_max_subscriptions() {
+    let governor = Arc::new(ScriptedGovernor::new(vec![
+        ScalingRecommendation::expand(1_000),
+        ScalingRecommendation::expand(1_000),
+    ]));
+    let manager = manager(7, 20, 5).await;
+    let scaler = scaler(&governor, &manager);
+
+    assert!(matches!(
+        scaler.check().await.unwrap(),
+        ScalingOutcome::Expanded(_)
+    ));
+    assert_eq!(manager.active_count(), 7);
+
+    assert_eq!(scaler.check().await.unwrap(), ScalingOutcome::Held);
+    assert_eq!(manager.active_count(), 7);
+    assert_eq!(governor.scaled(), 1);
+}
+
+#[tokio::test]
+async fn contract_sheds_subscriptions_and_requeues_markets() {
+    let governor = Arc::new(ScriptedGovernor::new(vec![
+        ScalingRecommendation::contract(1_000),
+        ScalingRecommendation::expand(1_000),
+    ]));
+    let manager = manager(100, 10, 10).await;
+    let scaler = scaler(&governor, &manager);
+
+    let outcome = scaler.check().await.unwrap();
+
+    assert_eq!(
+        outcome,
+        ScalingOutcome::Contracted(vec![TokenId::new("token-9"), TokenId::new("token-8")])
+    );
+    assert_eq!(manager.active_count(), 8);
+    assert!(!manager.is_subscribed(&MarketId::new("market-9")));
+    assert_eq!(manager.pending_count(), 2);
+
+    assert!(matches!(
+        scaler.check().await.unwrap(),
+        ScalingOutcome::Expanded(_)
+    ));
+    assert_eq!(manager.active_count(), 10);
+    assert!(manager.is_subscribed(&MarketId::new("market-9")));
+    assert_eq!(governor.scaled(), 2);
+}
+
+#[tokio::test]
+async fn contract_honours_deeper_suggested_count() {
+    let governor = Arc::new(ScriptedGovernor::new(vec![
+        ScalingRecommendation::contract(3),
+    ]));
+    let manager = manager(100, 10, 10).await;
+
+    scaler(&governor, &manager).check().await.unwrap();
+
+    assert_eq!(manager.active_count(), 3);
+}
+
+#[tokio::test]
+async fn hold_leaves_subscriptions_unchanged() {
+    let governor = Arc::new(ScriptedGovernor::new(vec![ScalingRecommendation::Hold]));
+    let manager = manager(100, 10, 5).await;
+
+    let outcome = scaler(&governor, &manager).check().await.unwrap();
+
+    assert_eq!(outcome, ScalingOutcome::Held);
+    assert_eq!(manager.active_count(), 5);
+    assert_eq!(governor.scaled(), 0);
+}
+
+#[tokio::test]
+async fn cooldown_defers_next_scaling_action() {
+    let governor = Arc::new(ScriptedGovernor::with_scaling(
+        vec![
+            ScalingRecommendation::expand(1_000),
+            ScalingRecommendation::expand(1_000),
+        ],
+        Duration::from_secs(60),
+        Duration::from_secs(5),
+    ));
+    let manager = manager(100, 20, 5).await;
+    let scaler = scaler(&governor, &manager);
+
+    scaler.check().await.unwrap();
+    let outcome = scaler.check().await.unwrap();
+
+    assert_eq!(outcome, ScalingOutcome::Held);
+    assert_eq!(manager.active_count(), 9);
+    assert_eq!(governor.remaining(), 1);
+}
+
+#[tokio::test]
+async fn disabled_governor_never_scales() {
+    let mut governor = ScriptedGovernor::new(vec![ScalingRecommendation::expand(1_000)]);
+    governor.config.enabled = false;
+    let governor = Arc::new(governor);
+    let manager = manager(100, 20, 5).await;
+
+    let outcome = scaler(&governor, &manager).check().await.unwrap();
+
+    assert_eq!(outcome, ScalingOutcome::Held);
+    assert_eq!(manager.active_count(), 5);
+}
+
+#[tokio::test]
+async fn record_latency_feeds_governor() {
+    let governor = Arc::new(ScriptedGovernor::new(Vec::new()));
+    let manager = manager(100, 0, 0).await;
+
+    scaler(&governor, &manager).record_latency(Duration::from_millis(7));
+
+    assert_eq!(
+        *governor.latencies.lock().unwrap(),
+        vec![Duration::from_millis(7)]
+    );
+}
+
+#[tokio::test]
+async fn spawned_scaler_checks_each_interval_until_shutdown() {
+    let governor = Arc::new(ScriptedGovernor::with_scaling(
+        vec![
+            ScalingRecommendation::contract(1_000),
+            ScalingRecommendation::contract(1_000),
+        ],
+        Duration::ZERO,
+        Duration::from_millis(10),
+    ));
+    let manager = manager(100, 10, 10).await;
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let handle = spawn_scaler(Arc::new(scaler(&governor, &manager)), shutdown_rx);
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while governor.scaled() < 2 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("scaler did not act on scripted recommendations");
+    assert_eq!(manager.active_count(), 6);
+
+    shutdown_tx.send(true).unwrap();
+    handle.await.unwrap();
+}
@@ -33,16 +33,21 @@ fn test_market_parser_parses_binary_markets() {
                 token_id: "yes-token".to_string(),
                 name: "Yes".to_string(),
                 price: None,
+                volume_24h: None,
+                liquidity: None,
             },
             OutcomeInfo {
                 token_id: "no-token".to_string(),
                 name: "No".to_string(),
                 price: None,
+                volume_24h: None,
+                liquidity: None,
             },
         ],
         active: true,
         volume_24h: None,
         liquidity: None,
+        close_time: None,
     }];
 
     let markets = parser.parse_markets(&market_infos);
@@ -0,0 +1,105 @@
+//! Comparison of the heuristic and `HiGHS` oracle backends.
+//!
+//! Small clusters of binary outcomes linked by exactly-one and implication
+//! constraints are what the combinatorial strategy mostly sees. The
+//! heuristic must agree with `HiGHS` on them, and should be faster.
+
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use edgelord::adapter::outbound::solver::highs::HiGHSSolver;
+use edgelord::application::solver::heuristic::HeuristicOracle;
+use edgelord::domain::constraint::{Constraint, VariableBounds};
+use edgelord::port::outbound::solver::{IlpProblem, LpProblem, SolverBackend};
+
+/// A cluster of `n` binary outcomes: exactly one holds, plus a few random
+/// implications `x_i <= x_j`.
+fn random_cluster(rng: &mut StdRng, n: usize) -> IlpProblem {
+    let mut constraints = vec![Constraint::eq(vec![Decimal::ONE; n], Decimal::ONE)];
+    for _ in 0..rng.gen_range(0..=n / 2) {
+        let i = rng.gen_range(0..n);
+        let j = rng.gen_range(0..n);
+        if i == j {
+            continue;
+        }
+        let mut coefficients = vec![Decimal::ZERO; n];
+        coefficients[i] = Decimal::ONE;
+        coefficients[j] = -Decimal::ONE;
+        constraints.push(Constraint::leq(coefficients, Decimal::ZERO));
+    }
+
+    IlpProblem::all_binary(LpProblem {
+        objective: vec![Decimal::ZERO; n],
+        constraints,
+        bounds: vec![VariableBounds::binary(); n],
+    })
+}
+
+fn random_gradient(rng: &mut StdRng, n: usize) -> Vec<Decimal> {
+    (0..n)
+        .map(|_| Decimal::new(rng.gen_range(-1000..1000), 3))
+        .collect()
+}
+
+fn small_clusters(count: usize) -> Vec<(Vec<Decimal>, IlpProblem)> {
+    let mut rng = StdRng::seed_from_u64(7);
+    (0..count)
+        .map(|_| {
+            let n = rng.gen_range(2..=8);
+            (random_gradient(&mut rng, n), random_cluster(&mut rng, n))
+        })
+        .collect()
+}
+
+fn time_backend(backend: &dyn SolverBackend, clusters: &[(Vec<Decimal>, IlpProblem)]) -> Duration {
+    let started = Instant::now();
+    for (gradient, problem) in clusters {
+        backend.solve_oracle(gradient, problem).unwrap();
+    }
+    started.elapsed()
+}
+
+#[test]
+fn heuristic_matches_highs_on_small_clusters() {
+    let heuristic = HeuristicOracle::new(8);
+    let highs = HiGHSSolver::new();
+
+    for (gradient, problem) in small_clusters(200) {
+        let fast = heuristic.solve_oracle(&gradient, &problem).unwrap();
+        let exact = highs.solve_oracle(&gradient, &problem).unwrap();
+
+        match (fast, exact) {
+            (Some(fast), Some(exact)) => assert!(
+                (fast.objective - exact.objective).abs() < dec!(0.000001),
+                "heuristic objective {} differs from HiGHS {} for gradient {:?}",
+                fast.objective,
+                exact.objective,
+                gradient
+            ),
+            (None, None) => {}
+            (fast, exact) => panic!(
+                "feasibility disagrees for gradient {gradient:?}: heuristic {fast:?}, HiGHS {exact:?}"
+            ),
+        }
+    }
+}
+
+#[test]
+#[ignore = "timing benchmark; run with --ignored --nocapture"]
+fn heuristic_is_faster_than_highs_on_small_clusters() {
+    let clusters = small_clusters(2000);
+    let heuristic = time_backend(&HeuristicOracle::new(8), &clusters);
+    let highs = time_backend(&HiGHSSolver::new(), &clusters);
+
+    println!(
+        "{} oracle calls: heuristic {:?}, HiGHS {:?}",
+        clusters.len(),
+        heuristic,
+        highs
+    );
+    assert!(heuristic < highs);
+}
@@ -5,7 +5,7 @@ use std::sync::Arc;
 
 use edgelord::adapter::outbound::sqlite::database::connection::run_migrations;
 use edgelord::adapter::outbound::sqlite::recorder::SqliteRecorder;
-use edgelord::domain::stats::RecordedOpportunity;
+use edgelord::domain::stats::{RecordedOpportunity, PRIMARY_VARIANT};
 use rust_decimal_macros::dec;
 
 /// Guard to clean up temporary database file after test
@@ -67,12 +67,15 @@ fn record_opportunity_returns_correct_id_under_concurrency() {
             std::thread::sleep(std::time::Duration::from_millis(i as u64 * 10));
 
             let event = RecordedOpportunity {
+                uid: format!("opp-{}", i),
                 strategy: format!("strategy-{}", i),
                 market_ids: vec![format!("market-{}", i)],
                 edge: dec!(0.05),
                 expected_profit: dec!(1.0),
                 executed: false,
                 rejected_reason: None,
+                variant: PRIMARY_VARIANT.to_string(),
+                content_hash: None,
             };
 
             recorder_clone.record_opportunity(&event)
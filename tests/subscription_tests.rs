@@ -26,6 +26,8 @@ fn make_market(id: &str, outcomes: usize) -> MarketInfo {
                 "No".to_string()
             },
             price: None,
+            volume_24h: None,
+            liquidity: None,
         })
         .collect();
 
@@ -36,6 +38,7 @@ fn make_market(id: &str, outcomes: usize) -> MarketInfo {
         active: true,
         volume_24h: None,
         liquidity: None,
+        close_time: None,
     }
 }
 
@@ -181,16 +184,21 @@ async fn test_filter_excludes_inactive_markets() {
                     token_id: "active-yes".to_string(),
                     name: "Yes".to_string(),
                     price: None,
+                    volume_24h: None,
+                    liquidity: None,
                 },
                 OutcomeInfo {
                     token_id: "active-no".to_string(),
                     name: "No".to_string(),
                     price: None,
+                    volume_24h: None,
+                    liquidity: None,
                 },
             ],
             active: true,
             volume_24h: None,
             liquidity: None,
+            close_time: None,
         },
         MarketInfo {
             id: "inactive-1".to_string(),
@@ -200,16 +208,21 @@ async fn test_filter_excludes_inactive_markets() {
                     token_id: "inactive-yes".to_string(),
                     name: "Yes".to_string(),
                     price: None,
+                    volume_24h: None,
+                    liquidity: None,
                 },
                 OutcomeInfo {
                     token_id: "inactive-no".to_string(),
                     name: "No".to_string(),
                     price: None,
+                    volume_24h: None,
+                    liquidity: None,
                 },
             ],
             active: false,
             volume_24h: None,
             liquidity: None,
+            close_time: None,
         },
     ];
 